@@ -112,6 +112,7 @@ define_common_strings! {
     str_dynamic: b"dynamic",
     str_embedded: b"embedded",
     str_embeddedCFF: b"embeddedCFF",
+    str_emergency: b"emergency",
     str_enabled: b"enabled",
     str_error: b"error",
     str_extension: b"extension",
@@ -140,7 +141,9 @@ define_common_strings! {
     str_inline: b"inline",
     str_inner: b"inner",
     str_input: b"input",
+    str_insufficientWidth: b"insufficientWidth",
     str_interfaces: b"interfaces",
+    str_invalid: b"invalid",
     str_ioError: b"ioError",
     str_isDynamic: b"isDynamic",
     str_isFinal: b"isFinal",
@@ -190,6 +193,7 @@ define_common_strings! {
     str_number: b"number",
     str_Number: b"Number",
     str_object: b"object",
+    str_onActivity: b"onActivity",
     str_onCancel: b"onCancel",
     str_onChanged: b"onChanged",
     str_onClose: b"onClose",
@@ -241,6 +245,7 @@ define_common_strings! {
     str_position: b"position",
     str_prefix: b"prefix",
     str_print: b"print",
+    str_printAsBitmap: b"printAsBitmap",
     str_prototype: b"prototype",
     str_push: b"push",
     str_quality: b"quality",
@@ -298,6 +303,7 @@ define_common_strings! {
     str_undefined: b"undefined",
     str_uri: b"uri",
     str_useHandCursor: b"useHandCursor",
+    str_valid: b"valid",
     str_value: b"value",
     str_valueOf: b"valueOf",
     str_variables: b"variables",
@@ -15,6 +15,7 @@ mod activation;
 mod callable_value;
 mod clamp;
 mod debug;
+pub mod debugger;
 mod error;
 mod flv;
 mod fscommand;
@@ -22,6 +23,7 @@ pub(crate) mod globals;
 mod object;
 mod object_reference;
 mod parameters;
+mod print;
 mod property;
 mod property_map;
 mod runtime;
@@ -4,7 +4,7 @@ use crate::avm1::function::{Avm1Function, ExecutionReason, FunctionObject};
 use crate::avm1::property::Attribute;
 use crate::avm1::runtime::skip_actions;
 use crate::avm1::scope::{Scope, ScopeClass};
-use crate::avm1::{ArrayBuilder, Object, Value, fscommand, globals, scope};
+use crate::avm1::{ArrayBuilder, Object, Value, fscommand, globals, print, scope};
 use crate::backend::navigator::{NavigationMethod, Request};
 use crate::context::UpdateContext;
 use crate::display_object::{
@@ -136,6 +136,15 @@ impl<'a> ActivationIdentifier<'a> {
     pub fn depth(&self) -> u16 {
         self.depth
     }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// The identifier of the activation this one was started from.
+    pub fn parent(&self) -> Option<&'a ActivationIdentifier<'a>> {
+        self.parent
+    }
 }
 
 /// Represents a single activation of a given AVM1 function or keyframe.
@@ -408,14 +417,24 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     pub fn run_actions(&mut self, code: SwfSlice) -> Result<ReturnType<'gc>, Error<'gc>> {
         let mut read = Reader::new(&code.movie.data()[code.start..], self.swf_version());
 
-        loop {
+        if let Some(debugger) = self.context.avm1.debugger_mut() {
+            debugger.enter_actions();
+        }
+
+        let result = loop {
             let result = self.do_action(&code, &mut read);
             match result {
                 Ok(FrameControl::Return(return_type)) => break Ok(return_type),
                 Ok(FrameControl::Continue) => {}
                 Err(e) => break Err(e),
             }
+        };
+
+        if let Some(debugger) = self.context.avm1.debugger_mut() {
+            debugger.exit_actions();
         }
+
+        result
     }
 
     /// Run a single action from a given action reader.
@@ -436,6 +455,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             //Executing beyond the end of a function constitutes an implicit return.
             Ok(FrameControl::Return(ReturnType::Implicit))
         } else {
+            if self.context.avm1.debugger_attached() {
+                self.debugger_hook(data, reader);
+            }
+
             let action = reader.read_action()?;
             avm_debug!(
                 self.context.avm1,
@@ -549,6 +572,47 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
+    /// Give the attached debugger a chance to pause before the action
+    /// `reader` is about to read is executed.
+    #[inline(never)]
+    fn debugger_hook(&mut self, data: &SwfSlice, reader: &Reader<'_>) {
+        let Some(mut debugger) = self.context.avm1.take_debugger() else {
+            return;
+        };
+
+        let movie = &data.movie;
+        let offset = reader.get_ref().as_ptr() as usize - movie.data().as_ptr() as usize;
+
+        if offset == data.start {
+            debugger.poll();
+        }
+
+        if debugger.is_attached() {
+            let swf_version = self.swf_version;
+            debugger.on_action(self, movie.url(), offset, || {
+                match Reader::new(reader.get_ref(), swf_version).read_action() {
+                    Ok(action) => format!("{action:?}"),
+                    Err(e) => format!("Invalid action: {e}"),
+                }
+            });
+        }
+
+        if debugger.is_attached() {
+            self.context.avm1.restore_debugger(debugger);
+        } else {
+            tracing::info!("AVM1 debugger detached");
+        }
+    }
+
+    /// The registers this activation can access, for debugging.
+    pub(super) fn debug_registers(&self) -> Vec<Value<'gc>> {
+        if self.local_registers.is_empty() {
+            (0..4).map(|id| self.current_register(id)).collect()
+        } else {
+            self.local_registers.iter().map(Cell::get).collect()
+        }
+    }
+
     fn stack_push(&mut self, mut value: Value<'gc>) {
         if let Value::Object(obj) = value {
             // Note that there currently exists a subtle issue with this logic:
@@ -1133,6 +1197,17 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn action_get_url(&mut self, action: GetUrl) -> Result<FrameControl<'gc>, Error<'gc>> {
         let target = action.target.decode(self.encoding());
         let url = action.url.decode(self.encoding());
+        if let Some(command) = print::parse(&url) {
+            let start = self.target_clip_or_root();
+            let target = self.resolve_target_display_object(
+                start,
+                AvmString::new(self.gc(), target).into(),
+                true,
+            )?;
+            print::handle(command, target, self)?;
+            return Ok(FrameControl::Continue);
+        }
+
         // TODO: Use `StageObject::get_level_by_path`.
         if target.starts_with(WStr::from_units(b"_level")) && target.len() > 6 {
             match target[6..].parse::<i32>() {
@@ -1195,6 +1270,13 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             return Ok(FrameControl::Continue);
         }
 
+        if let Some(command) = print::parse(&url) {
+            let start = self.target_clip_or_root();
+            let target = self.resolve_target_display_object(start, target_val, true)?;
+            print::handle(command, target, self)?;
+            return Ok(FrameControl::Continue);
+        }
+
         // TODO: Use `StageObject::get_level_by_path`.
         let level_target = if target.starts_with(WStr::from_units(b"_level")) && target.len() >= 6 {
             match target[6..].parse::<f64>() {
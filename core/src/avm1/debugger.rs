@@ -0,0 +1,1160 @@
+//! AVM1 step debugger
+//!
+//! The debugger lets an external client pause AVM1 execution on breakpoints,
+//! step through actions, and inspect the call stack, registers, constant
+//! pool, scope chain and operand stack of the paused activation, as well as
+//! evaluate watch expressions.
+//!
+//! Just like with the AVM2 debugger, the player thread blocks while paused,
+//! so nothing else runs until the client resumes execution.
+//!
+//! The debugger can also be driven by the AVM1 debugger window of the debug
+//! UI, either on its own or alongside a client. The debug UI is drawn on the
+//! player thread, in between frames, so it can't wait for the user in the
+//! middle of a script. Instead, when the window breaks, the state of the VM
+//! is recorded before every action until the script that hit the break
+//! returns, and the debug UI suspends the player while the user steps
+//! through that recording. Breakpoints hit while a client is connected pause
+//! for the client.
+//!
+//! # Protocol
+//!
+//! The transport and message format are the same as for the AVM2 debugger
+//! (see `avm2::debugger`): every message is a single JSON object on its own
+//! line, exchanged through a [`DebuggerConnection`].
+//!
+//! Requests sent by the client have a `command` field:
+//!
+//! | Command             | Arguments                    | Available     |
+//! | ------------------- | ---------------------------- | ------------- |
+//! | `set_breakpoint`    | `offset` and maybe `movie`   | always        |
+//! | `set_breakpoint`    | `clip` and maybe `frame`     | always        |
+//! | `remove_breakpoint` | `id`                         | always        |
+//! | `list_breakpoints`  |                              | always        |
+//! | `detach`            |                              | always        |
+//! | `pause`             |                              | while running |
+//! | `continue`          |                              | while paused  |
+//! | `step_in`           |                              | while paused  |
+//! | `step_over`         |                              | while paused  |
+//! | `step_out`          |                              | while paused  |
+//! | `call_stack`        |                              | while paused  |
+//! | `registers`         |                              | while paused  |
+//! | `constant_pool`     |                              | while paused  |
+//! | `scopes`            |                              | while paused  |
+//! | `operands`          |                              | while paused  |
+//! | `evaluate`          | `expression`                 | while paused  |
+//!
+//! `offset` is an offset into the data of the movie with the URL `movie`,
+//! or of any movie if `movie` is omitted. `clip` is the path of a display
+//! object (for example `_level0.menu`), whose timeline scripts are paused
+//! before their first action runs; `frame` restricts this to a single frame.
+//!
+//! Commands sent while the movie is running are processed the next time
+//! AVM1 code is executed. `detach` behaves as it does for AVM2.
+//!
+//! Messages sent by the player have an `event` field:
+//!
+//! * `breakpoint_set` (`id`), `breakpoint_removed` (`id`) and `breakpoints`
+//!   (`breakpoints`, a list of `id` plus location) answer the breakpoint
+//!   commands.
+//! * `paused` is sent whenever execution stops. `reason` is one of
+//!   `breakpoint` (with the breakpoint `id`), `step` or `pause`. The
+//!   `activation` (for example `[Frame] / onPress()`, although function
+//!   names are only known with the `avm_debug` feature), `movie` and
+//!   `offset` of the next action are included, as well as the `action`
+//!   itself.
+//! * `resumed` is sent when execution continues after a pause.
+//! * `call_stack` (`frames`, the names of the activations), `registers`
+//!   (`registers`), `constant_pool` (`constants`), `scopes` (`scopes`) and
+//!   `operands` (`operands`) answer the inspection commands. Frames and
+//!   scopes are ordered from innermost to outermost, operands from the
+//!   bottom of the stack to the top. Every scope has a `kind` and the
+//!   `variables` stored on it, by name.
+//! * `value` (`expression` and `value`) answers `evaluate`.
+//! * `error` (`message`) is sent when a command could not be processed.
+//!
+//! Values are described like they are for AVM2, with a `type` field
+//! (`undefined`, `null`, `Boolean`, `Number`, `String`, `DisplayObject`,
+//! `Function` or `Object`) and, except for `undefined` and `null`, a `value`
+//! field. Display objects are described by their path, other objects by an
+//! opaque identifier. Describing values never runs ActionScript, but
+//! evaluating an expression may, as it is looked up like `GetVariable` would.
+
+use crate::avm1::activation::{Activation, ActivationIdentifier};
+use crate::avm1::scope::Scope;
+use crate::avm1::{Object, Value};
+use crate::display_object::{DisplayObject, TDisplayObject};
+use crate::string::AvmString;
+use serde_json::{Map as JsonObject, Value as JsonValue, json};
+use std::fmt;
+use std::sync::mpsc::TryRecvError;
+use web_time::Instant;
+
+pub use crate::avm2::debugger::DebuggerConnection;
+
+/// Actions sent to the client are cut off after this many characters.
+const MAX_ACTION_LENGTH: usize = 200;
+
+/// How many actions are recorded for the debug UI after a break, at most.
+const MAX_SNAPSHOTS: usize = 10_000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BreakpointLocation {
+    /// Before running the action at the given offset into the data of the
+    /// movie with the given URL, or of any movie.
+    Action {
+        movie: Option<String>,
+        offset: usize,
+    },
+
+    /// Before running a timeline script of the display object with the given
+    /// path, optionally only on a given frame.
+    FrameScript { clip: String, frame: Option<u16> },
+}
+
+impl BreakpointLocation {
+    fn to_json(&self, id: u32) -> JsonValue {
+        match self {
+            BreakpointLocation::Action { movie, offset } => {
+                json!({ "id": id, "movie": movie, "offset": offset })
+            }
+            BreakpointLocation::FrameScript { clip, frame } => {
+                json!({ "id": id, "clip": clip, "frame": frame })
+            }
+        }
+    }
+}
+
+impl fmt::Display for BreakpointLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BreakpointLocation::Action {
+                movie: Some(movie),
+                offset,
+            } => write!(f, "{movie} @ {offset:#x}"),
+            BreakpointLocation::Action {
+                movie: None,
+                offset,
+            } => write!(f, "Any movie @ {offset:#x}"),
+            BreakpointLocation::FrameScript {
+                clip,
+                frame: Some(frame),
+            } => write!(f, "{clip}, frame {frame}"),
+            BreakpointLocation::FrameScript { clip, frame: None } => f.write_str(clip),
+        }
+    }
+}
+
+pub struct Breakpoint {
+    id: u32,
+    location: BreakpointLocation,
+
+    /// Whether the breakpoint was set by the client, rather than the debug UI.
+    remote: bool,
+}
+
+impl Breakpoint {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn location(&self) -> &BreakpointLocation {
+        &self.location
+    }
+}
+
+/// The state of the VM right before an action ran, as recorded for the
+/// debug UI.
+#[derive(Debug)]
+pub struct Snapshot {
+    /// The activation running the action, e.g. `[Frame] / onPress()`.
+    pub activation: String,
+
+    /// The activation and the activations it was called from, innermost
+    /// first.
+    pub call_stack: Vec<String>,
+
+    /// How deeply nested the activation is.
+    pub depth: u16,
+
+    pub movie: String,
+
+    /// Offset of the action into the data of the movie.
+    pub offset: usize,
+
+    pub action: String,
+
+    /// The operand stack, from the top of the stack to the bottom.
+    pub operands: Vec<String>,
+
+    pub registers: Vec<String>,
+    pub constant_pool: Vec<String>,
+
+    /// The scope chain, innermost scope first.
+    pub scopes: Vec<ScopeSnapshot>,
+
+    /// Every watch expression along with its value.
+    pub watches: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+pub struct ScopeSnapshot {
+    pub kind: String,
+    pub variables: Vec<(String, String)>,
+}
+
+/// The actions recorded for the debug UI after it hit a break.
+#[derive(Debug)]
+pub struct Break {
+    pub reason: String,
+    pub snapshots: Vec<Snapshot>,
+
+    /// Whether recording stopped before the script returned, because too
+    /// many actions ran.
+    pub truncated: bool,
+}
+
+impl Break {
+    /// The snapshot after `index`, whichever activation it belongs to.
+    pub fn step_in(&self, index: usize) -> Option<usize> {
+        (index + 1 < self.snapshots.len()).then_some(index + 1)
+    }
+
+    /// The next snapshot after `index` that isn't inside a call made by the
+    /// current activation.
+    pub fn step_over(&self, index: usize) -> Option<usize> {
+        let depth = self.snapshots.get(index)?.depth;
+        self.find_after(index, |snapshot| snapshot.depth <= depth)
+    }
+
+    /// The next snapshot after `index` that belongs to a caller of the
+    /// current activation.
+    pub fn step_out(&self, index: usize) -> Option<usize> {
+        let depth = self.snapshots.get(index)?.depth;
+        self.find_after(index, |snapshot| snapshot.depth < depth)
+    }
+
+    fn find_after(&self, index: usize, predicate: impl Fn(&Snapshot) -> bool) -> Option<usize> {
+        self.snapshots
+            .iter()
+            .enumerate()
+            .skip(index + 1)
+            .find(|(_, snapshot)| predicate(snapshot))
+            .map(|(index, _)| index)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum StepMode {
+    /// Pause on the next action.
+    Into,
+
+    /// Pause on the next action at or above the given activation depth.
+    Over(u16),
+
+    /// Pause on the next action above the given activation depth.
+    Out(u16),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PauseReason {
+    Breakpoint(u32),
+    Step,
+    Pause,
+}
+
+enum Command {
+    SetBreakpoint(BreakpointLocation),
+    RemoveBreakpoint(u32),
+    ListBreakpoints,
+    Detach,
+    Pause,
+    Continue,
+    StepIn,
+    StepOver,
+    StepOut,
+    CallStack,
+    Registers,
+    ConstantPool,
+    Scopes,
+    Operands,
+    Evaluate(String),
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let request: JsonValue =
+            serde_json::from_str(line).map_err(|e| format!("Malformed request: {e}"))?;
+        let name = request
+            .get("command")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| "Missing command".to_string())?;
+
+        let command = match name {
+            "set_breakpoint" => {
+                let location = if let Some(offset) = request.get("offset") {
+                    let offset = offset.as_u64().ok_or("Invalid offset")?;
+                    let movie = match request.get("movie") {
+                        Some(movie) => Some(movie.as_str().ok_or("Invalid movie")?.to_string()),
+                        None => None,
+                    };
+                    BreakpointLocation::Action {
+                        movie,
+                        offset: offset as usize,
+                    }
+                } else if let Some(clip) = request.get("clip") {
+                    let clip = clip.as_str().ok_or("Invalid clip")?;
+                    let frame = match request.get("frame") {
+                        Some(frame) => Some(
+                            frame
+                                .as_u64()
+                                .and_then(|frame| u16::try_from(frame).ok())
+                                .ok_or("Invalid frame")?,
+                        ),
+                        None => None,
+                    };
+                    BreakpointLocation::FrameScript {
+                        clip: clip.to_string(),
+                        frame,
+                    }
+                } else {
+                    return Err("A breakpoint needs either an offset or a clip".to_string());
+                };
+                Command::SetBreakpoint(location)
+            }
+            "remove_breakpoint" => {
+                let id = request
+                    .get("id")
+                    .and_then(JsonValue::as_u64)
+                    .ok_or("Missing or invalid id")?;
+                Command::RemoveBreakpoint(id as u32)
+            }
+            "list_breakpoints" => Command::ListBreakpoints,
+            "detach" => Command::Detach,
+            "pause" => Command::Pause,
+            "continue" => Command::Continue,
+            "step_in" => Command::StepIn,
+            "step_over" => Command::StepOver,
+            "step_out" => Command::StepOut,
+            "call_stack" => Command::CallStack,
+            "registers" => Command::Registers,
+            "constant_pool" => Command::ConstantPool,
+            "scopes" => Command::Scopes,
+            "operands" => Command::Operands,
+            "evaluate" => {
+                let expression = request
+                    .get("expression")
+                    .and_then(JsonValue::as_str)
+                    .ok_or("Missing or invalid expression")?;
+                Command::Evaluate(expression.to_string())
+            }
+            _ => return Err(format!("Unknown command {name}")),
+        };
+
+        Ok(command)
+    }
+}
+
+/// The state of an attached AVM1 debugger.
+pub struct Debugger {
+    /// The connection to the client, while one is attached.
+    connection: Option<DebuggerConnection>,
+
+    breakpoints: Vec<Breakpoint>,
+
+    next_breakpoint_id: u32,
+
+    /// The pending step request, if the client asked to step.
+    step: Option<StepMode>,
+
+    /// Whether the client asked to pause as soon as possible.
+    pause_requested: bool,
+
+    /// The frame script breakpoint hit by the timeline script about to run.
+    frame_script_breakpoint: Option<u32>,
+
+    /// Whether the AVM1 debugger window of the debug UI is open.
+    window_open: bool,
+
+    /// The watch expressions of the debug UI.
+    watches: Vec<String>,
+
+    /// Whether the debug UI asked to break on the next action.
+    break_requested: bool,
+
+    /// The break being recorded for the debug UI, if any.
+    recording: Option<Break>,
+
+    /// How many `Activation::run_actions` calls are in progress.
+    nesting: u32,
+
+    /// The last finished recording, until the debug UI continues.
+    current_break: Option<Break>,
+}
+
+impl Default for Debugger {
+    fn default() -> Self {
+        Self {
+            connection: None,
+            breakpoints: Vec::new(),
+            next_breakpoint_id: 1,
+            step: None,
+            pause_requested: false,
+            frame_script_breakpoint: None,
+            window_open: false,
+            watches: Vec::new(),
+            break_requested: false,
+            recording: None,
+            nesting: 0,
+            current_break: None,
+        }
+    }
+}
+
+impl Debugger {
+    pub fn new(connection: DebuggerConnection) -> Self {
+        Self {
+            connection: Some(connection),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the client connection is still open or the debug UI window is
+    /// still showing. Once neither is, the debugger should be dropped.
+    pub fn is_attached(&self) -> bool {
+        self.connection.is_some() || self.window_open
+    }
+
+    /// Process all commands the client sent while the movie was running.
+    pub fn poll(&mut self) {
+        loop {
+            let Some(connection) = &self.connection else {
+                break;
+            };
+
+            let line = match connection.commands.try_recv() {
+                Ok(line) => line,
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.disconnect();
+                    break;
+                }
+            };
+
+            match Command::parse(&line) {
+                Ok(Command::Pause) => self.pause_requested = true,
+                Ok(Command::Detach) => self.detach(),
+                Ok(command) => {
+                    if !self.handle_breakpoint_command(command) {
+                        self.send_error("Command is only available while paused");
+                    }
+                }
+                Err(message) => self.send_error(&message),
+            }
+        }
+    }
+
+    /// Called before a timeline script of `clip` starts running, so that the
+    /// first action of the script pauses if there's a breakpoint on it.
+    pub fn on_timeline_script(&mut self, clip: DisplayObject<'_>) {
+        // Breakpoints may have been set since any AVM1 code last ran.
+        self.poll();
+
+        let mut path = None;
+        let current_frame = clip.as_movie_clip().map(|clip| clip.current_frame());
+
+        self.frame_script_breakpoint = self
+            .breakpoints
+            .iter()
+            .find(|breakpoint| {
+                let BreakpointLocation::FrameScript {
+                    clip: target,
+                    frame,
+                } = &breakpoint.location
+                else {
+                    return false;
+                };
+
+                let path = path.get_or_insert_with(|| clip.path().to_utf8_lossy().into_owned());
+                target.eq_ignore_ascii_case(path)
+                    && frame.is_none_or(|frame| Some(frame) == current_frame)
+            })
+            .map(|breakpoint| breakpoint.id);
+    }
+
+    /// Called before the action at `offset` into the data of the movie with
+    /// the URL `movie` is executed. If execution should stop there for the
+    /// client, this blocks until the client resumes execution. If the debug
+    /// UI is recording a break, the state of the VM is recorded.
+    pub fn on_action<'gc>(
+        &mut self,
+        activation: &mut Activation<'_, 'gc>,
+        movie: &str,
+        offset: usize,
+        action: impl FnOnce() -> String,
+    ) {
+        let depth = activation.id.depth();
+
+        let breakpoint = self
+            .frame_script_breakpoint
+            .take()
+            .or_else(|| self.find_breakpoint(movie, offset));
+
+        let reason = if self.connection.is_none() {
+            None
+        } else if let Some(id) = breakpoint {
+            Some(PauseReason::Breakpoint(id))
+        } else if self.pause_requested {
+            Some(PauseReason::Pause)
+        } else if self.step_finished(depth) {
+            Some(PauseReason::Step)
+        } else {
+            None
+        };
+
+        if self.can_start_recording() {
+            if let (Some(id), None) = (breakpoint, reason) {
+                self.start_recording(self.describe_breakpoint(id));
+            } else if self.break_requested {
+                self.start_recording("Paused".to_string());
+            }
+        }
+
+        let record = match &mut self.recording {
+            Some(recording) if recording.snapshots.len() >= MAX_SNAPSHOTS => {
+                recording.truncated = true;
+                false
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        if reason.is_none() && !record {
+            return;
+        }
+
+        let mut action = action();
+        if let Some((index, _)) = action.char_indices().nth(MAX_ACTION_LENGTH) {
+            action.truncate(index);
+            action.push('…');
+        }
+
+        if record {
+            let snapshot = Snapshot::capture(activation, movie, offset, &action, &self.watches);
+            if let Some(recording) = &mut self.recording {
+                recording.snapshots.push(snapshot);
+            }
+        }
+
+        if let Some(reason) = reason {
+            self.pause(activation, movie, offset, &action, reason);
+        }
+    }
+
+    pub(crate) fn enter_actions(&mut self) {
+        self.nesting += 1;
+    }
+
+    pub(crate) fn exit_actions(&mut self) {
+        self.nesting = self.nesting.saturating_sub(1);
+        if self.nesting == 0 && self.recording.is_some() {
+            self.current_break = self.recording.take();
+        }
+    }
+
+    /// Called when the AVM1 debugger window of the debug UI opens.
+    pub fn open_window(&mut self) {
+        self.window_open = true;
+    }
+
+    /// Called when the AVM1 debugger window of the debug UI closes. Its
+    /// breakpoints are removed, and any break it's looking at is forgotten.
+    pub fn close_window(&mut self) {
+        self.window_open = false;
+        self.breakpoints.retain(|breakpoint| breakpoint.remote);
+        self.watches.clear();
+        self.break_requested = false;
+        self.recording = None;
+        self.current_break = None;
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    /// Add a breakpoint on behalf of the debug UI.
+    pub fn add_breakpoint(&mut self, location: BreakpointLocation) {
+        let id = self.next_breakpoint_id;
+        self.next_breakpoint_id += 1;
+        self.breakpoints.push(Breakpoint {
+            id,
+            location,
+            remote: false,
+        });
+    }
+
+    pub fn remove_breakpoint(&mut self, id: u32) {
+        self.breakpoints.retain(|breakpoint| breakpoint.id != id);
+    }
+
+    pub fn watches(&self) -> &[String] {
+        &self.watches
+    }
+
+    pub fn add_watch(&mut self, expression: String) {
+        self.watches.push(expression);
+    }
+
+    pub fn remove_watch(&mut self, index: usize) {
+        if index < self.watches.len() {
+            self.watches.remove(index);
+        }
+    }
+
+    /// Break for the debug UI on the next action that runs.
+    pub fn request_break(&mut self) {
+        self.break_requested = true;
+    }
+
+    pub fn is_break_requested(&self) -> bool {
+        self.break_requested
+    }
+
+    /// The break the debug UI is looking at, once its recording is complete.
+    pub fn current_break(&self) -> Option<&Break> {
+        self.current_break.as_ref()
+    }
+
+    /// Forget about the current break, so that the player can carry on.
+    pub fn resume(&mut self) {
+        self.current_break = None;
+    }
+
+    fn can_start_recording(&self) -> bool {
+        self.window_open && self.recording.is_none() && self.current_break.is_none()
+    }
+
+    fn start_recording(&mut self, reason: String) {
+        self.break_requested = false;
+        self.recording = Some(Break {
+            reason,
+            snapshots: Vec::new(),
+            truncated: false,
+        });
+    }
+
+    fn describe_breakpoint(&self, id: u32) -> String {
+        match self
+            .breakpoints
+            .iter()
+            .find(|breakpoint| breakpoint.id == id)
+        {
+            Some(breakpoint) => format!("Breakpoint {id}: {}", breakpoint.location),
+            None => format!("Breakpoint {id}"),
+        }
+    }
+
+    fn step_finished(&self, depth: u16) -> bool {
+        match self.step {
+            None => false,
+            Some(StepMode::Into) => true,
+            Some(StepMode::Over(step_depth)) => depth <= step_depth,
+            Some(StepMode::Out(step_depth)) => depth < step_depth,
+        }
+    }
+
+    fn find_breakpoint(&self, movie: &str, offset: usize) -> Option<u32> {
+        self.breakpoints
+            .iter()
+            .find(|breakpoint| {
+                matches!(
+                    &breakpoint.location,
+                    BreakpointLocation::Action { movie: url, offset: o }
+                        if *o == offset && url.as_deref().is_none_or(|url| url == movie)
+                )
+            })
+            .map(|breakpoint| breakpoint.id)
+    }
+
+    fn pause<'gc>(
+        &mut self,
+        activation: &mut Activation<'_, 'gc>,
+        movie: &str,
+        offset: usize,
+        action: &str,
+        reason: PauseReason,
+    ) {
+        self.step = None;
+        self.pause_requested = false;
+
+        let depth = activation.id.depth();
+
+        let mut event = JsonObject::new();
+        event.insert("event".to_string(), "paused".into());
+        match reason {
+            PauseReason::Breakpoint(id) => {
+                event.insert("reason".to_string(), "breakpoint".into());
+                event.insert("id".to_string(), id.into());
+            }
+            PauseReason::Step => {
+                event.insert("reason".to_string(), "step".into());
+            }
+            PauseReason::Pause => {
+                event.insert("reason".to_string(), "pause".into());
+            }
+        }
+        event.insert("activation".to_string(), activation.id.to_string().into());
+        event.insert("movie".to_string(), movie.into());
+        event.insert("offset".to_string(), offset.into());
+        event.insert("action".to_string(), action.into());
+        self.send(JsonValue::Object(event));
+
+        loop {
+            let Some(Ok(line)) = self
+                .connection
+                .as_ref()
+                .map(|connection| connection.commands.recv())
+            else {
+                self.disconnect();
+                break;
+            };
+
+            let command = match Command::parse(&line) {
+                Ok(command) => command,
+                Err(message) => {
+                    self.send_error(&message);
+                    continue;
+                }
+            };
+
+            match command {
+                Command::Continue => break,
+                Command::Detach => {
+                    self.detach();
+                    break;
+                }
+                Command::StepIn => {
+                    self.step = Some(StepMode::Into);
+                    break;
+                }
+                Command::StepOver => {
+                    self.step = Some(StepMode::Over(depth));
+                    break;
+                }
+                Command::StepOut => {
+                    self.step = Some(StepMode::Out(depth));
+                    break;
+                }
+                Command::Pause => self.send_error("Already paused"),
+                Command::CallStack => {
+                    let frames: Vec<_> = call_stack(&activation.id)
+                        .into_iter()
+                        .map(JsonValue::from)
+                        .collect();
+                    self.send(json!({ "event": "call_stack", "frames": frames }));
+                }
+                Command::Registers => {
+                    let registers: Vec<_> = activation
+                        .debug_registers()
+                        .into_iter()
+                        .map(describe_value)
+                        .collect();
+                    self.send(json!({ "event": "registers", "registers": registers }));
+                }
+                Command::ConstantPool => {
+                    let constants: Vec<_> = activation
+                        .constant_pool()
+                        .iter()
+                        .map(|value| describe_value(*value))
+                        .collect();
+                    self.send(json!({ "event": "constant_pool", "constants": constants }));
+                }
+                Command::Scopes => {
+                    let scopes: Vec<_> = Scope::ancestors(activation.scope()).collect();
+                    let scopes: Vec<_> = scopes
+                        .into_iter()
+                        .map(|scope| {
+                            json!({
+                                "kind": format!("{:?}", scope.class()),
+                                "variables": stored_variables(*scope.locals(), activation),
+                            })
+                        })
+                        .collect();
+                    self.send(json!({ "event": "scopes", "scopes": scopes }));
+                }
+                Command::Operands => {
+                    let operands: Vec<_> = activation
+                        .context
+                        .avm1
+                        .stack()
+                        .iter()
+                        .map(|value| describe_value(*value))
+                        .collect();
+                    self.send(json!({ "event": "operands", "operands": operands }));
+                }
+                Command::Evaluate(expression) => {
+                    let name = AvmString::new_utf8(activation.gc(), &expression);
+                    match activation.get_variable(name) {
+                        Ok(value) => {
+                            let value = describe_value(value.into());
+                            self.send(json!({
+                                "event": "value",
+                                "expression": expression,
+                                "value": value,
+                            }));
+                        }
+                        Err(e) => self.send_error(&format!("Couldn't evaluate {expression}: {e}")),
+                    }
+                }
+                command => {
+                    self.handle_breakpoint_command(command);
+                }
+            }
+        }
+
+        // Don't count the time spent paused against the script timeout.
+        activation.context.update_start = Instant::now();
+
+        self.send(json!({ "event": "resumed" }));
+    }
+
+    /// End the session of the current client, so that the next one starts
+    /// afresh. Breakpoints of the debug UI are kept.
+    fn detach(&mut self) {
+        self.breakpoints.retain(|breakpoint| !breakpoint.remote);
+        if self.breakpoints.is_empty() {
+            self.next_breakpoint_id = 1;
+        }
+        self.step = None;
+        self.pause_requested = false;
+        if let Some(id) = self.frame_script_breakpoint
+            && !self
+                .breakpoints
+                .iter()
+                .any(|breakpoint| breakpoint.id == id)
+        {
+            self.frame_script_breakpoint = None;
+        }
+    }
+
+    /// Forget about the client after its connection closed.
+    fn disconnect(&mut self) {
+        self.connection = None;
+        self.detach();
+    }
+
+    /// Handle the commands that are available regardless of whether we're
+    /// paused. Returns `false` if the command wasn't one of those.
+    fn handle_breakpoint_command(&mut self, command: Command) -> bool {
+        match command {
+            Command::SetBreakpoint(location) => {
+                let id = self.next_breakpoint_id;
+                self.next_breakpoint_id += 1;
+                self.breakpoints.push(Breakpoint {
+                    id,
+                    location,
+                    remote: true,
+                });
+                self.send(json!({ "event": "breakpoint_set", "id": id }));
+            }
+            Command::RemoveBreakpoint(id) => {
+                let len = self.breakpoints.len();
+                self.breakpoints.retain(|breakpoint| breakpoint.id != id);
+                if self.breakpoints.len() == len {
+                    self.send_error(&format!("No breakpoint with id {id}"));
+                } else {
+                    self.send(json!({ "event": "breakpoint_removed", "id": id }));
+                }
+            }
+            Command::ListBreakpoints => {
+                let breakpoints: Vec<_> = self
+                    .breakpoints
+                    .iter()
+                    .map(|breakpoint| breakpoint.location.to_json(breakpoint.id))
+                    .collect();
+                self.send(json!({ "event": "breakpoints", "breakpoints": breakpoints }));
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn send(&mut self, event: JsonValue) {
+        if let Some(connection) = &self.connection
+            && connection.events.send(event.to_string()).is_err()
+        {
+            self.disconnect();
+        }
+    }
+
+    fn send_error(&mut self, message: &str) {
+        self.send(json!({ "event": "error", "message": message }));
+    }
+}
+
+impl Snapshot {
+    fn capture<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        movie: &str,
+        offset: usize,
+        action: &str,
+        watches: &[String],
+    ) -> Self {
+        let operands = activation
+            .context
+            .avm1
+            .stack()
+            .iter()
+            .rev()
+            .map(|value| describe(*value))
+            .collect();
+        let registers = activation
+            .debug_registers()
+            .into_iter()
+            .map(describe)
+            .collect();
+        let constant_pool = activation
+            .constant_pool()
+            .iter()
+            .map(|value| describe(*value))
+            .collect();
+
+        let scopes: Vec<_> = Scope::ancestors(activation.scope()).collect();
+        let scopes = scopes
+            .into_iter()
+            .map(|scope| ScopeSnapshot {
+                kind: format!("{:?}", scope.class()),
+                variables: stored_variables(*scope.locals(), activation)
+                    .into_iter()
+                    .map(|(name, value)| (name, describe_json(&value)))
+                    .collect(),
+            })
+            .collect();
+
+        let watches = watches
+            .iter()
+            .map(|expression| {
+                let name = AvmString::new_utf8(activation.gc(), expression);
+                let value = match activation.get_variable(name) {
+                    Ok(value) => describe(value.into()),
+                    Err(e) => format!("Error: {e}"),
+                };
+                (expression.clone(), value)
+            })
+            .collect();
+
+        Self {
+            activation: activation.id.to_string(),
+            call_stack: call_stack(&activation.id),
+            depth: activation.id.depth(),
+            movie: movie.to_string(),
+            offset,
+            action: action.to_string(),
+            operands,
+            registers,
+            constant_pool,
+            scopes,
+            watches,
+        }
+    }
+}
+
+/// The names of `id` and the activations it was called from, innermost
+/// first.
+fn call_stack(id: &ActivationIdentifier<'_>) -> Vec<String> {
+    let mut frames = Vec::new();
+    let mut current = Some(id);
+    while let Some(id) = current {
+        frames.push(id.name().to_string());
+        current = id.parent();
+    }
+    frames
+}
+
+/// The enumerable properties stored directly on `object`, skipping anything
+/// that would need a getter to run.
+fn stored_variables<'gc>(
+    object: Object<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> JsonObject<String, JsonValue> {
+    object
+        .get_keys(activation, false)
+        .into_iter()
+        .filter_map(|key| {
+            let value = object.get_local_stored(key, activation)?;
+            Some((key.to_utf8_lossy().into_owned(), describe_value(value)))
+        })
+        .collect()
+}
+
+/// Describe a value for the client, without running any ActionScript.
+fn describe_value(value: Value<'_>) -> JsonValue {
+    match value {
+        Value::Undefined => json!({ "type": "undefined" }),
+        Value::Null => json!({ "type": "null" }),
+        Value::Bool(value) => json!({ "type": "Boolean", "value": value.to_string() }),
+        Value::Number(value) => json!({ "type": "Number", "value": value.to_string() }),
+        Value::String(value) => json!({ "type": "String", "value": value.to_utf8_lossy() }),
+        Value::Object(object) => {
+            if let Some(display_object) = object.native().as_display_object() {
+                json!({
+                    "type": "DisplayObject",
+                    "value": display_object.path().to_utf8_lossy(),
+                })
+            } else if object.as_function().is_some() {
+                json!({ "type": "Function", "value": format!("{:p}", object.as_ptr()) })
+            } else {
+                json!({ "type": "Object", "value": format!("{:p}", object.as_ptr()) })
+            }
+        }
+        Value::MovieClip(reference) => json!({
+            "type": "DisplayObject",
+            "value": reference.path().to_utf8_lossy(),
+        }),
+    }
+}
+
+/// A short description of a value for the debug UI.
+fn describe(value: Value<'_>) -> String {
+    describe_json(&describe_value(value))
+}
+
+/// Turn a value described for the client into a short description for the
+/// debug UI.
+fn describe_json(description: &JsonValue) -> String {
+    let kind = description["type"].as_str().unwrap_or_default();
+    match description.get("value").and_then(JsonValue::as_str) {
+        None => kind.to_string(),
+        Some(value) if kind == "String" => format!("{value:?}"),
+        Some(value) if kind == "Function" || kind == "Object" => format!("[{kind} {value}]"),
+        Some(value) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(depths: &[u16]) -> Break {
+        let snapshots = depths
+            .iter()
+            .map(|&depth| Snapshot {
+                activation: String::new(),
+                call_stack: Vec::new(),
+                depth,
+                movie: String::new(),
+                offset: 0,
+                action: String::new(),
+                operands: Vec::new(),
+                registers: Vec::new(),
+                constant_pool: Vec::new(),
+                scopes: Vec::new(),
+                watches: Vec::new(),
+            })
+            .collect();
+        Break {
+            reason: String::new(),
+            snapshots,
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn parse_action_breakpoint() {
+        let Ok(Command::SetBreakpoint(location)) =
+            Command::parse(r#"{"command":"set_breakpoint","offset":42}"#)
+        else {
+            panic!("Expected a breakpoint");
+        };
+        assert_eq!(
+            location,
+            BreakpointLocation::Action {
+                movie: None,
+                offset: 42
+            }
+        );
+
+        let Ok(Command::SetBreakpoint(location)) =
+            Command::parse(r#"{"command":"set_breakpoint","movie":"file:///test.swf","offset":7}"#)
+        else {
+            panic!("Expected a breakpoint");
+        };
+        assert_eq!(
+            location,
+            BreakpointLocation::Action {
+                movie: Some("file:///test.swf".to_string()),
+                offset: 7
+            }
+        );
+    }
+
+    #[test]
+    fn parse_frame_script_breakpoint() {
+        let Ok(Command::SetBreakpoint(location)) =
+            Command::parse(r#"{"command":"set_breakpoint","clip":"_level0.menu","frame":3}"#)
+        else {
+            panic!("Expected a breakpoint");
+        };
+        assert_eq!(
+            location,
+            BreakpointLocation::FrameScript {
+                clip: "_level0.menu".to_string(),
+                frame: Some(3)
+            }
+        );
+
+        assert!(
+            Command::parse(r#"{"command":"set_breakpoint","clip":"_level0","frame":-1}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_commands() {
+        assert!(Command::parse("not json").is_err());
+        assert!(Command::parse(r#"{"command":"jump"}"#).is_err());
+        assert!(Command::parse(r#"{"command":"set_breakpoint"}"#).is_err());
+        assert!(Command::parse(r#"{"command":"evaluate"}"#).is_err());
+    }
+
+    #[test]
+    fn stepping_through_recording() {
+        let recording = recording(&[0, 1, 2, 2, 1, 0]);
+        assert_eq!(recording.step_in(0), Some(1));
+        assert_eq!(recording.step_over(0), Some(5));
+        assert_eq!(recording.step_over(1), Some(4));
+        assert_eq!(recording.step_out(2), Some(4));
+        assert_eq!(recording.step_out(0), None);
+        assert_eq!(recording.step_in(5), None);
+    }
+
+    #[test]
+    fn detach_keeps_window_breakpoints() {
+        let (command_sender, commands) = std::sync::mpsc::channel();
+        let (events, _event_receiver) = std::sync::mpsc::channel();
+        let mut debugger = Debugger::new(DebuggerConnection { commands, events });
+        debugger.open_window();
+        debugger.add_breakpoint(BreakpointLocation::FrameScript {
+            clip: "_level0".to_string(),
+            frame: None,
+        });
+
+        for command in [
+            r#"{"command":"set_breakpoint","offset":42}"#,
+            r#"{"command":"detach"}"#,
+        ] {
+            command_sender.send(command.to_string()).unwrap();
+        }
+        debugger.poll();
+
+        let ids: Vec<_> = debugger.breakpoints().iter().map(Breakpoint::id).collect();
+        assert_eq!(ids, [1]);
+
+        drop(command_sender);
+        debugger.poll();
+        assert!(debugger.is_attached());
+        debugger.close_window();
+        assert!(!debugger.is_attached());
+        assert!(debugger.breakpoints().is_empty());
+    }
+}
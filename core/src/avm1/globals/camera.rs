@@ -3,8 +3,11 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::property_decl::{DeclContext, StaticDeclarations, SystemClass};
-use crate::avm1::{Object, Value};
+use crate::avm1::{ArrayBuilder, NativeObject, Object, Value};
 use crate::avm1_stub;
+use crate::backend::camera::CameraMode;
+use crate::camera::Cameras;
+use crate::string::AvmString;
 
 const PROTO_DECLS: StaticDeclarations = declare_static_properties! {
     use fn method;
@@ -14,6 +17,16 @@ const PROTO_DECLS: StaticDeclarations = declare_static_properties! {
     "setMotionLevel" => method(SET_MOTION_LEVEL; DONT_ENUM | DONT_DELETE);
     "setLoopback" => method(SET_LOOPBACK; DONT_ENUM | DONT_DELETE);
     "setCursor" => method(SET_CURSOR; DONT_ENUM | DONT_DELETE);
+    "activityLevel" => property(GET_ACTIVITY_LEVEL; DONT_ENUM | DONT_DELETE);
+    "currentFps" => property(GET_CURRENT_FPS; DONT_ENUM | DONT_DELETE);
+    "fps" => property(GET_FPS; DONT_ENUM | DONT_DELETE);
+    "height" => property(GET_HEIGHT; DONT_ENUM | DONT_DELETE);
+    "index" => property(GET_INDEX; DONT_ENUM | DONT_DELETE);
+    "motionLevel" => property(GET_MOTION_LEVEL; DONT_ENUM | DONT_DELETE);
+    "motionTimeout" => property(GET_MOTION_TIMEOUT; DONT_ENUM | DONT_DELETE);
+    "muted" => property(GET_MUTED; DONT_ENUM | DONT_DELETE);
+    "name" => property(GET_NAME; DONT_ENUM | DONT_DELETE);
+    "width" => property(GET_WIDTH; DONT_ENUM | DONT_DELETE);
 };
 
 const OBJECT_DECLS: StaticDeclarations = declare_static_properties! {
@@ -41,40 +54,132 @@ pub mod method {
     pub const SET_LOOPBACK: u16 = 4;
     pub const SET_CURSOR: u16 = 5;
 
+    pub const GET_ACTIVITY_LEVEL: u16 = 100;
+    pub const GET_CURRENT_FPS: u16 = 101;
+    pub const GET_FPS: u16 = 102;
+    pub const GET_HEIGHT: u16 = 103;
+    pub const GET_INDEX: u16 = 104;
+    pub const GET_MOTION_LEVEL: u16 = 105;
+    pub const GET_MOTION_TIMEOUT: u16 = 106;
+    pub const GET_MUTED: u16 = 107;
+    pub const GET_NAME: u16 = 108;
+    pub const GET_WIDTH: u16 = 109;
+
     pub const INTERNAL_GET: u16 = 200;
     pub const GET_NAMES: u16 = 201;
 }
 
 pub fn method<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
     index: u16,
 ) -> Result<Value<'gc>, Error<'gc>> {
     use method::*;
     const CNAME: &str = "Camera";
 
     match index {
-        INTERNAL_GET => {
-            avm1_stub!(activation, CNAME, "get");
-            // Camera.get() returns null when there's no camera.
-            return Ok(Value::Null);
-        }
+        INTERNAL_GET => return get(activation, this, args),
         GET_NAMES => {
-            avm1_stub!(activation, CNAME, "names");
-            return activation
-                .prototypes()
-                .array_constructor
-                .construct(activation, &[]);
+            let names = activation.context.camera.names();
+            let names = names
+                .iter()
+                .map(|name| AvmString::new_utf8(activation.gc(), name).into());
+            return Ok(ArrayBuilder::new(activation).with(names).into());
         }
-        SET_MODE => avm1_stub!(activation, CNAME, "setMode"),
         SET_QUALITY => avm1_stub!(activation, CNAME, "setQuality"),
         SET_KEY_FRAME_INTERVAL => avm1_stub!(activation, CNAME, "setKeyFrameInterval"),
-        SET_MOTION_LEVEL => avm1_stub!(activation, CNAME, "setMotionLevel"),
         SET_LOOPBACK => avm1_stub!(activation, CNAME, "setLoopback"),
         SET_CURSOR => avm1_stub!(activation, CNAME, "setCursor"),
         _ => (),
     }
 
+    let NativeObject::Camera(camera) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+
+    match index {
+        SET_MODE => {
+            let width = arg(args, 0).coerce_to_i32(activation)?;
+            let height = arg(args, 1).coerce_to_i32(activation)?;
+            let fps = arg(args, 2).coerce_to_f64(activation)?;
+
+            let mut settings = camera.settings_mut();
+            settings.mode = CameraMode {
+                width: width.max(1) as u32,
+                height: height.max(1) as u32,
+                fps: if fps.is_nan() || fps <= 0.0 {
+                    settings.mode.fps
+                } else {
+                    fps
+                },
+            };
+        }
+        SET_MOTION_LEVEL => {
+            let motion_level = arg(args, 0).coerce_to_i32(activation)?;
+            let timeout = match args.get(1) {
+                None | Some(Value::Undefined) => None,
+                Some(timeout) => Some(timeout.coerce_to_i32(activation)?),
+            };
+
+            let mut settings = camera.settings_mut();
+            settings.motion_level = motion_level.clamp(0, 100);
+            if let Some(timeout) = timeout {
+                settings.motion_timeout = timeout.max(0);
+            }
+        }
+        GET_ACTIVITY_LEVEL => return Ok(camera.activity_level().into()),
+        GET_CURRENT_FPS => return Ok(camera.current_fps().into()),
+        GET_FPS => return Ok(camera.mode().fps.into()),
+        GET_HEIGHT => return Ok(camera.mode().height.into()),
+        GET_INDEX => return Ok((camera.index() as f64).into()),
+        GET_MOTION_LEVEL => return Ok(camera.settings().motion_level.into()),
+        GET_MOTION_TIMEOUT => return Ok(camera.settings().motion_timeout.into()),
+        GET_MUTED => return Ok(camera.is_muted().into()),
+        GET_NAME => {
+            let names = activation.context.camera.names();
+            let name = names
+                .get(camera.index())
+                .map(String::as_str)
+                .unwrap_or_default();
+            return Ok(AvmString::new_utf8(activation.gc(), name).into());
+        }
+        GET_WIDTH => return Ok(camera.mode().width.into()),
+        _ => (),
+    }
+
     Ok(Value::Undefined)
 }
+
+fn arg<'a, 'gc>(args: &'a [Value<'gc>], index: usize) -> &'a Value<'gc> {
+    args.get(index).unwrap_or(&Value::Undefined)
+}
+
+fn get<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let index = match args.first() {
+        None | Some(Value::Undefined) | Some(Value::Null) => -1,
+        Some(index) => index.coerce_to_i32(activation)?,
+    };
+
+    // Camera.get() returns null when there's no camera.
+    let Some(index) = Cameras::device_index(activation.context.camera, index) else {
+        return Ok(Value::Null);
+    };
+
+    let camera = activation.context.cameras.get(activation.gc(), index);
+    if let Some(object) = camera.avm1_object() {
+        return Ok(object.into());
+    }
+
+    let object = this
+        .construct(activation, &[])?
+        .coerce_to_object_or_bare(activation)?;
+    object.set_native(activation.gc(), NativeObject::Camera(camera));
+    camera.set_avm1_object(activation.gc(), object);
+
+    Ok(object.into())
+}
@@ -3,8 +3,10 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::property_decl::{DeclContext, StaticDeclarations, SystemClass};
-use crate::avm1::{Object, Value};
+use crate::avm1::{ArrayBuilder, NativeObject, Object, Value};
 use crate::avm1_stub;
+use crate::microphone::Microphones;
+use crate::string::AvmString;
 
 const PROTO_DECLS: StaticDeclarations = declare_static_properties! {
     "setSilenceLevel" => method(set_silence_level; DONT_ENUM | DONT_DELETE);
@@ -14,6 +16,15 @@ const PROTO_DECLS: StaticDeclarations = declare_static_properties! {
     "setCodec" => method(set_codec; DONT_ENUM | DONT_DELETE);
     "setFramesPerPacket" => method(set_frames_per_packet; DONT_ENUM | DONT_DELETE);
     "setEncodeQuality" => method(set_encode_quality; DONT_ENUM | DONT_DELETE);
+    "activityLevel" => property(get_activity_level; DONT_ENUM | DONT_DELETE);
+    "gain" => property(get_gain; DONT_ENUM | DONT_DELETE);
+    "index" => property(get_index; DONT_ENUM | DONT_DELETE);
+    "muted" => property(get_muted; DONT_ENUM | DONT_DELETE);
+    "name" => property(get_name; DONT_ENUM | DONT_DELETE);
+    "rate" => property(get_rate; DONT_ENUM | DONT_DELETE);
+    "silenceLevel" => property(get_silence_level; DONT_ENUM | DONT_DELETE);
+    "silenceTimeout" => property(get_silence_timeout; DONT_ENUM | DONT_DELETE);
+    "useEchoSuppression" => property(get_use_echo_suppression; DONT_ENUM | DONT_DELETE);
 };
 
 const OBJECT_DECLS: StaticDeclarations = declare_static_properties! {
@@ -30,14 +41,44 @@ pub fn create_class<'gc>(
     context.define_properties_on(class.constr, OBJECT_DECLS(context));
     class
 }
+
+/// The device index of a `Microphone` object, if it is one.
+fn device_index(this: Object<'_>) -> Option<usize> {
+    match this.native() {
+        NativeObject::Microphone(index) => Some(index),
+        _ => None,
+    }
+}
+
 fn get<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "Microphone", "get");
+    let index = match args.first() {
+        None | Some(Value::Undefined) => -1,
+        Some(index) => index.coerce_to_i32(activation)?,
+    };
+
     // Microphone.get() returns null when there's no microphone.
-    Ok(Value::Null)
+    let Some(index) = Microphones::device_index(activation.context.microphone, index) else {
+        return Ok(Value::Null);
+    };
+
+    if let Some(microphone) = activation.context.microphones.avm1_object(index) {
+        return Ok(microphone.into());
+    }
+
+    let microphone = this
+        .construct(activation, &[])?
+        .coerce_to_object_or_bare(activation)?;
+    microphone.set_native(activation.gc(), NativeObject::Microphone(index));
+    activation
+        .context
+        .microphones
+        .set_avm1_object(index, microphone);
+
+    Ok(microphone.into())
 }
 
 fn get_names<'gc>(
@@ -45,46 +86,104 @@ fn get_names<'gc>(
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "Microphone", "names");
-    activation
-        .prototypes()
-        .array_constructor
-        .construct(activation, &[])
+    let names = activation.context.microphone.names();
+    let names = names
+        .iter()
+        .map(|name| AvmString::new_utf8(activation.gc(), name).into());
+
+    Ok(ArrayBuilder::new(activation).with(names).into())
 }
 
 fn set_silence_level<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "Microphone", "setSilenceLevel");
+    let Some(index) = device_index(this) else {
+        return Ok(Value::Undefined);
+    };
+
+    let silence_level = args
+        .first()
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_f64(activation)?;
+    let timeout = match args.get(1) {
+        None | Some(Value::Undefined) => None,
+        Some(timeout) => Some(timeout.coerce_to_i32(activation)?),
+    };
+
+    let settings = activation.context.microphones.settings_mut(index);
+    if !silence_level.is_nan() {
+        settings.silence_level = silence_level.clamp(0.0, 100.0);
+    }
+    if let Some(timeout) = timeout.filter(|timeout| *timeout >= 0) {
+        settings.silence_timeout = timeout;
+    }
+
     Ok(Value::Undefined)
 }
 
 fn set_rate<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "Microphone", "setRate");
+    let Some(index) = device_index(this) else {
+        return Ok(Value::Undefined);
+    };
+
+    let rate = args
+        .first()
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_i32(activation)?;
+    activation
+        .context
+        .microphones
+        .settings_mut(index)
+        .set_rate(rate);
+
     Ok(Value::Undefined)
 }
 
 fn set_gain<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "Microphone", "setGain");
+    let Some(index) = device_index(this) else {
+        return Ok(Value::Undefined);
+    };
+
+    let gain = args
+        .first()
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_f64(activation)?;
+    if !gain.is_nan() {
+        activation.context.microphones.settings_mut(index).gain = gain.clamp(0.0, 100.0);
+    }
+
     Ok(Value::Undefined)
 }
 
 fn set_use_echo_suppression<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "Microphone", "setUseEchoSuppression");
+    let Some(index) = device_index(this) else {
+        return Ok(Value::Undefined);
+    };
+
+    let use_echo_suppression = args
+        .first()
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+    activation
+        .context
+        .microphones
+        .settings_mut(index)
+        .use_echo_suppression = use_echo_suppression;
+
     Ok(Value::Undefined)
 }
 
@@ -114,3 +213,126 @@ fn set_encode_quality<'gc>(
     avm1_stub!(activation, "Microphone", "setEncodeQuality");
     Ok(Value::Undefined)
 }
+
+fn get_activity_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => activation.context.microphones.activity_level(index).into(),
+        None => Value::Undefined,
+    })
+}
+
+fn get_gain<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => activation.context.microphones.settings(index).gain.into(),
+        None => Value::Undefined,
+    })
+}
+
+fn get_index<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => (index as f64).into(),
+        None => Value::Undefined,
+    })
+}
+
+fn get_muted<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => {
+            let context = &activation.context;
+            context
+                .microphones
+                .is_muted(&*context.microphone, index)
+                .into()
+        }
+        None => Value::Undefined,
+    })
+}
+
+fn get_name<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(index) = device_index(this) else {
+        return Ok(Value::Undefined);
+    };
+
+    let names = activation.context.microphone.names();
+    let name = names.get(index).map(String::as_str).unwrap_or_default();
+    Ok(AvmString::new_utf8(activation.gc(), name).into())
+}
+
+fn get_rate<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => activation.context.microphones.settings(index).rate().into(),
+        None => Value::Undefined,
+    })
+}
+
+fn get_silence_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => activation
+            .context
+            .microphones
+            .settings(index)
+            .silence_level
+            .into(),
+        None => Value::Undefined,
+    })
+}
+
+fn get_silence_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => activation
+            .context
+            .microphones
+            .settings(index)
+            .silence_timeout
+            .into(),
+        None => Value::Undefined,
+    })
+}
+
+fn get_use_echo_suppression<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match device_index(this) {
+        Some(index) => activation
+            .context
+            .microphones
+            .settings(index)
+            .use_echo_suppression
+            .into(),
+        None => Value::Undefined,
+    })
+}
@@ -14,7 +14,7 @@ use crate::ecma_conversions::f64_to_wrapping_i32;
 use crate::prelude::*;
 use crate::string::AvmString;
 use crate::vminterface::Instantiator;
-use crate::{avm_error, avm_warn, avm1_stub};
+use crate::{avm_error, avm_warn};
 use ruffle_macros::istr;
 use ruffle_render::shape_utils::{DrawCommand, GradientType};
 use swf::{
@@ -198,7 +198,6 @@ fn scale_9_grid<'gc>(
     this: MovieClip<'gc>,
     activation: &mut Activation<'_, 'gc>,
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "MovieClip", "scale9Grid");
     let rect = this.scaling_grid();
     if rect.is_valid() {
         new_rectangle(activation, rect)
@@ -212,7 +211,6 @@ fn set_scale_9_grid<'gc>(
     activation: &mut Activation<'_, 'gc>,
     value: Value<'gc>,
 ) -> Result<(), Error<'gc>> {
-    avm1_stub!(activation, "MovieClip", "scale9Grid");
     if let Value::Object(object) = value {
         if let Some(rectangle) = object_to_rectangle(activation, object)? {
             this.set_scaling_grid(rectangle);
@@ -306,11 +304,19 @@ fn attach_audio<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let [Value::Object(netstream_obj), ..] = args {
-        if let NativeObject::NetStream(netstream) = netstream_obj.native() {
-            movie_clip.attach_audio(activation.context, Some(netstream));
+        match netstream_obj.native() {
+            NativeObject::NetStream(netstream) => {
+                movie_clip.attach_audio(activation.context, Some(netstream));
+            }
+            // Attached microphones are heard through the speakers.
+            NativeObject::Microphone(index) => {
+                activation.context.microphones.settings_mut(index).loop_back = true;
+            }
+            _ => {}
         }
     } else if let [Value::Bool(false), ..] = args {
         movie_clip.attach_audio(activation.context, None);
+        activation.context.microphones.stop_avm1_loop_back();
     }
 
     Ok(Value::Undefined)
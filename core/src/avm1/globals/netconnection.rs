@@ -49,6 +49,14 @@ impl<'gc> NetConnection<'gc> {
         context: &mut UpdateContext<'gc>,
         this: Object<'gc>,
         code: &'static str,
+    ) -> Result<(), Error<'gc>> {
+        Self::on_status_info(context, this, [("code", code), ("level", "status")])
+    }
+
+    pub fn on_status_info<'a>(
+        context: &mut UpdateContext<'gc>,
+        this: Object<'gc>,
+        info: impl IntoIterator<Item = (&'a str, &'a str)>,
     ) -> Result<(), Error<'gc>> {
         let Some(root_clip) = context.stage.root_clip() else {
             tracing::warn!("Ignored NetConnection callback as there's no root movie");
@@ -63,9 +71,11 @@ impl<'gc> NetConnection<'gc> {
         let event = constructor
             .construct(&mut activation, &[])?
             .coerce_to_object_or_bare(&mut activation)?;
-        let code = AvmString::new_utf8(activation.gc(), code);
-        event.set(istr!("code"), code.into(), &mut activation)?;
-        event.set(istr!("level"), istr!("status").into(), &mut activation)?;
+        for (key, value) in info {
+            let key = AvmString::new_utf8(activation.gc(), key);
+            let value = AvmString::new_utf8(activation.gc(), value);
+            event.set(key, value.into(), &mut activation)?;
+        }
         this.call_method(
             istr!("onStatus"),
             &[event.into()],
@@ -133,6 +143,42 @@ impl<'gc> NetConnection<'gc> {
         )?;
         Ok(())
     }
+
+    /// Call a method of this connection on behalf of an RTMP server,
+    /// returning what it returned.
+    pub fn call_client(
+        context: &mut UpdateContext<'gc>,
+        this: Object<'gc>,
+        name: &str,
+        arguments: &[Rc<AMFValue>],
+    ) -> Result<AMFValue, Error<'gc>> {
+        let Some(root_clip) = context.stage.root_clip() else {
+            tracing::warn!("Ignored NetConnection call as there's no root movie");
+            return Ok(AMFValue::Undefined);
+        };
+        let mut activation = Activation::from_nothing(
+            context,
+            ActivationIdentifier::root("[NetConnection call]"),
+            root_clip,
+        );
+        let reader = flash_lso::read::Reader::default();
+        let mut reference_cache = BTreeMap::default();
+        let arguments: Vec<_> = arguments
+            .iter()
+            .map(|argument| {
+                deserialize_value(
+                    &mut activation,
+                    argument,
+                    &reader.amf0_decoder,
+                    &mut reference_cache,
+                )
+            })
+            .collect();
+        let name = AvmString::new_utf8(activation.gc(), name);
+        let result =
+            this.call_method(name, &arguments, &mut activation, ExecutionReason::Special)?;
+        Ok(serialize(&mut activation, result))
+    }
 }
 
 pub fn constructor<'gc>(
@@ -335,12 +381,18 @@ fn connect<'gc>(
     {
         // HTTP(S) is for Flash Remoting, which is just POST requests to the URL.
         NetConnections::connect_to_flash_remoting(activation.context, this, url.to_string());
+    } else if url_lower.starts_with(WStr::from_units(b"rtmp://")) {
+        let arguments = args[1..]
+            .iter()
+            .map(|arg| Rc::new(serialize(activation, *arg)))
+            .collect();
+        NetConnections::connect_to_rtmp(activation.context, this, url.to_string(), arguments);
     } else {
         avm1_stub!(
             activation,
             "NetConnection",
             "connect",
-            "with non-null, non-http command"
+            "with non-null, non-http, non-rtmp command"
         );
     }
 
@@ -1,3 +1,4 @@
+use crate::avm1::globals::netconnection::NetConnection;
 use crate::avm1::property_decl::{DeclContext, StaticDeclarations, SystemClass};
 use crate::avm1::{Activation, Error, NativeObject, Object, Value};
 use crate::avm1_stub;
@@ -6,9 +7,14 @@ use crate::streams::NetStream;
 pub fn constructor<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let netstream = NetStream::new_avm1(activation.gc(), this);
+    let connection = args
+        .get(0)
+        .and_then(|connection| NetConnection::cast(*connection))
+        .and_then(|connection| connection.handle());
+    netstream.set_connection(connection);
     this.set_native(activation.gc(), NativeObject::NetStream(netstream));
 
     Ok(Value::Undefined)
@@ -51,14 +57,12 @@ pub fn create_class<'gc>(
 }
 
 fn get_buffer_length<'gc>(
-    activation: &mut Activation<'_, 'gc>,
+    _activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let NativeObject::NetStream(ns) = this.native() {
-        avm1_stub!(activation, "NetStream", "bufferLength");
-
-        return Ok(ns.buffer_time().into());
+        return Ok(ns.buffer_length().into());
     }
 
     Ok(Value::Undefined)
@@ -163,8 +167,6 @@ fn set_buffer_time<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let NativeObject::NetStream(ns) = this.native() {
-        avm1_stub!(activation, "NetStream", "setBufferTime");
-
         let buffer_time = args
             .get(0)
             .cloned()
@@ -256,10 +258,23 @@ fn attach_audio<'gc>(
 
 fn attach_video<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "NetStream", "attachVideo");
+    let NativeObject::NetStream(netstream) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+
+    let camera = match args.first() {
+        Some(Value::Object(camera)) => match camera.native() {
+            NativeObject::Camera(camera) => Some(camera),
+            _ => return Ok(Value::Undefined),
+        },
+        _ => None,
+    };
+
+    // TODO: Publish the video of the camera over RTMP.
+    netstream.attach_camera(activation.gc(), camera);
     Ok(Value::Undefined)
 }
 
@@ -2,9 +2,16 @@
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::parameters::ParametersExt;
 use crate::avm1::property_decl::{DeclContext, StaticDeclarations, SystemClass};
-use crate::avm1::{Object, Value};
-use crate::avm1_stub;
+use crate::avm1::{NativeObject, Object, Value};
+use crate::backend::print::PrintJobSettings;
+use crate::print_job;
+use crate::string::AvmString;
+use gc_arena::Gc;
+use ruffle_macros::istr;
+use std::cell::Cell;
+use swf::{Rectangle, Twips};
 
 const PROTO_DECLS: StaticDeclarations = declare_static_properties! {
     "start" => method(start; DONT_ENUM | DONT_DELETE | VERSION_7);
@@ -27,81 +34,190 @@ pub fn create_class<'gc>(
 }
 
 fn constructor<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(Value::Undefined)
+    this.set_native(
+        activation.gc(),
+        NativeObject::PrintJob(Gc::new(activation.gc(), Cell::new(None))),
+    );
+    Ok(this.into())
+}
+
+/// The settings of a print job, if it was started.
+fn settings(this: Object<'_>) -> Option<PrintJobSettings> {
+    match this.native() {
+        NativeObject::PrintJob(settings) => settings.get(),
+        _ => None,
+    }
 }
 
 fn start<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "start");
-    Ok(Value::Undefined)
+    let NativeObject::PrintJob(settings) = this.native() else {
+        return Ok(false.into());
+    };
+
+    let started = activation.context.print.start_job();
+    settings.set(started);
+    Ok(started.is_some().into())
 }
 
 fn add_page<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "addPage");
-    Ok(Value::Undefined)
+    let Some(settings) = settings(this) else {
+        return Ok(false.into());
+    };
+
+    let target = match args.get_value(0) {
+        Value::Number(level) => activation.get_level(level as i32),
+        target => {
+            let start = activation.target_clip_or_root();
+            activation.resolve_target_display_object(start, target, false)?
+        }
+    };
+    let Some(target) = target else {
+        return Ok(false.into());
+    };
+
+    let area = match args.try_get_object(activation, 1)? {
+        Some(print_area) => print_area_to_rectangle(activation, print_area)?,
+        None => None,
+    };
+    // Without a (valid) print area, the Stage area of the movie is printed.
+    let area = area.unwrap_or_else(|| {
+        let (width, height) = activation.context.stage.movie_size();
+        Rectangle {
+            x_min: Twips::ZERO,
+            y_min: Twips::ZERO,
+            x_max: Twips::from_pixels(width.into()),
+            y_max: Twips::from_pixels(height.into()),
+        }
+    });
+
+    let as_bitmap = match args.try_get_object(activation, 2)? {
+        Some(options) => options
+            .get(istr!("printAsBitmap"), activation)?
+            .as_bool(activation.swf_version()),
+        None => false,
+    };
+
+    let page_size = (settings.page_width, settings.page_height);
+    let frame = match args.get_optional(3) {
+        None | Some(Value::Undefined) => None,
+        Some(frame) => Some(frame.coerce_to_f64(activation)?),
+    };
+
+    // Clips can print another of their frames, which is only displayed for the page.
+    if let Some((clip, frame)) = target.as_movie_clip().zip(frame) {
+        if !(1.0..=clip.header_frames() as f64).contains(&frame) {
+            return Ok(false.into());
+        }
+        let mut printed = false;
+        print_job::for_each_frame(activation.context, clip, &[frame as u16], |context, _| {
+            printed = print_job::add_page(context, target, area, page_size, false, as_bitmap);
+        });
+        return Ok(printed.into());
+    }
+
+    Ok(print_job::add_page(
+        activation.context,
+        target,
+        area,
+        page_size,
+        false,
+        as_bitmap,
+    )
+    .into())
+}
+
+/// Reads the `xMin`, `xMax`, `yMin` and `yMax` pixel coordinates of a print area.
+fn print_area_to_rectangle<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    print_area: Object<'gc>,
+) -> Result<Option<Rectangle<Twips>>, Error<'gc>> {
+    let mut coordinates = [0.0; 4];
+    for (name, coordinate) in [istr!("xMin"), istr!("xMax"), istr!("yMin"), istr!("yMax")]
+        .into_iter()
+        .zip(&mut coordinates)
+    {
+        let value = print_area.get(name, activation)?;
+        if value == Value::Undefined {
+            return Ok(None);
+        }
+        *coordinate = value.coerce_to_f64(activation)?;
+    }
+
+    let [x_min, x_max, y_min, y_max] = coordinates.map(Twips::from_pixels);
+    if x_max <= x_min || y_max <= y_min {
+        return Ok(None);
+    }
+    Ok(Some(Rectangle {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+    }))
 }
 
 fn send<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "send");
+    if let NativeObject::PrintJob(settings) = this.native()
+        && settings.take().is_some()
+    {
+        activation.context.print.send();
+    }
     Ok(Value::Undefined)
 }
 
 fn paper_height<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "paperHeight");
-    Ok(0.into())
+    Ok(settings(this).map_or(Value::Undefined, |settings| settings.paper_height.into()))
 }
 
 fn paper_width<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "paperWidth");
-    Ok(0.into())
+    Ok(settings(this).map_or(Value::Undefined, |settings| settings.paper_width.into()))
 }
 
 fn page_height<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "pageHeight");
-    Ok(0.into())
+    Ok(settings(this).map_or(Value::Undefined, |settings| settings.page_height.into()))
 }
 
 fn page_width<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "pageWidth");
-    Ok(0.into())
+    Ok(settings(this).map_or(Value::Undefined, |settings| settings.page_width.into()))
 }
 
 fn orientation<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm1_stub!(activation, "PrintJob", "orientation");
-    Ok(0.into())
+    Ok(settings(this).map_or(Value::Undefined, |settings| {
+        AvmString::new_utf8(activation.gc(), settings.orientation.name()).into()
+    }))
 }
@@ -39,16 +39,17 @@ pub fn attach_video<'gc>(
     activation: &mut Activation<'_, 'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    let source = args
-        .get(0)
-        .cloned()
-        .unwrap_or(Value::Undefined)
-        .coerce_to_object_or_bare(activation)?;
+    let source = args.get(0).cloned().unwrap_or(Value::Undefined);
+    if let Value::Null = source {
+        video.attach_camera(activation.context, None);
+        return Ok(Value::Undefined);
+    }
 
-    if let NativeObject::NetStream(ns) = source.native() {
-        video.attach_netstream(activation.context, ns);
-    } else {
-        tracing::warn!("Cannot use object of type {:?} as video source", source);
+    let source = source.coerce_to_object_or_bare(activation)?;
+    match source.native() {
+        NativeObject::NetStream(ns) => video.attach_netstream(activation.context, ns),
+        NativeObject::Camera(camera) => video.attach_camera(activation.context, Some(camera)),
+        _ => tracing::warn!("Cannot use object of type {:?} as video source", source),
     }
 
     Ok(Value::Undefined)
@@ -24,7 +24,9 @@ use crate::avm1::globals::xml_socket::XmlSocket;
 use crate::avm1::object::super_object::SuperObject;
 use crate::avm1::xml::XmlNode;
 use crate::avm1::{Activation, Error, Value};
+use crate::backend::print::PrintJobSettings;
 use crate::bitmap::bitmap_data::BitmapData;
+use crate::camera::Camera;
 use crate::display_object::{
     Avm1Button, DisplayObject, EditText, MovieClip, TDisplayObject as _, Video,
 };
@@ -90,11 +92,16 @@ pub enum NativeObject<'gc> {
     Xml(Xml<'gc>),
     XmlNode(XmlNode<'gc>),
     SharedObject(Gc<'gc, RefCell<SharedObject>>),
+    /// A `PrintJob`, with what it prints on once it's started.
+    PrintJob(Gc<'gc, Cell<Option<PrintJobSettings>>>),
     XmlSocket(XmlSocket<'gc>),
     FileReference(FileReferenceObject<'gc>),
     NetConnection(NetConnection<'gc>),
     LocalConnection(LocalConnection<'gc>),
     Sound(Sound<'gc>),
+    Camera(Camera<'gc>),
+    /// A `Microphone`, identified by the index of its capture device.
+    Microphone(usize),
     StyleSheet(StyleSheetObject<'gc>),
     TextSnapshot(TextSnapshotObject<'gc>),
 }
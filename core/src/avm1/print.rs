@@ -0,0 +1,144 @@
+//! `print` and `printAsBitmap` handling
+//!
+//! These are compiled to `getURL` calls of `print:` and `printasbitmap:` URLs, with the
+//! bounding box as the fragment of the URL and the clip to print as the target.
+
+use crate::avm_warn;
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::context::UpdateContext;
+use crate::display_object::{BoundsMode, DisplayObject, MovieClip, TDisplayObject};
+use crate::print_job;
+use crate::string::WStr;
+use swf::{Rectangle, Twips};
+
+/// A `print` or `printAsBitmap` call.
+pub struct PrintCommand<'a> {
+    pub as_bitmap: bool,
+
+    /// Which area of the clip to print: `bmovie`, `bmax` or `bframe`.
+    pub bounding_box: &'a WStr,
+}
+
+/// Parse a print URL.
+pub fn parse(url: &WStr) -> Option<PrintCommand<'_>> {
+    let (scheme, rest) = url.split_at(url.find(b':')?);
+    let as_bitmap = if scheme.eq_ignore_case(WStr::from_units(b"print")) {
+        false
+    } else if scheme.eq_ignore_case(WStr::from_units(b"printasbitmap")) {
+        true
+    } else {
+        return None;
+    };
+
+    let rest = &rest[1..];
+    let bounding_box = match rest.find(b'#') {
+        Some(index) => &rest[index + 1..],
+        None => rest,
+    };
+    Some(PrintCommand {
+        as_bitmap,
+        bounding_box,
+    })
+}
+
+pub fn handle<'gc>(
+    command: PrintCommand<'_>,
+    target: Option<DisplayObject<'gc>>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<(), Error<'gc>> {
+    let Some(target) = target else {
+        avm_warn!(activation, "print: Target not found");
+        return Ok(());
+    };
+
+    let is_bounding_box = |name: &[u8]| command.bounding_box.eq_ignore_case(WStr::from_units(name));
+    let is_bmovie = is_bounding_box(b"bmovie");
+    let is_bframe = is_bounding_box(b"bframe");
+
+    let context = &mut *activation.context;
+    let Some(settings) = context.print.start_job() else {
+        return Ok(());
+    };
+    let page_size = (settings.page_width, settings.page_height);
+
+    // Clips print each of their printable frames on its own page.
+    let Some(clip) = target
+        .as_movie_clip()
+        .filter(|clip| clip.header_frames() > 1)
+    else {
+        let area = if is_bmovie {
+            stage_area(context)
+        } else {
+            target.bounds(BoundsMode::Engine)
+        };
+        if print_job::add_page(
+            context,
+            target,
+            area,
+            page_size,
+            is_bframe,
+            command.as_bitmap,
+        ) {
+            context.print.send();
+        }
+        return Ok(());
+    };
+
+    let frames = print_job::printable_frames(clip);
+
+    // `bframe` prints the bounds of each frame scaled to fit the page, while the others
+    // print the same area of every frame: the bounds of the frame labelled `#b` (or the
+    // Stage) for `bmovie`, and the bounds of all printable frames for `bmax`.
+    let area = if is_bframe {
+        None
+    } else if is_bmovie {
+        Some(match print_job::bounds_frame(clip) {
+            Some(frame) => frames_bounds(context, clip, &[frame]),
+            None => stage_area(context),
+        })
+    } else {
+        Some(frames_bounds(context, clip, &frames))
+    };
+
+    let mut printed = false;
+    print_job::for_each_frame(context, clip, &frames, |context, _| {
+        let area = area.unwrap_or_else(|| clip.bounds(BoundsMode::Engine));
+        printed |= print_job::add_page(
+            context,
+            clip.into(),
+            area,
+            page_size,
+            is_bframe,
+            command.as_bitmap,
+        );
+    });
+    if printed {
+        context.print.send();
+    }
+    Ok(())
+}
+
+/// The area of the Stage, as printed by `bmovie` without a frame labelled `#b`.
+fn stage_area(context: &UpdateContext<'_>) -> Rectangle<Twips> {
+    let (width, height) = context.stage.movie_size();
+    Rectangle {
+        x_min: Twips::ZERO,
+        y_min: Twips::ZERO,
+        x_max: Twips::from_pixels(width.into()),
+        y_max: Twips::from_pixels(height.into()),
+    }
+}
+
+/// The union of the bounds of a clip on some of its frames.
+fn frames_bounds<'gc>(
+    context: &mut UpdateContext<'gc>,
+    clip: MovieClip<'gc>,
+    frames: &[u16],
+) -> Rectangle<Twips> {
+    let mut bounds = Rectangle::default();
+    print_job::for_each_frame(context, clip, frames, |_, _| {
+        bounds = bounds.union(&clip.bounds(BoundsMode::Engine));
+    });
+    bounds
+}
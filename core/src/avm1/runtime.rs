@@ -1,3 +1,4 @@
+use crate::avm1::debugger::Debugger;
 use crate::avm1::function::ExecutionReason;
 use crate::avm1::globals::as_broadcaster::BroadcasterFunctions;
 use crate::avm1::globals::{as_broadcaster, create_globals};
@@ -106,6 +107,10 @@ pub struct Avm1<'gc> {
     /// More examples of this are in the movieclip_invalid_get_bounds_X tests.
     use_new_invalid_bounds_value: bool,
 
+    /// The attached step debugger, if any.
+    #[collect(require_static)]
+    debugger: Option<Box<Debugger>>,
+
     #[cfg(feature = "avm_debug")]
     pub debug_output: bool,
 }
@@ -130,6 +135,7 @@ impl<'gc> Avm1<'gc> {
             #[cfg(feature = "avm_debug")]
             debug_output: false,
             use_new_invalid_bounds_value: false,
+            debugger: None,
         }
     }
 
@@ -156,6 +162,10 @@ impl<'gc> Avm1<'gc> {
                 clip_obj,
             ),
         );
+        if let Some(debugger) = &mut context.avm1.debugger {
+            debugger.on_timeline_script(active_clip);
+        }
+
         let constant_pool = context.avm1.constant_pool;
         let mut child_activation = Activation::from_action(
             context,
@@ -325,6 +335,11 @@ impl<'gc> Avm1<'gc> {
         self.stack.len()
     }
 
+    /// The operand stack, bottom first.
+    pub fn stack(&self) -> &[Value<'gc>] {
+        &self.stack
+    }
+
     /// Resets the operand stack and the global registers.
     ///
     /// AVM1 bytecode may leave the stack unbalanced, or access global registers
@@ -412,6 +427,30 @@ impl<'gc> Avm1<'gc> {
         self.player_version
     }
 
+    /// Whether a step debugger is attached to this AVM.
+    pub fn debugger_attached(&self) -> bool {
+        self.debugger.is_some()
+    }
+
+    /// Attach a step debugger to this AVM.
+    pub fn set_debugger(&mut self, debugger: Debugger) {
+        self.debugger = Some(Box::new(debugger));
+    }
+
+    pub fn debugger_mut(&mut self) -> Option<&mut Debugger> {
+        self.debugger.as_deref_mut()
+    }
+
+    /// Take the step debugger out while it's running, so that code it runs
+    /// can't reach it. It's put back with `restore_debugger`.
+    pub(crate) fn take_debugger(&mut self) -> Option<Box<Debugger>> {
+        self.debugger.take()
+    }
+
+    pub(crate) fn restore_debugger(&mut self, debugger: Box<Debugger>) {
+        self.debugger = Some(debugger);
+    }
+
     pub fn get_register(&self, id: usize) -> Option<&Value<'gc>> {
         self.registers.get(id)
     }
@@ -7,6 +7,7 @@
 use crate::PlayerRuntime;
 use crate::avm2::bytearray::ObjectEncoding;
 use crate::avm2::class::{AllocatorFn, CustomConstructorFn};
+use crate::avm2::debugger::Debugger;
 use crate::avm2::e4x::XmlSettings;
 use crate::avm2::error::{
     Error1014Type, make_error_1014, make_error_1047, make_error_1107, make_error_2022,
@@ -51,6 +52,7 @@ mod array;
 pub mod bytearray;
 mod call_stack;
 mod class;
+pub mod debugger;
 mod domain;
 mod dynamic_map;
 mod e4x;
@@ -72,6 +74,7 @@ pub mod property;
 mod property_map;
 mod qname;
 mod regexp;
+mod sampler;
 mod scope;
 pub mod script;
 #[cfg(feature = "known_stubs")]
@@ -106,6 +109,7 @@ pub use crate::avm2::value::Value;
 
 use self::api_version::ApiVersion;
 use self::object::WeakObject;
+use self::sampler::Sampler;
 use self::scope::Scope;
 
 const BROADCAST_WHITELIST: [&[u8]; 4] =
@@ -193,6 +197,13 @@ pub struct Avm2<'gc> {
     pub debug_output: bool,
 
     pub optimizer_enabled: bool,
+
+    /// The attached step debugger, if any.
+    #[collect(require_static)]
+    debugger: Option<Box<Debugger>>,
+
+    /// The `flash.sampler` profiler.
+    sampler: Sampler<'gc>,
 }
 
 impl<'gc> Avm2<'gc> {
@@ -243,6 +254,10 @@ impl<'gc> Avm2<'gc> {
             debug_output: false,
 
             optimizer_enabled: true,
+
+            debugger: None,
+
+            sampler: Sampler::new(),
         }
     }
 
@@ -616,8 +631,13 @@ impl<'gc> Avm2<'gc> {
     }
 
     /// Pushes an executable on the call stack
-    pub fn push_call(&self, mc: &Mutation<'gc>, method: Method<'gc>) {
-        self.call_stack.borrow_mut(mc).push(method)
+    pub fn push_call(&mut self, mc: &Mutation<'gc>, method: Method<'gc>) {
+        self.call_stack.borrow_mut(mc).push(method);
+
+        if self.sampler.is_sampling() {
+            self.sampler
+                .record_invocation(method, &self.call_stack.borrow());
+        }
     }
 
     /// Pops an executable off the call stack
@@ -675,6 +695,41 @@ impl<'gc> Avm2<'gc> {
         self.optimizer_enabled = value;
     }
 
+    pub fn sampler(&self) -> &Sampler<'gc> {
+        &self.sampler
+    }
+
+    pub fn sampler_mut(&mut self) -> &mut Sampler<'gc> {
+        &mut self.sampler
+    }
+
+    /// Call the `flash.sampler` callback if the sample buffer is full.
+    pub fn run_sampler_callback(context: &mut UpdateContext<'gc>) {
+        let Some(callback) = context.avm2.sampler.due_callback() else {
+            return;
+        };
+
+        let mut activation = Activation::from_nothing(context);
+        let result =
+            Value::from(callback).call(&mut activation, Value::Null, FunctionArgs::empty());
+        if let Err(e) = result {
+            Avm2::uncaught_error(&mut activation, None, e, "Error running sampler callback");
+        }
+    }
+
+    /// Whether a step debugger is attached to this AVM.
+    pub fn debugger_attached(&self) -> bool {
+        self.debugger.is_some()
+    }
+
+    /// Attach a step debugger to this AVM.
+    ///
+    /// This must be done before any code is verified, as the debug
+    /// information the debugger relies on is otherwise optimized away.
+    pub fn set_debugger(&mut self, debugger: Debugger) {
+        self.debugger = Some(Box::new(debugger));
+    }
+
     // Report an uncaught AVM2 error.
     // TODO should the `display_object` parameter be optional or not?
     #[cold]
@@ -9,7 +9,7 @@ use crate::avm2::e4x::{escape_attribute_value, escape_element_value};
 use crate::avm2::error::{
     make_error_1016, make_error_1040, make_error_1041, make_error_1063, make_error_1065,
     make_error_1108, make_error_1119, make_error_1123, make_error_1127, make_error_1506,
-    make_null_or_undefined_error,
+    make_null_or_undefined_error, make_script_timeout_error,
 };
 use crate::avm2::function::FunctionArgs;
 use crate::avm2::method::{Method, NativeMethodImpl, ResolvedParamConfig};
@@ -19,6 +19,7 @@ use crate::avm2::object::{
 };
 use crate::avm2::object::{Object, TObject};
 use crate::avm2::op::{LookupSwitch, Op};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::scope::{Scope, ScopeChain, search_scope_stack};
 use crate::avm2::script::Script;
 use crate::avm2::stack::StackFrame;
@@ -476,6 +477,40 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         self.stack.value_at(id as usize)
     }
 
+    /// Retrieve all local registers, for use by the debugger.
+    pub fn debug_locals(&self) -> Vec<Value<'gc>> {
+        (0..self.num_locals)
+            .map(|i| self.stack.value_at(i))
+            .collect()
+    }
+
+    /// Retrieve the values on the operand stack, bottom first, for use by the
+    /// debugger.
+    pub fn debug_operands(&self) -> Vec<Value<'gc>> {
+        (self.num_locals..self.stack.len())
+            .map(|i| self.stack.value_at(i))
+            .collect()
+    }
+
+    /// Retrieve the outer scopes followed by the scopes pushed by this
+    /// activation, along with whether each of them is a `with` scope, for use
+    /// by the debugger.
+    pub fn debug_scopes(&self) -> Vec<(Value<'gc>, bool)> {
+        let mut scopes = Vec::new();
+
+        let mut index = 0;
+        while let Some(scope) = self.outer.get(index) {
+            scopes.push((scope.values(), scope.with()));
+            index += 1;
+        }
+
+        for scope in self.scope_frame() {
+            scopes.push((scope.values(), scope.with()));
+        }
+
+        scopes
+    }
+
     /// Set a local register.
     pub fn set_local_register(&mut self, id: u32, value: impl Into<Value<'gc>>) {
         // Verification guarantees that this is valid
@@ -651,8 +686,15 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         self.timeout_check()?;
 
         let mut ip = 0;
+        let mut previous_ip = None;
 
         loop {
+            if self.context.avm2.debugger.is_some() {
+                let jumped_back = previous_ip.is_some_and(|previous| ip <= previous);
+                self.debugger_hook(method, ip, jumped_back);
+                previous_ip = Some(ip);
+            }
+
             let op = &opcodes[ip];
             ip += 1;
             avm_debug!(self.avm2(), "Opcode: {op:?}");
@@ -893,6 +935,31 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
+    /// Give the attached debugger a chance to pause before the op at `ip` is
+    /// executed, after jumping backward to it if `jumped_back` is set.
+    #[inline(never)]
+    fn debugger_hook(&mut self, method: Method<'gc>, ip: usize, jumped_back: bool) {
+        let Some(mut debugger) = self.context.avm2.debugger.take() else {
+            return;
+        };
+
+        if jumped_back {
+            debugger.on_backward_jump();
+        } else if ip == 0 {
+            debugger.poll();
+        }
+
+        if debugger.is_attached() {
+            debugger.on_op(self, method, ip);
+        }
+
+        if debugger.is_attached() {
+            self.context.avm2.debugger = Some(debugger);
+        } else {
+            tracing::info!("AVM2 debugger detached");
+        }
+    }
+
     /// If a local exception handler exists for the error, use it to handle
     /// the error. Otherwise pass the error down the stack.
     fn handle_err(
@@ -944,10 +1011,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         if *self.context.actions_since_timeout_check >= 10000 {
             *self.context.actions_since_timeout_check = 0;
             if self.context.update_start.elapsed() >= self.context.max_execution_duration {
-                return Err(
-                    "A script in this movie has taken too long to execute and has been terminated."
-                        .into(),
-                );
+                return Err(make_script_timeout_error());
             }
         }
         Ok(())
@@ -1801,6 +1865,9 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let new_fn = FunctionObject::from_method(self.context, method, scope, None, None);
 
+        let function_class = self.avm2().classes().function;
+        Sampler::record_allocation(self.context, new_fn.into(), function_class);
+
         self.push_stack(new_fn);
 
         Ok(())
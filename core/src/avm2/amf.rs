@@ -12,6 +12,7 @@ use crate::avm2::{Activation, Error, Object, Value};
 use crate::avm2_stub_method;
 use crate::string::AvmString;
 use enumset::EnumSet;
+use flash_lso::amf3::read::AMF3Decoder;
 use flash_lso::types::{AMFVersion, Element, Lso, ObjectId};
 use flash_lso::types::{Attribute, ClassDefinition, Value as AmfValue};
 use fnv::FnvHashMap;
@@ -310,6 +311,33 @@ fn get_or_create_value<'gc>(
     }
 }
 
+/// Serialize a Value to the bytes of a single AMF value, as written by `ByteArray.writeObject`.
+pub fn serialize_value_to_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    elem: Value<'gc>,
+    amf_version: AMFVersion,
+) -> Result<Vec<u8>, Error<'gc>> {
+    let amf = serialize_value(activation, elem, amf_version, &mut Default::default())
+        .unwrap_or(AmfValue::Undefined);
+
+    Ok(amf_value_to_bytes(amf, amf_version).ok_or("Failed to serialize object")?)
+}
+
+/// Encode an AmfValue as the bytes of a single AMF value.
+pub fn amf_value_to_bytes(value: AmfValue, amf_version: AMFVersion) -> Option<Vec<u8>> {
+    let element = Element::new("", Rc::new(value));
+    let mut lso = Lso::new(vec![element], "", amf_version);
+    let bytes = flash_lso::write::write_to_bytes(&mut lso).ok()?;
+    // This is kind of hacky: We need to strip out the header and any padding so that we only write
+    // the value. In the future, there should be a method to do this in the flash_lso crate.
+    let element_padding = match amf_version {
+        AMFVersion::AMF0 => 8,
+        AMFVersion::AMF3 => 7,
+    };
+    let start = flash_lso::write::header_length(&lso.header) + element_padding;
+    Some(bytes[start..bytes.len() - 1].to_vec())
+}
+
 /// Deserialize a AmfValue to a Value
 pub fn deserialize_value<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -319,6 +347,17 @@ pub fn deserialize_value<'gc>(
     deserialize_value_impl(activation, val, &mut x)
 }
 
+/// Deserialize the bytes of a single AMF3 value to a Value.
+pub fn deserialize_value_from_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    bytes: &[u8],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let (_, amf) = AMF3Decoder::default()
+        .parse_single_element(bytes)
+        .map_err(|_| "Error: Invalid object")?;
+    deserialize_value(activation, &amf)
+}
+
 pub fn deserialize_value_impl<'gc>(
     activation: &mut Activation<'_, 'gc>,
     val: &AmfValue,
@@ -11,6 +11,7 @@ use std::cmp;
 use std::fmt::{self, Display, Formatter};
 use std::io::prelude::*;
 use std::io::{self, SeekFrom};
+use std::sync::{Arc, Mutex};
 
 #[derive(Clone, Collect, Debug, Copy, PartialEq, Eq)]
 #[collect(no_drop)]
@@ -76,6 +77,9 @@ pub enum ObjectEncoding {
     Amf3 = 3,
 }
 
+/// The storage of a shareable `ByteArray`, shared between workers.
+pub type SharedByteArray = Arc<Mutex<ByteArrayStorage>>;
+
 #[derive(Clone, Debug)]
 pub struct ByteArrayStorage {
     /// Underlying ByteArray
@@ -56,6 +56,15 @@ impl<'gc> CallStack<'gc> {
     pub fn is_empty(&self) -> bool {
         self.stack.is_empty()
     }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Iterate over the methods on the call stack, outermost first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Method<'gc>> + '_ {
+        self.stack.iter().copied()
+    }
 }
 
 impl Default for CallStack<'_> {
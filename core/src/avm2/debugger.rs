@@ -0,0 +1,736 @@
+//! AVM2 step debugger
+//!
+//! The debugger lets an external client pause AVM2 execution on breakpoints,
+//! step through bytecode or source lines, and inspect the call stack, local
+//! registers, scope stack and operand stack of the paused activation.
+//!
+//! While paused, the player thread blocks until the client resumes
+//! execution, so nothing else (rendering, events, timers) runs in the
+//! meantime. This mirrors the behavior of the Flash Player debugger.
+//!
+//! # Protocol
+//!
+//! The frontend is responsible for the transport (a TCP socket, stdio...)
+//! and exchanges messages with the player through a [`DebuggerConnection`].
+//! Every message is a single JSON object on its own line.
+//!
+//! Requests sent by the client have a `command` field:
+//!
+//! | Command             | Arguments             | Available     |
+//! | ------------------- | --------------------- | ------------- |
+//! | `set_breakpoint`    | `method` and `offset` | always        |
+//! | `set_breakpoint`    | `file` and `line`     | always        |
+//! | `remove_breakpoint` | `id`                  | always        |
+//! | `list_breakpoints`  |                       | always        |
+//! | `detach`            |                       | always        |
+//! | `pause`             |                       | while running |
+//! | `continue`          |                       | while paused  |
+//! | `step_in`           |                       | while paused  |
+//! | `step_over`         |                       | while paused  |
+//! | `step_out`          |                       | while paused  |
+//! | `call_stack`        |                       | while paused  |
+//! | `locals`            |                       | while paused  |
+//! | `scopes`            |                       | while paused  |
+//! | `operands`          |                       | while paused  |
+//!
+//! `method` is the name of the method as printed in stack traces, without
+//! the trailing parentheses (for example `com.example::Main/update`), and
+//! `offset` is an offset into that method's bytecode. `file` is matched
+//! against the end of the path given by the `debugfile` opcode, so both
+//! `Main.as` and `com/example/Main.as` will match a method compiled from
+//! `C:\project\src;com\example;Main.as`.
+//!
+//! Commands sent while the movie is running are processed the next time an
+//! AVM2 method is called, and regularly while a method loops.
+//!
+//! `detach` ends the session of the current client: all breakpoints are
+//! removed and execution resumes if paused. The frontend sends it when the
+//! client disconnects, after which another client can attach through the
+//! same connection.
+//!
+//! Messages sent by the player have an `event` field:
+//!
+//! * `breakpoint_set` (`id`), `breakpoint_removed` (`id`) and `breakpoints`
+//!   (`breakpoints`, a list of `id` plus location) answer the breakpoint
+//!   commands.
+//! * `paused` is sent whenever execution stops. `reason` is one of
+//!   `breakpoint` (with the breakpoint `id`), `step`, `pause` or `bkpt` (a
+//!   `bkpt` opcode in the movie). The current `method` and `offset` are
+//!   included, as well as `file` and `line` when the movie contains debug
+//!   information.
+//! * `resumed` is sent when execution continues after a pause.
+//! * `call_stack` (`frames`), `locals` (`locals`), `scopes` (`scopes`) and
+//!   `operands` (`operands`) answer the inspection commands. Frames are
+//!   ordered from innermost to outermost, while scopes and operands are
+//!   ordered from the bottom of their stack to the top.
+//! * `error` (`message`) is sent when a command could not be processed.
+//!
+//! Values are described by an object with a `type` field (`undefined`,
+//! `null`, `Boolean`, `int`, `Number`, `String`, `Class`, or the qualified
+//! class name of an object) and, except for `undefined` and `null`, a `value`
+//! field containing a string representation of the value. Objects are never
+//! converted to strings, as that could run ActionScript code; their `value`
+//! is an opaque identifier instead.
+//!
+//! Stepping works on source lines when the current method has debug
+//! information, and on individual opcodes otherwise.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::function::display_function;
+use crate::avm2::method::Method;
+use crate::avm2::object::TObject;
+use crate::avm2::op::Op;
+use crate::avm2::value::Value;
+use crate::string::WString;
+use serde_json::{Map as JsonObject, Value as JsonValue, json};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+use web_time::Instant;
+
+/// How many backward jumps are taken between two checks for commands of the
+/// client, so that a movie stuck in a loop can still be paused.
+const BACKWARD_JUMPS_PER_POLL: u32 = 100;
+
+/// The connection to a debugger client, as provided by the frontend.
+pub struct DebuggerConnection {
+    /// Lines received from the client.
+    pub commands: Receiver<String>,
+
+    /// Lines to be sent to the client.
+    pub events: Sender<String>,
+}
+
+/// A source file given by the client, matched against the end of the path
+/// given by the `debugfile` opcode.
+#[derive(Clone, Debug, PartialEq)]
+struct SourceFile {
+    /// The normalized path.
+    path: String,
+
+    /// The path with a leading `/`, so that only whole path components match.
+    suffix: String,
+}
+
+impl SourceFile {
+    fn new(path: &str) -> Self {
+        let path = normalize_path(path);
+        let suffix = format!("/{path}");
+        Self { path, suffix }
+    }
+
+    /// Whether this is the file at the normalized `path`.
+    fn matches(&self, path: &str) -> bool {
+        path == self.path || path.ends_with(&self.suffix)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum BreakpointLocation {
+    Offset { method: String, offset: usize },
+    Line { file: SourceFile, line: u32 },
+}
+
+impl BreakpointLocation {
+    fn to_json(&self, id: u32) -> JsonValue {
+        match self {
+            BreakpointLocation::Offset { method, offset } => {
+                json!({ "id": id, "method": method, "offset": offset })
+            }
+            BreakpointLocation::Line { file, line } => {
+                json!({ "id": id, "file": file.path, "line": line })
+            }
+        }
+    }
+}
+
+struct Breakpoint {
+    id: u32,
+    location: BreakpointLocation,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum StepMode {
+    /// Pause on the next statement.
+    Into,
+
+    /// Pause on the next statement at or above the given call depth.
+    Over(usize),
+
+    /// Pause on the next statement above the given call depth.
+    Out(usize),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PauseReason {
+    Breakpoint(u32),
+    Step,
+    Pause,
+    Bkpt,
+}
+
+enum Command {
+    SetBreakpoint(BreakpointLocation),
+    RemoveBreakpoint(u32),
+    ListBreakpoints,
+    Detach,
+    Pause,
+    Continue,
+    StepIn,
+    StepOver,
+    StepOut,
+    CallStack,
+    Locals,
+    Scopes,
+    Operands,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let request: JsonValue =
+            serde_json::from_str(line).map_err(|e| format!("Malformed request: {e}"))?;
+        let name = request
+            .get("command")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| "Missing command".to_string())?;
+
+        let command = match name {
+            "set_breakpoint" => {
+                let location = if let Some(method) = request.get("method") {
+                    let method = method.as_str().ok_or("Invalid method")?;
+                    let offset = request
+                        .get("offset")
+                        .and_then(JsonValue::as_u64)
+                        .ok_or("Missing or invalid offset")?;
+                    BreakpointLocation::Offset {
+                        method: method.trim_end_matches("()").to_string(),
+                        offset: offset as usize,
+                    }
+                } else if let Some(file) = request.get("file") {
+                    let file = file.as_str().ok_or("Invalid file")?;
+                    let line = request
+                        .get("line")
+                        .and_then(JsonValue::as_u64)
+                        .ok_or("Missing or invalid line")?;
+                    BreakpointLocation::Line {
+                        file: SourceFile::new(file),
+                        line: line as u32,
+                    }
+                } else {
+                    return Err("A breakpoint needs either a method or a file".to_string());
+                };
+                Command::SetBreakpoint(location)
+            }
+            "remove_breakpoint" => {
+                let id = request
+                    .get("id")
+                    .and_then(JsonValue::as_u64)
+                    .ok_or("Missing or invalid id")?;
+                Command::RemoveBreakpoint(id as u32)
+            }
+            "list_breakpoints" => Command::ListBreakpoints,
+            "detach" => Command::Detach,
+            "pause" => Command::Pause,
+            "continue" => Command::Continue,
+            "step_in" => Command::StepIn,
+            "step_over" => Command::StepOver,
+            "step_out" => Command::StepOut,
+            "call_stack" => Command::CallStack,
+            "locals" => Command::Locals,
+            "scopes" => Command::Scopes,
+            "operands" => Command::Operands,
+            _ => return Err(format!("Unknown command {name}")),
+        };
+
+        Ok(command)
+    }
+}
+
+/// The state of an attached AVM2 debugger.
+pub struct Debugger {
+    connection: DebuggerConnection,
+
+    breakpoints: Vec<Breakpoint>,
+
+    next_breakpoint_id: u32,
+
+    /// The pending step request, if the client asked to step.
+    step: Option<StepMode>,
+
+    /// Whether the client asked to pause as soon as possible.
+    pause_requested: bool,
+
+    /// Whether the client is still connected.
+    attached: bool,
+
+    /// The backward jumps taken since the commands of the client were last processed.
+    backward_jumps: u32,
+}
+
+impl Debugger {
+    pub fn new(connection: DebuggerConnection) -> Self {
+        Self {
+            connection,
+            breakpoints: Vec::new(),
+            next_breakpoint_id: 1,
+            step: None,
+            pause_requested: false,
+            attached: true,
+            backward_jumps: 0,
+        }
+    }
+
+    /// Whether the connection is still open. Once the frontend closes it,
+    /// the debugger should be dropped.
+    pub fn is_attached(&self) -> bool {
+        self.attached
+    }
+
+    /// Process all commands the client sent while the movie was running.
+    pub fn poll(&mut self) {
+        self.backward_jumps = 0;
+        loop {
+            let line = match self.connection.commands.try_recv() {
+                Ok(line) => line,
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.attached = false;
+                    break;
+                }
+            };
+
+            match Command::parse(&line) {
+                Ok(Command::Pause) => self.pause_requested = true,
+                Ok(Command::Detach) => self.detach(),
+                Ok(command) => {
+                    if !self.handle_breakpoint_command(command) {
+                        self.send_error("Command is only available while paused");
+                    }
+                }
+                Err(message) => self.send_error(&message),
+            }
+        }
+    }
+
+    /// Called when a method jumps backward, which is how loops go around.
+    pub fn on_backward_jump(&mut self) {
+        self.backward_jumps += 1;
+        if self.backward_jumps >= BACKWARD_JUMPS_PER_POLL {
+            self.poll();
+        }
+    }
+
+    /// Called before the op at `ip` in `method` is executed. If execution
+    /// should stop there, this blocks until the client resumes execution.
+    pub fn on_op<'gc>(
+        &mut self,
+        activation: &mut Activation<'_, 'gc>,
+        method: Method<'gc>,
+        ip: usize,
+    ) {
+        let verified_info = method.get_verified_info();
+        let opcodes = verified_info.parsed_code.as_slice();
+        let op = &opcodes[ip];
+        let depth = activation.context.avm2.call_stack().borrow().len();
+
+        let reason = if matches!(op, Op::Bkpt | Op::BkptLine { .. }) {
+            Some(PauseReason::Bkpt)
+        } else if self.pause_requested {
+            Some(PauseReason::Pause)
+        } else if self.step_finished(verified_info.has_line_info, opcodes, ip, depth) {
+            Some(PauseReason::Step)
+        } else {
+            self.find_breakpoint(method, ip)
+                .map(PauseReason::Breakpoint)
+        };
+
+        if let Some(reason) = reason {
+            self.pause(activation, method, ip, depth, reason);
+        }
+    }
+
+    fn step_finished(
+        &self,
+        has_line_info: bool,
+        opcodes: &[Op<'_>],
+        ip: usize,
+        depth: usize,
+    ) -> bool {
+        let Some(step) = self.step else {
+            return false;
+        };
+
+        let depth_reached = match step {
+            StepMode::Into => true,
+            StepMode::Over(step_depth) => depth <= step_depth,
+            StepMode::Out(step_depth) => depth < step_depth,
+        };
+
+        depth_reached && is_statement(has_line_info, opcodes, ip)
+    }
+
+    fn find_breakpoint(&self, method: Method<'_>, ip: usize) -> Option<u32> {
+        let verified_info = method.get_verified_info();
+        let opcodes = verified_info.parsed_code.as_slice();
+        let byte_offset = verified_info.byte_offsets[ip];
+
+        // The method name and file are only computed once a candidate
+        // breakpoint was found, as this runs for every executed op.
+        let mut method_name = None;
+        let mut file_name = None;
+
+        for breakpoint in &self.breakpoints {
+            let hit = match &breakpoint.location {
+                BreakpointLocation::Offset {
+                    method: name,
+                    offset,
+                } => {
+                    *offset == byte_offset
+                        && *method_name.get_or_insert_with(|| full_method_name(method)) == *name
+                }
+                BreakpointLocation::Line { file, line } => {
+                    matches!(opcodes[ip], Op::DebugLine { line_num } if line_num == *line)
+                        && file_name
+                            .get_or_insert_with(|| current_file(opcodes, ip))
+                            .as_deref()
+                            .is_some_and(|current| file.matches(current))
+                }
+            };
+
+            if hit {
+                return Some(breakpoint.id);
+            }
+        }
+
+        None
+    }
+
+    fn pause<'gc>(
+        &mut self,
+        activation: &mut Activation<'_, 'gc>,
+        method: Method<'gc>,
+        ip: usize,
+        depth: usize,
+        reason: PauseReason,
+    ) {
+        self.step = None;
+        self.pause_requested = false;
+
+        let mut event = location(method, ip);
+        event.insert("event".to_string(), "paused".into());
+        match reason {
+            PauseReason::Breakpoint(id) => {
+                event.insert("reason".to_string(), "breakpoint".into());
+                event.insert("id".to_string(), id.into());
+            }
+            PauseReason::Step => {
+                event.insert("reason".to_string(), "step".into());
+            }
+            PauseReason::Pause => {
+                event.insert("reason".to_string(), "pause".into());
+            }
+            PauseReason::Bkpt => {
+                event.insert("reason".to_string(), "bkpt".into());
+            }
+        }
+        self.send(JsonValue::Object(event));
+
+        loop {
+            let Ok(line) = self.connection.commands.recv() else {
+                self.attached = false;
+                break;
+            };
+
+            let command = match Command::parse(&line) {
+                Ok(command) => command,
+                Err(message) => {
+                    self.send_error(&message);
+                    continue;
+                }
+            };
+
+            match command {
+                Command::Continue => break,
+                Command::Detach => {
+                    self.detach();
+                    break;
+                }
+                Command::StepIn => {
+                    self.step = Some(StepMode::Into);
+                    break;
+                }
+                Command::StepOver => {
+                    self.step = Some(StepMode::Over(depth));
+                    break;
+                }
+                Command::StepOut => {
+                    self.step = Some(StepMode::Out(depth));
+                    break;
+                }
+                Command::Pause => self.send_error("Already paused"),
+                Command::CallStack => {
+                    let mut frames = vec![JsonValue::Object(location(method, ip))];
+                    let call_stack = activation.context.avm2.call_stack();
+                    // The innermost entry is the current method, which
+                    // was already described above.
+                    for caller in call_stack.borrow().iter().rev().skip(1) {
+                        frames.push(json!({ "method": full_method_name(caller) }));
+                    }
+                    self.send(json!({ "event": "call_stack", "frames": frames }));
+                }
+                Command::Locals => {
+                    let names = register_names(method);
+                    let locals: Vec<_> = activation
+                        .debug_locals()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, value)| {
+                            let mut local = JsonObject::new();
+                            local.insert("index".to_string(), index.into());
+                            if let Some(name) = names.get(index).cloned().flatten() {
+                                local.insert("name".to_string(), name.into());
+                            }
+                            local.insert("value".to_string(), describe_value(activation, value));
+                            JsonValue::Object(local)
+                        })
+                        .collect();
+                    self.send(json!({ "event": "locals", "locals": locals }));
+                }
+                Command::Scopes => {
+                    let scopes: Vec<_> = activation
+                        .debug_scopes()
+                        .into_iter()
+                        .map(|(value, is_with)| {
+                            json!({
+                                "with": is_with,
+                                "value": describe_value(activation, value),
+                            })
+                        })
+                        .collect();
+                    self.send(json!({ "event": "scopes", "scopes": scopes }));
+                }
+                Command::Operands => {
+                    let operands: Vec<_> = activation
+                        .debug_operands()
+                        .into_iter()
+                        .map(|value| describe_value(activation, value))
+                        .collect();
+                    self.send(json!({ "event": "operands", "operands": operands }));
+                }
+                command => {
+                    self.handle_breakpoint_command(command);
+                }
+            }
+        }
+
+        // Don't count the time spent paused against the script timeout.
+        activation.context.update_start = Instant::now();
+
+        self.send(json!({ "event": "resumed" }));
+    }
+
+    /// End the session of the current client, so that the next one starts
+    /// afresh.
+    fn detach(&mut self) {
+        self.breakpoints.clear();
+        self.next_breakpoint_id = 1;
+        self.step = None;
+        self.pause_requested = false;
+    }
+
+    /// Handle the commands that are available regardless of whether we're
+    /// paused. Returns `false` if the command wasn't one of those.
+    fn handle_breakpoint_command(&mut self, command: Command) -> bool {
+        match command {
+            Command::SetBreakpoint(location) => {
+                let id = self.next_breakpoint_id;
+                self.next_breakpoint_id += 1;
+                self.breakpoints.push(Breakpoint { id, location });
+                self.send(json!({ "event": "breakpoint_set", "id": id }));
+            }
+            Command::RemoveBreakpoint(id) => {
+                let len = self.breakpoints.len();
+                self.breakpoints.retain(|breakpoint| breakpoint.id != id);
+                if self.breakpoints.len() == len {
+                    self.send_error(&format!("No breakpoint with id {id}"));
+                } else {
+                    self.send(json!({ "event": "breakpoint_removed", "id": id }));
+                }
+            }
+            Command::ListBreakpoints => {
+                let breakpoints: Vec<_> = self
+                    .breakpoints
+                    .iter()
+                    .map(|breakpoint| breakpoint.location.to_json(breakpoint.id))
+                    .collect();
+                self.send(json!({ "event": "breakpoints", "breakpoints": breakpoints }));
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn send(&mut self, event: JsonValue) {
+        if self.connection.events.send(event.to_string()).is_err() {
+            self.attached = false;
+        }
+    }
+
+    fn send_error(&mut self, message: &str) {
+        self.send(json!({ "event": "error", "message": message }));
+    }
+}
+
+/// The name of a method as shown in stack traces, without parentheses.
+fn full_method_name(method: Method<'_>) -> String {
+    let mut output = WString::new();
+    display_function(&mut output, method);
+    output.to_utf8_lossy().trim_end_matches("()").to_string()
+}
+
+/// Normalize the path separators used by the `debugfile` opcode (`;` between
+/// the source root and the package path, `\` on Windows) to `/`.
+fn normalize_path(path: &str) -> String {
+    path.replace([';', '\\'], "/")
+}
+
+/// Whether the op at `ip` starts a new statement for the purpose of
+/// stepping: a `debugline` op if the method has line information, or any op
+/// otherwise.
+fn is_statement(has_line_info: bool, opcodes: &[Op<'_>], ip: usize) -> bool {
+    !has_line_info || matches!(opcodes[ip], Op::DebugLine { .. })
+}
+
+/// The source file that was last declared before the op at `ip`.
+fn current_file(opcodes: &[Op<'_>], ip: usize) -> Option<String> {
+    opcodes[..=ip].iter().rev().find_map(|op| match op {
+        Op::DebugFile { file_name } => Some(normalize_path(&file_name.to_string())),
+        _ => None,
+    })
+}
+
+/// The source line that was last declared before the op at `ip`.
+fn current_line(opcodes: &[Op<'_>], ip: usize) -> Option<u32> {
+    opcodes[..=ip].iter().rev().find_map(|op| match op {
+        Op::DebugLine { line_num } => Some(*line_num),
+        _ => None,
+    })
+}
+
+fn location(method: Method<'_>, ip: usize) -> JsonObject<String, JsonValue> {
+    let verified_info = method.get_verified_info();
+    let opcodes = verified_info.parsed_code.as_slice();
+
+    let mut location = JsonObject::new();
+    location.insert("method".to_string(), full_method_name(method).into());
+    location.insert("offset".to_string(), verified_info.byte_offsets[ip].into());
+    if let Some(file) = current_file(opcodes, ip) {
+        location.insert("file".to_string(), file.into());
+    }
+    if let Some(line) = current_line(opcodes, ip) {
+        location.insert("line".to_string(), line.into());
+    }
+    location
+}
+
+/// The names of the local registers of a method, as declared by its `debug`
+/// ops. Register 0 is always `this`, and isn't counted by `debug` ops.
+fn register_names(method: Method<'_>) -> Vec<Option<String>> {
+    let mut names = vec![Some("this".to_string())];
+
+    for op in &method.get_verified_info().parsed_code {
+        if let Op::Debug {
+            is_local_register: true,
+            register_name,
+            register,
+        } = op
+        {
+            let register = *register as usize + 1;
+            if names.len() <= register {
+                names.resize(register + 1, None);
+            }
+            names[register] = Some(register_name.to_string());
+        }
+    }
+
+    names
+}
+
+/// Describe a value without running any ActionScript code.
+fn describe_value<'gc>(activation: &mut Activation<'_, 'gc>, value: Value<'gc>) -> JsonValue {
+    match value.normalize() {
+        Value::Undefined => json!({ "type": "undefined" }),
+        Value::Null => json!({ "type": "null" }),
+        Value::Bool(value) => json!({ "type": "Boolean", "value": value.to_string() }),
+        Value::Integer(value) => json!({ "type": "int", "value": value.to_string() }),
+        Value::Number(value) => json!({ "type": "Number", "value": value.to_string() }),
+        Value::String(value) => json!({ "type": "String", "value": value.to_string() }),
+        Value::Object(object) => {
+            if let Some(class) = object.as_class_object() {
+                let class_name = class
+                    .inner_class_definition()
+                    .name()
+                    .to_qualified_name(activation.gc());
+                json!({ "type": "Class", "value": class_name.to_string() })
+            } else {
+                let class_name = object.instance_of_class_name(activation.gc());
+                json!({
+                    "type": class_name.to_string(),
+                    "value": format!("{:p}", object.as_ptr()),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_offset_breakpoint() {
+        let command =
+            Command::parse(r#"{"command":"set_breakpoint","method":"Main/update()","offset":12}"#);
+        assert!(matches!(
+            command,
+            Ok(Command::SetBreakpoint(BreakpointLocation::Offset { method, offset: 12 }))
+                if method == "Main/update"
+        ));
+    }
+
+    #[test]
+    fn parse_line_breakpoint() {
+        let command = Command::parse(
+            r#"{"command":"set_breakpoint","file":"com\\example\\Main.as","line":4}"#,
+        );
+        assert!(matches!(
+            command,
+            Ok(Command::SetBreakpoint(BreakpointLocation::Line { file, line: 4 }))
+                if file.path == "com/example/Main.as"
+        ));
+    }
+
+    #[test]
+    fn match_source_file() {
+        let file = SourceFile::new(r"example\Main.as");
+        assert!(file.matches("example/Main.as"));
+        assert!(file.matches("C:/project/src/com/example/Main.as"));
+        assert!(!file.matches("C:/project/src/com/other_example/Main.as"));
+        assert!(!file.matches("C:/project/src/com/example/Main.as2"));
+    }
+
+    #[test]
+    fn parse_invalid_commands() {
+        assert!(Command::parse("not json").is_err());
+        assert!(Command::parse(r#"{"command":"explode"}"#).is_err());
+        assert!(Command::parse(r#"{"command":"set_breakpoint"}"#).is_err());
+        assert!(Command::parse(r#"{"command":"remove_breakpoint","id":"one"}"#).is_err());
+    }
+
+    #[test]
+    fn normalize_debugfile_path() {
+        assert_eq!(
+            normalize_path(r"C:\project\src;com\example;Main.as"),
+            "C:/project/src/com/example/Main.as"
+        );
+    }
+}
@@ -1246,6 +1246,54 @@ pub fn make_error_1508<'gc>(activation: &mut Activation<'_, 'gc>, param_name: &s
     ))
 }
 
+#[inline(never)]
+#[cold]
+pub fn make_error_1516<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(illegal_operation_error(
+        activation,
+        "Error #1516: The Condition cannot notify because the Mutex is not owned by the current worker.",
+        1516,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_1517<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(illegal_operation_error(
+        activation,
+        "Error #1517: The Condition cannot notifyAll because the Mutex is not owned by the current worker.",
+        1517,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_1518<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(illegal_operation_error(
+        activation,
+        "Error #1518: The Condition cannot wait because the Mutex is not owned by the current worker.",
+        1518,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_1519<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(illegal_operation_error(
+        activation,
+        "Error #1519: The Mutex cannot be unlocked because it is not owned by the current worker.",
+        1519,
+    ))
+}
+
+/// The error that terminates a script which ran for longer than the script
+/// timeout.
+#[inline(never)]
+#[cold]
+pub fn make_script_timeout_error<'gc>() -> Error<'gc> {
+    "A script in this movie has taken too long to execute and has been terminated.".into()
+}
+
 #[inline(never)]
 #[cold]
 pub fn make_error_2002<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
@@ -1415,6 +1463,16 @@ pub fn make_error_2027<'gc>(
     ))
 }
 
+#[inline(never)]
+#[cold]
+pub fn make_error_2029<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(
+        activation,
+        "Error #2029: This URLStream object does not have a stream opened.",
+        2029,
+    ))
+}
+
 #[inline(never)]
 #[cold]
 pub fn make_error_2030<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
@@ -1425,6 +1483,12 @@ pub fn make_error_2030<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc>
     ))
 }
 
+#[inline(never)]
+#[cold]
+pub fn make_error_2032<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(activation, "Error #2032: Stream Error.", 2032))
+}
+
 #[inline(never)]
 #[cold]
 pub fn make_error_2037<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
@@ -1435,6 +1499,12 @@ pub fn make_error_2037<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc>
     ))
 }
 
+#[inline(never)]
+#[cold]
+pub fn make_error_2038<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(activation, "Error #2038: File I/O Error.", 2038))
+}
+
 #[inline(never)]
 #[cold]
 pub fn make_error_2058<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
@@ -1654,6 +1724,78 @@ pub fn make_error_2186<'gc>(activation: &mut Activation<'_, 'gc>, focal_length:
     ))
 }
 
+#[inline(never)]
+#[cold]
+pub fn make_error_3001<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(
+        activation,
+        "Error #3001: File or directory access denied.",
+        3001,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_3002<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(
+        activation,
+        "Error #3002: File or directory exists.",
+        3002,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_3003<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(
+        activation,
+        "Error #3003: File or directory does not exist.",
+        3003,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_3006<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(activation, "Error #3006: Not a file.", 3006))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_3007<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(activation, "Error #3007: Not a directory.", 3007))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_3010<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(
+        activation,
+        "Error #3010: Directory is not empty.",
+        3010,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_3011<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(
+        activation,
+        "Error #3011: Move or copy destination already exists.",
+        3011,
+    ))
+}
+
+#[inline(never)]
+#[cold]
+pub fn make_error_3014<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    make_error!(io_error(
+        activation,
+        "Error #3014: Cannot copy or move a file or directory to overwrite a containing directory.",
+        3014,
+    ))
+}
+
 #[inline(never)]
 #[cold]
 pub fn make_error_3669<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
@@ -85,6 +85,12 @@ pub struct SystemClasses<'gc> {
     pub textfield: ClassObject<'gc>,
     pub textformat: ClassObject<'gc>,
     pub graphics: ClassObject<'gc>,
+    pub graphicsbitmapfill: ClassObject<'gc>,
+    pub graphicsendfill: ClassObject<'gc>,
+    pub graphicsgradientfill: ClassObject<'gc>,
+    pub graphicspath: ClassObject<'gc>,
+    pub graphicssolidfill: ClassObject<'gc>,
+    pub graphicsstroke: ClassObject<'gc>,
     pub loader: ClassObject<'gc>,
     pub loaderinfo: ClassObject<'gc>,
     pub bytearray: ClassObject<'gc>,
@@ -103,6 +109,10 @@ pub struct SystemClasses<'gc> {
     pub date: ClassObject<'gc>,
     pub qname: ClassObject<'gc>,
     pub mouseevent: ClassObject<'gc>,
+    pub touchevent: ClassObject<'gc>,
+    pub gestureevent: ClassObject<'gc>,
+    pub transformgestureevent: ClassObject<'gc>,
+    pub pressandtapgestureevent: ClassObject<'gc>,
     pub progressevent: ClassObject<'gc>,
     pub httpstatusevent: ClassObject<'gc>,
     pub textevent: ClassObject<'gc>,
@@ -162,12 +172,23 @@ pub struct SystemClasses<'gc> {
     pub netstatusevent: ClassObject<'gc>,
     pub shaderfilter: ClassObject<'gc>,
     pub statusevent: ClassObject<'gc>,
+    pub activityevent: ClassObject<'gc>,
+    pub nativeprocessexitevent: ClassObject<'gc>,
+    pub nativewindowboundsevent: ClassObject<'gc>,
+    pub nativewindowdisplaystateevent: ClassObject<'gc>,
     pub asyncerrorevent: ClassObject<'gc>,
     pub contextmenuevent: ClassObject<'gc>,
     pub filereference: ClassObject<'gc>,
     pub filefilter: ClassObject<'gc>,
+    pub file: ClassObject<'gc>,
     pub font: ClassObject<'gc>,
     pub textline: ClassObject<'gc>,
+    pub textlinemirrorregion: ClassObject<'gc>,
+    pub groupelement: ClassObject<'gc>,
+    pub graphicelement: ClassObject<'gc>,
+    pub spacejustifier: ClassObject<'gc>,
+    pub numberparseresult: ClassObject<'gc>,
+    pub currencyparseresult: ClassObject<'gc>,
     pub sampledataevent: ClassObject<'gc>,
     pub avm1movie: ClassObject<'gc>,
     pub focusevent: ClassObject<'gc>,
@@ -179,6 +200,12 @@ pub struct SystemClasses<'gc> {
     pub workerdomain: ClassObject<'gc>,
     pub messagechannel: ClassObject<'gc>,
     pub securitydomain: ClassObject<'gc>,
+    pub mutex: ClassObject<'gc>,
+    pub condition: ClassObject<'gc>,
+    pub sample: ClassObject<'gc>,
+    pub newobjectsample: ClassObject<'gc>,
+    pub deleteobjectsample: ClassObject<'gc>,
+    pub stackframe: ClassObject<'gc>,
 }
 
 #[derive(Clone, Collect)]
@@ -265,6 +292,12 @@ impl<'gc> SystemClasses<'gc> {
             textfield: object,
             textformat: object,
             graphics: object,
+            graphicsbitmapfill: object,
+            graphicsendfill: object,
+            graphicsgradientfill: object,
+            graphicspath: object,
+            graphicssolidfill: object,
+            graphicsstroke: object,
             loader: object,
             loaderinfo: object,
             bytearray: object,
@@ -281,6 +314,10 @@ impl<'gc> SystemClasses<'gc> {
             date: object,
             qname: object,
             mouseevent: object,
+            touchevent: object,
+            gestureevent: object,
+            transformgestureevent: object,
+            pressandtapgestureevent: object,
             progressevent: object,
             httpstatusevent: object,
             textevent: object,
@@ -340,12 +377,23 @@ impl<'gc> SystemClasses<'gc> {
             netstatusevent: object,
             shaderfilter: object,
             statusevent: object,
+            activityevent: object,
+            nativeprocessexitevent: object,
+            nativewindowboundsevent: object,
+            nativewindowdisplaystateevent: object,
             asyncerrorevent: object,
             contextmenuevent: object,
             filereference: object,
             filefilter: object,
+            file: object,
             font: object,
             textline: object,
+            textlinemirrorregion: object,
+            groupelement: object,
+            graphicelement: object,
+            spacejustifier: object,
+            numberparseresult: object,
+            currencyparseresult: object,
             sampledataevent: object,
             avm1movie: object,
             focusevent: object,
@@ -357,6 +405,12 @@ impl<'gc> SystemClasses<'gc> {
             workerdomain: object,
             messagechannel: object,
             securitydomain: object,
+            mutex: object,
+            condition: object,
+            sample: object,
+            newobjectsample: object,
+            deleteobjectsample: object,
+            stackframe: object,
         }
     }
 }
@@ -679,12 +733,24 @@ pub fn init_native_system_classes(activation: &mut Activation<'_, '_>) {
         [
             ("", "Date", date),
             ("", "RegExp", regexp),
+            ("flash.concurrent", "Condition", condition),
+            ("flash.concurrent", "Mutex", mutex),
             ("flash.display", "AVM1Movie", avm1movie),
             ("flash.display", "Bitmap", bitmap),
             ("flash.display", "BitmapData", bitmapdata),
             ("flash.display", "Scene", scene),
             ("flash.display", "FrameLabel", framelabel),
             ("flash.display", "Graphics", graphics),
+            ("flash.display", "GraphicsBitmapFill", graphicsbitmapfill),
+            ("flash.display", "GraphicsEndFill", graphicsendfill),
+            (
+                "flash.display",
+                "GraphicsGradientFill",
+                graphicsgradientfill
+            ),
+            ("flash.display", "GraphicsPath", graphicspath),
+            ("flash.display", "GraphicsSolidFill", graphicssolidfill),
+            ("flash.display", "GraphicsStroke", graphicsstroke),
             ("flash.display", "Loader", loader),
             ("flash.display", "LoaderInfo", loaderinfo),
             ("flash.display", "MorphShape", morphshape),
@@ -724,10 +790,38 @@ pub fn init_native_system_classes(activation: &mut Activation<'_, '_>) {
             ("flash.events", "SecurityErrorEvent", securityerrorevent),
             ("flash.events", "IOErrorEvent", ioerrorevent),
             ("flash.events", "MouseEvent", mouseevent),
+            ("flash.events", "TouchEvent", touchevent),
+            ("flash.events", "GestureEvent", gestureevent),
+            (
+                "flash.events",
+                "TransformGestureEvent",
+                transformgestureevent
+            ),
+            (
+                "flash.events",
+                "PressAndTapGestureEvent",
+                pressandtapgestureevent
+            ),
             ("flash.events", "FullScreenEvent", fullscreenevent),
             ("flash.events", "UncaughtErrorEvents", uncaughterrorevents),
             ("flash.events", "NetStatusEvent", netstatusevent),
             ("flash.events", "StatusEvent", statusevent),
+            ("flash.events", "ActivityEvent", activityevent),
+            (
+                "flash.events",
+                "NativeProcessExitEvent",
+                nativeprocessexitevent
+            ),
+            (
+                "flash.events",
+                "NativeWindowBoundsEvent",
+                nativewindowboundsevent
+            ),
+            (
+                "flash.events",
+                "NativeWindowDisplayStateEvent",
+                nativewindowdisplaystateevent
+            ),
             ("flash.events", "AsyncErrorEvent", asyncerrorevent),
             ("flash.events", "ContextMenuEvent", contextmenuevent),
             ("flash.events", "FocusEvent", focusevent),
@@ -745,7 +839,12 @@ pub fn init_native_system_classes(activation: &mut Activation<'_, '_>) {
             ("flash.net", "URLVariables", urlvariables),
             ("flash.net", "FileReference", filereference),
             ("flash.net", "FileFilter", filefilter),
+            ("flash.filesystem", "File", file),
             ("flash.net", "SharedObject", sharedobject),
+            ("flash.sampler", "Sample", sample),
+            ("flash.sampler", "NewObjectSample", newobjectsample),
+            ("flash.sampler", "DeleteObjectSample", deleteobjectsample),
+            ("flash.sampler", "StackFrame", stackframe),
             ("flash.utils", "ByteArray", bytearray),
             ("flash.utils", "Dictionary", dictionary),
             ("flash.system", "ApplicationDomain", application_domain),
@@ -760,6 +859,24 @@ pub fn init_native_system_classes(activation: &mut Activation<'_, '_>) {
             ("flash.text", "TextLineMetrics", textlinemetrics),
             ("flash.text", "TextRun", textrun),
             ("flash.text.engine", "TextLine", textline),
+            (
+                "flash.text.engine",
+                "TextLineMirrorRegion",
+                textlinemirrorregion,
+            ),
+            ("flash.text.engine", "GroupElement", groupelement),
+            ("flash.text.engine", "GraphicElement", graphicelement),
+            ("flash.text.engine", "SpaceJustifier", spacejustifier),
+            (
+                "flash.globalization",
+                "NumberParseResult",
+                numberparseresult
+            ),
+            (
+                "flash.globalization",
+                "CurrencyParseResult",
+                currencyparseresult
+            ),
             ("flash.filters", "BevelFilter", bevelfilter),
             ("flash.filters", "BitmapFilter", bitmapfilter),
             ("flash.filters", "BlurFilter", blurfilter),
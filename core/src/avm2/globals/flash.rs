@@ -1,14 +1,20 @@
 //! `flash` namespace
 
+pub mod concurrent;
 pub mod crypto;
+pub mod desktop;
 pub mod display;
 #[expect(non_snake_case)]
 pub mod display3D;
 pub mod events;
 pub mod external;
+pub mod filesystem;
 pub mod geom;
+pub mod globalization;
 pub mod media;
 pub mod net;
+pub mod printing;
+pub mod sampler;
 pub mod system;
 pub mod text;
 pub mod ui;
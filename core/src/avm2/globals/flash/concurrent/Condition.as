@@ -1,8 +1,21 @@
 package flash.concurrent {
     [API("684")]
+    [Ruffle(InstanceAllocator)]
     public final class Condition {
-        public static const isSupported:Boolean = false;
+        public static native function get isSupported():Boolean;
 
-        public function Condition(mutex:Mutex) {}
+        public function Condition(mutex:Mutex) {
+            this.init(mutex);
+        }
+
+        private native function init(mutex:Mutex):void;
+
+        public native function get mutex():Mutex;
+
+        public native function notify():void;
+
+        public native function notifyAll():void;
+
+        public native function wait(timeout:Number = -1):Boolean;
     }
 }
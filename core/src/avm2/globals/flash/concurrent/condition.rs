@@ -0,0 +1,121 @@
+//! `flash.concurrent.Condition` native methods
+
+use crate::avm2::Error;
+use crate::avm2::activation::Activation;
+use crate::avm2::error::{
+    make_error_1508, make_error_1516, make_error_1517, make_error_1518, make_error_2007,
+    make_script_timeout_error,
+};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::worker::{WORKERS_SUPPORTED, WaitError, Workers};
+use std::time::Duration;
+
+pub use crate::avm2::object::condition_allocator;
+
+/// Implements `Condition.isSupported`
+pub fn get_is_supported<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(WORKERS_SUPPORTED.into())
+}
+
+/// Implements `Condition`'s constructor.
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let Some(mutex) = args.try_get_object(0).and_then(|mutex| mutex.as_mutex()) else {
+        return Err(make_error_2007(activation, "mutex"));
+    };
+
+    if let Some(condition) = this.as_condition() {
+        condition.init(activation.gc(), mutex);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Condition.mutex`
+pub fn get_mutex<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(mutex) = this.as_condition().and_then(|condition| condition.mutex()) {
+        return Ok(mutex.into());
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `Condition.notify`
+pub fn notify<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(handle) = this.as_condition().and_then(|c| c.handle().clone()) {
+        if !handle.notify(activation.context.workers.current(), false) {
+            return Err(make_error_1516(activation));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Condition.notifyAll`
+pub fn notify_all<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(handle) = this.as_condition().and_then(|c| c.handle().clone()) {
+        if !handle.notify(activation.context.workers.current(), true) {
+            return Err(make_error_1517(activation));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Condition.wait`
+pub fn wait<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let timeout = args.get_f64(0);
+    let timeout = if timeout == -1.0 {
+        None
+    } else if timeout >= 0.0 {
+        // Timeouts too long to represent are the same as no timeout.
+        Duration::try_from_secs_f64(timeout / 1000.0).ok()
+    } else {
+        return Err(make_error_1508(activation, "timeout"));
+    };
+
+    if let Some(handle) = this.as_condition().and_then(|c| c.handle().clone()) {
+        let deadline = Workers::blocking_deadline(activation.context);
+        return match handle.wait(activation.context.workers.current(), timeout, deadline) {
+            Ok(notified) => Ok(notified.into()),
+            Err(WaitError::NotOwner) => Err(make_error_1518(activation)),
+            Err(WaitError::DeadlinePassed) => Err(make_script_timeout_error()),
+        };
+    }
+
+    Ok(false.into())
+}
@@ -0,0 +1,74 @@
+//! `flash.concurrent.Mutex` native methods
+
+use crate::avm2::Error;
+use crate::avm2::activation::Activation;
+use crate::avm2::error::{make_error_1519, make_script_timeout_error};
+use crate::avm2::value::Value;
+use crate::worker::{WORKERS_SUPPORTED, Workers};
+
+pub use crate::avm2::object::mutex_allocator;
+
+/// Implements `Mutex.isSupported`
+pub fn get_is_supported<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(WORKERS_SUPPORTED.into())
+}
+
+/// Implements `Mutex.lock`
+pub fn lock<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(mutex) = this.as_mutex() {
+        let deadline = Workers::blocking_deadline(activation.context);
+        if !mutex
+            .handle()
+            .lock(activation.context.workers.current(), deadline)
+        {
+            return Err(make_script_timeout_error());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Mutex.tryLock`
+pub fn try_lock<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(mutex) = this.as_mutex() {
+        let locked = mutex
+            .handle()
+            .try_lock(activation.context.workers.current());
+        return Ok(locked.into());
+    }
+
+    Ok(false.into())
+}
+
+/// Implements `Mutex.unlock`
+pub fn unlock<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(mutex) = this.as_mutex() {
+        if !mutex.handle().unlock(activation.context.workers.current()) {
+            return Err(make_error_1519(activation));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
@@ -1,58 +1,219 @@
 package flash.desktop {
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-
-    import flash.system.System;
+    import flash.display.BitmapData;
+    import flash.utils.ByteArray;
+    import flash.utils.setTimeout;
 
     public class Clipboard {
-        private static var _generalClipboard = new Clipboard();
+        private static var _generalClipboard:Clipboard = null;
+
+        // Whether this is the general clipboard, which stores data in the
+        // standard formats on the system clipboard.
+        private var _isGeneral:Boolean = false;
+
+        // The formats stored in this object, in the order they were added.
+        // For the general clipboard, this only contains formats that the
+        // system clipboard can't hold, and deferred formats.
+        private var _formats:Array = [];
+
+        // The data and the not yet called data handlers, by format.
+        private var _data:Object = {};
+        private var _handlers:Object = {};
 
         public static function get generalClipboard():Clipboard {
-            return Clipboard._generalClipboard;
+            if (_generalClipboard == null) {
+                _generalClipboard = new Clipboard();
+                _generalClipboard._isGeneral = true;
+            }
+            return _generalClipboard;
         }
 
         function Clipboard() {
             // TODO: This should only be callable in AIR
         }
 
+        private static native function systemFormats():Array;
+
+        private static native function readSystemData(format:String):*;
+
+        private static native function writeSystemData(formats:Array, values:Array):void;
+
+        private static function isSystemFormat(format:String):Boolean {
+            return format == ClipboardFormats.TEXT_FORMAT ||
+                format == ClipboardFormats.HTML_FORMAT ||
+                format == ClipboardFormats.RICH_TEXT_FORMAT ||
+                format == ClipboardFormats.URL_FORMAT ||
+                format == ClipboardFormats.BITMAP_FORMAT;
+        }
+
+        private function storesOnSystem(format:String):Boolean {
+            return this._isGeneral && isSystemFormat(format);
+        }
+
         public function get formats():Array {
-            stub_getter("flash.desktop.Clipboard", "formats");
-            return new Array();
+            var result:Array = this._formats.concat();
+            if (this._isGeneral) {
+                for each (var format:String in systemFormats()) {
+                    if (result.indexOf(format) == -1) {
+                        result.push(format);
+                    }
+                }
+            }
+            return result;
         }
 
         public function clear():void {
-            stub_method("flash.desktop.Clipboard", "clear");
+            this._formats = [];
+            this._data = {};
+            this._handlers = {};
+            if (this._isGeneral) {
+                writeSystemData([], []);
+            }
         }
 
         public function clearData(format:String):void {
-            stub_method("flash.desktop.Clipboard", "clearData");
+            this.removeFormat(format);
+            if (this.storesOnSystem(format)) {
+                this.writeSystemFormat(format, undefined);
+            }
         }
 
         public function getData(
             format:String,
             transferMode:String = ClipboardTransferMode.ORIGINAL_PREFERRED
         ):Object {
-            stub_method("flash.desktop.Clipboard", "getData");
-            return null;
+            if (this._handlers[format] !== undefined) {
+                this.renderDeferredData(format);
+            }
+
+            if (this.storesOnSystem(format)) {
+                // Data from the system clipboard is always a new copy.
+                return readSystemData(format);
+            }
+
+            if (this._data[format] === undefined) {
+                return null;
+            }
+            var value:* = this._data[format];
+            if (transferMode == ClipboardTransferMode.CLONE_ONLY ||
+                transferMode == ClipboardTransferMode.CLONE_PREFERRED) {
+                return cloneData(value);
+            }
+            return value;
         }
 
         public function hasFormat(format:String):Boolean {
-            stub_method("flash.desktop.Clipboard", "hasFormat");
-            return false;
+            return this.formats.indexOf(format) != -1;
         }
 
         public function setData(format:String, data:Object, serializable:Boolean = true):Boolean {
-            stub_method("flash.desktop.Clipboard", "setData");
-            if (format == ClipboardFormats.TEXT_FORMAT) {
-                System.setClipboard(data);
-                return true;
+            if (!isValidData(format, data)) {
+                return false;
             }
-            return false;
+
+            this.removeFormat(format);
+            if (this.storesOnSystem(format)) {
+                this.writeSystemFormat(format, data);
+            } else {
+                this._formats.push(format);
+                this._data[format] = data;
+            }
+            return true;
         }
 
         public function setDataHandler(format:String, handler:Function, serializable:Boolean = true):Boolean {
-            stub_method("flash.desktop.Clipboard", "setDataHandler");
-            return false;
+            if (handler == null) {
+                return false;
+            }
+
+            this.removeFormat(format);
+            this._formats.push(format);
+            this._handlers[format] = handler;
+
+            if (this.storesOnSystem(format)) {
+                // We aren't told when another application wants to paste the
+                // data, so it's rendered at the end of the frame instead.
+                var self:Clipboard = this;
+                setTimeout(function():void {
+                    if (self._handlers[format] === handler) {
+                        self.renderDeferredData(format);
+                    }
+                }, 0);
+            }
+            return true;
+        }
+
+        // Calls the handler of a deferred format, and stores the data it returns.
+        private function renderDeferredData(format:String):void {
+            var handler:Function = this._handlers[format];
+            this.removeFormat(format);
+
+            var data:* = handler();
+            if (data != null) {
+                this.setData(format, data);
+            }
+        }
+
+        private function removeFormat(format:String):void {
+            var index:int = this._formats.indexOf(format);
+            if (index != -1) {
+                this._formats.splice(index, 1);
+            }
+            delete this._data[format];
+            delete this._handlers[format];
+        }
+
+        // Replaces the data of one format on the system clipboard, keeping
+        // the data in the other formats.
+        private function writeSystemFormat(format:String, data:*):void {
+            var formats:Array = [];
+            var values:Array = [];
+            for each (var existing:String in systemFormats()) {
+                if (existing != format) {
+                    formats.push(existing);
+                    values.push(readSystemData(existing));
+                }
+            }
+            if (data !== undefined) {
+                formats.push(format);
+                values.push(data);
+            }
+            writeSystemData(formats, values);
+        }
+
+        private static function isValidData(format:String, data:Object):Boolean {
+            if (data == null) {
+                return false;
+            }
+            switch (format) {
+                case ClipboardFormats.TEXT_FORMAT:
+                case ClipboardFormats.HTML_FORMAT:
+                case ClipboardFormats.URL_FORMAT:
+                    return data is String;
+                case ClipboardFormats.RICH_TEXT_FORMAT:
+                    return data is ByteArray;
+                case ClipboardFormats.BITMAP_FORMAT:
+                    return data is BitmapData;
+                case ClipboardFormats.FILE_LIST_FORMAT:
+                    return data is Array;
+                default:
+                    return true;
+            }
+        }
+
+        private static function cloneData(data:*):* {
+            if (data is BitmapData) {
+                return BitmapData(data).clone();
+            }
+            if (data is ByteArray) {
+                var copy:ByteArray = new ByteArray();
+                copy.writeBytes(ByteArray(data));
+                copy.position = 0;
+                return copy;
+            }
+            if (data is Array) {
+                return (data as Array).concat();
+            }
+            return data;
         }
     }
 }
@@ -4,8 +4,7 @@ package flash.desktop {
     import flash.events.Event;
     import flash.events.TimerEvent;
     import flash.events.EventDispatcher;
-    import flash.system.Security;
-    import flash.utils.Timer;
+    import flash.filesystem.File;
     import flash.utils.setTimeout;
     import __ruffle__.stub_method;
     import __ruffle__.stub_getter;
@@ -15,15 +14,44 @@ package flash.desktop {
     public final class NativeApplication extends EventDispatcher {
         private static var _instance:NativeApplication;
 
-        private var _openedWindows:Array = [];
-        private var _idleThreshold:int = 300;
+        private var _invoked:Boolean = false;
 
         public function NativeApplication() {
             super();
-            // TODO
-            setTimeout(function():void {
-                dispatchEvent(new InvokeEvent(InvokeEvent.INVOKE, false, false, null, []));
-            }, 500);
+            this.init();
+        }
+
+        // Registers this object, so that it receives the events of the application.
+        private native function init():void;
+
+        private native function getInvokeArguments():Array;
+
+        // Returns the contents of the `application.xml` of the application, or null.
+        private native function getDescriptor():String;
+
+        override public function addEventListener(
+            type:String,
+            listener:Function,
+            useCapture:Boolean = false,
+            priority:int = 0,
+            useWeakReference:Boolean = false
+        ):void {
+            super.addEventListener(type, listener, useCapture, priority, useWeakReference);
+
+            // The invoke event is held back until there is a listener for it.
+            if (type == InvokeEvent.INVOKE && !this._invoked) {
+                this._invoked = true;
+                var self:NativeApplication = this;
+                setTimeout(function():void {
+                    self.dispatchEvent(new InvokeEvent(
+                        InvokeEvent.INVOKE,
+                        false,
+                        false,
+                        File.applicationDirectory,
+                        self.getInvokeArguments()
+                    ));
+                }, 0);
+            }
         }
 
         public static function get nativeApplication():NativeApplication {
@@ -60,9 +88,7 @@ package flash.desktop {
             return false;
         }
 
-        public function exit(exitCode:int = 0):void {
-            stub_method("flash.desktop.NativeApplication", "exit");
-        }
+        public native function exit(exitCode:int = 0):void;
 
         public function get runtimeVersion():String {
             stub_getter("flash.desktop.NativeApplication", "runtimeVersion");
@@ -75,7 +101,11 @@ package flash.desktop {
         }
 
         public function get applicationID():String {
-            stub_getter("flash.desktop.NativeApplication", "applicationID");
+            for each (var child:XML in this.applicationDescriptor.children()) {
+                if (child.localName() == "id") {
+                    return child.toString();
+                }
+            }
             return "";
         }
 
@@ -85,22 +115,20 @@ package flash.desktop {
         }
 
         public function get applicationDescriptor():XML {
-            stub_getter("flash.desktop.NativeApplication", "applicationDescriptor");
+            var descriptor:String = this.getDescriptor();
+            if (descriptor) {
+                return new XML(descriptor);
+            }
 
-            // Return some generic info in the descriptor so that SWFs that read
+            // Without an `application.xml`, return some generic info in the descriptor so that SWFs that read
             // from it don't error out
             var genericDescriptor:XML = <application xmlns="http://ns.adobe.com/air/application/33.1"><id>com.example.Game</id><versionNumber>1.0.0</versionNumber></application>;
             return genericDescriptor;
         }
 
-        public function get autoExit():Boolean {
-            stub_getter("flash.desktop.NativeApplication", "autoExit");
-            return false;
-        }
+        public native function get autoExit():Boolean;
 
-        public function set autoExit(param1:Boolean):void {
-            stub_setter("flash.desktop.NativeApplication", "autoExit");
-        }
+        public native function set autoExit(value:Boolean):void;
 
         public function get icon():InteractiveIcon {
             stub_getter("flash.desktop.NativeApplication", "icon");
@@ -128,32 +156,32 @@ package flash.desktop {
         }
 
         public function activate(window:NativeWindow = null):void {
-            stub_method("flash.desktop.NativeApplication", "activate");
+            if (!window) {
+                window = this.activeWindow;
+            }
+            if (!window) {
+                window = this.openedWindows[0];
+            }
+            if (window) {
+                window.activate();
+            }
         }
 
-        public function get activeWindow():NativeWindow {
-            stub_getter("flash.desktop.NativeApplication", "activeWindow");
-            return _openedWindows[0];
-        }
+        public native function get activeWindow():NativeWindow;
 
-        public function get openedWindows():Array {
-            stub_getter("flash.desktop.NativeApplication", "openedWindows");
-            return _openedWindows;
-        }
+        public native function get openedWindows():Array;
 
-        public function get timeSinceLastUserInput():int {
-            stub_getter("flash.desktop.NativeApplication", "timeSinceLastUserInput");
-            return 100;
-        }
+        public native function get timeSinceLastUserInput():int;
 
-        public function get idleThreshold():int {
-            stub_getter("flash.desktop.NativeApplication", "idleThreshold");
-            return this._idleThreshold;
-        }
+        public native function get idleThreshold():int;
+
+        private native function setIdleThreshold(value:int):void;
 
         public function set idleThreshold(value:int):void {
-            stub_setter("flash.desktop.NativeApplication", "idleThreshold");
-            this._idleThreshold = value;
+            if (value < 5 || value > 86400) {
+                throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
+            }
+            this.setIdleThreshold(value);
         }
 
         public function copy():Boolean {
@@ -219,9 +247,6 @@ package flash.desktop {
 
         // [API("721")] Ruffle doesn't support this API Version
         [API("681")]
-        public function get isActive():Boolean {
-            stub_getter("flash.desktop.NativeApplication", "isActive");
-            return true;
-        }
+        public native function get isActive():Boolean;
     }
 }
@@ -1,20 +1,87 @@
 package flash.desktop {
-    import __ruffle__.stub_method;
-
+    import flash.errors.IllegalOperationError;
     import flash.events.EventDispatcher;
+    import flash.utils.ByteArray;
+    import flash.utils.IDataInput;
+    import flash.utils.IDataOutput;
 
     [API("668")]
     public class NativeProcess extends EventDispatcher {
+        // What the movie writes is sent to the program on the next frame, and what the
+        // program writes is appended to the streams it reads from.
+        private var _standardInput:ByteArray = new ByteArray();
+        private var _standardOutput:ByteArray = new ByteArray();
+        private var _standardError:ByteArray = new ByteArray();
+
         public function NativeProcess() {
             super();
         }
 
-        public static function get isSupported():Boolean {
-            return false;
+        public static native function get isSupported():Boolean;
+
+        private native function startProcess(
+            executable:String,
+            args:Array,
+            workingDirectory:String,
+            standardInput:ByteArray,
+            standardOutput:ByteArray,
+            standardError:ByteArray
+        ):void;
+
+        public native function get running():Boolean;
+
+        public function get standardInput():IDataOutput {
+            return this._standardInput;
+        }
+
+        public function get standardOutput():IDataInput {
+            return this._standardOutput;
+        }
+
+        public function get standardError():IDataInput {
+            return this._standardError;
         }
 
         public function start(info:NativeProcessStartupInfo):void {
-            stub_method("flash.desktop.NativeProcess", "start");
+            if (!NativeProcess.isSupported) {
+                throw new IllegalOperationError(
+                    "Error #3219: The NativeProcess could not be started. 'Not supported in current profile.'",
+                    3219
+                );
+            }
+            if (this.running) {
+                throw new IllegalOperationError("Error #3212: Cannot start a native process that is already running.", 3212);
+            }
+            if (!info.executable) {
+                throw new ArgumentError(
+                    "Error #3214: NativeProcessStartupInfo.executable does not specify a valid executable file.",
+                    3214
+                );
+            }
+
+            var args:Array = [];
+            if (info.arguments) {
+                for each (var arg:String in info.arguments) {
+                    args.push(arg);
+                }
+            }
+            var workingDirectory:String = info.workingDirectory ? info.workingDirectory.nativePath : null;
+
+            this._standardInput.clear();
+            this._standardOutput.clear();
+            this._standardError.clear();
+            this.startProcess(
+                info.executable.nativePath,
+                args,
+                workingDirectory,
+                this._standardInput,
+                this._standardOutput,
+                this._standardError
+            );
         }
+
+        public native function closeInput():void;
+
+        public native function exit(force:Boolean = false):void;
     }
 }
@@ -0,0 +1,165 @@
+//! `flash.desktop.Clipboard` native methods
+
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::object::{ArrayObject, BitmapDataObject, ByteArrayObject, TObject as _};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayStorage, Error, Value};
+use crate::backend::ui::{ClipboardData, ClipboardFormat};
+use crate::bitmap::bitmap_data::{BitmapData, Color};
+use crate::string::{AvmString, WStr};
+use ruffle_render::bitmap::PixelRegion;
+
+/// The names of the clipboard formats the system clipboard can hold.
+const FORMAT_NAMES: [(&str, ClipboardFormat); 5] = [
+    ("air:text", ClipboardFormat::Text),
+    ("air:html", ClipboardFormat::Html),
+    ("air:rtf", ClipboardFormat::RichText),
+    ("air:url", ClipboardFormat::Urls),
+    ("air:bitmap", ClipboardFormat::Bitmap),
+];
+
+fn format_from_name(name: &WStr) -> Option<ClipboardFormat> {
+    FORMAT_NAMES
+        .iter()
+        .find(|(format_name, _)| name == WStr::from_units(format_name.as_bytes()))
+        .map(|(_, format)| *format)
+}
+
+fn format_name(format: ClipboardFormat) -> &'static str {
+    FORMAT_NAMES
+        .iter()
+        .find(|(_, f)| *f == format)
+        .map(|(name, _)| *name)
+        .unwrap_or_default()
+}
+
+/// Implements `Clipboard.systemFormats`
+pub fn system_formats<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let formats = activation.context.ui.clipboard_formats();
+
+    let mut storage = ArrayStorage::new(formats.len());
+    for format in formats {
+        storage.push(AvmString::new_utf8(activation.gc(), format_name(format)).into());
+    }
+
+    Ok(ArrayObject::from_storage(activation.context, storage).into())
+}
+
+/// Implements `Clipboard.readSystemData`
+pub fn read_system_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = args.get_string(activation, 0);
+    let Some(format) = format_from_name(&name) else {
+        return Ok(Value::Null);
+    };
+
+    let value = match activation.context.ui.clipboard_data(format) {
+        Some(ClipboardData::Text(text) | ClipboardData::Html(text)) => {
+            AvmString::new_utf8(activation.gc(), text).into()
+        }
+        Some(ClipboardData::Urls(urls)) => {
+            AvmString::new_utf8(activation.gc(), urls.join("\n")).into()
+        }
+        Some(ClipboardData::RichText(data)) => {
+            let storage = ByteArrayStorage::from_vec(activation.context, data);
+            ByteArrayObject::from_storage(activation.context, storage).into()
+        }
+        Some(ClipboardData::Bitmap {
+            width,
+            height,
+            rgba,
+        }) if rgba.len() == width as usize * height as usize * 4 => {
+            let pixels = rgba
+                .chunks_exact(4)
+                .map(|c| Color::rgba(c[0], c[1], c[2], c[3]).to_premultiplied_alpha(true))
+                .collect();
+            let bitmap_data =
+                BitmapData::new_with_pixels(activation.gc(), width, height, true, pixels);
+            BitmapDataObject::from_bitmap_data(activation.context, bitmap_data).into()
+        }
+        _ => Value::Null,
+    };
+
+    Ok(value)
+}
+
+/// Implements `Clipboard.writeSystemData`
+pub fn write_system_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let formats = args.get_object(activation, 0, "formats")?;
+    let values = args.get_object(activation, 1, "values")?;
+    let formats: Vec<_> = formats.as_array_storage().unwrap().iter().collect();
+    let values: Vec<_> = values.as_array_storage().unwrap().iter().collect();
+
+    let mut data = Vec::with_capacity(formats.len());
+    for (name, value) in formats.into_iter().zip(values) {
+        let name = name
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        let value = value.unwrap_or(Value::Undefined);
+        let Some(format) = format_from_name(&name) else {
+            continue;
+        };
+
+        data.push(match format {
+            ClipboardFormat::Text => {
+                ClipboardData::Text(value.coerce_to_string(activation)?.to_string())
+            }
+            ClipboardFormat::Html => {
+                ClipboardData::Html(value.coerce_to_string(activation)?.to_string())
+            }
+            ClipboardFormat::Urls => {
+                let urls = value.coerce_to_string(activation)?.to_string();
+                ClipboardData::Urls(
+                    urls.lines()
+                        .map(str::trim)
+                        .filter(|url| !url.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                )
+            }
+            ClipboardFormat::RichText => {
+                let Some(bytearray) = value.as_object().and_then(|o| o.as_bytearray()) else {
+                    continue;
+                };
+                ClipboardData::RichText(bytearray.bytes().to_vec())
+            }
+            ClipboardFormat::Bitmap => {
+                let Some(bitmap_data) = value.as_object().and_then(|o| o.as_bitmap_data()) else {
+                    continue;
+                };
+                let (width, height) = (bitmap_data.width(), bitmap_data.height());
+                let read = bitmap_data.read_area(
+                    PixelRegion::for_whole_size(width, height),
+                    activation.context.renderer,
+                );
+                let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
+                for y in 0..height {
+                    for x in 0..width {
+                        let color = read.get_pixel32_raw(x, y).to_un_multiplied_alpha();
+                        rgba.extend([color.red(), color.green(), color.blue(), color.alpha()]);
+                    }
+                }
+                ClipboardData::Bitmap {
+                    width,
+                    height,
+                    rgba,
+                }
+            }
+        });
+    }
+
+    activation.context.ui.set_clipboard_data(data);
+
+    Ok(Value::Undefined)
+}
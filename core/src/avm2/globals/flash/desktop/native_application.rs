@@ -0,0 +1,183 @@
+//! `flash.desktop.NativeApplication` native methods
+
+use crate::avm2::object::ArrayObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayStorage, Error, Value};
+use crate::events::PlayerNotification;
+use crate::native_window::NativeWindows;
+use crate::string::AvmString;
+
+/// The main window is only registered once its `NativeWindow` exists, which is created the
+/// first time `Stage.nativeWindow` is accessed.
+fn ensure_main_window<'gc>(activation: &mut Activation<'_, 'gc>) -> Result<(), Error<'gc>> {
+    if let Some(stage) = NativeWindows::main_stage(activation.context).object2() {
+        Value::from(stage).get_public_property("nativeWindow", activation)?;
+    }
+    Ok(())
+}
+
+/// Implements `NativeApplication.init`
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    activation.context.native_application.set_object(this);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeApplication.getInvokeArguments`
+pub fn get_invoke_arguments<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let arguments: ArrayStorage<'gc> = activation
+        .context
+        .native_application
+        .invoke_arguments()
+        .iter()
+        .map(|argument| AvmString::new_utf8(activation.gc(), argument).into())
+        .collect();
+
+    Ok(ArrayObject::from_storage(activation.context, arguments).into())
+}
+
+/// Implements `NativeApplication.getDescriptor`
+pub fn get_descriptor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation
+        .context
+        .native_application
+        .descriptor()
+        .map_or(Value::Null, |descriptor| {
+            AvmString::new_utf8(activation.gc(), descriptor).into()
+        }))
+}
+
+/// Implements `NativeApplication.exit`
+pub fn exit<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let exit_code = args.get_i32(0);
+    activation
+        .context
+        .send_notification(PlayerNotification::ExitRequested { exit_code });
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeApplication.autoExit`'s getter
+pub fn get_auto_exit<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.native_application.auto_exit().into())
+}
+
+/// Implements `NativeApplication.autoExit`'s setter
+pub fn set_auto_exit<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let auto_exit = args.get_bool(0);
+    activation
+        .context
+        .native_application
+        .set_auto_exit(auto_exit);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeApplication.activeWindow`'s getter
+pub fn get_active_window<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    ensure_main_window(activation)?;
+
+    if !activation.context.native_application.is_active() {
+        return Ok(Value::Null);
+    }
+    Ok(activation
+        .context
+        .native_windows
+        .active_object()
+        .map_or(Value::Null, |window| window.into()))
+}
+
+/// Implements `NativeApplication.openedWindows`'s getter
+pub fn get_opened_windows<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    ensure_main_window(activation)?;
+
+    let windows: ArrayStorage<'gc> = activation
+        .context
+        .native_windows
+        .objects()
+        .map(Value::from)
+        .collect();
+
+    Ok(ArrayObject::from_storage(activation.context, windows).into())
+}
+
+/// Implements `NativeApplication.timeSinceLastUserInput`'s getter
+pub fn get_time_since_last_user_input<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let time = activation.context.native_application.time_since_input();
+
+    Ok(((time.as_millis() / 1000.0) as i32).into())
+}
+
+/// Implements `NativeApplication.idleThreshold`'s getter
+pub fn get_idle_threshold<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation
+        .context
+        .native_application
+        .idle_threshold()
+        .into())
+}
+
+/// Implements `NativeApplication.setIdleThreshold`
+pub fn set_idle_threshold<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let idle_threshold = args.get_u32(0);
+    activation
+        .context
+        .native_application
+        .set_idle_threshold(idle_threshold);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeApplication.isActive`'s getter
+pub fn get_is_active<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.native_application.is_active().into())
+}
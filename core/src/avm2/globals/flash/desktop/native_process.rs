@@ -0,0 +1,91 @@
+//! `flash.desktop.NativeProcess` native methods
+
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Value};
+use crate::backend::process::NativeProcessStartupInfo;
+
+/// Implements `NativeProcess.isSupported`
+pub fn get_is_supported<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.process.is_supported().into())
+}
+
+/// Implements `NativeProcess.startProcess`
+pub fn start_process<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let executable = args.get_string(activation, 0).to_string();
+    let arguments = args.get_object(activation, 1, "arguments")?;
+    let arguments: Vec<_> = arguments.as_array_storage().unwrap().iter().collect();
+    let arguments = arguments
+        .into_iter()
+        .map(|argument| {
+            argument
+                .unwrap_or(Value::Undefined)
+                .coerce_to_string(activation)
+                .map(|argument| argument.to_string())
+        })
+        .collect::<Result<_, _>>()?;
+    let working_directory = args.try_get_string(2).map(|path| path.to_string());
+
+    let standard_input = args.get_object(activation, 3, "standardInput")?;
+    let standard_output = args.get_object(activation, 4, "standardOutput")?;
+    let standard_error = args.get_object(activation, 5, "standardError")?;
+    let streams = [standard_input, standard_output, standard_error]
+        .map(|stream| stream.as_bytearray_object().unwrap());
+
+    let info = NativeProcessStartupInfo {
+        executable,
+        arguments,
+        working_directory,
+    };
+    let context = &mut activation.context;
+    context
+        .native_processes
+        .start(context.process, this, streams, info);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeProcess.running`
+pub fn get_running<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    Ok(activation.context.native_processes.is_running(this).into())
+}
+
+/// Implements `NativeProcess.closeInput`
+pub fn close_input<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    activation.context.native_processes.close_input(this);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeProcess.exit`
+pub fn exit<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let force = args.get_bool(0);
+    activation.context.native_processes.exit(this, force);
+
+    Ok(Value::Undefined)
+}
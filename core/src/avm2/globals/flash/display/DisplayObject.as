@@ -1,6 +1,4 @@
 package flash.display {
-    import __ruffle__.stub_method;
-
     import flash.accessibility.AccessibilityProperties;
     import flash.geom.Rectangle;
     import flash.geom.Transform;
@@ -129,16 +127,20 @@ package flash.display {
 
         [API("662")]
         public function local3DToGlobal(point3d:Vector3D):Point {
-            stub_method("flash.display.DisplayObject", "local3DToGlobal");
-            return new Point(0, 0);
+            return this.projectLocalPoint(point3d.x, point3d.y, point3d.z);
         }
 
+        private native function projectLocalPoint(x:Number, y:Number, z:Number):Point;
+
         [API("662")]
         public function globalToLocal3D(point:Point):Vector3D {
-            stub_method("flash.display.DisplayObject", "globalToLocal3D");
-            return new Vector3D(0, 0, 0);
+            // The point on this object's plane, which is at z = 0 in its own space.
+            var local:Point = this.unprojectGlobalPoint(point.x, point.y);
+            return new Vector3D(local.x, local.y, 0);
         }
 
+        private native function unprojectGlobalPoint(x:Number, y:Number):Point;
+
         public native function getBounds(targetCoordinateSpace:DisplayObject):Rectangle;
 
         public native function getRect(targetCoordinateSpace:DisplayObject):Rectangle;
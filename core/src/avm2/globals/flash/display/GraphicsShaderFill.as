@@ -3,8 +3,10 @@ package flash.display {
 
     [API("662")]
     public final class GraphicsShaderFill implements IGraphicsFill, IGraphicsData {
+        [Ruffle(NativeAccessible)]
         public var shader:Shader;
 
+        [Ruffle(NativeAccessible)]
         public var matrix:Matrix;
 
         public function GraphicsShaderFill(shader:Shader = null, matrix:Matrix = null) {
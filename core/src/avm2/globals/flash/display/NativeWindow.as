@@ -1,131 +1,212 @@
 package flash.display {
     import __ruffle__.stub_method;
     import __ruffle__.stub_getter;
-    import __ruffle__.stub_setter;
-    import __ruffle__.stub_constructor;
 
     import flash.geom.Point;
     import flash.geom.Rectangle;
-    import flash.events.NativeWindowBoundsEvent;
-    import flash.events.Event;
     import flash.events.EventDispatcher;
+    import flash.events.NativeWindowDisplayStateEvent;
     import flash.desktop.NativeApplication;
 
     [API("661")]
     public class NativeWindow extends EventDispatcher {
         public const systemMaxSize:Point = new Point(2880, 2880);
         public const systemMinSize:Point = new Point(1, 1);
-        public var minSize:Point = systemMinSize;
-        public var maxSize:Point = systemMaxSize;
-        public var title:String;
-        public var alwaysInFront:Boolean = true;
-        public var visible:Boolean = true;
 
-        private var _bounds:Rectangle;
+        private var _minSize:Point = systemMinSize;
+        private var _maxSize:Point = systemMaxSize;
+        private var _title:String;
+        private var _alwaysInFront:Boolean = false;
+        private var _visible:Boolean;
+
         private var _maximizable:Boolean;
         private var _minimizable:Boolean;
         private var _resizable:Boolean;
         private var _systemChrome:String;
         private var _transparent:Boolean;
         private var _type:String;
-        private var _closed:Boolean = false;
+        private var _owner:NativeWindow;
         private var _stage:Stage;
 
         // TODO: FP does not have the `_stage` parameter, we should be constructing
         // the NativeWindow as a native object
         public function NativeWindow(initOptions:NativeWindowInitOptions, _stage:Stage = null) {
-            stub_constructor("flash.display.NativeWindow");
-            NativeApplication.nativeApplication.openedWindows.push(this);
-            if (_stage) {
-                this._stage = _stage;
-                _stage.addEventListener(Event.RESIZE, function(e:Event):void {
-                    dispatchEvent(new NativeWindowBoundsEvent(NativeWindowBoundsEvent.RESIZE, false, false, _bounds, _bounds = new Rectangle(x, y, width, height)));
-                });
-            }
-
             _maximizable = initOptions.maximizable;
             _minimizable = initOptions.minimizable;
             _resizable = initOptions.resizable;
             _systemChrome = initOptions.systemChrome;
             _transparent = initOptions.transparent;
             _type = initOptions.type;
+            _owner = initOptions.owner;
+
+            // The main window is already shown, new windows are hidden until activated.
+            _visible = _stage != null;
+
+            this._stage = this.init(
+                _stage,
+                _systemChrome != NativeWindowSystemChrome.NONE,
+                _transparent,
+                _type != NativeWindowType.NORMAL,
+                _resizable,
+                _maximizable,
+                _minimizable,
+                _owner
+            );
+        }
+
+        // Opens the window, or registers the main window if `mainStage` is given,
+        // and returns the stage displayed in it.
+        private native function init(
+            mainStage:Stage,
+            systemChrome:Boolean,
+            transparent:Boolean,
+            utility:Boolean,
+            resizable:Boolean,
+            maximizable:Boolean,
+            minimizable:Boolean,
+            owner:NativeWindow
+        ):Stage;
+
+        private native function setTitle(title:String):void;
+
+        private native function setVisible(visible:Boolean):void;
+
+        private native function setAlwaysInFront(alwaysInFront:Boolean):void;
+
+        private native function setSizeLimits(minWidth:Number, minHeight:Number, maxWidth:Number, maxHeight:Number):void;
+
+        private native function setDisplayState(state:String):void;
+
+        private native function closeWindow():void;
+
+        private native function activateWindow():void;
+
+        public native function get bounds():Rectangle;
+
+        public native function set bounds(value:Rectangle):void;
+
+        public native function get displayState():String;
+
+        public native function get active():Boolean;
+
+        public native function get closed():Boolean;
+
+
+        public native function startMove():Boolean;
+
+        public native function startResize(edgeOrCorner:String = "BR"):Boolean;
+
+        public function activate():void {
+            this.visible = true;
+            this.activateWindow();
+        }
+
+        public function get x():Number {
+            return this.bounds.x;
+        }
+
+        public function set x(value:Number):void {
+            var bounds:Rectangle = this.bounds;
+            bounds.x = value;
+            this.bounds = bounds;
+        }
+
+        public function get y():Number {
+            return this.bounds.y;
+        }
+
+        public function set y(value:Number):void {
+            var bounds:Rectangle = this.bounds;
+            bounds.y = value;
+            this.bounds = bounds;
         }
 
         public function get width():Number {
-            stub_getter("flash.display.NativeWindow", "width");
-            return _stage.stageWidth;
+            return this.bounds.width;
         }
 
         public function set width(value:Number):void {
-            stub_setter("flash.display.NativeWindow", "width");
-            _stage.stageWidth = value;
+            var bounds:Rectangle = this.bounds;
+            bounds.width = value;
+            this.bounds = bounds;
         }
 
         public function get height():Number {
-            stub_getter("flash.display.NativeWindow", "height");
-            return _stage.stageHeight;
+            return this.bounds.height;
         }
 
         public function set height(value:Number):void {
-            stub_setter("flash.display.NativeWindow", "height");
-            _stage.stageHeight = value;
+            var bounds:Rectangle = this.bounds;
+            bounds.height = value;
+            this.bounds = bounds;
         }
 
-        public function get x():Number {
-            stub_getter("flash.display.NativeWindow", "x");
-            return _stage.x;
+        public function get title():String {
+            return _title;
         }
 
-        public function set x(value:Number):void {
-            stub_setter("flash.display.NativeWindow", "x");
+        public function set title(value:String):void {
+            _title = value;
+            this.setTitle(value == null ? "" : value);
         }
 
-        public function get y():Number {
-            stub_getter("flash.display.NativeWindow", "y");
-            return _stage.y;
+        public function get visible():Boolean {
+            return _visible;
         }
 
-        public function set y(value:Number):void {
-            stub_setter("flash.display.NativeWindow", "y");
+        public function set visible(value:Boolean):void {
+            _visible = value;
+            this.setVisible(value);
         }
 
-        public function get bounds():Rectangle {
-            stub_getter("flash.display.NativeWindow", "bounds");
-            return _bounds;
+        public function get alwaysInFront():Boolean {
+            return _alwaysInFront;
         }
 
-        public function set bounds(value:Rectangle):void {
-            stub_setter("flash.display.NativeWindow", "bounds");
-            _bounds = value;
+        public function set alwaysInFront(value:Boolean):void {
+            _alwaysInFront = value;
+            this.setAlwaysInFront(value);
+        }
+
+        public function get minSize():Point {
+            return _minSize;
+        }
+
+        public function set minSize(value:Point):void {
+            _minSize = value;
+            this.setSizeLimits(_minSize.x, _minSize.y, _maxSize.x, _maxSize.y);
+        }
+
+        public function get maxSize():Point {
+            return _maxSize;
+        }
+
+        public function set maxSize(value:Point):void {
+            _maxSize = value;
+            this.setSizeLimits(_minSize.x, _minSize.y, _maxSize.x, _maxSize.y);
         }
 
         public function get maximizable():Boolean {
-            stub_getter("flash.display.NativeWindow", "maximizable");
             return _maximizable;
         }
 
         public function get minimizable():Boolean {
-            stub_getter("flash.display.NativeWindow", "minimizable");
             return _minimizable;
         }
 
         public function get resizable():Boolean {
-            stub_getter("flash.display.NativeWindow", "resizable");
             return _resizable;
         }
 
         public function get systemChrome():String {
-            stub_getter("flash.display.NativeWindow", "systemChrome");
             return _systemChrome;
         }
 
         public function get transparent():Boolean {
-            stub_getter("flash.display.NativeWindow", "transparent");
             return _transparent;
         }
 
         public function get type():String {
-            stub_getter("flash.display.NativeWindow", "type");
             return _type;
         }
 
@@ -133,37 +214,59 @@ package flash.display {
             return _stage;
         }
 
-        public function activate():void {
-            stub_method("flash.display.NativeWindow", "activate");
-            dispatchEvent(new Event(Event.ACTIVATE));
+        // Unlike closing the window from its title bar, this doesn't dispatch `closing`.
+        public function close():void {
+            if (!this.closed) {
+                this.closeWindow();
+            }
         }
 
-        public function close():void {
-            stub_method("flash.display.NativeWindow", "close");
-            if (dispatchEvent(new Event(Event.CLOSING, false, true))) {
-                _closed = true;
-                dispatchEvent(new Event(Event.CLOSE));
-                dispatchEvent(new Event(Event.DEACTIVATE));
+        public function maximize():void {
+            if (_maximizable) {
+                this.changeDisplayState(NativeWindowDisplayState.MAXIMIZED);
             }
         }
 
-        public function globalToScreen(globalPoint:Point):Point {
-            stub_method("flash.display.NativeWindow", "globalToScreen");
-            return null;
+        public function minimize():void {
+            if (_minimizable) {
+                this.changeDisplayState(NativeWindowDisplayState.MINIMIZED);
+            }
         }
 
-        [API("671")]
-        public function listOwnedWindows():Vector.<NativeWindow> {
-            stub_method("flash.display.NativeWindow", "listOwnedWindows");
-            return new Vector.<NativeWindow>();
+        public function restore():void {
+            this.changeDisplayState(NativeWindowDisplayState.NORMAL);
         }
 
-        public function maximize():void {
-            stub_method("flash.display.NativeWindow", "maximize");
+        private function changeDisplayState(state:String):void {
+            var before:String = this.displayState;
+            if (before == state) {
+                return;
+            }
+            var changing:NativeWindowDisplayStateEvent = new NativeWindowDisplayStateEvent(
+                NativeWindowDisplayStateEvent.DISPLAY_STATE_CHANGING,
+                false,
+                true,
+                before,
+                state
+            );
+            if (this.dispatchEvent(changing)) {
+                this.setDisplayState(state);
+            }
         }
 
-        public function minimize():void {
-            stub_method("flash.display.NativeWindow", "minimize");
+        public function globalToScreen(globalPoint:Point):Point {
+            return new Point(this.x + globalPoint.x, this.y + globalPoint.y);
+        }
+
+        [API("671")]
+        public function listOwnedWindows():Vector.<NativeWindow> {
+            var owned:Vector.<NativeWindow> = new Vector.<NativeWindow>();
+            for each (var window:NativeWindow in NativeApplication.nativeApplication.openedWindows) {
+                if (window._owner === this) {
+                    owned.push(window);
+                }
+            }
+            return owned;
         }
 
         public function notifyUser(type:String):void {
@@ -190,44 +293,14 @@ package flash.display {
             return false;
         }
 
-        public function restore():void {
-            stub_method("flash.display.NativeWindow", "restore");
-        }
-
-        public function startMove():Boolean {
-            stub_method("flash.display.NativeWindow", "startMove");
-            return false;
-        }
-
-        public function startResize(edgeOrCorner:String = "BR"):Boolean {
-            stub_method("flash.display.NativeWindow", "startResize");
-            return false;
-        }
-
-        public function get active():Boolean {
-            stub_getter("flash.display.NativeWindow", "active");
-            return true;
-        }
-
-        public function get closed():Boolean {
-            return this._closed;
-        }
-
-        public function get displayState():String {
-            stub_getter("flash.display.NativeWindow", "displayState");
-            return "normal";
-        }
-
         [API("668")]
-        public function get isSupported():Boolean {
-            stub_getter("flash.display.NativeWindow", "isSupported");
-            return false;
+        public static function get isSupported():Boolean {
+            return true;
         }
 
         [API("671")]
         public function get owner():NativeWindow {
-            stub_getter("flash.display.NativeWindow", "owner");
-            return this;
+            return _owner;
         }
 
         [API("675")]
@@ -236,19 +309,16 @@ package flash.display {
             return "auto";
         }
 
-        public function get supportsMenu():Boolean {
-            stub_getter("flash.display.NativeWindow", "supportsMenu");
+        public static function get supportsMenu():Boolean {
             return false;
         }
 
-        public function get supportsNotification():Boolean {
-            stub_getter("flash.display.NativeWindow", "supportsNotification");
+        public static function get supportsNotification():Boolean {
             return false;
         }
 
-        public function get supportsTransparency():Boolean {
-            stub_getter("flash.display.NativeWindow", "supportsTransparency");
-            return false;
+        public static function get supportsTransparency():Boolean {
+            return true;
         }
     }
 }
@@ -405,10 +405,15 @@ package flash.display {
             stub_setter("flash.display.Stage", "constructor");
         }
 
+        // The `NativeWindow` displaying this stage, if it was already created.
+        private native function findNativeWindow():NativeWindow;
+
         // TODO: Construct the NativeWindow as a native object
         [API("661")]
         public function get nativeWindow():NativeWindow {
-            stub_getter("flash.display.Stage", "nativeWindow");
+            if (!this._nativeWindow) {
+                this._nativeWindow = this.findNativeWindow();
+            }
             if (!this._nativeWindow) {
                 this._nativeWindow = new NativeWindow(new NativeWindowInitOptions(), this);
             }
@@ -5,8 +5,8 @@ use crate::avm2::activation::Activation;
 use crate::avm2::error::{make_error_2005, make_error_2007, make_error_2008, make_error_2078};
 use crate::avm2::filters::FilterAvm2Ext;
 use crate::avm2::globals::flash::geom::transform::color_transform_from_transform_object;
-use crate::avm2::globals::flash::geom::transform::has_matrix3d_from_transform_object;
 use crate::avm2::globals::flash::geom::transform::matrix_from_transform_object;
+use crate::avm2::globals::flash::geom::transform::matrix3d_from_transform_object;
 use crate::avm2::globals::slots::flash_display_shader as shader_slots;
 use crate::avm2::globals::slots::flash_geom_point as point_slots;
 use crate::avm2::globals::slots::flash_geom_rectangle as rectangle_slots;
@@ -15,6 +15,7 @@ use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::{ArrayObject, ArrayStorage};
 use crate::avm2::{ClassObject, Error};
+use crate::avm2_stub_getter;
 use crate::context::UpdateContext;
 use crate::display_object::BoundsMode;
 use crate::ecma_conversions::round_to_even;
@@ -22,9 +23,9 @@ use crate::prelude::*;
 use crate::string::AvmString;
 use crate::types::{Degrees, Percent};
 use crate::vminterface::Instantiator;
-use crate::{avm2_stub_getter, avm2_stub_setter};
 use ruffle_render::blend::ExtendedBlendMode;
 use ruffle_render::filters::Filter;
+use ruffle_render::matrix3d::Matrix3D;
 use std::str::FromStr;
 
 /// Initializes a DisplayObject created from ActionScript.
@@ -126,7 +127,6 @@ pub fn get_scale9grid<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "scale9Grid");
     if let Some(dobj) = this.as_display_object() {
         let rect = dobj.scaling_grid();
         return if rect.is_valid() {
@@ -147,7 +147,6 @@ pub fn set_scale9grid<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "scale9Grid");
     if let Some(dobj) = this.as_display_object() {
         let rect = match args.try_get_object(0) {
             None => Rectangle::default(),
@@ -372,92 +371,144 @@ pub fn set_y<'gc>(
 }
 
 pub fn get_z<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "z");
-    Ok(0.into())
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let components = dobj.matrix3d_components();
+        return Ok(components.translation[2].into());
+    }
+
+    Ok(Value::Undefined)
 }
 
 pub fn set_z<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
-    _args: &[Value<'gc>],
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "z");
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let mut components = dobj.matrix3d_components();
+        components.translation[2] = args.get_f64(0);
+        dobj.set_matrix3d_components(components);
+    }
+
     Ok(Value::Undefined)
 }
 
 pub fn get_rotation_x<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationX");
-    Ok(0.into())
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let components = dobj.matrix3d_components();
+        return Ok(components.rotation[0].to_degrees().into());
+    }
+
+    Ok(Value::Undefined)
 }
 
 pub fn set_rotation_x<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
-    _args: &[Value<'gc>],
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationX");
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let mut components = dobj.matrix3d_components();
+        components.rotation[0] = args.get_f64(0).to_radians();
+        dobj.set_matrix3d_components(components);
+    }
+
     Ok(Value::Undefined)
 }
 
 pub fn get_rotation_y<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationY");
-    Ok(0.into())
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let components = dobj.matrix3d_components();
+        return Ok(components.rotation[1].to_degrees().into());
+    }
+
+    Ok(Value::Undefined)
 }
 
 pub fn set_rotation_y<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
-    _args: &[Value<'gc>],
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationY");
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let mut components = dobj.matrix3d_components();
+        components.rotation[1] = args.get_f64(0).to_radians();
+        dobj.set_matrix3d_components(components);
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Implements `rotationZ`'s getter, which is the same as `rotation`'s.
 pub fn get_rotation_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
-    _args: &[Value<'gc>],
+    this: Value<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationZ");
-    Ok(0.into())
+    get_rotation(activation, this, args)
 }
 
+/// Implements `rotationZ`'s setter, which is the same as `rotation`'s.
 pub fn set_rotation_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
-    _args: &[Value<'gc>],
+    this: Value<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationZ");
-    Ok(Value::Undefined)
+    set_rotation(activation, this, args)
 }
 
 pub fn get_scale_z<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "scaleZ");
-    Ok(1.into())
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let components = dobj.matrix3d_components();
+        return Ok(components.scale[2].into());
+    }
+
+    Ok(Value::Undefined)
 }
 
 pub fn set_scale_z<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
-    _args: &[Value<'gc>],
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "scaleZ");
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let mut components = dobj.matrix3d_components();
+        components.scale[2] = args.get_f64(0);
+        dobj.set_matrix3d_components(components);
+    }
+
     Ok(Value::Undefined)
 }
 
@@ -808,14 +859,16 @@ pub fn set_transform<'gc>(
 
     // FIXME - consider pixel bounds
     let matrix = matrix_from_transform_object(transform);
-    let has_matrix3d = has_matrix3d_from_transform_object(transform);
+    let matrix3d = matrix3d_from_transform_object(transform);
     let color_transform = color_transform_from_transform_object(transform);
 
     let dobj = this.as_display_object().unwrap();
-    let base = dobj.base();
-    base.set_matrix(matrix);
-    base.set_has_matrix3d_stub(has_matrix3d);
-    base.set_color_transform(color_transform);
+    if matrix3d.is_some() {
+        dobj.set_matrix3d(matrix3d);
+    } else {
+        dobj.set_matrix(matrix);
+    }
+    dobj.base().set_color_transform(color_transform);
     if let Some(parent) = dobj.parent() {
         // Self-transform changes are automatically handled,
         // we only want to inform ancestors to avoid unnecessary invalidations for tx/ty
@@ -861,7 +914,7 @@ pub fn set_blend_mode<'gc>(
     Ok(Value::Undefined)
 }
 
-fn new_rectangle<'gc>(
+pub fn new_rectangle<'gc>(
     activation: &mut Activation<'_, 'gc>,
     rectangle: Rectangle<Twips>,
 ) -> Result<Value<'gc>, Error<'gc>> {
@@ -973,6 +1026,52 @@ pub fn local_to_global<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn unproject_global_point<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let global = Point::from_pixels(args.get_f64(0), args.get_f64(1));
+
+        // Bring the point into the parent's space, then follow the ray of the
+        // projection back to where it meets the object's plane.
+        let in_parent = match dobj.parent() {
+            Some(parent) if parent.as_stage().is_none() => parent.global_to_local(global),
+            _ => Some(global),
+        };
+        let matrix3d = dobj
+            .base()
+            .matrix3d()
+            .unwrap_or_else(|| Matrix3D::from_matrix(dobj.base().matrix()));
+        let local = in_parent.and_then(|point| {
+            (dobj.parent_projection_matrix() * matrix3d)
+                .inverse_transform_plane_point(point.x.to_pixels(), point.y.to_pixels())
+        });
+
+        // A plane seen edge-on has no point under the given position.
+        let (x, y) = match local {
+            Some((x, y)) => {
+                let mut local = Point::from_pixels(x, y);
+                if let Some(rect) = dobj.scroll_rect() {
+                    local = Matrix::translate(rect.x_min, rect.y_min) * local;
+                }
+                (local.x.to_pixels(), local.y.to_pixels())
+            }
+            None => (f64::NAN, f64::NAN),
+        };
+        return activation
+            .avm2()
+            .classes()
+            .point
+            .construct(activation, &[x.into(), y.into()]);
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn global_to_local<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Value<'gc>,
@@ -1000,6 +1099,49 @@ pub fn global_to_local<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements the projection behind `DisplayObject.local3DToGlobal`.
+pub fn project_local_point<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(dobj) = this.as_display_object() {
+        let x = args.get_f64(0);
+        let y = args.get_f64(1);
+        let z = args.get_f64(2);
+
+        // Project the point into the parent's space just like the object itself would be.
+        let mut local = Point::from_pixels(x, y);
+        if let Some(rect) = dobj.scroll_rect() {
+            local = Matrix::translate(-rect.x_min, -rect.y_min) * local;
+        }
+        let matrix3d = dobj
+            .base()
+            .matrix3d()
+            .unwrap_or_else(|| Matrix3D::from_matrix(dobj.base().matrix()));
+        let (x, y, _) = (dobj.parent_projection_matrix() * matrix3d).transform_point(
+            local.x.to_pixels(),
+            local.y.to_pixels(),
+            z,
+        );
+
+        let mut global = Point::from_pixels(x, y);
+        if let Some(parent) = dobj.parent()
+            && parent.as_stage().is_none()
+        {
+            global = parent.local_to_global(global);
+        }
+        return activation.avm2().classes().point.construct(
+            activation,
+            &[global.x.to_pixels().into(), global.y.to_pixels().into()],
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn get_bounds<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Value<'gc>,
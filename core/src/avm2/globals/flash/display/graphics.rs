@@ -4,24 +4,37 @@
 #![allow(clippy::doc_lazy_continuation)]
 
 use crate::avm2::activation::Activation;
-use crate::avm2::error::{Error2004Type, make_error_2004, make_error_2007, make_error_2008};
-use crate::avm2::globals::flash::geom::transform::object_to_matrix;
+use crate::avm2::error::{
+    Error2004Type, make_error_2004, make_error_2007, make_error_2008, make_error_2162,
+};
+use crate::avm2::globals::flash::display::shader_job::get_shader_args;
+use crate::avm2::globals::flash::geom::transform::{matrix_to_object, object_to_matrix};
 use crate::avm2::globals::slots::flash_display_graphics_bitmap_fill as graphics_bitmap_fill_slots;
 use crate::avm2::globals::slots::flash_display_graphics_gradient_fill as graphics_gradient_fill_slots;
 use crate::avm2::globals::slots::flash_display_graphics_path as graphics_path_slots;
+use crate::avm2::globals::slots::flash_display_graphics_shader_fill as graphics_shader_fill_slots;
 use crate::avm2::globals::slots::flash_display_graphics_solid_fill as graphics_solid_fill_slots;
 use crate::avm2::globals::slots::flash_display_graphics_stroke as graphics_stroke_slots;
 use crate::avm2::globals::slots::flash_display_graphics_triangle_path as graphics_triangle_path_slots;
-use crate::avm2::object::{Object, TObject as _, VectorObject};
+use crate::avm2::object::{ArrayObject, BitmapDataObject, Object, TObject as _, VectorObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::vector::VectorStorage;
 use crate::avm2::{ArrayStorage, Error};
 use crate::avm2_stub_method;
-use crate::display_object::TDisplayObject;
+use crate::bitmap::bitmap_data::BitmapData;
+use crate::context::UpdateContext;
+use crate::display_object::{DisplayObject, TDisplayObject, TDisplayObjectContainer};
 use crate::drawing::Drawing;
 use crate::string::{AvmString, WStr};
-use ruffle_render::shape_utils::{DrawCommand, FillRule, GradientType};
+use ruffle_render::backend::RenderBackend;
+use ruffle_render::bitmap::{BitmapInfo, BitmapSource, PixelRegion, PixelSnapping};
+use ruffle_render::commands::{CommandHandler, CommandList};
+use ruffle_render::matrix::Matrix as RenderMatrix;
+use ruffle_render::pixel_bender::PixelBenderShaderHandle;
+use ruffle_render::pixel_bender_support::PixelBenderShaderArgument;
+use ruffle_render::shape_utils::{DistilledShape, DrawCommand, DrawPath, FillRule, GradientType};
+use ruffle_render::transform::Transform;
 use std::f64::consts::FRAC_1_SQRT_2;
 use swf::{
     Color, FillStyle, Fixed8, Fixed16, Gradient, GradientInterpolation, GradientRecord,
@@ -1339,16 +1352,494 @@ pub fn line_bitmap_style<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Graphics.beginShaderFill`
+pub fn begin_shader_fill<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(this) = this.as_display_object() {
+        let shader = args.get_object(activation, 0, "shader")?;
+        let matrix = if let Some(matrix) = args.try_get_object(1) {
+            Matrix::from(object_to_matrix(matrix, activation)?)
+        } else {
+            // Users can explicitly pass in `null` to mean identity matrix
+            Matrix::IDENTITY
+        };
+
+        let shader = shader_fill_args(activation, shader)?;
+
+        if let Some(mut draw) = this.as_drawing() {
+            let style = shader_fill_style(&mut draw, shader, matrix);
+            draw.set_fill_style(Some(style));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.lineShaderStyle`
+pub fn line_shader_style<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(this) = this.as_display_object() {
+        let shader = args.get_object(activation, 0, "shader")?;
+        let matrix = if let Some(matrix) = args.try_get_object(1) {
+            Matrix::from(object_to_matrix(matrix, activation)?)
+        } else {
+            // Users can explicitly pass in `null` to mean identity matrix
+            Matrix::IDENTITY
+        };
+
+        let shader = shader_fill_args(activation, shader)?;
+
+        if let Some(mut draw) = this.as_drawing() {
+            let style = shader_fill_style(&mut draw, shader, matrix);
+            draw.set_line_fill_style(style);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Checks that `shader` can be used as a fill, and returns the shader to run
+/// with its arguments.
+fn shader_fill_args<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    shader: Object<'gc>,
+) -> Result<
+    (
+        PixelBenderShaderHandle,
+        Vec<PixelBenderShaderArgument<'static>>,
+    ),
+    Error<'gc>,
+> {
+    let (shader_handle, arguments) = get_shader_args(shader, activation)?;
+
+    match shader_handle.0.parsed_shader().output_channels() {
+        Some(3) | Some(4) => Ok((shader_handle, arguments)),
+        channels => {
+            tracing::warn!(
+                "Unsupported number of shader output channels: {channels:?}, expected 3 or 4"
+            );
+            Err(make_error_2162(activation))
+        }
+    }
+}
+
+/// Builds the style of a shader fill, which is rendered as a repeating bitmap
+/// once the paths it fills are known.
+fn shader_fill_style(
+    drawing: &mut Drawing,
+    (shader, arguments): (
+        PixelBenderShaderHandle,
+        Vec<PixelBenderShaderArgument<'static>>,
+    ),
+    matrix: Matrix,
+) -> FillStyle {
+    let scale_matrix = Matrix::scale(
+        (Twips::TWIPS_PER_PIXEL as i16).into(),
+        (Twips::TWIPS_PER_PIXEL as i16).into(),
+    );
+
+    let id = drawing.add_shader_fill(shader, arguments);
+    FillStyle::Bitmap {
+        id,
+        matrix: matrix * scale_matrix,
+        is_smoothed: false,
+        is_repeating: true,
+    }
+}
+
 /// Implements `Graphics.readGraphicsData`
 pub fn read_graphics_data<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
-    _args: &[Value<'gc>],
+    this: Value<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "readGraphicsData");
+    let this = this.as_object().unwrap();
+    let recurse = args.get_bool(0);
+
+    let mut records = Vec::new();
+    if let Some(this) = this.as_display_object() {
+        read_object_graphics(activation.context, this, recurse, &mut records);
+    }
+
+    let values = records
+        .into_iter()
+        .map(|record| record.into_object(activation))
+        .collect::<Result<Vec<_>, _>>()?;
+
     let value_type = activation.avm2().class_defs().igraphicsdata;
-    let new_storage = VectorStorage::new(0, false, Some(value_type));
-    Ok(VectorObject::from_vector(new_storage, activation).into())
+    let storage = VectorStorage::from_values(values, false, Some(value_type));
+    Ok(VectorObject::from_vector(storage, activation).into())
+}
+
+/// A graphics data object read back by `Graphics.readGraphicsData`.
+enum GraphicsRecord {
+    Fill(ReadFill),
+    EndFill,
+    Stroke(LineStyle, Option<ReadFill>),
+    EndStroke,
+    Path(Vec<DrawCommand>, FillRule),
+}
+
+/// A fill read back by `Graphics.readGraphicsData`, with its bitmap resolved.
+enum ReadFill {
+    /// A solid or gradient fill.
+    Style(FillStyle),
+    Bitmap {
+        bitmap: BitmapInfo,
+        matrix: RenderMatrix,
+        is_repeating: bool,
+        is_smoothed: bool,
+    },
+}
+
+impl ReadFill {
+    /// Reads `style`.
+    ///
+    /// Returns `None` for a bitmap fill whose bitmap is missing.
+    fn new(
+        style: &FillStyle,
+        source: &dyn BitmapSource,
+        renderer: &mut dyn RenderBackend,
+    ) -> Option<Self> {
+        let fill = match style {
+            FillStyle::Bitmap {
+                id,
+                matrix,
+                is_smoothed,
+                is_repeating,
+            } => {
+                let size = source.bitmap_size(*id)?;
+                let handle = source.bitmap_handle(*id, renderer)?;
+                let pixel_scale = RenderMatrix::scale(
+                    1.0 / Twips::TWIPS_PER_PIXEL as f32,
+                    1.0 / Twips::TWIPS_PER_PIXEL as f32,
+                );
+                Self::Bitmap {
+                    bitmap: BitmapInfo {
+                        handle,
+                        width: size.width,
+                        height: size.height,
+                    },
+                    matrix: RenderMatrix::from(*matrix) * pixel_scale,
+                    is_repeating: *is_repeating,
+                    is_smoothed: *is_smoothed,
+                }
+            }
+            style => Self::Style(style.clone()),
+        };
+        Some(fill)
+    }
+
+    /// Creates the `IGraphicsFill` object for this fill.
+    fn into_object<'gc>(
+        self,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        let (gradient, gradient_type, focal_point) = match self {
+            Self::Style(FillStyle::Color(color)) => {
+                let args = [color.to_rgb().into(), (color.a as f64 / 255.0).into()];
+                return activation
+                    .avm2()
+                    .classes()
+                    .graphicssolidfill
+                    .construct(activation, &args);
+            }
+            Self::Style(FillStyle::LinearGradient(gradient)) => (gradient, "linear", 0.0),
+            Self::Style(FillStyle::RadialGradient(gradient)) => (gradient, "radial", 0.0),
+            Self::Style(FillStyle::FocalGradient {
+                gradient,
+                focal_point,
+            }) => (gradient, "radial", focal_point.to_f64()),
+            Self::Style(FillStyle::Bitmap { .. }) => {
+                unreachable!("Bitmap fills are read with their bitmap")
+            }
+            Self::Bitmap {
+                bitmap,
+                matrix,
+                is_repeating,
+                is_smoothed,
+            } => {
+                let bitmap_data = copy_bitmap(activation, bitmap);
+                let args = [
+                    bitmap_data,
+                    matrix_to_object(matrix, activation)?,
+                    is_repeating.into(),
+                    is_smoothed.into(),
+                ];
+                return activation
+                    .avm2()
+                    .classes()
+                    .graphicsbitmapfill
+                    .construct(activation, &args);
+            }
+        };
+
+        let colors = gradient
+            .records
+            .iter()
+            .map(|record| record.color.to_rgb())
+            .collect();
+        let alphas = gradient
+            .records
+            .iter()
+            .map(|record| record.color.a as f64 / 255.0)
+            .collect();
+        let ratios = gradient
+            .records
+            .iter()
+            .map(|record| record.ratio as u32)
+            .collect();
+        let spread_method = match gradient.spread {
+            GradientSpread::Pad => "pad",
+            GradientSpread::Reflect => "reflect",
+            GradientSpread::Repeat => "repeat",
+        };
+        let interpolation_method = match gradient.interpolation {
+            GradientInterpolation::Rgb => "rgb",
+            GradientInterpolation::LinearRgb => "linearRGB",
+        };
+
+        let args = [
+            AvmString::new_utf8(activation.gc(), gradient_type).into(),
+            ArrayObject::from_storage(activation.context, colors).into(),
+            ArrayObject::from_storage(activation.context, alphas).into(),
+            ArrayObject::from_storage(activation.context, ratios).into(),
+            matrix_to_object(RenderMatrix::from(gradient.matrix), activation)?,
+            AvmString::new_utf8(activation.gc(), spread_method).into(),
+            AvmString::new_utf8(activation.gc(), interpolation_method).into(),
+            focal_point.into(),
+        ];
+        activation
+            .avm2()
+            .classes()
+            .graphicsgradientfill
+            .construct(activation, &args)
+    }
+}
+
+/// Reads the graphics of `object`, and those of its children if `recurse` is set.
+///
+/// Like in Flash, the graphics of each object are read in its own coordinates.
+fn read_object_graphics<'gc>(
+    context: &mut UpdateContext<'gc>,
+    object: DisplayObject<'gc>,
+    recurse: bool,
+    records: &mut Vec<GraphicsRecord>,
+) {
+    let renderer = &mut *context.renderer;
+    if let Some(graphic) = object.as_graphic() {
+        graphic.with_shape(context.library, |shape, source| {
+            read_shape(shape, source, renderer, records)
+        });
+    } else if let Some(mc) = object.as_movie_clip()
+        && let Some(drawing) = mc.drawing()
+    {
+        read_shape(&drawing.distilled_shape(), &*drawing, renderer, records);
+    }
+
+    if recurse && let Some(container) = object.as_container() {
+        for child in container.iter_render_list() {
+            read_object_graphics(context, child, recurse, records);
+        }
+    }
+}
+
+/// Reads the paths of `shape`.
+fn read_shape(
+    shape: &DistilledShape<'_>,
+    source: &dyn BitmapSource,
+    renderer: &mut dyn RenderBackend,
+    records: &mut Vec<GraphicsRecord>,
+) {
+    for path in &shape.paths {
+        match path {
+            DrawPath::Fill {
+                style,
+                commands,
+                winding_rule,
+            } => {
+                let Some(commands) = read_commands(commands) else {
+                    continue;
+                };
+                let Some(fill) = ReadFill::new(style, source, renderer) else {
+                    continue;
+                };
+                records.push(GraphicsRecord::Fill(fill));
+                records.push(GraphicsRecord::Path(commands, *winding_rule));
+                records.push(GraphicsRecord::EndFill);
+            }
+            DrawPath::Stroke {
+                style, commands, ..
+            } => {
+                let Some(commands) = read_commands(commands) else {
+                    continue;
+                };
+                let fill = ReadFill::new(style.fill_style(), source, renderer);
+                records.push(GraphicsRecord::Stroke((*style).clone(), fill));
+                records.push(GraphicsRecord::Path(commands, FillRule::EvenOdd));
+                records.push(GraphicsRecord::EndStroke);
+            }
+        }
+    }
+}
+
+/// Reads the commands of a path, dropping any move immediately followed by another one.
+///
+/// Returns `None` if the path doesn't draw anything.
+fn read_commands(commands: &[DrawCommand]) -> Option<Vec<DrawCommand>> {
+    let mut read: Vec<DrawCommand> = Vec::with_capacity(commands.len());
+    for command in commands {
+        if let DrawCommand::MoveTo(_) = command
+            && let Some(DrawCommand::MoveTo(_)) = read.last()
+        {
+            read.pop();
+        }
+        read.push(command.clone());
+    }
+    if let Some(DrawCommand::MoveTo(_)) = read.last() {
+        read.pop();
+    }
+
+    if read.is_empty() { None } else { Some(read) }
+}
+
+impl GraphicsRecord {
+    /// Creates the `IGraphicsData` object for this record.
+    fn into_object<'gc>(
+        self,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        match self {
+            Self::Fill(fill) => fill.into_object(activation),
+            Self::EndFill => activation
+                .avm2()
+                .classes()
+                .graphicsendfill
+                .construct(activation, &[]),
+            Self::Stroke(style, fill) => {
+                let fill = match fill {
+                    Some(fill) => fill.into_object(activation)?,
+                    None => Value::Null,
+                };
+                let scale_mode = match (style.allow_scale_x(), style.allow_scale_y()) {
+                    (true, true) => "normal",
+                    (true, false) => "horizontal",
+                    (false, true) => "vertical",
+                    (false, false) => "none",
+                };
+                let caps = match style.start_cap() {
+                    LineCapStyle::None => "none",
+                    LineCapStyle::Round => "round",
+                    LineCapStyle::Square => "square",
+                };
+                let (joints, miter_limit) = match style.join_style() {
+                    LineJoinStyle::Round => ("round", 3.0),
+                    LineJoinStyle::Bevel => ("bevel", 3.0),
+                    LineJoinStyle::Miter(limit) => ("miter", limit.to_f64()),
+                };
+                let args = [
+                    style.width().to_pixels().into(),
+                    style.is_pixel_hinted().into(),
+                    AvmString::new_utf8(activation.gc(), scale_mode).into(),
+                    AvmString::new_utf8(activation.gc(), caps).into(),
+                    AvmString::new_utf8(activation.gc(), joints).into(),
+                    miter_limit.into(),
+                    fill,
+                ];
+                activation
+                    .avm2()
+                    .classes()
+                    .graphicsstroke
+                    .construct(activation, &args)
+            }
+            Self::EndStroke => activation
+                .avm2()
+                .classes()
+                .graphicsstroke
+                .construct(activation, &[]),
+            Self::Path(commands, winding_rule) => {
+                let mut command_values = Vec::with_capacity(commands.len());
+                let mut data_values: Vec<Value<'gc>> = Vec::new();
+                for command in commands {
+                    let (command, points) = match command {
+                        DrawCommand::MoveTo(point) => (1, vec![point]),
+                        DrawCommand::LineTo(point) => (2, vec![point]),
+                        DrawCommand::QuadraticCurveTo { control, anchor } => {
+                            (3, vec![control, anchor])
+                        }
+                        DrawCommand::CubicCurveTo {
+                            control_a,
+                            control_b,
+                            anchor,
+                        } => (6, vec![control_a, control_b, anchor]),
+                    };
+                    command_values.push(Value::Integer(command));
+                    for point in points {
+                        data_values.push(point.x.to_pixels().into());
+                        data_values.push(point.y.to_pixels().into());
+                    }
+                }
+
+                let int_class = activation.avm2().class_defs().int;
+                let number_class = activation.avm2().class_defs().number;
+                let commands = VectorStorage::from_values(command_values, false, Some(int_class));
+                let data = VectorStorage::from_values(data_values, false, Some(number_class));
+                let winding = match winding_rule {
+                    FillRule::EvenOdd => "evenOdd",
+                    FillRule::NonZero => "nonZero",
+                };
+                let args = [
+                    VectorObject::from_vector(commands, activation).into(),
+                    VectorObject::from_vector(data, activation).into(),
+                    AvmString::new_utf8(activation.gc(), winding).into(),
+                ];
+                activation
+                    .avm2()
+                    .classes()
+                    .graphicspath
+                    .construct(activation, &args)
+            }
+        }
+    }
+}
+
+/// Copies the pixels of `bitmap` into a new `BitmapData`.
+fn copy_bitmap<'gc>(activation: &mut Activation<'_, 'gc>, bitmap: BitmapInfo) -> Value<'gc> {
+    let bitmap_data = BitmapData::new(activation.gc(), bitmap.width, bitmap.height, true, 0);
+    let target = bitmap_data.bitmap_handle(activation.gc(), activation.context.renderer);
+
+    let mut commands = CommandList::new();
+    commands.render_bitmap(
+        bitmap.handle,
+        Transform::default(),
+        false,
+        PixelSnapping::Never,
+    );
+
+    let region = PixelRegion::for_whole_size(bitmap.width, bitmap.height);
+    let quality = activation.context.stage.quality();
+    if let Some(sync_handle) = activation
+        .context
+        .renderer
+        .render_offscreen(target, commands, quality, region)
+    {
+        let (write, _) = bitmap_data.overwrite_cpu_pixels_from_gpu(activation.gc());
+        write
+            .borrow_mut(activation.gc())
+            .set_gpu_dirty(activation.gc(), sync_handle, region);
+    }
+
+    BitmapDataObject::from_bitmap_data(activation.context, bitmap_data).into()
 }
 
 fn read_point<'gc>(
@@ -1504,8 +1995,8 @@ fn handle_igraphics_data<'gc>(
         let style = handle_solid_fill(activation, obj)?;
         drawing.set_fill_style(Some(style));
     } else if class == activation.avm2().class_defs().graphicsshaderfill {
-        tracing::warn!("Graphics shader fill unimplemented {:?}", class);
-        drawing.set_fill_style(None);
+        let style = handle_shader_fill(activation, drawing, obj)?;
+        drawing.set_fill_style(style);
     } else if class == activation.avm2().class_defs().graphicsstroke {
         let thickness = obj
             .get_slot(graphics_stroke_slots::THICKNESS)
@@ -1630,8 +2121,7 @@ fn handle_igraphics_fill<'gc>(
         let style = handle_solid_fill(activation, obj)?;
         Ok(Some(style))
     } else if class == activation.avm2().class_defs().graphicsshaderfill {
-        tracing::warn!("Graphics shader fill unimplemented {:?}", class);
-        Ok(None)
+        handle_shader_fill(activation, drawing, obj)
     } else {
         tracing::warn!("Unknown graphics fill class {:?}", class);
         Ok(None)
@@ -1798,3 +2288,28 @@ fn handle_bitmap_fill<'gc>(
 
     Ok(style)
 }
+
+fn handle_shader_fill<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    drawing: &mut Drawing,
+    obj: &Object<'gc>,
+) -> Result<Option<FillStyle>, Error<'gc>> {
+    let shader = obj
+        .get_slot(graphics_shader_fill_slots::SHADER)
+        .as_object()
+        .ok_or_else(|| make_error_2007(activation, "shader"))?;
+
+    let matrix = obj
+        .get_slot(graphics_shader_fill_slots::MATRIX)
+        .as_object()
+        .and_then(|matrix| {
+            let matrix = Matrix::from(object_to_matrix(matrix, activation).ok()?);
+
+            Some(matrix)
+        })
+        .unwrap_or(Matrix::IDENTITY);
+
+    let shader = shader_fill_args(activation, shader)?;
+
+    Ok(Some(shader_fill_style(drawing, shader, matrix)))
+}
@@ -0,0 +1,307 @@
+//! `flash.display.NativeWindow` native methods
+
+use crate::avm2::globals::slots::flash_geom_rectangle as rectangle_slots;
+use crate::avm2::object::{Object, TObject as _};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Value};
+use crate::backend::ui::{
+    NativeWindowBounds, NativeWindowDisplayState, NativeWindowEdge, NativeWindowId,
+    NativeWindowOptions,
+};
+use crate::display_object::{DisplayObject, TDisplayObject};
+use crate::events::NativeWindowEvent;
+use crate::native_window::{NativeWindows, display_state_name};
+use crate::string::AvmString;
+
+/// The window of a `NativeWindow` object, if it's still open.
+fn window_id<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+) -> Option<NativeWindowId> {
+    let this = this.as_object()?;
+    activation.context.native_windows.id_of(this)
+}
+
+/// Implements `NativeWindow.init`
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(main_stage) = args
+        .try_get_object(0)
+        .and_then(|stage| stage.as_display_object())
+        .and_then(|stage| stage.as_stage())
+    {
+        // A stage that isn't the player's belongs to a closed window, which stays closed.
+        let player_stage = NativeWindows::main_stage(activation.context);
+        if DisplayObject::ptr_eq(main_stage.into(), player_stage.into()) {
+            NativeWindows::open_main(activation.context, this);
+        }
+        return Ok(main_stage
+            .object2()
+            .map_or(Value::Null, |stage| stage.into()));
+    }
+
+    let owner = args
+        .try_get_object(7)
+        .and_then(|owner| activation.context.native_windows.id_of(owner));
+    let options = NativeWindowOptions {
+        title: String::new(),
+        bounds: None,
+        system_chrome: args.get_bool(1),
+        transparent: args.get_bool(2),
+        utility: args.get_bool(3),
+        resizable: args.get_bool(4),
+        maximizable: args.get_bool(5),
+        minimizable: args.get_bool(6),
+        owner,
+    };
+
+    let stage = NativeWindows::open(activation.context, this, options);
+    Ok(stage.object2().map_or(Value::Null, |stage| stage.into()))
+}
+
+/// Implements `NativeWindow.setTitle`
+pub fn set_title<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let title = args.get_string(activation, 0);
+    if let Some(id) = window_id(activation, this) {
+        activation
+            .context
+            .ui
+            .set_native_window_title(id, &title.to_utf8_lossy());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.setVisible`
+pub fn set_visible<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(id) = window_id(activation, this) {
+        activation
+            .context
+            .ui
+            .set_native_window_visible(id, args.get_bool(0));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.setAlwaysInFront`
+pub fn set_always_in_front<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(id) = window_id(activation, this) {
+        activation
+            .context
+            .ui
+            .set_native_window_always_in_front(id, args.get_bool(0));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.setSizeLimits`
+pub fn set_size_limits<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let [min_width, min_height, max_width, max_height] =
+        [0, 1, 2, 3].map(|index| args.get_f64(index).max(0.0) as u32);
+    if let Some(id) = window_id(activation, this) {
+        activation.context.ui.set_native_window_size_limits(
+            id,
+            (min_width, min_height),
+            (max_width, max_height),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.setDisplayState`
+pub fn set_display_state<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let state = args.get_string(activation, 0);
+    let state = if &state == b"maximized" {
+        NativeWindowDisplayState::Maximized
+    } else if &state == b"minimized" {
+        NativeWindowDisplayState::Minimized
+    } else {
+        NativeWindowDisplayState::Normal
+    };
+
+    if let Some(id) = window_id(activation, this) {
+        activation
+            .context
+            .ui
+            .set_native_window_display_state(id, state);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.closeWindow`
+pub fn close_window<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(id) = window_id(activation, this) {
+        NativeWindows::close(activation.context, id);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.activateWindow`
+pub fn activate_window<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(id) = window_id(activation, this) {
+        activation.context.ui.activate_native_window(id);
+        NativeWindows::handle_event(activation.context, id, NativeWindowEvent::Activated);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.bounds`'s getter
+pub fn get_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let bounds = window_id(activation, this)
+        .map(|id| activation.context.native_windows.bounds(id))
+        .unwrap_or_default();
+
+    let args = [
+        bounds.x.into(),
+        bounds.y.into(),
+        bounds.width.into(),
+        bounds.height.into(),
+    ];
+    activation
+        .avm2()
+        .classes()
+        .rectangle
+        .construct(activation, &args)
+}
+
+/// Implements `NativeWindow.bounds`'s setter
+pub fn set_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let rectangle = args.get_object(activation, 0, "rectangle")?;
+    let [x, y, width, height] = [
+        rectangle_slots::X,
+        rectangle_slots::Y,
+        rectangle_slots::WIDTH,
+        rectangle_slots::HEIGHT,
+    ]
+    .map(|slot| rectangle.get_slot(slot));
+    let bounds = NativeWindowBounds {
+        x: x.coerce_to_i32(activation)?,
+        y: y.coerce_to_i32(activation)?,
+        width: width.coerce_to_u32(activation)?,
+        height: height.coerce_to_u32(activation)?,
+    };
+
+    if let Some(id) = window_id(activation, this) {
+        NativeWindows::set_bounds(activation.context, id, bounds);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NativeWindow.displayState`'s getter
+pub fn get_display_state<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let state = window_id(activation, this)
+        .map(|id| activation.context.native_windows.display_state(id))
+        .unwrap_or_default();
+
+    Ok(AvmString::new_utf8(activation.gc(), display_state_name(state)).into())
+}
+
+/// Implements `NativeWindow.active`'s getter
+pub fn get_active<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let active = window_id(activation, this)
+        .is_some_and(|id| activation.context.native_windows.is_active(id));
+
+    Ok(active.into())
+}
+
+/// Implements `NativeWindow.closed`'s getter
+pub fn get_closed<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(window_id(activation, this).is_none().into())
+}
+
+/// Implements `NativeWindow.startMove`
+pub fn start_move<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let started = window_id(activation, this)
+        .is_some_and(|id| activation.context.ui.start_native_window_move(id));
+
+    Ok(started.into())
+}
+
+/// Implements `NativeWindow.startResize`
+pub fn start_resize<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let edge = args.get_string(activation, 0);
+    let edge = match &*edge.to_utf8_lossy() {
+        "T" => NativeWindowEdge::Top,
+        "B" => NativeWindowEdge::Bottom,
+        "L" => NativeWindowEdge::Left,
+        "R" => NativeWindowEdge::Right,
+        "TL" => NativeWindowEdge::TopLeft,
+        "TR" => NativeWindowEdge::TopRight,
+        "BL" => NativeWindowEdge::BottomLeft,
+        "BR" => NativeWindowEdge::BottomRight,
+        _ => return Ok(false.into()),
+    };
+
+    let started = window_id(activation, this)
+        .is_some_and(|id| activation.context.ui.start_native_window_resize(id, edge));
+
+    Ok(started.into())
+}
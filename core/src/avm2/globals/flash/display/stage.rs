@@ -500,3 +500,19 @@ pub fn set_tab_children<'gc>(
 
     Ok(Value::Undefined)
 }
+
+/// Implements `Stage.findNativeWindow`
+pub fn find_native_window<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let window = this
+        .as_display_object()
+        .and_then(|this| this.as_stage())
+        .and_then(|stage| activation.context.native_windows.object_of_stage(stage));
+
+    Ok(window.map_or(Value::Null, |window| window.into()))
+}
@@ -24,8 +24,13 @@ package flash.events {
 
         private var _touchPointID:int;
         private var _isPrimaryTouchPoint:Boolean;
+
+        [Ruffle(NativeAccessible)]
         private var _localX:Number;
+
+        [Ruffle(NativeAccessible)]
         private var _localY:Number;
+
         private var _sizeX:Number;
         private var _sizeY:Number;
         private var _pressure:Number;
@@ -34,8 +39,6 @@ package flash.events {
         private var _altKey:Boolean;
         private var _shiftKey:Boolean;
         private var _isRelatedObjectInaccessible:Boolean;
-        private var _stageX:Number;
-        private var _stageY:Number;
 
         public function TouchEvent(
             type:String,
@@ -219,12 +222,8 @@ package flash.events {
             this._isRelatedObjectInaccessible = value;
         }
 
-        public function get stageX():Number {
-            return this._stageX;
-        }
+        public native function get stageX():Number;
 
-        public function get stageY():Number {
-            return this._stageY;
-        }
+        public native function get stageY():Number;
     }
 }
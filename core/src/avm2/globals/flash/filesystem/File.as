@@ -1,111 +1,151 @@
 package flash.filesystem {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-
+    import flash.events.Event;
+    import flash.events.FileListEvent;
+    import flash.events.IOErrorEvent;
     import flash.net.FileReference;
+    import flash.utils.setTimeout;
 
     [API("661")]
     public class File extends FileReference {
-        private static var _applicationDirectory:File = null;
-        private static var _applicationStorageDirectory:File = null;
-        private static var _documentsDirectory:File = null;
-        private static var _userDirectory:File = null;
-
         public function File(path:String = null) {
-            stub_constructor("flash.filesystem.File");
+            if (path != null) {
+                this.init(path);
+            }
         }
 
-        public static function get applicationDirectory():File {
-            stub_getter("flash.filesystem.File", "applicationDirectory");
+        private native function init(path:String):void;
 
-            if (_applicationDirectory === null) {
-                _applicationDirectory = new File();
-            }
+        public static native function get applicationDirectory():File;
 
-            return _applicationDirectory;
-        }
+        public static native function get applicationStorageDirectory():File;
 
-        public static function get applicationStorageDirectory():File {
-            stub_getter("flash.filesystem.File", "applicationStorageDirectory");
+        public static native function get desktopDirectory():File;
 
-            if (_applicationStorageDirectory === null) {
-                _applicationStorageDirectory = new File();
-            }
+        public static native function get documentsDirectory():File;
 
-            return _applicationStorageDirectory;
+        public static native function get userDirectory():File;
+
+        public static function get separator():String {
+            return "/";
         }
 
-        public static function get documentsDirectory():File {
-            stub_getter("flash.filesystem.File", "documentsDirectory");
+        public static function get lineEnding():String {
+            return "\n";
+        }
 
-            if (_documentsDirectory === null) {
-                _documentsDirectory = new File();
-            }
+        public static function get systemCharset():String {
+            return "utf-8";
+        }
 
-            return _documentsDirectory;
+        public static function getRootDirectories():Array {
+            return [new File("/")];
         }
 
-        public static function get userDirectory():File {
-            stub_getter("flash.filesystem.File", "userDirectory");
+        public native function get exists():Boolean;
 
-            if (_userDirectory === null) {
-                _userDirectory = new File();
-            }
+        public native function get isDirectory():Boolean;
 
-            return _userDirectory;
+        public function get isHidden():Boolean {
+            return this.name.charAt(0) == ".";
         }
 
-        public static function get separator():String {
-            stub_getter("flash.filesystem.File", "separator");
+        public function get isPackage():Boolean {
+            return false;
+        }
 
-            return "/";
+        public function get isSymbolicLink():Boolean {
+            return false;
         }
 
-        public function resolvePath(path:String):File {
-            stub_method("flash.filesystem.File", "resolvePath");
+        public native function get nativePath():String;
+        public native function set nativePath(value:String):void;
 
-            return new File();
-        }
+        public native function get url():String;
+        public native function set url(value:String):void;
 
-        public function get exists():Boolean {
-            stub_getter("flash.filesystem.File", "exists");
+        public native function get parent():File;
 
-            return false;
-        }
+        public native function resolvePath(path:String):File;
 
-        public function get nativePath():String {
-            stub_getter("flash.filesystem.File", "nativePath");
+        public native function getRelativePath(ref:FileReference, useDotDot:Boolean = false):String;
 
-            return "";
+        public function canonicalize():void {
+            // Paths in the sandbox are always canonical.
         }
 
-        public function get url():String {
-            stub_getter("flash.filesystem.File", "url");
+        public function clone():File {
+            var file:File = new File();
+            if (this.nativePath != "") {
+                file.nativePath = this.nativePath;
+            }
+            return file;
+        }
+
+        public native function createDirectory():void;
 
-            return "";
+        public native function deleteDirectory(deleteDirectoryContents:Boolean = false):void;
+
+        public function deleteDirectoryAsync(deleteDirectoryContents:Boolean = false):void {
+            var self:File = this;
+            this.runAsync(function():Event {
+                self.deleteDirectory(deleteDirectoryContents);
+                return new Event(Event.COMPLETE);
+            });
         }
 
-        public function canonicalize():void {
-            stub_method("flash.filesystem.File", "canonicalize");
+        public native function deleteFile():void;
+
+        public function deleteFileAsync():void {
+            var self:File = this;
+            this.runAsync(function():Event {
+                self.deleteFile();
+                return new Event(Event.COMPLETE);
+            });
         }
 
-        public function createDirectory():void {
-            stub_method("flash.filesystem.File", "createDirectory");
+        public native function getDirectoryListing():Array;
+
+        public function getDirectoryListingAsync():void {
+            var self:File = this;
+            this.runAsync(function():Event {
+                var files:Array = self.getDirectoryListing();
+                return new FileListEvent(FileListEvent.DIRECTORY_LISTING, false, false, files);
+            });
         }
 
-        public function deleteDirectory(deleteDirectoryContents:Boolean = false):void {
-            stub_method("flash.filesystem.File", "deleteDirectory");
+        public native function copyTo(newLocation:FileReference, overwrite:Boolean = false):void;
+
+        public function copyToAsync(newLocation:FileReference, overwrite:Boolean = false):void {
+            var self:File = this;
+            this.runAsync(function():Event {
+                self.copyTo(newLocation, overwrite);
+                return new Event(Event.COMPLETE);
+            });
         }
 
-        public function getDirectoryListing():Array {
-            stub_method("flash.filesystem.File", "getDirectoryListing");
+        public native function moveTo(newLocation:FileReference, overwrite:Boolean = false):void;
 
-            return [];
+        public function moveToAsync(newLocation:FileReference, overwrite:Boolean = false):void {
+            var self:File = this;
+            this.runAsync(function():Event {
+                self.moveTo(newLocation, overwrite);
+                return new Event(Event.COMPLETE);
+            });
         }
 
-        public function moveTo(newLocation:FileReference, overwrite:Boolean = false):void {
-            stub_method("flash.filesystem.File", "moveTo");
+        // Runs a synchronous operation after the current script, and dispatches
+        // the event it returns, or an `ioError` event if it throws.
+        private function runAsync(operation:Function):void {
+            var self:File = this;
+            setTimeout(function():void {
+                var event:Event;
+                try {
+                    event = operation();
+                } catch (e:Error) {
+                    event = new IOErrorEvent(IOErrorEvent.IO_ERROR, false, false, e.message, e.errorID);
+                }
+                self.dispatchEvent(event);
+            }, 0);
         }
     }
 }
@@ -1,18 +1,382 @@
 package flash.filesystem {
-    import __ruffle__.stub_method;
-
+    import flash.errors.EOFError;
+    import flash.errors.IOError;
+    import flash.events.Event;
     import flash.events.EventDispatcher;
-
-    // TODO this class should implement IDataInput and IDataOutput
+    import flash.events.IOErrorEvent;
+    import flash.events.OutputProgressEvent;
+    import flash.events.ProgressEvent;
+    import flash.utils.ByteArray;
+    import flash.utils.Endian;
+    import flash.utils.IDataInput;
+    import flash.utils.IDataOutput;
+    import flash.utils.setTimeout;
 
     [API("661")]
-    public class FileStream extends EventDispatcher {
+    public class FileStream extends EventDispatcher implements IDataInput, IDataOutput {
+        // The contents of the open file. Reads and writes go through this
+        // buffer, which is written back to the file at the end of the frame
+        // it was modified in, and when the stream is closed.
+        private var _buffer:ByteArray = null;
+        private var _path:String = null;
+        private var _mode:String = null;
+        private var _async:Boolean = false;
+
+        // Streams opened asynchronously can only be read from once the
+        // contents of the file were delivered, one frame after opening.
+        private var _loaded:Boolean = false;
+
+        private var _modified:Boolean = false;
+
+        private var _endian:String = Endian.BIG_ENDIAN;
+        private var _objectEncoding:uint = ByteArray.defaultObjectEncoding;
+        private var _readAhead:Number = Infinity;
+
         public function FileStream() {
             super();
         }
 
+        // Reads the contents of the file, after checking that it can be
+        // opened in the given mode. Files opened for writing are created.
+        private static native function openFile(file:File, fileMode:String):ByteArray;
+
+        private static native function writeFile(path:String, data:ByteArray):void;
+
+        public function open(file:File, fileMode:String):void {
+            this.openInternal(file, fileMode, false);
+        }
+
+        public function openAsync(file:File, fileMode:String):void {
+            this.openInternal(file, fileMode, true);
+        }
+
+        private function openInternal(file:File, fileMode:String, async:Boolean):void {
+            if (fileMode !== FileMode.READ &&
+                fileMode !== FileMode.WRITE &&
+                fileMode !== FileMode.APPEND &&
+                fileMode !== FileMode.UPDATE) {
+                throw new ArgumentError("Error #2008: Parameter fileMode must be one of the accepted values.", 2008);
+            }
+
+            if (this._buffer != null) {
+                this.close();
+            }
+
+            var self:FileStream = this;
+            var contents:ByteArray;
+            try {
+                contents = openFile(file, fileMode);
+            } catch (e:Error) {
+                if (!async) {
+                    throw e;
+                }
+                setTimeout(function():void {
+                    self.dispatchEvent(new IOErrorEvent(IOErrorEvent.IO_ERROR, false, false, e.message, e.errorID));
+                }, 0);
+                return;
+            }
+
+            contents.endian = this._endian;
+            contents.objectEncoding = this._objectEncoding;
+            contents.position = fileMode === FileMode.APPEND ? contents.length : 0;
+
+            this._buffer = contents;
+            this._path = file.nativePath;
+            this._mode = fileMode;
+            this._async = async;
+            this._loaded = !async;
+            this._modified = false;
+
+            if (async) {
+                setTimeout(function():void {
+                    // The stream may have been closed in the meantime.
+                    if (self._buffer !== contents) {
+                        return;
+                    }
+                    self._loaded = true;
+                    if (fileMode === FileMode.READ || fileMode === FileMode.UPDATE) {
+                        self.dispatchEvent(new ProgressEvent(ProgressEvent.PROGRESS, false, false, contents.length, contents.length));
+                        self.dispatchEvent(new Event(Event.COMPLETE));
+                    }
+                }, 0);
+            }
+        }
+
         public function close():void {
-            stub_method("flash.filesystem.FileStream", "close");
+            var buffer:ByteArray = this._buffer;
+            if (buffer == null) {
+                return;
+            }
+
+            var path:String = this._path;
+            var modified:Boolean = this._modified;
+            this._buffer = null;
+            this._path = null;
+            this._mode = null;
+            this._modified = false;
+
+            if (!this._async) {
+                if (modified) {
+                    writeFile(path, buffer);
+                }
+                return;
+            }
+
+            var self:FileStream = this;
+            setTimeout(function():void {
+                var event:Event = new Event(Event.CLOSE);
+                if (modified) {
+                    try {
+                        writeFile(path, buffer);
+                    } catch (e:Error) {
+                        event = new IOErrorEvent(IOErrorEvent.IO_ERROR, false, false, e.message, e.errorID);
+                    }
+                }
+                self.dispatchEvent(event);
+            }, 0);
+        }
+
+        public function truncate():void {
+            this.checkWritable();
+            this._buffer.length = this._buffer.position;
+        }
+
+        public function get bytesAvailable():uint {
+            if (this._buffer == null || !this._loaded) {
+                return 0;
+            }
+            return this._buffer.bytesAvailable;
+        }
+
+        public function get position():Number {
+            return this._buffer != null ? this._buffer.position : 0;
+        }
+
+        public function set position(value:Number):void {
+            if (this._buffer == null) {
+                throw new IOError("Error #2029: This URLStream object does not have a stream opened.", 2029);
+            }
+            this._buffer.position = value;
+        }
+
+        public function get endian():String {
+            return this._endian;
+        }
+
+        public function set endian(value:String):void {
+            if (value !== Endian.BIG_ENDIAN && value !== Endian.LITTLE_ENDIAN) {
+                throw new ArgumentError("Error #2008: Parameter type must be one of the accepted values.", 2008);
+            }
+            this._endian = value;
+            if (this._buffer != null) {
+                this._buffer.endian = value;
+            }
+        }
+
+        public function get objectEncoding():uint {
+            return this._objectEncoding;
+        }
+
+        public function set objectEncoding(value:uint):void {
+            this._objectEncoding = value;
+            if (this._buffer != null) {
+                this._buffer.objectEncoding = value;
+            }
+        }
+
+        public function get readAhead():Number {
+            return this._readAhead;
+        }
+
+        public function set readAhead(value:Number):void {
+            // The whole file is always read at once.
+            this._readAhead = value;
+        }
+
+        private function checkOpen():void {
+            if (this._buffer == null) {
+                throw new IOError("Error #2029: This URLStream object does not have a stream opened.", 2029);
+            }
+        }
+
+        private function checkReadable():void {
+            this.checkOpen();
+            if (this._mode === FileMode.WRITE || this._mode === FileMode.APPEND) {
+                throw new IOError("Error #2038: File I/O Error.", 2038);
+            }
+            if (!this._loaded) {
+                throw new EOFError("Error #2030: End of file was encountered.", 2030);
+            }
+        }
+
+        private function checkWritable():void {
+            this.checkOpen();
+            if (this._mode === FileMode.READ) {
+                throw new IOError("Error #2038: File I/O Error.", 2038);
+            }
+            if (this._mode === FileMode.APPEND) {
+                this._buffer.position = this._buffer.length;
+            }
+            if (!this._modified) {
+                this._modified = true;
+                this.scheduleFlush();
+            }
+        }
+
+        // Writes the buffer back to the file at the end of the frame, so that
+        // the changes survive even if the stream is never closed.
+        private function scheduleFlush():void {
+            var self:FileStream = this;
+            var buffer:ByteArray = this._buffer;
+            setTimeout(function():void {
+                if (self._buffer !== buffer || !self._modified) {
+                    return;
+                }
+                self._modified = false;
+
+                var event:Event = null;
+                try {
+                    writeFile(self._path, buffer);
+                    if (self._async) {
+                        event = new OutputProgressEvent(OutputProgressEvent.OUTPUT_PROGRESS, false, false, 0, buffer.length);
+                    }
+                } catch (e:Error) {
+                    event = new IOErrorEvent(IOErrorEvent.IO_ERROR, false, false, e.message, e.errorID);
+                }
+                if (event != null) {
+                    self.dispatchEvent(event);
+                }
+            }, 0);
+        }
+
+        public function readBoolean():Boolean {
+            this.checkReadable();
+            return this._buffer.readBoolean();
+        }
+
+        public function readByte():int {
+            this.checkReadable();
+            return this._buffer.readByte();
+        }
+
+        public function readBytes(bytes:ByteArray, offset:uint = 0, length:uint = 0):void {
+            this.checkReadable();
+            this._buffer.readBytes(bytes, offset, length);
+        }
+
+        public function readDouble():Number {
+            this.checkReadable();
+            return this._buffer.readDouble();
+        }
+
+        public function readFloat():Number {
+            this.checkReadable();
+            return this._buffer.readFloat();
+        }
+
+        public function readInt():int {
+            this.checkReadable();
+            return this._buffer.readInt();
+        }
+
+        public function readMultiByte(length:uint, charSet:String):String {
+            this.checkReadable();
+            return this._buffer.readMultiByte(length, charSet);
+        }
+
+        public function readObject():* {
+            this.checkReadable();
+            return this._buffer.readObject();
+        }
+
+        public function readShort():int {
+            this.checkReadable();
+            return this._buffer.readShort();
+        }
+
+        public function readUnsignedByte():uint {
+            this.checkReadable();
+            return this._buffer.readUnsignedByte();
+        }
+
+        public function readUnsignedInt():uint {
+            this.checkReadable();
+            return this._buffer.readUnsignedInt();
+        }
+
+        public function readUnsignedShort():uint {
+            this.checkReadable();
+            return this._buffer.readUnsignedShort();
+        }
+
+        public function readUTF():String {
+            this.checkReadable();
+            return this._buffer.readUTF();
+        }
+
+        public function readUTFBytes(length:uint):String {
+            this.checkReadable();
+            return this._buffer.readUTFBytes(length);
+        }
+
+        public function writeBoolean(value:Boolean):void {
+            this.checkWritable();
+            this._buffer.writeBoolean(value);
+        }
+
+        public function writeByte(value:int):void {
+            this.checkWritable();
+            this._buffer.writeByte(value);
+        }
+
+        public function writeBytes(bytes:ByteArray, offset:uint = 0, length:uint = 0):void {
+            this.checkWritable();
+            this._buffer.writeBytes(bytes, offset, length);
+        }
+
+        public function writeDouble(value:Number):void {
+            this.checkWritable();
+            this._buffer.writeDouble(value);
+        }
+
+        public function writeFloat(value:Number):void {
+            this.checkWritable();
+            this._buffer.writeFloat(value);
+        }
+
+        public function writeInt(value:int):void {
+            this.checkWritable();
+            this._buffer.writeInt(value);
+        }
+
+        public function writeMultiByte(value:String, charSet:String):void {
+            this.checkWritable();
+            this._buffer.writeMultiByte(value, charSet);
+        }
+
+        public function writeObject(object:*):void {
+            this.checkWritable();
+            this._buffer.writeObject(object);
+        }
+
+        public function writeShort(value:int):void {
+            this.checkWritable();
+            this._buffer.writeShort(value);
+        }
+
+        public function writeUnsignedInt(value:uint):void {
+            this.checkWritable();
+            this._buffer.writeUnsignedInt(value);
+        }
+
+        public function writeUTF(value:String):void {
+            this.checkWritable();
+            this._buffer.writeUTF(value);
+        }
+
+        public function writeUTFBytes(value:String):void {
+            this.checkWritable();
+            this._buffer.writeUTFBytes(value);
         }
     }
 }
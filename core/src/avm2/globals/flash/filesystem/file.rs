@@ -0,0 +1,438 @@
+//! `flash.filesystem.File` native methods
+
+use crate::avm2::error::{
+    Error2004Type, make_error_2004, make_error_2037, make_error_2038, make_error_3001,
+    make_error_3002, make_error_3003, make_error_3006, make_error_3007, make_error_3010,
+    make_error_3011, make_error_3014,
+};
+use crate::avm2::object::{ArrayObject, Object, TObject as _};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayStorage, Error, Value};
+use crate::backend::filesystem::{
+    FileMetadata, FileSystemError, SpecialDirectory, join_path, parent_path,
+    path_from_native_path_or_url, resolve_path as resolve_sandbox_path, url_from_path,
+};
+use crate::string::AvmString;
+
+/// The error AIR throws for a failed filesystem operation.
+pub fn filesystem_error<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    error: FileSystemError,
+) -> Error<'gc> {
+    match error {
+        FileSystemError::NotFound => make_error_3003(activation),
+        FileSystemError::AlreadyExists => make_error_3002(activation),
+        FileSystemError::NotAFile => make_error_3006(activation),
+        FileSystemError::NotADirectory => make_error_3007(activation),
+        FileSystemError::DirectoryNotEmpty => make_error_3010(activation),
+        FileSystemError::AccessDenied => make_error_3001(activation),
+        FileSystemError::Io(e) => {
+            tracing::warn!("Filesystem error: {e}");
+            make_error_2038(activation)
+        }
+    }
+}
+
+/// Checks that the movie may create, change or delete `path`.
+///
+/// Only the contents of the special directories can be modified, except for the
+/// application directory.
+pub fn check_writable(path: &str) -> Result<(), FileSystemError> {
+    match SpecialDirectory::containing(path) {
+        Some(dir) if dir != SpecialDirectory::Application && dir.path() != path => Ok(()),
+        _ => Err(FileSystemError::AccessDenied),
+    }
+}
+
+/// The sandbox path a `File` refers to.
+pub fn file_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    file: Object<'gc>,
+) -> Result<String, Error<'gc>> {
+    match file.as_file_reference().and_then(|file| file.path()) {
+        Some(path) => Ok(path),
+        None => Err(make_error_2037(activation)),
+    }
+}
+
+/// Creates a `File` referring to `path`.
+pub fn file_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    path: String,
+) -> Result<Object<'gc>, Error<'gc>> {
+    let class = activation.avm2().classes().file;
+    let file = class.construct(activation, &[])?.as_object().unwrap();
+    file.as_file_reference().unwrap().init_from_path(path);
+    Ok(file)
+}
+
+pub fn metadata<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    path: &str,
+) -> Result<FileMetadata, Error<'gc>> {
+    activation
+        .context
+        .filesystem
+        .metadata(path)
+        .map_err(|e| filesystem_error(activation, e))
+}
+
+fn set_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    value: AvmString<'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(path) = path_from_native_path_or_url(&value.to_utf8_lossy()) else {
+        return Err(make_error_2004(activation, Error2004Type::ArgumentError));
+    };
+    this.as_file_reference().unwrap().init_from_path(path);
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.init`
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = args.get_string(activation, 0);
+    set_path(activation, this, path)
+}
+
+fn special_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    dir: SpecialDirectory,
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(file_object(activation, dir.path().to_string())?.into())
+}
+
+/// Implements `File.applicationDirectory`'s getter
+pub fn get_application_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    special_directory(activation, SpecialDirectory::Application)
+}
+
+/// Implements `File.applicationStorageDirectory`'s getter
+pub fn get_application_storage_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    special_directory(activation, SpecialDirectory::ApplicationStorage)
+}
+
+/// Implements `File.desktopDirectory`'s getter
+pub fn get_desktop_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    special_directory(activation, SpecialDirectory::Desktop)
+}
+
+/// Implements `File.documentsDirectory`'s getter
+pub fn get_documents_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    special_directory(activation, SpecialDirectory::Documents)
+}
+
+/// Implements `File.userDirectory`'s getter
+pub fn get_user_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    special_directory(activation, SpecialDirectory::User)
+}
+
+/// Implements `File.exists`'s getter
+pub fn get_exists<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = file_path(activation, this)?;
+
+    Ok(activation.context.filesystem.exists(&path).into())
+}
+
+/// Implements `File.isDirectory`'s getter
+pub fn get_is_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = file_path(activation, this)?;
+
+    let metadata = activation.context.filesystem.metadata(&path);
+    Ok(metadata.is_ok_and(|metadata| metadata.is_directory).into())
+}
+
+/// Implements `File.nativePath`'s getter
+pub fn get_native_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = this.as_file_reference().unwrap().path();
+
+    Ok(AvmString::new_utf8(activation.gc(), path.unwrap_or_default()).into())
+}
+
+/// Implements `File.nativePath`'s setter
+pub fn set_native_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = args.get_string(activation, 0);
+    set_path(activation, this, path)
+}
+
+/// Implements `File.url`'s getter
+pub fn get_url<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let url = this
+        .as_file_reference()
+        .unwrap()
+        .path()
+        .map(|path| url_from_path(&path));
+
+    Ok(AvmString::new_utf8(activation.gc(), url.unwrap_or_default()).into())
+}
+
+/// Implements `File.url`'s setter
+pub fn set_url<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let url = args.get_string(activation, 0);
+    set_path(activation, this, url)
+}
+
+/// Implements `File.parent`'s getter
+pub fn get_parent<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = file_path(activation, this)?;
+
+    match parent_path(&path) {
+        Some(parent) => Ok(file_object(activation, parent.to_string())?.into()),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements `File.resolvePath`
+pub fn resolve_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let base = file_path(activation, this)?;
+    let path = args.get_string(activation, 0);
+
+    let path = resolve_sandbox_path(&base, &path.to_utf8_lossy());
+    Ok(file_object(activation, path)?.into())
+}
+
+/// Implements `File.getRelativePath`
+pub fn get_relative_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let from = file_path(activation, this)?;
+    let to = args.get_object(activation, 0, "ref")?;
+    let to = file_path(activation, to)?;
+    let use_dot_dot = args.get_bool(1);
+
+    let from: Vec<_> = from.split('/').filter(|c| !c.is_empty()).collect();
+    let to: Vec<_> = to.split('/').filter(|c| !c.is_empty()).collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let ups = from.len() - common;
+    if ups > 0 && !use_dot_dot {
+        return Ok(Value::Null);
+    }
+
+    let relative = std::iter::repeat_n("..", ups)
+        .chain(to[common..].iter().copied())
+        .collect::<Vec<_>>()
+        .join("/");
+    Ok(AvmString::new_utf8(activation.gc(), relative).into())
+}
+
+/// Implements `File.createDirectory`
+pub fn create_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = file_path(activation, this)?;
+
+    let backend = &mut *activation.context.filesystem;
+    let result = match backend.metadata(&path) {
+        Ok(metadata) if metadata.is_directory => Ok(()),
+        Ok(_) => Err(FileSystemError::AlreadyExists),
+        Err(_) => check_writable(&path).and_then(|()| backend.create_directory(&path)),
+    };
+    result.map_err(|e| filesystem_error(activation, e))?;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.deleteDirectory`
+pub fn delete_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = file_path(activation, this)?;
+    let delete_contents = args.get_bool(0);
+
+    if !metadata(activation, &path)?.is_directory {
+        return Err(make_error_3007(activation));
+    }
+    check_writable(&path)
+        .and_then(|()| activation.context.filesystem.remove(&path, delete_contents))
+        .map_err(|e| filesystem_error(activation, e))?;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.deleteFile`
+pub fn delete_file<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = file_path(activation, this)?;
+
+    if metadata(activation, &path)?.is_directory {
+        return Err(make_error_3006(activation));
+    }
+    check_writable(&path)
+        .and_then(|()| activation.context.filesystem.remove(&path, false))
+        .map_err(|e| filesystem_error(activation, e))?;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.getDirectoryListing`
+pub fn get_directory_listing<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let path = file_path(activation, this)?;
+
+    let mut names = activation
+        .context
+        .filesystem
+        .read_directory(&path)
+        .map_err(|e| filesystem_error(activation, e))?;
+    names.sort();
+
+    let mut storage = ArrayStorage::new(names.len());
+    for name in names {
+        let file = file_object(activation, join_path(&path, &name))?;
+        storage.push(file.into());
+    }
+
+    Ok(ArrayObject::from_storage(activation.context, storage).into())
+}
+
+/// Copies or moves the file or directory a `File` refers to.
+fn transfer<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+    is_move: bool,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let from = file_path(activation, this)?;
+    let to = args.get_object(activation, 0, "newLocation")?;
+    let to = file_path(activation, to)?;
+    let overwrite = args.get_bool(1);
+
+    metadata(activation, &from)?;
+    if to == from || from.starts_with(&join_path(&to, "")) || to.starts_with(&join_path(&from, ""))
+    {
+        return Err(make_error_3014(activation));
+    }
+
+    let backend = &mut *activation.context.filesystem;
+    let destination_exists = backend.exists(&to);
+    if destination_exists && !overwrite {
+        return Err(make_error_3011(activation));
+    }
+
+    let result = check_writable(&to)
+        .and_then(|()| {
+            if is_move {
+                check_writable(&from)
+            } else {
+                Ok(())
+            }
+        })
+        .and_then(|()| {
+            if destination_exists {
+                backend.remove(&to, true)?;
+            }
+            if let Some(parent) = parent_path(&to) {
+                backend.create_directory(parent)?;
+            }
+            if is_move {
+                backend.rename(&from, &to)
+            } else {
+                backend.copy(&from, &to)
+            }
+        });
+    result.map_err(|e| filesystem_error(activation, e))?;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.copyTo`
+pub fn copy_to<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    transfer(activation, this, args, false)
+}
+
+/// Implements `File.moveTo`
+pub fn move_to<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    transfer(activation, this, args, true)
+}
@@ -0,0 +1,70 @@
+//! `flash.filesystem.FileStream` native methods
+
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::globals::flash::filesystem::file::{check_writable, file_path, filesystem_error};
+use crate::avm2::object::ByteArrayObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Value};
+use crate::backend::filesystem::{FileSystemBackend, FileSystemError, parent_path};
+
+/// Prepares a file to be written to, creating it along with its parent directories.
+///
+/// Returns the contents the stream starts with.
+fn open_for_writing(
+    backend: &mut dyn FileSystemBackend,
+    path: &str,
+    truncate: bool,
+) -> Result<Vec<u8>, FileSystemError> {
+    check_writable(path)?;
+    if let Some(parent) = parent_path(path) {
+        backend.create_directory(parent)?;
+    }
+
+    match backend.read(path) {
+        Ok(data) if !truncate => Ok(data),
+        Ok(_) | Err(FileSystemError::NotFound) => {
+            backend.write(path, &[])?;
+            Ok(Vec::new())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Implements `FileStream.openFile`
+pub fn open_file<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let file = args.get_object(activation, 0, "file")?;
+    let path = file_path(activation, file)?;
+    let mode = args.get_string(activation, 1);
+
+    let backend = &mut *activation.context.filesystem;
+    let contents = if &*mode == b"read" {
+        backend.read(&path)
+    } else {
+        open_for_writing(backend, &path, &*mode == b"write")
+    };
+    let contents = contents.map_err(|e| filesystem_error(activation, e))?;
+
+    let storage = ByteArrayStorage::from_vec(activation.context, contents);
+    Ok(ByteArrayObject::from_storage(activation.context, storage).into())
+}
+
+/// Implements `FileStream.writeFile`
+pub fn write_file<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = args.get_string(activation, 0).to_utf8_lossy().into_owned();
+    let data = args.get_object(activation, 1, "data")?;
+    let data = data.as_bytearray().unwrap();
+
+    check_writable(&path)
+        .and_then(|()| activation.context.filesystem.write(&path, data.bytes()))
+        .map_err(|e| filesystem_error(activation, e))?;
+
+    Ok(Value::Undefined)
+}
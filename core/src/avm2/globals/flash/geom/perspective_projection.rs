@@ -6,7 +6,6 @@ use crate::avm2::globals::slots::flash_geom_perspective_projection as pp_slots;
 use crate::avm2::globals::slots::flash_geom_point as point_slots;
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::{Activation, Error, Object, TObject as _, Value};
-use crate::avm2_stub_setter;
 use crate::display_object::TDisplayObject;
 use ruffle_render::perspective_projection::PerspectiveProjection;
 
@@ -18,9 +17,11 @@ fn get_width<'gc>(activation: &mut Activation<'_, 'gc>, this: Object<'gc>) -> f6
 
     match dobj {
         // Not associated with any DO
-        None => 500.0,
+        None => PerspectiveProjection::DEFAULT_STAGE_WIDTH as f64,
         // Stage's PerspectiveProjection
-        Some(dobj) if dobj.as_stage().is_some() => 500.0,
+        Some(dobj) if dobj.as_stage().is_some() => {
+            PerspectiveProjection::DEFAULT_STAGE_WIDTH as f64
+        }
         // Associated with other DO.
         Some(_dobj) => activation.context.stage.stage_size().0 as f64,
     }
@@ -45,12 +46,6 @@ pub fn set_focal_length<'gc>(
     this: Value<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    // FIXME: Render with the given PerspectiveProjection.
-    avm2_stub_setter!(
-        activation,
-        "flash.geom.PerspectiveProjection",
-        "focalLength"
-    );
     let this = this.as_object().unwrap();
 
     let focal_length = args.get_f64(0);
@@ -86,13 +81,6 @@ pub fn set_field_of_view<'gc>(
     this: Value<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    // FIXME: Render with the given PerspectiveProjection.
-    avm2_stub_setter!(
-        activation,
-        "flash.geom.PerspectiveProjection",
-        "fieldOfView"
-    );
-
     let this = this.as_object().unwrap();
 
     let fov = args.get_f64(0);
@@ -131,13 +119,6 @@ pub fn set_projection_center<'gc>(
     this: Value<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    // FIXME: Render with the given PerspectiveProjection.
-    avm2_stub_setter!(
-        activation,
-        "flash.geom.PerspectiveProjection",
-        "projectionCenter"
-    );
-
     let this = this.as_object().unwrap();
 
     sync_from_display_object(activation, this)?;
@@ -204,9 +185,9 @@ fn sync_to_display_object<'gc>(this: Object<'gc>) -> Result<(), Error<'gc>> {
         // Not associated with DO. Unnecessary to sync.
         return Ok(());
     };
-    let base = dobj.as_display_object().unwrap().base();
+    let dobj = dobj.as_display_object().unwrap();
 
-    let Some(mut proj) = base.perspective_projection() else {
+    let Some(mut proj) = dobj.base().perspective_projection() else {
         return Ok(());
     };
 
@@ -220,7 +201,7 @@ fn sync_to_display_object<'gc>(this: Object<'gc>) -> Result<(), Error<'gc>> {
 
     proj.field_of_view = fov;
     proj.center = (x, y);
-    base.set_perspective_projection(Some(proj));
+    dobj.set_perspective_projection(Some(proj));
 
     Ok(())
 }
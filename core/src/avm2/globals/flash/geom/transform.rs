@@ -10,7 +10,6 @@ use crate::avm2::vector::VectorStorage;
 use crate::avm2::{Activation, Error, Object, TObject as _, Value};
 use crate::display_object::{BoundsMode, TDisplayObject};
 use crate::prelude::{DisplayObject, Matrix, Twips};
-use crate::{avm2_stub_getter, avm2_stub_method, avm2_stub_setter};
 use ruffle_render::matrix3d::Matrix3D;
 use ruffle_render::perspective_projection::PerspectiveProjection;
 use ruffle_render::quality::StageQuality;
@@ -61,7 +60,7 @@ pub fn get_matrix<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    if get_display_object(this).base().has_matrix3d_stub() {
+    if get_display_object(this).base().matrix3d().is_some() {
         Ok(Value::Null)
     } else {
         let matrix = matrix_from_transform_object(this);
@@ -78,7 +77,10 @@ pub fn set_matrix<'gc>(
 
     let dobj = get_display_object(this);
     let Some(obj) = args.try_get_object(0) else {
-        dobj.base().set_has_matrix3d_stub(true);
+        // Setting a null matrix turns the object into a 3D one.
+        if dobj.base().matrix3d().is_none() {
+            dobj.set_matrix3d(Some(Matrix3D::from_matrix(dobj.base().matrix())));
+        }
         return Ok(Value::Undefined);
     };
 
@@ -89,7 +91,6 @@ pub fn set_matrix<'gc>(
         // we only want to inform ancestors to avoid unnecessary invalidations for tx/ty
         parent.invalidate_cached_bitmap();
     }
-    dobj.base().set_has_matrix3d_stub(false);
     Ok(Value::Undefined)
 }
 
@@ -133,10 +134,8 @@ pub fn get_concatenated_matrix<'gc>(
     }
 }
 
-pub fn has_matrix3d_from_transform_object(transform_object: Object<'_>) -> bool {
-    get_display_object(transform_object)
-        .base()
-        .has_matrix3d_stub()
+pub fn matrix3d_from_transform_object(transform_object: Object<'_>) -> Option<Matrix3D> {
+    get_display_object(transform_object).base().matrix3d()
 }
 
 pub fn matrix_from_transform_object(transform_object: Object<'_>) -> Matrix {
@@ -359,18 +358,9 @@ pub fn get_matrix_3d<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    // FIXME: This Matrix3D is generated from the 2D Matrix.
-    // It does not work when the matrix contains any transformation in 3D.
-    // Support native Matrix3D.
-    avm2_stub_getter!(activation, "flash.geom.Transform", "matrix3D");
-
-    let display_object = get_display_object(this);
-    if display_object.base().has_matrix3d_stub() {
-        let matrix = get_display_object(this).base().matrix();
-        let matrix3d = Matrix3D::from_matrix(matrix);
-        matrix3d_to_object(matrix3d, activation)
-    } else {
-        Ok(Value::Null)
+    match get_display_object(this).base().matrix3d() {
+        Some(matrix3d) => matrix3d_to_object(matrix3d, activation),
+        None => Ok(Value::Null),
     }
 }
 
@@ -381,30 +371,22 @@ pub fn set_matrix_3d<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    // FIXME: This sets 2D Matrix generated from the given Matrix3D, ignoring 3D parameters.
-    // Support native Matrix3D.
-    avm2_stub_setter!(activation, "flash.geom.Transform", "matrix3D");
-
     let display_object = get_display_object(this);
 
-    let (matrix, has_matrix3d) = {
-        match args.try_get_object(0) {
-            Some(obj) => {
-                let matrix3d = object_to_matrix3d(obj, activation)?;
-                let matrix = matrix3d.to_matrix();
-                (matrix, true)
+    match args.try_get_object(0) {
+        Some(obj) => {
+            let matrix3d = object_to_matrix3d(obj, activation)?;
+            display_object.set_matrix3d(Some(matrix3d));
+        }
+        None => {
+            display_object.set_matrix(Matrix::IDENTITY);
+            if let Some(parent) = display_object.parent() {
+                // Self-transform changes are automatically handled,
+                // we only want to inform ancestors to avoid unnecessary invalidations for tx/ty
+                parent.invalidate_cached_bitmap();
             }
-            None => (Matrix::IDENTITY, false),
         }
-    };
-
-    display_object.set_matrix(matrix);
-    if let Some(parent) = display_object.parent() {
-        // Self-transform changes are automatically handled,
-        // we only want to inform ancestors to avoid unnecessary invalidations for tx/ty
-        parent.invalidate_cached_bitmap();
     }
-    display_object.base().set_has_matrix3d_stub(has_matrix3d);
 
     Ok(Value::Undefined)
 }
@@ -447,9 +429,6 @@ pub fn set_perspective_projection<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    // FIXME: Render with the given PerspectiveProjection.
-    avm2_stub_setter!(activation, "flash.geom.Transform", "perspectiveProjection");
-
     let perspective_projection = args
         .try_get_object(0)
         .map(|object| object_to_perspective_projection(object, activation))
@@ -467,14 +446,40 @@ pub fn get_relative_matrix_3d<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    let _relative_to = args.get_object(activation, 0, "relativeTo")?;
-
-    avm2_stub_method!(activation, "flash.geom.Transform", "getRelativeMatrix3D");
+    let relative_to = args.get_object(activation, 0, "relativeTo")?;
 
     let display_object = get_display_object(this);
-    if !display_object.base().has_matrix3d_stub() {
+    if display_object.base().matrix3d().is_none() {
         return Ok(Value::Null);
     }
 
-    matrix3d_to_object(Matrix3D::from_matrix(Matrix::IDENTITY), activation)
+    let matrix3d = match relative_to.as_display_object() {
+        Some(relative_to) => {
+            let Some(global_to_relative) = concatenated_matrix3d(relative_to).inverse() else {
+                return Ok(Value::Null);
+            };
+            global_to_relative * concatenated_matrix3d(display_object)
+        }
+        None => concatenated_matrix3d(display_object),
+    };
+    matrix3d_to_object(matrix3d, activation)
+}
+
+/// The 3D transform from the given object's space to the stage's,
+/// ignoring any perspective projection along the way.
+fn concatenated_matrix3d(display_object: DisplayObject<'_>) -> Matrix3D {
+    let mut matrix = Matrix3D::IDENTITY;
+    let mut node = Some(display_object);
+    while let Some(display_object) = node {
+        if display_object.as_stage().is_some() {
+            break;
+        }
+        let base = display_object.base();
+        let local = base
+            .matrix3d()
+            .unwrap_or_else(|| Matrix3D::from_matrix(base.matrix()));
+        matrix = local * matrix;
+        node = display_object.parent();
+    }
+    matrix
 }
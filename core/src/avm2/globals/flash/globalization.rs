@@ -0,0 +1,112 @@
+//! `flash.globalization` namespace
+
+pub mod collator;
+pub mod currency_formatter;
+pub mod date_time_formatter;
+pub mod locale_id;
+pub mod number_formatter;
+pub mod string_tools;
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::{Object, TObject, VectorObject};
+use crate::avm2::value::Value;
+use crate::avm2::vector::VectorStorage;
+use crate::locale::cldr::{LOCALES, LocaleData};
+use crate::locale::number_format::NumberFormat;
+use crate::locale::{LocaleMatch, available_locale_names, resolve_locale};
+use crate::string::AvmString;
+
+/// Picks the locale data for a requested locale ID name, falling back to
+/// the language of the user.
+fn resolve<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    requested: AvmString<'gc>,
+) -> (&'static LocaleData, LocaleMatch) {
+    let language = activation.context.ui.language();
+    resolve_locale(&requested.to_utf8_lossy(), &language)
+}
+
+fn string_value<'gc>(activation: &mut Activation<'_, 'gc>, value: &str) -> Value<'gc> {
+    AvmString::new_utf8(activation.gc(), value).into()
+}
+
+/// Reads a `String` slot, treating `null` as the empty string.
+fn string_slot(this: Object<'_>, slot: usize) -> String {
+    match this.get_slot(slot) {
+        Value::String(s) => s.to_utf8_lossy().into_owned(),
+        _ => String::new(),
+    }
+}
+
+fn set_string_slot<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    slot: usize,
+    value: &str,
+) {
+    let value = string_value(activation, value);
+    this.set_slot_no_coerce(slot, value, activation.gc());
+}
+
+/// The language of a locale ID name, used for case mapping and collation.
+fn language_of(locale_name: &str) -> &str {
+    locale_name.split(['-', '_']).next().unwrap_or(locale_name)
+}
+
+/// The locale data of the locale that was picked for an object.
+fn locale_data(this: Object<'_>, actual_locale_slot: usize) -> &'static LocaleData {
+    let name = string_slot(this, actual_locale_slot);
+    LOCALES
+        .iter()
+        .find(|data| data.name == name)
+        .unwrap_or(&LOCALES[0])
+}
+
+fn string_vector<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    strings: impl IntoIterator<Item = &'static str>,
+) -> Value<'gc> {
+    let values = strings
+        .into_iter()
+        .map(|string| string_value(activation, string))
+        .collect();
+    let storage =
+        VectorStorage::from_values(values, false, Some(activation.avm2().class_defs().string));
+
+    VectorObject::from_vector(storage, activation).into()
+}
+
+/// Implements `getAvailableLocaleIDNames`, which is shared by all formatters.
+fn available_locale_id_names<'gc>(activation: &mut Activation<'_, 'gc>) -> Value<'gc> {
+    string_vector(activation, available_locale_names())
+}
+
+/// The number formatting properties shared by `NumberFormatter` and
+/// `CurrencyFormatter`, read from the slots of either.
+struct FormatterProperties {
+    decimal_separator: String,
+    grouping_separator: String,
+    grouping_pattern: String,
+    negative_symbol: String,
+    digits_type: u32,
+    fractional_digits: i32,
+    leading_zero: bool,
+    trailing_zeros: bool,
+    use_grouping: bool,
+}
+
+impl FormatterProperties {
+    fn number_format(&self) -> NumberFormat<'_> {
+        NumberFormat {
+            decimal_separator: &self.decimal_separator,
+            grouping_separator: &self.grouping_separator,
+            grouping_pattern: &self.grouping_pattern,
+            negative_symbol: &self.negative_symbol,
+            digits_type: self.digits_type,
+            fractional_digits: self.fractional_digits,
+            leading_zero: self.leading_zero,
+            trailing_zeros: self.trailing_zeros,
+            use_grouping: self.use_grouping,
+        }
+    }
+}
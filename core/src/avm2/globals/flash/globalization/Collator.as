@@ -1,99 +1,108 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_setter;
-
     [API("667")]
     public final class Collator {
         private var _requestedLocaleIDName:String;
 
+        [Ruffle(NativeAccessible)]
+        private var _actualLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _lastOperationStatus:String = LastOperationStatus.NO_ERROR;
+
+        [Ruffle(NativeAccessible)]
+        private var _ignoreCase:Boolean = false;
+
+        [Ruffle(NativeAccessible)]
+        private var _ignoreCharacterWidth:Boolean = false;
+
+        [Ruffle(NativeAccessible)]
+        private var _ignoreDiacritics:Boolean = false;
+
+        [Ruffle(NativeAccessible)]
+        private var _ignoreKanaType:Boolean = false;
+
+        [Ruffle(NativeAccessible)]
+        private var _ignoreSymbols:Boolean = false;
+
+        [Ruffle(NativeAccessible)]
+        private var _numericComparison:Boolean = false;
+
         public function Collator(requestedLocaleIDName:String, initialMode:String = "sorting") {
-            stub_constructor("flash.globalization.Collator");
+            if (requestedLocaleIDName == null) {
+                throw new TypeError("Error #2007: Parameter requestedLocaleIDName must be non-null.", 2007);
+            }
             this._requestedLocaleIDName = requestedLocaleIDName;
+            this.init(requestedLocaleIDName, initialMode);
         }
 
+        private native function init(requestedLocaleIDName:String, initialMode:String):void;
+
         public function get actualLocaleIDName():String {
-            stub_getter("flash.globalization.Collator", "actualLocaleIDName");
-            return "en-US";
+            return this._actualLocaleIDName;
         }
 
         public function get ignoreCase():Boolean {
-            stub_getter("flash.globalization.Collator", "ignoreCase");
-            return false;
+            return this._ignoreCase;
         }
-
         public function set ignoreCase(value:Boolean):void {
-            stub_setter("flash.globalization.Collator", "ignoreCase");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._ignoreCase = value;
         }
 
         public function get ignoreCharacterWidth():Boolean {
-            stub_getter("flash.globalization.Collator", "ignoreCharacterWidth");
-            return false;
+            return this._ignoreCharacterWidth;
         }
-
         public function set ignoreCharacterWidth(value:Boolean):void {
-            stub_setter("flash.globalization.Collator", "ignoreCharacterWidth");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._ignoreCharacterWidth = value;
         }
 
         public function get ignoreDiacritics():Boolean {
-            stub_getter("flash.globalization.Collator", "ignoreDiacritics");
-            return false;
+            return this._ignoreDiacritics;
         }
-
         public function set ignoreDiacritics(value:Boolean):void {
-            stub_setter("flash.globalization.Collator", "ignoreDiacritics");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._ignoreDiacritics = value;
         }
 
         public function get ignoreKanaType():Boolean {
-            stub_getter("flash.globalization.Collator", "ignoreKanaType");
-            return false;
+            return this._ignoreKanaType;
         }
-
         public function set ignoreKanaType(value:Boolean):void {
-            stub_setter("flash.globalization.Collator", "ignoreKanaType");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._ignoreKanaType = value;
         }
 
         public function get ignoreSymbols():Boolean {
-            stub_getter("flash.globalization.Collator", "ignoreSymbols");
-            return false;
+            return this._ignoreSymbols;
         }
-
         public function set ignoreSymbols(value:Boolean):void {
-            stub_setter("flash.globalization.Collator", "ignoreSymbols");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._ignoreSymbols = value;
         }
 
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.Collator", "lastOperationStatus");
-            return "noError";
+            return this._lastOperationStatus;
         }
 
         public function get numericComparison():Boolean {
-            stub_getter("flash.globalization.Collator", "numericComparison");
-            return false;
+            return this._numericComparison;
         }
-
         public function set numericComparison(value:Boolean):void {
-            stub_setter("flash.globalization.Collator", "numericComparison");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._numericComparison = value;
         }
 
         public function get requestedLocaleIDName():String {
             return this._requestedLocaleIDName;
         }
 
-        public function compare(string1:String, string2:String):int {
-            stub_method("flash.globalization.Collator", "compare");
-            return string1.localeCompare(string2);
-        }
+        public native function compare(string1:String, string2:String):int;
 
         public function equals(string1:String, string2:String):Boolean {
-            stub_method("flash.globalization.Collator", "equals");
             return this.compare(string1, string2) == 0;
         }
 
-        public static function getAvailableLocaleIDNames():Vector.<String> {
-            stub_method("flash.globalization.Collator", "getAvailableLocaleIDNames");
-            return new <String>["en-US"];
-        }
+        public static native function getAvailableLocaleIDNames():Vector.<String>;
     }
 }
@@ -1,136 +1,174 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_setter;
-
     [API("667")]
     public final class CurrencyFormatter {
         private var _requestedLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _actualLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _lastOperationStatus:String = LastOperationStatus.NO_ERROR;
+
+        [Ruffle(NativeAccessible)]
         private var _decimalSeparator:String = ".";
+
+        [Ruffle(NativeAccessible)]
         private var _digitsType:uint = NationalDigitsType.EUROPEAN;
+
+        [Ruffle(NativeAccessible)]
         private var _fractionalDigits:int = 2;
+
+        [Ruffle(NativeAccessible)]
         private var _groupingPattern:String = "3;*";
+
+        [Ruffle(NativeAccessible)]
         private var _groupingSeparator:String = ",";
+
+        [Ruffle(NativeAccessible)]
         private var _leadingZero:Boolean = true;
+
+        [Ruffle(NativeAccessible)]
         private var _negativeCurrencyFormat:uint = 1;
-        private var _negativeSymbol:String = "-";
+
+        [Ruffle(NativeAccessible)]
         private var _positiveCurrencyFormat:uint = 0;
-        private var _trailingZeros = true;
-        private var _useGrouping = true;
+
+        [Ruffle(NativeAccessible)]
+        private var _currencyISOCode:String = "USD";
+
+        [Ruffle(NativeAccessible)]
+        private var _currencySymbol:String = "$";
+
+        [Ruffle(NativeAccessible)]
+        private var _negativeSymbol:String = "-";
+
+        [Ruffle(NativeAccessible)]
+        private var _trailingZeros:Boolean = true;
+
+        [Ruffle(NativeAccessible)]
+        private var _useGrouping:Boolean = true;
+
+        private static function throwNonNull(name:String) {
+            throw new TypeError("Error #2007: Parameter " + name + " must be non-null.", 2007);
+        }
 
         public function CurrencyFormatter(requestedLocaleIDName:String) {
-            stub_constructor("flash.globalization.CurrencyFormatter");
+            if (requestedLocaleIDName == null) {
+                throwNonNull("requestedLocaleIDName");
+            }
             this._requestedLocaleIDName = requestedLocaleIDName;
+            this.init(requestedLocaleIDName);
         }
 
+        private native function init(requestedLocaleIDName:String):void;
+
         public function get actualLocaleIDName():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "actualLocaleIDName");
-            return "en-US";
+            return this._actualLocaleIDName;
         }
 
         public function get currencyISOCode():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "currencyISOCode");
-            return "USD";
+            return this._currencyISOCode;
         }
 
         public function get currencySymbol():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "currencySymbol");
-            return "$";
+            return this._currencySymbol;
         }
 
         public function get decimalSeparator():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "decimalSeparator");
             return this._decimalSeparator;
         }
-
         public function set decimalSeparator(value:String):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "decimalSeparator");
+            if (value == null) {
+                throwNonNull("decimalSeparator");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._decimalSeparator = value;
         }
 
         public function get digitsType():uint {
-            stub_getter("flash.globalization.CurrencyFormatter", "digitsType");
             return this._digitsType;
         }
-
         public function set digitsType(value:uint):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "digitsType");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._digitsType = value;
         }
 
         public function get fractionalDigits():int {
-            stub_getter("flash.globalization.CurrencyFormatter", "fractionalDigits");
             return this._fractionalDigits;
         }
-
         public function set fractionalDigits(value:int):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "fractionalDigits");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._fractionalDigits = value;
         }
 
         public function get groupingPattern():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "groupingPattern");
             return this._groupingPattern;
         }
-
         public function set groupingPattern(value:String):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "groupingPattern");
-            this._groupingPattern = value;
+            if (value == null) {
+                throwNonNull("groupingPattern");
+            }
+            if (isValidGroupingPattern(value)) {
+                this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+                this._groupingPattern = value;
+            } else {
+                this._lastOperationStatus = LastOperationStatus.ILLEGAL_ARGUMENT_ERROR;
+            }
         }
 
         public function get groupingSeparator():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "groupingSeparator");
             return this._groupingSeparator;
         }
-
         public function set groupingSeparator(value:String):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "groupingSeparator");
+            if (value == null) {
+                throwNonNull("groupingSeparator");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._groupingSeparator = value;
         }
 
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "lastOperationStatus");
-            return "noError";
+            return this._lastOperationStatus;
         }
 
         public function get leadingZero():Boolean {
-            stub_getter("flash.globalization.CurrencyFormatter", "leadingZero");
             return this._leadingZero;
         }
-
         public function set leadingZero(value:Boolean):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "leadingZero");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._leadingZero = value;
         }
 
         public function get negativeCurrencyFormat():uint {
-            stub_getter("flash.globalization.CurrencyFormatter", "negativeCurrencyFormat");
             return this._negativeCurrencyFormat;
         }
-
         public function set negativeCurrencyFormat(value:uint):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "negativeCurrencyFormat");
+            if (value > 15) {
+                throw new ArgumentError("Error #2008: Parameter negativeCurrencyFormat must be one of the accepted values.", 2008);
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._negativeCurrencyFormat = value;
         }
 
         public function get negativeSymbol():String {
-            stub_getter("flash.globalization.CurrencyFormatter", "negativeSymbol");
             return this._negativeSymbol;
         }
-
         public function set negativeSymbol(value:String):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "negativeSymbol");
+            if (value == null) {
+                throwNonNull("negativeSymbol");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._negativeSymbol = value;
         }
 
         public function get positiveCurrencyFormat():uint {
-            stub_getter("flash.globalization.CurrencyFormatter", "positiveCurrencyFormat");
             return this._positiveCurrencyFormat;
         }
-
         public function set positiveCurrencyFormat(value:uint):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "positiveCurrencyFormat");
+            if (value > 3) {
+                throw new ArgumentError("Error #2008: Parameter positiveCurrencyFormat must be one of the accepted values.", 2008);
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._positiveCurrencyFormat = value;
         }
 
@@ -139,47 +177,47 @@ package flash.globalization {
         }
 
         public function get trailingZeros():Boolean {
-            stub_getter("flash.globalization.CurrencyFormatter", "trailingZeros");
             return this._trailingZeros;
         }
-
         public function set trailingZeros(value:Boolean):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "trailingZeros");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._trailingZeros = value;
         }
 
         public function get useGrouping():Boolean {
-            stub_getter("flash.globalization.CurrencyFormatter", "useGrouping");
             return this._useGrouping;
         }
-
         public function set useGrouping(value:Boolean):void {
-            stub_setter("flash.globalization.CurrencyFormatter", "useGrouping");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._useGrouping = value;
         }
 
-        public function format(value:Number, withCurrencySymbol:Boolean = false):String {
-            stub_method("flash.globalization.CurrencyFormatter", "format");
-            return value.toString();
-        }
+        public native function format(value:Number, withCurrencySymbol:Boolean = false):String;
 
         public function formattingWithCurrencySymbolIsSafe(requestedISOCode:String):Boolean {
-            stub_method("flash.globalization.CurrencyFormatter", "formattingWithCurrencySymbolIsSafe");
-            return true;
+            if (requestedISOCode == null) {
+                throwNonNull("requestedISOCode");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return requestedISOCode == this._currencyISOCode;
         }
 
-        public function parse(inputString:String):CurrencyParseResult {
-            stub_method("flash.globalization.CurrencyFormatter", "parse");
-            return new CurrencyParseResult();
-        }
+        public native function parse(inputString:String):CurrencyParseResult;
 
         public function setCurrency(currencyISOCode:String, currencySymbol:String):void {
-            stub_method("flash.globalization.CurrencyFormatter", "setCurrency");
+            if (currencyISOCode == null) {
+                throwNonNull("currencyISOCode");
+            }
+            if (currencySymbol == null) {
+                throwNonNull("currencySymbol");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._currencyISOCode = currencyISOCode;
+            this._currencySymbol = currencySymbol;
         }
 
-        public static function getAvailableLocaleIDNames():Vector.<String> {
-            stub_method("flash.globalization.CurrencyFormatter", "getAvailableLocaleIDNames");
-            return new <String>["en-US"];
-        }
+        public static native function getAvailableLocaleIDNames():Vector.<String>;
+
+        private static native function isValidGroupingPattern(pattern:String):Boolean;
     }
 }
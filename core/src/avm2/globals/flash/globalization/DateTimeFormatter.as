@@ -1,151 +1,91 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-
     [API("667")]
     public final class DateTimeFormatter {
+        private var _requestedLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _actualLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _lastOperationStatus:String = LastOperationStatus.NO_ERROR;
+
+        [Ruffle(NativeAccessible)]
         private var _dateStyle:String;
-        private var _dateTimePattern:String;
-        private var _localeIDName:String;
+
+        [Ruffle(NativeAccessible)]
         private var _timeStyle:String;
 
+        [Ruffle(NativeAccessible)]
+        private var _dateTimePattern:String;
+
         private static function throwNonNull(name:String) {
             throw new TypeError("Error #2007: Parameter " + name + " must be non-null.", 2007);
         }
 
         public function DateTimeFormatter(requestedLocaleIDName:String, dateStyle:String = "long", timeStyle:String = "long") {
-            stub_constructor("flash.globalization.DateTimeFormatter");
-
             if (requestedLocaleIDName == null) {
                 throwNonNull("requestedLocaleIDName");
             }
+            this._requestedLocaleIDName = requestedLocaleIDName;
+            this.init(requestedLocaleIDName);
 
-            this._localeIDName = requestedLocaleIDName;
-            this._dateTimePattern = "EEEE, MMMM d, yyyy h:mm:ss a";
+            // Picking the locale sets the status, which the styles shouldn't overwrite.
+            var status:String = this._lastOperationStatus;
             this.setDateTimeStyles(dateStyle, timeStyle);
+            this._lastOperationStatus = status;
         }
 
+        private native function init(requestedLocaleIDName:String):void;
+
         public function get actualLocaleIDName():String {
-            stub_getter("flash.globalization.DateTimeFormatter", "actualLocaleIDName");
-            return this._localeIDName;
+            return this._actualLocaleIDName;
         }
 
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.DateTimeFormatter", "lastOperationStatus");
-            return LastOperationStatus.NO_ERROR;
+            return this._lastOperationStatus;
         }
 
         public function get requestedLocaleIDName():String {
-            return this._localeIDName;
+            return this._requestedLocaleIDName;
         }
 
-        public function format(dateTime:Date):String {
-            stub_method("flash.globalization.DateTimeFormatter", "format");
+        public native function format(dateTime:Date):String;
 
-            if (dateTime == null) {
-                throwNonNull("dateTime");
-            }
-
-            return dateTime.toString();
-        }
-
-        public function formatUTC(dateTime:Date):String {
-            stub_method("flash.globalization.DateTimeFormatter", "formatUTC");
-
-            if (dateTime == null) {
-                throwNonNull("dateTime");
-            }
+        public native function formatUTC(dateTime:Date):String;
 
-            return dateTime.toUTCString();
-        }
-
-        public static function getAvailableLocaleIDNames():Vector.<String> {
-            stub_method("flash.globalization.DateTimeFormatter", "getAvailableLocaleIDNames");
-            return new <String>["en-US"];
-        }
+        public static native function getAvailableLocaleIDNames():Vector.<String>;
 
         public function getDateStyle():String {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             return this._dateStyle;
         }
 
         public function getDateTimePattern():String {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             return this._dateTimePattern;
         }
 
-        public function getFirstWeekday():int {
-            stub_method("flash.globalization.DateTimeFormatter", "getFirstWeekday");
-            return 0;
-        }
-
-        public function getMonthNames(nameStyle:String = "full", context:String = "standalone"):Vector.<String> {
-            stub_method("flash.globalization.DateTimeFormatter", "getMonthNames");
-
-            if (nameStyle == null) {
-                throwNonNull("nameStyle");
-            }
-
-            if (context == null) {
-                throwNonNull("context");
-            }
+        public native function getFirstWeekday():int;
 
-            return new <String>[
-                "January",
-                "February",
-                "March",
-                "April",
-                "May",
-                "June",
-                "July",
-                "August",
-                "September",
-                "October",
-                "November",
-                "December"
-            ];
-        }
+        public native function getMonthNames(nameStyle:String = "full", context:String = "standalone"):Vector.<String>;
 
         public function getTimeStyle():String {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             return this._timeStyle;
         }
 
-        public function getWeekdayNames(nameStyle:String = "full", context:String = "standalone"):Vector.<String> {
-            stub_method("flash.globalization.DateTimeFormatter", "getWeekdayNames");
-
-            if (nameStyle == null) {
-                throwNonNull("nameStyle");
-            }
-
-            if (context == null) {
-                throwNonNull("context");
-            }
-
-            return new <String>["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
-        }
+        public native function getWeekdayNames(nameStyle:String = "full", context:String = "standalone"):Vector.<String>;
 
         public function setDateTimePattern(pattern:String):void {
-            stub_method("flash.globalization.DateTimeFormatter", "setDateTimePattern");
-
             if (pattern == null) {
                 throwNonNull("pattern");
             }
-
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._dateTimePattern = pattern;
+            this._dateStyle = DateTimeStyle.CUSTOM;
+            this._timeStyle = DateTimeStyle.CUSTOM;
         }
 
-        public function setDateTimeStyles(dateStyle:String, timeStyle:String):void {
-            stub_method("flash.globalization.DateTimeFormatter", "setDateTimeStyles");
-
-            if (dateStyle == null) {
-                throwNonNull("dateStyle");
-            }
-
-            if (timeStyle == null) {
-                throwNonNull("timeStyle");
-            }
-
-            this._dateStyle = dateStyle;
-            this._timeStyle = timeStyle;
-        }
+        public native function setDateTimeStyles(dateStyle:String, timeStyle:String):void;
     }
 }
@@ -1,8 +1,4 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-
     import flash.globalization.LastOperationStatus;
 
     [API("667")]
@@ -11,14 +7,39 @@ package flash.globalization {
 
         private var _name:String;
 
+        private var _lastOperationStatus:String = LastOperationStatus.NO_ERROR;
+
+        [Ruffle(NativeAccessible)]
+        private var _language:String = "";
+
+        [Ruffle(NativeAccessible)]
+        private var _script:String = "";
+
+        [Ruffle(NativeAccessible)]
+        private var _region:String = "";
+
+        [Ruffle(NativeAccessible)]
+        private var _variant:String = "";
+
+        // Alternating keys and values of the keywords after the `@`.
+        [Ruffle(NativeAccessible)]
+        private var _keywords:Array = [];
+
+        [Ruffle(NativeAccessible)]
+        private var _isRightToLeft:Boolean = false;
+
         public function LocaleID(name:String) {
-            stub_constructor("flash.globalization.LocaleID");
+            if (name == null) {
+                throw new TypeError("Error #2007: Parameter name must be non-null.", 2007);
+            }
             this._name = name;
+            this.init(name);
         }
 
+        private native function init(name:String):void;
+
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.LocaleID", "lastOperationStatus");
-            return LastOperationStatus.NO_ERROR;
+            return this._lastOperationStatus;
         }
 
         public function get name():String {
@@ -30,38 +51,69 @@ package flash.globalization {
             have:Vector.<String>,
             keyword:String = "userinterface"
         ):Vector.<String> {
-            stub_method("flash.globalization.LocaleID", "determinePreferredLocales");
-            return null;
+            if (want == null) {
+                throw new TypeError("Error #2007: Parameter want must be non-null.", 2007);
+            }
+            if (have == null) {
+                throw new TypeError("Error #2007: Parameter have must be non-null.", 2007);
+            }
+
+            var result:Vector.<String> = new Vector.<String>();
+            for each (var wantedName:String in want) {
+                var wanted:LocaleID = new LocaleID(wantedName);
+
+                // Exact matches come before matches of only the language.
+                for each (var exactName:String in have) {
+                    var exact:LocaleID = new LocaleID(exactName);
+                    if (exact.getLanguage() == wanted.getLanguage() &&
+                        exact.getScript() == wanted.getScript() &&
+                        exact.getRegion() == wanted.getRegion() &&
+                        result.indexOf(exactName) == -1) {
+                        result.push(exactName);
+                    }
+                }
+                for each (var haveName:String in have) {
+                    if (new LocaleID(haveName).getLanguage() == wanted.getLanguage() &&
+                        result.indexOf(haveName) == -1) {
+                        result.push(haveName);
+                    }
+                }
+            }
+            return result;
         }
 
         public function getKeysAndValues():Object {
-            stub_method("flash.globalization.LocaleID", "getKeysAndValues");
-            return new Object();
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            var result:Object = {};
+            for (var i:int = 0; i < this._keywords.length; i += 2) {
+                result[this._keywords[i]] = this._keywords[i + 1];
+            }
+            return result;
         }
 
         public function getLanguage():String {
-            stub_method("flash.globalization.LocaleID", "getLanguage");
-            return this._name;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._language;
         }
 
         public function getRegion():String {
-            stub_method("flash.globalization.LocaleID", "getRegion");
-            return "";
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._region;
         }
 
         public function getScript():String {
-            stub_method("flash.globalization.LocaleID", "getScript");
-            return "";
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._script;
         }
 
         public function getVariant():String {
-            stub_method("flash.globalization.LocaleID", "getVariant");
-            return "";
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._variant;
         }
 
         public function isRightToLeft():Boolean {
-            stub_method("flash.globalization.LocaleID", "isRightToLeft");
-            return false;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._isRightToLeft;
         }
     }
 }
@@ -1,36 +1,70 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-
     [API("667")]
     public final class NumberFormatter {
-        private var _decimalSeparator = ".";
-        private var _digitsType = NationalDigitsType.EUROPEAN;
-        private var _fractionalDigits = 2;
-        private var _groupingPattern = "3;*";
-        private var _groupingSeparator = ",";
-        private var _leadingZero = true;
-        private var _localeIDName:String;
-        private var _negativeNumberFormat = 1;
-        private var _negativeSymbol = "-";
-        private var _trailingZeros = true;
-        private var _useGrouping = true;
+        private var _requestedLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _actualLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _lastOperationStatus:String = LastOperationStatus.NO_ERROR;
+
+        [Ruffle(NativeAccessible)]
+        private var _decimalSeparator:String = ".";
+
+        [Ruffle(NativeAccessible)]
+        private var _digitsType:uint = NationalDigitsType.EUROPEAN;
+
+        [Ruffle(NativeAccessible)]
+        private var _fractionalDigits:int = 2;
+
+        [Ruffle(NativeAccessible)]
+        private var _groupingPattern:String = "3;*";
+
+        [Ruffle(NativeAccessible)]
+        private var _groupingSeparator:String = ",";
+
+        [Ruffle(NativeAccessible)]
+        private var _leadingZero:Boolean = true;
+
+        [Ruffle(NativeAccessible)]
+        private var _negativeNumberFormat:uint = 1;
+
+        [Ruffle(NativeAccessible)]
+        private var _negativeSymbol:String = "-";
+
+        [Ruffle(NativeAccessible)]
+        private var _trailingZeros:Boolean = false;
+
+        [Ruffle(NativeAccessible)]
+        private var _useGrouping:Boolean = true;
+
+        private static function throwNonNull(name:String) {
+            throw new TypeError("Error #2007: Parameter " + name + " must be non-null.", 2007);
+        }
 
         public function NumberFormatter(requestedLocaleIDName:String) {
-            stub_constructor("flash.globalization.NumberFormatter");
-            this._localeIDName = requestedLocaleIDName;
+            if (requestedLocaleIDName == null) {
+                throwNonNull("requestedLocaleIDName");
+            }
+            this._requestedLocaleIDName = requestedLocaleIDName;
+            this.init(requestedLocaleIDName);
         }
 
+        private native function init(requestedLocaleIDName:String):void;
+
         public function get actualLocaleIDName():String {
-            stub_getter("flash.globalization.NumberFormatter", "actualLocaleIDName");
-            return this._localeIDName;
+            return this._actualLocaleIDName;
         }
 
         public function get decimalSeparator():String {
             return this._decimalSeparator;
         }
         public function set decimalSeparator(value:String):void {
+            if (value == null) {
+                throwNonNull("decimalSeparator");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._decimalSeparator = value;
         }
 
@@ -38,6 +72,7 @@ package flash.globalization {
             return this._digitsType;
         }
         public function set digitsType(value:uint):void {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._digitsType = value;
         }
 
@@ -45,6 +80,7 @@ package flash.globalization {
             return this._fractionalDigits;
         }
         public function set fractionalDigits(value:int):void {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._fractionalDigits = value;
         }
 
@@ -52,25 +88,37 @@ package flash.globalization {
             return this._groupingPattern;
         }
         public function set groupingPattern(value:String):void {
-            this._groupingPattern = value;
+            if (value == null) {
+                throwNonNull("groupingPattern");
+            }
+            if (isValidGroupingPattern(value)) {
+                this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+                this._groupingPattern = value;
+            } else {
+                this._lastOperationStatus = LastOperationStatus.ILLEGAL_ARGUMENT_ERROR;
+            }
         }
 
         public function get groupingSeparator():String {
             return this._groupingSeparator;
         }
         public function set groupingSeparator(value:String):void {
+            if (value == null) {
+                throwNonNull("groupingSeparator");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._groupingSeparator = value;
         }
 
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.NumberFormatter", "lastOperationStatus");
-            return LastOperationStatus.NO_ERROR;
+            return this._lastOperationStatus;
         }
 
         public function get leadingZero():Boolean {
             return this._leadingZero;
         }
         public function set leadingZero(value:Boolean):void {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._leadingZero = value;
         }
 
@@ -78,26 +126,33 @@ package flash.globalization {
             return this._negativeNumberFormat;
         }
         public function set negativeNumberFormat(value:uint):void {
-            if (value >= 0 && value <= 4) {
-                this._negativeNumberFormat = value;
+            if (value > 4) {
+                throw new ArgumentError("Error #2008: Parameter negativeNumberFormat must be one of the accepted values.", 2008);
             }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            this._negativeNumberFormat = value;
         }
 
         public function get negativeSymbol():String {
             return this._negativeSymbol;
         }
         public function set negativeSymbol(value:String):void {
+            if (value == null) {
+                throwNonNull("negativeSymbol");
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._negativeSymbol = value;
         }
 
         public function get requestedLocaleIDName():String {
-            return this._localeIDName;
+            return this._requestedLocaleIDName;
         }
 
         public function get trailingZeros():Boolean {
             return this._trailingZeros;
         }
         public function set trailingZeros(value:Boolean):void {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._trailingZeros = value;
         }
 
@@ -105,37 +160,22 @@ package flash.globalization {
             return this._useGrouping;
         }
         public function set useGrouping(value:Boolean):void {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
             this._useGrouping = value;
         }
 
-        public function formatInt(value:int):String {
-            stub_method("flash.globalization.NumberFormatter", "formatInt");
-            return value.toString();
-        }
+        public native function formatInt(value:int):String;
 
-        public function formatNumber(value:Number):String {
-            stub_method("flash.globalization.NumberFormatter", "formatNumber");
-            return value.toString();
-        }
+        public native function formatNumber(value:Number):String;
 
-        public function formatUint(value:uint):String {
-            stub_method("flash.globalization.NumberFormatter", "formatUint");
-            return value.toString();
-        }
+        public native function formatUint(value:uint):String;
 
-        public static function getAvailableLocaleIDNames():Vector.<String> {
-            stub_method("flash.globalization.NumberFormatter", "getAvailableLocaleIDNames");
-            return new <String>["en-US"];
-        }
+        public static native function getAvailableLocaleIDNames():Vector.<String>;
 
-        public function parse(parseString:String):NumberParseResult {
-            stub_method("flash.globalization.NumberFormatter", "parse");
-            return new NumberParseResult();
-        }
+        public native function parse(parseString:String):NumberParseResult;
 
-        public function parseNumber(parseString:String):Number {
-            stub_method("flash.globalization.NumberFormatter", "parseNumber");
-            return Number(parseString);
-        }
+        public native function parseNumber(parseString:String):Number;
+
+        private static native function isValidGroupingPattern(pattern:String):Boolean;
     }
 }
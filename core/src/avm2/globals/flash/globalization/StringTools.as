@@ -1,44 +1,40 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-
     [API("667")]
     public final class StringTools {
         private var _requestedLocaleIDName:String;
 
+        [Ruffle(NativeAccessible)]
+        private var _actualLocaleIDName:String;
+
+        [Ruffle(NativeAccessible)]
+        private var _lastOperationStatus:String = LastOperationStatus.NO_ERROR;
+
         public function StringTools(requestedLocaleIDName:String) {
-            stub_constructor("flash.globalization.StringTools");
+            if (requestedLocaleIDName == null) {
+                throw new TypeError("Error #2007: Parameter requestedLocaleIDName must be non-null.", 2007);
+            }
             this._requestedLocaleIDName = requestedLocaleIDName;
+            this.init(requestedLocaleIDName);
         }
 
+        private native function init(requestedLocaleIDName:String):void;
+
         public function get actualLocaleIDName():String {
-            stub_getter("flash.globalization.StringTools", "actualLocaleIDName");
-            return "en-US";
+            return this._actualLocaleIDName;
         }
 
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.StringTools", "lastOperationStatus");
-            return "noError";
+            return this._lastOperationStatus;
         }
 
         public function get requestedLocaleIDName():String {
             return this._requestedLocaleIDName;
         }
 
-        public function toLowerCase(s:String):String {
-            stub_method("flash.globalization.StringTools", "toLowerCase");
-            return s.toLowerCase();
-        }
+        public native function toLowerCase(s:String):String;
 
-        public function toUpperCase(s:String):String {
-            stub_method("flash.globalization.StringTools", "toUpperCase");
-            return s.toUpperCase();
-        }
+        public native function toUpperCase(s:String):String;
 
-        public static function getAvailableLocaleIDNames():Vector.<String> {
-            stub_method("flash.globalization.StringTools", "getAvailableLocaleIDNames");
-            return new <String>["en-US"];
-        }
+        public static native function getAvailableLocaleIDNames():Vector.<String>;
     }
 }
@@ -0,0 +1,104 @@
+//! `flash.globalization.Collator` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::{Error, make_error_2008};
+use crate::avm2::globals::flash::globalization::{
+    available_locale_id_names, language_of, resolve, set_string_slot, string_slot,
+};
+use crate::avm2::globals::slots::flash_globalization_collator as slots;
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::locale::collation::{self, CollatorOptions};
+use std::cmp::Ordering;
+
+fn options(this: Object<'_>) -> CollatorOptions {
+    CollatorOptions {
+        ignore_case: this.get_slot(slots::_IGNORE_CASE).coerce_to_boolean(),
+        ignore_character_width: this
+            .get_slot(slots::_IGNORE_CHARACTER_WIDTH)
+            .coerce_to_boolean(),
+        ignore_diacritics: this.get_slot(slots::_IGNORE_DIACRITICS).coerce_to_boolean(),
+        ignore_kana_type: this.get_slot(slots::_IGNORE_KANA_TYPE).coerce_to_boolean(),
+        ignore_symbols: this.get_slot(slots::_IGNORE_SYMBOLS).coerce_to_boolean(),
+        numeric_comparison: this
+            .get_slot(slots::_NUMERIC_COMPARISON)
+            .coerce_to_boolean(),
+    }
+}
+
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let requested = args.get_string(activation, 0);
+    let mode = args.get_string_non_null(activation, 1, "initialMode")?;
+    let options = match &*mode.to_utf8_lossy() {
+        "sorting" => CollatorOptions::default(),
+        "matching" => CollatorOptions::matching(),
+        _ => return Err(make_error_2008(activation, "initialMode")),
+    };
+
+    let (data, locale_match) = resolve(activation, requested);
+    set_string_slot(activation, this, slots::_ACTUAL_LOCALE_ID_NAME, data.name);
+    set_string_slot(
+        activation,
+        this,
+        slots::_LAST_OPERATION_STATUS,
+        locale_match.status(),
+    );
+
+    let mc = activation.gc();
+    for (slot, value) in [
+        (slots::_IGNORE_CASE, options.ignore_case),
+        (
+            slots::_IGNORE_CHARACTER_WIDTH,
+            options.ignore_character_width,
+        ),
+        (slots::_IGNORE_DIACRITICS, options.ignore_diacritics),
+        (slots::_IGNORE_KANA_TYPE, options.ignore_kana_type),
+    ] {
+        this.set_slot_no_coerce(slot, value.into(), mc);
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn compare<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let string1 = args.get_string_non_null(activation, 0, "string1")?;
+    let string2 = args.get_string_non_null(activation, 1, "string2")?;
+
+    let locale_name = string_slot(this, slots::_ACTUAL_LOCALE_ID_NAME);
+    let ordering = collation::compare(
+        language_of(&locale_name),
+        &string1.to_utf8_lossy(),
+        &string2.to_utf8_lossy(),
+        &options(this),
+    );
+
+    let result: i32 = match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    };
+
+    set_string_slot(activation, this, slots::_LAST_OPERATION_STATUS, "noError");
+    Ok(result.into())
+}
+
+pub fn get_available_locale_id_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(available_locale_id_names(activation))
+}
@@ -0,0 +1,179 @@
+//! `flash.globalization.CurrencyFormatter` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::Error;
+use crate::avm2::globals::flash::globalization::{
+    FormatterProperties, available_locale_id_names, resolve, set_string_slot, string_slot,
+    string_value,
+};
+use crate::avm2::globals::slots::flash_globalization_currency_formatter as slots;
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::locale::number_format;
+
+fn properties(this: Object<'_>) -> FormatterProperties {
+    FormatterProperties {
+        decimal_separator: string_slot(this, slots::_DECIMAL_SEPARATOR),
+        grouping_separator: string_slot(this, slots::_GROUPING_SEPARATOR),
+        grouping_pattern: string_slot(this, slots::_GROUPING_PATTERN),
+        negative_symbol: string_slot(this, slots::_NEGATIVE_SYMBOL),
+        digits_type: this.get_slot(slots::_DIGITS_TYPE).as_u32(),
+        fractional_digits: this.get_slot(slots::_FRACTIONAL_DIGITS).as_i32(),
+        leading_zero: this.get_slot(slots::_LEADING_ZERO).coerce_to_boolean(),
+        trailing_zeros: this.get_slot(slots::_TRAILING_ZEROS).coerce_to_boolean(),
+        use_grouping: this.get_slot(slots::_USE_GROUPING).coerce_to_boolean(),
+    }
+}
+
+fn set_status<'gc>(activation: &mut Activation<'_, 'gc>, this: Object<'gc>, status: &str) {
+    set_string_slot(activation, this, slots::_LAST_OPERATION_STATUS, status);
+}
+
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let requested = args.get_string(activation, 0);
+    let (data, locale_match) = resolve(activation, requested);
+
+    set_string_slot(activation, this, slots::_ACTUAL_LOCALE_ID_NAME, data.name);
+    set_string_slot(
+        activation,
+        this,
+        slots::_DECIMAL_SEPARATOR,
+        data.decimal_separator,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_GROUPING_SEPARATOR,
+        data.grouping_separator,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_GROUPING_PATTERN,
+        data.grouping_pattern,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_NEGATIVE_SYMBOL,
+        data.negative_symbol,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_CURRENCY_ISO_CODE,
+        data.currency_iso_code,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_CURRENCY_SYMBOL,
+        data.currency_symbol,
+    );
+
+    let mc = activation.gc();
+    this.set_slot_no_coerce(
+        slots::_FRACTIONAL_DIGITS,
+        data.currency_fractional_digits.into(),
+        mc,
+    );
+    this.set_slot_no_coerce(
+        slots::_POSITIVE_CURRENCY_FORMAT,
+        data.positive_currency_format.into(),
+        mc,
+    );
+    this.set_slot_no_coerce(
+        slots::_NEGATIVE_CURRENCY_FORMAT,
+        data.negative_currency_format.into(),
+        mc,
+    );
+    set_status(activation, this, locale_match.status());
+
+    Ok(Value::Undefined)
+}
+
+pub fn format<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let value = args.get_f64(0);
+    let with_currency_symbol = args.get_bool(1);
+
+    // Without the symbol, the ISO code is used as it is never ambiguous.
+    let currency = if with_currency_symbol {
+        string_slot(this, slots::_CURRENCY_SYMBOL)
+    } else {
+        string_slot(this, slots::_CURRENCY_ISO_CODE)
+    };
+    let positive_format = this.get_slot(slots::_POSITIVE_CURRENCY_FORMAT).as_u32();
+    let negative_format = this.get_slot(slots::_NEGATIVE_CURRENCY_FORMAT).as_u32();
+
+    let properties = properties(this);
+    let formatted = properties.number_format().format_currency(
+        value,
+        &currency,
+        positive_format,
+        negative_format,
+    );
+
+    set_status(activation, this, "noError");
+    Ok(string_value(activation, &formatted))
+}
+
+pub fn parse<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let text = args.get_string_non_null(activation, 0, "inputString")?;
+    let properties = properties(this);
+    let result = properties
+        .number_format()
+        .parse_currency(&text.to_utf8_lossy());
+
+    let args = match result {
+        Some((value, currency)) => {
+            set_status(activation, this, "noError");
+            [value.into(), string_value(activation, &currency)]
+        }
+        None => {
+            set_status(activation, this, "parseError");
+            [f64::NAN.into(), string_value(activation, "")]
+        }
+    };
+
+    activation
+        .avm2()
+        .classes()
+        .currencyparseresult
+        .construct(activation, &args)
+}
+
+pub fn get_available_locale_id_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(available_locale_id_names(activation))
+}
+
+pub fn is_valid_grouping_pattern<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let pattern = args.get_string(activation, 0);
+    Ok(number_format::is_valid_grouping_pattern(&pattern.to_utf8_lossy()).into())
+}
@@ -0,0 +1,171 @@
+//! `flash.globalization.DateTimeFormatter` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::{Error, make_error_2008};
+use crate::avm2::globals::flash::globalization::{
+    available_locale_id_names, locale_data, resolve, set_string_slot, string_slot, string_value,
+    string_vector,
+};
+use crate::avm2::globals::slots::flash_globalization_date_time_formatter as slots;
+use crate::avm2::object::Object;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::locale::date_format::{
+    self, DateTimeStyle, NameContext, NameStyle, month_names, style_pattern, weekday_names,
+};
+use crate::locale::get_timezone;
+use chrono::FixedOffset;
+
+fn set_status<'gc>(activation: &mut Activation<'_, 'gc>, this: Object<'gc>, status: &str) {
+    set_string_slot(activation, this, slots::_LAST_OPERATION_STATUS, status);
+}
+
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let requested = args.get_string(activation, 0);
+    let (data, locale_match) = resolve(activation, requested);
+
+    set_string_slot(activation, this, slots::_ACTUAL_LOCALE_ID_NAME, data.name);
+    set_status(activation, this, locale_match.status());
+
+    Ok(Value::Undefined)
+}
+
+fn format_date<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+    offset: FixedOffset,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let date = args.get_object(activation, 0, "dateTime")?;
+    let Some(date_time) = date.as_date_object().and_then(|date| date.date_time()) else {
+        set_status(activation, this, "illegalArgumentError");
+        return Ok(string_value(activation, ""));
+    };
+
+    let data = locale_data(this, slots::_ACTUAL_LOCALE_ID_NAME);
+    let pattern = string_slot(this, slots::_DATE_TIME_PATTERN);
+    let formatted = date_format::format(data, &pattern, &date_time.with_timezone(&offset));
+
+    set_status(activation, this, "noError");
+    Ok(string_value(activation, &formatted))
+}
+
+pub fn format<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    format_date(activation, this, args, get_timezone())
+}
+
+pub fn format_utc<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let utc = FixedOffset::east_opt(0).expect("UTC is a valid offset");
+    format_date(activation, this, args, utc)
+}
+
+pub fn get_available_locale_id_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(available_locale_id_names(activation))
+}
+
+pub fn get_first_weekday<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let data = locale_data(this, slots::_ACTUAL_LOCALE_ID_NAME);
+    set_status(activation, this, "noError");
+    Ok(data.first_weekday.into())
+}
+
+/// Reads the `nameStyle` and `context` arguments of `getMonthNames` and
+/// `getWeekdayNames`.
+fn name_arguments<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+) -> Result<(NameStyle, NameContext), Error<'gc>> {
+    let style = args.get_string_non_null(activation, 0, "nameStyle")?;
+    let context = args.get_string_non_null(activation, 1, "context")?;
+
+    let style = NameStyle::from_name(&style.to_utf8_lossy())
+        .ok_or_else(|| make_error_2008(activation, "nameStyle"))?;
+    let context = NameContext::from_name(&context.to_utf8_lossy())
+        .ok_or_else(|| make_error_2008(activation, "context"))?;
+
+    Ok((style, context))
+}
+
+pub fn get_month_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let (style, context) = name_arguments(activation, args)?;
+    let data = locale_data(this, slots::_ACTUAL_LOCALE_ID_NAME);
+
+    set_status(activation, this, "noError");
+    Ok(string_vector(activation, month_names(data, style, context)))
+}
+
+pub fn get_weekday_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    // Weekday names don't change with the context in any supported locale.
+    let (style, _context) = name_arguments(activation, args)?;
+    let data = locale_data(this, slots::_ACTUAL_LOCALE_ID_NAME);
+
+    set_status(activation, this, "noError");
+    Ok(string_vector(activation, weekday_names(data, style)))
+}
+
+pub fn set_date_time_styles<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let date_style = args.get_string_non_null(activation, 0, "dateStyle")?;
+    let time_style = args.get_string_non_null(activation, 1, "timeStyle")?;
+
+    // The custom style can only be set by setting a pattern.
+    let date_style = DateTimeStyle::from_name(&date_style.to_utf8_lossy())
+        .filter(|style| *style != DateTimeStyle::Custom)
+        .ok_or_else(|| make_error_2008(activation, "dateStyle"))?;
+    let time_style = DateTimeStyle::from_name(&time_style.to_utf8_lossy())
+        .filter(|style| *style != DateTimeStyle::Custom)
+        .ok_or_else(|| make_error_2008(activation, "timeStyle"))?;
+
+    let data = locale_data(this, slots::_ACTUAL_LOCALE_ID_NAME);
+    let pattern = style_pattern(data, date_style, time_style).unwrap_or_default();
+
+    set_string_slot(activation, this, slots::_DATE_STYLE, date_style.name());
+    set_string_slot(activation, this, slots::_TIME_STYLE, time_style.name());
+    set_string_slot(activation, this, slots::_DATE_TIME_PATTERN, &pattern);
+    set_status(activation, this, "noError");
+
+    Ok(Value::Undefined)
+}
@@ -0,0 +1,43 @@
+//! `flash.globalization.LocaleID` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::error::Error;
+use crate::avm2::globals::flash::globalization::{set_string_slot, string_value};
+use crate::avm2::globals::slots::flash_globalization_locale_id as slots;
+use crate::avm2::object::{ArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::locale::LocaleId;
+
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let name = args.get_string(activation, 0);
+    let id = LocaleId::parse(&name.to_utf8_lossy());
+
+    set_string_slot(activation, this, slots::_LANGUAGE, &id.language);
+    set_string_slot(activation, this, slots::_SCRIPT, &id.script);
+    set_string_slot(activation, this, slots::_REGION, &id.region);
+    set_string_slot(activation, this, slots::_VARIANT, &id.variant);
+
+    let keywords: ArrayStorage<'gc> = id
+        .keywords
+        .iter()
+        .flat_map(|(key, value)| [key, value])
+        .map(|string| string_value(activation, string))
+        .collect();
+    let keywords = ArrayObject::from_storage(activation.context, keywords);
+    this.set_slot_no_coerce(slots::_KEYWORDS, keywords.into(), activation.gc());
+    this.set_slot_no_coerce(
+        slots::_IS_RIGHT_TO_LEFT,
+        id.is_right_to_left().into(),
+        activation.gc(),
+    );
+
+    Ok(Value::Undefined)
+}
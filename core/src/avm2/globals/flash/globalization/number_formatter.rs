@@ -0,0 +1,192 @@
+//! `flash.globalization.NumberFormatter` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::Error;
+use crate::avm2::globals::flash::globalization::{
+    FormatterProperties, available_locale_id_names, resolve, set_string_slot, string_slot,
+    string_value,
+};
+use crate::avm2::globals::slots::flash_globalization_number_formatter as slots;
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::locale::number_format;
+
+fn properties(this: Object<'_>) -> FormatterProperties {
+    FormatterProperties {
+        decimal_separator: string_slot(this, slots::_DECIMAL_SEPARATOR),
+        grouping_separator: string_slot(this, slots::_GROUPING_SEPARATOR),
+        grouping_pattern: string_slot(this, slots::_GROUPING_PATTERN),
+        negative_symbol: string_slot(this, slots::_NEGATIVE_SYMBOL),
+        digits_type: this.get_slot(slots::_DIGITS_TYPE).as_u32(),
+        fractional_digits: this.get_slot(slots::_FRACTIONAL_DIGITS).as_i32(),
+        leading_zero: this.get_slot(slots::_LEADING_ZERO).coerce_to_boolean(),
+        trailing_zeros: this.get_slot(slots::_TRAILING_ZEROS).coerce_to_boolean(),
+        use_grouping: this.get_slot(slots::_USE_GROUPING).coerce_to_boolean(),
+    }
+}
+
+fn set_status<'gc>(activation: &mut Activation<'_, 'gc>, this: Object<'gc>, status: &str) {
+    set_string_slot(activation, this, slots::_LAST_OPERATION_STATUS, status);
+}
+
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let requested = args.get_string(activation, 0);
+    let (data, locale_match) = resolve(activation, requested);
+
+    set_string_slot(activation, this, slots::_ACTUAL_LOCALE_ID_NAME, data.name);
+    set_string_slot(
+        activation,
+        this,
+        slots::_DECIMAL_SEPARATOR,
+        data.decimal_separator,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_GROUPING_SEPARATOR,
+        data.grouping_separator,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_GROUPING_PATTERN,
+        data.grouping_pattern,
+    );
+    set_string_slot(
+        activation,
+        this,
+        slots::_NEGATIVE_SYMBOL,
+        data.negative_symbol,
+    );
+    this.set_slot_no_coerce(
+        slots::_NEGATIVE_NUMBER_FORMAT,
+        data.negative_number_format.into(),
+        activation.gc(),
+    );
+    set_status(activation, this, locale_match.status());
+
+    Ok(Value::Undefined)
+}
+
+fn format<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    value: f64,
+    is_integer: bool,
+) -> Value<'gc> {
+    let this = this.as_object().unwrap();
+
+    let properties = properties(this);
+    let negative_format = this.get_slot(slots::_NEGATIVE_NUMBER_FORMAT).as_u32();
+    let format = properties.number_format();
+    let formatted = if is_integer {
+        format.format_integer(value, negative_format)
+    } else {
+        format.format_number(value, negative_format)
+    };
+
+    set_status(activation, this, "noError");
+    string_value(activation, &formatted)
+}
+
+pub fn format_int<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = args.get_i32(0);
+    Ok(format(activation, this, value.into(), true))
+}
+
+pub fn format_number<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = args.get_f64(0);
+    Ok(format(activation, this, value, false))
+}
+
+pub fn format_uint<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = args.get_u32(0);
+    Ok(format(activation, this, value.into(), true))
+}
+
+pub fn get_available_locale_id_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(available_locale_id_names(activation))
+}
+
+pub fn parse<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let text = args.get_string_non_null(activation, 0, "parseString")?;
+    let properties = properties(this);
+    let result = properties.number_format().parse(&text.to_utf8_lossy());
+
+    let args = match result {
+        Some((value, start, end)) => {
+            set_status(activation, this, "noError");
+            [value.into(), (start as i32).into(), (end as i32).into()]
+        }
+        None => {
+            set_status(activation, this, "parseError");
+            [f64::NAN.into(), i32::MAX.into(), i32::MAX.into()]
+        }
+    };
+
+    activation
+        .avm2()
+        .classes()
+        .numberparseresult
+        .construct(activation, &args)
+}
+
+pub fn parse_number<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let text = args.get_string_non_null(activation, 0, "parseString")?;
+    let properties = properties(this);
+    let value = properties
+        .number_format()
+        .parse_number(&text.to_utf8_lossy());
+
+    if value.is_some() {
+        set_status(activation, this, "noError");
+    } else {
+        set_status(activation, this, "parseError");
+    }
+
+    Ok(value.unwrap_or(f64::NAN).into())
+}
+
+pub fn is_valid_grouping_pattern<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let pattern = args.get_string(activation, 0);
+    Ok(number_format::is_valid_grouping_pattern(&pattern.to_utf8_lossy()).into())
+}
@@ -0,0 +1,72 @@
+//! `flash.globalization.StringTools` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::Error;
+use crate::avm2::globals::flash::globalization::{
+    available_locale_id_names, language_of, resolve, set_string_slot, string_slot, string_value,
+};
+use crate::avm2::globals::slots::flash_globalization_string_tools as slots;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::locale::collation;
+
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let requested = args.get_string(activation, 0);
+    let (data, locale_match) = resolve(activation, requested);
+
+    set_string_slot(activation, this, slots::_ACTUAL_LOCALE_ID_NAME, data.name);
+    set_string_slot(
+        activation,
+        this,
+        slots::_LAST_OPERATION_STATUS,
+        locale_match.status(),
+    );
+
+    Ok(Value::Undefined)
+}
+
+fn map_case<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+    map: fn(&str, &str) -> String,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let text = args.get_string_non_null(activation, 0, "s")?;
+    let locale_name = string_slot(this, slots::_ACTUAL_LOCALE_ID_NAME);
+    let mapped = map(language_of(&locale_name), &text.to_utf8_lossy());
+
+    set_string_slot(activation, this, slots::_LAST_OPERATION_STATUS, "noError");
+    Ok(string_value(activation, &mapped))
+}
+
+pub fn to_lower_case<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    map_case(activation, this, args, collation::to_lower_case)
+}
+
+pub fn to_upper_case<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    map_case(activation, this, args, collation::to_upper_case)
+}
+
+pub fn get_available_locale_id_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(available_locale_id_names(activation))
+}
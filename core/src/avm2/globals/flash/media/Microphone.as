@@ -6,59 +6,53 @@ package flash.media {
     import flash.events.EventDispatcher;
 
     public final class Microphone extends EventDispatcher {
+        [Ruffle(NativeAccessible)]
+        private var _index:int = -1;
+
+        private var _codec:String = SoundCodec.NELLYMOSER;
+        private var _enableVAD:Boolean = true;
+        private var _encodeQuality:int = 6;
+        private var _framesPerPacket:int = 2;
+        private var _noiseSuppressionLevel:int = -30;
+
         [API("672")]
         public static function getEnhancedMicrophone(index:int = -1):Microphone {
             stub_method("flash.media.Microphone", "getEnhancedMicrophone");
-            return new Microphone();
+            return getMicrophone(index);
         }
 
-        public static function getMicrophone(index:int = -1):Microphone {
-            stub_method("flash.media.Microphone", "getMicrophone");
-            return new Microphone();
-        }
+        public static native function getMicrophone(index:int = -1):Microphone;
 
-        public function setLoopBack(isLooped:Boolean = true):void {
-            stub_method("flash.media.Microphone", "setLoopBack");
-        }
+        public native function setLoopBack(isLooped:Boolean = true):void;
 
-        public function setSilenceLevel(silenceLevel:Number, timeout:int = -1):void {
-            stub_method("flash.media.Microphone", "setSilenceLevel");
-        }
+        public native function setSilenceLevel(silenceLevel:Number, timeout:int = -1):void;
 
-        public function setUseEchoSuppression(isEchoSuppressed:Boolean):void {
-            stub_method("flash.media.Microphone", "setUseEchoSuppression");
-        }
+        public native function setUseEchoSuppression(isEchoSuppressed:Boolean):void;
 
-        public function get activityLevel():Number {
-            stub_getter("flash.media.Microphone", "activityLevel");
-            return 0.0;
-        }
+        public native function get activityLevel():Number;
 
         public function get codec():String {
-            stub_getter("flash.media.Microphone", "codec");
-            return "";
+            return this._codec;
         }
 
         public function set codec(codec:String) {
-            stub_setter("flash.media.Microphone", "codec");
+            this._codec = codec;
         }
 
         public function get enableVAD():Boolean {
-            stub_getter("flash.media.Microphone", "enableVAD");
-            return false;
+            return this._enableVAD;
         }
 
         public function set enableVAD(isEnabled:Boolean) {
-            stub_setter("flash.media.Microphone", "enableVAD");
+            this._enableVAD = isEnabled;
         }
 
         public function get encodeQuality():int {
-            stub_getter("flash.media.Microphone", "encodeQuality");
-            return 0;
+            return this._encodeQuality;
         }
 
         public function set encodeQuality(quality:int) {
-            stub_setter("flash.media.Microphone", "encodeQuality");
+            this._encodeQuality = quality;
         }
 
         [API("672")]
@@ -73,75 +67,44 @@ package flash.media {
         }
 
         public function get framesPerPacket():int {
-            stub_getter("flash.media.Microphone", "framesPerPacket");
-            return 0;
+            return this._framesPerPacket;
         }
 
         public function set framesPerPacket(fpp:int) {
-            stub_setter("flash.media.Microphone", "framesPerPacket");
+            this._framesPerPacket = fpp;
         }
 
-        public function get gain():Number {
-            stub_getter("flash.media.Microphone", "gain");
-            return 0.0;
-        }
+        public native function get gain():Number;
 
-        public function set gain(gain:Number) {
-            stub_setter("flash.media.Microphone", "gain");
-        }
+        public native function set gain(gain:Number);
 
         public function get index():int {
-            stub_getter("flash.media.Microphone", "index");
-            return 0;
+            return this._index;
         }
 
-        public static function get isSupported():Boolean {
-            stub_getter("flash.media.Microphone", "isSupported");
-            return false;
-        }
+        public static native function get isSupported():Boolean;
 
-        public function get muted():Boolean {
-            stub_getter("flash.media.Microphone", "muted");
-            return true;
-        }
+        public native function get muted():Boolean;
 
-        public function get name():String {
-            stub_getter("flash.media.Microphone", "name");
-            return "";
-        }
+        public native function get name():String;
 
-        public static function get names():Array {
-            stub_getter("flash.media.Microphone", "names");
-            return [];
-        }
+        public static native function get names():Array;
 
         public function get noiseSuppressionLevel():int {
-            stub_getter("flash.media.Microphone", "noiseSuppressionLevel");
-            return 0;
+            return this._noiseSuppressionLevel;
         }
 
         public function set noiseSuppressionLevel(level:int) {
-            stub_setter("flash.media.Microphone", "noiseSuppressionLevel");
+            this._noiseSuppressionLevel = level;
         }
 
-        public function get rate():int {
-            stub_getter("flash.media.Microphone", "rate");
-            return 0;
-        }
+        public native function get rate():int;
 
-        public function set rate(level:int) {
-            stub_setter("flash.media.Microphone", "rate");
-        }
+        public native function set rate(level:int);
 
-        public function get silenceLevel():Number {
-            stub_getter("flash.media.Microphone", "silenceLevel");
-            return 0;
-        }
+        public native function get silenceLevel():Number;
 
-        public function get silenceTimeout():int {
-            stub_getter("flash.media.Microphone", "silenceTimeout");
-            return 0;
-        }
+        public native function get silenceTimeout():int;
 
         public function get soundTransform():flash.media.SoundTransform {
             stub_getter("flash.media.Microphone", "soundTransform");
@@ -152,9 +115,6 @@ package flash.media {
             stub_setter("flash.media.Microphone", "soundTransform");
         }
 
-        public function get useEchoSuppression():Boolean {
-            stub_getter("flash.media.Microphone", "useEchoSuppression");
-            return false;
-        }
+        public native function get useEchoSuppression():Boolean;
     }
 }
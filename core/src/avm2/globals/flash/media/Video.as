@@ -46,6 +46,8 @@ package flash.media {
             return this._videoHeight;
         }
 
+        public native function attachCamera(camera:Camera):void;
+
         public native function attachNetStream(netStream:NetStream):void;
 
         public function clear():void {
@@ -0,0 +1,335 @@
+//! `flash.media.Camera` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::globals::flash::display::bitmap_data::get_rectangle_x_y_width_height;
+use crate::avm2::globals::slots::flash_media_camera as slots;
+use crate::avm2::object::{ArrayObject, Object, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{ArrayStorage, Error};
+use crate::backend::camera::CameraMode;
+use crate::bitmap::operations;
+use crate::camera::{Camera, Cameras};
+use crate::string::AvmString;
+use ruffle_render::bitmap::PixelRegion;
+
+/// The camera of a `Camera` object.
+pub fn camera_for_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    object: Object<'gc>,
+) -> Camera<'gc> {
+    let index = object.get_slot(slots::_INDEX).as_i32().max(0) as usize;
+    activation.context.cameras.get(activation.gc(), index)
+}
+
+/// The pixels of the latest frame of a camera within `rect`, as ARGB colors.
+fn frame_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    camera: Camera<'gc>,
+    rect: Object<'gc>,
+) -> Result<Vec<u32>, Error<'gc>> {
+    let (x, y, width, height) = get_rectangle_x_y_width_height(activation, rect)?;
+
+    let Some(frame) = camera.frame_pixels() else {
+        return Ok(Vec::new());
+    };
+
+    let mut region = PixelRegion::for_region_i32(x, y, width, height);
+    region.clamp(frame.width(), frame.height());
+
+    let stride = frame.width() as usize * 4;
+    let mut pixels = Vec::with_capacity((region.width() * region.height()) as usize);
+    for y in region.y_min..region.y_max {
+        let row = &frame.data()[y as usize * stride..];
+        for x in region.x_min..region.x_max {
+            let rgba = &row[x as usize * 4..x as usize * 4 + 4];
+            pixels.push(u32::from_be_bytes([rgba[3], rgba[0], rgba[1], rgba[2]]));
+        }
+    }
+
+    Ok(pixels)
+}
+
+/// Implements `Camera.getCamera`
+pub fn get_camera<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let class = this.as_class_object().unwrap();
+
+    // Cameras are named by their index in `Camera.names`.
+    let index = match args.try_get_string(0) {
+        Some(name) => match name.to_string().parse::<i32>() {
+            Ok(index) if index >= 0 => index,
+            _ => return Ok(Value::Null),
+        },
+        None => -1,
+    };
+
+    let Some(index) = Cameras::device_index(activation.context.camera, index) else {
+        return Ok(Value::Null);
+    };
+
+    let camera = activation.context.cameras.get(activation.gc(), index);
+
+    // A device is always represented by the same object.
+    if let Some(object) = camera.avm2_object() {
+        return Ok(object.into());
+    }
+
+    let object = class.construct(activation, &[])?;
+    let object = object.as_object().unwrap();
+    object.set_slot_no_coerce(slots::_INDEX, (index as i32).into(), activation.gc());
+    camera.set_avm2_object(activation.gc(), object);
+
+    Ok(object.into())
+}
+
+/// Implements `Camera.names`'s getter
+pub fn get_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let names = activation.context.camera.names();
+    let storage = names
+        .iter()
+        .map(|name| Value::from(AvmString::new_utf8(activation.gc(), name)))
+        .collect::<ArrayStorage>();
+
+    Ok(ArrayObject::from_storage(activation.context, storage).into())
+}
+
+/// Implements `Camera.isSupported`'s getter
+pub fn get_is_supported<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok((!activation.context.camera.names().is_empty()).into())
+}
+
+/// Implements `Camera.copyToByteArray`
+pub fn copy_to_byte_array<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let camera = camera_for_object(activation, this);
+
+    let rect = args.get_object(activation, 0, "rect")?;
+    let destination = args.get_object(activation, 1, "destination")?;
+    let pixels = frame_pixels(activation, camera, rect)?;
+
+    let mut storage = destination.as_bytearray_mut().unwrap();
+    for pixel in pixels {
+        storage
+            .write_unsigned_int(pixel)
+            .map_err(|e| e.to_avm(activation))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Camera.copyToVector`
+pub fn copy_to_vector<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let camera = camera_for_object(activation, this);
+
+    let rect = args.get_object(activation, 0, "rect")?;
+    let destination = args.get_object(activation, 1, "destination")?;
+    let pixels = frame_pixels(activation, camera, rect)?;
+
+    let mut storage = destination.as_vector_storage_mut(activation.gc()).unwrap();
+    if storage.length() != pixels.len() {
+        storage.resize(pixels.len(), activation)?;
+    }
+    for (i, pixel) in pixels.into_iter().enumerate() {
+        storage.set(i, pixel.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Camera.drawToBitmapData`
+pub fn draw_to_bitmap_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let camera = camera_for_object(activation, this);
+
+    let destination = args.get_object(activation, 0, "destination")?;
+    let bitmap_data = destination.as_bitmap_data().unwrap();
+    bitmap_data.check_valid(activation)?;
+
+    if let Some(frame) = camera.frame_pixels() {
+        operations::set_pixels_from_bitmap(
+            activation.gc(),
+            activation.context.renderer,
+            bitmap_data,
+            &frame,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Camera.setMode`
+pub fn set_mode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let camera = camera_for_object(activation, this);
+
+    let width = args.get_i32(0);
+    let height = args.get_i32(1);
+    let fps = args.get_f64(2);
+
+    // `favorArea` only matters to devices that can't capture in the requested
+    // mode, and the backend always picks the closest mode it supports.
+    let mut settings = camera.settings_mut();
+    settings.mode = CameraMode {
+        width: width.max(1) as u32,
+        height: height.max(1) as u32,
+        fps: if fps.is_nan() || fps <= 0.0 {
+            settings.mode.fps
+        } else {
+            fps
+        },
+    };
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Camera.setMotionLevel`
+pub fn set_motion_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let camera = camera_for_object(activation, this);
+
+    let mut settings = camera.settings_mut();
+    settings.motion_level = args.get_i32(0).clamp(0, 100);
+    settings.motion_timeout = args.get_i32(1).max(0);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Camera.activityLevel`'s getter
+pub fn get_activity_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    Ok(camera_for_object(activation, this).activity_level().into())
+}
+
+/// Implements `Camera.currentFPS`'s getter
+pub fn get_current_fps<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    Ok(camera_for_object(activation, this).current_fps().into())
+}
+
+/// Implements `Camera.fps`'s getter
+pub fn get_fps<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    Ok(camera_for_object(activation, this).mode().fps.into())
+}
+
+/// Implements `Camera.height`'s getter
+pub fn get_height<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    Ok(camera_for_object(activation, this).mode().height.into())
+}
+
+/// Implements `Camera.motionLevel`'s getter
+pub fn get_motion_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let camera = camera_for_object(activation, this);
+    let motion_level = camera.settings().motion_level;
+    Ok(motion_level.into())
+}
+
+/// Implements `Camera.motionTimeout`'s getter
+pub fn get_motion_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let camera = camera_for_object(activation, this);
+    let motion_timeout = camera.settings().motion_timeout;
+    Ok(motion_timeout.into())
+}
+
+/// Implements `Camera.muted`'s getter
+pub fn get_muted<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    Ok(camera_for_object(activation, this).is_muted().into())
+}
+
+/// Implements `Camera.name`'s getter
+pub fn get_name<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let index = camera_for_object(activation, this).index();
+    let names = activation.context.camera.names();
+    let name = names.get(index).map(String::as_str).unwrap_or_default();
+    Ok(AvmString::new_utf8(activation.gc(), name).into())
+}
+
+/// Implements `Camera.width`'s getter
+pub fn get_width<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    Ok(camera_for_object(activation, this).mode().width.into())
+}
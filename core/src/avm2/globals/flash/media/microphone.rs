@@ -0,0 +1,273 @@
+//! `flash.media.Microphone` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::globals::slots::flash_media_microphone as slots;
+use crate::avm2::object::{ArrayObject, Object, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{ArrayStorage, Error};
+use crate::microphone::Microphones;
+use crate::string::AvmString;
+
+/// The device index of a `Microphone` object.
+fn device_index(this: Object<'_>) -> usize {
+    this.get_slot(slots::_INDEX).as_i32().max(0) as usize
+}
+
+/// Implements `Microphone.getMicrophone`
+pub fn get_microphone<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+    let class = this.as_class_object().unwrap();
+
+    let Some(index) = Microphones::device_index(activation.context.microphone, args.get_i32(0))
+    else {
+        return Ok(Value::Null);
+    };
+
+    // A device is always represented by the same object.
+    if let Some(microphone) = activation.context.microphones.avm2_object(index) {
+        return Ok(microphone.into());
+    }
+
+    let microphone = class.construct(activation, &[])?;
+    let microphone = microphone.as_object().unwrap();
+    microphone.set_slot_no_coerce(slots::_INDEX, (index as i32).into(), activation.gc());
+    activation
+        .context
+        .microphones
+        .set_avm2_object(index, microphone);
+
+    Ok(microphone.into())
+}
+
+/// Implements `Microphone.names`'s getter
+pub fn get_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let names = activation.context.microphone.names();
+    let storage = names
+        .iter()
+        .map(|name| Value::from(AvmString::new_utf8(activation.gc(), name)))
+        .collect::<ArrayStorage>();
+
+    Ok(ArrayObject::from_storage(activation.context, storage).into())
+}
+
+/// Implements `Microphone.isSupported`'s getter
+pub fn get_is_supported<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok((!activation.context.microphone.names().is_empty()).into())
+}
+
+/// Implements `Microphone.setLoopBack`
+pub fn set_loop_back<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let settings = activation
+        .context
+        .microphones
+        .settings_mut(device_index(this));
+    settings.loop_back = args.get_bool(0);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Microphone.setSilenceLevel`
+pub fn set_silence_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let silence_level = args.get_f64(0);
+    let timeout = args.get_i32(1);
+
+    let settings = activation
+        .context
+        .microphones
+        .settings_mut(device_index(this));
+    if !silence_level.is_nan() {
+        settings.silence_level = silence_level.clamp(0.0, 100.0);
+    }
+    // A negative timeout keeps the current one.
+    if timeout >= 0 {
+        settings.silence_timeout = timeout;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Microphone.setUseEchoSuppression`
+pub fn set_use_echo_suppression<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let settings = activation
+        .context
+        .microphones
+        .settings_mut(device_index(this));
+    settings.use_echo_suppression = args.get_bool(0);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Microphone.activityLevel`'s getter
+pub fn get_activity_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let level = activation
+        .context
+        .microphones
+        .activity_level(device_index(this));
+    Ok(level.into())
+}
+
+/// Implements `Microphone.gain`'s getter
+pub fn get_gain<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let settings = activation.context.microphones.settings(device_index(this));
+    Ok(settings.gain.into())
+}
+
+/// Implements `Microphone.gain`'s setter
+pub fn set_gain<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let gain = args.get_f64(0);
+    if !gain.is_nan() {
+        let settings = activation
+            .context
+            .microphones
+            .settings_mut(device_index(this));
+        settings.gain = gain.clamp(0.0, 100.0);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Microphone.muted`'s getter
+pub fn get_muted<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let context = &activation.context;
+    Ok(context
+        .microphones
+        .is_muted(&*context.microphone, device_index(this))
+        .into())
+}
+
+/// Implements `Microphone.name`'s getter
+pub fn get_name<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let names = activation.context.microphone.names();
+    let name = names
+        .get(device_index(this))
+        .map(String::as_str)
+        .unwrap_or_default();
+    Ok(AvmString::new_utf8(activation.gc(), name).into())
+}
+
+/// Implements `Microphone.rate`'s getter
+pub fn get_rate<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let settings = activation.context.microphones.settings(device_index(this));
+    Ok(settings.rate().into())
+}
+
+/// Implements `Microphone.rate`'s setter
+pub fn set_rate<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let rate = args.get_i32(0);
+    let settings = activation
+        .context
+        .microphones
+        .settings_mut(device_index(this));
+    settings.set_rate(rate);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Microphone.silenceLevel`'s getter
+pub fn get_silence_level<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let settings = activation.context.microphones.settings(device_index(this));
+    Ok(settings.silence_level.into())
+}
+
+/// Implements `Microphone.silenceTimeout`'s getter
+pub fn get_silence_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let settings = activation.context.microphones.settings(device_index(this));
+    Ok(settings.silence_timeout.into())
+}
+
+/// Implements `Microphone.useEchoSuppression`'s getter
+pub fn get_use_echo_suppression<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let settings = activation.context.microphones.settings(device_index(this));
+    Ok(settings.use_echo_suppression.into())
+}
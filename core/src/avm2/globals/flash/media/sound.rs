@@ -3,7 +3,10 @@
 use crate::avm2::Avm2;
 use crate::avm2::Error;
 use crate::avm2::activation::Activation;
-use crate::avm2::error::{make_error_2037, make_error_2084};
+use crate::avm2::error::{
+    Error2004Type, make_error_2004, make_error_2008, make_error_2029, make_error_2032,
+    make_error_2037, make_error_2084,
+};
 use crate::avm2::globals::methods::flash_media_sound as sound_methods;
 use crate::avm2::globals::slots::flash_net_url_request as url_request_slots;
 use crate::avm2::object::{
@@ -15,7 +18,7 @@ use crate::backend::navigator::Request;
 use crate::character::Character;
 use crate::display_object::SoundTransform;
 use crate::{avm2_stub_getter, avm2_stub_method};
-use swf::{SoundEvent, SoundInfo};
+use swf::{AudioCompression, SoundEvent, SoundFormat, SoundInfo};
 
 pub use crate::avm2::object::sound_allocator;
 
@@ -196,32 +199,86 @@ pub fn play<'gc>(
 /// `Sound.extract`
 pub fn extract<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    this: Value<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.media.Sound", "extract");
+    let this = this.as_object().unwrap();
+
+    let Some(sound) = this.as_sound_object() else {
+        return Ok(0.into());
+    };
 
-    let bytearray = args.try_get_object(0);
+    let target = args.get_object(activation, 0, "target")?;
     let length = args.get_f64(1);
+    let start_position = args.get_f64(2);
+
+    let Some(sound_handle) = sound.sound_handle() else {
+        return Ok(0.into());
+    };
+
+    // A negative start position continues from the end of the previous extraction.
+    let position = if start_position >= 0.0 {
+        start_position as u32
+    } else {
+        sound.extract_position()
+    };
 
-    if let Some(bytearray) = bytearray {
-        if let Some(mut bytearray) = bytearray.as_bytearray_mut() {
+    // Avoid allocating more than the sound could ever provide.
+    let num_sample_frames = activation
+        .context
+        .audio
+        .get_sound_duration(sound_handle)
+        .map_or(0.0, |duration| (duration.as_millis() * 44.1).ceil());
+    let length = if length > 0.0 {
+        length.min((num_sample_frames - f64::from(position)).max(0.0)) as usize
+    } else {
+        0
+    };
+
+    let mut samples = vec![[0.0; 2]; length];
+    let num_extracted =
+        match activation
+            .context
+            .audio
+            .extract_sound(sound_handle, position, &mut samples)
+        {
+            Ok(num_extracted) => num_extracted,
+            Err(e) => {
+                tracing::error!("Sound.extract: failed to decode sound: {e}");
+                0
+            }
+        };
+
+    if let Some(mut bytearray) = target.as_bytearray_mut() {
+        for [left, right] in &samples[..num_extracted] {
+            bytearray
+                .write_float(*left)
+                .map_err(|e| e.to_avm(activation))?;
             bytearray
-                .write_bytes(vec![0u8; length.ceil() as usize].as_slice())
+                .write_float(*right)
                 .map_err(|e| e.to_avm(activation))?;
         }
     }
 
-    Ok(Value::Undefined)
+    sound.set_extract_position(position.saturating_add(num_extracted as u32));
+
+    Ok(num_extracted.into())
 }
 
 /// `Sound.close`
 pub fn close<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    this: Value<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.media.Sound", "close");
+    let this = this.as_object().unwrap().as_sound_object().unwrap();
+
+    if this.loading_state() != SoundLoadingState::Loading {
+        return Err(make_error_2029(activation));
+    }
+
+    // The response of the pending download is discarded once it arrives.
+    this.set_loading_state(SoundLoadingState::Closed);
     Ok(Value::Undefined)
 }
 
@@ -290,10 +347,13 @@ pub fn load_compressed_data_from_byte_array<'gc>(
         return Err(make_error_2084(activation));
     };
 
-    // FIXME - determine the actual error thrown by Flash Player
-    let handle = activation.context.audio.register_mp3(bytes).map_err(|e| {
-        Error::rust_error(format!("Failed to register sound from bytearray: {e:?}").into())
-    })?;
+    let handle = match activation.context.audio.register_mp3(bytes) {
+        Ok(handle) => handle,
+        Err(e) => {
+            tracing::error!("Failed to register sound from bytearray: {e:?}");
+            return Err(make_error_2032(activation));
+        }
+    };
 
     let progress_evt =
         EventObject::progress_event(activation, "progress", bytes.len(), bytes.len());
@@ -310,7 +370,7 @@ pub fn load_compressed_data_from_byte_array<'gc>(
 pub fn load_pcm_from_byte_array<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Value<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this_object = this.as_object().unwrap();
 
@@ -319,11 +379,59 @@ pub fn load_pcm_from_byte_array<'gc>(
         return Ok(Value::Undefined);
     }
 
-    // TODO Add proper implementation.
-    //   The following line ensures proper behavior
-    //   when calling load multiple times.
-    this.set_loading_state(SoundLoadingState::Loaded);
-    avm2_stub_method!(activation, "flash.media.Sound", "loadPCMFromByteArray");
+    let bytearray = args.get_object(activation, 0, "bytes")?;
+    let num_samples = args.get_u32(1);
+    let format = args.get_string_non_null(activation, 2, "format")?;
+    let is_stereo = args.get_bool(3);
+    let sample_rate = args.get_f64(4);
+
+    let is_float = if &format == b"float" {
+        true
+    } else if &format == b"short" {
+        false
+    } else {
+        return Err(make_error_2008(activation, "format"));
+    };
+    if !(1.0..=f64::from(u16::MAX)).contains(&sample_rate) {
+        return Err(make_error_2008(activation, "sampleRate"));
+    }
+
+    // Convert the samples to 16-bit PCM, which the audio backends can decode.
+    let bytearray = bytearray.as_bytearray().unwrap();
+    let num_channels = if is_stereo { 2 } else { 1 };
+    let bytes_per_frame = num_channels * if is_float { 4 } else { 2 };
+    let num_samples = num_samples.min((bytearray.bytes_available() / bytes_per_frame) as u32);
+    let mut data = Vec::with_capacity(num_samples as usize * num_channels * 2);
+    for _ in 0..num_samples as usize * num_channels {
+        let sample = if is_float {
+            let sample = bytearray.read_float().map_err(|e| e.to_avm(activation))?;
+            (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16
+        } else {
+            bytearray.read_short().map_err(|e| e.to_avm(activation))?
+        };
+        data.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    let sound = swf::Sound {
+        id: 0,
+        format: SoundFormat {
+            compression: AudioCompression::Uncompressed,
+            sample_rate: sample_rate as u16,
+            is_stereo,
+            is_16_bit: true,
+        },
+        num_samples,
+        data: &data,
+    };
+    let handle = match activation.context.audio.register_sound(&sound) {
+        Ok(handle) => handle,
+        Err(e) => {
+            tracing::error!("Failed to register PCM sound: {e:?}");
+            return Err(make_error_2004(activation, Error2004Type::ArgumentError));
+        }
+    };
+
+    this.set_sound(activation.context, handle);
 
     Ok(Value::Undefined)
 }
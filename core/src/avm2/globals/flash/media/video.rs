@@ -1,5 +1,6 @@
 use crate::avm2::error::{Error, make_error_2136};
 use crate::avm2::globals::flash::display::display_object::initialize_for_allocator;
+use crate::avm2::globals::flash::media::camera::camera_for_object;
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::{Activation, ClassObject, Object, Value};
 use crate::avm2_stub_method;
@@ -62,6 +63,24 @@ pub fn init<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn attach_camera<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(video) = this.as_display_object().and_then(|dobj| dobj.as_video()) {
+        let camera = args
+            .try_get_object(0)
+            .map(|camera| camera_for_object(activation, camera));
+
+        video.attach_camera(activation.context, camera);
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn attach_net_stream<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Value<'gc>,
@@ -17,15 +17,15 @@ package flash.net {
         public static const CONNECT_TO_FMS:String = "connectToFMS";
         public static const DIRECT_CONNECTIONS:String = "directConnections";
 
-        public function NetStream(connection:NetConnection, peer:String = CONNECT_TO_FMS) {}
-
-        public function appendBytes(bytes:ByteArray) {
-            stub_method("flash.net.NetStream", "appendBytes");
+        public function NetStream(connection:NetConnection, peer:String = CONNECT_TO_FMS) {
+            init(connection);
         }
 
-        public function appendBytesAction(action:String) {
-            stub_method("flash.net.NetStream", "appendBytesAction");
-        }
+        private native function init(connection:NetConnection):void;
+
+        public native function appendBytes(bytes:ByteArray):void;
+
+        public native function appendBytesAction(action:String):void;
 
         public function attach(connection:NetConnection) {
             stub_method("flash.net.NetStream", "attach");
@@ -35,9 +35,7 @@ package flash.net {
             stub_method("flash.net.NetStream", "attachAudio");
         }
 
-        public function attachCamera(cam:Camera, ms:int = -1) {
-            stub_method("flash.net.NetStream", "attachCamera");
-        }
+        public native function attachCamera(cam:Camera, ms:int = -1):void;
 
         public function close() {
             stub_method("flash.net.NetStream", "close");
@@ -133,19 +131,11 @@ package flash.net {
             stub_setter("flash.net.NetStream", "backBufferTime");
         }
 
-        public function get bufferLength():Number {
-            stub_getter("flash.net.NetStream", "bufferLength");
-            return 0.0;
-        }
+        public native function get bufferLength():Number;
 
-        public function get bufferTime():Number {
-            stub_getter("flash.net.NetStream", "bufferTime");
-            return 0.0;
-        }
+        public native function get bufferTime():Number;
 
-        public function set bufferTime(time:Number) {
-            stub_setter("flash.net.NetStream", "bufferTime");
-        }
+        public native function set bufferTime(time:Number);
 
         public function get bufferTimeMax():Number {
             stub_getter("flash.net.NetStream", "bufferTimeMax");
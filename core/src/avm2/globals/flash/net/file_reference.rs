@@ -1,9 +1,11 @@
 use crate::avm2::bytearray::ByteArrayStorage;
 use crate::avm2::error::{make_error_2037, make_error_2097, make_error_2174};
+use crate::avm2::globals::flash::filesystem::file;
 use crate::avm2::globals::slots::flash_net_file_filter as file_filter_slots;
 use crate::avm2::object::{ByteArrayObject, DateObject, FileReference};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::{Activation, Avm2, Error, EventObject, TObject as _, Value};
+use crate::backend::filesystem::file_name;
 use crate::backend::ui::FileFilter;
 use crate::string::AvmString;
 
@@ -18,15 +20,16 @@ pub fn get_creation_date<'gc>(
 
     let this = this.as_file_reference().unwrap();
 
-    let creation_date = match *this.file_reference() {
+    let creation_time = match *this.file_reference() {
         FileReference::None => return Err(make_error_2037(activation)),
-        FileReference::FileDialogResult(ref dialog_result) => {
-            if let Some(time) = dialog_result.creation_time() {
-                DateObject::from_date_time(activation.context, time).into()
-            } else {
-                Value::Null
-            }
-        }
+        FileReference::FileDialogResult(ref dialog_result) => dialog_result.creation_time(),
+        FileReference::Path { ref path, .. } => file::metadata(activation, path)?.creation_time,
+    };
+
+    let creation_date = if let Some(time) = creation_time {
+        DateObject::from_date_time(activation.context, time).into()
+    } else {
+        Value::Null
     };
 
     Ok(creation_date)
@@ -41,16 +44,18 @@ pub fn get_data<'gc>(
 
     let this = this.as_file_reference().unwrap();
 
-    let bytearray = match *this.file_reference() {
+    let bytes = match *this.file_reference() {
         FileReference::FileDialogResult(ref dialog_result) if this.loaded() => {
-            let bytes = dialog_result.contents();
-            let storage = ByteArrayStorage::from_vec(activation.context, bytes.to_vec());
-            ByteArrayObject::from_storage(activation.context, storage)
+            dialog_result.contents().to_vec()
         }
+        FileReference::Path { ref contents, .. } if this.loaded() => contents.clone(),
         // Contrary to other getters `data` will return null instead of throwing.
         _ => return Ok(Value::Null),
     };
 
+    let storage = ByteArrayStorage::from_vec(activation.context, bytes);
+    let bytearray = ByteArrayObject::from_storage(activation.context, storage);
+
     Ok(bytearray.into())
 }
 
@@ -63,15 +68,16 @@ pub fn get_modification_date<'gc>(
 
     let this = this.as_file_reference().unwrap();
 
-    let modification_date = match *this.file_reference() {
+    let modification_time = match *this.file_reference() {
         FileReference::None => return Err(make_error_2037(activation)),
-        FileReference::FileDialogResult(ref dialog_result) => {
-            if let Some(time) = dialog_result.modification_time() {
-                DateObject::from_date_time(activation.context, time).into()
-            } else {
-                Value::Null
-            }
-        }
+        FileReference::FileDialogResult(ref dialog_result) => dialog_result.modification_time(),
+        FileReference::Path { ref path, .. } => file::metadata(activation, path)?.modification_time,
+    };
+
+    let modification_date = if let Some(time) = modification_time {
+        DateObject::from_date_time(activation.context, time).into()
+    } else {
+        Value::Null
     };
 
     Ok(modification_date)
@@ -92,6 +98,9 @@ pub fn get_name<'gc>(
             let name = dialog_result.file_name().unwrap_or_default();
             AvmString::new_utf8(activation.gc(), name).into()
         }
+        FileReference::Path { ref path, .. } => {
+            AvmString::new_utf8(activation.gc(), file_name(path)).into()
+        }
     };
 
     Ok(name)
@@ -109,6 +118,7 @@ pub fn get_size<'gc>(
     let size = match *this.file_reference() {
         FileReference::None => return Err(make_error_2037(activation)),
         FileReference::FileDialogResult(ref dialog_result) => dialog_result.size().unwrap_or(0),
+        FileReference::Path { ref path, .. } => file::metadata(activation, path)?.size,
     };
 
     Ok(Value::Number(size as f64))
@@ -129,6 +139,10 @@ pub fn get_type<'gc>(
             let type_ = dialog_result.file_type().unwrap_or_default();
             AvmString::new_utf8(activation.gc(), type_).into()
         }
+        FileReference::Path { ref path, .. } => match file_name(path).rfind('.') {
+            Some(index) => AvmString::new_utf8(activation.gc(), &file_name(path)[index..]).into(),
+            None => Value::Null,
+        },
     };
 
     Ok(type_)
@@ -212,11 +226,23 @@ pub fn load<'gc>(
     let this = this.as_file_reference().unwrap();
 
     // Somewhat unexpectedly, we don't need to load anything here, because
-    // that already happened during browse() or save().
+    // that already happened during browse() or save(). Only AIR `File`s are
+    // read here.
+    if let Some(path) = this.path() {
+        let bytes = activation
+            .context
+            .filesystem
+            .read(&path)
+            .map_err(|e| file::filesystem_error(activation, e))?;
+        if let FileReference::Path { contents, .. } = &mut *this.file_reference_mut() {
+            *contents = bytes;
+        }
+    }
 
     let size = match *this.file_reference() {
         FileReference::None => return Err(make_error_2037(activation)),
         FileReference::FileDialogResult(ref dialog_result) => dialog_result.size().unwrap_or(0),
+        FileReference::Path { ref contents, .. } => contents.len() as u64,
     };
 
     let size = size as usize;
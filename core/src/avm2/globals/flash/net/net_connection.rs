@@ -41,12 +41,29 @@ pub fn connect<'gc>(
                 connection,
                 url.to_string(),
             );
+        } else if url_lower.starts_with(WStr::from_units(b"rtmp://")) {
+            let mut arguments = Vec::new();
+            let mut object_table = FnvHashMap::default();
+            for arg in &args[1..] {
+                if let Some(value) =
+                    serialize_value(activation, *arg, AMFVersion::AMF0, &mut object_table)
+                {
+                    arguments.push(Rc::new(value));
+                }
+            }
+
+            NetConnections::connect_to_rtmp(
+                activation.context,
+                connection,
+                url.to_string(),
+                arguments,
+            );
         } else {
             avm2_stub_method!(
                 activation,
                 "flash.net.NetConnection",
                 "connect",
-                "with non-null, non-http command"
+                "with non-null, non-http, non-rtmp command"
             );
         }
     } else {
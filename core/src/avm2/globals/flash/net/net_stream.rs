@@ -1,9 +1,90 @@
-use crate::avm2::error::{Error2004Type, make_error_2004};
+use crate::avm2::error::{Error2004Type, make_error_2004, make_error_2008};
+use crate::avm2::globals::flash::media::camera::camera_for_object;
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::{Activation, Error, Value};
+use crate::streams::AppendBytesAction;
 
 pub use crate::avm2::object::netstream_allocator as net_stream_allocator;
 
+pub fn init<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(ns) = this.as_netstream() {
+        let connection = args
+            .try_get_object(0)
+            .and_then(|connection| connection.as_net_connection())
+            .and_then(|connection| connection.handle());
+        ns.set_connection(connection);
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn attach_camera<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(ns) = this.as_netstream() {
+        let camera = args
+            .try_get_object(0)
+            .map(|camera| camera_for_object(activation, camera));
+
+        // TODO: Publish the video of the camera over RTMP.
+        ns.attach_camera(activation.gc(), camera);
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_buffer_length<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(ns) = this.as_netstream() {
+        return Ok(ns.buffer_length().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_buffer_time<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(ns) = this.as_netstream() {
+        return Ok(ns.buffer_time().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn set_buffer_time<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(ns) = this.as_netstream() {
+        ns.set_buffer_time(args.get_f64(0));
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn get_bytes_loaded<'gc>(
     _activation: &mut Activation<'_, 'gc>,
     this: Value<'gc>,
@@ -46,7 +127,64 @@ pub fn play<'gc>(
             .map(|v| v.coerce_to_string(activation))
             .transpose()?;
 
-        ns.play(activation.context, name);
+        if let Some(name) = name {
+            ns.play(activation.context, Some(name));
+        } else {
+            // `play(null)` switches the stream to data generation mode.
+            ns.play_data_generation(activation.context);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn append_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(ns) = this.as_netstream() {
+        let bytearray = args.get_object(activation, 0, "bytes")?;
+        let mut data = bytearray
+            .as_bytearray()
+            .expect("Parameter must be a bytearray!")
+            .bytes()
+            .to_vec();
+
+        if ns.is_data_generation() {
+            ns.append_bytes(activation.context, &mut data);
+        } else {
+            tracing::warn!("NetStream.appendBytes called outside of data generation mode");
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn append_bytes_action<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(ns) = this.as_netstream() {
+        let action = args.get_string(activation, 0);
+        let action = if &action == b"resetBegin" {
+            AppendBytesAction::ResetBegin
+        } else if &action == b"resetSeek" {
+            AppendBytesAction::ResetSeek
+        } else if &action == b"endSequence" {
+            AppendBytesAction::EndSequence
+        } else {
+            return Err(make_error_2008(activation, "action"));
+        };
+
+        if ns.is_data_generation() {
+            ns.append_bytes_action(activation.context, action);
+        }
     }
 
     Ok(Value::Undefined)
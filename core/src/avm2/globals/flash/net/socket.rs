@@ -1,5 +1,3 @@
-use std::rc::Rc;
-
 use crate::avm2::bytearray::{Endian, ObjectEncoding};
 use crate::avm2::error::{make_error_2002, make_error_2003, make_error_2008};
 pub use crate::avm2::object::socket_allocator;
@@ -11,7 +9,7 @@ use encoding_rs::Encoding;
 use encoding_rs::UTF_8;
 use flash_lso::amf0::read::AMF0Decoder;
 use flash_lso::amf3::read::AMF3Decoder;
-use flash_lso::types::{AMFVersion, Element};
+use flash_lso::types::AMFVersion;
 use ruffle_macros::istr;
 
 macro_rules! assert_socket_open {
@@ -715,27 +713,8 @@ pub fn write_object<'gc>(
             ObjectEncoding::Amf3 => AMFVersion::AMF3,
         };
 
-        let amf = crate::avm2::amf::serialize_value(
-            activation,
-            obj,
-            amf_version,
-            &mut Default::default(),
-        )
-        .unwrap_or(flash_lso::types::Value::Undefined);
-
-        let element = Element::new("", Rc::new(amf));
-        let mut lso = flash_lso::types::Lso::new(vec![element], "", amf_version);
-        let bytes =
-            flash_lso::write::write_to_bytes(&mut lso).map_err(|_| "Failed to serialize object")?;
-        // This is kind of hacky: We need to strip out the header and any padding so that we only write
-        // the value. In the future, there should be a method to do this in the flash_lso crate.
-        let element_padding = match amf_version {
-            AMFVersion::AMF0 => 8,
-            AMFVersion::AMF3 => 7,
-        };
-        socket.write_bytes(
-            &bytes[flash_lso::write::header_length(&lso.header) + element_padding..bytes.len() - 1],
-        );
+        let bytes = crate::avm2::amf::serialize_value_to_bytes(activation, obj, amf_version)?;
+        socket.write_bytes(&bytes);
     }
 
     Ok(Value::Undefined)
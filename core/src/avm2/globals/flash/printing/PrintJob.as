@@ -1,5 +1,97 @@
 package flash.printing {
+    import flash.display.Sprite;
     import flash.events.EventDispatcher;
+    import flash.geom.Rectangle;
+    import __ruffle__.stub_method;
 
-    public class PrintJob extends EventDispatcher {}
+    public class PrintJob extends EventDispatcher {
+        // What the job prints on, as chosen when it was started.
+        private var _started:Boolean = false;
+        private var _paperWidth:int = 0;
+        private var _paperHeight:int = 0;
+        private var _pageWidth:int = 0;
+        private var _pageHeight:int = 0;
+        private var _orientation:String = PrintJobOrientation.PORTRAIT;
+
+        public function PrintJob() {
+            super();
+        }
+
+        [API("667")]
+        public static native function get isSupported():Boolean;
+
+        // Returns the paper width, paper height, page width, page height and orientation
+        // of the job, or null if it couldn't be started.
+        private native function startJob():Array;
+
+        private native function printPage(
+            sprite:Sprite,
+            printArea:Rectangle,
+            printAsBitmap:Boolean,
+            pageWidth:int,
+            pageHeight:int
+        ):Boolean;
+
+        private native function sendJob():void;
+
+        public function start():Boolean {
+            var settings:Array = this.startJob();
+            if (settings == null) {
+                this._started = false;
+                return false;
+            }
+
+            this._started = true;
+            this._paperWidth = settings[0];
+            this._paperHeight = settings[1];
+            this._pageWidth = settings[2];
+            this._pageHeight = settings[3];
+            this._orientation = settings[4];
+            return true;
+        }
+
+        public function addPage(
+            sprite:Sprite,
+            printArea:Rectangle = null,
+            options:PrintJobOptions = null,
+            frameNum:int = 0
+        ):void {
+            if (frameNum != 0) {
+                stub_method("flash.printing.PrintJob", "addPage", "with frameNum");
+            }
+
+            var printAsBitmap:Boolean = options != null && options.printAsBitmap;
+            if (!this._started ||
+                !this.printPage(sprite, printArea, printAsBitmap, this._pageWidth, this._pageHeight)) {
+                throw new Error("Error #2057: The page could not be added to the print job.", 2057);
+            }
+        }
+
+        public function send():void {
+            if (this._started) {
+                this._started = false;
+                this.sendJob();
+            }
+        }
+
+        public function get paperWidth():int {
+            return this._paperWidth;
+        }
+
+        public function get paperHeight():int {
+            return this._paperHeight;
+        }
+
+        public function get pageWidth():int {
+            return this._pageWidth;
+        }
+
+        public function get pageHeight():int {
+            return this._pageHeight;
+        }
+
+        public function get orientation():String {
+            return this._orientation;
+        }
+    }
 }
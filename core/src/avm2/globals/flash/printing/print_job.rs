@@ -0,0 +1,83 @@
+//! `flash.printing.PrintJob` native methods
+
+use crate::avm2::globals::flash::display::display_object::object_to_rectangle;
+use crate::avm2::object::ArrayObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayStorage, Error, Value};
+use crate::display_object::{BoundsMode, TDisplayObject};
+use crate::print_job;
+use crate::string::AvmString;
+
+/// Implements `PrintJob.isSupported`
+pub fn get_is_supported<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.print.is_supported().into())
+}
+
+/// Implements `PrintJob.startJob`
+pub fn start_job<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(settings) = activation.context.print.start_job() else {
+        return Ok(Value::Null);
+    };
+
+    let storage = ArrayStorage::from_args(&[
+        settings.paper_width.into(),
+        settings.paper_height.into(),
+        settings.page_width.into(),
+        settings.page_height.into(),
+        AvmString::new_utf8(activation.gc(), settings.orientation.name()).into(),
+    ]);
+    Ok(ArrayObject::from_storage(activation.context, storage).into())
+}
+
+/// Implements `PrintJob.printPage`
+pub fn print_page<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let sprite = args.get_object(activation, 0, "sprite")?;
+    let Some(sprite) = sprite.as_display_object() else {
+        return Ok(false.into());
+    };
+
+    let area = match args.try_get_object(1) {
+        Some(print_area) => Some(object_to_rectangle(activation, print_area)?),
+        None => None,
+    };
+    // Without a (valid) print area, the whole sprite is printed.
+    let area = area
+        .filter(|area| area.width().get() > 0 && area.height().get() > 0)
+        .unwrap_or_else(|| sprite.bounds(BoundsMode::Engine));
+
+    let as_bitmap = args.get_bool(2);
+    let page_size = (args.get_u32(3), args.get_u32(4));
+
+    Ok(print_job::add_page(
+        activation.context,
+        sprite,
+        area,
+        page_size,
+        false,
+        as_bitmap,
+    )
+    .into())
+}
+
+/// Implements `PrintJob.sendJob`
+pub fn send_job<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.context.print.send();
+
+    Ok(Value::Undefined)
+}
@@ -0,0 +1,313 @@
+//! `flash.sampler` namespace
+
+use crate::avm2::function::display_function;
+use crate::avm2::globals::slots::flash_sampler_delete_object_sample as delete_object_sample_slots;
+use crate::avm2::globals::slots::flash_sampler_new_object_sample as new_object_sample_slots;
+use crate::avm2::globals::slots::flash_sampler_sample as sample_slots;
+use crate::avm2::globals::slots::flash_sampler_stack_frame as stack_frame_slots;
+use crate::avm2::method::Method;
+use crate::avm2::object::{ArrayObject, Object, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::property::Property;
+use crate::avm2::sampler::{SampleKind, estimate_size};
+use crate::avm2::vtable::VTable;
+use crate::avm2::{Activation, ArrayStorage, Error, Multiname, Value};
+use crate::string::{AvmString, WString};
+
+pub mod new_object_sample;
+
+/// The kind of method to look up for the invocation count functions.
+#[derive(Clone, Copy)]
+enum MethodKind {
+    Method,
+    Getter,
+    Setter,
+}
+
+/// The vtables to look up the traits of `object` in.
+///
+/// A class stands in for its instances, for when no instance is available.
+fn vtables<'gc>(object: Object<'gc>) -> Vec<VTable<'gc>> {
+    match object.as_class_object() {
+        Some(class) => vec![class.instance_vtable(), object.vtable()],
+        None => vec![object.vtable()],
+    }
+}
+
+/// The name passed to the invocation count functions, if any.
+fn name_arg<'gc>(args: &[Value<'gc>], index: usize) -> Option<Multiname<'gc>> {
+    args.try_get_object(index)
+        .and_then(|name| name.as_qname_object())
+        .map(|name| name.name().clone())
+}
+
+/// The method of `object` of the given kind named `name`, or its constructor
+/// if there's no name.
+fn find_method<'gc>(
+    object: Object<'gc>,
+    name: Option<Multiname<'gc>>,
+    kind: MethodKind,
+) -> Option<Method<'gc>> {
+    let Some(name) = name else {
+        return match (kind, object) {
+            (MethodKind::Method, Object::FunctionObject(function)) => {
+                Some(function.executable().as_method())
+            }
+            (MethodKind::Method, Object::ClassObject(class)) => class.init_method(),
+            (MethodKind::Method, _) => object.instance_class().instance_init(),
+            _ => None,
+        };
+    };
+
+    vtables(object).into_iter().find_map(|vtable| {
+        let disp_id = match (vtable.get_trait(&name)?, kind) {
+            (Property::Method { disp_id }, MethodKind::Method) => disp_id,
+            (
+                Property::Virtual {
+                    get: Some(disp_id), ..
+                },
+                MethodKind::Getter,
+            ) => disp_id,
+            (
+                Property::Virtual {
+                    set: Some(disp_id), ..
+                },
+                MethodKind::Setter,
+            ) => disp_id,
+            _ => return None,
+        };
+        vtable.get_method(disp_id)
+    })
+}
+
+fn invocation_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+    kind: MethodKind,
+) -> Value<'gc> {
+    let Some(object) = args.try_get_object(0) else {
+        return (-1).into();
+    };
+
+    match find_method(object, name_arg(args, 1), kind) {
+        Some(method) => activation.avm2().sampler().invocation_count(method).into(),
+        None => (-1).into(),
+    }
+}
+
+/// Implements `flash.sampler.getInvocationCount`
+pub fn get_invocation_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(invocation_count(activation, args, MethodKind::Method))
+}
+
+/// Implements `flash.sampler.getGetterInvocationCount`
+pub fn get_getter_invocation_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(invocation_count(activation, args, MethodKind::Getter))
+}
+
+/// Implements `flash.sampler.getSetterInvocationCount`
+pub fn get_setter_invocation_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(invocation_count(activation, args, MethodKind::Setter))
+}
+
+/// Implements `flash.sampler.isGetterSetter`
+pub fn is_getter_setter<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let (Some(object), Some(name)) = (args.try_get_object(0), name_arg(args, 1)) else {
+        return Ok(false.into());
+    };
+
+    let is_getter_setter = vtables(object)
+        .into_iter()
+        .any(|vtable| matches!(vtable.get_trait(&name), Some(Property::Virtual { .. })));
+
+    Ok(is_getter_setter.into())
+}
+
+/// Implements `flash.sampler.getSize`
+pub fn get_size<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok((estimate_size(args.get_value(0)) as f64).into())
+}
+
+/// Implements `flash.sampler.startSampling`
+pub fn start_sampling<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler_mut().start();
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.pauseSampling`
+pub fn pause_sampling<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler_mut().pause();
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.stopSampling`
+pub fn stop_sampling<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler_mut().stop();
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.clearSamples`
+pub fn clear_samples<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler_mut().clear_samples();
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.setSamplerCallback`
+pub fn set_sampler_callback<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let callback = args.try_get_function(0).map(Object::from);
+    activation.avm2().sampler_mut().set_callback(callback);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.getSampleCount`
+pub fn get_sample_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mc = activation.gc();
+    let count = activation.avm2().sampler_mut().samples(mc).len();
+
+    Ok((count as f64).into())
+}
+
+/// Implements `flash.sampler.getSamples`
+pub fn get_samples<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mc = activation.gc();
+    let sampler = activation.avm2().sampler_mut();
+    let samples = sampler.samples(mc).to_vec();
+
+    // Don't sample the creation of the samples themselves.
+    let was_sampling = sampler.is_sampling();
+    sampler.pause();
+
+    let result = samples
+        .into_iter()
+        .map(|sample| {
+            let classes = activation.avm2().classes();
+            let class = match sample.kind {
+                SampleKind::Stack => classes.sample,
+                SampleKind::NewObject { .. } => classes.newobjectsample,
+                SampleKind::DeleteObject { .. } => classes.deleteobjectsample,
+            };
+            let object = class.construct(activation, &[])?.as_object().unwrap();
+
+            let stack = stack_frames(activation, &sample.stack)?;
+            object.set_slot_no_coerce(sample_slots::TIME, sample.time.into(), mc);
+            object.set_slot_no_coerce(sample_slots::STACK, stack.into(), mc);
+
+            match sample.kind {
+                SampleKind::Stack => {}
+                SampleKind::NewObject { id, class, size } => {
+                    object.set_slot_no_coerce(new_object_sample_slots::ID, (id as f64).into(), mc);
+                    object.set_slot_no_coerce(new_object_sample_slots::TYPE, class.into(), mc);
+                    object.set_slot_no_coerce(
+                        new_object_sample_slots::_SIZE,
+                        (size as f64).into(),
+                        mc,
+                    );
+                }
+                SampleKind::DeleteObject { id, size } => {
+                    object.set_slot_no_coerce(
+                        delete_object_sample_slots::ID,
+                        (id as f64).into(),
+                        mc,
+                    );
+                    object.set_slot_no_coerce(
+                        delete_object_sample_slots::SIZE,
+                        (size as f64).into(),
+                        mc,
+                    );
+                }
+            }
+
+            Ok(Value::from(object))
+        })
+        .collect::<Result<ArrayStorage, Error<'gc>>>()
+        .map(|samples| ArrayObject::from_storage(activation.context, samples));
+
+    if was_sampling {
+        activation.avm2().sampler_mut().start();
+    }
+
+    Ok(result?.into())
+}
+
+/// An array of `StackFrame`s for `stack`, innermost method first.
+fn stack_frames<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    stack: &[Method<'gc>],
+) -> Result<Object<'gc>, Error<'gc>> {
+    let mc = activation.gc();
+    let class = activation.avm2().classes().stackframe;
+
+    let frames = stack
+        .iter()
+        .rev()
+        .map(|method| {
+            let mut name = WString::new();
+            display_function(&mut name, *method);
+            let name = AvmString::new(mc, name);
+
+            // Line information isn't kept after verification.
+            let frame = class.construct(activation, &[])?.as_object().unwrap();
+            frame.set_slot_no_coerce(stack_frame_slots::NAME, name.into(), mc);
+            frame.set_slot_no_coerce(stack_frame_slots::FILE, Value::Null, mc);
+            frame.set_slot_no_coerce(stack_frame_slots::LINE, 0.into(), mc);
+            frame.set_slot_no_coerce(stack_frame_slots::SCRIPT_ID, 0.into(), mc);
+
+            Ok(Value::from(frame))
+        })
+        .collect::<Result<ArrayStorage, Error<'gc>>>()?;
+
+    Ok(ArrayObject::from_storage(activation.context, frames).into())
+}
@@ -1,18 +1,18 @@
 package flash.sampler {
-    import __ruffle__.stub_getter;
-
     public final class NewObjectSample extends Sample {
+        [Ruffle(NativeAccessible)]
         public const id:Number;
+
+        [Ruffle(NativeAccessible)]
         public const type:Class;
 
-        public function get object():* {
-            stub_getter("flash.sampler.NewObjectSample", "object");
-            return {};
-        }
+        [Ruffle(NativeAccessible)]
+        private var _size:Number;
+
+        public native function get object():*;
 
         public function get size():Number {
-            stub_getter("flash.sampler.NewObjectSample", "size");
-            return 0;
+            return this._size;
         }
     }
 }
@@ -1,6 +1,9 @@
 package flash.sampler {
     public class Sample {
+        [Ruffle(NativeAccessible)]
         public const time:Number;
+
+        [Ruffle(NativeAccessible)]
         public const stack:Array;
     }
 }
@@ -0,0 +1,21 @@
+//! `flash.sampler.NewObjectSample` native methods
+
+use crate::avm2::globals::slots::flash_sampler_new_object_sample as slots;
+use crate::avm2::object::TObject;
+use crate::avm2::{Activation, Error, Value};
+
+/// Implements `NewObjectSample.object`'s getter
+pub fn get_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    // The object is undefined once it has been collected.
+    let id = this.get_slot(slots::ID).as_f64() as u64;
+    let mc = activation.gc();
+    let object = activation.avm2().sampler().object(mc, id);
+
+    Ok(object.map_or(Value::Undefined, Value::from))
+}
@@ -1,46 +1,26 @@
 package flash.system {
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-
-    import flash.events.Event;
     import flash.events.EventDispatcher;
     import flash.system.MessageChannel;
 
     [API("682")]
     [Ruffle(Abstract)]
     public final class Worker extends EventDispatcher {
-        public static function get isSupported():Boolean {
-            return false;
-        }
-
-        private static var _current:Worker;
+        public static native function get isSupported():Boolean;
 
-        public static function get current():Worker {
-            stub_getter("flash.system.Worker", "current");
+        public static native function get current():Worker;
 
-            if (!_current) {
-                _current = instantiateInternal();
-            }
+        public native function get isPrimordial():Boolean;
 
-            return _current;
-        }
+        public native function get state():String;
 
         public native function createMessageChannel(receiver:Worker):MessageChannel;
 
-        public function setSharedProperty(key:String, value:*):void {
-            stub_method("flash.system.Worker", "setSharedProperty");
-        }
-
-        public function getSharedProperty(key:String):* {
-            stub_method("flash.system.Worker", "getSharedProperty");
-        }
+        public native function setSharedProperty(key:String, value:*):void;
 
-        public function start():void {
-            this.dispatchEvent(new Event(Event.WORKER_STATE));
+        public native function getSharedProperty(key:String):*;
 
-            stub_method("flash.system.Worker", "start");
-        }
+        public native function start():void;
 
-        private static native function instantiateInternal():Worker;
+        public native function terminate():Boolean;
     }
 }
@@ -1,21 +1,15 @@
 package flash.system {
-    import __ruffle__.stub_getter;
-
     import flash.utils.ByteArray;
     import flash.system.Worker;
 
     [API("680")] // the docs say 682, that's wrong
     [Ruffle(Abstract)]
     public final class WorkerDomain {
-        public static function get isSupported():Boolean {
-            return false;
-        }
+        public static native function get isSupported():Boolean;
 
         private static var _current:WorkerDomain;
 
         public static function get current():WorkerDomain {
-            stub_getter("flash.system.WorkerDomain", "current");
-
             if (!_current) {
                 _current = instantiateInternal();
             }
@@ -0,0 +1,91 @@
+//! `flash.system.MessageChannel` native methods
+
+use crate::avm2::Error;
+use crate::avm2::activation::Activation;
+use crate::avm2::globals::flash::system::worker::{from_shared_value, to_shared_value};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::string::AvmString;
+
+/// Implements `MessageChannel.messageAvailable`
+pub fn get_message_available<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(channel) = this.as_message_channel() {
+        return Ok(channel.handle().message_available().into());
+    }
+
+    Ok(false.into())
+}
+
+/// Implements `MessageChannel.state`
+pub fn get_state<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(channel) = this.as_message_channel() {
+        let state = channel.handle().state().as_str();
+        return Ok(AvmString::new_utf8(activation.gc(), state).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MessageChannel.send`
+pub fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(channel) = this.as_message_channel() {
+        let value = to_shared_value(activation, args.get_value(0))?;
+        let queue_limit = usize::try_from(args.get_i32(1)).ok();
+        if !channel.handle().send(value, queue_limit) {
+            tracing::warn!("MessageChannel.send: Channel is closed");
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MessageChannel.receive`
+pub fn receive<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(channel) = this.as_message_channel() {
+        let block_until_received = args.get_bool(0);
+        if let Some(value) = channel.handle().receive(block_until_received) {
+            return from_shared_value(activation, &value);
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `MessageChannel.close`
+pub fn close<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(channel) = this.as_message_channel() {
+        channel.handle().close();
+    }
+
+    Ok(Value::Undefined)
+}
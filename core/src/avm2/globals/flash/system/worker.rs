@@ -2,32 +2,230 @@
 
 use crate::avm2::Error;
 use crate::avm2::activation::Activation;
-use crate::avm2::object::{MessageChannelObject, WorkerObject};
+use crate::avm2::amf::{deserialize_value_from_bytes, serialize_value_to_bytes};
+use crate::avm2::error::make_error_2007;
+use crate::avm2::object::{
+    ByteArrayObject, ConditionObject, MessageChannelObject, MutexObject, WorkerObject,
+};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
-use crate::avm2_stub_method;
+use crate::string::AvmString;
+use crate::worker::{MessageChannelHandle, SharedValue, WORKERS_SUPPORTED};
+use flash_lso::types::AMFVersion;
+
+/// Converts a value to be handed to another worker.
+///
+/// Shareable `ByteArray`s and concurrency objects are passed by reference,
+/// everything else is copied using AMF3.
+pub fn to_shared_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<SharedValue, Error<'gc>> {
+    if let Some(object) = value.as_object() {
+        if let Some(bytearray) = object.as_bytearray_object() {
+            if bytearray.shareable() {
+                return Ok(SharedValue::ByteArray(bytearray.share()));
+            }
+        } else if let Some(worker) = object.as_worker() {
+            return Ok(SharedValue::Worker(worker.handle().clone()));
+        } else if let Some(channel) = object.as_message_channel() {
+            return Ok(SharedValue::MessageChannel(channel.handle().clone()));
+        } else if let Some(mutex) = object.as_mutex() {
+            return Ok(SharedValue::Mutex(mutex.handle().clone()));
+        } else if let Some(condition) = object.as_condition() {
+            if let Some(handle) = condition.handle().clone() {
+                return Ok(SharedValue::Condition(handle));
+            }
+        }
+    }
+
+    Ok(SharedValue::Serialized(serialize_value_to_bytes(
+        activation,
+        value,
+        AMFVersion::AMF3,
+    )?))
+}
+
+/// Converts a value received from another worker.
+pub fn from_shared_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    value: &SharedValue,
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(match value {
+        SharedValue::Serialized(bytes) => deserialize_value_from_bytes(activation, bytes)?,
+        SharedValue::ByteArray(storage) => {
+            ByteArrayObject::from_shared_storage(activation.context, storage.clone()).into()
+        }
+        SharedValue::Worker(handle) => {
+            let worker = WorkerObject::new(activation, handle.clone());
+            activation.context.workers.track_worker(worker);
+            worker.into()
+        }
+        SharedValue::MessageChannel(handle) => {
+            let channel = MessageChannelObject::new(activation, handle.clone());
+            activation.context.workers.track_channel(channel);
+            channel.into()
+        }
+        SharedValue::Mutex(handle) => MutexObject::from_handle(activation, handle.clone()).into(),
+        SharedValue::Condition(handle) => {
+            ConditionObject::from_handle(activation, handle.clone()).into()
+        }
+    })
+}
+
+/// Implements `Worker.isSupported`
+pub fn get_is_supported<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(WORKERS_SUPPORTED.into())
+}
+
+/// Implements `Worker.current`
+pub fn get_current<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(current) = activation.context.workers.current_object() {
+        return Ok(current.into());
+    }
+
+    let handle = activation.context.workers.current().clone();
+    let current = WorkerObject::new(activation, handle);
+    activation.context.workers.set_current_object(current);
+
+    Ok(current.into())
+}
+
+/// Implements `Worker.isPrimordial`
+pub fn get_is_primordial<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(worker) = this.as_worker() {
+        return Ok(worker.handle().is_primordial().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Worker.state`
+pub fn get_state<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(worker) = this.as_worker() {
+        let state = worker.handle().state().as_str();
+        return Ok(AvmString::new_utf8(activation.gc(), state).into());
+    }
+
+    Ok(Value::Undefined)
+}
 
 /// Implements `Worker.createMessageChannel`
 pub fn create_message_channel<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    this: Value<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.system.Worker", "createMessageChannel");
+    let this = this.as_object().unwrap();
 
-    let _receiver = args.get_object(activation, 0, "receiver")?;
+    let Some(receiver) = args
+        .try_get_object(0)
+        .and_then(|receiver| receiver.as_worker())
+    else {
+        return Err(make_error_2007(activation, "receiver"));
+    };
 
-    let message_channel = MessageChannelObject::new(activation);
+    if let Some(worker) = this.as_worker() {
+        let handle = MessageChannelHandle::new(worker.handle(), receiver.handle());
+        let channel = MessageChannelObject::new(activation, handle);
+        activation.context.workers.track_channel(channel);
+        return Ok(channel.into());
+    }
 
-    Ok(message_channel.into())
+    Ok(Value::Null)
 }
 
-pub fn instantiate_internal<'gc>(
+/// Implements `Worker.setSharedProperty`
+pub fn set_shared_property<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Value<'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(worker) = this.as_worker() {
+        let key = args.get_string(activation, 0).to_string();
+        let value = match args.get_value(1) {
+            Value::Undefined => None,
+            value => Some(to_shared_value(activation, value)?),
+        };
+        worker.handle().set_shared_property(key, value);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Worker.getSharedProperty`
+pub fn get_shared_property<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(worker) = this.as_worker() {
+        let key = args.get_string(activation, 0).to_string();
+        if let Some(value) = worker.handle().shared_property(&key) {
+            return from_shared_value(activation, &value);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Worker.start`
+pub fn start<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(worker) = this.as_worker() {
+        activation.context.workers.track_worker(worker);
+
+        let player_version = activation.context.player_version;
+        let player_runtime = activation.avm2().player_runtime;
+        activation
+            .context
+            .workers
+            .start(worker.handle(), player_version, player_runtime);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Worker.terminate`
+pub fn terminate<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    let worker = WorkerObject::new(activation);
+    let this = this.as_object().unwrap();
+
+    if let Some(worker) = this.as_worker() {
+        return Ok(worker.handle().terminate().into());
+    }
 
-    Ok(worker.into())
+    Ok(false.into())
 }
@@ -2,10 +2,21 @@
 
 use crate::avm2::Error;
 use crate::avm2::activation::Activation;
+use crate::avm2::error::{make_error_1508, make_error_2007};
 use crate::avm2::object::{WorkerDomainObject, WorkerObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
-use crate::avm2_stub_method;
+use crate::tag_utils::SwfMovie;
+use crate::worker::{WORKERS_SUPPORTED, WorkerHandle};
+
+/// Implements `WorkerDomain.isSupported`
+pub fn get_is_supported<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(WORKERS_SUPPORTED.into())
+}
 
 /// Implements `WorkerDomain.createWorker`
 pub fn create_worker<'gc>(
@@ -13,12 +24,27 @@ pub fn create_worker<'gc>(
     _this: Value<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.system.WorkerDomain", "createWorker");
-
-    let _swf = args.get_object(activation, 0, "swf")?;
+    let Some(swf) = args.try_get_object(0) else {
+        return Err(make_error_2007(activation, "swf"));
+    };
+    // Ruffle doesn't restrict what AIR content may do, so a background worker
+    // has the same privileges as the primordial worker either way.
     let _give_app_privileges = args.get_bool(1);
 
-    let worker = WorkerObject::new(activation);
+    let Some(bytes) = swf.as_bytearray().map(|storage| storage.bytes().to_vec()) else {
+        return Err(make_error_2007(activation, "swf"));
+    };
+
+    let url = activation.context.root_swf.url().to_string();
+    let movie = match SwfMovie::from_data(&bytes, url.clone(), Some(url)) {
+        Ok(movie) => movie,
+        Err(e) => {
+            tracing::warn!("WorkerDomain.createWorker: Invalid SWF: {e}");
+            return Err(make_error_1508(activation, "swf"));
+        }
+    };
+
+    let worker = WorkerObject::new(activation, WorkerHandle::background(movie));
 
     Ok(worker.into())
 }
@@ -7,11 +7,18 @@ package flash.text.engine {
 
         public var userData;
 
+        [Ruffle(NativeAccessible)]
         internal var _text:String = null;
 
         [Ruffle(NativeAccessible)]
         private var _elementFormat:ElementFormat;
 
+        [Ruffle(NativeAccessible)]
+        private var _eventMirror:EventDispatcher;
+
+        [Ruffle(NativeAccessible)]
+        private var _textRotation:String;
+
         public function ContentElement(
             elementFormat:ElementFormat = null,
             eventMirror:EventDispatcher = null,
@@ -19,6 +26,8 @@ package flash.text.engine {
         ) {
             // FIXME: `new ContentElement()` throws an error in Flash; see TextJustifier
             this._elementFormat = elementFormat;
+            this._eventMirror = eventMirror;
+            this._textRotation = textRotation;
         }
 
         [Ruffle(NativeCallable)]
@@ -27,7 +36,7 @@ package flash.text.engine {
         }
 
         public function get rawText():String {
-            return this._text;
+            return this.text;
         }
 
         public function get elementFormat():ElementFormat {
@@ -37,5 +46,21 @@ package flash.text.engine {
         public function set elementFormat(value:ElementFormat):void {
             this._elementFormat = value;
         }
+
+        public function get eventMirror():EventDispatcher {
+            return this._eventMirror;
+        }
+
+        public function set eventMirror(value:EventDispatcher):void {
+            this._eventMirror = value;
+        }
+
+        public function get textRotation():String {
+            return this._textRotation;
+        }
+
+        public function set textRotation(value:String):void {
+            this._textRotation = value;
+        }
     }
 }
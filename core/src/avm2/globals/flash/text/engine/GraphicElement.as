@@ -1,13 +1,18 @@
 package flash.text.engine {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_setter;
-
     import flash.display.DisplayObject;
     import flash.events.EventDispatcher;
 
     [API("662")]
     public final class GraphicElement extends ContentElement {
+        [Ruffle(NativeAccessible)]
+        private var _graphic:DisplayObject;
+
+        [Ruffle(NativeAccessible)]
+        private var _elementWidth:Number;
+
+        [Ruffle(NativeAccessible)]
+        private var _elementHeight:Number;
+
         public function GraphicElement(
             graphic:DisplayObject = null,
             elementWidth:Number = 15.0,
@@ -16,33 +21,37 @@ package flash.text.engine {
             eventMirror:EventDispatcher = null,
             textRotation:String = "rotate0"
         ) {
-            stub_constructor("flash.text.engine.GraphicElement");
+            super(elementFormat, eventMirror, textRotation);
+            this._graphic = graphic;
+            this._elementWidth = elementWidth;
+            this._elementHeight = elementHeight;
+
+            // A graphic occupies a single placeholder character in the text.
+            this._text = String.fromCharCode(ContentElement.GRAPHIC_ELEMENT);
         }
 
         public function get elementHeight():Number {
-            stub_getter("flash.text.engine.GraphicElement", "elementHeight");
-            return 15.0;
+            return this._elementHeight;
         }
+
         public function set elementHeight(value:Number):void {
-            stub_setter("flash.text.engine.GraphicElement", "elementHeight");
+            this._elementHeight = value;
         }
 
         public function get elementWidth():Number {
-            stub_getter("flash.text.engine.GraphicElement", "elementWidth");
-            return 15.0;
+            return this._elementWidth;
         }
 
         public function set elementWidth(value:Number):void {
-            stub_setter("flash.text.engine.GraphicElement", "elementWidth");
+            this._elementWidth = value;
         }
 
         public function get graphic():DisplayObject {
-            stub_getter("flash.text.engine.GraphicElement", "graphic");
-            return null;
+            return this._graphic;
         }
 
         public function set graphic(value:DisplayObject):void {
-            stub_setter("flash.text.engine.GraphicElement", "graphic");
+            this._graphic = value;
         }
     }
 }
@@ -1,6 +1,7 @@
 package flash.text.engine {
     [API("662")]
     public final class SpaceJustifier extends TextJustifier {
+        [Ruffle(NativeAccessible)]
         private var _letterSpacing:Boolean;
         private var _minimumSpacing:Number = 0.5;
         private var _optimumSpacing:Number = 1.0;
@@ -1,6 +1,4 @@
 package flash.text.engine {
-    import __ruffle__.stub_method;
-
     [API("662")]
     public final class TextBlock {
         public var userData;
@@ -12,6 +10,7 @@ package flash.text.engine {
         private var _bidiLevel:int;
         private var _lineRotation:String;
         private var _tabStops:Vector.<TabStop>;
+        [Ruffle(NativeAccessible)]
         private var _textJustifier:TextJustifier;
 
         [Ruffle(NativeAccessible)]
@@ -23,6 +22,9 @@ package flash.text.engine {
         [Ruffle(NativeAccessible)]
         private var _firstLine:TextLine = null;
 
+        [Ruffle(NativeAccessible)]
+        private var _lastLine:TextLine = null;
+
         public function TextBlock(
             content:ContentElement = null,
             tabStops:Vector.<TabStop> = null,
@@ -45,7 +47,7 @@ package flash.text.engine {
             if (textJustifier) {
                 this.textJustifier = textJustifier;
             } else {
-                // This should create a new TextJustifier with locale "en", but we don't actually support creating TextJustifiers yet.
+                this.textJustifier = new SpaceJustifier("en", LineJustification.UNJUSTIFIED);
             }
 
             this.lineRotation = lineRotation;
@@ -136,14 +138,21 @@ package flash.text.engine {
 
         public function set content(value:ContentElement):void {
             this._content = value;
+            this.invalidateLines();
         }
 
-        public native function createTextLine(
+        public function createTextLine(
             previousLine:TextLine = null,
             width:Number = 1000000,
             lineOffset:Number = 0,
             fitSomething:Boolean = false
-        ):TextLine;
+        ):TextLine {
+            var line:TextLine = this.createTextLineInternal(previousLine, width, lineOffset, fitSomething);
+            if (line) {
+                line.listenToMirrors();
+            }
+            return line;
+        }
 
         public function recreateTextLine(
             textLine:TextLine,
@@ -152,21 +161,31 @@ package flash.text.engine {
             lineOffset:Number = 0,
             fitSomething:Boolean = false
         ):TextLine {
-            if (textLine == null) {
+            if (textLine == null || textLine == previousLine) {
                 throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
             }
 
-            if (previousLine) {
-                return null;
+            var line:TextLine = this.recreateTextLineInternal(textLine, previousLine, width, lineOffset, fitSomething);
+            if (line) {
+                line.listenToMirrors();
             }
+            return line;
+        }
 
-            stub_method("flash.text.engine.TextBlock", "recreateTextLine");
+        private native function createTextLineInternal(
+            previousLine:TextLine,
+            width:Number,
+            lineOffset:Number,
+            fitSomething:Boolean
+        ):TextLine;
 
-            // FIXME: Properly recalculate new properties of new TextLine. Text layout
-            // modules often depend on this returning the same textLine, so we can't
-            // call `createTextLine` again.
-            return textLine;
-        }
+        private native function recreateTextLineInternal(
+            textLine:TextLine,
+            previousLine:TextLine,
+            width:Number,
+            lineOffset:Number,
+            fitSomething:Boolean
+        ):TextLine;
 
         public function get textLineCreationResult():String {
             return this._textLineCreationResult;
@@ -177,16 +196,130 @@ package flash.text.engine {
         }
 
         public function get lastLine():TextLine {
-            return this._firstLine;
+            return this._lastLine;
+        }
+
+        public function get firstInvalidLine():TextLine {
+            for (var line:TextLine = this._firstLine; line != null; line = line._nextLine) {
+                if (line._validity != TextLineValidity.VALID) {
+                    return line;
+                }
+            }
+            return null;
+        }
+
+        public function getTextLineAtCharIndex(charIndex:int):TextLine {
+            if (charIndex < 0 || charIndex >= this.textLength()) {
+                throw new RangeError("Error #2006: The supplied index is out of bounds.", 2006);
+            }
+
+            for (var line:TextLine = this._firstLine; line != null; line = line._nextLine) {
+                var begin:int = line.textBlockBeginIndex;
+                if (charIndex >= begin && charIndex < begin + line.rawTextLength) {
+                    return line;
+                }
+            }
+            return null;
+        }
+
+        public function releaseLines(firstLine:TextLine, lastLine:TextLine):void {
+            if (firstLine == null || lastLine == null ||
+                firstLine._textBlock != this || lastLine._textBlock != this) {
+                throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
+            }
+
+            var before:TextLine = firstLine._previousLine;
+            var after:TextLine = lastLine._nextLine;
+
+            var line:TextLine = firstLine;
+            while (line != null) {
+                var next:TextLine = line._nextLine;
+                line._validity = TextLineValidity.INVALID;
+                line._textBlock = null;
+                line._previousLine = null;
+                line._nextLine = null;
+                if (line == lastLine) {
+                    break;
+                }
+                line = next;
+            }
+
+            if (before != null) {
+                before._nextLine = after;
+            } else {
+                this._firstLine = after;
+            }
+
+            if (after != null) {
+                after._previousLine = before;
+            } else {
+                this._lastLine = before;
+            }
+        }
+
+        public function releaseLineCreationData():void {
+            // Ruffle doesn't keep any data around between creating lines.
+        }
+
+        public function findNextAtomBoundary(afterCharIndex:int):int {
+            if (afterCharIndex < 0 || afterCharIndex >= this.textLength()) {
+                throw new RangeError("Error #2006: The supplied index is out of bounds.", 2006);
+            }
+            return afterCharIndex + 1;
+        }
+
+        public function findPreviousAtomBoundary(beforeCharIndex:int):int {
+            if (beforeCharIndex < 0 || beforeCharIndex > this.textLength()) {
+                throw new RangeError("Error #2006: The supplied index is out of bounds.", 2006);
+            }
+            return beforeCharIndex > 0 ? beforeCharIndex - 1 : 0;
+        }
+
+        public function findNextWordBoundary(afterCharIndex:int):int {
+            var text:String = this.rawText();
+            if (afterCharIndex < 0 || afterCharIndex >= text.length) {
+                throw new RangeError("Error #2006: The supplied index is out of bounds.", 2006);
+            }
+
+            var index:int = afterCharIndex + 1;
+            while (index < text.length &&
+                   TextLine.isWhitespace(text.charAt(index)) == TextLine.isWhitespace(text.charAt(index - 1))) {
+                index++;
+            }
+            return index;
+        }
+
+        public function findPreviousWordBoundary(beforeCharIndex:int):int {
+            var text:String = this.rawText();
+            if (beforeCharIndex < 0 || beforeCharIndex > text.length) {
+                throw new RangeError("Error #2006: The supplied index is out of bounds.", 2006);
+            }
+
+            var index:int = beforeCharIndex > 0 ? beforeCharIndex - 1 : 0;
+            while (index > 0 &&
+                   TextLine.isWhitespace(text.charAt(index)) == TextLine.isWhitespace(text.charAt(index - 1))) {
+                index--;
+            }
+            return index;
+        }
+
+        private function rawText():String {
+            if (this._content == null || this._content.rawText == null) {
+                return "";
+            }
+            return this._content.rawText;
+        }
+
+        private function textLength():int {
+            return this.rawText().length;
         }
 
-        public function releaseLines(start:TextLine, end:TextLine):void {
-            if (start != end || end != this._firstLine) {
-                stub_method("flash.text.engine.TextBlock", "releaseLines", "with start != end or multiple lines");
-                return;
+        private function invalidateLines():void {
+            for (var line:TextLine = this._firstLine; line != null; line = line._nextLine) {
+                if (line._validity == TextLineValidity.VALID) {
+                    line._validity = TextLineValidity.INVALID;
+                }
             }
-            this._firstLine._validity = "invalid";
-            this._firstLine._textBlock = null;
         }
     }
 }
@@ -1,11 +1,9 @@
 package flash.text.engine {
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_setter;
-    import __ruffle__.stub_method;
-
     import flash.display.DisplayObject;
     import flash.display.DisplayObjectContainer;
     import flash.errors.IllegalOperationError;
+    import flash.events.EventDispatcher;
+    import flash.events.MouseEvent;
     import flash.geom.Rectangle;
     import flash.ui.ContextMenu;
 
@@ -16,6 +14,16 @@ package flash.text.engine {
     [Ruffle(Abstract)]
     [API("662")]
     public final class TextLine extends DisplayObjectContainer {
+        // Mouse events that are forwarded to the event mirrors of the content.
+        private static const MIRRORED_EVENTS:Array = [
+            MouseEvent.CLICK,
+            MouseEvent.DOUBLE_CLICK,
+            MouseEvent.MOUSE_DOWN,
+            MouseEvent.MOUSE_MOVE,
+            MouseEvent.MOUSE_UP,
+            MouseEvent.MOUSE_WHEEL
+        ];
+
         [Ruffle(NativeAccessible)]
         private var _specifiedWidth:Number = 0.0;
 
@@ -25,8 +33,48 @@ package flash.text.engine {
         [Ruffle(NativeAccessible)]
         private var _rawTextLength:int = 0;
 
+        [Ruffle(NativeAccessible)]
+        private var _textBlockBeginIndex:int = 0;
+
+        [Ruffle(NativeAccessible)]
+        private var _rawText:String = "";
+
+        [Ruffle(NativeAccessible)]
+        private var _ascent:Number = 0.0;
+
+        [Ruffle(NativeAccessible)]
+        private var _descent:Number = 0.0;
+
+        [Ruffle(NativeAccessible)]
+        private var _totalAscent:Number = 0.0;
+
+        [Ruffle(NativeAccessible)]
+        private var _unjustifiedTextWidth:Number = 0.0;
+
+        [Ruffle(NativeAccessible)]
+        private var _hasGraphicElement:Boolean = false;
+
+        [Ruffle(NativeAccessible)]
+        private var _hasTabs:Boolean = false;
+
+        // The `ContentElement` each atom of this line belongs to.
+        [Ruffle(NativeAccessible)]
+        private var _atomElements:Array = null;
+
+        [Ruffle(NativeAccessible)]
+        private var _mirrorRegions:Array = null;
+
+        [Ruffle(NativeAccessible)]
+        internal var _nextLine:TextLine = null;
+
+        [Ruffle(NativeAccessible)]
+        internal var _previousLine:TextLine = null;
+
+        [Ruffle(NativeAccessible)]
         internal var _validity:String = "valid";
 
+        private var _listeningToMirrors:Boolean = false;
+
         public static const MAX_LINE_WIDTH:int = 1000000;
 
         public var userData;
@@ -36,8 +84,7 @@ package flash.text.engine {
         }
 
         public function get textBlockBeginIndex():int {
-            stub_getter("flash.text.engine.TextLine", "textBlockBeginIndex");
-            return 0;
+            return this._textBlockBeginIndex;
         }
 
         public function get specifiedWidth():Number {
@@ -49,126 +96,195 @@ package flash.text.engine {
         }
 
         public function get ascent():Number {
-            stub_getter("flash.text.engine.TextLine", "ascent");
-            return 12.0;
+            return this._ascent;
         }
 
         [API("670")]
         public function get totalAscent():Number {
-            stub_getter("flash.text.engine.TextLine", "totalAscent");
-            return 12.0;
+            return this._totalAscent;
         }
 
         public function get descent():Number {
-            stub_getter("flash.text.engine.TextLine", "descent");
-            return 3.0;
+            return this._descent;
         }
 
         [API("670")]
         public function get totalDescent():Number {
-            stub_getter("flash.text.engine.TextLine", "totalDescent");
-            return 3.0;
+            return this._descent;
+        }
+
+        [API("670")]
+        public function get totalHeight():Number {
+            return this._totalAscent + this._descent;
         }
 
         public function get unjustifiedTextWidth():Number {
-            stub_getter("flash.text.engine.TextLine", "unjustifiedTextWidth");
-            return this._specifiedWidth;
+            return this._unjustifiedTextWidth;
         }
 
         public native function get textWidth():Number;
         public native function get textHeight():Number;
 
         public function get validity():String {
-            stub_getter("flash.text.engine.TextLine", "validity");
             return this._validity;
         }
 
         public function set validity(value:String):void {
-            stub_setter("flash.text.engine.TextLine", "validity");
+            // Only the text engine itself can make a line valid, and static lines stay static.
+            if (this._validity == TextLineValidity.STATIC ||
+                (value != TextLineValidity.INVALID &&
+                 value != TextLineValidity.POSSIBLY_INVALID &&
+                 value != TextLineValidity.STATIC)) {
+                throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
+            }
+
             this._validity = value;
         }
 
         public function get hasGraphicElement():Boolean {
-            stub_getter("flash.text.engine.TextLine", "hasGraphicElement");
-            return false;
+            return this._hasGraphicElement;
         }
 
         public function get atomCount():int {
-            stub_getter("flash.text.engine.TextLine", "atomCount");
             return this._rawTextLength;
         }
 
         public function get nextLine():TextLine {
-            return null;
+            return this._nextLine;
         }
 
         public function get previousLine():TextLine {
+            return this._previousLine;
+        }
+
+        public function get mirrorRegions():Vector.<TextLineMirrorRegion> {
+            if (this._mirrorRegions == null) {
+                return null;
+            }
+            return Vector.<TextLineMirrorRegion>(this._mirrorRegions);
+        }
+
+        public function getMirrorRegion(mirror:EventDispatcher):TextLineMirrorRegion {
+            if (this._mirrorRegions != null) {
+                for each (var region:TextLineMirrorRegion in this._mirrorRegions) {
+                    if (region.mirror == mirror) {
+                        return region;
+                    }
+                }
+            }
             return null;
         }
 
         public function getBaselinePosition(baseline:String):Number {
-            stub_method("flash.text.engine.TextLine", "getBaselinePosition");
-            return 0.0;
+            // All positions are relative to the roman baseline, which is the origin of the line.
+            switch (baseline) {
+                case TextBaseline.ROMAN:
+                    return 0.0;
+                case TextBaseline.ASCENT:
+                case TextBaseline.IDEOGRAPHIC_TOP:
+                    return -this._ascent;
+                case TextBaseline.DESCENT:
+                case TextBaseline.IDEOGRAPHIC_BOTTOM:
+                    return this._descent;
+                case TextBaseline.IDEOGRAPHIC_CENTER:
+                    return (this._descent - this._ascent) / 2;
+                default:
+                    throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
+            }
         }
 
         public function get hasTabs():Boolean {
-            stub_getter("flash.text.engine.TextLine", "hasTabs");
-            return false;
+            return this._hasTabs;
         }
 
-        public function getAtomIndexAtPoint(stageX:Number, stageY:Number):int {
-            stub_method("flash.text.engine.TextLine", "getAtomIndexAtPoint");
-            return -1;
-        }
+        public native function getAtomIndexAtPoint(stageX:Number, stageY:Number):int;
 
         public function getAtomIndexAtCharIndex(charIndex:int):int {
-            stub_method("flash.text.engine.TextLine", "getAtomIndexAtCharIndex");
-            return -1;
+            var index:int = charIndex - this._textBlockBeginIndex;
+            if (index < 0 || index >= this._rawTextLength) {
+                return -1;
+            }
+            return index;
         }
 
         public function getAtomBidiLevel(index:int):int {
-            stub_method("flash.text.engine.TextLine", "getAtomBidiLevel");
-            return 0;
+            this.checkAtomIndex(index);
+            return this._textBlock != null ? this._textBlock.bidiLevel : 0;
         }
 
-        public function getAtomBounds(index:int):Rectangle {
-            stub_method("flash.text.engine.TextLine", "getAtomBounds");
-            return new Rectangle(0, 0, 0, 0);
-        }
+        public native function getAtomBounds(index:int):Rectangle;
 
-        public function getAtomCenter(index:int):Number {
-            stub_method("flash.text.engine.TextLine", "getAtomCenter");
-            return 1.0;
-        }
+        public native function getAtomCenter(index:int):Number;
 
         public function getAtomGraphic(index:int):DisplayObject {
-            stub_method("flash.text.engine.TextLine", "getAtomGraphic");
-            return null;
+            this.checkAtomIndex(index);
+            var element:GraphicElement = this._atomElements[index] as GraphicElement;
+            return element != null ? element.graphic : null;
         }
 
         public function getAtomTextBlockBeginIndex(index:int):int {
-            stub_method("flash.text.engine.TextLine", "getAtomTextBlockBeginIndex");
-            return 0;
+            this.checkAtomIndex(index);
+            return this._textBlockBeginIndex + index;
         }
 
         public function getAtomTextBlockEndIndex(index:int):int {
-            stub_method("flash.text.engine.TextLine", "getAtomTextBlockEndIndex");
-            return 0;
+            this.checkAtomIndex(index);
+            return this._textBlockBeginIndex + index + 1;
         }
 
         public function getAtomTextRotation(index:int):String {
-            stub_method("flash.text.engine.TextLine", "getAtomTextRotation");
-            return TextRotation.ROTATE_0;
+            this.checkAtomIndex(index);
+            var element:ContentElement = this._atomElements[index];
+            return element.textRotation;
         }
 
         public function getAtomWordBoundaryOnLeft(index:int):Boolean {
-            stub_method("flash.text.engine.TextLine", "getAtomWordBoundaryOnLeft");
-            return false;
+            this.checkAtomIndex(index);
+            if (index == 0) {
+                return true;
+            }
+            return isWhitespace(this._rawText.charAt(index - 1)) != isWhitespace(this._rawText.charAt(index));
         }
 
         // This function does nothing in Flash Player 32
         public function flushAtomData():void { }
 
+        private function checkAtomIndex(index:int):void {
+            if (index < 0 || index >= this._rawTextLength) {
+                throw new RangeError("Error #2006: The supplied index is out of bounds.", 2006);
+            }
+        }
+
+        internal static function isWhitespace(character:String):Boolean {
+            return character == " " || character == "\t" || character == "\n" || character == "\r" ||
+                character == "\u2028" || character == "\u2029" || character == "\u3000";
+        }
+
+        // Start forwarding mouse events to the event mirrors of this line, if it has any.
+        internal function listenToMirrors():void {
+            if (this._mirrorRegions == null || this._listeningToMirrors) {
+                return;
+            }
+
+            this._listeningToMirrors = true;
+            for each (var type:String in MIRRORED_EVENTS) {
+                this.addEventListener(type, this.forwardToMirror);
+            }
+        }
+
+        private function forwardToMirror(event:MouseEvent):void {
+            if (this._mirrorRegions == null) {
+                return;
+            }
+
+            for each (var region:TextLineMirrorRegion in this._mirrorRegions) {
+                if (region.bounds.contains(event.localX, event.localY)) {
+                    region.mirror.dispatchEvent(event.clone());
+                    return;
+                }
+            }
+        }
+
         // Overrides
 
         override public function set contextMenu(cm:ContextMenu):void {
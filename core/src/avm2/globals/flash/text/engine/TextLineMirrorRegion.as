@@ -1,5 +1,50 @@
 package flash.text.engine {
+    import flash.events.EventDispatcher;
+    import flash.geom.Rectangle;
+
     [API("662")]
     [Ruffle(Abstract)]
-    public final class TextLineMirrorRegion {}
+    public final class TextLineMirrorRegion {
+        [Ruffle(NativeAccessible)]
+        private var _textLine:TextLine = null;
+
+        [Ruffle(NativeAccessible)]
+        private var _element:ContentElement = null;
+
+        [Ruffle(NativeAccessible)]
+        private var _mirror:EventDispatcher = null;
+
+        [Ruffle(NativeAccessible)]
+        private var _bounds:Rectangle = null;
+
+        [Ruffle(NativeAccessible)]
+        private var _nextRegion:TextLineMirrorRegion = null;
+
+        [Ruffle(NativeAccessible)]
+        private var _previousRegion:TextLineMirrorRegion = null;
+
+        public function get bounds():Rectangle {
+            return this._bounds.clone();
+        }
+
+        public function get element():ContentElement {
+            return this._element;
+        }
+
+        public function get mirror():EventDispatcher {
+            return this._mirror;
+        }
+
+        public function get nextRegion():TextLineMirrorRegion {
+            return this._nextRegion;
+        }
+
+        public function get previousRegion():TextLineMirrorRegion {
+            return this._previousRegion;
+        }
+
+        public function get textLine():TextLine {
+            return this._textLine;
+        }
+    }
 }
@@ -1,176 +1,805 @@
 use ruffle_macros::istr;
 
 use crate::avm2::activation::Activation;
-use crate::avm2::error::Error;
-use crate::avm2::globals::flash::display::display_object::initialize_for_allocator;
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::error::{Error, Error2004Type, make_error_2004};
+use crate::avm2::globals::flash::display::display_object::{
+    initialize_for_allocator, new_rectangle,
+};
 use crate::avm2::globals::methods::flash_text_engine_content_element as element_methods;
 use crate::avm2::globals::slots::flash_text_engine_content_element as element_slots;
 use crate::avm2::globals::slots::flash_text_engine_element_format as format_slots;
 use crate::avm2::globals::slots::flash_text_engine_font_description as font_desc_slots;
+use crate::avm2::globals::slots::flash_text_engine_graphic_element as graphic_slots;
+use crate::avm2::globals::slots::flash_text_engine_group_element as group_slots;
+use crate::avm2::globals::slots::flash_text_engine_space_justifier as space_justifier_slots;
 use crate::avm2::globals::slots::flash_text_engine_text_block as block_slots;
+use crate::avm2::globals::slots::flash_text_engine_text_justifier as justifier_slots;
 use crate::avm2::globals::slots::flash_text_engine_text_line as line_slots;
-use crate::avm2::object::{Object, TObject};
+use crate::avm2::globals::slots::flash_text_engine_text_line_mirror_region as region_slots;
+use crate::avm2::object::{ArrayObject, Object, ScriptObject, ScriptObjectData, TObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
-use crate::avm2_stub_method;
 use crate::display_object::{EditText, TDisplayObject};
 use crate::html::TextFormat;
-use crate::string::WStr;
+use crate::string::{AvmString, WStr, WString};
+use gc_arena::Gc;
+use swf::{Rectangle, Twips};
 
-pub fn create_text_line<'gc>(
+/// The character used as a placeholder for a `GraphicElement` in the raw text.
+const GRAPHIC_ELEMENT_CHAR: u16 = 0xFDEF;
+
+/// The character a `GraphicElement` is laid out as.
+///
+/// A graphic must not be a break opportunity, and we need a glyph that is
+/// present in every font so that it can be widened using letter spacing.
+const GRAPHIC_LAYOUT_CHAR: u16 = 0x00A0;
+
+/// The maximum width of a line, see `TextLine.MAX_LINE_WIDTH`.
+const MAX_LINE_WIDTH: f64 = 1000000.0;
+
+/// How many characters are laid out at first when looking for the end of a line.
+const LINE_LAYOUT_CHUNK: usize = 256;
+
+/// A range of the raw text of a `TextBlock` belonging to a single leaf
+/// `ContentElement`.
+struct ElementRun<'gc> {
+    element: Object<'gc>,
+    start: usize,
+    end: usize,
+
+    /// The format of the element, or that of its closest `GroupElement`.
+    format: Option<Object<'gc>>,
+
+    /// The size of a `GraphicElement`, in pixels.
+    graphic_size: Option<(f64, f64)>,
+}
+
+/// The content of a `TextBlock`, flattened into runs of text.
+struct FlatContent<'gc> {
+    text: WString,
+    runs: Vec<ElementRun<'gc>>,
+}
+
+impl<'gc> FlatContent<'gc> {
+    fn from_content(
+        activation: &mut Activation<'_, 'gc>,
+        content: Object<'gc>,
+    ) -> Result<Self, Error<'gc>> {
+        let mut flat = Self {
+            text: WString::new(),
+            runs: Vec::new(),
+        };
+        flat.push_element(activation, content, None)?;
+        Ok(flat)
+    }
+
+    fn push_element(
+        &mut self,
+        activation: &mut Activation<'_, 'gc>,
+        element: Object<'gc>,
+        inherited_format: Option<Object<'gc>>,
+    ) -> Result<(), Error<'gc>> {
+        let format = element
+            .get_slot(element_slots::_ELEMENT_FORMAT)
+            .as_object()
+            .or(inherited_format);
+
+        let classes = activation.avm2().classes();
+        let group_element = classes.groupelement.inner_class_definition();
+        let graphic_element = classes.graphicelement.inner_class_definition();
+
+        if element.is_of_type(group_element) {
+            let children: Vec<Value<'gc>> = element
+                .get_slot(group_slots::_ELEMENTS)
+                .as_object()
+                .and_then(|elements| {
+                    elements
+                        .as_vector_storage()
+                        .map(|storage| storage.iter().collect())
+                })
+                .unwrap_or_default();
+
+            for child in children {
+                if let Some(child) = child.as_object() {
+                    self.push_element(activation, child, format)?;
+                }
+            }
+
+            return Ok(());
+        }
+
+        let graphic_size = if element.is_of_type(graphic_element) {
+            let width = element
+                .get_slot(graphic_slots::_ELEMENT_WIDTH)
+                .coerce_to_number(activation)?;
+            let height = element
+                .get_slot(graphic_slots::_ELEMENT_HEIGHT)
+                .coerce_to_number(activation)?;
+            Some((width, height))
+        } else {
+            None
+        };
+
+        let text = element.get_slot(element_slots::_TEXT);
+        let start = self.text.len();
+        if !matches!(text, Value::Null | Value::Undefined) {
+            self.text
+                .push_str(text.coerce_to_string(activation)?.as_wstr());
+        }
+
+        if self.text.len() > start {
+            self.runs.push(ElementRun {
+                element,
+                start,
+                end: self.text.len(),
+                format,
+                graphic_size,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Iterate over the runs overlapping with the given range, clipped to it.
+    fn runs_in(
+        &self,
+        begin: usize,
+        end: usize,
+    ) -> impl Iterator<Item = (&ElementRun<'gc>, usize, usize)> {
+        self.runs.iter().filter_map(move |run| {
+            let start = run.start.max(begin);
+            let end = run.end.min(end);
+            (start < end).then_some((run, start, end))
+        })
+    }
+}
+
+fn is_whitespace(c: u16) -> bool {
+    matches!(
+        c,
+        0x20 | 0x09 | 0x0A | 0x0D | 0x2028 | 0x2029 | 0x3000 | 0x00A0
+    )
+}
+
+fn is_newline(c: u16) -> bool {
+    matches!(c, 0x0A | 0x0D | 0x2028 | 0x2029)
+}
+
+/// Converts raw text of a `TextBlock` into text that can be laid out by an `EditText`.
+fn to_layout_text(text: &WStr) -> WString {
+    text.iter()
+        .map(|c| match c {
+            GRAPHIC_ELEMENT_CHAR => GRAPHIC_LAYOUT_CHAR,
+            0x2028 | 0x2029 => 0x0D,
+            c => c,
+        })
+        .collect()
+}
+
+pub fn create_text_line_internal<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Value<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    avm2_stub_method!(activation, "flash.text.TextBlock", "createTextLine");
-
     let previous_text_line = args.try_get_object(0);
     let width = args.get_f64(1);
+    let fit_something = args.get_bool(3);
 
-    let content = this.get_slot(block_slots::_CONTENT);
+    fill_text_line(
+        activation,
+        this,
+        None,
+        previous_text_line,
+        width,
+        fit_something,
+    )
+}
+
+pub fn recreate_text_line_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let text_line = args.get_object(activation, 0, "textLine")?;
+    let previous_text_line = args.try_get_object(1);
+    let width = args.get_f64(2);
+    let fit_something = args.get_bool(4);
+
+    fill_text_line(
+        activation,
+        this,
+        Some(text_line),
+        previous_text_line,
+        width,
+        fit_something,
+    )
+}
 
-    let content = if matches!(content, Value::Null) {
+/// Lay out the next line of a `TextBlock` after `previous_text_line`.
+///
+/// When `text_line` is given, it's reused instead of creating a new line.
+fn fill_text_line<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    text_line: Option<Object<'gc>>,
+    previous_text_line: Option<Object<'gc>>,
+    width: f64,
+    fit_something: bool,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(content) = this.get_slot(block_slots::_CONTENT).as_object() else {
         return Ok(Value::Null);
-    } else {
-        content
     };
 
-    let text = match previous_text_line {
-        Some(_) => {
-            // Some SWFs rely on eventually getting `null` from createLineText.
-            // TODO: Support multiple lines
-            this.set_slot(
-                block_slots::_TEXT_LINE_CREATION_RESULT,
-                istr!("complete").into(),
-                activation,
-            )?;
-            return Ok(Value::Null);
+    if width.is_nan() || !(0.0..=MAX_LINE_WIDTH).contains(&width) {
+        return Err(make_error_2004(activation, Error2004Type::ArgumentError));
+    }
+
+    let begin_index = if let Some(previous) = previous_text_line {
+        let previous_block = previous.get_slot(line_slots::_TEXT_BLOCK).as_object();
+        if !previous_block.is_some_and(|block| Object::ptr_eq(block, this)) {
+            return Err(make_error_2004(activation, Error2004Type::ArgumentError));
         }
+
+        let begin = previous
+            .get_slot(line_slots::_TEXT_BLOCK_BEGIN_INDEX)
+            .coerce_to_u32(activation)?;
+        let length = previous
+            .get_slot(line_slots::_RAW_TEXT_LENGTH)
+            .coerce_to_u32(activation)?;
+        (begin + length) as usize
+    } else {
         // Get the content element's text property (it's a getter).
-        // TODO: GraphicElement?
+        let text = content
+            .call_method(element_methods::GET_TEXT, &[], activation)
+            .unwrap_or_else(|_| istr!("").into());
+
+        if matches!(text, Value::Null) {
+            // FP returns a null TextLine when `o` is null- note that
+            // `o` is already coerced to a String because of the AS bindings.
+            return Ok(Value::Null);
+        }
+
+        0
+    };
+
+    let flat = FlatContent::from_content(activation, content)?;
+    let text_length = flat.text.len();
+
+    // An empty block still produces a single, empty line.
+    if begin_index >= text_length && (text_length > 0 || previous_text_line.is_some()) {
+        this.set_slot(
+            block_slots::_TEXT_LINE_CREATION_RESULT,
+            istr!("complete").into(),
+            activation,
+        )?;
+        return Ok(Value::Null);
+    }
+
+    let display_object = match text_line {
+        Some(text_line) => {
+            let Some(display_object) = text_line
+                .as_display_object()
+                .and_then(|display_object| display_object.as_edit_text())
+            else {
+                return Err(make_error_2004(activation, Error2004Type::ArgumentError));
+            };
+            display_object
+        }
         None => {
-            let txt = content
-                .call_method(element_methods::GET_TEXT, &[], activation)
-                .unwrap_or_else(|_| istr!("").into());
-
-            if matches!(txt, Value::Null) {
-                // FP returns a null TextLine when `o` is null- note that
-                // `o` is already coerced to a String because of the AS bindings.
-                return Ok(Value::Null);
-            } else {
-                txt.coerce_to_string(activation)
-                    .expect("Guaranteed by AS bindings")
-            }
+            // FIXME: TextLine should be its own DisplayObject
+            let movie = activation.caller_movie_or_root();
+            EditText::new_fte(activation.context, movie, 0.0, 0.0, width, 15.0)
         }
     };
 
-    let class = activation.avm2().classes().textline;
-    let movie = activation.caller_movie_or_root();
+    // First lay out the text that's left with word wrapping, to find out where
+    // this line should break. Laying out everything that's left for every line
+    // would take quadratic time, so only lay out a chunk of the current
+    // paragraph, and grow it until the first line ends within it.
+    let remaining = &flat.text[begin_index..];
+    let paragraph_length = remaining
+        .iter()
+        .position(is_newline)
+        .map_or(remaining.len(), |index| index + 1);
+    display_object.set_fte_line_width(Twips::from_pixels(width), activation.context);
+    display_object.set_word_wrap(true, activation.context);
 
-    // FIXME: TextLine should be its own DisplayObject
-    let display_object: EditText =
-        EditText::new_fte(activation.context, movie, 0.0, 0.0, width, 15.0);
+    let mut chunk_length = paragraph_length.min(LINE_LAYOUT_CHUNK);
+    let mut line_length = loop {
+        let chunk = &remaining[..chunk_length];
+        display_object.set_text(&to_layout_text(chunk), activation.context);
+        apply_runs(
+            activation,
+            display_object,
+            &flat,
+            begin_index,
+            begin_index + chunk_length,
+        )?;
 
-    display_object.set_text(text.as_wstr(), activation.context);
+        let line_length = display_object
+            .line_length(0)
+            .unwrap_or(chunk_length)
+            .min(chunk_length);
+        if line_length < chunk_length || chunk_length == paragraph_length {
+            break line_length;
+        }
+        chunk_length = (chunk_length * 2).min(paragraph_length);
+    };
+    if line_length < remaining.len() && is_newline(remaining.at(line_length)) {
+        // The terminating line break belongs to this line.
+        line_length += 1;
+    }
 
-    // FIXME: This needs to use `intrinsic_bounds` to measure the width
-    // of the provided text, and set the width of the EditText to that.
-    // Some games depend on this (e.g. Realm Grinder).
+    let first_atom_fits = remaining.is_empty()
+        || display_object
+            .char_bounds(0)
+            .is_none_or(|bounds| bounds.width().to_pixels() <= width);
+    let result = if !first_atom_fits && !fit_something {
+        this.set_slot(
+            block_slots::_TEXT_LINE_CREATION_RESULT,
+            istr!("insufficientWidth").into(),
+            activation,
+        )?;
+        return Ok(Value::Null);
+    } else if line_length == 0 && !remaining.is_empty() {
+        // Always fit at least one atom into a line.
+        line_length = 1;
+        istr!("emergency")
+    } else if line_length < remaining.len()
+        && !is_whitespace(remaining.at(line_length - 1))
+        && !is_whitespace(remaining.at(line_length))
+    {
+        // The line had to be broken in the middle of a word.
+        istr!("emergency")
+    } else {
+        istr!("success")
+    };
 
-    let content = content.as_object().unwrap();
-    let element_format = content.get_slot(element_slots::_ELEMENT_FORMAT).as_object();
+    let end_index = begin_index + line_length;
+    let line_text = &flat.text[begin_index..end_index];
 
-    apply_format(activation, display_object, text.as_wstr(), element_format)?;
+    // Now lay out only the text of this line.
+    let mut visible_length = line_length;
+    while visible_length > 0 && is_newline(line_text.at(visible_length - 1)) {
+        visible_length -= 1;
+    }
+    display_object.set_word_wrap(false, activation.context);
+    display_object.set_text(
+        &to_layout_text(&line_text[..visible_length]),
+        activation.context,
+    );
+    apply_runs(activation, display_object, &flat, begin_index, end_index)?;
 
-    let instance = initialize_for_allocator(activation.context, display_object.into(), class);
+    let unjustified_width = display_object.measure_text(activation.context).0;
+    let is_last_line = end_index >= text_length;
+    justify(
+        activation,
+        this,
+        display_object,
+        &line_text[..visible_length],
+        width - unjustified_width.to_pixels(),
+        is_last_line,
+        visible_length < line_length,
+    )?;
 
-    instance.set_slot(line_slots::_TEXT_BLOCK, this.into(), activation)?;
+    display_object.fit_fte_line_bounds();
+    place_graphics(activation, display_object, &flat, begin_index, end_index);
 
-    instance.set_slot(line_slots::_SPECIFIED_WIDTH, args.get_value(1), activation)?;
+    let instance: Object<'gc> = match text_line {
+        Some(text_line) => text_line,
+        None => {
+            let class = activation.avm2().classes().textline;
+            initialize_for_allocator(activation.context, display_object.into(), class).into()
+        }
+    };
 
-    instance.set_slot(line_slots::_RAW_TEXT_LENGTH, text.len().into(), activation)?;
+    let (ascent, descent) = display_object
+        .line_metrics(0)
+        .map(|metrics| (metrics.ascent.to_pixels(), metrics.descent.to_pixels()))
+        .unwrap_or_default();
+
+    let mut total_ascent = ascent;
+    let mut has_graphic_element = false;
+    let mut atom_elements = Vec::with_capacity(line_length);
+    for (run, start, end) in flat.runs_in(begin_index, end_index) {
+        if let Some((_, height)) = run.graphic_size {
+            has_graphic_element = true;
+            total_ascent = total_ascent.max(height);
+        }
+        atom_elements.extend((start..end).map(|_| Value::from(run.element)));
+    }
+    let atom_elements = ArrayObject::from_storage(
+        activation.context,
+        atom_elements.into_iter().collect::<ArrayStorage<'gc>>(),
+    );
+
+    let has_tabs = line_text.iter().any(|c| c == 0x09);
+    let raw_text = AvmString::new(activation.gc(), line_text);
+
+    link_line(activation, this, instance, previous_text_line)?;
+
+    instance.set_slot(line_slots::_TEXT_BLOCK, this.into(), activation)?;
+    instance.set_slot(line_slots::_SPECIFIED_WIDTH, width.into(), activation)?;
+    instance.set_slot(
+        line_slots::_TEXT_BLOCK_BEGIN_INDEX,
+        begin_index.into(),
+        activation,
+    )?;
+    instance.set_slot(line_slots::_RAW_TEXT_LENGTH, line_length.into(), activation)?;
+    instance.set_slot(line_slots::_RAW_TEXT, raw_text.into(), activation)?;
+    instance.set_slot(line_slots::_ASCENT, ascent.into(), activation)?;
+    instance.set_slot(line_slots::_DESCENT, descent.into(), activation)?;
+    instance.set_slot(line_slots::_TOTAL_ASCENT, total_ascent.into(), activation)?;
+    instance.set_slot(
+        line_slots::_UNJUSTIFIED_TEXT_WIDTH,
+        unjustified_width.to_pixels().into(),
+        activation,
+    )?;
+    instance.set_slot(
+        line_slots::_HAS_GRAPHIC_ELEMENT,
+        has_graphic_element.into(),
+        activation,
+    )?;
+    instance.set_slot(line_slots::_HAS_TABS, has_tabs.into(), activation)?;
+    instance.set_slot(line_slots::_ATOM_ELEMENTS, atom_elements.into(), activation)?;
+    instance.set_slot(line_slots::_VALIDITY, istr!("valid").into(), activation)?;
+
+    let mirror_regions = create_mirror_regions(
+        activation,
+        instance,
+        display_object,
+        &flat,
+        begin_index,
+        end_index,
+    )?;
+    instance.set_slot(line_slots::_MIRROR_REGIONS, mirror_regions, activation)?;
 
     this.set_slot(
         block_slots::_TEXT_LINE_CREATION_RESULT,
-        istr!("success").into(),
+        result.into(),
         activation,
     )?;
 
-    this.set_slot(block_slots::_FIRST_LINE, instance.into(), activation)?;
-
     Ok(instance.into())
 }
 
-fn apply_format<'gc>(
+/// Apply the formats of the elements of `flat` between `begin` and `end` to
+/// the text of an `EditText`, which starts at `begin`.
+fn apply_runs<'gc>(
     activation: &mut Activation<'_, 'gc>,
     display_object: EditText<'gc>,
-    text: &WStr,
-    element_format: Option<Object<'gc>>,
+    flat: &FlatContent<'gc>,
+    begin: usize,
+    end: usize,
 ) -> Result<(), Error<'gc>> {
-    if let Some(element_format) = element_format {
-        // TODO: Support more ElementFormat properties
-        let color = element_format
-            .get_slot(format_slots::_COLOR)
-            .coerce_to_u32(activation)?;
-        let size = element_format
-            .get_slot(format_slots::_FONT_SIZE)
-            .coerce_to_number(activation)?;
-
-        let (font, bold, italic, is_device_font) = if let Value::Object(font_description) =
-            element_format.get_slot(format_slots::_FONT_DESCRIPTION)
-        {
-            (
-                Some(
-                    font_description
-                        .get_slot(font_desc_slots::_FONT_NAME)
-                        .coerce_to_string(activation)?
-                        .as_wstr()
-                        .into(),
-                ),
-                Some(
-                    &font_description
-                        .get_slot(font_desc_slots::_FONT_WEIGHT)
-                        .coerce_to_string(activation)?
-                        == b"bold",
-                ),
-                Some(
-                    &font_description
-                        .get_slot(font_desc_slots::_FONT_POSTURE)
-                        .coerce_to_string(activation)?
-                        == b"italic",
-                ),
-                &font_description
-                    .get_slot(font_desc_slots::_FONT_LOOKUP)
-                    .coerce_to_string(activation)?
-                    == b"device",
-            )
-        } else {
-            (None, None, None, true)
+    let length = display_object.text_length();
+
+    // Reset any justification from a previous layout of this line.
+    let reset = TextFormat {
+        letter_spacing: Some(0.0),
+        ..TextFormat::default()
+    };
+    display_object.set_text_format(0, length, reset, activation.context);
+
+    let mut is_device_font = true;
+    let mut first_format = None;
+    for (run, start, run_end) in flat.runs_in(begin, end) {
+        let Some(element_format) = run.format else {
+            continue;
+        };
+
+        let (format, device_font) = text_format_from_element_format(activation, element_format)?;
+        if first_format.is_none() {
+            is_device_font = device_font;
+            first_format = Some(format.clone());
+        }
+
+        let from = (start - begin).min(length);
+        let to = (run_end - begin).min(length);
+        display_object.set_text_format(from, to, format, activation.context);
+    }
+
+    display_object.set_is_device_font(activation.context, is_device_font);
+    if let Some(format) = first_format {
+        display_object.set_new_text_format(format);
+    }
+
+    // Graphics are laid out as a single character, widened to the size of the graphic.
+    for (run, start, _) in flat.runs_in(begin, end) {
+        let Some((graphic_width, _)) = run.graphic_size else {
+            continue;
         };
 
+        let index = start - begin;
+        if index >= length {
+            continue;
+        }
+
+        let char_width = display_object
+            .char_bounds(index)
+            .map(|bounds| bounds.width().to_pixels())
+            .unwrap_or_default();
         let format = TextFormat {
-            color: Some(swf::Color::from_rgb(color, 0xFF)),
-            size: Some(size),
-            font,
-            bold,
-            italic,
+            letter_spacing: Some(graphic_width - char_width),
             ..TextFormat::default()
         };
+        display_object.set_text_format(index, index + 1, format, activation.context);
+    }
 
-        display_object.set_is_device_font(activation.context, is_device_font);
-        display_object.set_text_format(0, text.len(), format.clone(), activation.context);
-        display_object.set_new_text_format(format);
+    Ok(())
+}
+
+/// Place the graphics of the `GraphicElement`s of a line on their characters,
+/// standing on the baseline, and have the line render them.
+fn place_graphics<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    display_object: EditText<'gc>,
+    flat: &FlatContent<'gc>,
+    begin: usize,
+    end: usize,
+) {
+    let mut graphics = Vec::new();
+    for (run, start, _) in flat.runs_in(begin, end) {
+        let Some((_, height)) = run.graphic_size else {
+            continue;
+        };
+        let Some(graphic) = run
+            .element
+            .get_slot(graphic_slots::_GRAPHIC)
+            .as_object()
+            .and_then(|graphic| graphic.as_display_object())
+        else {
+            continue;
+        };
+
+        let x = display_object
+            .char_bounds(start - begin)
+            .map(|bounds| bounds.x_min)
+            .unwrap_or_default();
+        graphic.set_x(x);
+        graphic.set_y(Twips::from_pixels(-height));
+        graphics.push(graphic);
+    }
+    display_object.set_fte_graphics(activation.gc(), graphics);
+}
+
+/// Spread `extra_width` over the line according to the `TextJustifier` of the block.
+fn justify<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    text_block: Object<'gc>,
+    display_object: EditText<'gc>,
+    text: &WStr,
+    extra_width: f64,
+    is_last_line: bool,
+    ends_with_break: bool,
+) -> Result<(), Error<'gc>> {
+    let Some(justifier) = text_block
+        .get_slot(block_slots::_TEXT_JUSTIFIER)
+        .as_object()
+    else {
+        return Ok(());
+    };
+
+    let line_justification = justifier
+        .get_slot(justifier_slots::_LINE_JUSTIFICATION)
+        .coerce_to_string(activation)?;
+    let justified = &line_justification == b"allIncludingLast"
+        || (&line_justification == b"allButLast" && !is_last_line)
+        || (&line_justification == b"allButMandatoryBreak" && !is_last_line && !ends_with_break);
+    if !justified || extra_width <= 0.0 {
+        return Ok(());
+    }
+
+    // Trailing whitespace doesn't take part in justification.
+    let mut length = text.len();
+    while length > 0 && is_whitespace(text.at(length - 1)) {
+        length -= 1;
+    }
+
+    // A `SpaceJustifier` only widens spaces, unless letter spacing was requested.
+    let space_justifier = activation
+        .avm2()
+        .classes()
+        .spacejustifier
+        .inner_class_definition();
+    let space_only = justifier.is_of_type(space_justifier)
+        && !justifier
+            .get_slot(space_justifier_slots::_LETTER_SPACING)
+            .coerce_to_boolean();
+
+    let gaps: Vec<usize> = if space_only {
+        (0..length).filter(|&i| text.at(i) == 0x20).collect()
     } else {
-        display_object.set_is_device_font(activation.context, true);
+        (0..length.saturating_sub(1))
+            .filter(|&i| text.at(i) != GRAPHIC_LAYOUT_CHAR)
+            .collect()
+    };
+    if gaps.is_empty() {
+        return Ok(());
     }
 
-    display_object.set_word_wrap(true, activation.context);
+    let spacing = extra_width / gaps.len() as f64;
+    for index in gaps {
+        let format = TextFormat {
+            letter_spacing: Some(spacing),
+            ..TextFormat::default()
+        };
+        display_object.set_text_format(index, index + 1, format, activation.context);
+    }
 
-    let measured_text = display_object.measure_text(activation.context);
+    Ok(())
+}
+
+/// Create the `TextLineMirrorRegion`s of a line, one for each element with an
+/// event mirror.
+fn create_mirror_regions<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    text_line: Object<'gc>,
+    display_object: EditText<'gc>,
+    flat: &FlatContent<'gc>,
+    begin: usize,
+    end: usize,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let class = activation.avm2().classes().textlinemirrorregion;
+    let mut regions: Vec<Object<'gc>> = Vec::new();
+
+    for (run, start, run_end) in flat.runs_in(begin, end) {
+        let mirror = run.element.get_slot(element_slots::_EVENT_MIRROR);
+        if mirror.as_object().is_none() {
+            continue;
+        }
+
+        let bounds = (start - begin..run_end - begin)
+            .filter_map(|index| display_object.char_bounds(index))
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or(Rectangle::ZERO);
+        let bounds = new_rectangle(activation, bounds)?;
+
+        let region: Object<'gc> =
+            ScriptObject(Gc::new(activation.gc(), ScriptObjectData::new(class))).into();
+        region.set_slot(region_slots::_TEXT_LINE, text_line.into(), activation)?;
+        region.set_slot(region_slots::_ELEMENT, run.element.into(), activation)?;
+        region.set_slot(region_slots::_MIRROR, mirror, activation)?;
+        region.set_slot(region_slots::_BOUNDS, bounds, activation)?;
+
+        if let Some(previous) = regions.last().copied() {
+            previous.set_slot(region_slots::_NEXT_REGION, region.into(), activation)?;
+            region.set_slot(region_slots::_PREVIOUS_REGION, previous.into(), activation)?;
+        }
+        regions.push(region);
+    }
+
+    if regions.is_empty() {
+        return Ok(Value::Null);
+    }
+
+    let storage = regions
+        .into_iter()
+        .map(Value::from)
+        .collect::<ArrayStorage<'gc>>();
+    Ok(ArrayObject::from_storage(activation.context, storage).into())
+}
+
+/// Insert `line` into the list of lines of `text_block` after `previous`.
+///
+/// Any lines that previously followed `previous` are released from the block.
+fn link_line<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    text_block: Object<'gc>,
+    line: Object<'gc>,
+    previous: Option<Object<'gc>>,
+) -> Result<(), Error<'gc>> {
+    // A recreated line may still be linked into this block somewhere else.
+    let line_block = line.get_slot(line_slots::_TEXT_BLOCK).as_object();
+    if line_block.is_some_and(|block| Object::ptr_eq(block, text_block)) {
+        let old_previous = line.get_slot(line_slots::_PREVIOUS_LINE);
+        let old_next = line.get_slot(line_slots::_NEXT_LINE);
+
+        match old_previous.as_object() {
+            Some(old_previous) => {
+                old_previous.set_slot(line_slots::_NEXT_LINE, old_next, activation)?
+            }
+            None => text_block.set_slot(block_slots::_FIRST_LINE, old_next, activation)?,
+        }
+        match old_next.as_object() {
+            Some(old_next) => {
+                old_next.set_slot(line_slots::_PREVIOUS_LINE, old_previous, activation)?
+            }
+            None => text_block.set_slot(block_slots::_LAST_LINE, old_previous, activation)?,
+        }
+    }
+
+    let mut stale = match previous {
+        Some(previous) => previous.get_slot(line_slots::_NEXT_LINE).as_object(),
+        None => text_block.get_slot(block_slots::_FIRST_LINE).as_object(),
+    };
 
-    display_object.set_height(activation.context, measured_text.1.to_pixels());
+    while let Some(stale_line) = stale {
+        stale = stale_line.get_slot(line_slots::_NEXT_LINE).as_object();
+        if Object::ptr_eq(stale_line, line) {
+            continue;
+        }
+
+        stale_line.set_slot(line_slots::_VALIDITY, istr!("invalid").into(), activation)?;
+        stale_line.set_slot(line_slots::_TEXT_BLOCK, Value::Null, activation)?;
+        stale_line.set_slot(line_slots::_PREVIOUS_LINE, Value::Null, activation)?;
+        stale_line.set_slot(line_slots::_NEXT_LINE, Value::Null, activation)?;
+    }
+
+    match previous {
+        Some(previous) => previous.set_slot(line_slots::_NEXT_LINE, line.into(), activation)?,
+        None => text_block.set_slot(block_slots::_FIRST_LINE, line.into(), activation)?,
+    }
+    line.set_slot(
+        line_slots::_PREVIOUS_LINE,
+        previous.map_or(Value::Null, Value::from),
+        activation,
+    )?;
+    line.set_slot(line_slots::_NEXT_LINE, Value::Null, activation)?;
+    text_block.set_slot(block_slots::_LAST_LINE, line.into(), activation)?;
 
     Ok(())
 }
+
+/// Converts an `ElementFormat` into a `TextFormat`, also returning whether
+/// it uses device fonts.
+fn text_format_from_element_format<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    element_format: Object<'gc>,
+) -> Result<(TextFormat, bool), Error<'gc>> {
+    // TODO: Support more ElementFormat properties
+    let color = element_format
+        .get_slot(format_slots::_COLOR)
+        .coerce_to_u32(activation)?;
+    let size = element_format
+        .get_slot(format_slots::_FONT_SIZE)
+        .coerce_to_number(activation)?;
+
+    let (font, bold, italic, is_device_font) = if let Value::Object(font_description) =
+        element_format.get_slot(format_slots::_FONT_DESCRIPTION)
+    {
+        (
+            Some(
+                font_description
+                    .get_slot(font_desc_slots::_FONT_NAME)
+                    .coerce_to_string(activation)?
+                    .as_wstr()
+                    .into(),
+            ),
+            Some(
+                &font_description
+                    .get_slot(font_desc_slots::_FONT_WEIGHT)
+                    .coerce_to_string(activation)?
+                    == b"bold",
+            ),
+            Some(
+                &font_description
+                    .get_slot(font_desc_slots::_FONT_POSTURE)
+                    .coerce_to_string(activation)?
+                    == b"italic",
+            ),
+            &font_description
+                .get_slot(font_desc_slots::_FONT_LOOKUP)
+                .coerce_to_string(activation)?
+                == b"device",
+        )
+    } else {
+        (None, None, None, true)
+    };
+
+    let format = TextFormat {
+        color: Some(swf::Color::from_rgb(color, 0xFF)),
+        size: Some(size),
+        font,
+        bold,
+        italic,
+        ..TextFormat::default()
+    };
+
+    Ok((format, is_device_font))
+}
@@ -1,6 +1,37 @@
 use crate::avm2::activation::Activation;
-use crate::avm2::error::Error;
+use crate::avm2::error::{Error, make_error_2006};
+use crate::avm2::globals::flash::display::display_object::new_rectangle;
+use crate::avm2::globals::slots::flash_text_engine_text_line as line_slots;
+use crate::avm2::object::Object;
+use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
+use crate::display_object::{EditText, TDisplayObject};
+use swf::{Point, Rectangle, Twips};
+
+fn edit_text<'gc>(this: Object<'gc>) -> EditText<'gc> {
+    let display_object = this.as_display_object().unwrap();
+    display_object.as_edit_text().unwrap()
+}
+
+/// Returns the bounds of an atom in the coordinate space of the line,
+/// throwing a `RangeError` for atoms outside of the line.
+fn atom_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    index: i32,
+) -> Result<Rectangle<Twips>, Error<'gc>> {
+    let atom_count = this
+        .get_slot(line_slots::_RAW_TEXT_LENGTH)
+        .coerce_to_i32(activation)?;
+    if index < 0 || index >= atom_count {
+        return Err(make_error_2006(activation));
+    }
+
+    // Line breaks aren't laid out, so they have no extent.
+    Ok(edit_text(this)
+        .char_bounds(index as usize)
+        .unwrap_or(Rectangle::ZERO))
+}
 
 pub fn get_text_width<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -9,10 +40,7 @@ pub fn get_text_width<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    let display_object = this.as_display_object().unwrap();
-    let edit_text = display_object.as_edit_text().unwrap();
-
-    let measured_text = edit_text.measure_text(activation.context);
+    let measured_text = edit_text(this).measure_text(activation.context);
     Ok(measured_text.0.to_pixels().into())
 }
 
@@ -23,9 +51,54 @@ pub fn get_text_height<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.as_object().unwrap();
 
-    let display_object = this.as_display_object().unwrap();
-    let edit_text = display_object.as_edit_text().unwrap();
-
-    let measured_text = edit_text.measure_text(activation.context);
+    let measured_text = edit_text(this).measure_text(activation.context);
     Ok(measured_text.1.to_pixels().into())
 }
+
+pub fn get_atom_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let bounds = atom_bounds(activation, this, args.get_i32(0))?;
+    new_rectangle(activation, bounds)
+}
+
+pub fn get_atom_center<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let bounds = atom_bounds(activation, this, args.get_i32(0))?;
+    let center = (bounds.x_min.to_pixels() + bounds.x_max.to_pixels()) / 2.0;
+    Ok(center.into())
+}
+
+pub fn get_atom_index_at_point<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    let edit_text = edit_text(this);
+    let stage_point = Point::from_pixels(args.get_f64(0), args.get_f64(1));
+    let Some(point) = edit_text.global_to_local(stage_point) else {
+        return Ok((-1).into());
+    };
+
+    let atom_count = this
+        .get_slot(line_slots::_RAW_TEXT_LENGTH)
+        .coerce_to_i32(activation)?;
+    let index = (0..atom_count.max(0) as usize).find(|&index| {
+        edit_text
+            .char_bounds(index)
+            .is_some_and(|bounds| bounds.contains(point))
+    });
+
+    Ok(index.map_or(-1, |index| index as i32).into())
+}
@@ -1,7 +1,32 @@
 package flash.ui {
+    import flash.events.GestureEvent;
+    import flash.events.PressAndTapGestureEvent;
+    import flash.events.TransformGestureEvent;
+
     [Ruffle(Abstract)]
     public final class Multitouch {
-        // FIXME - implement a setter and gett
-        public static var inputMode:String = MultitouchInputMode.GESTURE;
+        public static native function get inputMode():String;
+        public static native function set inputMode(value:String):void;
+
+        public static native function get maxTouchPoints():int;
+
+        public static function get supportedGestures():Vector.<String> {
+            if (!supportsGestureEvents) {
+                return null;
+            }
+
+            return new <String>[
+                TransformGestureEvent.GESTURE_PAN,
+                TransformGestureEvent.GESTURE_ROTATE,
+                TransformGestureEvent.GESTURE_SWIPE,
+                TransformGestureEvent.GESTURE_ZOOM,
+                GestureEvent.GESTURE_TWO_FINGER_TAP,
+                PressAndTapGestureEvent.GESTURE_PRESS_AND_TAP
+            ];
+        }
+
+        public static native function get supportsGestureEvents():Boolean;
+
+        public static native function get supportsTouchEvents():Boolean;
     }
 }
@@ -0,0 +1,59 @@
+//! `flash.ui.Multitouch` builtin
+
+use crate::avm2::Error;
+use crate::avm2::activation::Activation;
+use crate::avm2::error::make_error_2008;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::string::AvmString;
+
+/// Implements `Multitouch.inputMode`'s getter
+pub fn get_input_mode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let input_mode = activation.context.touches.input_mode();
+    Ok(AvmString::new_utf8(activation.gc(), input_mode.to_string()).into())
+}
+
+/// Implements `Multitouch.inputMode`'s setter
+pub fn set_input_mode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Ok(input_mode) = args.get_string(activation, 0).parse() else {
+        return Err(make_error_2008(activation, "inputMode"));
+    };
+    activation.context.touches.set_input_mode(input_mode);
+    Ok(Value::Undefined)
+}
+
+/// Implements `Multitouch.maxTouchPoints`'s getter
+pub fn get_max_touch_points<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.touches.max_touch_points().into())
+}
+
+/// Implements `Multitouch.supportsGestureEvents`'s getter
+pub fn get_supports_gesture_events<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // Most gestures are made with two fingers.
+    Ok((activation.context.touches.max_touch_points() >= 2).into())
+}
+
+/// Implements `Multitouch.supportsTouchEvents`'s getter
+pub fn get_supports_touch_events<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok((activation.context.touches.max_touch_points() > 0).into())
+}
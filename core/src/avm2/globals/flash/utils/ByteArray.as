@@ -1,21 +1,10 @@
 package flash.utils {
-    import __ruffle__.stub_setter;
-
     [Ruffle(InstanceAllocator)]
     public class ByteArray implements IDataInput2, IDataOutput2 {
-        private var _shareable:Boolean = false;
-
         [API("684")]
-        public function set shareable(shareable:Boolean):void {
-            stub_setter("flash.utils.ByteArray", "shareable");
-
-            this._shareable = shareable;
-        }
-
+        public native function get shareable():Boolean;
         [API("684")]
-        public function get shareable():Boolean {
-            return this._shareable;
-        }
+        public native function set shareable(shareable:Boolean):void;
 
         public static native function get defaultObjectEncoding():uint;
         public static native function set defaultObjectEncoding(encoding:uint):void;
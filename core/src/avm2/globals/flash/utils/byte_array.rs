@@ -1,5 +1,3 @@
-use std::rc::Rc;
-
 use crate::avm2::Error;
 use crate::avm2::activation::Activation;
 use crate::avm2::bytearray::{Endian, ObjectEncoding};
@@ -12,7 +10,7 @@ use encoding_rs::Encoding;
 use encoding_rs::UTF_8;
 use flash_lso::amf0::read::AMF0Decoder;
 use flash_lso::amf3::read::AMF3Decoder;
-use flash_lso::types::{AMFVersion, Element};
+use flash_lso::types::AMFVersion;
 use ruffle_macros::istr;
 use ruffle_wstr::WString;
 
@@ -814,29 +812,9 @@ pub fn write_object<'gc>(
             ObjectEncoding::Amf3 => AMFVersion::AMF3,
         };
 
-        let amf = crate::avm2::amf::serialize_value(
-            activation,
-            obj,
-            amf_version,
-            &mut Default::default(),
-        )
-        .unwrap_or(flash_lso::types::Value::Undefined);
-
-        let element = Element::new("", Rc::new(amf));
-        let mut lso = flash_lso::types::Lso::new(vec![element], "", amf_version);
-        let bytes =
-            flash_lso::write::write_to_bytes(&mut lso).map_err(|_| "Failed to serialize object")?;
-        // This is kind of hacky: We need to strip out the header and any padding so that we only write
-        // the value. In the future, there should be a method to do this in the flash_lso crate.
-        let element_padding = match amf_version {
-            AMFVersion::AMF0 => 8,
-            AMFVersion::AMF3 => 7,
-        };
+        let bytes = crate::avm2::amf::serialize_value_to_bytes(activation, obj, amf_version)?;
         bytearray
-            .write_bytes(
-                &bytes[flash_lso::write::header_length(&lso.header) + element_padding
-                    ..bytes.len() - 1],
-            )
+            .write_bytes(&bytes)
             .map_err(|e| e.to_avm(activation))?;
     }
 
@@ -875,3 +853,31 @@ pub fn set_object_encoding<'gc>(
 
     Ok(Value::Undefined)
 }
+
+pub fn get_shareable<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(bytearray) = this.as_bytearray_object() {
+        return Ok(bytearray.shareable().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn set_shareable<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Value<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.as_object().unwrap();
+
+    if let Some(bytearray) = this.as_bytearray_object() {
+        bytearray.set_shareable(args.get_bool(0));
+    }
+
+    Ok(Value::Undefined)
+}
@@ -92,6 +92,7 @@ include "flash/display/MorphShape.as"
 include "flash/display/NativeMenu.as"
 include "flash/display/NativeMenuItem.as"
 include "flash/display/NativeWindowDisplayState.as"
+include "flash/display/NativeWindowResize.as"
 include "flash/display/NativeWindowSystemChrome.as"
 include "flash/display/NativeWindowType.as"
 include "flash/display/NativeWindowInitOptions.as"
@@ -177,6 +178,7 @@ include "flash/events/DRMLicenseRequestEvent.as"
 include "flash/events/DRMReturnVoucherCompleteEvent.as"
 include "flash/events/DRMReturnVoucherErrorEvent.as"
 include "flash/events/EventPhase.as"
+include "flash/events/FileListEvent.as"
 include "flash/events/FocusEvent.as"
 include "flash/events/FullScreenEvent.as"
 include "flash/events/GameInputEvent.as"
@@ -187,11 +189,13 @@ include "flash/events/IOErrorEvent.as"
 include "flash/events/InvokeEvent.as"
 include "flash/events/KeyboardEvent.as"
 include "flash/events/NativeDragEvent.as"
+include "flash/events/NativeProcessExitEvent.as"
 include "flash/events/NativeWindowBoundsEvent.as"
 include "flash/events/NativeWindowDisplayStateEvent.as"
 include "flash/events/NetDataEvent.as"
 include "flash/events/NetFilterEvent.as"
 include "flash/events/NetStatusEvent.as"
+include "flash/events/OutputProgressEvent.as"
 include "flash/events/PressAndTapGestureEvent.as"
 include "flash/events/ProgressEvent.as"
 include "flash/events/SampleDataEvent.as"
@@ -325,6 +329,7 @@ include "flash/net/URLVariables.as"
 include "flash/net/XMLSocket.as"
 
 include "flash/filesystem/File.as" // File extends FileReference
+include "flash/filesystem/FileMode.as"
 include "flash/filesystem/FileStream.as"
 
 include "flash/net/drm/AuthenticationMethod.as"
@@ -13,6 +13,7 @@ use gc_arena::barrier::{Write, unlock};
 use gc_arena::lock::OnceLock;
 use gc_arena::{Collect, Gc};
 use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 use std::sync::Arc;
 use swf::avm2::types::{
@@ -144,6 +145,14 @@ impl PartialEq for Method<'_> {
     }
 }
 
+impl Eq for Method<'_> {}
+
+impl Hash for Method<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Gc::as_ptr(self.0).hash(state);
+    }
+}
+
 impl core::fmt::Debug for Method<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         f.debug_struct("Method")
@@ -5,7 +5,6 @@ use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::activation::Activation;
 use crate::avm2::array::ArrayStorage;
-use crate::avm2::bytearray::ByteArrayStorage;
 use crate::avm2::class::Class;
 use crate::avm2::domain::Domain;
 use crate::avm2::error::{self, make_error_2012};
@@ -31,6 +30,7 @@ mod array_object;
 mod bitmapdata_object;
 mod bytearray_object;
 mod class_object;
+mod condition_object;
 mod context3d_object;
 mod date_object;
 mod dictionary_object;
@@ -45,6 +45,7 @@ mod index_buffer_3d_object;
 mod loaderinfo_object;
 mod local_connection_object;
 mod message_channel_object;
+mod mutex_object;
 mod namespace_object;
 mod net_connection_object;
 mod netstream_object;
@@ -78,9 +79,13 @@ pub use crate::avm2::object::bitmapdata_object::{
     BitmapDataObject, BitmapDataObjectWeak, bitmap_data_allocator,
 };
 pub use crate::avm2::object::bytearray_object::{
-    ByteArrayObject, ByteArrayObjectWeak, byte_array_allocator,
+    ByteArrayObject, ByteArrayObjectWeak, ByteArrayStorageRef, ByteArrayStorageRefMut,
+    byte_array_allocator,
 };
 pub use crate::avm2::object::class_object::{ClassObject, ClassObjectWeak};
+pub use crate::avm2::object::condition_object::{
+    ConditionObject, ConditionObjectWeak, condition_allocator,
+};
 pub use crate::avm2::object::context3d_object::{Context3DObject, Context3DObjectWeak};
 pub use crate::avm2::object::date_object::{DateObject, DateObjectWeak, date_allocator};
 pub use crate::avm2::object::dictionary_object::{
@@ -110,6 +115,7 @@ pub use crate::avm2::object::local_connection_object::{
 pub use crate::avm2::object::message_channel_object::{
     MessageChannelObject, MessageChannelObjectWeak,
 };
+pub use crate::avm2::object::mutex_object::{MutexObject, MutexObjectWeak, mutex_allocator};
 pub use crate::avm2::object::namespace_object::{NamespaceObject, NamespaceObjectWeak};
 pub use crate::avm2::object::net_connection_object::{
     NetConnectionObject, NetConnectionObjectWeak, net_connection_allocator,
@@ -217,6 +223,8 @@ use crate::font::Font;
         WorkerObject(WorkerObject<'gc>),
         WorkerDomainObject(WorkerDomainObject<'gc>),
         MessageChannelObject(MessageChannelObject<'gc>),
+        MutexObject(MutexObject<'gc>),
+        ConditionObject(ConditionObject<'gc>),
         SecurityDomainObject(SecurityDomainObject<'gc>),
     }
 )]
@@ -789,6 +797,10 @@ impl<'gc> Object<'gc> {
         pub fn as_shared_object for SharedObjectObject;
         pub fn as_sound_transform for SoundTransformObject;
         pub fn as_style_sheet for StyleSheetObject;
+        pub fn as_worker for WorkerObject;
+        pub fn as_message_channel for MessageChannelObject;
+        pub fn as_mutex for MutexObject;
+        pub fn as_condition for ConditionObject;
     }
 
     /// Unwrap this object's `Namespace`, if the object is a boxed namespace.
@@ -810,12 +822,12 @@ impl<'gc> Object<'gc> {
     }
 
     /// Unwrap this object as byte array storage.
-    pub fn as_bytearray(&self) -> Option<Ref<'_, ByteArrayStorage>> {
+    pub fn as_bytearray(&self) -> Option<ByteArrayStorageRef<'_>> {
         self.as_bytearray_object().map(|o| o.storage())
     }
 
     /// Unwrap this object as mutable byte array storage.
-    pub fn as_bytearray_mut(&self) -> Option<RefMut<'_, ByteArrayStorage>> {
+    pub fn as_bytearray_mut(&self) -> Option<ByteArrayStorageRefMut<'_>> {
         self.as_bytearray_object().map(|o| o.storage_mut())
     }
 
@@ -991,6 +1003,8 @@ define_weak_enum! {
         WorkerObject(WorkerObjectWeak<'gc>),
         WorkerDomainObject(WorkerDomainObjectWeak<'gc>),
         MessageChannelObject(MessageChannelObjectWeak<'gc>),
+        MutexObject(MutexObjectWeak<'gc>),
+        ConditionObject(ConditionObjectWeak<'gc>),
         SecurityDomainObject(SecurityDomainObjectWeak<'gc>),
     }
 }
@@ -6,6 +6,7 @@ use crate::avm2::activation::Activation;
 use crate::avm2::array::ArrayStorage;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::context::UpdateContext;
 use crate::string::{AvmString, WStr};
@@ -76,13 +77,16 @@ impl<'gc> ArrayObject<'gc> {
         let class = context.avm2.classes().array;
         let base = ScriptObjectData::new(class);
 
-        ArrayObject(Gc::new(
+        let array = ArrayObject(Gc::new(
             context.gc(),
             ArrayObjectData {
                 base,
                 array: RefLock::new(array),
             },
-        ))
+        ));
+        Sampler::record_allocation(context, array.into(), class);
+
+        array
     }
 
     pub fn for_prototype(
@@ -1,16 +1,19 @@
 use crate::avm2::Error;
 use crate::avm2::Multiname;
 use crate::avm2::activation::Activation;
-use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::bytearray::{ByteArrayStorage, SharedByteArray};
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ArrayObject, ClassObject, Object, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::character::Character;
 use crate::context::UpdateContext;
 use core::fmt;
 use gc_arena::{Collect, Gc, GcWeak};
 use ruffle_common::utils::HasPrefixField;
-use std::cell::{Ref, RefCell, RefMut};
+use std::cell::{Cell, OnceCell, Ref, RefCell, RefMut};
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 /// A class instance allocator that allocates ByteArray objects.
 pub fn byte_array_allocator<'gc>(
@@ -50,6 +53,8 @@ pub fn byte_array_allocator<'gc>(
         ByteArrayObjectData {
             base,
             storage: RefCell::new(storage),
+            shared_storage: OnceCell::new(),
+            shareable: Cell::new(false),
         },
     ))
     .into())
@@ -79,6 +84,55 @@ pub struct ByteArrayObjectData<'gc> {
     base: ScriptObjectData<'gc>,
 
     storage: RefCell<ByteArrayStorage>,
+
+    /// Once this ByteArray is shared with another worker, its storage lives here instead.
+    #[collect(require_static)]
+    shared_storage: OnceCell<SharedByteArray>,
+
+    shareable: Cell<bool>,
+}
+
+/// A borrow of the storage of a `ByteArrayObject`.
+pub enum ByteArrayStorageRef<'a> {
+    Local(Ref<'a, ByteArrayStorage>),
+    Shared(MutexGuard<'a, ByteArrayStorage>),
+}
+
+impl Deref for ByteArrayStorageRef<'_> {
+    type Target = ByteArrayStorage;
+
+    fn deref(&self) -> &ByteArrayStorage {
+        match self {
+            Self::Local(storage) => storage,
+            Self::Shared(storage) => storage,
+        }
+    }
+}
+
+/// A mutable borrow of the storage of a `ByteArrayObject`.
+pub enum ByteArrayStorageRefMut<'a> {
+    Local(RefMut<'a, ByteArrayStorage>),
+    Shared(MutexGuard<'a, ByteArrayStorage>),
+}
+
+impl Deref for ByteArrayStorageRefMut<'_> {
+    type Target = ByteArrayStorage;
+
+    fn deref(&self) -> &ByteArrayStorage {
+        match self {
+            Self::Local(storage) => storage,
+            Self::Shared(storage) => storage,
+        }
+    }
+}
+
+impl DerefMut for ByteArrayStorageRefMut<'_> {
+    fn deref_mut(&mut self) -> &mut ByteArrayStorage {
+        match self {
+            Self::Local(storage) => storage,
+            Self::Shared(storage) => storage,
+        }
+    }
 }
 
 impl<'gc> ByteArrayObject<'gc> {
@@ -86,11 +140,32 @@ impl<'gc> ByteArrayObject<'gc> {
         let class = context.avm2.classes().bytearray;
         let base = ScriptObjectData::new(class);
 
-        ByteArrayObject(Gc::new(
+        let object = ByteArrayObject(Gc::new(
             context.gc(),
             ByteArrayObjectData {
                 base,
                 storage: RefCell::new(bytes),
+                shared_storage: OnceCell::new(),
+                shareable: Cell::new(false),
+            },
+        ));
+        Sampler::record_allocation(context, object.into(), class);
+
+        object
+    }
+
+    /// Creates a ByteArray using storage shared by another worker.
+    pub fn from_shared_storage(context: &mut UpdateContext<'gc>, storage: SharedByteArray) -> Self {
+        let class = context.avm2.classes().bytearray;
+        let base = ScriptObjectData::new(class);
+
+        ByteArrayObject(Gc::new(
+            context.gc(),
+            ByteArrayObjectData {
+                base,
+                storage: RefCell::new(ByteArrayStorage::new(context)),
+                shared_storage: OnceCell::from(storage),
+                shareable: Cell::new(true),
             },
         ))
     }
@@ -101,20 +176,52 @@ impl<'gc> ByteArrayObject<'gc> {
         index: usize,
         value: Value<'gc>,
     ) -> Result<(), Error<'gc>> {
-        self.0
-            .storage
-            .borrow_mut()
-            .set(index, value.coerce_to_u32(activation)? as u8);
+        let value = value.coerce_to_u32(activation)? as u8;
+        self.storage_mut().set(index, value);
 
         Ok(())
     }
 
-    pub fn storage(self) -> Ref<'gc, ByteArrayStorage> {
-        Gc::as_ref(self.0).storage.borrow()
+    pub fn storage(self) -> ByteArrayStorageRef<'gc> {
+        let data = Gc::as_ref(self.0);
+        match data.shared_storage.get() {
+            Some(shared) => {
+                ByteArrayStorageRef::Shared(shared.lock().unwrap_or_else(PoisonError::into_inner))
+            }
+            None => ByteArrayStorageRef::Local(data.storage.borrow()),
+        }
+    }
+
+    pub fn storage_mut(self) -> ByteArrayStorageRefMut<'gc> {
+        let data = Gc::as_ref(self.0);
+        match data.shared_storage.get() {
+            Some(shared) => ByteArrayStorageRefMut::Shared(
+                shared.lock().unwrap_or_else(PoisonError::into_inner),
+            ),
+            None => ByteArrayStorageRefMut::Local(data.storage.borrow_mut()),
+        }
     }
 
-    pub fn storage_mut(self) -> RefMut<'gc, ByteArrayStorage> {
-        Gc::as_ref(self.0).storage.borrow_mut()
+    pub fn shareable(self) -> bool {
+        self.0.shareable.get()
+    }
+
+    pub fn set_shareable(self, shareable: bool) {
+        self.0.shareable.set(shareable);
+    }
+
+    /// Moves the storage of this ByteArray to where other workers can share it.
+    pub fn share(self) -> SharedByteArray {
+        self.0
+            .shared_storage
+            .get_or_init(|| {
+                let mut storage = self.0.storage.borrow_mut();
+                let shared = Arc::new(Mutex::new(storage.clone()));
+                storage.clear();
+                storage.shrink_to_fit();
+                shared
+            })
+            .clone()
     }
 }
 
@@ -142,9 +249,7 @@ impl<'gc> TObject<'gc> for ByteArrayObject<'gc> {
     fn get_index_property(self, index: usize) -> Option<Value<'gc>> {
         // ByteArrays never forward to base even for out-of-bounds access.
         Some(
-            self.0
-                .storage
-                .borrow()
+            self.storage()
                 .get(index)
                 .map_or(Value::Undefined, |val| Value::Integer(val as i32)),
         )
@@ -198,7 +303,7 @@ impl<'gc> TObject<'gc> for ByteArrayObject<'gc> {
         if name.valid_dynamic_name() {
             if let Some(name) = name.local_name() {
                 if let Some(index) = ArrayObject::as_array_index(&name) {
-                    return self.0.storage.borrow().get(index).is_some();
+                    return self.storage().get(index).is_some();
                 }
             }
         }
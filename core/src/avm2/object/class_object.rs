@@ -15,6 +15,7 @@ use crate::avm2::object::function_object::FunctionObject;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{Object, ScriptObject, TObject};
 use crate::avm2::property::Property;
+use crate::avm2::sampler::Sampler;
 use crate::avm2::scope::{Scope, ScopeChain};
 use crate::avm2::value::Value;
 use crate::avm2::vtable::VTable;
@@ -719,6 +720,8 @@ impl<'gc> ClassObject<'gc> {
 
             let instance = instance_allocator(self, activation)?;
 
+            Sampler::record_allocation(activation.context, instance, self);
+
             self.call_init(instance.into(), arguments, activation)?;
 
             Ok(instance.into())
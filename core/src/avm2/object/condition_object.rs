@@ -0,0 +1,100 @@
+use crate::avm2::Error;
+use crate::avm2::activation::Activation;
+use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::{ClassObject, MutexObject, Object, TObject};
+use crate::worker::ConditionHandle;
+use core::fmt;
+use gc_arena::barrier::unlock;
+use gc_arena::lock::Lock;
+use gc_arena::{Collect, Gc, GcWeak, Mutation};
+use ruffle_common::utils::HasPrefixField;
+use std::cell::{Ref, RefCell};
+
+/// A class instance allocator that allocates Condition objects.
+pub fn condition_allocator<'gc>(
+    class: ClassObject<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Object<'gc>, Error<'gc>> {
+    let base = ScriptObjectData::new(class);
+
+    Ok(ConditionObject(Gc::new(
+        activation.gc(),
+        ConditionObjectData {
+            base,
+            mutex: Lock::new(None),
+            handle: RefCell::new(None),
+        },
+    ))
+    .into())
+}
+
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct ConditionObject<'gc>(pub Gc<'gc, ConditionObjectData<'gc>>);
+
+#[derive(Clone, Collect, Copy, Debug)]
+#[collect(no_drop)]
+pub struct ConditionObjectWeak<'gc>(pub GcWeak<'gc, ConditionObjectData<'gc>>);
+
+impl fmt::Debug for ConditionObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConditionObject")
+            .field("ptr", &Gc::as_ptr(self.0))
+            .finish()
+    }
+}
+
+#[derive(Collect, HasPrefixField)]
+#[collect(no_drop)]
+#[repr(C, align(8))]
+pub struct ConditionObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// The `Mutex` object this condition was constructed with.
+    mutex: Lock<Option<MutexObject<'gc>>>,
+
+    /// The condition itself, which may be shared with other workers.
+    ///
+    /// This is `None` until the constructor has run.
+    #[collect(require_static)]
+    handle: RefCell<Option<ConditionHandle>>,
+}
+
+impl<'gc> TObject<'gc> for ConditionObject<'gc> {
+    fn gc_base(&self) -> Gc<'gc, ScriptObjectData<'gc>> {
+        HasPrefixField::as_prefix_gc(self.0)
+    }
+}
+
+impl<'gc> ConditionObject<'gc> {
+    /// Wrap a condition received from another worker.
+    pub fn from_handle(activation: &mut Activation<'_, 'gc>, handle: ConditionHandle) -> Self {
+        let mutex = MutexObject::from_handle(activation, handle.mutex().clone());
+        let class = activation.avm2().classes().condition;
+        let base = ScriptObjectData::new(class);
+        ConditionObject(Gc::new(
+            activation.gc(),
+            ConditionObjectData {
+                base,
+                mutex: Lock::new(Some(mutex)),
+                handle: RefCell::new(Some(handle)),
+            },
+        ))
+    }
+
+    /// Associate this condition with a mutex, as done by the constructor.
+    pub fn init(self, mc: &Mutation<'gc>, mutex: MutexObject<'gc>) {
+        let write = Gc::write(mc, self.0);
+        unlock!(write, ConditionObjectData, mutex).set(Some(mutex));
+        *self.0.handle.borrow_mut() = Some(ConditionHandle::new(mutex.handle().clone()));
+    }
+
+    pub fn mutex(self) -> Option<MutexObject<'gc>> {
+        self.0.mutex.get()
+    }
+
+    pub fn handle(&self) -> Ref<'_, Option<ConditionHandle>> {
+        self.0.handle.borrow()
+    }
+}
@@ -4,19 +4,22 @@ use crate::avm2::Error;
 use crate::avm2::activation::Activation;
 use crate::avm2::events::Event;
 use crate::avm2::object::script_object::ScriptObjectData;
-use crate::avm2::object::{ClassObject, Object, ScriptObject, TObject};
+use crate::avm2::object::{ByteArrayObject, ClassObject, Object, ScriptObject, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::context::UpdateContext;
 use crate::display_object::TDisplayObject;
 use crate::display_object::{DisplayObject, InteractiveObject, TInteractiveObject};
 use crate::events::{KeyCode, MouseButton};
 use crate::string::AvmString;
+use crate::touch::{Gesture, GesturePhase};
 use gc_arena::barrier::unlock;
 use gc_arena::{Collect, Gc, GcWeak, Mutation, lock::RefLock};
 use ruffle_common::utils::HasPrefixField;
 use ruffle_macros::istr;
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
+use swf::{Point, Twips};
 
 /// A class instance allocator that allocates Event objects.
 pub fn event_allocator<'gc>(
@@ -84,13 +87,16 @@ impl<'gc> EventObject<'gc> {
         event.set_bubbles(bubbles);
         event.set_cancelable(cancelable);
 
-        EventObject(Gc::new(
+        let object = EventObject(Gc::new(
             context.gc(),
             EventObjectData {
                 base,
                 event: RefLock::new(event),
             },
-        ))
+        ));
+        Sampler::record_allocation(context, object.into(), class);
+
+        object
     }
 
     #[inline]
@@ -202,6 +208,184 @@ impl<'gc> EventObject<'gc> {
         Self::mouse_event(activation, event_name, target, None, 0, true, button)
     }
 
+    /// Creates a `TouchEvent`.
+    ///
+    /// `touch_point` is the ID of the touch point, and whether it's the primary one.
+    pub fn touch_event(
+        activation: &mut Activation<'_, 'gc>,
+        event_type: &str,
+        target: DisplayObject<'gc>,
+        touch_point: (u32, bool),
+        position: Point<Twips>,
+        related_object: Option<InteractiveObject<'gc>>,
+        bubbles: bool,
+    ) -> EventObject<'gc> {
+        let (touch_point_id, is_primary) = touch_point;
+        let event_type = AvmString::new_utf8(activation.gc(), event_type);
+        let local = target.global_to_local(position).unwrap_or(position);
+
+        let touch_event_cls = activation.avm2().classes().touchevent;
+        Self::from_class_and_args(
+            activation,
+            touch_event_cls,
+            &[
+                event_type.into(),
+                // bubbles
+                bubbles.into(),
+                // cancelable
+                false.into(),
+                // touchPointID
+                touch_point_id.into(),
+                // isPrimaryTouchPoint
+                is_primary.into(),
+                // localX
+                local.x.to_pixels().into(),
+                // localY
+                local.y.to_pixels().into(),
+                // sizeX
+                f64::NAN.into(),
+                // sizeY
+                f64::NAN.into(),
+                // pressure
+                f64::NAN.into(),
+                // relatedObject
+                related_object
+                    .map(|o| o.as_displayobject().object2_or_null())
+                    .unwrap_or(Value::Null),
+                // ctrlKey
+                activation
+                    .context
+                    .input
+                    .is_key_down(KeyCode::CONTROL)
+                    .into(),
+                // altKey
+                activation.context.input.is_key_down(KeyCode::ALT).into(),
+                // shiftKey
+                activation.context.input.is_key_down(KeyCode::SHIFT).into(),
+            ],
+        )
+    }
+
+    /// Creates the `GestureEvent` reporting a recognized gesture.
+    pub fn gesture_event(
+        activation: &mut Activation<'_, 'gc>,
+        target: DisplayObject<'gc>,
+        gesture: Gesture,
+    ) -> EventObject<'gc> {
+        let to_local = |x, y| {
+            let global = Point::from_pixels(x, y);
+            let local = target.global_to_local(global).unwrap_or(global);
+            (local.x.to_pixels(), local.y.to_pixels())
+        };
+
+        let ctrl_key = activation.context.input.is_key_down(KeyCode::CONTROL);
+        let alt_key = activation.context.input.is_key_down(KeyCode::ALT);
+        let shift_key = activation.context.input.is_key_down(KeyCode::SHIFT);
+
+        match gesture {
+            Gesture::Transform {
+                kind,
+                phase,
+                x,
+                y,
+                scale,
+                rotation,
+                offset_x,
+                offset_y,
+            } => {
+                let event_type = AvmString::new_utf8(activation.gc(), kind.event_type());
+                let phase = AvmString::new_utf8(activation.gc(), phase.to_string());
+                let (local_x, local_y) = to_local(x, y);
+
+                let transform_gesture_event_cls = activation.avm2().classes().transformgestureevent;
+                Self::from_class_and_args(
+                    activation,
+                    transform_gesture_event_cls,
+                    &[
+                        event_type.into(),
+                        // bubbles
+                        true.into(),
+                        // cancelable
+                        false.into(),
+                        phase.into(),
+                        local_x.into(),
+                        local_y.into(),
+                        // scaleX
+                        scale.into(),
+                        // scaleY
+                        scale.into(),
+                        rotation.into(),
+                        offset_x.into(),
+                        offset_y.into(),
+                        ctrl_key.into(),
+                        alt_key.into(),
+                        shift_key.into(),
+                        // controlKey
+                        ctrl_key.into(),
+                    ],
+                )
+            }
+            Gesture::TwoFingerTap { x, y } => {
+                let event_type = AvmString::new_utf8(activation.gc(), "gestureTwoFingerTap");
+                let phase = AvmString::new_utf8(activation.gc(), GesturePhase::All.to_string());
+                let (local_x, local_y) = to_local(x, y);
+
+                let gesture_event_cls = activation.avm2().classes().gestureevent;
+                Self::from_class_and_args(
+                    activation,
+                    gesture_event_cls,
+                    &[
+                        event_type.into(),
+                        // bubbles
+                        true.into(),
+                        // cancelable
+                        false.into(),
+                        phase.into(),
+                        local_x.into(),
+                        local_y.into(),
+                        ctrl_key.into(),
+                        alt_key.into(),
+                        shift_key.into(),
+                        // commandKey
+                        false.into(),
+                        // controlKey
+                        ctrl_key.into(),
+                    ],
+                )
+            }
+            Gesture::PressAndTap { x, y, tap_x, tap_y } => {
+                let event_type = AvmString::new_utf8(activation.gc(), "gesturePressAndTap");
+                let phase = AvmString::new_utf8(activation.gc(), GesturePhase::All.to_string());
+                let (local_x, local_y) = to_local(x, y);
+                let (tap_local_x, tap_local_y) = to_local(tap_x, tap_y);
+
+                let press_and_tap_gesture_event_cls =
+                    activation.avm2().classes().pressandtapgestureevent;
+                Self::from_class_and_args(
+                    activation,
+                    press_and_tap_gesture_event_cls,
+                    &[
+                        event_type.into(),
+                        // bubbles
+                        true.into(),
+                        // cancelable
+                        false.into(),
+                        phase.into(),
+                        local_x.into(),
+                        local_y.into(),
+                        tap_local_x.into(),
+                        tap_local_y.into(),
+                        ctrl_key.into(),
+                        alt_key.into(),
+                        shift_key.into(),
+                        // controlKey
+                        ctrl_key.into(),
+                    ],
+                )
+            }
+        }
+    }
+
     pub fn text_event(
         activation: &mut Activation<'_, 'gc>,
         event_type: &str,
@@ -281,6 +465,50 @@ impl<'gc> EventObject<'gc> {
         )
     }
 
+    pub fn sample_data_event(
+        activation: &mut Activation<'_, 'gc>,
+        position: f64,
+        data: ByteArrayObject<'gc>,
+    ) -> EventObject<'gc> {
+        let event_type = AvmString::new_utf8(activation.gc(), "sampleData");
+
+        let sample_data_event_cls = activation.avm2().classes().sampledataevent;
+        Self::from_class_and_args(
+            activation,
+            sample_data_event_cls,
+            &[
+                event_type.into(),
+                // bubbles
+                false.into(),
+                // cancelable
+                false.into(),
+                position.into(),
+                data.into(),
+            ],
+        )
+    }
+
+    pub fn activity_event(
+        activation: &mut Activation<'_, 'gc>,
+        activating: bool,
+    ) -> EventObject<'gc> {
+        let event_type = AvmString::new_utf8(activation.gc(), "activity");
+
+        let activity_event_cls = activation.avm2().classes().activityevent;
+        Self::from_class_and_args(
+            activation,
+            activity_event_cls,
+            &[
+                event_type.into(),
+                // bubbles
+                false.into(),
+                // cancelable
+                false.into(),
+                activating.into(),
+            ],
+        )
+    }
+
     pub fn focus_event(
         activation: &mut Activation<'_, 'gc>,
         event_type: &str,
@@ -5,7 +5,7 @@ use crate::backend::ui::FileDialogResult;
 use crate::context::UpdateContext;
 use gc_arena::{Collect, DynamicRoot, Gc, GcWeak, Rootable};
 use ruffle_common::utils::HasPrefixField;
-use std::cell::{Cell, Ref, RefCell};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::fmt;
 
 pub fn file_reference_allocator<'gc>(
@@ -59,10 +59,31 @@ impl FileReferenceObject<'_> {
             .replace(FileReference::FileDialogResult(result))
     }
 
+    /// Makes this object refer to a file in the sandboxed filesystem.
+    pub fn init_from_path(self, path: String) -> FileReference {
+        self.set_loaded(false);
+        self.0.reference.replace(FileReference::Path {
+            path,
+            contents: Vec::new(),
+        })
+    }
+
     pub fn file_reference(&self) -> Ref<'_, FileReference> {
         self.0.reference.borrow()
     }
 
+    pub fn file_reference_mut(&self) -> RefMut<'_, FileReference> {
+        self.0.reference.borrow_mut()
+    }
+
+    /// The path of the file in the sandboxed filesystem, if this object refers to one.
+    pub fn path(&self) -> Option<String> {
+        match &*self.file_reference() {
+            FileReference::Path { path, .. } => Some(path.clone()),
+            _ => None,
+        }
+    }
+
     pub fn set_loaded(self, value: bool) {
         self.0.loaded.set(value)
     }
@@ -75,6 +96,14 @@ impl FileReferenceObject<'_> {
 pub enum FileReference {
     None,
     FileDialogResult(Box<dyn FileDialogResult>),
+
+    /// A file in the sandboxed filesystem, as referred to by an AIR `File`.
+    ///
+    /// `contents` is only filled in once the file was loaded.
+    Path {
+        path: String,
+        contents: Vec<u8>,
+    },
 }
 
 #[derive(Collect, HasPrefixField)]
@@ -1,9 +1,11 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::object::TObject;
 use crate::avm2::object::script_object::ScriptObjectData;
+use crate::worker::{MessageChannelHandle, MessageChannelState};
 use core::fmt;
 use gc_arena::{Collect, Gc, GcWeak};
 use ruffle_common::utils::HasPrefixField;
+use std::cell::Cell;
 
 #[derive(Clone, Collect, Copy)]
 #[collect(no_drop)]
@@ -27,6 +29,16 @@ impl fmt::Debug for MessageChannelObject<'_> {
 pub struct MessageChannelObjectData<'gc> {
     /// Base script object
     base: ScriptObjectData<'gc>,
+
+    #[collect(require_static)]
+    handle: MessageChannelHandle,
+
+    /// The state of the channel when a `channelState` event was last dispatched.
+    #[collect(require_static)]
+    seen_state: Cell<MessageChannelState>,
+
+    /// How many messages a `channelMessage` event was dispatched for.
+    seen_messages: Cell<usize>,
 }
 
 impl<'gc> TObject<'gc> for MessageChannelObject<'gc> {
@@ -36,9 +48,45 @@ impl<'gc> TObject<'gc> for MessageChannelObject<'gc> {
 }
 
 impl<'gc> MessageChannelObject<'gc> {
-    pub fn new(activation: &mut Activation<'_, 'gc>) -> Self {
+    pub fn new(activation: &mut Activation<'_, 'gc>, handle: MessageChannelHandle) -> Self {
         let class = activation.avm2().classes().messagechannel;
         let base = ScriptObjectData::new(class);
-        MessageChannelObject(Gc::new(activation.gc(), MessageChannelObjectData { base }))
+        let seen_state = Cell::new(handle.state());
+        // Messages that are still queued weren't seen by anyone yet.
+        let seen_messages = Cell::new(handle.received_count());
+        MessageChannelObject(Gc::new(
+            activation.gc(),
+            MessageChannelObjectData {
+                base,
+                handle,
+                seen_state,
+                seen_messages,
+            },
+        ))
+    }
+
+    pub fn handle(self) -> &'gc MessageChannelHandle {
+        &Gc::as_ref(self.0).handle
+    }
+
+    pub fn seen_state(self) -> MessageChannelState {
+        self.0.seen_state.get()
+    }
+
+    /// Returns whether the state of the channel changed since this was last called.
+    pub fn observe_state(self) -> bool {
+        let state = self.0.handle.state();
+        self.0.seen_state.replace(state) != state
+    }
+
+    /// Returns whether a message was sent that this wasn't told about yet.
+    pub fn observe_message(self) -> bool {
+        let seen_messages = self.0.seen_messages.get();
+        if seen_messages < self.0.handle.sent_count() {
+            self.0.seen_messages.set(seen_messages + 1);
+            true
+        } else {
+            false
+        }
     }
 }
@@ -0,0 +1,72 @@
+use crate::avm2::Error;
+use crate::avm2::activation::Activation;
+use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::{ClassObject, Object, TObject};
+use crate::worker::MutexHandle;
+use core::fmt;
+use gc_arena::{Collect, Gc, GcWeak};
+use ruffle_common::utils::HasPrefixField;
+
+/// A class instance allocator that allocates Mutex objects.
+pub fn mutex_allocator<'gc>(
+    class: ClassObject<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Object<'gc>, Error<'gc>> {
+    let base = ScriptObjectData::new(class);
+
+    Ok(MutexObject(Gc::new(
+        activation.gc(),
+        MutexObjectData {
+            base,
+            handle: MutexHandle::new(),
+        },
+    ))
+    .into())
+}
+
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct MutexObject<'gc>(pub Gc<'gc, MutexObjectData<'gc>>);
+
+#[derive(Clone, Collect, Copy, Debug)]
+#[collect(no_drop)]
+pub struct MutexObjectWeak<'gc>(pub GcWeak<'gc, MutexObjectData<'gc>>);
+
+impl fmt::Debug for MutexObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MutexObject")
+            .field("ptr", &Gc::as_ptr(self.0))
+            .finish()
+    }
+}
+
+#[derive(Collect, HasPrefixField)]
+#[collect(no_drop)]
+#[repr(C, align(8))]
+pub struct MutexObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// The mutex itself, which may be shared with other workers.
+    #[collect(require_static)]
+    handle: MutexHandle,
+}
+
+impl<'gc> TObject<'gc> for MutexObject<'gc> {
+    fn gc_base(&self) -> Gc<'gc, ScriptObjectData<'gc>> {
+        HasPrefixField::as_prefix_gc(self.0)
+    }
+}
+
+impl<'gc> MutexObject<'gc> {
+    /// Wrap a mutex received from another worker.
+    pub fn from_handle(activation: &mut Activation<'_, 'gc>, handle: MutexHandle) -> Self {
+        let class = activation.avm2().classes().mutex;
+        let base = ScriptObjectData::new(class);
+        MutexObject(Gc::new(activation.gc(), MutexObjectData { base, handle }))
+    }
+
+    pub fn handle(self) -> &'gc MutexHandle {
+        &Gc::as_ref(self.0).handle
+    }
+}
@@ -5,6 +5,7 @@ use crate::avm2::class::Class;
 use crate::avm2::dynamic_map::{DynamicKey, DynamicMap};
 use crate::avm2::error;
 use crate::avm2::object::{ArrayObject, ClassObject, FunctionObject, Object, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::avm2::vtable::VTable;
 use crate::avm2::{Error, Multiname, QName};
@@ -101,7 +102,11 @@ impl<'gc> ScriptObject<'gc> {
     pub fn new_object(context: &mut UpdateContext<'gc>) -> Object<'gc> {
         let object_class = context.avm2.classes().object;
 
-        ScriptObject(Gc::new(context.gc(), ScriptObjectData::new(object_class))).into()
+        let object =
+            ScriptObject(Gc::new(context.gc(), ScriptObjectData::new(object_class))).into();
+        Sampler::record_allocation(context, object, object_class);
+
+        object
     }
 
     /// Construct an instance with a possibly-none class and proto chain.
@@ -41,6 +41,7 @@ pub fn sound_allocator<'gc>(
                 queued_plays: Vec::new(),
             }),
             id3: Lock::new(None),
+            extract_position: Cell::new(0),
         },
     ))
     .into())
@@ -90,6 +91,9 @@ pub struct SoundObjectData<'gc> {
 
     /// ID3Info Object
     id3: Lock<Option<Object<'gc>>>,
+
+    /// The sample frame following the last one read by `Sound.extract`.
+    extract_position: Cell<u32>,
 }
 
 #[derive(Collect)]
@@ -120,6 +124,8 @@ pub enum SoundLoadingState {
     New,
     Loading,
     Loaded,
+    /// The download was cancelled by `Sound.close`.
+    Closed,
 }
 
 impl<'gc> SoundObject<'gc> {
@@ -182,6 +188,14 @@ impl<'gc> SoundObject<'gc> {
         self.set_loading_state(SoundLoadingState::Loaded);
     }
 
+    pub fn extract_position(self) -> u32 {
+        self.0.extract_position.get()
+    }
+
+    pub fn set_extract_position(self, position: u32) {
+        self.0.extract_position.set(position);
+    }
+
     pub fn id3(self) -> Option<Object<'gc>> {
         self.0.id3.get()
     }
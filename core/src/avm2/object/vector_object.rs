@@ -5,6 +5,7 @@ use crate::avm2::activation::Activation;
 use crate::avm2::error::{Error, ReferenceErrorCode, make_error_1125, make_reference_error};
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::avm2::vector::VectorStorage;
 use crate::string::WStr;
@@ -75,13 +76,16 @@ impl<'gc> VectorObject<'gc> {
 
         let applied_class = vector_class.parametrize(activation, value_type);
 
-        VectorObject(Gc::new(
+        let object = VectorObject(Gc::new(
             activation.gc(),
             VectorObjectData {
                 base: ScriptObjectData::new(applied_class),
                 vector: RefLock::new(vector),
             },
-        ))
+        ));
+        Sampler::record_allocation(activation.context, object.into(), applied_class);
+
+        object
     }
 
     fn as_vector_index(local_name: &WStr) -> Option<f64> {
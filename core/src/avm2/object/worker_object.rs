@@ -1,9 +1,11 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::object::TObject;
 use crate::avm2::object::script_object::ScriptObjectData;
+use crate::worker::{WorkerHandle, WorkerState};
 use core::fmt;
 use gc_arena::{Collect, Gc, GcWeak};
 use ruffle_common::utils::HasPrefixField;
+use std::cell::Cell;
 
 #[derive(Clone, Collect, Copy)]
 #[collect(no_drop)]
@@ -27,6 +29,13 @@ impl fmt::Debug for WorkerObject<'_> {
 pub struct WorkerObjectData<'gc> {
     /// Base script object
     base: ScriptObjectData<'gc>,
+
+    #[collect(require_static)]
+    handle: WorkerHandle,
+
+    /// The state of the worker when a `workerState` event was last dispatched.
+    #[collect(require_static)]
+    seen_state: Cell<WorkerState>,
 }
 
 impl<'gc> TObject<'gc> for WorkerObject<'gc> {
@@ -36,9 +45,31 @@ impl<'gc> TObject<'gc> for WorkerObject<'gc> {
 }
 
 impl<'gc> WorkerObject<'gc> {
-    pub fn new(activation: &mut Activation<'_, 'gc>) -> Self {
+    pub fn new(activation: &mut Activation<'_, 'gc>, handle: WorkerHandle) -> Self {
         let class = activation.avm2().classes().worker;
         let base = ScriptObjectData::new(class);
-        WorkerObject(Gc::new(activation.gc(), WorkerObjectData { base }))
+        let seen_state = Cell::new(handle.state());
+        WorkerObject(Gc::new(
+            activation.gc(),
+            WorkerObjectData {
+                base,
+                handle,
+                seen_state,
+            },
+        ))
+    }
+
+    pub fn handle(self) -> &'gc WorkerHandle {
+        &Gc::as_ref(self.0).handle
+    }
+
+    pub fn seen_state(self) -> WorkerState {
+        self.0.seen_state.get()
+    }
+
+    /// Returns whether the state of the worker changed since this was last called.
+    pub fn observe_state(self) -> bool {
+        let state = self.0.handle.state();
+        self.0.seen_state.replace(state) != state
     }
 }
@@ -8,6 +8,7 @@ use crate::avm2::error::make_error_1089;
 use crate::avm2::function::FunctionArgs;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{Object, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::avm2::{Error, Multiname, Namespace};
 use crate::string::AvmString;
@@ -77,17 +78,20 @@ impl<'gc> XmlListObject<'gc> {
         target_object: Option<XmlOrXmlListObject<'gc>>,
         target_property: Option<Multiname<'gc>>,
     ) -> XmlListObject<'gc> {
-        let base = ScriptObjectData::new(activation.context.avm2.classes().xml_list);
-        XmlListObject(Gc::new(
+        let class = activation.avm2().classes().xml_list;
+        let object = XmlListObject(Gc::new(
             activation.gc(),
             XmlListObjectData {
-                base,
+                base: ScriptObjectData::new(class),
                 children: RefLock::new(children),
                 target_object: Lock::new(target_object),
                 target_property: RefLock::new(target_property),
                 target_dirty: Cell::new(false),
             },
-        ))
+        ));
+        Sampler::record_allocation(activation.context, object.into(), class);
+
+        object
     }
 
     pub fn set_dirty_flag(self) {
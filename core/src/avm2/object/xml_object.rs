@@ -9,6 +9,7 @@ use crate::avm2::error::make_error_1087;
 use crate::avm2::function::FunctionArgs;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, NamespaceObject, Object, TObject, XmlListObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::string::AvmString;
 use crate::avm2::value::Value;
 use crate::avm2::{Error, Multiname};
@@ -65,13 +66,17 @@ pub struct XmlObjectData<'gc> {
 
 impl<'gc> XmlObject<'gc> {
     pub fn new(node: E4XNode<'gc>, activation: &mut Activation<'_, 'gc>) -> Self {
-        XmlObject(Gc::new(
+        let class = activation.avm2().classes().xml;
+        let object = XmlObject(Gc::new(
             activation.gc(),
             XmlObjectData {
-                base: ScriptObjectData::new(activation.context.avm2.classes().xml),
+                base: ScriptObjectData::new(class),
                 node: Lock::new(node),
             },
-        ))
+        ));
+        Sampler::record_allocation(activation.context, object.into(), class);
+
+        object
     }
 
     fn get_child_list(
@@ -391,6 +391,15 @@ impl Op<'_> {
         }
     }
 
+    /// Whether this op only carries debug information (register names,
+    /// source file and line numbers).
+    pub fn is_debug(&self) -> bool {
+        matches!(
+            self,
+            Op::Debug { .. } | Op::DebugFile { .. } | Op::DebugLine { .. }
+        )
+    }
+
     /// Whether all this op does is push a single value to the stack, possibly
     /// reading from stack or locals, but never, e.g., throwing an error or
     /// calling a method.
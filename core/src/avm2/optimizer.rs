@@ -21,6 +21,7 @@ pub fn optimize<'gc>(
     activation: &mut Activation<'_, 'gc>,
     method: Method<'gc>,
     code: &mut Vec<Op<'gc>>,
+    byte_offsets: &mut Vec<usize>,
     method_exceptions: &mut [Exception<'gc>],
     resolved_parameters: &[ResolvedParamConfig<'gc>],
     mut jump_targets: HashSet<usize>,
@@ -45,7 +46,11 @@ pub fn optimize<'gc>(
 
     dce::eliminate_dead_code(code_slice, &jump_targets);
 
-    nop_remover::remove_nops(code, method_exceptions);
+    // Debug ops are normally stripped, but an attached debugger needs them to
+    // map execution back to source lines.
+    let keep_debug_ops = activation.context.avm2.debugger_attached();
+
+    nop_remover::remove_nops(code, byte_offsets, method_exceptions, keep_debug_ops);
 
     Ok(())
 }
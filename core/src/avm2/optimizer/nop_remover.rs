@@ -1,7 +1,12 @@
 use crate::avm2::op::Op;
 use crate::avm2::verify::Exception;
 
-pub fn remove_nops<'gc>(code: &mut Vec<Op<'gc>>, exceptions: &mut [Exception<'gc>]) {
+pub fn remove_nops<'gc>(
+    code: &mut Vec<Op<'gc>>,
+    byte_offsets: &mut Vec<usize>,
+    exceptions: &mut [Exception<'gc>],
+    keep_debug_ops: bool,
+) {
     let mut offset_vec = vec![0; code.len()];
     let mut current_offset = 0;
 
@@ -9,11 +14,12 @@ pub fn remove_nops<'gc>(code: &mut Vec<Op<'gc>>, exceptions: &mut [Exception<'gc
     let mut i = 0;
     while i < code.len() {
         offset_vec[i] = i - current_offset;
-        if code[i].is_nop() {
+        if code[i].is_nop() && !(keep_debug_ops && code[i].is_debug()) {
             current_offset += 1;
         } else {
             // Shift the ops over the nops
             code[i - current_offset] = code[i];
+            byte_offsets[i - current_offset] = byte_offsets[i];
         }
 
         i += 1;
@@ -22,6 +28,7 @@ pub fn remove_nops<'gc>(code: &mut Vec<Op<'gc>>, exceptions: &mut [Exception<'gc
     // The ops have all been shifted over now, so remove the garbage ops left
     // at the end of the code Vec
     code.truncate(code.len() - current_offset);
+    byte_offsets.truncate(code.len());
 
     // Rewrite jump offsets
     for op in code {
@@ -0,0 +1,308 @@
+//! Memory and invocation profiling, as exposed by `flash.sampler`
+//!
+//! Allocations are sampled for objects constructed by ActionScript, and for
+//! the objects, arrays, vectors, byte arrays, XML and events that are created
+//! natively. Strings aren't objects here and have no identity to track their
+//! collection with, so their allocations aren't sampled.
+
+use crate::avm2::call_stack::CallStack;
+use crate::avm2::method::Method;
+use crate::avm2::object::{ClassObject, Object, TObject, WeakObject};
+use crate::avm2::value::Value;
+use crate::context::UpdateContext;
+use fnv::FnvHashMap;
+use gc_arena::{Collect, Mutation};
+use std::collections::VecDeque;
+use std::mem::size_of;
+use std::time::Duration;
+use web_time::Instant;
+
+/// How often the call stack is sampled while sampling.
+const STACK_SAMPLE_INTERVAL: Duration = Duration::from_millis(1);
+
+/// How many samples are buffered before the sampler callback is called.
+const SAMPLE_BUFFER_SIZE: usize = 4096;
+
+/// How many samples are kept at most. The oldest samples are dropped to make
+/// room for new ones, so that sampling without a callback doesn't grow forever.
+const MAX_SAMPLES: usize = 16 * SAMPLE_BUFFER_SIZE;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SamplerState {
+    Stopped,
+    Sampling,
+    Paused,
+}
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub enum SampleKind<'gc> {
+    /// The call stack at the time of the sample.
+    Stack,
+
+    /// An object was allocated.
+    NewObject {
+        id: u64,
+        class: ClassObject<'gc>,
+        size: usize,
+    },
+
+    /// An object that was allocated while sampling was garbage collected.
+    DeleteObject { id: u64, size: usize },
+}
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct Sample<'gc> {
+    /// The time of the sample, in microseconds since sampling started.
+    pub time: f64,
+
+    /// The call stack, outermost method first.
+    pub stack: Vec<Method<'gc>>,
+
+    pub kind: SampleKind<'gc>,
+}
+
+/// An object allocated while sampling, which hasn't been collected yet.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct LiveObject<'gc> {
+    size: usize,
+    object: WeakObject<'gc>,
+}
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct Sampler<'gc> {
+    #[collect(require_static)]
+    state: SamplerState,
+
+    /// When sampling was started.
+    #[collect(require_static)]
+    start_time: Instant,
+
+    /// When the call stack was last sampled.
+    #[collect(require_static)]
+    last_stack_sample: Instant,
+
+    samples: VecDeque<Sample<'gc>>,
+
+    /// The objects allocated while sampling that are still alive, by sample id.
+    live_objects: FnvHashMap<u64, LiveObject<'gc>>,
+
+    next_object_id: u64,
+
+    /// How often each method has been called while sampling.
+    invocations: FnvHashMap<Method<'gc>, u32>,
+
+    /// The function to call when the sample buffer is full.
+    callback: Option<Object<'gc>>,
+}
+
+impl<'gc> Sampler<'gc> {
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            state: SamplerState::Stopped,
+            start_time: now,
+            last_stack_sample: now,
+            samples: VecDeque::new(),
+            live_objects: Default::default(),
+            next_object_id: 1,
+            invocations: Default::default(),
+            callback: None,
+        }
+    }
+
+    /// Whether samples are currently being recorded.
+    #[inline]
+    pub fn is_sampling(&self) -> bool {
+        self.state == SamplerState::Sampling
+    }
+
+    /// Start recording samples, or resume recording them if paused.
+    pub fn start(&mut self) {
+        if self.state == SamplerState::Stopped {
+            self.start_time = Instant::now();
+            self.last_stack_sample = self.start_time;
+        }
+        self.state = SamplerState::Sampling;
+    }
+
+    /// Stop recording samples, keeping the ones recorded so far.
+    pub fn pause(&mut self) {
+        if self.state == SamplerState::Sampling {
+            self.state = SamplerState::Paused;
+        }
+    }
+
+    /// Stop recording samples and discard everything recorded so far.
+    pub fn stop(&mut self) {
+        self.state = SamplerState::Stopped;
+        self.samples.clear();
+        self.live_objects.clear();
+        self.invocations.clear();
+    }
+
+    pub fn clear_samples(&mut self) {
+        self.samples.clear();
+    }
+
+    pub fn set_callback(&mut self, callback: Option<Object<'gc>>) {
+        self.callback = callback;
+    }
+
+    /// The recorded samples, after recording the deletion of any sampled
+    /// objects that have been collected since.
+    pub fn samples(&mut self, mc: &Mutation<'gc>) -> &[Sample<'gc>] {
+        self.record_deletions(mc);
+        self.samples.make_contiguous()
+    }
+
+    /// How often `method` has been called while sampling.
+    pub fn invocation_count(&self, method: Method<'gc>) -> u32 {
+        self.invocations.get(&method).copied().unwrap_or_default()
+    }
+
+    /// The object allocated with the given sample id, if it's still alive.
+    pub fn object(&self, mc: &Mutation<'gc>, id: u64) -> Option<Object<'gc>> {
+        self.live_objects
+            .get(&id)
+            .and_then(|live| live.object.upgrade(mc))
+    }
+
+    fn time(&self, now: Instant) -> f64 {
+        now.duration_since(self.start_time).as_micros() as f64
+    }
+
+    fn push_sample(&mut self, sample: Sample<'gc>) {
+        if self.samples.len() >= MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Record a call of `method`, which was just pushed on `call_stack`.
+    pub fn record_invocation(&mut self, method: Method<'gc>, call_stack: &CallStack<'gc>) {
+        *self.invocations.entry(method).or_default() += 1;
+
+        let now = Instant::now();
+        if now.duration_since(self.last_stack_sample) >= STACK_SAMPLE_INTERVAL {
+            self.last_stack_sample = now;
+            self.push_sample(Sample {
+                time: self.time(now),
+                stack: call_stack.iter().collect(),
+                kind: SampleKind::Stack,
+            });
+        }
+    }
+
+    /// Record the allocation of `object`, an instance of `class`, if sampling.
+    #[inline]
+    pub fn record_allocation(
+        context: &mut UpdateContext<'gc>,
+        object: Object<'gc>,
+        class: ClassObject<'gc>,
+    ) {
+        if context.avm2.sampler().is_sampling() {
+            Self::record_allocation_slow(context, object, class);
+        }
+    }
+
+    #[inline(never)]
+    fn record_allocation_slow(
+        context: &mut UpdateContext<'gc>,
+        object: Object<'gc>,
+        class: ClassObject<'gc>,
+    ) {
+        let stack = context.avm2.call_stack().borrow().iter().collect();
+        let size = estimate_size(object.into());
+
+        let sampler = context.avm2.sampler_mut();
+        let id = sampler.next_object_id;
+        sampler.next_object_id += 1;
+        sampler.live_objects.insert(
+            id,
+            LiveObject {
+                size,
+                object: object.downgrade(),
+            },
+        );
+        let time = sampler.time(Instant::now());
+        sampler.push_sample(Sample {
+            time,
+            stack,
+            kind: SampleKind::NewObject { id, class, size },
+        });
+    }
+
+    /// Record a `DeleteObject` sample for every sampled object that has been
+    /// collected.
+    fn record_deletions(&mut self, mc: &Mutation<'gc>) {
+        let time = self.time(Instant::now());
+        let mut deleted = Vec::new();
+        self.live_objects.retain(|&id, live| {
+            if live.object.upgrade(mc).is_some() {
+                return true;
+            }
+            deleted.push((id, live.size));
+            false
+        });
+
+        // Record the deletions in the order the objects were allocated.
+        deleted.sort_unstable_by_key(|&(id, _)| id);
+        for (id, size) in deleted {
+            self.push_sample(Sample {
+                time,
+                stack: Vec::new(),
+                kind: SampleKind::DeleteObject { id, size },
+            });
+        }
+    }
+
+    /// The sampler callback, if it's due because the sample buffer is full.
+    pub fn due_callback(&self) -> Option<Object<'gc>> {
+        if self.samples.len() >= SAMPLE_BUFFER_SIZE {
+            self.callback
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Sampler<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An estimate of how many bytes of memory `value` uses.
+pub fn estimate_size(value: Value<'_>) -> usize {
+    let value_size = size_of::<Value>();
+    match value {
+        Value::Undefined | Value::Null => 0,
+        Value::Bool(_) | Value::Integer(_) => 4,
+        Value::Number(_) => 8,
+        Value::String(string) => {
+            let char_size = if string.is_wide() { 2 } else { 1 };
+            // The string header, plus its characters.
+            32 + string.len() * char_size
+        }
+        Value::Object(object) => {
+            let mut size = 64 + object.vtable().slot_table().len() * value_size;
+            size += object.base().values().len() * 2 * value_size;
+
+            if let Some(array) = object.as_array_storage() {
+                size += array.length() * value_size;
+            } else if let Some(vector) = object.as_vector_storage() {
+                size += vector.length() * value_size;
+            } else if let Some(bytearray) = object.as_bytearray() {
+                size += bytearray.len();
+            } else if let Some(bitmap_data) = object.as_bitmap_data() {
+                size += bitmap_data.width() as usize * bitmap_data.height() as usize * 4;
+            }
+
+            size
+        }
+    }
+}
@@ -25,6 +25,14 @@ use swf::error::Error as AbcReadError;
 pub struct VerifiedMethodInfo<'gc> {
     pub parsed_code: Vec<Op<'gc>>,
 
+    /// The offset in the method body's bytecode that each op in `parsed_code`
+    /// was translated from. This is used by the debugger to map breakpoints.
+    pub byte_offsets: Vec<usize>,
+
+    /// Whether `parsed_code` contains `debugline` ops. The debugger steps
+    /// through source lines if so, and through individual ops otherwise.
+    pub has_line_info: bool,
+
     pub exceptions: Vec<Exception<'gc>>,
 }
 
@@ -511,13 +519,20 @@ pub fn verify_method<'gc>(
         activation,
         method,
         &mut verified_code,
+        &mut idx_to_byte_offset,
         &mut new_exceptions,
         resolved_param_config,
         jump_targets,
     )?;
 
+    let has_line_info = verified_code
+        .iter()
+        .any(|op| matches!(op, Op::DebugLine { .. }));
+
     Ok(VerifiedMethodInfo {
         parsed_code: verified_code,
+        byte_offsets: idx_to_byte_offset,
+        has_line_info,
         exceptions: new_exceptions,
     })
 }
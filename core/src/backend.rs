@@ -1,5 +1,10 @@
 pub mod audio;
+pub mod camera;
+pub mod filesystem;
 pub mod log;
+pub mod microphone;
 pub mod navigator;
+pub mod print;
+pub mod process;
 pub mod storage;
 pub mod ui;
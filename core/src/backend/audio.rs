@@ -156,6 +156,18 @@ pub trait AudioBackend: Any {
     /// Get the sound format that a given sound was added with.
     fn get_sound_format(&self, sound: SoundHandle) -> Option<&swf::SoundFormat>;
 
+    /// Decodes a registered sound into 44.1 kHz stereo samples, as used by `Sound.extract`.
+    ///
+    /// Decoding starts at `position`, measured in 44.1 kHz sample frames, and stops when
+    /// `output` is full or the end of the sound is reached.
+    /// Returns the number of sample frames written to `output`.
+    fn extract_sound(
+        &self,
+        sound: SoundHandle,
+        position: u32,
+        output: &mut [[f32; 2]],
+    ) -> Result<usize, DecodeError>;
+
     /// Set the volume transform for a sound instance.
     fn set_sound_transform(&mut self, instance: SoundInstanceHandle, transform: SoundTransform);
 
@@ -308,6 +320,15 @@ impl AudioBackend for NullAudioBackend {
         self.sounds.get(sound).map(|s| &s.format)
     }
 
+    fn extract_sound(
+        &self,
+        _sound: SoundHandle,
+        _position: u32,
+        _output: &mut [[f32; 2]],
+    ) -> Result<usize, DecodeError> {
+        Ok(0)
+    }
+
     fn set_sound_transform(&mut self, _instance: SoundInstanceHandle, _transform: SoundTransform) {}
 
     fn get_sound_peak(&mut self, _instance: SoundInstanceHandle) -> Option<[f32; 2]> {
@@ -162,6 +162,18 @@ struct Sound {
     /// `skip_sample_frames` indicates how many sample frames to skip to bypass the delay.
     /// This is `0` unless `format.compression` is `AudioCompression::Mp3`.
     skip_sample_frames: u16,
+
+    /// The stream used by the last `Sound.extract` call, so that extracting
+    /// consecutive ranges doesn't decode the sound from the start every time.
+    extractor: Mutex<Option<SoundExtractor>>,
+}
+
+/// A 44.1 kHz stream of a sound, as read by `Sound.extract`.
+struct SoundExtractor {
+    stream: Box<dyn Stream>,
+
+    /// The position of the next sample frame of `stream`, in 44.1 kHz sample frames.
+    position: u32,
 }
 
 /// An actively playing instance of a sound.
@@ -337,17 +349,22 @@ impl AudioMixer {
     }
 
     /// Transforms a `Stream` into a new `Stream` that matches the output sample rate.
-    fn make_resampler<S: Stream>(&self, mut stream: S) -> impl Stream + use<S> {
+    fn make_resampler<S: Stream>(&self, stream: S) -> impl Stream + use<S> {
+        Self::make_resampler_to(stream, self.output_sample_rate)
+    }
+
+    /// Transforms a `Stream` into a new `Stream` with the given sample rate.
+    fn make_resampler_to<S: Stream>(mut stream: S, sample_rate: u32) -> impl Stream + use<S> {
         // TODO: Allow interpolator to be user-configurable?
         let left = stream.next();
         let right = stream.next();
         let interpolator = dasp::interpolate::linear::Linear::new(left, right);
-        let sample_rate = stream.source_sample_rate().into();
+        let source_sample_rate = stream.source_sample_rate().into();
         ConverterStream(dasp::signal::interpolate::Converter::from_hz_to_hz(
             stream,
             interpolator,
-            sample_rate,
-            self.output_sample_rate.into(),
+            source_sample_rate,
+            sample_rate.into(),
         ))
     }
 
@@ -527,6 +544,7 @@ impl AudioMixer {
             data: Arc::from(data),
             num_sample_frames: swf_sound.num_samples,
             skip_sample_frames,
+            extractor: Mutex::new(None),
         };
         Ok(self.sounds.insert(sound))
     }
@@ -547,6 +565,7 @@ impl AudioMixer {
             data,
             num_sample_frames: metadata.num_sample_frames,
             skip_sample_frames: 0,
+            extractor: Mutex::new(None),
         };
         Ok(self.sounds.insert(sound))
     }
@@ -691,6 +710,68 @@ impl AudioMixer {
         self.sounds.get(sound).map(|s| &s.format)
     }
 
+    /// Decodes a registered sound into 44.1 kHz stereo samples, starting at `position`
+    /// (in 44.1 kHz sample frames).
+    ///
+    /// Returns the number of sample frames written to `output`, which is less than its
+    /// length if the end of the sound is reached.
+    pub fn extract_sound(
+        &self,
+        sound_handle: SoundHandle,
+        position: u32,
+        output: &mut [[f32; 2]],
+    ) -> Result<usize, DecodeError> {
+        use dasp::Sample;
+
+        let Some(sound) = self.sounds.get(sound_handle) else {
+            return Ok(0);
+        };
+
+        // `position` and the output use 44.1 kHz sample frames, regardless of the sound's format.
+        let sample_divisor = 44100.0 / f64::from(sound.format.sample_rate);
+        let num_sample_frames = (f64::from(sound.num_sample_frames) * sample_divisor) as usize;
+        let len = output
+            .len()
+            .min(num_sample_frames.saturating_sub(position as usize));
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let mut extractor = sound.extractor.lock().expect("Cannot be called reentrant");
+        // Only seek when the requested range doesn't follow the previous one.
+        let extractor = match &mut *extractor {
+            Some(extractor) if extractor.position == position => extractor,
+            extractor => {
+                let data = Cursor::new(ArcAsRef(Arc::clone(&sound.data)));
+                let decoder = Self::make_seekable_decoder(&sound.format, data)?;
+                let settings = swf::SoundInfo {
+                    event: swf::SoundEvent::Start,
+                    in_sample: Some(position),
+                    out_sample: None,
+                    num_loops: 1,
+                    envelope: None,
+                };
+                let stream = EventSoundStream::new_with_settings(
+                    decoder,
+                    &settings,
+                    sound.num_sample_frames,
+                    sound.skip_sample_frames,
+                );
+                extractor.insert(SoundExtractor {
+                    stream: Box::new(Self::make_resampler_to(stream, 44100)),
+                    position,
+                })
+            }
+        };
+
+        for frame in &mut output[..len] {
+            let [left, right] = extractor.stream.next();
+            *frame = [left.to_sample(), right.to_sample()];
+        }
+        extractor.position += len as u32;
+        Ok(len)
+    }
+
     /// Sets the sound transform for the given playing sound.
     pub fn set_sound_transform(
         &mut self,
@@ -1140,6 +1221,16 @@ macro_rules! impl_audio_mixer_backend {
             self.$mixer.get_sound_format(sound)
         }
 
+        #[inline]
+        fn extract_sound(
+            &self,
+            sound: SoundHandle,
+            position: u32,
+            output: &mut [[f32; 2]],
+        ) -> Result<usize, DecodeError> {
+            self.$mixer.extract_sound(sound, position, output)
+        }
+
         #[inline]
         fn set_sound_transform(
             &mut self,
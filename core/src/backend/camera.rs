@@ -0,0 +1,157 @@
+use ruffle_render::bitmap::Bitmap;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CameraError {
+    #[error("No camera with index {0}")]
+    NoDevice(usize),
+
+    #[error("Couldn't start capturing video: {0}")]
+    Capture(String),
+}
+
+/// The size and frame rate a camera captures at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraMode {
+    pub width: u32,
+    pub height: u32,
+
+    /// The maximum number of frames captured per second.
+    pub fps: f64,
+}
+
+/// A backend giving access to the video capture devices of the system.
+///
+/// Devices are referred to by their index in the list returned by
+/// [`CameraBackend::names`].
+pub trait CameraBackend {
+    /// The names of the available capture devices.
+    fn names(&self) -> Vec<String>;
+
+    /// The index of the device that should be used when a movie doesn't ask
+    /// for a specific one.
+    fn default_device(&self) -> Option<usize> {
+        (!self.names().is_empty()).then_some(0)
+    }
+
+    /// Starts capturing video from the given device.
+    ///
+    /// Devices can't capture in every mode, so the backend picks the mode closest
+    /// to `mode` that the device supports, and returns it. If the device is
+    /// already capturing, it is restarted in the new mode.
+    fn start_capture(&mut self, index: usize, mode: CameraMode) -> Result<CameraMode, CameraError>;
+
+    /// Stops capturing video from the given device.
+    fn stop_capture(&mut self, index: usize);
+
+    /// Takes the latest frame captured by the given device, if one was captured
+    /// since the last call.
+    ///
+    /// Frames are RGBA, in the size of the mode returned by
+    /// [`CameraBackend::start_capture`].
+    fn read_frame(&mut self, index: usize) -> Option<Bitmap<'static>>;
+}
+
+/// Camera backend for platforms without any capture device.
+pub struct NullCameraBackend;
+
+impl NullCameraBackend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CameraBackend for NullCameraBackend {
+    fn names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn start_capture(
+        &mut self,
+        index: usize,
+        _mode: CameraMode,
+    ) -> Result<CameraMode, CameraError> {
+        Err(CameraError::NoDevice(index))
+    }
+
+    fn stop_capture(&mut self, _index: usize) {}
+
+    fn read_frame(&mut self, _index: usize) -> Option<Bitmap<'static>> {
+        None
+    }
+}
+
+impl Default for NullCameraBackend {
+    fn default() -> Self {
+        NullCameraBackend::new()
+    }
+}
+
+/// Camera backend with a single device playing back a sequence of frames.
+///
+/// Every read yields the next frame, looping once the sequence runs out, so
+/// that movies see the same frames on every run regardless of timing. The
+/// frames are typically decoded from a sequence of images or from a video file.
+///
+/// The device only supports the size of the frames, but accepts any frame rate.
+pub struct FrameSequenceCameraBackend {
+    name: String,
+    frames: Vec<Bitmap<'static>>,
+    capturing: bool,
+    position: usize,
+}
+
+impl FrameSequenceCameraBackend {
+    /// Creates a device playing back the given frames.
+    ///
+    /// Frames of any format are converted to RGBA; frames that don't have the
+    /// size of the first frame are skipped.
+    pub fn new(name: impl Into<String>, frames: Vec<Bitmap<'static>>) -> Self {
+        let size = frames.first().map(|frame| (frame.width(), frame.height()));
+        let frames = frames
+            .into_iter()
+            .filter(|frame| Some((frame.width(), frame.height())) == size)
+            .map(Bitmap::to_rgba)
+            .collect();
+
+        Self {
+            name: name.into(),
+            frames,
+            capturing: false,
+            position: 0,
+        }
+    }
+}
+
+impl CameraBackend for FrameSequenceCameraBackend {
+    fn names(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+
+    fn start_capture(&mut self, index: usize, mode: CameraMode) -> Result<CameraMode, CameraError> {
+        let Some(frame) = self.frames.first().filter(|_| index == 0) else {
+            return Err(CameraError::NoDevice(index));
+        };
+
+        self.capturing = true;
+        self.position = 0;
+        Ok(CameraMode {
+            width: frame.width(),
+            height: frame.height(),
+            fps: mode.fps,
+        })
+    }
+
+    fn stop_capture(&mut self, _index: usize) {
+        self.capturing = false;
+    }
+
+    fn read_frame(&mut self, index: usize) -> Option<Bitmap<'static>> {
+        if !self.capturing || index != 0 || self.frames.is_empty() {
+            return None;
+        }
+
+        let frame = self.frames[self.position % self.frames.len()].clone();
+        self.position += 1;
+        Some(frame)
+    }
+}
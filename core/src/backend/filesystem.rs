@@ -0,0 +1,531 @@
+//! Access to files, as exposed by the AIR `flash.filesystem` API.
+//!
+//! Movies never see the real filesystem. Every path given to a [`FileSystemBackend`] is an
+//! absolute, normalized, `/`-separated path inside the movie's sandbox, such as
+//! `/app-storage/settings.xml`. The top level of the sandbox only contains the
+//! [`SpecialDirectory`]s, and it's up to the backend to decide where they're actually stored.
+
+use chrono::{DateTime, Utc};
+use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str, utf8_percent_encode};
+use std::collections::BTreeMap;
+
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+pub enum FileSystemError {
+    #[error("No such file or directory")]
+    NotFound,
+
+    #[error("File or directory already exists")]
+    AlreadyExists,
+
+    #[error("Not a file")]
+    NotAFile,
+
+    #[error("Not a directory")]
+    NotADirectory,
+
+    #[error("Directory is not empty")]
+    DirectoryNotEmpty,
+
+    #[error("Access denied")]
+    AccessDenied,
+
+    #[error("I/O error: {0}")]
+    Io(String),
+}
+
+/// The directories at the top level of the sandbox.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpecialDirectory {
+    /// The files the application was installed with. Read-only.
+    Application,
+
+    /// Where the application stores its private data.
+    ApplicationStorage,
+
+    Desktop,
+
+    Documents,
+
+    User,
+}
+
+impl SpecialDirectory {
+    pub const ALL: [Self; 5] = [
+        Self::Application,
+        Self::ApplicationStorage,
+        Self::Desktop,
+        Self::Documents,
+        Self::User,
+    ];
+
+    /// The path of this directory in the sandbox.
+    pub fn path(self) -> &'static str {
+        match self {
+            Self::Application => "/app",
+            Self::ApplicationStorage => "/app-storage",
+            Self::Desktop => "/desktop",
+            Self::Documents => "/documents",
+            Self::User => "/user",
+        }
+    }
+
+    /// The special directory that contains `path`, if any.
+    pub fn containing(path: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| {
+            path.strip_prefix(dir.path())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileMetadata {
+    pub is_directory: bool,
+
+    /// The size of the file in bytes, or 0 for directories.
+    pub size: u64,
+
+    pub creation_time: Option<DateTime<Utc>>,
+
+    pub modification_time: Option<DateTime<Utc>>,
+}
+
+/// A backend giving movies access to files in their sandbox.
+///
+/// The special directories are expected to always exist. Everything else is created by the movie.
+pub trait FileSystemBackend {
+    fn metadata(&self, path: &str) -> Result<FileMetadata, FileSystemError>;
+
+    fn exists(&self, path: &str) -> bool {
+        self.metadata(path).is_ok()
+    }
+
+    /// Reads the whole contents of a file.
+    fn read(&self, path: &str) -> Result<Vec<u8>, FileSystemError>;
+
+    /// Replaces the contents of a file, creating it if it doesn't exist yet.
+    ///
+    /// The parent directory has to exist.
+    fn write(&mut self, path: &str, data: &[u8]) -> Result<(), FileSystemError>;
+
+    /// Creates a directory, along with any missing parent directories.
+    ///
+    /// Creating a directory that already exists succeeds.
+    fn create_directory(&mut self, path: &str) -> Result<(), FileSystemError>;
+
+    /// The names of the entries of a directory.
+    fn read_directory(&self, path: &str) -> Result<Vec<String>, FileSystemError>;
+
+    /// Deletes a file, or a directory.
+    ///
+    /// Directories which aren't empty are only deleted if `recursive` is set.
+    fn remove(&mut self, path: &str, recursive: bool) -> Result<(), FileSystemError>;
+
+    /// Moves a file or directory to `to`, which must not exist yet.
+    fn rename(&mut self, from: &str, to: &str) -> Result<(), FileSystemError>;
+
+    /// Copies a file or directory to `to`, which must not exist yet.
+    fn copy(&mut self, from: &str, to: &str) -> Result<(), FileSystemError> {
+        if !self.metadata(from)?.is_directory {
+            let data = self.read(from)?;
+            return self.write(to, &data);
+        }
+
+        self.create_directory(to)?;
+        for name in self.read_directory(from)? {
+            self.copy(&join_path(from, &name), &join_path(to, &name))?;
+        }
+        Ok(())
+    }
+}
+
+/// Characters escaped in the paths of `file:`, `app:` and `app-storage:` URLs.
+const URL_PATH: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Resolves `path` relative to the sandbox directory `base`.
+///
+/// Both `/` and `\` separate components. `..` never leaves the sandbox.
+pub fn resolve_path(base: &str, path: &str) -> String {
+    let mut components: Vec<&str> = if path.starts_with(['/', '\\']) {
+        Vec::new()
+    } else {
+        base.split('/').filter(|c| !c.is_empty()).collect()
+    };
+    for component in path.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+    format!("/{}", components.join("/"))
+}
+
+/// The sandbox path of an absolute native path, or of an `app:`, `app-storage:` or `file:` URL.
+pub fn path_from_native_path_or_url(path: &str) -> Option<String> {
+    let (base, rest) = if let Some(rest) = path.strip_prefix("app:") {
+        (SpecialDirectory::Application.path(), rest)
+    } else if let Some(rest) = path.strip_prefix("app-storage:") {
+        (SpecialDirectory::ApplicationStorage.path(), rest)
+    } else if let Some(rest) = path.strip_prefix("file://") {
+        ("/", rest)
+    } else if path.starts_with('/') {
+        return Some(resolve_path("/", path));
+    } else {
+        return None;
+    };
+
+    let rest = percent_decode_str(rest).decode_utf8().ok()?;
+    // Relative URLs are resolved against the directory, not the root of the sandbox.
+    Some(resolve_path(base, rest.trim_start_matches('/')))
+}
+
+/// The URL of a sandbox path.
+pub fn url_from_path(path: &str) -> String {
+    for (dir, scheme) in [
+        (SpecialDirectory::ApplicationStorage, "app-storage:"),
+        (SpecialDirectory::Application, "app:"),
+    ] {
+        if SpecialDirectory::containing(path) == Some(dir) {
+            let rest = &path[dir.path().len()..];
+            let rest = if rest.is_empty() { "/" } else { rest };
+            return format!("{scheme}{}", utf8_percent_encode(rest, URL_PATH));
+        }
+    }
+    format!("file://{}", utf8_percent_encode(path, URL_PATH))
+}
+
+/// The parent of a sandbox path, or `None` for the root.
+pub fn parent_path(path: &str) -> Option<&str> {
+    if path == "/" {
+        return None;
+    }
+    match path.rfind('/') {
+        Some(0) => Some("/"),
+        Some(index) => Some(&path[..index]),
+        None => None,
+    }
+}
+
+/// The last component of a sandbox path, or an empty string for the root.
+pub fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or_default()
+}
+
+/// Joins a sandbox directory path and the name of one of its entries.
+pub fn join_path(directory: &str, name: &str) -> String {
+    if directory == "/" {
+        format!("/{name}")
+    } else {
+        format!("{directory}/{name}")
+    }
+}
+
+enum MemoryEntry {
+    Directory,
+    File(Vec<u8>),
+}
+
+struct MemoryNode {
+    entry: MemoryEntry,
+    creation_time: DateTime<Utc>,
+    modification_time: DateTime<Utc>,
+}
+
+impl MemoryNode {
+    fn new(entry: MemoryEntry) -> Self {
+        let now = Utc::now();
+        Self {
+            entry,
+            creation_time: now,
+            modification_time: now,
+        }
+    }
+}
+
+/// A filesystem that only lives as long as the player.
+pub struct MemoryFileSystemBackend {
+    nodes: BTreeMap<String, MemoryNode>,
+}
+
+impl MemoryFileSystemBackend {
+    pub fn new() -> Self {
+        let mut nodes = BTreeMap::new();
+        nodes.insert("/".to_string(), MemoryNode::new(MemoryEntry::Directory));
+        for dir in SpecialDirectory::ALL {
+            nodes.insert(
+                dir.path().to_string(),
+                MemoryNode::new(MemoryEntry::Directory),
+            );
+        }
+        Self { nodes }
+    }
+
+    fn is_directory(&self, path: &str) -> bool {
+        matches!(
+            self.nodes.get(path),
+            Some(MemoryNode {
+                entry: MemoryEntry::Directory,
+                ..
+            })
+        )
+    }
+
+    /// The paths of all descendants of a directory.
+    fn descendants(&self, path: &str) -> Vec<String> {
+        let prefix = join_path(path, "");
+        self.nodes
+            .range(prefix.clone()..)
+            .take_while(|(child, _)| child.starts_with(&prefix))
+            .map(|(child, _)| child.clone())
+            .collect()
+    }
+
+    fn check_parent(&self, path: &str) -> Result<(), FileSystemError> {
+        let parent = parent_path(path).ok_or(FileSystemError::AccessDenied)?;
+        match self.nodes.get(parent) {
+            Some(MemoryNode {
+                entry: MemoryEntry::Directory,
+                ..
+            }) => Ok(()),
+            Some(_) => Err(FileSystemError::NotADirectory),
+            None => Err(FileSystemError::NotFound),
+        }
+    }
+}
+
+impl Default for MemoryFileSystemBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystemBackend for MemoryFileSystemBackend {
+    fn metadata(&self, path: &str) -> Result<FileMetadata, FileSystemError> {
+        let node = self.nodes.get(path).ok_or(FileSystemError::NotFound)?;
+        let (is_directory, size) = match &node.entry {
+            MemoryEntry::Directory => (true, 0),
+            MemoryEntry::File(data) => (false, data.len() as u64),
+        };
+        Ok(FileMetadata {
+            is_directory,
+            size,
+            creation_time: Some(node.creation_time),
+            modification_time: Some(node.modification_time),
+        })
+    }
+
+    fn read(&self, path: &str) -> Result<Vec<u8>, FileSystemError> {
+        match self.nodes.get(path) {
+            Some(MemoryNode {
+                entry: MemoryEntry::File(data),
+                ..
+            }) => Ok(data.clone()),
+            Some(_) => Err(FileSystemError::NotAFile),
+            None => Err(FileSystemError::NotFound),
+        }
+    }
+
+    fn write(&mut self, path: &str, data: &[u8]) -> Result<(), FileSystemError> {
+        self.check_parent(path)?;
+        match self.nodes.get_mut(path) {
+            Some(MemoryNode {
+                entry: MemoryEntry::Directory,
+                ..
+            }) => Err(FileSystemError::NotAFile),
+            Some(node) => {
+                node.entry = MemoryEntry::File(data.to_vec());
+                node.modification_time = Utc::now();
+                Ok(())
+            }
+            None => {
+                self.nodes.insert(
+                    path.to_string(),
+                    MemoryNode::new(MemoryEntry::File(data.to_vec())),
+                );
+                Ok(())
+            }
+        }
+    }
+
+    fn create_directory(&mut self, path: &str) -> Result<(), FileSystemError> {
+        match self.nodes.get(path) {
+            Some(MemoryNode {
+                entry: MemoryEntry::Directory,
+                ..
+            }) => return Ok(()),
+            Some(_) => return Err(FileSystemError::AlreadyExists),
+            None => {}
+        }
+        if let Some(parent) = parent_path(path) {
+            self.create_directory(parent)?;
+        }
+        self.nodes
+            .insert(path.to_string(), MemoryNode::new(MemoryEntry::Directory));
+        Ok(())
+    }
+
+    fn read_directory(&self, path: &str) -> Result<Vec<String>, FileSystemError> {
+        if !self.is_directory(path) {
+            return Err(if self.nodes.contains_key(path) {
+                FileSystemError::NotADirectory
+            } else {
+                FileSystemError::NotFound
+            });
+        }
+        Ok(self
+            .descendants(path)
+            .iter()
+            .filter(|child| parent_path(child) == Some(path))
+            .map(|child| file_name(child).to_string())
+            .collect())
+    }
+
+    fn remove(&mut self, path: &str, recursive: bool) -> Result<(), FileSystemError> {
+        if !self.nodes.contains_key(path) {
+            return Err(FileSystemError::NotFound);
+        }
+        if self.is_directory(path) {
+            let descendants = self.descendants(path);
+            if !descendants.is_empty() && !recursive {
+                return Err(FileSystemError::DirectoryNotEmpty);
+            }
+            for descendant in descendants {
+                self.nodes.remove(&descendant);
+            }
+        }
+        self.nodes.remove(path);
+        Ok(())
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> Result<(), FileSystemError> {
+        if !self.nodes.contains_key(from) {
+            return Err(FileSystemError::NotFound);
+        }
+        if self.nodes.contains_key(to) {
+            return Err(FileSystemError::AlreadyExists);
+        }
+        if to.starts_with(&join_path(from, "")) {
+            return Err(FileSystemError::AccessDenied);
+        }
+        self.check_parent(to)?;
+
+        let mut moved = self.descendants(from);
+        moved.push(from.to_string());
+        for old_path in moved {
+            if let Some(node) = self.nodes.remove(&old_path) {
+                let new_path = format!("{to}{}", &old_path[from.len()..]);
+                self.nodes.insert(new_path, node);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special_directories_exist() {
+        let backend = MemoryFileSystemBackend::new();
+        for dir in SpecialDirectory::ALL {
+            assert!(backend.metadata(dir.path()).unwrap().is_directory);
+        }
+        assert_eq!(
+            SpecialDirectory::containing("/app-storage/a/b"),
+            Some(SpecialDirectory::ApplicationStorage)
+        );
+        assert_eq!(
+            SpecialDirectory::containing("/app"),
+            Some(SpecialDirectory::Application)
+        );
+        assert_eq!(SpecialDirectory::containing("/apple"), None);
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(resolve_path("/user", "a/./b/../c"), "/user/a/c");
+        assert_eq!(resolve_path("/user", "..\\..\\.."), "/");
+        assert_eq!(resolve_path("/user/a", "/documents/"), "/documents");
+        assert_eq!(parent_path("/user/a"), Some("/user"));
+        assert_eq!(parent_path("/user"), Some("/"));
+        assert_eq!(parent_path("/"), None);
+        assert_eq!(file_name("/user/a.txt"), "a.txt");
+    }
+
+    #[test]
+    fn urls() {
+        assert_eq!(
+            path_from_native_path_or_url("app-storage:/prefs/my%20file.xml").as_deref(),
+            Some("/app-storage/prefs/my file.xml")
+        );
+        assert_eq!(
+            path_from_native_path_or_url("app:/../../user").as_deref(),
+            Some("/user")
+        );
+        assert_eq!(
+            path_from_native_path_or_url("file:///documents/a").as_deref(),
+            Some("/documents/a")
+        );
+        assert_eq!(path_from_native_path_or_url("relative/path"), None);
+        assert_eq!(
+            url_from_path("/app-storage/prefs/my file.xml"),
+            "app-storage:/prefs/my%20file.xml"
+        );
+        assert_eq!(url_from_path("/app"), "app:/");
+        assert_eq!(url_from_path("/documents/a#b"), "file:///documents/a%23b");
+    }
+
+    #[test]
+    fn write_and_read() {
+        let mut backend = MemoryFileSystemBackend::new();
+        assert_eq!(
+            backend.write("/user/missing/file.txt", b"data"),
+            Err(FileSystemError::NotFound)
+        );
+        backend.create_directory("/user/a/b").unwrap();
+        backend.write("/user/a/b/file.txt", b"data").unwrap();
+        assert_eq!(backend.read("/user/a/b/file.txt").unwrap(), b"data");
+        assert_eq!(backend.metadata("/user/a/b/file.txt").unwrap().size, 4);
+        assert_eq!(backend.read("/user/a"), Err(FileSystemError::NotAFile));
+        assert_eq!(backend.read_directory("/user/a").unwrap(), vec!["b"]);
+    }
+
+    #[test]
+    fn remove_and_rename() {
+        let mut backend = MemoryFileSystemBackend::new();
+        backend.create_directory("/documents/a").unwrap();
+        backend.write("/documents/a/file", b"data").unwrap();
+        assert_eq!(
+            backend.remove("/documents/a", false),
+            Err(FileSystemError::DirectoryNotEmpty)
+        );
+
+        backend.copy("/documents/a", "/user/a").unwrap();
+        assert_eq!(backend.read("/user/a/file").unwrap(), b"data");
+
+        backend.rename("/documents/a", "/documents/b").unwrap();
+        assert!(!backend.exists("/documents/a/file"));
+        assert_eq!(backend.read("/documents/b/file").unwrap(), b"data");
+        assert_eq!(
+            backend.rename("/documents/b", "/documents/b/c"),
+            Err(FileSystemError::AccessDenied)
+        );
+
+        backend.remove("/documents/b", true).unwrap();
+        assert!(backend.read_directory("/documents").unwrap().is_empty());
+    }
+}
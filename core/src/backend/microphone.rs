@@ -0,0 +1,232 @@
+use std::f32::consts::TAU;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MicrophoneError {
+    #[error("No microphone with index {0}")]
+    NoDevice(usize),
+
+    #[error("Couldn't start capturing audio: {0}")]
+    Capture(String),
+}
+
+/// Whether the user lets movies capture audio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MicrophonePermission {
+    /// The user wasn't asked yet, or hasn't answered.
+    Undecided,
+    Allowed,
+    Denied,
+}
+
+/// A backend giving access to the audio capture devices of the system.
+///
+/// Devices are referred to by their index in the list returned by
+/// [`MicrophoneBackend::names`]. Captured audio is always mono, made of
+/// samples in the range `-1.0..=1.0`, at the sample rate requested when
+/// capturing was started.
+pub trait MicrophoneBackend {
+    /// The names of the available capture devices.
+    fn names(&self) -> Vec<String>;
+
+    /// The index of the device that should be used when a movie doesn't ask
+    /// for a specific one.
+    fn default_device(&self) -> Option<usize> {
+        (!self.names().is_empty()).then_some(0)
+    }
+
+    /// Whether the user allows movies to capture audio.
+    ///
+    /// Devices stay muted, and don't capture anything, until this is
+    /// [`MicrophonePermission::Allowed`].
+    fn permission(&self) -> MicrophonePermission {
+        MicrophonePermission::Allowed
+    }
+
+    /// Asks the user whether movies may capture audio.
+    ///
+    /// This must not block; the answer is reported by later calls to
+    /// [`MicrophoneBackend::permission`].
+    fn request_permission(&mut self) {}
+
+    /// Starts capturing audio from the given device at `sample_rate` Hz.
+    ///
+    /// If the device is already capturing, it is restarted at the new rate.
+    fn start_capture(&mut self, index: usize, sample_rate: u32) -> Result<(), MicrophoneError>;
+
+    /// Stops capturing audio from the given device, discarding any samples
+    /// that were not read yet.
+    fn stop_capture(&mut self, index: usize);
+
+    /// Moves every sample captured since the last call into `samples`.
+    fn read_samples(&mut self, index: usize, samples: &mut Vec<f32>);
+
+    /// Plays the audio captured by the given device back through the speakers
+    /// with the given volume, or stops doing so when `volume` is `None`.
+    fn set_loop_back(&mut self, _index: usize, _volume: Option<f32>) {}
+}
+
+/// Microphone backend for platforms without any capture device.
+pub struct NullMicrophoneBackend;
+
+impl NullMicrophoneBackend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MicrophoneBackend for NullMicrophoneBackend {
+    fn names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn start_capture(&mut self, index: usize, _sample_rate: u32) -> Result<(), MicrophoneError> {
+        Err(MicrophoneError::NoDevice(index))
+    }
+
+    fn stop_capture(&mut self, _index: usize) {}
+
+    fn read_samples(&mut self, _index: usize, _samples: &mut Vec<f32>) {}
+}
+
+impl Default for NullMicrophoneBackend {
+    fn default() -> Self {
+        NullMicrophoneBackend::new()
+    }
+}
+
+/// Microphone backend with a single device producing predictable audio.
+///
+/// Every read yields a fixed amount of audio (1/20th of a second), so that
+/// movies see the same samples on every run regardless of timing. By default
+/// the device produces a 440 Hz sine wave, but it can also play back
+/// arbitrary samples, such as audio decoded from a file.
+///
+/// Capturing is allowed from the start, unless the device is told to ask for
+/// permission first with [`SyntheticMicrophoneBackend::asking`].
+pub struct SyntheticMicrophoneBackend {
+    name: String,
+    permission: MicrophonePermission,
+    answer: Option<MicrophonePermission>,
+    source: SyntheticSource,
+    sample_rate: Option<u32>,
+    position: usize,
+    loop_back: Option<f32>,
+}
+
+enum SyntheticSource {
+    Tone { frequency: f32, amplitude: f32 },
+    Samples(Vec<f32>),
+}
+
+impl SyntheticMicrophoneBackend {
+    /// Creates a device producing a sine wave.
+    pub fn new() -> Self {
+        Self::with_source(SyntheticSource::Tone {
+            frequency: 440.0,
+            amplitude: 0.5,
+        })
+    }
+
+    /// Creates a device producing the given samples, looping once they run out.
+    ///
+    /// The samples are used as is, whatever sample rate the movie asks for.
+    pub fn with_samples(samples: Vec<f32>) -> Self {
+        Self::with_source(SyntheticSource::Samples(samples))
+    }
+
+    fn with_source(source: SyntheticSource) -> Self {
+        Self {
+            name: "Synthetic Microphone".to_string(),
+            permission: MicrophonePermission::Allowed,
+            answer: None,
+            source,
+            sample_rate: None,
+            position: 0,
+            loop_back: None,
+        }
+    }
+
+    /// Makes the device wait for the movie to ask for permission before
+    /// capturing, and then answer with `allow`.
+    pub fn asking(mut self, allow: bool) -> Self {
+        self.permission = MicrophonePermission::Undecided;
+        self.answer = Some(if allow {
+            MicrophonePermission::Allowed
+        } else {
+            MicrophonePermission::Denied
+        });
+        self
+    }
+
+    /// The loop back volume requested by the player, if any.
+    pub fn loop_back(&self) -> Option<f32> {
+        self.loop_back
+    }
+}
+
+impl MicrophoneBackend for SyntheticMicrophoneBackend {
+    fn names(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+
+    fn permission(&self) -> MicrophonePermission {
+        self.permission
+    }
+
+    fn request_permission(&mut self) {
+        if let Some(answer) = self.answer.take() {
+            self.permission = answer;
+        }
+    }
+
+    fn start_capture(&mut self, index: usize, sample_rate: u32) -> Result<(), MicrophoneError> {
+        if index != 0 {
+            return Err(MicrophoneError::NoDevice(index));
+        }
+
+        self.sample_rate = Some(sample_rate);
+        self.position = 0;
+        Ok(())
+    }
+
+    fn stop_capture(&mut self, _index: usize) {
+        self.sample_rate = None;
+    }
+
+    fn read_samples(&mut self, index: usize, samples: &mut Vec<f32>) {
+        let Some(sample_rate) = self.sample_rate.filter(|_| index == 0) else {
+            return;
+        };
+
+        let count = sample_rate as usize / 20;
+        match &self.source {
+            SyntheticSource::Tone {
+                frequency,
+                amplitude,
+            } => {
+                let step = frequency / sample_rate as f32;
+                samples.extend((self.position..self.position + count).map(|i| {
+                    let phase = (i as f32 * step).fract();
+                    amplitude * (phase * TAU).sin()
+                }));
+            }
+            SyntheticSource::Samples(source) if !source.is_empty() => {
+                samples.extend(
+                    (self.position..self.position + count).map(|i| source[i % source.len()]),
+                );
+            }
+            SyntheticSource::Samples(_) => samples.extend(std::iter::repeat_n(0.0, count)),
+        }
+        self.position += count;
+    }
+
+    fn set_loop_back(&mut self, _index: usize, volume: Option<f32>) {
+        self.loop_back = volume;
+    }
+}
+
+impl Default for SyntheticMicrophoneBackend {
+    fn default() -> Self {
+        SyntheticMicrophoneBackend::new()
+    }
+}
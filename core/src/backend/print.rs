@@ -0,0 +1,118 @@
+/// How the pages of a print job are turned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PrintOrientation {
+    #[default]
+    Portrait,
+    Landscape,
+}
+
+impl PrintOrientation {
+    /// The name of the orientation, as used by `PrintJobOrientation`.
+    pub fn name(self) -> &'static str {
+        match self {
+            PrintOrientation::Portrait => "portrait",
+            PrintOrientation::Landscape => "landscape",
+        }
+    }
+}
+
+/// What a print job prints on, as chosen when it's started.
+///
+/// Every size is in points (1/72 of an inch), which is also the size of a pixel of a movie
+/// when it's printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrintJobSettings {
+    pub paper_width: u32,
+    pub paper_height: u32,
+
+    /// The size of the area of the paper which can be printed on.
+    pub page_width: u32,
+    pub page_height: u32,
+
+    pub orientation: PrintOrientation,
+}
+
+impl PrintJobSettings {
+    /// A US Letter sheet, without margins.
+    pub const LETTER: Self = Self {
+        paper_width: 612,
+        paper_height: 792,
+        page_width: 612,
+        page_height: 792,
+        orientation: PrintOrientation::Portrait,
+    };
+
+    /// An A4 sheet, without margins.
+    pub const A4: Self = Self {
+        paper_width: 595,
+        paper_height: 842,
+        page_width: 595,
+        page_height: 842,
+        orientation: PrintOrientation::Portrait,
+    };
+}
+
+/// A page added to a print job, rendered to a bitmap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrintPage {
+    /// The size of the page, in points.
+    pub width: u32,
+    pub height: u32,
+
+    /// The size of the bitmap, which is usually larger than the page to print it sharply.
+    pub pixel_width: u32,
+    pub pixel_height: u32,
+
+    /// The opaque RGBA pixels of the page.
+    pub rgba: Vec<u8>,
+}
+
+/// A backend printing the pages of `PrintJob`s.
+///
+/// Only one print job runs at a time: it's started by [PrintBackend::start_job], then gets
+/// its pages by [PrintBackend::add_page] until it's printed by [PrintBackend::send].
+pub trait PrintBackend {
+    /// Whether movies can print at all, as reported by `PrintJob.isSupported`.
+    fn is_supported(&self) -> bool;
+
+    /// Starts a print job, returning what it prints on, or `None` if the user cancelled it.
+    ///
+    /// This may block, for example to ask the user about the job. A job which was started
+    /// before, but never sent, is dropped.
+    fn start_job(&mut self) -> Option<PrintJobSettings>;
+
+    /// Adds a page to the current print job.
+    fn add_page(&mut self, page: PrintPage);
+
+    /// Prints the pages of the current print job.
+    fn send(&mut self);
+}
+
+/// Print backend for platforms which can't print.
+pub struct NullPrintBackend;
+
+impl NullPrintBackend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl PrintBackend for NullPrintBackend {
+    fn is_supported(&self) -> bool {
+        false
+    }
+
+    fn start_job(&mut self) -> Option<PrintJobSettings> {
+        None
+    }
+
+    fn add_page(&mut self, _page: PrintPage) {}
+
+    fn send(&mut self) {}
+}
+
+impl Default for NullPrintBackend {
+    fn default() -> Self {
+        NullPrintBackend::new()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::native_process::{NativeProcessAction, NativeProcessCommand, NativeProcessHandle};
+use async_channel::{Receiver, Sender};
+
+/// What a movie asks to run with `NativeProcess.start`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NativeProcessStartupInfo {
+    /// The sandbox path of the program to run.
+    pub executable: String,
+
+    pub arguments: Vec<String>,
+
+    /// The sandbox path of the directory to run the program in, if the movie chose one.
+    pub working_directory: Option<String>,
+}
+
+/// A backend running programs of the system on behalf of AIR movies.
+pub trait ProcessBackend {
+    /// Whether programs can be run at all, as reported by `NativeProcess.isSupported`.
+    fn is_supported(&self) -> bool;
+
+    /// Runs a program, without waiting for it to start.
+    ///
+    /// Use [NativeProcessAction::StandardOutput] and [NativeProcessAction::StandardError]
+    /// to send what the program writes to AVM side, and [NativeProcessAction::Exited] once
+    /// it has exited. A program which fails to start, or isn't allowed to, is reported as
+    /// having exited without an exit code.
+    ///
+    /// When the Sender of the Receiver is dropped, the program should be killed.
+    fn start_process(
+        &mut self,
+        info: NativeProcessStartupInfo,
+        handle: NativeProcessHandle,
+        receiver: Receiver<NativeProcessCommand>,
+        sender: Sender<NativeProcessAction>,
+    );
+}
+
+/// Process backend for platforms which can't run programs.
+pub struct NullProcessBackend;
+
+impl NullProcessBackend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ProcessBackend for NullProcessBackend {
+    fn is_supported(&self) -> bool {
+        false
+    }
+
+    fn start_process(
+        &mut self,
+        _info: NativeProcessStartupInfo,
+        handle: NativeProcessHandle,
+        _receiver: Receiver<NativeProcessCommand>,
+        sender: Sender<NativeProcessAction>,
+    ) {
+        sender
+            .try_send(NativeProcessAction::Exited(handle, None))
+            .expect("working channel send");
+    }
+}
+
+impl Default for NullProcessBackend {
+    fn default() -> Self {
+        NullProcessBackend::new()
+    }
+}
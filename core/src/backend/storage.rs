@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use url::Url;
 
 pub trait StorageBackend {
     fn get(&self, name: &str) -> Option<Vec<u8>>;
@@ -37,3 +38,25 @@ impl StorageBackend for MemoryStorageBackend {
         self.map.remove(name);
     }
 }
+
+/// The host and path that shared objects of the movie at `movie_url` are stored under
+/// by default, e.g. `("foo.com", "folder/game.swf")`.
+///
+/// Local movies have no host, so their shared objects are stored under `localhost`.
+pub fn shared_object_location(movie_url: &Url) -> (&str, &str) {
+    let mut movie_path = movie_url.path();
+    // Remove leading/trailing slashes.
+    movie_path = movie_path.strip_prefix('/').unwrap_or(movie_path);
+    movie_path = movie_path.strip_suffix('/').unwrap_or(movie_path);
+
+    // AIR applications are run from `app:` URLs, which have no host either.
+    if matches!(movie_url.scheme(), "file" | "app") {
+        // Remove drive letter on Windows (TODO: move this logic into DiskStorageBackend?)
+        if let [_, b':', b'/', ..] = movie_path.as_bytes() {
+            movie_path = &movie_path[3..];
+        }
+        ("localhost", movie_path)
+    } else {
+        (movie_url.host_str().unwrap_or_default(), movie_path)
+    }
+}
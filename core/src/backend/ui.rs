@@ -93,16 +93,34 @@ pub trait UiBackend: Any {
     /// Changes the mouse cursor image.
     fn set_mouse_cursor(&mut self, cursor: MouseCursor);
 
-    /// Get the clipboard content
-    fn clipboard_content(&mut self) -> String;
+    /// Get the formats the clipboard currently has data in.
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat>;
 
-    /// Check if the clipboard is available and not empty
+    /// Get the clipboard data in the given format, if it's available in that format.
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData>;
+
+    /// Replaces the clipboard contents with the given data.
+    ///
+    /// Each entry provides the same content in a different format.
+    fn set_clipboard_data(&mut self, data: Vec<ClipboardData>);
+
+    /// Get the clipboard content as text
+    fn clipboard_content(&mut self) -> String {
+        match self.clipboard_data(ClipboardFormat::Text) {
+            Some(ClipboardData::Text(text)) => text,
+            _ => String::new(),
+        }
+    }
+
+    /// Check if the clipboard is available and has text
     fn clipboard_available(&mut self) -> bool {
         !self.clipboard_content().is_empty()
     }
 
-    /// Sets the clipboard to the given content.
-    fn set_clipboard_content(&mut self, content: String);
+    /// Sets the clipboard to the given text.
+    fn set_clipboard_content(&mut self, content: String) {
+        self.set_clipboard_data(vec![ClipboardData::Text(content)]);
+    }
 
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), FullscreenError>;
 
@@ -157,6 +175,181 @@ pub trait UiBackend: Any {
 
     /// Mark that any previously open dialog has been closed
     fn close_file_dialog(&mut self);
+
+    /// Opens a new window for an AIR application, identified by `id` from then on.
+    /// Returns `false` if the backend can't open windows.
+    ///
+    /// The window stays hidden until it's made visible with `set_native_window_visible`.
+    fn open_native_window(&mut self, _id: NativeWindowId, _options: NativeWindowOptions) -> bool {
+        false
+    }
+
+    /// Closes a window opened with `open_native_window`, or hides the main window.
+    ///
+    /// Closing windows never quits by itself: the player asks to exit with
+    /// `PlayerNotification::ExitRequested` once the application should exit.
+    fn close_native_window(&mut self, _id: NativeWindowId) {}
+
+    /// The position and size of a window, or `None` if they aren't known.
+    fn native_window_bounds(&self, _id: NativeWindowId) -> Option<NativeWindowBounds> {
+        None
+    }
+
+    fn set_native_window_bounds(&mut self, _id: NativeWindowId, _bounds: NativeWindowBounds) {}
+
+    fn native_window_display_state(&self, _id: NativeWindowId) -> NativeWindowDisplayState {
+        NativeWindowDisplayState::Normal
+    }
+
+    fn set_native_window_display_state(
+        &mut self,
+        _id: NativeWindowId,
+        _state: NativeWindowDisplayState,
+    ) {
+    }
+
+    /// Brings a window to the front and gives it the keyboard focus.
+    fn activate_native_window(&mut self, _id: NativeWindowId) {}
+
+    fn set_native_window_visible(&mut self, _id: NativeWindowId, _visible: bool) {}
+
+    fn set_native_window_title(&mut self, _id: NativeWindowId, _title: &str) {}
+
+    fn set_native_window_always_in_front(&mut self, _id: NativeWindowId, _always_in_front: bool) {}
+
+    /// Limits the size a window can be resized to, in pixels.
+    fn set_native_window_size_limits(
+        &mut self,
+        _id: NativeWindowId,
+        _min: (u32, u32),
+        _max: (u32, u32),
+    ) {
+    }
+
+    /// Lets the user move a window with the mouse, until the button is released.
+    /// Returns `false` if this isn't supported.
+    fn start_native_window_move(&mut self, _id: NativeWindowId) -> bool {
+        false
+    }
+
+    /// Lets the user resize a window from an edge or corner with the mouse, until the
+    /// button is released. Returns `false` if this isn't supported.
+    fn start_native_window_resize(&mut self, _id: NativeWindowId, _edge: NativeWindowEdge) -> bool {
+        false
+    }
+}
+
+/// Identifies a window of an AIR application.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NativeWindowId(pub u32);
+
+impl NativeWindowId {
+    /// The window the player itself is displayed in.
+    pub const MAIN: Self = Self(0);
+}
+
+/// The position and size of the contents of a window on the screen, in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NativeWindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Equivalent to AS3 `NativeWindowDisplayState`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NativeWindowDisplayState {
+    #[default]
+    Normal,
+    Minimized,
+    Maximized,
+}
+
+/// An edge or corner a window can be resized from.
+/// Equivalent to AS3 `NativeWindowResize`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NativeWindowEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// How a window should be opened, from AS3 `NativeWindowInitOptions`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NativeWindowOptions {
+    pub title: String,
+
+    /// The initial bounds of the window, or `None` to let the system choose.
+    pub bounds: Option<NativeWindowBounds>,
+
+    /// Whether the window has the frame and controls of the operating system.
+    pub system_chrome: bool,
+
+    pub transparent: bool,
+
+    /// Whether the window is a lightweight or utility window, rather than a normal one.
+    pub utility: bool,
+
+    pub resizable: bool,
+    pub maximizable: bool,
+    pub minimizable: bool,
+
+    /// The window owning this one, which it stays in front of.
+    pub owner: Option<NativeWindowId>,
+}
+
+/// A format of data on the clipboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClipboardFormat {
+    Text,
+    Html,
+    RichText,
+    Urls,
+    Bitmap,
+}
+
+/// Data on the clipboard, in one of the formats in [`ClipboardFormat`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClipboardData {
+    Text(String),
+
+    Html(String),
+
+    /// A document in the Rich Text Format.
+    RichText(Vec<u8>),
+
+    /// A list of URLs, such as links or files copied from a file manager.
+    Urls(Vec<String>),
+
+    /// An image, as rows of unmultiplied RGBA pixels.
+    Bitmap {
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    },
+}
+
+impl ClipboardData {
+    pub fn format(&self) -> ClipboardFormat {
+        match self {
+            ClipboardData::Text(_) => ClipboardFormat::Text,
+            ClipboardData::Html(_) => ClipboardFormat::Html,
+            ClipboardData::RichText(_) => ClipboardFormat::RichText,
+            ClipboardData::Urls(_) => ClipboardFormat::Urls,
+            ClipboardData::Bitmap { .. } => ClipboardFormat::Bitmap,
+        }
+    }
+
+    /// Finds the data in the given format within a set of clipboard data.
+    pub fn find(data: &[ClipboardData], format: ClipboardFormat) -> Option<&ClipboardData> {
+        data.iter().find(|data| data.format() == format)
+    }
 }
 
 /// A mouse cursor icon displayed by the Flash Player.
@@ -198,11 +391,15 @@ impl UiBackend for NullUiBackend {
 
     fn set_mouse_cursor(&mut self, _cursor: MouseCursor) {}
 
-    fn clipboard_content(&mut self) -> String {
-        "".into()
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        Vec::new()
+    }
+
+    fn clipboard_data(&mut self, _format: ClipboardFormat) -> Option<ClipboardData> {
+        None
     }
 
-    fn set_clipboard_content(&mut self, _content: String) {}
+    fn set_clipboard_data(&mut self, _data: Vec<ClipboardData>) {}
 
     fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), FullscreenError> {
         Ok(())
@@ -11,7 +11,7 @@ use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{RenderOptions, TDisplayObject};
 use gc_arena::Mutation;
 use ruffle_render::backend::RenderBackend;
-use ruffle_render::bitmap::{PixelRegion, PixelSnapping};
+use ruffle_render::bitmap::{Bitmap, PixelRegion, PixelSnapping};
 use ruffle_render::commands::{CommandHandler, CommandList, RenderBlendMode};
 use ruffle_render::filters::Filter;
 use ruffle_render::matrix::Matrix;
@@ -1763,6 +1763,39 @@ pub fn set_pixels_from_byte_array<'gc>(
     Ok(())
 }
 
+/// Copies an RGBA bitmap into the top left corner of `target`, cropping it to the size of `target`.
+pub fn set_pixels_from_bitmap<'gc>(
+    mc: &Mutation<'gc>,
+    renderer: &mut dyn RenderBackend,
+    target: BitmapData<'gc>,
+    source: &Bitmap<'_>,
+) {
+    let mut region = PixelRegion::for_whole_size(source.width(), source.height());
+    region.clamp(target.width(), target.height());
+    let transparency = target.transparency();
+
+    let target = if region.width() == target.width() && region.height() == target.height() {
+        target.overwrite_cpu_pixels_from_gpu(mc).0
+    } else {
+        target.sync(renderer)
+    };
+    let mut write = target.borrow_mut(mc);
+
+    if region.width() > 0 && region.height() > 0 {
+        let stride = source.width() as usize * 4;
+        for y in region.y_min..region.y_max {
+            let row = &source.data()[y as usize * stride..];
+            for x in region.x_min..region.x_max {
+                let rgba = &row[x as usize * 4..x as usize * 4 + 4];
+                let color = Color::rgba(rgba[0], rgba[1], rgba[2], rgba[3]);
+                write.set_pixel32_raw(x, y, color.to_premultiplied_alpha(transparency));
+            }
+        }
+
+        write.set_cpu_dirty(mc, region)
+    }
+}
+
 #[expect(clippy::too_many_arguments)]
 pub fn pixel_dissolve<'gc>(
     mc: &Mutation<'gc>,
@@ -0,0 +1,494 @@
+//! Cameras, shared by the AVM1 and AVM2 `Camera` classes.
+//!
+//! A movie gets one `Camera` object per capture device. A device only captures while its camera
+//! is attached to a `Video` or a `NetStream`; `Video`s attached to a camera display its latest
+//! frame.
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier, ExecutionReason, Object as Avm1Object,
+};
+use crate::avm2::object::{EventObject, Object as Avm2Object};
+use crate::avm2::{Activation as Avm2Activation, Avm2};
+use crate::backend::camera::{CameraBackend, CameraMode};
+use crate::context::UpdateContext;
+use crate::display_object::{TDisplayObject, VideoWeak};
+use crate::streams::NetStream;
+use gc_arena::barrier::unlock;
+use gc_arena::lock::{Lock, RefLock};
+use gc_arena::{Collect, Gc, Mutation};
+use ruffle_common::duration::FloatDuration;
+use ruffle_macros::istr;
+use ruffle_render::backend::RenderBackend;
+use ruffle_render::bitmap::{Bitmap, BitmapFormat, BitmapInfo, PixelRegion};
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+/// How much the brightness of a pixel has to change between two frames for the pixel to be
+/// considered in motion.
+const MOTION_THRESHOLD: i32 = 16;
+
+/// The settings of a camera, as exposed to ActionScript.
+#[derive(Clone, Debug)]
+pub struct CameraSettings {
+    /// The capture mode requested by the movie.
+    pub mode: CameraMode,
+
+    /// The amount of motion, from 0 to 100, above which the camera is considered active.
+    pub motion_level: i32,
+
+    /// How long, in milliseconds, the camera has to see no motion to become inactive.
+    pub motion_timeout: i32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            mode: CameraMode {
+                width: 160,
+                height: 120,
+                fps: 15.0,
+            },
+            motion_level: 50,
+            motion_timeout: 2000,
+        }
+    }
+}
+
+/// The latest frame captured by a camera.
+struct CameraFrame {
+    /// The frame as RGBA pixels.
+    pixels: Bitmap<'static>,
+
+    /// The frame, as uploaded to the renderer.
+    bitmap: Option<BitmapInfo>,
+}
+
+#[derive(Default)]
+struct CameraState {
+    settings: CameraSettings,
+
+    /// The mode the device was asked to capture in, if it is capturing.
+    requested_mode: Option<CameraMode>,
+
+    /// The mode the device is actually capturing in, if it is capturing.
+    capture_mode: Option<CameraMode>,
+
+    /// Whether the device refused to capture.
+    muted: bool,
+
+    frame: Option<CameraFrame>,
+
+    /// The amount of motion in the latest frame, or `None` before any was captured.
+    activity_level: Option<f64>,
+
+    /// Whether there has been motion recently.
+    active: bool,
+
+    /// For how long, in milliseconds, there has been no motion.
+    still_for: f64,
+
+    /// How long ago, in milliseconds, the latest frame was read.
+    since_frame: f64,
+
+    /// The time, in milliseconds, since the camera started capturing.
+    clock: f64,
+
+    /// The times at which the frames of the last second were captured.
+    frame_times: VecDeque<f64>,
+}
+
+impl CameraState {
+    /// Tracks the activity level against the motion settings, returning the new state if the
+    /// camera just became active or inactive.
+    fn observe_activity(&mut self, level: f64, dt: f64) -> Option<bool> {
+        if level >= f64::from(self.settings.motion_level) {
+            self.still_for = 0.0;
+            if !self.active {
+                self.active = true;
+                return Some(true);
+            }
+        } else {
+            self.still_for += dt;
+            let timeout = f64::from(self.settings.motion_timeout.max(0));
+            if self.active && self.still_for >= timeout {
+                self.active = false;
+                return Some(false);
+            }
+        }
+
+        None
+    }
+}
+
+/// A video capture device, as seen by a movie.
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct Camera<'gc>(Gc<'gc, CameraData<'gc>>);
+
+impl fmt::Debug for Camera<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Camera")
+            .field("index", &self.0.index)
+            .finish()
+    }
+}
+
+impl PartialEq for Camera<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(Gc::as_ptr(self.0), Gc::as_ptr(other.0))
+    }
+}
+
+impl Eq for Camera<'_> {}
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct CameraData<'gc> {
+    /// The index of the device in the list of the camera backend.
+    index: usize,
+
+    avm1_object: Lock<Option<Avm1Object<'gc>>>,
+    avm2_object: Lock<Option<Avm2Object<'gc>>>,
+
+    /// The `Video`s this camera was attached to.
+    ///
+    /// Some of them may have been attached to another source since.
+    videos: RefLock<Vec<VideoWeak<'gc>>>,
+
+    /// The `NetStream`s this camera is attached to.
+    streams: RefLock<Vec<NetStream<'gc>>>,
+
+    #[collect(require_static)]
+    state: RefCell<CameraState>,
+}
+
+impl<'gc> Camera<'gc> {
+    fn new(mc: &Mutation<'gc>, index: usize) -> Self {
+        Camera(Gc::new(
+            mc,
+            CameraData {
+                index,
+                avm1_object: Lock::new(None),
+                avm2_object: Lock::new(None),
+                videos: RefLock::new(Vec::new()),
+                streams: RefLock::new(Vec::new()),
+                state: RefCell::new(CameraState::default()),
+            },
+        ))
+    }
+
+    pub fn index(self) -> usize {
+        self.0.index
+    }
+
+    pub fn avm1_object(self) -> Option<Avm1Object<'gc>> {
+        self.0.avm1_object.get()
+    }
+
+    pub fn set_avm1_object(self, mc: &Mutation<'gc>, object: Avm1Object<'gc>) {
+        unlock!(Gc::write(mc, self.0), CameraData, avm1_object).set(Some(object));
+    }
+
+    pub fn avm2_object(self) -> Option<Avm2Object<'gc>> {
+        self.0.avm2_object.get()
+    }
+
+    pub fn set_avm2_object(self, mc: &Mutation<'gc>, object: Avm2Object<'gc>) {
+        unlock!(Gc::write(mc, self.0), CameraData, avm2_object).set(Some(object));
+    }
+
+    pub fn settings(&self) -> Ref<'_, CameraSettings> {
+        Ref::map(self.0.state.borrow(), |state| &state.settings)
+    }
+
+    pub fn settings_mut(&self) -> RefMut<'_, CameraSettings> {
+        RefMut::map(self.0.state.borrow_mut(), |state| &mut state.settings)
+    }
+
+    /// The mode the device captures in, or the requested one if it isn't capturing.
+    pub fn mode(self) -> CameraMode {
+        let state = self.0.state.borrow();
+        state.capture_mode.unwrap_or(state.settings.mode)
+    }
+
+    /// The amount of motion seen by the camera, from 0 to 100, or -1 if the camera hasn't
+    /// captured anything yet.
+    pub fn activity_level(self) -> f64 {
+        self.0.state.borrow().activity_level.unwrap_or(-1.0)
+    }
+
+    /// The number of frames captured during the last second.
+    pub fn current_fps(self) -> f64 {
+        self.0.state.borrow().frame_times.len() as f64
+    }
+
+    /// Whether the device refused to capture video.
+    pub fn is_muted(self) -> bool {
+        self.0.state.borrow().muted
+    }
+
+    /// The latest captured frame, as uploaded to the renderer.
+    pub fn frame_bitmap(self) -> Option<BitmapInfo> {
+        let state = self.0.state.borrow();
+        state.frame.as_ref().and_then(|frame| frame.bitmap.clone())
+    }
+
+    /// The latest captured frame, as RGBA pixels.
+    pub fn frame_pixels(&self) -> Option<Ref<'_, Bitmap<'static>>> {
+        Ref::filter_map(self.0.state.borrow(), |state| {
+            state.frame.as_ref().map(|frame| &frame.pixels)
+        })
+        .ok()
+    }
+
+    /// Starts displaying the frames of this camera in a `Video`.
+    pub fn add_video(self, mc: &Mutation<'gc>, video: VideoWeak<'gc>) {
+        let mut videos = unlock!(Gc::write(mc, self.0), CameraData, videos).borrow_mut();
+        if !videos.iter().any(|v| v.as_ptr() == video.as_ptr()) {
+            videos.push(video);
+        }
+    }
+
+    pub fn add_stream(self, mc: &Mutation<'gc>, stream: NetStream<'gc>) {
+        let mut streams = unlock!(Gc::write(mc, self.0), CameraData, streams).borrow_mut();
+        if !streams.contains(&stream) {
+            streams.push(stream);
+        }
+    }
+
+    pub fn remove_stream(self, mc: &Mutation<'gc>, stream: NetStream<'gc>) {
+        let mut streams = unlock!(Gc::write(mc, self.0), CameraData, streams).borrow_mut();
+        streams.retain(|s| *s != stream);
+    }
+
+    /// Forgets the `Video`s that are gone or display something else, returning whether any is
+    /// left.
+    fn prune_videos(self, mc: &Mutation<'gc>) -> bool {
+        let mut videos = unlock!(Gc::write(mc, self.0), CameraData, videos).borrow_mut();
+        videos.retain(|video| {
+            video
+                .upgrade(mc)
+                .is_some_and(|video| video.camera() == Some(self))
+        });
+        !videos.is_empty()
+    }
+
+    /// Starts capturing if the camera is attached to anything, and stops it otherwise.
+    fn update_capture(self, mc: &Mutation<'gc>, backend: &mut dyn CameraBackend) {
+        let attached = self.prune_videos(mc) || !self.0.streams.borrow().is_empty();
+
+        let mut state = self.0.state.borrow_mut();
+        let wanted_mode = (attached && !state.muted).then_some(state.settings.mode);
+        if wanted_mode == state.requested_mode {
+            return;
+        }
+
+        match wanted_mode {
+            Some(mode) => match backend.start_capture(self.0.index, mode) {
+                Ok(capture_mode) => {
+                    state.requested_mode = Some(mode);
+                    state.capture_mode = Some(capture_mode);
+                    state.since_frame = f64::INFINITY;
+                    state.clock = 0.0;
+                    state.frame_times.clear();
+                }
+                Err(e) => {
+                    tracing::error!("Couldn't use camera {}: {e}", self.0.index);
+                    state.muted = true;
+                }
+            },
+            None => {
+                backend.stop_capture(self.0.index);
+                state.requested_mode = None;
+                state.capture_mode = None;
+                state.frame_times.clear();
+            }
+        }
+    }
+
+    /// Reads the next frame from the device if it is time to, returning whether there was one
+    /// along with the new activity state if it changed.
+    fn capture_frame(
+        self,
+        backend: &mut dyn CameraBackend,
+        renderer: &mut dyn RenderBackend,
+        dt: f64,
+    ) -> (bool, Option<bool>) {
+        let mut state = self.0.state.borrow_mut();
+        let Some(mode) = state.capture_mode else {
+            return (false, None);
+        };
+
+        state.clock += dt;
+        let clock = state.clock;
+        while state
+            .frame_times
+            .front()
+            .is_some_and(|time| clock - time >= 1000.0)
+        {
+            state.frame_times.pop_front();
+        }
+
+        let interval = 1000.0 / mode.fps.max(1.0);
+        state.since_frame += dt;
+        if state.since_frame < interval {
+            return (false, None);
+        }
+        state.since_frame -= interval;
+        if state.since_frame >= interval {
+            // Don't catch up on frames missed during a long tick.
+            state.since_frame = 0.0;
+        }
+
+        let Some(pixels) = backend.read_frame(self.0.index) else {
+            return (false, None);
+        };
+        let pixels = pixels.to_rgba();
+
+        let level = state
+            .frame
+            .as_ref()
+            .map_or(0.0, |previous| motion_level(&previous.pixels, &pixels));
+        state.activity_level = Some(level);
+        state.frame_times.push_back(clock);
+
+        let previous_bitmap = state.frame.take().and_then(|frame| frame.bitmap);
+        let bitmap = upload_frame(renderer, previous_bitmap, &pixels);
+        state.frame = Some(CameraFrame { pixels, bitmap });
+
+        (true, state.observe_activity(level, interval))
+    }
+}
+
+/// Uploads a frame to the renderer, reusing the texture of the previous frame when possible.
+fn upload_frame(
+    renderer: &mut dyn RenderBackend,
+    previous: Option<BitmapInfo>,
+    pixels: &Bitmap<'static>,
+) -> Option<BitmapInfo> {
+    let (width, height) = (pixels.width(), pixels.height());
+    let bitmap = Bitmap::new(width, height, BitmapFormat::Rgba, pixels.data());
+
+    if let Some(previous) = previous
+        && previous.width == width
+        && previous.height == height
+    {
+        let region = PixelRegion::for_whole_size(width, height);
+        match renderer.update_texture(&previous.handle, bitmap, region) {
+            Ok(()) => return Some(previous),
+            Err(e) => {
+                tracing::error!("Couldn't update camera frame: {e}");
+                return None;
+            }
+        }
+    }
+
+    match renderer.register_bitmap(bitmap) {
+        Ok(handle) => Some(BitmapInfo {
+            handle,
+            width,
+            height,
+        }),
+        Err(e) => {
+            tracing::error!("Couldn't register camera frame: {e}");
+            None
+        }
+    }
+}
+
+/// The percentage of pixels whose brightness changed noticeably between two frames.
+fn motion_level(previous: &Bitmap<'_>, current: &Bitmap<'_>) -> f64 {
+    if previous.width() != current.width() || previous.height() != current.height() {
+        return 100.0;
+    }
+
+    let luma = |rgba: &[u8]| {
+        (i32::from(rgba[0]) * 299 + i32::from(rgba[1]) * 587 + i32::from(rgba[2]) * 114) / 1000
+    };
+    let count = previous.data().len() / 4;
+    if count == 0 {
+        return 0.0;
+    }
+
+    let previous = previous.data().chunks_exact(4);
+    let moving = previous
+        .zip(current.data().chunks_exact(4))
+        .filter(|(a, b)| (luma(a) - luma(b)).abs() > MOTION_THRESHOLD)
+        .count();
+    (moving as f64 * 100.0 / count as f64).round()
+}
+
+/// Manages the cameras requested by the movie.
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+pub struct Cameras<'gc> {
+    devices: BTreeMap<usize, Camera<'gc>>,
+}
+
+impl<'gc> Cameras<'gc> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves the index of a device as given by a movie, where `-1` means the default device.
+    pub fn device_index(backend: &dyn CameraBackend, index: i32) -> Option<usize> {
+        if index < 0 {
+            return backend.default_device();
+        }
+
+        let index = index as usize;
+        (index < backend.names().len()).then_some(index)
+    }
+
+    /// The camera of the given device, which is always the same one.
+    pub fn get(&mut self, mc: &Mutation<'gc>, index: usize) -> Camera<'gc> {
+        *self
+            .devices
+            .entry(index)
+            .or_insert_with(|| Camera::new(mc, index))
+    }
+
+    /// Starts or stops capturing on every device depending on what they're attached to, and
+    /// reads the frames captured since the last update.
+    pub fn update_cameras(context: &mut UpdateContext<'gc>, dt: FloatDuration) {
+        let cameras: Vec<_> = context.cameras.devices.values().copied().collect();
+
+        for camera in cameras {
+            camera.update_capture(context.gc(), context.camera);
+
+            let (new_frame, activity) =
+                camera.capture_frame(context.camera, context.renderer, dt.as_millis());
+
+            if new_frame {
+                for video in camera.0.videos.borrow().iter() {
+                    if let Some(video) = video.upgrade(context.gc()) {
+                        video.invalidate_cached_bitmap();
+                    }
+                }
+                *context.needs_render = true;
+            }
+
+            let Some(activating) = activity else {
+                continue;
+            };
+
+            if let Some(object) = camera.avm2_object() {
+                let mut activation = Avm2Activation::from_nothing(context);
+                let event = EventObject::activity_event(&mut activation, activating);
+                Avm2::dispatch_event(activation.context, event, object);
+            }
+
+            if let Some(object) = camera.avm1_object() {
+                let mut activation =
+                    Avm1Activation::from_stub(context, ActivationIdentifier::root("[Camera]"));
+                let _ = object.call_method(
+                    istr!("onActivity"),
+                    &[activating.into()],
+                    &mut activation,
+                    ExecutionReason::Special,
+                );
+            }
+        }
+    }
+}
@@ -10,11 +10,17 @@ use crate::avm2::api_version::ApiVersion;
 use crate::avm2::{Avm2, LoaderInfoObject, SharedObjectObject, SoundChannelObject};
 use crate::backend::{
     audio::{AudioBackend, AudioManager, SoundHandle, SoundInstanceHandle},
+    camera::CameraBackend,
+    filesystem::FileSystemBackend,
     log::LogBackend,
+    microphone::MicrophoneBackend,
     navigator::NavigatorBackend,
+    print::PrintBackend,
+    process::ProcessBackend,
     storage::StorageBackend,
     ui::UiBackend,
 };
+use crate::camera::Cameras;
 use crate::context_menu::ContextMenuState;
 use crate::display_object::{EditText, MovieClip, SoundTransform, Stage};
 use crate::events::PlayerNotification;
@@ -25,6 +31,10 @@ use crate::input::InputManager;
 use crate::library::Library;
 use crate::loader::LoadManager;
 use crate::local_connection::LocalConnections;
+use crate::microphone::Microphones;
+use crate::native_application::NativeApplication;
+use crate::native_process::NativeProcesses;
+use crate::native_window::NativeWindows;
 use crate::net_connection::NetConnections;
 use crate::orphan_manager::OrphanManager;
 use crate::player::PostFrameCallback;
@@ -38,7 +48,9 @@ use crate::stub::StubCollection;
 use crate::system_properties::SystemProperties;
 use crate::tag_utils::{SwfMovie, SwfSlice};
 use crate::timer::Timers;
+use crate::touch::Touches;
 use crate::vminterface::Instantiator;
+use crate::worker::Workers;
 use async_channel::Sender;
 use core::fmt;
 use gc_arena::{Collect, Mutation};
@@ -116,6 +128,21 @@ pub struct UpdateContext<'gc> {
     /// The video backend, used for video decoding
     pub video: &'gc mut dyn VideoBackend,
 
+    /// The microphone backend, used to capture audio.
+    pub microphone: &'gc mut dyn MicrophoneBackend,
+
+    /// The camera backend, used to capture video.
+    pub camera: &'gc mut dyn CameraBackend,
+
+    /// The filesystem backend, used by AIR movies to access files.
+    pub filesystem: &'gc mut dyn FileSystemBackend,
+
+    /// The process backend, used by AIR movies to run programs.
+    pub process: &'gc mut dyn ProcessBackend,
+
+    /// The print backend, used to print pages of `PrintJob`s.
+    pub print: &'gc mut dyn PrintBackend,
+
     /// The RNG, used by the AVM `RandomNumber` opcode, `Math.random(),` and `random()`.
     pub rng: &'gc mut AvmRng,
 
@@ -219,6 +246,27 @@ pub struct UpdateContext<'gc> {
 
     pub local_connections: &'gc mut LocalConnections<'gc>,
 
+    /// The worker this player runs as, and the other workers it knows of.
+    pub workers: &'gc mut Workers<'gc>,
+
+    /// Microphones requested by the movie.
+    pub microphones: &'gc mut Microphones<'gc>,
+
+    /// Cameras requested by the movie.
+    pub cameras: &'gc mut Cameras<'gc>,
+
+    /// The touch points on the screen.
+    pub touches: &'gc mut Touches<'gc>,
+
+    /// The windows opened by the movie.
+    pub native_windows: &'gc mut NativeWindows<'gc>,
+
+    /// The application of an AIR movie.
+    pub native_application: &'gc mut NativeApplication<'gc>,
+
+    /// The programs run by an AIR movie.
+    pub native_processes: &'gc mut NativeProcesses<'gc>,
+
     pub orphan_manager: &'gc mut OrphanManager<'gc>,
 
     /// Dynamic root for allowing handles to GC objects to exist outside of the GC.
@@ -433,6 +481,7 @@ impl<'gc> UpdateContext<'gc> {
 
         // Clean up the stage before loading another root movie.
         self.sockets.close_all();
+        self.native_processes.exit_all();
         self.timers.remove_all();
 
         self.set_root_movie(movie);
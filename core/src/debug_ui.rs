@@ -1,4 +1,5 @@
 mod avm1;
+mod avm1_debugger;
 mod avm2;
 mod common;
 mod display_object;
@@ -8,6 +9,7 @@ mod movie;
 
 use crate::context::{RenderContext, UpdateContext};
 use crate::debug_ui::avm1::Avm1ObjectWindow;
+use crate::debug_ui::avm1_debugger::Avm1DebuggerWindow;
 use crate::debug_ui::avm2::Avm2ObjectWindow;
 use crate::debug_ui::display_object::{DisplayObjectSearchWindow, DisplayObjectWindow};
 use crate::debug_ui::domain::DomainListWindow;
@@ -37,6 +39,7 @@ pub struct DebugUi {
     movie_list: Option<MovieListWindow>,
     domain_list: Option<DomainListWindow>,
     display_object_search: Option<DisplayObjectSearchWindow>,
+    avm1_debugger: Option<Avm1DebuggerWindow>,
 }
 
 #[derive(Debug)]
@@ -53,6 +56,7 @@ pub enum Message {
     SaveFile(ItemToSave),
     SearchForDisplayObject,
     TrackRootMovieClip,
+    ShowAvm1Debugger,
 }
 
 impl DebugUi {
@@ -100,6 +104,12 @@ impl DebugUi {
             self.display_object_search = Some(search);
         }
 
+        if let Some(mut debugger) = self.avm1_debugger.take()
+            && debugger.show(egui_ctx, context)
+        {
+            self.avm1_debugger = Some(debugger);
+        }
+
         for message in messages {
             match message {
                 Message::TrackDisplayObject(object) => {
@@ -136,6 +146,9 @@ impl DebugUi {
                 Message::SearchForDisplayObject => {
                     self.display_object_search = Some(Default::default());
                 }
+                Message::ShowAvm1Debugger => {
+                    self.avm1_debugger.get_or_insert_with(Default::default);
+                }
                 Message::TrackRootMovieClip => {
                     // Convenience action to quickly access the root movie clip
                     if let Some(obj @ DisplayObject::MovieClip(_)) = context.stage.root_clip() {
@@ -151,6 +164,10 @@ impl DebugUi {
 
     pub fn should_suspend_player(&self) -> bool {
         self.display_object_search.is_some()
+            || self
+                .avm1_debugger
+                .as_ref()
+                .is_some_and(|debugger| debugger.is_suspended())
     }
 
     pub fn items_to_save(&mut self) -> Vec<ItemToSave> {
@@ -0,0 +1,360 @@
+use crate::avm1::debugger::{Break, BreakpointLocation, Debugger, Snapshot};
+use crate::context::UpdateContext;
+use egui::{CollapsingHeader, ComboBox, Grid, TextEdit, Ui, Window};
+
+#[derive(Debug, Eq, PartialEq, Hash, Default, Copy, Clone)]
+enum Panel {
+    #[default]
+    CallStack,
+    Operands,
+    Registers,
+    ConstantPool,
+    ScopeChain,
+    Watches,
+}
+
+#[derive(Debug, Eq, PartialEq, Default, Copy, Clone)]
+enum BreakpointKind {
+    #[default]
+    FrameScript,
+    Action,
+}
+
+#[derive(Debug, Default)]
+pub struct Avm1DebuggerWindow {
+    open_panel: Panel,
+    new_breakpoint_kind: BreakpointKind,
+    new_breakpoint_clip: String,
+    new_breakpoint_frame: String,
+    new_breakpoint_movie: String,
+    new_breakpoint_offset: String,
+    new_watch: String,
+
+    /// The recorded action being looked at, if execution is suspended.
+    position: Option<usize>,
+}
+
+impl Avm1DebuggerWindow {
+    /// Whether the player should stay suspended while the user looks at a break.
+    pub fn is_suspended(&self) -> bool {
+        self.position.is_some()
+    }
+
+    pub fn show(&mut self, egui_ctx: &egui::Context, context: &mut UpdateContext) -> bool {
+        let mut keep_open = true;
+
+        // The debugger may already be attached for a client, in which case
+        // the window shares it.
+        if !context.avm1.debugger_attached() {
+            context.avm1.set_debugger(Debugger::default());
+        }
+        let Some(debugger) = context.avm1.debugger_mut() else {
+            return false;
+        };
+        debugger.open_window();
+
+        if self.position.is_none() && debugger.current_break().is_some() {
+            self.position = Some(0);
+        }
+
+        Window::new("AVM1 Debugger")
+            .open(&mut keep_open)
+            .scroll([true, true])
+            .show(egui_ctx, |ui| {
+                self.show_controls(ui, debugger);
+                ui.separator();
+
+                CollapsingHeader::new("Breakpoints")
+                    .default_open(true)
+                    .show(ui, |ui| self.show_breakpoints(ui, debugger));
+
+                CollapsingHeader::new("Watch Expressions")
+                    .default_open(true)
+                    .show(ui, |ui| self.show_watches(ui, debugger));
+
+                if let (Some(position), Some(current_break)) =
+                    (self.position, debugger.current_break())
+                {
+                    ui.separator();
+                    self.show_break(ui, current_break, position);
+                }
+            });
+
+        if !keep_open {
+            // Nobody is left to step through a break, so forget about it.
+            debugger.close_window();
+            if !debugger.is_attached() {
+                context.avm1.take_debugger();
+            }
+            self.position = None;
+        }
+
+        keep_open
+    }
+
+    fn show_controls(&mut self, ui: &mut Ui, debugger: &mut Debugger) {
+        ui.horizontal(|ui| {
+            let (Some(position), Some(current_break)) = (self.position, debugger.current_break())
+            else {
+                if debugger.is_break_requested() {
+                    ui.add_enabled(false, egui::Button::new("Pausing..."));
+                } else if ui.button("Pause").clicked() {
+                    debugger.request_break();
+                }
+                return;
+            };
+
+            if ui.button("Continue").clicked() {
+                debugger.resume();
+                self.position = None;
+                return;
+            }
+
+            if ui
+                .add_enabled(position > 0, egui::Button::new("Step Back"))
+                .clicked()
+            {
+                self.position = Some(position - 1);
+            }
+
+            let steps = [
+                ("Step In", current_break.step_in(position)),
+                ("Step Over", current_break.step_over(position)),
+                ("Step Out", current_break.step_out(position)),
+            ];
+            for (label, target) in steps {
+                if ui
+                    .add_enabled(target.is_some(), egui::Button::new(label))
+                    .clicked()
+                {
+                    self.position = target;
+                }
+            }
+        });
+    }
+
+    fn show_breakpoints(&mut self, ui: &mut Ui, debugger: &mut Debugger) {
+        let mut to_remove = None;
+        Grid::new(ui.id().with("breakpoints"))
+            .num_columns(3)
+            .show(ui, |ui| {
+                for breakpoint in debugger.breakpoints() {
+                    ui.label(breakpoint.id().to_string());
+                    ui.label(breakpoint.location().to_string());
+                    if ui.button("Remove").clicked() {
+                        to_remove = Some(breakpoint.id());
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(id) = to_remove {
+            debugger.remove_breakpoint(id);
+        }
+
+        ui.horizontal(|ui| {
+            ComboBox::from_id_salt(ui.id().with("breakpoint_kind"))
+                .selected_text(match self.new_breakpoint_kind {
+                    BreakpointKind::FrameScript => "Frame Script",
+                    BreakpointKind::Action => "Action",
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(
+                        &mut self.new_breakpoint_kind,
+                        BreakpointKind::FrameScript,
+                        "Frame Script",
+                    );
+                    ui.selectable_value(
+                        &mut self.new_breakpoint_kind,
+                        BreakpointKind::Action,
+                        "Action",
+                    );
+                });
+
+            let location = match self.new_breakpoint_kind {
+                BreakpointKind::FrameScript => {
+                    TextEdit::singleline(&mut self.new_breakpoint_clip)
+                        .hint_text("_level0.clip")
+                        .show(ui);
+                    TextEdit::singleline(&mut self.new_breakpoint_frame)
+                        .hint_text("Any frame")
+                        .desired_width(60.0)
+                        .show(ui);
+
+                    let clip = self.new_breakpoint_clip.trim();
+                    let frame = self.new_breakpoint_frame.trim();
+                    match frame.parse() {
+                        _ if clip.is_empty() => None,
+                        Ok(frame) => Some(BreakpointLocation::FrameScript {
+                            clip: clip.to_string(),
+                            frame: Some(frame),
+                        }),
+                        Err(_) if frame.is_empty() => Some(BreakpointLocation::FrameScript {
+                            clip: clip.to_string(),
+                            frame: None,
+                        }),
+                        Err(_) => None,
+                    }
+                }
+                BreakpointKind::Action => {
+                    TextEdit::singleline(&mut self.new_breakpoint_movie)
+                        .hint_text("Any movie")
+                        .show(ui);
+                    TextEdit::singleline(&mut self.new_breakpoint_offset)
+                        .hint_text("Offset")
+                        .desired_width(80.0)
+                        .show(ui);
+
+                    let movie = match self.new_breakpoint_movie.trim() {
+                        "" => None,
+                        url => Some(url.to_string()),
+                    };
+                    parse_offset(&self.new_breakpoint_offset)
+                        .map(|offset| BreakpointLocation::Action { movie, offset })
+                }
+            };
+
+            if ui
+                .add_enabled(location.is_some(), egui::Button::new("Add"))
+                .clicked()
+                && let Some(location) = location
+            {
+                debugger.add_breakpoint(location);
+                self.new_breakpoint_clip.clear();
+                self.new_breakpoint_frame.clear();
+                self.new_breakpoint_offset.clear();
+            }
+        });
+    }
+
+    fn show_watches(&mut self, ui: &mut Ui, debugger: &mut Debugger) {
+        let mut to_remove = None;
+        Grid::new(ui.id().with("watches"))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (index, expression) in debugger.watches().iter().enumerate() {
+                    ui.label(expression);
+                    if ui.button("Remove").clicked() {
+                        to_remove = Some(index);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(index) = to_remove {
+            debugger.remove_watch(index);
+        }
+
+        ui.horizontal(|ui| {
+            TextEdit::singleline(&mut self.new_watch)
+                .hint_text("_root.score")
+                .show(ui);
+            let expression = self.new_watch.trim();
+            if ui
+                .add_enabled(!expression.is_empty(), egui::Button::new("Add"))
+                .clicked()
+            {
+                debugger.add_watch(expression.to_string());
+                self.new_watch.clear();
+            }
+        });
+        ui.weak("Watch expressions are evaluated before every recorded action.");
+    }
+
+    fn show_break(&mut self, ui: &mut Ui, current_break: &Break, position: usize) {
+        ui.heading(&current_break.reason);
+        ui.label(format!(
+            "Action {} of {}",
+            position + 1,
+            current_break.snapshots.len()
+        ));
+        if current_break.truncated {
+            ui.weak("Recording stopped early, as the script ran too many actions.");
+        }
+
+        let Some(snapshot) = current_break.snapshots.get(position) else {
+            return;
+        };
+
+        Grid::new(ui.id().with("snapshot"))
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Activation");
+                ui.label(&snapshot.activation);
+                ui.end_row();
+
+                ui.label("Location");
+                ui.label(format!("{} @ {:#x}", snapshot.movie, snapshot.offset));
+                ui.end_row();
+
+                ui.label("Action");
+                ui.monospace(&snapshot.action);
+                ui.end_row();
+            });
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.open_panel, Panel::CallStack, "Call Stack");
+            ui.selectable_value(&mut self.open_panel, Panel::Operands, "Stack");
+            ui.selectable_value(&mut self.open_panel, Panel::Registers, "Registers");
+            ui.selectable_value(&mut self.open_panel, Panel::ConstantPool, "Constant Pool");
+            ui.selectable_value(&mut self.open_panel, Panel::ScopeChain, "Scope Chain");
+            ui.selectable_value(&mut self.open_panel, Panel::Watches, "Watches");
+        });
+        ui.separator();
+
+        match self.open_panel {
+            Panel::CallStack => show_list(ui, "call_stack", &snapshot.call_stack),
+            Panel::Operands => show_list(ui, "operands", &snapshot.operands),
+            Panel::Registers => show_list(ui, "registers", &snapshot.registers),
+            Panel::ConstantPool => show_list(ui, "constant_pool", &snapshot.constant_pool),
+            Panel::ScopeChain => show_scope_chain(ui, snapshot),
+            Panel::Watches => show_pairs(ui, "watches", &snapshot.watches),
+        }
+    }
+}
+
+fn show_scope_chain(ui: &mut Ui, snapshot: &Snapshot) {
+    for (index, scope) in snapshot.scopes.iter().enumerate() {
+        CollapsingHeader::new(&scope.kind)
+            .id_salt(ui.id().with(index))
+            .default_open(index == 0)
+            .show(ui, |ui| show_pairs(ui, "variables", &scope.variables));
+    }
+}
+
+fn show_list(ui: &mut Ui, id: &str, values: &[String]) {
+    if values.is_empty() {
+        ui.weak("(Empty)");
+        return;
+    }
+
+    Grid::new(ui.id().with(id)).num_columns(2).show(ui, |ui| {
+        for (index, value) in values.iter().enumerate() {
+            ui.label(index.to_string());
+            ui.monospace(value);
+            ui.end_row();
+        }
+    });
+}
+
+fn show_pairs(ui: &mut Ui, id: &str, pairs: &[(String, String)]) {
+    if pairs.is_empty() {
+        ui.weak("(Empty)");
+        return;
+    }
+
+    Grid::new(ui.id().with(id)).num_columns(2).show(ui, |ui| {
+        for (name, value) in pairs {
+            ui.label(name);
+            ui.monospace(value);
+            ui.end_row();
+        }
+    });
+}
+
+/// Parses an offset in either decimal or hexadecimal (with a `0x` prefix).
+fn parse_offset(offset: &str) -> Option<usize> {
+    let offset = offset.trim();
+    match offset.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16).ok(),
+        None => offset.parse().ok(),
+    }
+}
@@ -18,6 +18,7 @@ use gc_arena::barrier::{Write, unlock};
 use gc_arena::lock::Lock;
 use gc_arena::{Collect, Gc, Mutation};
 use ruffle_macros::{enum_trait_object, istr};
+use ruffle_render::matrix3d::{Matrix3D, Matrix3DComponents};
 use ruffle_render::perspective_projection::PerspectiveProjection;
 use ruffle_render::pixel_bender::PixelBenderShaderHandle;
 use ruffle_render::transform::{Transform, TransformStack};
@@ -38,6 +39,7 @@ mod interactive;
 mod loader_display;
 mod morph_shape;
 mod movie_clip;
+mod scaling_grid;
 mod stage;
 mod text;
 mod video;
@@ -60,13 +62,14 @@ pub use loader_display::LoaderDisplay;
 pub use morph_shape::MorphShape;
 pub use movie_clip::{MovieClip, MovieClipHandle, MovieClipWeak, Scene};
 use ruffle_render::backend::{BitmapCacheEntry, RenderBackend};
-use ruffle_render::bitmap::{BitmapHandle, BitmapInfo, PixelSnapping};
+use ruffle_render::bitmap::{BitmapHandle, BitmapInfo, PixelRegion, PixelSnapping};
 use ruffle_render::blend::ExtendedBlendMode;
 use ruffle_render::commands::{CommandHandler, CommandList, RenderBlendMode};
 use ruffle_render::filters::Filter;
+pub use scaling_grid::ScalingGrid;
 pub use stage::{Stage, StageAlign, StageDisplayState, StageScaleMode, WindowMode};
 pub use text::{Text, TextSnapshot};
-pub use video::Video;
+pub use video::{Video, VideoWeak};
 
 use self::loader_display::LoaderDisplayWeak;
 
@@ -271,6 +274,11 @@ pub struct DisplayObjectBase<'gc> {
     color_transform: Cell<ColorTransform>,
     perspective_projection: Cell<Option<PerspectiveProjection>>,
 
+    /// The 3D transform of this display object, set by `z`, `rotationX`,
+    /// `transform.matrix3D`, etc. `matrix` holds its affine part.
+    /// `None` for objects which were never transformed in 3D.
+    matrix3d: Cell<Option<Matrix3D>>,
+
     // Cached transform properties `_xscale`, `_yscale`, `_rotation`.
     // These are expensive to calculate, so they will be calculated and cached
     // when AS requests one of these properties.
@@ -346,6 +354,7 @@ impl Default for DisplayObjectBase<'_> {
             matrix: Default::default(),
             color_transform: Default::default(),
             perspective_projection: Default::default(),
+            matrix3d: Cell::new(None),
             rotation: Cell::new(Degrees::from_radians(0.0)),
             scale_x: Cell::new(Percent::from_unit(1.0)),
             scale_y: Cell::new(Percent::from_unit(1.0)),
@@ -416,6 +425,68 @@ impl<'gc> DisplayObjectBase<'gc> {
     pub fn set_matrix(&self, matrix: Matrix) {
         self.matrix.set(matrix);
         self.set_scale_rotation_cached(false);
+        if self.matrix3d.take().is_some() {
+            self.recheck_cache_as_bitmap();
+        }
+    }
+
+    pub fn matrix3d(&self) -> Option<Matrix3D> {
+        self.matrix3d.get()
+    }
+
+    /// Sets the 3D transform of this object, or reverts it to a 2D one with `None`.
+    pub fn set_matrix3d(&self, matrix3d: Option<Matrix3D>) {
+        self.matrix3d.set(matrix3d);
+        if let Some(matrix3d) = matrix3d {
+            self.matrix.set(matrix3d.to_matrix());
+        }
+        self.set_scale_rotation_cached(false);
+        self.cache_scale_rotation();
+        self.recheck_cache_as_bitmap();
+    }
+
+    /// The components of this object's 3D transform.
+    /// For 2D objects, these are derived from the 2D transform.
+    fn matrix3d_components(&self) -> Matrix3DComponents {
+        if let Some(matrix3d) = self.matrix3d.get() {
+            return matrix3d.decompose();
+        }
+
+        self.cache_scale_rotation();
+        let matrix = self.matrix.get();
+        let rotation = self.rotation.get().into_radians();
+        let scale_x = self.scale_x.get().unit();
+        let scale_y = self.scale_y.get().unit();
+        Matrix3DComponents {
+            translation: [matrix.tx.to_pixels(), matrix.ty.to_pixels(), 0.0],
+            rotation: [0.0, 0.0, if rotation.is_nan() { 0.0 } else { rotation }],
+            scale: [
+                if scale_x.is_nan() { 0.0 } else { scale_x },
+                if scale_y.is_nan() { 0.0 } else { scale_y },
+                1.0,
+            ],
+        }
+    }
+
+    /// Sets the 3D transform of this object from its components, turning it
+    /// into a 3D object if needed.
+    fn set_matrix3d_components(&self, components: Matrix3DComponents) -> bool {
+        self.set_transformed_by_script(true);
+        let values = components
+            .translation
+            .iter()
+            .chain(&components.rotation)
+            .chain(&components.scale);
+        if values.clone().any(|v| v.is_nan()) {
+            return false;
+        }
+
+        let matrix3d = Matrix3D::recompose(&components);
+        let changed = self.matrix3d.get() != Some(matrix3d);
+        if changed {
+            self.set_matrix3d(Some(matrix3d));
+        }
+        changed
     }
 
     pub fn color_transform(&self) -> ColorTransform {
@@ -447,6 +518,10 @@ impl<'gc> DisplayObjectBase<'gc> {
         let changed = matrix.tx != x;
         matrix.tx = x;
         self.matrix.set(matrix);
+        if let Some(mut matrix3d) = self.matrix3d.get() {
+            matrix3d.raw_data[12] = x.to_pixels();
+            self.matrix3d.set(Some(matrix3d));
+        }
         self.set_transformed_by_script(true);
         changed
     }
@@ -460,6 +535,10 @@ impl<'gc> DisplayObjectBase<'gc> {
         let changed = matrix.ty != y;
         matrix.ty = y;
         self.matrix.set(matrix);
+        if let Some(mut matrix3d) = self.matrix3d.get() {
+            matrix3d.raw_data[13] = y.to_pixels();
+            self.matrix3d.set(Some(matrix3d));
+        }
         self.set_transformed_by_script(true);
         changed
     }
@@ -468,7 +547,19 @@ impl<'gc> DisplayObjectBase<'gc> {
     /// Calculating these requires heavy trig ops, so we only do it when `_xscale`, `_yscale` or
     /// `_rotation` is accessed.
     fn cache_scale_rotation(&self) {
-        if !self.scale_rotation_cached() {
+        if self.scale_rotation_cached() {
+            return;
+        }
+
+        if let Some(matrix3d) = self.matrix3d.get() {
+            // The 2D properties of 3D objects are the Z rotation and the X/Y scale.
+            let components = matrix3d.decompose();
+            self.rotation
+                .set(Degrees::from_radians(components.rotation[2]));
+            self.scale_x.set(Percent::from_unit(components.scale[0]));
+            self.scale_y.set(Percent::from_unit(components.scale[1]));
+            self.skew.set(0.0);
+        } else {
             let Matrix { a, b, c, d, .. } = self.matrix.get();
             let a = f64::from(a);
             let b = f64::from(b);
@@ -506,6 +597,12 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn set_rotation(&self, degrees: Degrees) -> bool {
+        if self.matrix3d.get().is_some() {
+            let mut components = self.matrix3d_components();
+            components.rotation[2] = degrees.into_radians();
+            return self.set_matrix3d_components(components);
+        }
+
         self.set_transformed_by_script(true);
         self.cache_scale_rotation();
         let changed = self.rotation.get() != degrees;
@@ -547,6 +644,12 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn set_scale_x(&self, mut value: Percent) -> bool {
+        if self.matrix3d.get().is_some() {
+            let mut components = self.matrix3d_components();
+            components.scale[0] = value.unit();
+            return self.set_matrix3d_components(components);
+        }
+
         let changed = self.scale_x.get() != value;
         self.set_transformed_by_script(true);
         self.cache_scale_rotation();
@@ -582,6 +685,12 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn set_scale_y(&self, mut value: Percent) -> bool {
+        if self.matrix3d.get().is_some() {
+            let mut components = self.matrix3d_components();
+            components.scale[1] = value.unit();
+            return self.set_matrix3d_components(components);
+        }
+
         let changed = self.scale_y.get() != value;
         self.set_transformed_by_script(true);
         self.cache_scale_rotation();
@@ -841,7 +950,10 @@ impl<'gc> DisplayObjectBase<'gc> {
 
     fn recheck_cache_as_bitmap(&self) {
         let mut write = self.cell.borrow_mut();
-        let should_cache = self.is_bitmap_cached_preference() || !write.filters.is_empty();
+        // Objects with a 3D transform are rendered to a bitmap which is then projected.
+        let should_cache = self.is_bitmap_cached_preference()
+            || !write.filters.is_empty()
+            || self.matrix3d.get().is_some();
         if should_cache {
             write.cache.get_or_insert_default();
         } else {
@@ -849,6 +961,14 @@ impl<'gc> DisplayObjectBase<'gc> {
         }
     }
 
+    fn has_3d_ancestor_or_self(&self) -> bool {
+        self.contains_flag(DisplayObjectFlags::HAS_3D_ANCESTOR_OR_SELF)
+    }
+
+    fn set_has_3d_ancestor_or_self(&self, value: bool) {
+        self.set_flag(DisplayObjectFlags::HAS_3D_ANCESTOR_OR_SELF, value);
+    }
+
     fn instantiated_by_timeline(&self) -> bool {
         self.contains_flag(DisplayObjectFlags::INSTANTIATED_BY_TIMELINE)
     }
@@ -896,14 +1016,6 @@ impl<'gc> DisplayObjectBase<'gc> {
     fn set_meta_data(this: &Write<Self>, value: Avm2Object<'gc>) {
         unlock!(this, Self, meta_data).set(Some(value));
     }
-
-    pub fn has_matrix3d_stub(&self) -> bool {
-        self.contains_flag(DisplayObjectFlags::HAS_MATRIX3D_STUB)
-    }
-
-    pub fn set_has_matrix3d_stub(&self, value: bool) {
-        self.set_flag(DisplayObjectFlags::HAS_MATRIX3D_STUB, value)
-    }
 }
 
 /// Indicates which kind of bounds should be returned by `self_bounds`.
@@ -929,6 +1041,33 @@ struct DrawCacheInfo {
     bounds: Rectangle<Twips>,
     draw_offset: Point<i32>,
     filters: Vec<Filter>,
+    size: (u32, u32),
+}
+
+/// Returns the matrix to draw the bitmap cache of an object with a 3D transform with.
+///
+/// `base_matrix` is the concatenated matrix of the object's parent, which the cache
+/// was rendered with (minus its translation, and shifted by the offset),
+/// and `projection` maps the object's space to its parent's.
+fn projected_cache_matrix(
+    base_matrix: Matrix,
+    projection: Matrix3D,
+    offset_x: Twips,
+    offset_y: Twips,
+) -> Option<Matrix3D> {
+    let linear_inverse = Matrix {
+        tx: Twips::ZERO,
+        ty: Twips::ZERO,
+        ..base_matrix
+    }
+    .inverse()?;
+
+    Some(
+        Matrix3D::from_matrix(base_matrix)
+            * projection
+            * Matrix3D::from_matrix(linear_inverse)
+            * Matrix3D::translation(offset_x.to_pixels(), offset_y.to_pixels(), 0.0),
+    )
 }
 
 pub fn render_base<'gc>(
@@ -941,8 +1080,19 @@ pub fn render_base<'gc>(
         return;
     }
 
+    // Objects with a 3D transform are rendered into their bitmap cache without it,
+    // and the cache is then drawn with the projection applied.
+    let projection = if options.apply_transform && options.apply_matrix {
+        this.projection_to_parent()
+    } else {
+        None
+    };
+
     if options.apply_transform {
-        let transform = this.base().transform(options.apply_matrix);
+        let mut transform = this.base().transform(options.apply_matrix);
+        if projection.is_some() {
+            transform.matrix = Matrix::IDENTITY;
+        }
         context.transform_stack.push(&transform);
     }
 
@@ -953,7 +1103,9 @@ pub fn render_base<'gc>(
         None
     };
 
-    let cache_info = if context.use_bitmap_cache && this.is_bitmap_cached() {
+    let cache_info = if (context.use_bitmap_cache || projection.is_some())
+        && this.is_bitmap_cached()
+    {
         let mut cache_info: Option<DrawCacheInfo> = None;
         let base_transform = context.transform_stack.transform();
         let bounds: Rectangle<Twips> = this.render_bounds_with_transform(
@@ -966,7 +1118,19 @@ pub fn render_base<'gc>(
         let swf_version = this.swf_version();
         filters.retain(|f| !f.impotent());
 
-        if let Some(cache) = &mut *this.base().bitmap_cache_mut() {
+        // When bitmap caching is disabled (i.e. for `BitmapData.draw`), a 3D object
+        // is still rendered to a bitmap to be projected, but to a throwaway one
+        // which is drawn immediately. Filters are skipped, like for any other object.
+        let mut temporary_cache = BitmapCache::default();
+        let mut persistent_cache = this.base().bitmap_cache_mut();
+        let cache = if context.use_bitmap_cache {
+            persistent_cache.as_mut()
+        } else {
+            filters.clear();
+            Some(&mut temporary_cache)
+        };
+
+        if let Some(cache) = cache {
             let width = bounds.width().to_pixels().ceil().max(0.0);
             let height = bounds.height().to_pixels().ceil().max(0.0);
             if width <= u16::MAX as f64 && height <= u16::MAX as f64 {
@@ -1009,6 +1173,7 @@ pub fn render_base<'gc>(
                         bounds,
                         draw_offset,
                         filters,
+                        size: (filter_rect.width() as u32, filter_rect.height() as u32),
                     });
                 } else {
                     cache_info = cache.handle().map(|handle| DrawCacheInfo {
@@ -1018,6 +1183,7 @@ pub fn render_base<'gc>(
                         bounds,
                         draw_offset,
                         filters,
+                        size: (filter_rect.width() as u32, filter_rect.height() as u32),
                     });
                 }
             } else {
@@ -1066,16 +1232,34 @@ pub fn render_base<'gc>(
                 library: context.library,
                 transform_stack: &mut transform_stack,
                 is_offscreen: true,
-                use_bitmap_cache: true,
+                use_bitmap_cache: context.use_bitmap_cache,
                 stage: context.stage,
             };
+            if !context.use_bitmap_cache
+                && let Some(background) = this.opaque_background()
+            {
+                let (width, height) = cache_info.size;
+                offscreen_context
+                    .commands
+                    .draw_rect(background, Matrix::scale(width as f32, height as f32));
+            }
             this.render_self(&mut offscreen_context);
-            offscreen_context.cache_draws.push(BitmapCacheEntry {
-                handle: cache_info.handle.clone(),
-                commands: offscreen_context.commands,
-                clear: this.opaque_background().unwrap_or_default(),
-                filters: cache_info.filters,
-            });
+            if context.use_bitmap_cache {
+                offscreen_context.cache_draws.push(BitmapCacheEntry {
+                    handle: cache_info.handle.clone(),
+                    commands: offscreen_context.commands,
+                    clear: this.opaque_background().unwrap_or_default(),
+                    filters: cache_info.filters,
+                });
+            } else {
+                let (width, height) = cache_info.size;
+                let _ = offscreen_context.renderer.render_offscreen(
+                    cache_info.handle.clone(),
+                    offscreen_context.commands,
+                    context.stage.quality(),
+                    PixelRegion::for_whole_size(width, height),
+                );
+            }
         }
 
         // When rendering it back, ensure we're only keeping the translation - scale/rotation is within the image already
@@ -1083,6 +1267,23 @@ pub fn render_base<'gc>(
             this,
             context,
             |context| {
+                if let Some(projection) = projection {
+                    if let Some(matrix) = projected_cache_matrix(
+                        cache_info.base_transform.matrix,
+                        projection,
+                        offset_x,
+                        offset_y,
+                    ) {
+                        context.commands.render_bitmap_projected(
+                            cache_info.handle,
+                            matrix,
+                            cache_info.base_transform.color_transform,
+                            true,
+                        );
+                    }
+                    return;
+                }
+
                 context.commands.render_bitmap(
                     cache_info.handle,
                     Transform {
@@ -1337,7 +1538,28 @@ pub trait TDisplayObject<'gc>:
     /// The world bounding box of this object including children, relative to the stage.
     #[no_dynamic]
     fn world_bounds(self, mode: BoundsMode) -> Rectangle<Twips> {
-        self.bounds_with_transform(&self.local_to_global_matrix(), mode)
+        if !self.has_3d_ancestor_or_self() {
+            return self.bounds_with_transform(&self.local_to_global_matrix(), mode);
+        }
+
+        // Projected bounds aren't affine, so project each corner of the local bounds instead.
+        let scroll_rect_matrix = self.scroll_rect().map_or(Matrix::IDENTITY, |rect| {
+            Matrix::translate(-rect.x_min, -rect.y_min)
+        });
+        let bounds = self.bounds_with_transform(&scroll_rect_matrix, mode);
+        if !bounds.is_valid() {
+            return bounds;
+        }
+        [
+            Point::new(bounds.x_min, bounds.y_min),
+            Point::new(bounds.x_max, bounds.y_min),
+            Point::new(bounds.x_min, bounds.y_max),
+            Point::new(bounds.x_max, bounds.y_max),
+        ]
+        .into_iter()
+        .fold(Rectangle::INVALID, |rect, corner| {
+            rect.encompass(self.local_to_global_projected(corner))
+        })
     }
 
     /// The world bounding box of this object, as reported by `Transform.pixelBounds`.
@@ -1436,6 +1658,7 @@ pub trait TDisplayObject<'gc>:
     /// It is the callers responsibility to do so.
     fn set_matrix(self, matrix: Matrix) {
         self.base().set_matrix(matrix);
+        self.update_has_3d_ancestor_or_self();
     }
 
     /// Sets the color transform of this object.
@@ -1460,6 +1683,196 @@ pub trait TDisplayObject<'gc>:
         }
     }
 
+    /// Sets the 3D transform of this object, or reverts it to a 2D one with `None`.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    #[no_dynamic]
+    fn set_matrix3d(self, matrix3d: Option<Matrix3D>) {
+        self.base().set_matrix3d(matrix3d);
+        self.update_has_3d_ancestor_or_self();
+        if let Some(parent) = self.parent() {
+            parent.invalidate_cached_bitmap();
+        }
+    }
+
+    /// The components of the 3D transform of this object,
+    /// as returned by the `z`, `rotationX`, `scaleZ`, etc. ActionScript properties.
+    #[no_dynamic]
+    fn matrix3d_components(self) -> Matrix3DComponents {
+        self.base().matrix3d_components()
+    }
+
+    /// Sets the 3D transform of this object from its components,
+    /// as set by the `z`, `rotationX`, `scaleZ`, etc. ActionScript properties.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    #[no_dynamic]
+    fn set_matrix3d_components(self, components: Matrix3DComponents) {
+        if self.base().set_matrix3d_components(components) {
+            self.set_scale_rotation_cached();
+            self.update_has_3d_ancestor_or_self();
+            if let Some(parent) = self.parent() {
+                parent.invalidate_cached_bitmap();
+            }
+        }
+    }
+
+    /// Returns the transform from this object's local space to its parent's space,
+    /// including the perspective projection which applies to it.
+    /// `None` is returned if this object has no 3D transform.
+    #[no_dynamic]
+    fn projection_to_parent(self) -> Option<Matrix3D> {
+        let matrix3d = self.base().matrix3d()?;
+        Some(self.parent_projection_matrix() * matrix3d)
+    }
+
+    /// Returns the perspective projection of the nearest ancestor which has one,
+    /// as a matrix in the space of this object's parent.
+    #[no_dynamic]
+    fn parent_projection_matrix(self) -> Matrix3D {
+        // The projection is done in the space of the ancestor it is set on.
+        let mut parent_to_projector = Matrix::IDENTITY;
+        let mut node = self.parent();
+        let (projector, projection) = loop {
+            let Some(display_object) = node else {
+                return Matrix3D::IDENTITY;
+            };
+            if let Some(projection) = display_object.base().perspective_projection() {
+                break (display_object, projection);
+            }
+            parent_to_projector = display_object.base().matrix() * parent_to_projector;
+            node = display_object.parent();
+        };
+        let Some(projector_to_parent) = parent_to_projector.inverse() else {
+            return Matrix3D::IDENTITY;
+        };
+
+        // Like `PerspectiveProjection.focalLength`, the focal length depends on the stage size,
+        // unless the projection belongs to the stage itself.
+        let mut width = PerspectiveProjection::DEFAULT_STAGE_WIDTH;
+        if projector.as_stage().is_none() {
+            let mut node = projector.parent();
+            while let Some(display_object) = node {
+                if let Some(stage) = display_object.as_stage() {
+                    width = stage.stage_size().0 as f32;
+                }
+                node = display_object.parent();
+            }
+        }
+
+        Matrix3D::from_matrix(projector_to_parent)
+            * projection.projection_matrix(width)
+            * Matrix3D::from_matrix(parent_to_projector)
+    }
+
+    /// Whether this object or any of its ancestors has a 3D transform.
+    /// Mapping points between the local and global space of such objects
+    /// can't be done with a single 2D matrix.
+    #[no_dynamic]
+    fn has_3d_ancestor_or_self(self) -> bool {
+        self.base().has_3d_ancestor_or_self()
+    }
+
+    /// Recomputes whether this object or any of its ancestors has a 3D transform,
+    /// updating its descendants too if that changed.
+    /// This must be called whenever the object is reparented or its 3D transform is added or removed.
+    #[no_dynamic]
+    fn update_has_3d_ancestor_or_self(self) {
+        let value = self.base().matrix3d().is_some()
+            || self
+                .parent()
+                .is_some_and(|parent| parent.has_3d_ancestor_or_self());
+        if self.base().has_3d_ancestor_or_self() == value {
+            return;
+        }
+        self.base().set_has_3d_ancestor_or_self(value);
+
+        if let Some(container) = self.as_container() {
+            for child in container.iter_render_list() {
+                child.update_has_3d_ancestor_or_self();
+            }
+        } else if let Some(button) = self.as_avm2_button() {
+            let states = [
+                swf::ButtonState::UP,
+                swf::ButtonState::OVER,
+                swf::ButtonState::DOWN,
+                swf::ButtonState::HIT_TEST,
+            ];
+            for state in states {
+                if let Some(child) = button.get_state_child(state) {
+                    child.update_has_3d_ancestor_or_self();
+                }
+            }
+        }
+    }
+
+    /// Converts a position in this object's local space to its parent's space,
+    /// with the 3D projection applied for objects that have a 3D transform.
+    #[no_dynamic]
+    fn local_to_parent(self, local: Point<Twips>) -> Point<Twips> {
+        if let Some(matrix) = self.projection_to_parent() {
+            let (x, y, _) = matrix.transform_point(local.x.to_pixels(), local.y.to_pixels(), 0.0);
+            Point::from_pixels(x, y)
+        } else {
+            self.base().matrix() * local
+        }
+    }
+
+    /// Converts a position in this object's parent's space to its local space,
+    /// picking the point on this object's plane for objects that have a 3D transform.
+    /// Returns `None` if the object has zero scale.
+    #[no_dynamic]
+    fn parent_to_local(self, point: Point<Twips>) -> Option<Point<Twips>> {
+        if let Some(matrix) = self.projection_to_parent() {
+            let (x, y) =
+                matrix.inverse_transform_plane_point(point.x.to_pixels(), point.y.to_pixels())?;
+            Some(Point::from_pixels(x, y))
+        } else {
+            self.base().matrix().inverse().map(|matrix| matrix * point)
+        }
+    }
+
+    /// Converts a position in this object's local space, after its own scroll rect,
+    /// to a global stage position, following the 3D projections along the way.
+    #[no_dynamic]
+    fn local_to_global_projected(self, mut point: Point<Twips>) -> Point<Twips> {
+        let mut node: Option<DisplayObject<'gc>> = Some(self.into());
+        while let Some(display_object) = node {
+            if display_object.as_stage().is_some() {
+                break;
+            }
+            point = display_object.local_to_parent(point);
+            node = display_object.parent();
+            if let Some(rect) = node.and_then(|parent| parent.scroll_rect()) {
+                point = Matrix::translate(-rect.x_min, -rect.y_min) * point;
+            }
+        }
+        point
+    }
+
+    /// The inverse of `local_to_global_projected`.
+    #[no_dynamic]
+    fn global_to_local_projected(self, mut point: Point<Twips>) -> Option<Point<Twips>> {
+        let mut chain = vec![];
+        let mut node: Option<DisplayObject<'gc>> = Some(self.into());
+        while let Some(display_object) = node {
+            if display_object.as_stage().is_some() {
+                break;
+            }
+            chain.push(display_object);
+            node = display_object.parent();
+        }
+
+        for display_object in chain.into_iter().rev() {
+            if let Some(rect) = display_object
+                .parent()
+                .and_then(|parent| parent.scroll_rect())
+            {
+                point = Matrix::translate(rect.x_min, rect.y_min) * point;
+            }
+            point = display_object.parent_to_local(point)?;
+        }
+        Some(point)
+    }
+
     /// Should only be used to implement 'Transform.concatenatedMatrix'
     #[no_dynamic]
     fn local_to_global_matrix_without_own_scroll_rect(self) -> Matrix {
@@ -1499,14 +1912,30 @@ pub trait TDisplayObject<'gc>:
     /// Converts a local position to a global stage position
     #[no_dynamic]
     fn local_to_global(self, local: Point<Twips>) -> Point<Twips> {
-        self.local_to_global_matrix() * local
+        if !self.has_3d_ancestor_or_self() {
+            return self.local_to_global_matrix() * local;
+        }
+
+        let mut point = local;
+        if let Some(rect) = self.scroll_rect() {
+            point = Matrix::translate(-rect.x_min, -rect.y_min) * point;
+        }
+        self.local_to_global_projected(point)
     }
 
     /// Converts a local position on the stage to a local position on this display object
     /// Returns `None` if the object has zero scale.
     #[no_dynamic]
     fn global_to_local(self, global: Point<Twips>) -> Option<Point<Twips>> {
-        self.global_to_local_matrix().map(|matrix| matrix * global)
+        if !self.has_3d_ancestor_or_self() {
+            return self.global_to_local_matrix().map(|matrix| matrix * global);
+        }
+
+        let mut point = self.global_to_local_projected(global)?;
+        if let Some(rect) = self.scroll_rect() {
+            point = Matrix::translate(rect.x_min, rect.y_min) * point;
+        }
+        Some(point)
     }
 
     /// Converts the mouse position on the stage to a local position on this display object.
@@ -1514,6 +1943,12 @@ pub trait TDisplayObject<'gc>:
     /// This matches Flash's behavior for `mouseX`/`mouseY` on an object with zero scale.
     #[no_dynamic]
     fn local_mouse_position(self, context: &UpdateContext<'gc>) -> Point<Twips> {
+        if self.has_3d_ancestor_or_self()
+            && let Some(local) = self.global_to_local(*context.mouse_position)
+        {
+            return local;
+        }
+
         let stage = context.stage;
         let pixel_ratio = stage.view_matrix().a;
         let virtual_to_device = Matrix::scale(pixel_ratio, pixel_ratio);
@@ -1869,6 +2304,7 @@ pub trait TDisplayObject<'gc>:
         let had_parent = self.parent().is_some();
         let write = Gc::write(context.gc(), self.base());
         DisplayObjectBase::set_parent_ignoring_orphan_list(write, parent);
+        self.update_has_3d_ancestor_or_self();
         let parent_removed = had_parent && parent.is_none();
 
         if parent_removed {
@@ -2002,9 +2438,13 @@ pub trait TDisplayObject<'gc>:
         self.base().scaling_grid.get()
     }
 
+    /// Sets the `scale9Grid` of this object.
+    /// This invalidates any cacheAsBitmap automatically.
     #[no_dynamic]
     fn set_scaling_grid(self, rect: Rectangle<Twips>) {
-        self.base().scaling_grid.set(rect);
+        if self.base().scaling_grid.replace(rect) != rect {
+            self.invalidate_cached_bitmap();
+        }
     }
 
     #[no_dynamic]
@@ -2882,6 +3322,7 @@ impl<'gc> DisplayObject<'gc> {
         pub fn as_avm1_button for Avm1Button;
         pub fn as_avm2_button for Avm2Button;
         pub fn as_movie_clip for MovieClip;
+        pub fn as_graphic for Graphic;
         pub fn as_edit_text for EditText;
         pub fn as_text for Text;
         pub fn as_morph_shape for MorphShape;
@@ -2968,9 +3409,6 @@ bitflags! {
         /// If this AVM1 object is pending removal (will be removed on the next frame).
         const AVM1_PENDING_REMOVAL     = 1 << 13;
 
-        /// Whether this object has matrix3D (used for stubbing).
-        const HAS_MATRIX3D_STUB        = 1 << 14;
-
         /// Whether this object has been placed by an AVM1 method,
         /// i.e. attachMovie, createEmptyMovieClip, duplicateMovieClip.
         // TODO [KJ] Can this be merged with PLACED_BY_AVM2_SCRIPT?
@@ -2987,6 +3425,10 @@ bitflags! {
         /// (they need to be instantiated "manually" by
         /// `Sprite.constructChildren`).
         const MANUAL_FRAME_CONSTRUCT  = 1 << 16;
+
+        /// Whether this object or any of its ancestors has a 3D transform.
+        /// Kept up to date by `update_has_3d_ancestor_or_self`.
+        const HAS_3D_ANCESTOR_OR_SELF = 1 << 17;
     }
 }
 
@@ -9,7 +9,7 @@ use crate::avm2::object::{
     StyleSheetObject as Avm2StyleSheetObject,
 };
 use crate::avm2::{Activation as Avm2Activation, Avm2};
-use crate::backend::ui::MouseCursor;
+use crate::backend::ui::{ClipboardData, ClipboardFormat, MouseCursor};
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::interactive::{
     InteractiveObject, InteractiveObjectBase, TInteractiveObject,
@@ -196,6 +196,10 @@ pub struct EditTextData<'gc> {
     /// See <https://docs.ruffle.rs/en_US/FlashPlatform/reference/actionscript/3/flash/text/engine/package-detail.html>
     /// See <https://docs.ruffle.rs/en_US/as3/dev/WS9dd7ed846a005b294b857bfa122bd808ea6-8000.html>
     is_fte: Cell<bool>,
+
+    /// The graphics of the `GraphicElement`s laid out in an FTE line, which
+    /// are rendered over its text.
+    fte_graphics: RefLock<Vec<DisplayObject<'gc>>>,
 }
 
 impl EditTextData<'_> {
@@ -345,6 +349,7 @@ impl<'gc> EditText<'gc> {
                 max_chars: Cell::new(swf_tag.max_length().unwrap_or_default() as i32),
                 mouse_wheel_enabled: Cell::new(true),
                 is_fte: Cell::new(false),
+                fte_graphics: RefLock::new(Vec::new()),
                 restrict: RefCell::new(EditTextRestrict::allow_all()),
                 last_click: Cell::new(None),
                 layout_debug_boxes_flags: Cell::new(LayoutDebugBoxesFlag::empty()),
@@ -420,12 +425,21 @@ impl<'gc> EditText<'gc> {
     }
 
     fn bounds_x_offset(self) -> Twips {
+        if self.is_fte() {
+            // TextLines are positioned by their origin, not by their bounds.
+            return Twips::ZERO;
+        }
+
         let scale_x = self.base().scale_x().unit();
         let offset = self.0.bounds.get().x_min.to_pixels();
         Twips::from_pixels(scale_x * offset)
     }
 
     fn bounds_y_offset(self) -> Twips {
+        if self.is_fte() {
+            return Twips::ZERO;
+        }
+
         let scale_y = self.base().scale_y().unit();
         let offset = self.0.bounds.get().y_min.to_pixels();
         Twips::from_pixels(scale_y * offset)
@@ -746,10 +760,54 @@ impl<'gc> EditText<'gc> {
         self.0.is_fte.set(is_fte);
     }
 
+    /// Set the graphics shown by an FTE line, which are positioned in the
+    /// coordinates of the line.
+    pub fn set_fte_graphics(self, mc: &Mutation<'gc>, graphics: Vec<DisplayObject<'gc>>) {
+        *unlock!(Gc::write(mc, self.0), EditTextData, fte_graphics).borrow_mut() = graphics;
+    }
+
     pub fn layout_debug_boxes_flag(self, flag: LayoutDebugBoxesFlag) -> bool {
         self.0.layout_debug_boxes_flags.get().contains(flag)
     }
 
+    /// Set the width at which an FTE line wraps its text.
+    ///
+    /// Unlike the width of a text field, this doesn't include the gutter.
+    pub fn set_fte_line_width(self, width: Twips, context: &mut UpdateContext<'gc>) {
+        let bounds = self.0.bounds.get();
+        self.0
+            .bounds
+            .set(bounds.with_width(width + Self::GUTTER * 2));
+        self.relayout(context);
+    }
+
+    /// Fit the bounds of an FTE line around its laid out text.
+    ///
+    /// The origin of a TextLine lies on the baseline of its first line,
+    /// so the bounds start one ascent above it.
+    pub fn fit_fte_line_bounds(self) {
+        let (ascent, text_size) = {
+            let layout = self.0.layout.borrow();
+            let ascent = layout
+                .lines()
+                .first()
+                .map(|line| line.ascent())
+                .unwrap_or_default();
+            (ascent, layout.text_size())
+        };
+
+        let width = self.layout_width_to_local_width(text_size.width());
+        let x_min = -Self::GUTTER;
+        let y_min = -Self::GUTTER - ascent;
+        self.0.bounds.set(Rectangle {
+            x_min,
+            y_min,
+            x_max: x_min + width + Self::GUTTER * 2,
+            y_max: y_min + text_size.height() + Self::GUTTER * 2,
+        });
+        self.invalidate_cached_bitmap();
+    }
+
     pub fn set_layout_debug_boxes_flag(self, flag: LayoutDebugBoxesFlag, value: bool) {
         let mut flags = self.0.layout_debug_boxes_flags.get();
         flags.set(flag, value);
@@ -1704,6 +1762,18 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    /// Copies the selected text to the clipboard, along with its formatting in HTML text fields.
+    fn copy_selection(self, selection: TextSelection, context: &mut UpdateContext<'gc>) {
+        let text = self.text()[selection.start()..selection.end()].to_string();
+        let mut data = vec![ClipboardData::Text(text)];
+        if self.is_effectively_html() {
+            let spans = self.0.text_spans.borrow();
+            let html = spans.slice(selection.start(), selection.end()).to_html();
+            data.push(ClipboardData::Html(html.to_string()));
+        }
+        context.ui.set_clipboard_data(data);
+    }
+
     pub fn text_control_input(
         self,
         control_code: TextControlCode,
@@ -1767,11 +1837,14 @@ impl<'gc> EditText<'gc> {
                 self.set_selection(Some(TextSelection::for_range(0, self.text().len())));
             }
             TextControlCode::Copy => {
-                let text = &self.text()[selection.start()..selection.end()];
-                context.ui.set_clipboard_content(text.to_string());
+                self.copy_selection(selection, context);
             }
             TextControlCode::Paste => 'paste: {
-                let text = context.ui.clipboard_content();
+                // Only plain text is pasted, even into HTML text fields.
+                let text = match context.ui.clipboard_data(ClipboardFormat::Text) {
+                    Some(ClipboardData::Text(text)) => text,
+                    _ => String::new(),
+                };
                 if text.is_empty() {
                     // When the clipboard is empty, nothing is pasted
                     // and the already selected text is not removed.
@@ -1783,8 +1856,7 @@ impl<'gc> EditText<'gc> {
                 self.text_input(text, context);
             }
             TextControlCode::Cut => {
-                let text = &self.text()[selection.start()..selection.end()];
-                context.ui.set_clipboard_content(text.to_string());
+                self.copy_selection(selection, context);
 
                 self.replace_text(selection.start(), selection.end(), WStr::empty(), context);
                 if is_selectable {
@@ -2674,6 +2746,7 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
 
     fn set_matrix(self, matrix: Matrix) {
         self.base().set_matrix(matrix);
+        self.update_has_3d_ancestor_or_self();
         self.invalidate_cached_bitmap();
     }
 
@@ -2765,6 +2838,10 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         if let Some(draw_caret_command) = render_state.draw_caret_command {
             context.commands.commands.push(draw_caret_command);
         }
+
+        for graphic in self.0.fte_graphics.borrow().iter() {
+            graphic.render(context);
+        }
     }
 
     fn allow_as_mask(self) -> bool {
@@ -4,9 +4,9 @@ use crate::avm2::{
     StageObject as Avm2StageObject,
 };
 use crate::context::{RenderContext, UpdateContext};
-use crate::display_object::{BoundsMode, DisplayObjectBase};
+use crate::display_object::{BoundsMode, DisplayObjectBase, ScalingGrid};
 use crate::drawing::Drawing;
-use crate::library::MovieLibrarySource;
+use crate::library::{Library, MovieLibrarySource};
 use crate::prelude::*;
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::Instantiator;
@@ -16,7 +16,9 @@ use gc_arena::lock::Lock;
 use gc_arena::{Collect, Gc, Mutation};
 use ruffle_common::utils::HasPrefixField;
 use ruffle_render::backend::ShapeHandle;
+use ruffle_render::bitmap::BitmapSource;
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::shape_utils::DistilledShape;
 use std::cell::{OnceCell, RefCell, RefMut};
 use std::sync::Arc;
 
@@ -118,6 +120,25 @@ impl<'gc> Graphic<'gc> {
         unlock!(Gc::write(mc, self.0), GraphicData, class).set(Some(class));
     }
 
+    /// Calls `f` with the shape of this graphic, and the source of the bitmaps used by its fills.
+    pub fn with_shape<R>(
+        self,
+        library: &Library<'gc>,
+        f: impl FnOnce(&DistilledShape<'_>, &dyn BitmapSource) -> R,
+    ) -> R {
+        if let Some(drawing) = self.0.drawing.get() {
+            let drawing = drawing.borrow();
+            return f(&drawing.distilled_shape(), &*drawing);
+        }
+
+        let shared = self.0.shared.get();
+        let shape: DistilledShape = (&shared.shape).into();
+        match library.library_for_movie(shared.movie.clone()) {
+            Some(library) => f(&shape, &MovieLibrarySource { library }),
+            None => f(&shape, &Drawing::new()),
+        }
+    }
+
     fn set_shared(self, mc: &Mutation<'gc>, shared: Gc<'gc, GraphicShared>) {
         unlock!(Gc::write(mc, self.0), GraphicData, shared).set(shared);
     }
@@ -199,12 +220,23 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
             return;
         }
 
+        let scaling_grid = ScalingGrid::for_shape(self.into());
         if let Some(drawing) = self.0.drawing.get() {
-            drawing.borrow().render(context);
+            if let Some((grid, matrix)) = scaling_grid {
+                drawing
+                    .borrow()
+                    .render_with_scaling_grid(context, &grid, &matrix);
+            } else {
+                drawing.borrow().render(context);
+            }
         } else if let Some(render_handle) = self.0.shared.get().render_handle.clone() {
-            context
-                .commands
-                .render_shape(render_handle, context.transform_stack.transform())
+            if let Some((grid, matrix)) = scaling_grid {
+                grid.render_shape(context, render_handle, &matrix);
+            } else {
+                context
+                    .commands
+                    .render_shape(render_handle, context.transform_stack.transform())
+            }
         }
     }
 
@@ -221,7 +253,12 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
             let Some(local_matrix) = self.global_to_local_matrix() else {
                 return false;
             };
-            let point = local_matrix * point;
+            let Some(mut point) = self.global_to_local(point) else {
+                return false;
+            };
+            if let Some((grid, matrix)) = ScalingGrid::for_shape(self.into()) {
+                point = grid.unmap_point(point, &matrix);
+            }
             if let Some(drawing) = self.0.drawing.get() {
                 if drawing.borrow().hit_test(point, &local_matrix) {
                     return true;
@@ -22,7 +22,7 @@ use crate::display_object::interactive::{
 };
 use crate::display_object::{
     Avm1Button, Avm1TextFieldBinding, Avm2Button, BoundsMode, DisplayObjectBase, DisplayObjectPtr,
-    EditText, Graphic, MorphShape, Text, Video,
+    EditText, Graphic, MorphShape, ScalingGrid, Text, Video,
 };
 use crate::drawing::Drawing;
 use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult};
@@ -2247,6 +2247,18 @@ impl<'gc> MovieClip<'gc> {
         self.0.drawing.get().map(|d| d.borrow())
     }
 
+    /// Hit tests the drawing of this clip, stretched by its `scale9Grid`.
+    fn hit_test_drawing(self, local_point: Point<Twips>, local_matrix: &Matrix) -> bool {
+        let Some(drawing) = self.drawing() else {
+            return false;
+        };
+        let local_point = match ScalingGrid::for_owner(self.into()) {
+            Some(grid) => grid.unmap_point(local_point, &Matrix::IDENTITY),
+            None => local_point,
+        };
+        drawing.hit_test(local_point, local_matrix)
+    }
+
     pub fn is_button_mode(self, context: &mut UpdateContext<'gc>) -> bool {
         if self.forced_button_mode()
             || self
@@ -2606,7 +2618,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
 
     fn render_self(self, context: &mut RenderContext<'_, 'gc>) {
         if let Some(drawing) = self.drawing() {
-            drawing.render(context);
+            match ScalingGrid::for_owner(self.into()) {
+                Some(grid) => drawing.render_with_scaling_grid(context, &grid, &Matrix::IDENTITY),
+                None => drawing.render(context),
+            }
         }
         self.render_children(context);
     }
@@ -2664,10 +2679,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
                 }
             }
 
-            let point = local_matrix * point;
-            if let Some(drawing) = self.drawing()
-                && drawing.hit_test(point, &local_matrix)
-            {
+            let Some(point) = self.global_to_local(point) else {
+                return false;
+            };
+            if self.hit_test_drawing(point, &local_matrix) {
                 return true;
             }
         }
@@ -3014,8 +3029,8 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
             // Check drawing, because this selects the current clip, it must have mouse enabled
             if self.mouse_enabled()
                 && check_non_interactive
-                && let Some(drawing) = self.drawing()
-                && drawing.hit_test(local_matrix * point, &local_matrix)
+                && let Some(local_point) = self.global_to_local(point)
+                && self.hit_test_drawing(local_point, &local_matrix)
             {
                 return Some(this);
             }
@@ -3157,8 +3172,8 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
 
             // Check drawing, because this selects the current clip, it must have mouse enabled
             if self.world_bounds(BoundsMode::Engine).contains(point)
-                && let Some(drawing) = self.drawing()
-                && drawing.hit_test(local_matrix * point, &local_matrix)
+                && let Some(local_point) = self.global_to_local(point)
+                && self.hit_test_drawing(local_point, &local_matrix)
             {
                 return if self.mouse_enabled() {
                     Avm2MousePick::Hit(self.into())
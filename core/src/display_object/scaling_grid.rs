@@ -0,0 +1,312 @@
+//! `scale9Grid` support.
+
+use crate::context::RenderContext;
+use crate::display_object::{BoundsMode, DisplayObject, TDisplayObject, TDisplayObjectContainer};
+use crate::prelude::*;
+use ruffle_render::backend::ShapeHandle;
+use ruffle_render::transform::Transform;
+
+/// The 9-slice scaling applied to the shapes of an object with a `scale9Grid`.
+///
+/// The content of the object is stretched so that, once the object's own scale
+/// is applied, the four corners of the grid keep their original size, the
+/// edges only stretch along their length and the center stretches in both
+/// directions. Like in Flash Player, this only applies to the vector shapes of
+/// the object itself and of its direct shape children, and is ignored entirely
+/// if the object is rotated or skewed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScalingGrid {
+    x: AxisMapping,
+    y: AxisMapping,
+}
+
+impl ScalingGrid {
+    /// Returns the grid to apply to the content of `owner`, or `None` if its
+    /// `scale9Grid` has no effect with its current transform.
+    pub fn for_owner(owner: DisplayObject<'_>) -> Option<Self> {
+        let grid = owner.scaling_grid();
+        if !grid.is_valid() || owner.base().matrix3d().is_some() {
+            return None;
+        }
+
+        let matrix = owner.base().matrix();
+        if matrix.b != 0.0 || matrix.c != 0.0 || matrix.a == 0.0 || matrix.d == 0.0 {
+            return None;
+        }
+        if matrix.a.abs() == 1.0 && matrix.d.abs() == 1.0 {
+            return None;
+        }
+
+        let bounds = content_bounds(owner);
+        if !bounds.is_valid() {
+            return None;
+        }
+
+        Some(Self {
+            x: AxisMapping::new(
+                [bounds.x_min, grid.x_min, grid.x_max, bounds.x_max],
+                matrix.a.abs(),
+            ),
+            y: AxisMapping::new(
+                [bounds.y_min, grid.y_min, grid.y_max, bounds.y_max],
+                matrix.d.abs(),
+            ),
+        })
+    }
+
+    /// Returns the grid to apply to the shape of `object`, along with the
+    /// matrix from the shape's space to the space of the grid.
+    ///
+    /// This is either the object's own grid, or the grid of its parent.
+    pub fn for_shape(object: DisplayObject<'_>) -> Option<(Self, Matrix)> {
+        if let Some(grid) = Self::for_owner(object) {
+            return Some((grid, Matrix::IDENTITY));
+        }
+
+        let parent = object.parent()?;
+        parent.as_movie_clip()?;
+        Self::for_owner(parent).map(|grid| (grid, object.base().matrix()))
+    }
+
+    /// Maps a stretched point back to its original position in grid space.
+    fn unmap(&self, point: Point<Twips>) -> Point<Twips> {
+        Point::new(
+            interpolate(point.x, &self.x.to, &self.x.from),
+            interpolate(point.y, &self.y.to, &self.y.from),
+        )
+    }
+
+    /// Converts a point in the space of a stretched shape drawn with `matrix`
+    /// into grid space back to the point of the original shape, for hit testing.
+    pub fn unmap_point(&self, point: Point<Twips>, matrix: &Matrix) -> Point<Twips> {
+        let Some(inverse) = matrix.inverse() else {
+            return point;
+        };
+        inverse * self.unmap(*matrix * point)
+    }
+
+    /// Renders `shape`, drawn with `matrix` into grid space, stretched by this grid.
+    ///
+    /// Instead of registering a stretched copy of the shape whenever the scale
+    /// changes, each cell of the grid renders the original shape with its own
+    /// scale, masked to the area of the cell.
+    pub fn render_shape(&self, context: &mut RenderContext, shape: ShapeHandle, matrix: &Matrix) {
+        let transform = context.transform_stack.transform();
+        let inverse = matrix.inverse().unwrap_or(Matrix::IDENTITY);
+        let grid_to_world = transform.matrix * inverse;
+
+        let columns = self.x.cells();
+        let rows = self.y.cells();
+        for row in &rows {
+            for column in &columns {
+                let cell_bounds = Rectangle {
+                    x_min: Twips::new(column.start.round() as i32),
+                    y_min: Twips::new(row.start.round() as i32),
+                    x_max: Twips::new(column.end.round() as i32),
+                    y_max: Twips::new(row.end.round() as i32),
+                };
+                let mask_matrix = grid_to_world * Matrix::create_box_from_rectangle(&cell_bounds);
+                let cell_matrix = Matrix {
+                    a: column.scale as f32,
+                    b: 0.0,
+                    c: 0.0,
+                    d: row.scale as f32,
+                    tx: Twips::new(column.offset.round() as i32),
+                    ty: Twips::new(row.offset.round() as i32),
+                };
+
+                context.commands.push_mask();
+                // The color doesn't matter, as this is a mask.
+                context.commands.draw_rect(Color::WHITE, mask_matrix);
+                context.commands.activate_mask();
+                context.commands.render_shape(
+                    shape.clone(),
+                    Transform {
+                        matrix: grid_to_world * cell_matrix * *matrix,
+                        ..transform.clone()
+                    },
+                );
+                context.commands.deactivate_mask();
+                context.commands.draw_rect(Color::WHITE, mask_matrix);
+                context.commands.pop_mask();
+            }
+        }
+    }
+}
+
+/// The bounds of the content of `owner` in its own space, ignoring any scroll rect.
+fn content_bounds(owner: DisplayObject<'_>) -> Rectangle<Twips> {
+    let mut bounds = owner.self_bounds(BoundsMode::Engine);
+    if let Some(container) = owner.as_container() {
+        for child in container.iter_render_list() {
+            bounds = bounds.union(&child.local_bounds(BoundsMode::Engine));
+        }
+    }
+    bounds
+}
+
+/// A piecewise linear mapping along one axis, between the edges of the
+/// content bounds and of the grid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct AxisMapping {
+    from: [f64; 4],
+    to: [f64; 4],
+}
+
+impl AxisMapping {
+    /// Creates the mapping for the `edges` (content start, grid start, grid end
+    /// and content end) of an object scaled by `scale` along this axis.
+    fn new(edges: [Twips; 4], scale: f32) -> Self {
+        let start = edges[0].get() as f64;
+        let end = edges[3].get() as f64;
+        let grid_start = (edges[1].get() as f64).clamp(start, end);
+        let grid_end = (edges[2].get() as f64).clamp(grid_start, end);
+        let from = [start, grid_start, grid_end, end];
+
+        // Undo the object's scale on the corners, so that they keep their size.
+        let scale = scale as f64;
+        let mut start_size = (grid_start - start) / scale;
+        let mut end_size = (end - grid_end) / scale;
+
+        // When scaled down too much, the corners are shrunk to fit.
+        let total = end - start;
+        if start_size + end_size > total {
+            let factor = total / (start_size + end_size);
+            start_size *= factor;
+            end_size *= factor;
+        }
+
+        Self {
+            from,
+            to: [start, start + start_size, end - end_size, end],
+        }
+    }
+}
+
+/// How far the outer cells of a grid extend past the content bounds, so that
+/// anything drawn outside of them (like antialiased edges) isn't cut off.
+const OUTER_CELL_EXTENT: f64 = (1 << 20) as f64;
+
+/// One cell of a grid along a single axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Cell {
+    /// The stretched area of the cell.
+    start: f64,
+    end: f64,
+    /// The mapping of the content of the cell into that area.
+    scale: f64,
+    offset: f64,
+}
+
+impl AxisMapping {
+    /// The cells of this mapping that content can be drawn into.
+    fn cells(&self) -> Vec<Cell> {
+        let mut cells: Vec<_> = segments(&self.from)
+            .filter(|&i| self.to[i + 1] > self.to[i])
+            .map(|i| {
+                let scale = (self.to[i + 1] - self.to[i]) / (self.from[i + 1] - self.from[i]);
+                Cell {
+                    start: self.to[i],
+                    end: self.to[i + 1],
+                    scale,
+                    offset: self.to[i] - self.from[i] * scale,
+                }
+            })
+            .collect();
+        if let Some(first) = cells.first_mut() {
+            first.start -= OUTER_CELL_EXTENT;
+        }
+        if let Some(last) = cells.last_mut() {
+            last.end += OUTER_CELL_EXTENT;
+        }
+        cells
+    }
+}
+
+/// The indices of the segments of `edges` that aren't empty.
+fn segments(edges: &[f64; 4]) -> impl DoubleEndedIterator<Item = usize> + Clone + '_ {
+    (0..3).filter(|&i| edges[i + 1] > edges[i])
+}
+
+/// Maps `value` with the piecewise linear function going through the points
+/// of `from` and `to`.
+///
+/// Empty segments (like when the grid reaches the content bounds) are skipped,
+/// and values outside of the edges are mapped by extending the outer segments.
+fn interpolate(value: Twips, from: &[f64; 4], to: &[f64; 4]) -> Twips {
+    let value = value.get() as f64;
+    let mut non_empty = segments(from);
+    let segment = non_empty
+        .clone()
+        .find(|&i| value < from[i + 1])
+        .or_else(|| non_empty.next_back());
+    let mapped = match segment {
+        Some(i) => to[i] + (value - from[i]) * (to[i + 1] - to[i]) / (from[i + 1] - from[i]),
+        None => value - from[0] + to[0],
+    };
+    Twips::new(mapped.round() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn twips(values: [i32; 4]) -> [Twips; 4] {
+        values.map(Twips::new)
+    }
+
+    #[test]
+    fn corners_keep_their_size() {
+        // Content from 0 to 100, grid from 10 to 90, scaled by 2.
+        let mapping = AxisMapping::new(twips([0, 10, 90, 100]), 2.0);
+        assert_eq!(mapping.to, [0.0, 5.0, 95.0, 100.0]);
+
+        let map = |value| interpolate(Twips::new(value), &mapping.from, &mapping.to);
+        assert_eq!(map(0), Twips::new(0));
+        assert_eq!(map(10), Twips::new(5));
+        assert_eq!(map(50), Twips::new(50));
+        assert_eq!(map(100), Twips::new(100));
+    }
+
+    #[test]
+    fn corners_shrink_to_fit() {
+        // Scaled down to a tenth, the corners would be 4 times the content size.
+        let mapping = AxisMapping::new(twips([0, 20, 80, 100]), 0.1);
+        assert_eq!(mapping.to, [0.0, 50.0, 50.0, 100.0]);
+    }
+
+    #[test]
+    fn empty_segments_are_skipped() {
+        // The grid reaches the start of the content.
+        let mapping = AxisMapping::new(twips([0, 0, 80, 100]), 2.0);
+        assert_eq!(mapping.to, [0.0, 0.0, 90.0, 100.0]);
+
+        let map = |value| interpolate(Twips::new(value), &mapping.from, &mapping.to);
+        assert_eq!(map(-10), Twips::new(-11));
+        assert_eq!(map(0), Twips::new(0));
+        assert_eq!(map(40), Twips::new(45));
+        assert_eq!(map(100), Twips::new(100));
+
+        let cells = mapping.cells();
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells[0].start, -OUTER_CELL_EXTENT);
+        assert_eq!(cells[0].end, 90.0);
+        assert_eq!(cells[1].start, 90.0);
+        assert_eq!(cells[1].scale, 0.5);
+
+        // A grid covering nothing at all only offsets the content.
+        let from = [10.0; 4];
+        let to = [20.0; 4];
+        assert_eq!(interpolate(Twips::new(15), &from, &to), Twips::new(25));
+    }
+
+    #[test]
+    fn unmap_is_the_inverse_of_map() {
+        let mapping = AxisMapping::new(twips([-40, 0, 200, 400]), 3.0);
+        for value in [-40, -10, 0, 100, 200, 300, 400] {
+            let mapped = interpolate(Twips::new(value), &mapping.from, &mapping.to);
+            let unmapped = interpolate(mapped, &mapping.to, &mapping.from);
+            assert!((unmapped.get() - value).abs() <= 2);
+        }
+    }
+}
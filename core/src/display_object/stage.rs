@@ -311,6 +311,22 @@ impl<'gc> Stage<'gc> {
         self.0.stage_size.get()
     }
 
+    /// Set the size of a stage that isn't displayed in the player's viewport, such as the
+    /// stage of a secondary AIR window, firing a resize event if it changed.
+    pub fn set_stage_size(self, context: &mut UpdateContext<'gc>, width: u32, height: u32) {
+        let size = (width, height);
+        self.0.movie_size.set(size);
+        self.0.view_bounds.set(Rectangle {
+            x_min: Twips::ZERO,
+            y_min: Twips::ZERO,
+            x_max: Twips::from_pixels_i32(width as i32),
+            y_max: Twips::from_pixels_i32(height as i32),
+        });
+        if self.0.stage_size.replace(size) != size {
+            self.fire_resize_event(context);
+        }
+    }
+
     /// Get the stage mode.
     /// This controls how the content scales to fill the viewport.
     pub fn scale_mode(self) -> StageScaleMode {
@@ -2,6 +2,7 @@
 
 use crate::avm1::{NativeObject as Avm1NativeObject, Object as Avm1Object};
 use crate::avm2::StageObject as Avm2StageObject;
+use crate::camera::Camera;
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{Avm1TextFieldBinding, BoundsMode, DisplayObjectBase, RenderOptions};
 use crate::prelude::*;
@@ -11,7 +12,7 @@ use crate::vminterface::{AvmObject, Instantiator};
 use core::fmt;
 use gc_arena::barrier::unlock;
 use gc_arena::lock::{Lock, RefLock};
-use gc_arena::{Collect, Gc, Mutation};
+use gc_arena::{Collect, Gc, GcWeak, Mutation};
 use ruffle_common::utils::HasPrefixField;
 use ruffle_render::bitmap::{BitmapInfo, PixelSnapping};
 use ruffle_render::commands::CommandHandler;
@@ -34,6 +35,20 @@ use swf::{DefineVideoStream, VideoCodec, VideoFrame};
 #[collect(no_drop)]
 pub struct Video<'gc>(Gc<'gc, VideoData<'gc>>);
 
+#[derive(Clone, Collect, Copy, Debug)]
+#[collect(no_drop)]
+pub struct VideoWeak<'gc>(GcWeak<'gc, VideoData<'gc>>);
+
+impl<'gc> VideoWeak<'gc> {
+    pub fn upgrade(self, mc: &Mutation<'gc>) -> Option<Video<'gc>> {
+        self.0.upgrade(mc).map(Video)
+    }
+
+    pub fn as_ptr(self) -> *const VideoData<'gc> {
+        self.0.as_ptr()
+    }
+}
+
 impl fmt::Debug for Video<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Video")
@@ -100,14 +115,19 @@ pub enum VideoSource<'gc> {
     /// particular character. If you need to mutate the video source, consider
     /// reallocating a new source for your specific video instead.
     ///
-    /// This warning does not apply to `NetStream` or `Unconnected` videos,
-    /// which are never aliased.
+    /// This warning does not apply to `NetStream`, `Camera` or `Unconnected`
+    /// videos, which are never aliased.
     Swf(Gc<'gc, SwfVideoSource>),
     /// An attached NetStream.
     NetStream {
         /// The stream the video is downloaded from.
         stream: NetStream<'gc>,
     },
+    /// An attached Camera.
+    Camera {
+        /// The camera the video is captured by.
+        camera: Camera<'gc>,
+    },
     Unconnected,
 }
 
@@ -202,6 +222,33 @@ impl<'gc> Video<'gc> {
         self.0.keyframes.replace(BTreeSet::new());
     }
 
+    /// Convert this Video into a Camera sourced video, or disconnect it if
+    /// `camera` is `None`.
+    pub fn attach_camera(self, context: &mut UpdateContext<'gc>, camera: Option<Camera<'gc>>) {
+        match camera {
+            Some(camera) => {
+                self.set_source(context, VideoSource::Camera { camera });
+                camera.add_video(context.gc(), self.downgrade());
+            }
+            None => self.set_source(context, VideoSource::Unconnected),
+        }
+        self.0.stream.set(VideoStream::Uninstantiated(0));
+        self.0.keyframes.replace(BTreeSet::new());
+        self.invalidate_cached_bitmap();
+    }
+
+    /// The camera this Video displays, if any.
+    pub fn camera(self) -> Option<Camera<'gc>> {
+        match self.0.source.get() {
+            VideoSource::Camera { camera } => Some(camera),
+            _ => None,
+        }
+    }
+
+    pub fn downgrade(self) -> VideoWeak<'gc> {
+        VideoWeak(Gc::downgrade(self.0))
+    }
+
     /// Preload frame data from an SWF.
     ///
     /// This function yields an error if this video player is not playing an
@@ -221,6 +268,7 @@ impl<'gc> Video<'gc> {
                 frames.insert(tag.frame_num.into(), (subslice.start, subslice.end));
             }
             VideoSource::NetStream { .. } => {}
+            VideoSource::Camera { .. } => {}
             VideoSource::Unconnected { .. } => {}
         }
     }
@@ -244,6 +292,7 @@ impl<'gc> Video<'gc> {
         let num_frames = match self.0.source.get() {
             VideoSource::Swf(swf_source) => swf_source.streamdef.num_frames as usize,
             VideoSource::NetStream { .. } => return,
+            VideoSource::Camera { .. } => return,
             VideoSource::Unconnected { .. } => return,
         };
 
@@ -330,6 +379,7 @@ impl<'gc> Video<'gc> {
                 }
             },
             VideoSource::NetStream { .. } => return,
+            VideoSource::Camera { .. } => return,
             VideoSource::Unconnected { .. } => return,
         };
 
@@ -412,6 +462,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
                 }
             }
             VideoSource::NetStream { .. } => return,
+            VideoSource::Camera { .. } => return,
             VideoSource::Unconnected { .. } => return,
         };
 
@@ -463,6 +514,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
         match self.0.source.get() {
             VideoSource::Swf(swf_source) => swf_source.streamdef.id,
             VideoSource::NetStream { .. } => 0,
+            VideoSource::Camera { .. } => 0,
             VideoSource::Unconnected { .. } => 0,
         }
     }
@@ -511,6 +563,13 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
                 stream.last_decoded_bitmap(),
                 None,
             ),
+            VideoSource::Camera { camera } => (
+                false,
+                None,
+                self.0.movie.version(),
+                camera.frame_bitmap(),
+                None,
+            ),
             VideoSource::Unconnected { .. } => return context.transform_stack.pop(),
         };
 
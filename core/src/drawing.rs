@@ -1,7 +1,13 @@
 use crate::context::RenderContext;
-use ruffle_render::backend::{RenderBackend, ShapeHandle};
-use ruffle_render::bitmap::{BitmapHandle, BitmapInfo, BitmapSize, BitmapSource};
+use crate::display_object::ScalingGrid;
+use ruffle_render::backend::{PixelBenderTarget, RenderBackend, ShapeHandle};
+use ruffle_render::bitmap::{
+    Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, BitmapSize, BitmapSource,
+};
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::matrix::Matrix;
+use ruffle_render::pixel_bender::PixelBenderShaderHandle;
+use ruffle_render::pixel_bender_support::PixelBenderShaderArgument;
 use ruffle_render::shape_utils::{
     DistilledShape, DrawCommand, DrawPath, FillRule, cubic_curve_bounds, quadratic_curve_bounds,
 };
@@ -14,7 +20,7 @@ pub struct Drawing {
     shape_bounds: Rectangle<Twips>,
     edge_bounds: Rectangle<Twips>,
     paths: Vec<DrawingPath>,
-    bitmaps: Vec<BitmapInfo>,
+    bitmaps: Vec<DrawingBitmap>,
     current_fill: Option<DrawingFill>,
     current_line: Option<DrawingLine>,
     pending_lines: Vec<DrawingLine>,
@@ -107,6 +113,13 @@ impl Drawing {
     fn mark_dirty(&mut self) {
         self.is_empty = false;
         self.render_handle.take();
+        for bitmap in &mut self.bitmaps {
+            if let DrawingBitmap::Shader(shader_fill) = bitmap {
+                // The area covered by the shader may have changed.
+                shader_fill.size.take();
+                shader_fill.bitmap.take();
+            }
+        }
     }
 
     /// Set fill style and reset fill rule to default.
@@ -235,86 +248,168 @@ impl Drawing {
 
     pub fn add_bitmap(&mut self, bitmap: BitmapInfo) -> u16 {
         let id = self.bitmaps.len() as u16;
-        self.bitmaps.push(bitmap);
+        self.bitmaps.push(DrawingBitmap::Bitmap(bitmap));
+        id
+    }
+
+    /// Adds the output of a shader as a bitmap, to be used by a repeating bitmap fill.
+    ///
+    /// See [`ShaderFill`] for how the shader is run.
+    pub fn add_shader_fill(
+        &mut self,
+        shader: PixelBenderShaderHandle,
+        arguments: Vec<PixelBenderShaderArgument<'static>>,
+    ) -> u16 {
+        let id = self.bitmaps.len() as u16;
+        self.bitmaps.push(DrawingBitmap::Shader(ShaderFill {
+            shader,
+            arguments,
+            size: OnceCell::new(),
+            bitmap: OnceCell::new(),
+        }));
         id
     }
 
+    /// The size of the bitmap the shader fill `id` is rendered to:
+    /// the bounds of all the paths it fills, in the coordinates of the shader.
+    fn shader_fill_size(&self, id: u16) -> BitmapSize {
+        let mut width = 1.0f64;
+        let mut height = 1.0f64;
+        for path in self.distilled_shape().paths {
+            let (style, commands, stroke_width) = match &path {
+                DrawPath::Fill {
+                    style, commands, ..
+                } => (*style, commands, Twips::ZERO),
+                DrawPath::Stroke {
+                    style, commands, ..
+                } => (style.fill_style(), commands, style.width()),
+            };
+            let FillStyle::Bitmap {
+                id: fill_id,
+                matrix,
+                ..
+            } = style
+            else {
+                continue;
+            };
+            if *fill_id != id {
+                continue;
+            }
+            let Some(to_shader) = Matrix::from(*matrix).inverse() else {
+                continue;
+            };
+
+            let mut bounds = Rectangle::INVALID;
+            let mut cursor = Point::ZERO;
+            for command in commands {
+                bounds = stretch_bounds(&bounds, command, stroke_width, cursor);
+                cursor = command.end_point();
+            }
+            if !bounds.is_valid() {
+                continue;
+            }
+
+            // The fill matrix maps pixels of the shader output to twips, so the
+            // inverse gives shader pixels (stored in `Twips` values).
+            let corners = [
+                Point::new(bounds.x_min, bounds.y_min),
+                Point::new(bounds.x_max, bounds.y_min),
+                Point::new(bounds.x_min, bounds.y_max),
+                Point::new(bounds.x_max, bounds.y_max),
+            ];
+            for corner in corners {
+                let corner = to_shader * corner;
+                width = width.max(f64::from(corner.x.get()).ceil());
+                height = height.max(f64::from(corner.y.get()).ceil());
+            }
+        }
+
+        BitmapSize {
+            width: width.min(MAX_SHADER_FILL_SIZE as f64) as u32,
+            height: height.min(MAX_SHADER_FILL_SIZE as f64) as u32,
+        }
+    }
+
     /// Obtain a `ShapeHandle` that represents this `Drawing`, or `None` if it is empty.
     pub fn register_or_replace(&self, renderer: &mut dyn RenderBackend) -> Option<ShapeHandle> {
         if self.is_empty {
             return None;
         }
 
-        let handle = self.render_handle.get_or_init(|| {
-            let mut paths = Vec::with_capacity(self.paths.len());
-
-            for path in &self.paths {
-                match path {
-                    DrawingPath::Fill(fill) => {
-                        paths.push(DrawPath::Fill {
-                            style: &fill.style,
-                            commands: fill.commands.to_owned(),
-                            winding_rule: fill.rule,
-                        });
-                    }
-                    DrawingPath::Line(line) => {
-                        paths.push(DrawPath::Stroke {
-                            style: &line.style,
-                            commands: line.commands.to_owned(),
-                            is_closed: line.is_closed,
-                        });
-                    }
-                }
-            }
+        let handle = self
+            .render_handle
+            .get_or_init(|| renderer.register_shape(self.distilled_shape(), self));
 
-            if let Some(fill) = &self.current_fill {
-                paths.push(DrawPath::Fill {
-                    style: &fill.style,
-                    commands: fill.commands.to_owned(),
-                    winding_rule: fill.rule,
-                })
-            }
+        Some(handle.clone())
+    }
 
-            for line in &self.pending_lines {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
-                })
-            }
+    /// The paths of this drawing, including any pending fill and lines.
+    pub fn distilled_shape(&self) -> DistilledShape<'_> {
+        let mut paths = Vec::with_capacity(self.paths.len());
 
-            if let Some(line) = &self.current_line {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
-                })
+        for path in &self.paths {
+            match path {
+                DrawingPath::Fill(fill) => {
+                    paths.push(DrawPath::Fill {
+                        style: &fill.style,
+                        commands: fill.commands.to_owned(),
+                        winding_rule: fill.rule,
+                    });
+                }
+                DrawingPath::Line(line) => {
+                    paths.push(DrawPath::Stroke {
+                        style: &line.style,
+                        commands: line.commands.to_owned(),
+                        is_closed: line.is_closed,
+                    });
+                }
             }
+        }
+
+        if let Some(fill) = &self.current_fill {
+            paths.push(DrawPath::Fill {
+                style: &fill.style,
+                commands: fill.commands.to_owned(),
+                winding_rule: fill.rule,
+            })
+        }
 
-            let shape = DistilledShape {
-                paths,
-                shape_bounds: self.shape_bounds,
-                edge_bounds: self.edge_bounds,
-                id: 0,
+        for line in &self.pending_lines {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
             };
-            renderer.register_shape(shape, self)
-        });
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
 
-        Some(handle.clone())
+        if let Some(line) = &self.current_line {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
+            };
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
+
+        DistilledShape {
+            paths,
+            shape_bounds: self.shape_bounds,
+            edge_bounds: self.edge_bounds,
+            id: 0,
+        }
     }
 
     pub fn render(&self, context: &mut RenderContext) {
@@ -325,6 +420,18 @@ impl Drawing {
         }
     }
 
+    /// Renders this drawing stretched by a `scale9Grid`, see [`ScalingGrid`].
+    pub fn render_with_scaling_grid(
+        &self,
+        context: &mut RenderContext,
+        grid: &ScalingGrid,
+        matrix: &Matrix,
+    ) {
+        if let Some(handle) = self.register_or_replace(context.renderer) {
+            grid.render_shape(context, handle, matrix);
+        }
+    }
+
     pub fn self_bounds(&self) -> Rectangle<Twips> {
         self.shape_bounds
     }
@@ -419,13 +526,78 @@ impl Drawing {
 
 impl BitmapSource for Drawing {
     fn bitmap_size(&self, id: u16) -> Option<BitmapSize> {
-        self.bitmaps.get(id as usize).map(|bm| BitmapSize {
-            width: bm.width,
-            height: bm.height,
-        })
+        match self.bitmaps.get(id as usize)? {
+            DrawingBitmap::Bitmap(bm) => Some(BitmapSize {
+                width: bm.width,
+                height: bm.height,
+            }),
+            DrawingBitmap::Shader(shader_fill) => {
+                Some(*shader_fill.size.get_or_init(|| self.shader_fill_size(id)))
+            }
+        }
     }
-    fn bitmap_handle(&self, id: u16, _backend: &mut dyn RenderBackend) -> Option<BitmapHandle> {
-        self.bitmaps.get(id as usize).map(|bm| bm.handle.clone())
+    fn bitmap_handle(&self, id: u16, backend: &mut dyn RenderBackend) -> Option<BitmapHandle> {
+        match self.bitmaps.get(id as usize)? {
+            DrawingBitmap::Bitmap(bm) => Some(bm.handle.clone()),
+            DrawingBitmap::Shader(shader_fill) => {
+                let size = self.bitmap_size(id)?;
+                shader_fill
+                    .bitmap
+                    .get_or_init(|| shader_fill.render(backend, size))
+                    .clone()
+            }
+        }
+    }
+}
+
+/// The largest width and height of the bitmap a shader fill is rendered to.
+const MAX_SHADER_FILL_SIZE: u32 = 4096;
+
+/// A bitmap used by the fills of a `Drawing`.
+#[derive(Debug, Clone)]
+enum DrawingBitmap {
+    Bitmap(BitmapInfo),
+    Shader(ShaderFill),
+}
+
+/// A fill drawn by a Pixel Bender shader, as set by `Graphics.beginShaderFill`.
+///
+/// The shader is run when the fill is first rendered, over the bounds of the paths
+/// it fills in the shader's own coordinates, and its output is then used as a
+/// repeating bitmap fill. The output always starts at the shader's origin, so
+/// parts of a path at negative shader coordinates show a repeated copy of the output
+/// instead of what the shader would compute there.
+#[derive(Debug, Clone)]
+struct ShaderFill {
+    shader: PixelBenderShaderHandle,
+    arguments: Vec<PixelBenderShaderArgument<'static>>,
+    size: OnceCell<BitmapSize>,
+    bitmap: OnceCell<Option<BitmapHandle>>,
+}
+
+impl ShaderFill {
+    /// Runs the shader into a new bitmap of the given size.
+    ///
+    /// Returns `None` if the renderer is unable to run the shader.
+    fn render(&self, renderer: &mut dyn RenderBackend, size: BitmapSize) -> Option<BitmapHandle> {
+        let pixels = vec![0; size.width as usize * size.height as usize * 4];
+        let bitmap = Bitmap::new(size.width, size.height, BitmapFormat::Rgba, pixels);
+        let handle = match renderer.register_bitmap(bitmap) {
+            Ok(handle) => handle,
+            Err(e) => {
+                tracing::warn!("Failed to register shader fill bitmap: {e}");
+                return None;
+            }
+        };
+
+        let target = PixelBenderTarget::Bitmap(handle.clone());
+        if let Err(e) =
+            renderer.run_pixelbender_shader(self.shader.clone(), &self.arguments, &target)
+        {
+            tracing::warn!("Failed to run shader fill: {e}");
+            return None;
+        }
+        Some(handle)
     }
 }
 
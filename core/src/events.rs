@@ -33,6 +33,33 @@ pub enum PlayerEvent {
     MouseWheel {
         delta: MouseWheelDelta,
     },
+
+    /// A finger touched the screen.
+    ///
+    /// `id` identifies the touch point until it's lifted. Taps and gestures
+    /// are recognized by the player from these events.
+    TouchBegin {
+        id: u32,
+        x: f64,
+        y: f64,
+    },
+    TouchMove {
+        id: u32,
+        x: f64,
+        y: f64,
+    },
+    TouchEnd {
+        id: u32,
+        x: f64,
+        y: f64,
+    },
+
+    /// The system took over a touch point, e.g. to perform a system gesture.
+    ///
+    /// The touch point is lifted without being considered a tap.
+    TouchCancel {
+        id: u32,
+    },
     GamepadButtonDown {
         button: GamepadButton,
     },
@@ -50,6 +77,20 @@ pub enum PlayerEvent {
     FocusLost,
 }
 
+/// An event from a window of an AIR application, see `Player::native_window_event`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NativeWindowEvent {
+    /// The window was moved, resized, minimized, maximized or restored.
+    ///
+    /// The player asks the UI backend for the new bounds and display state.
+    BoundsChanged,
+    Activated,
+    Deactivated,
+
+    /// The user asked to close the window, which the movie may prevent.
+    CloseRequested,
+}
+
 /// The distance scrolled by the mouse wheel.
 #[derive(Debug, Clone, Copy)]
 pub enum MouseWheelDelta {
@@ -1189,6 +1230,12 @@ pub enum KeyLocation {
 #[derive(Debug, Clone)]
 pub enum PlayerNotification {
     ImeNotification(ImeNotification),
+
+    /// An AIR application called `NativeApplication.exit`, or closed its last window.
+    /// The process should exit with the given code, without asking the movie again.
+    ExitRequested {
+        exit_code: i32,
+    },
 }
 
 #[derive(Debug, Clone)]
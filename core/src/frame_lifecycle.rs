@@ -15,6 +15,7 @@ use crate::avm2::{Avm2, EventObject};
 use crate::context::UpdateContext;
 use crate::display_object::{DisplayObject, MovieClip, TDisplayObject};
 use crate::loader::LoadManager;
+use crate::native_window::NativeWindows;
 use crate::orphan_manager::OrphanManager;
 use tracing::instrument;
 
@@ -82,12 +83,18 @@ pub fn run_all_phases_avm2(context: &mut UpdateContext<'_>) {
         orphan.enter_frame(context);
     });
     stage.enter_frame(context);
+    NativeWindows::each_window_child(context, |child, context| {
+        child.enter_frame(context);
+    });
 
     *context.frame_phase = FramePhase::Construct;
     OrphanManager::each_orphan_obj(context, |orphan, context| {
         orphan.construct_frame(context);
     });
     stage.construct_frame(context);
+    NativeWindows::each_window_child(context, |child, context| {
+        child.construct_frame(context);
+    });
     broadcast_frame_constructed(context);
 
     *context.frame_phase = FramePhase::FrameScripts;
@@ -95,6 +102,9 @@ pub fn run_all_phases_avm2(context: &mut UpdateContext<'_>) {
         orphan.run_frame_scripts(context);
     });
     stage.run_frame_scripts(context);
+    NativeWindows::each_window_child(context, |child, context| {
+        child.run_frame_scripts(context);
+    });
     run_frame_script_cleanup(context);
 
     *context.frame_phase = FramePhase::Exit;
@@ -1485,6 +1485,18 @@ impl FormatSpans {
         self.normalize();
     }
 
+    /// Copy the text in the range [from, to), along with its formatting.
+    pub fn slice(&self, from: usize, to: usize) -> Self {
+        let mut sliced = self.clone();
+        if to < sliced.text.len() {
+            sliced.replace_text(to, sliced.text.len(), WStr::empty());
+        }
+        if from > 0 {
+            sliced.replace_text(0, from, WStr::empty());
+        }
+        sliced
+    }
+
     /// Iterate over all text spans in the current list of format spans.
     ///
     /// The iterator returned by this function yields a tuple for each span,
@@ -226,6 +226,13 @@ impl InputManager {
 
             // The following are not input events.
             PlayerEvent::FocusGained | PlayerEvent::FocusLost => return None,
+
+            // Touch events are handled separately, and drive the mouse through
+            // mouse events.
+            PlayerEvent::TouchBegin { .. }
+            | PlayerEvent::TouchMove { .. }
+            | PlayerEvent::TouchEnd { .. }
+            | PlayerEvent::TouchCancel { .. } => return None,
         };
 
         self.handle_event(&event);
@@ -18,6 +18,7 @@ mod avm2;
 mod avm_rng;
 mod binary_data;
 pub mod bitmap;
+mod camera;
 mod character;
 pub mod context;
 pub mod context_menu;
@@ -34,19 +35,26 @@ pub mod limits;
 pub mod loader;
 mod local_connection;
 mod locale;
+mod microphone;
+mod native_application;
+pub mod native_process;
+mod native_window;
 mod net_connection;
 mod orphan_manager;
 pub mod pixel_bender;
 mod player;
 mod prelude;
+mod print_job;
 pub mod socket;
 mod streams;
 pub mod string;
 mod system_properties;
 pub mod tag_utils;
 pub mod timer;
+mod touch;
 mod types;
 mod vminterface;
+mod worker;
 
 pub mod backend;
 pub mod compatibility_rules;
@@ -57,6 +65,7 @@ pub mod external;
 pub mod i18n;
 pub mod stub;
 
+pub use avm2::debugger::DebuggerConnection;
 pub use context_menu::ContextMenuItem;
 pub use events::PlayerEvent;
 pub use indexmap;
@@ -1377,8 +1377,8 @@ pub fn load_sound_avm2<'gc>(
             let sound = sound.fetch(uc);
             let sound_object = Avm2Object::from(sound);
 
-            if sound.loading_state() == SoundLoadingState::Loaded {
-                // Sound has already been loaded.
+            if sound.loading_state() != SoundLoadingState::Loading {
+                // Sound has already been loaded, or the download was cancelled by `Sound.close`.
                 return Ok(());
             }
 
@@ -1,4 +1,11 @@
+pub mod cldr;
+pub mod collation;
+pub mod date_format;
+pub mod number_format;
+
+use crate::backend::ui::LanguageIdentifier;
 use chrono::{DateTime, FixedOffset, Local, Offset, TimeZone, Utc};
+use cldr::{LOCALES, LocaleData};
 
 // For tests, we emulate being in Nepal with a local time of 2001-02-03 at 04:05:06.
 // Nepal has a timezone offset of +5:45, and has never used DST.
@@ -24,3 +31,183 @@ pub fn get_timezone() -> FixedOffset {
         Local::now().offset().fix()
     }
 }
+
+/// The name of the default locale of the user, see `LocaleID.DEFAULT`.
+pub const DEFAULT_LOCALE_NAME: &str = "i-default";
+
+/// How well the locale picked by [`resolve_locale`] matches the requested one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocaleMatch {
+    /// The requested locale is supported.
+    Exact,
+
+    /// Only the language of the requested locale is supported.
+    Fallback,
+
+    /// Nothing about the requested locale is supported, so the default
+    /// locale of the user was picked instead.
+    Default,
+}
+
+impl LocaleMatch {
+    /// The `LastOperationStatus` reported after picking a locale.
+    pub fn status(self) -> &'static str {
+        match self {
+            LocaleMatch::Exact => "noError",
+            LocaleMatch::Fallback => "usingFallbackWarning",
+            LocaleMatch::Default => "usingDefaultWarning",
+        }
+    }
+}
+
+/// A parsed locale ID name, such as `zh-Hant-TW@collation=stroke`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LocaleId {
+    pub language: String,
+    pub script: String,
+    pub region: String,
+    pub variant: String,
+    pub keywords: Vec<(String, String)>,
+}
+
+impl LocaleId {
+    pub fn parse(name: &str) -> Self {
+        let (tags, keywords) = name.split_once('@').unwrap_or((name, ""));
+
+        let mut id = LocaleId::default();
+        let mut subtags = tags.split(['-', '_']).filter(|tag| !tag.is_empty());
+        if let Some(language) = subtags.next() {
+            id.language = language.to_ascii_lowercase();
+        }
+
+        let mut variants = Vec::new();
+        for tag in subtags {
+            let is_alpha = tag.chars().all(|c| c.is_ascii_alphabetic());
+            let is_digit = tag.chars().all(|c| c.is_ascii_digit());
+            if id.script.is_empty()
+                && id.region.is_empty()
+                && variants.is_empty()
+                && tag.len() == 4
+                && is_alpha
+            {
+                let mut script = tag.to_ascii_lowercase();
+                script[..1].make_ascii_uppercase();
+                id.script = script;
+            } else if id.region.is_empty()
+                && variants.is_empty()
+                && ((tag.len() == 2 && is_alpha) || (tag.len() == 3 && is_digit))
+            {
+                id.region = tag.to_ascii_uppercase();
+            } else {
+                variants.push(tag);
+            }
+        }
+        id.variant = variants.join("_").to_ascii_uppercase();
+
+        id.keywords = keywords
+            .split(';')
+            .filter_map(|keyword| {
+                let (key, value) = keyword.split_once('=')?;
+                Some((key.trim().to_string(), value.trim().to_string()))
+            })
+            .collect();
+
+        id
+    }
+
+    pub fn is_right_to_left(&self) -> bool {
+        cldr::is_right_to_left(&self.language, &self.script)
+    }
+}
+
+fn find_locale(requested: &LocaleId) -> Option<(&'static LocaleData, LocaleMatch)> {
+    if requested.language.is_empty() {
+        return None;
+    }
+
+    let mut region = requested.region.as_str();
+    if region.is_empty() && requested.language == "zh" {
+        // Chinese is the only supported language where the script decides the region.
+        region = if requested.script == "Hant" {
+            "TW"
+        } else {
+            "CN"
+        };
+    }
+
+    let name = format!("{}-{region}", requested.language);
+    if let Some(data) = LOCALES
+        .iter()
+        .find(|data| data.name.eq_ignore_ascii_case(&name))
+    {
+        let status = if requested.region.is_empty() && requested.language != "zh" {
+            LocaleMatch::Fallback
+        } else {
+            LocaleMatch::Exact
+        };
+        return Some((data, status));
+    }
+
+    // The first locale of a language in the table is the default for that language.
+    LOCALES
+        .iter()
+        .find(|data| data.language() == requested.language)
+        .map(|data| (data, LocaleMatch::Fallback))
+}
+
+/// Pick the locale data to use for the requested locale ID name.
+pub fn resolve_locale(
+    requested: &str,
+    system_language: &LanguageIdentifier,
+) -> (&'static LocaleData, LocaleMatch) {
+    let system = find_locale(&LocaleId::parse(&system_language.to_string()))
+        .map(|(data, _)| data)
+        .unwrap_or(&LOCALES[0]);
+
+    if requested.eq_ignore_ascii_case(DEFAULT_LOCALE_NAME) {
+        return (system, LocaleMatch::Exact);
+    }
+
+    find_locale(&LocaleId::parse(requested)).unwrap_or((system, LocaleMatch::Default))
+}
+
+/// The names of all locales with locale data.
+pub fn available_locale_names() -> impl Iterator<Item = &'static str> {
+    LOCALES.iter().map(|data| data.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluent_templates::langid;
+
+    #[test]
+    fn parse_locale_id() {
+        let id = LocaleId::parse("zh-hant_tw@collation=stroke; calendar=chinese");
+        assert_eq!(id.language, "zh");
+        assert_eq!(id.script, "Hant");
+        assert_eq!(id.region, "TW");
+        assert_eq!(
+            id.keywords,
+            vec![
+                ("collation".to_string(), "stroke".to_string()),
+                ("calendar".to_string(), "chinese".to_string())
+            ]
+        );
+        assert!(!id.is_right_to_left());
+        assert!(LocaleId::parse("ar-EG").is_right_to_left());
+    }
+
+    #[test]
+    fn resolve() {
+        let system = langid!("de-DE");
+        assert_eq!(resolve_locale("fr-FR", &system).0.name, "fr-FR");
+        assert_eq!(resolve_locale("fr-FR", &system).1, LocaleMatch::Exact);
+        assert_eq!(resolve_locale("fr-CA", &system).0.name, "fr-FR");
+        assert_eq!(resolve_locale("fr-CA", &system).1, LocaleMatch::Fallback);
+        assert_eq!(resolve_locale("xx-YY", &system).0.name, "de-DE");
+        assert_eq!(resolve_locale("xx-YY", &system).1, LocaleMatch::Default);
+        assert_eq!(resolve_locale("i-default", &system).0.name, "de-DE");
+        assert_eq!(resolve_locale("zh-Hant", &system).0.name, "zh-TW");
+    }
+}
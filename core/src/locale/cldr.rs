@@ -0,0 +1,866 @@
+//! Locale data used by `flash.globalization`.
+//!
+//! The values are taken from the Unicode CLDR, adjusted where Flash Player
+//! reports something different (Flash uses the data of the operating system).
+
+/// The formatting conventions of a single locale.
+pub struct LocaleData {
+    /// The name of the locale, as reported by `actualLocaleIDName`.
+    pub name: &'static str,
+
+    pub decimal_separator: &'static str,
+    pub grouping_separator: &'static str,
+    pub grouping_pattern: &'static str,
+    pub negative_symbol: &'static str,
+    pub negative_number_format: u32,
+
+    pub currency_iso_code: &'static str,
+    pub currency_symbol: &'static str,
+    pub currency_fractional_digits: i32,
+    pub positive_currency_format: u32,
+    pub negative_currency_format: u32,
+
+    /// Month names as used on their own, e.g. in a calendar header.
+    pub months: [&'static str; 12],
+
+    /// Month names as used within a date, if they differ from `months`.
+    pub months_format: Option<[&'static str; 12]>,
+
+    pub months_abbr: [&'static str; 12],
+    pub weekdays: [&'static str; 7],
+    pub weekdays_abbr: [&'static str; 7],
+    pub am_pm: [&'static str; 2],
+    pub eras: [&'static str; 2],
+
+    /// Date patterns for the long, medium and short styles.
+    pub date_patterns: [&'static str; 3],
+
+    /// Time patterns for the long, medium and short styles.
+    pub time_patterns: [&'static str; 3],
+
+    /// The first day of the week, where 0 is Sunday.
+    pub first_weekday: u32,
+}
+
+impl LocaleData {
+    pub fn language(&self) -> &'static str {
+        self.name.split('-').next().unwrap_or(self.name)
+    }
+}
+
+const LATIN_ERAS: [&str; 2] = ["BC", "AD"];
+
+pub static LOCALES: &[LocaleData] = &[
+    LocaleData {
+        name: "en-US",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "USD",
+        currency_symbol: "$",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        months_format: None,
+        months_abbr: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekdays: [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ],
+        weekdays_abbr: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        am_pm: ["AM", "PM"],
+        eras: LATIN_ERAS,
+        date_patterns: ["EEEE, MMMM d, yyyy", "MMM d, yyyy", "M/d/yy"],
+        time_patterns: ["h:mm:ss a", "h:mm:ss a", "h:mm a"],
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "en-GB",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "GBP",
+        currency_symbol: "£",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        months_format: None,
+        months_abbr: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekdays: [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ],
+        weekdays_abbr: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        am_pm: ["am", "pm"],
+        eras: LATIN_ERAS,
+        date_patterns: ["EEEE, d MMMM yyyy", "d MMM yyyy", "dd/MM/yyyy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "de-DE",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        months_format: None,
+        months_abbr: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        weekdays: [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        weekdays_abbr: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+        am_pm: ["AM", "PM"],
+        eras: ["v. Chr.", "n. Chr."],
+        date_patterns: ["EEEE, d. MMMM yyyy", "dd.MM.yyyy", "dd.MM.yy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "fr-FR",
+        decimal_separator: ",",
+        grouping_separator: "\u{a0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        months_format: None,
+        months_abbr: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+        ],
+        weekdays_abbr: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+        am_pm: ["AM", "PM"],
+        eras: ["av. J.-C.", "ap. J.-C."],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "dd/MM/yyyy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "es-ES",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        months_format: None,
+        months_abbr: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ],
+        weekdays_abbr: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+        am_pm: ["a. m.", "p. m."],
+        eras: ["a. C.", "d. C."],
+        date_patterns: ["EEEE, d 'de' MMMM 'de' yyyy", "d MMM yyyy", "d/M/yy"],
+        time_patterns: ["H:mm:ss", "H:mm:ss", "H:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "it-IT",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        months_format: None,
+        months_abbr: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        weekdays: [
+            "domenica",
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+        ],
+        weekdays_abbr: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+        am_pm: ["AM", "PM"],
+        eras: ["a.C.", "d.C."],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "dd/MM/yy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "pt-BR",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "BRL",
+        currency_symbol: "R$",
+        currency_fractional_digits: 2,
+        positive_currency_format: 2,
+        negative_currency_format: 9,
+        months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        months_format: None,
+        months_abbr: [
+            "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+        ],
+        weekdays: [
+            "domingo",
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+        ],
+        weekdays_abbr: ["dom", "seg", "ter", "qua", "qui", "sex", "sáb"],
+        am_pm: ["AM", "PM"],
+        eras: ["a.C.", "d.C."],
+        date_patterns: [
+            "EEEE, d 'de' MMMM 'de' yyyy",
+            "d 'de' MMM 'de' yyyy",
+            "dd/MM/yyyy",
+        ],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "nl-NL",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 2,
+        negative_currency_format: 12,
+        months: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        months_format: None,
+        months_abbr: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        weekdays: [
+            "zondag",
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+        ],
+        weekdays_abbr: ["zo", "ma", "di", "wo", "do", "vr", "za"],
+        am_pm: ["a.m.", "p.m."],
+        eras: ["v.Chr.", "n.Chr."],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "dd-MM-yyyy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "sv-SE",
+        decimal_separator: ",",
+        grouping_separator: "\u{a0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "SEK",
+        currency_symbol: "kr",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "januari",
+            "februari",
+            "mars",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "augusti",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        months_format: None,
+        months_abbr: [
+            "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ],
+        weekdays: [
+            "söndag", "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag",
+        ],
+        weekdays_abbr: ["sön", "mån", "tis", "ons", "tors", "fre", "lör"],
+        am_pm: ["fm", "em"],
+        eras: ["f.Kr.", "e.Kr."],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "yyyy-MM-dd"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "pl-PL",
+        decimal_separator: ",",
+        grouping_separator: "\u{a0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "PLN",
+        currency_symbol: "zł",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "styczeń",
+            "luty",
+            "marzec",
+            "kwiecień",
+            "maj",
+            "czerwiec",
+            "lipiec",
+            "sierpień",
+            "wrzesień",
+            "październik",
+            "listopad",
+            "grudzień",
+        ],
+        months_format: Some([
+            "stycznia",
+            "lutego",
+            "marca",
+            "kwietnia",
+            "maja",
+            "czerwca",
+            "lipca",
+            "sierpnia",
+            "września",
+            "października",
+            "listopada",
+            "grudnia",
+        ]),
+        months_abbr: [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ],
+        weekdays: [
+            "niedziela",
+            "poniedziałek",
+            "wtorek",
+            "środa",
+            "czwartek",
+            "piątek",
+            "sobota",
+        ],
+        weekdays_abbr: ["niedz.", "pon.", "wt.", "śr.", "czw.", "pt.", "sob."],
+        am_pm: ["AM", "PM"],
+        eras: ["p.n.e.", "n.e."],
+        date_patterns: ["EEEE, d MMMM yyyy", "d MMM yyyy", "dd.MM.yyyy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "cs-CZ",
+        decimal_separator: ",",
+        grouping_separator: "\u{a0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "CZK",
+        currency_symbol: "Kč",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "leden",
+            "únor",
+            "březen",
+            "duben",
+            "květen",
+            "červen",
+            "červenec",
+            "srpen",
+            "září",
+            "říjen",
+            "listopad",
+            "prosinec",
+        ],
+        months_format: Some([
+            "ledna",
+            "února",
+            "března",
+            "dubna",
+            "května",
+            "června",
+            "července",
+            "srpna",
+            "září",
+            "října",
+            "listopadu",
+            "prosince",
+        ]),
+        months_abbr: [
+            "led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro",
+        ],
+        weekdays: [
+            "neděle",
+            "pondělí",
+            "úterý",
+            "středa",
+            "čtvrtek",
+            "pátek",
+            "sobota",
+        ],
+        weekdays_abbr: ["ne", "po", "út", "st", "čt", "pá", "so"],
+        am_pm: ["dop.", "odp."],
+        eras: ["př. n. l.", "n. l."],
+        date_patterns: ["EEEE d. MMMM yyyy", "d. M. yyyy", "dd.MM.yy"],
+        time_patterns: ["H:mm:ss", "H:mm:ss", "H:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "ru-RU",
+        decimal_separator: ",",
+        grouping_separator: "\u{a0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "RUB",
+        currency_symbol: "₽",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        months: [
+            "январь",
+            "февраль",
+            "март",
+            "апрель",
+            "май",
+            "июнь",
+            "июль",
+            "август",
+            "сентябрь",
+            "октябрь",
+            "ноябрь",
+            "декабрь",
+        ],
+        months_format: Some([
+            "января",
+            "февраля",
+            "марта",
+            "апреля",
+            "мая",
+            "июня",
+            "июля",
+            "августа",
+            "сентября",
+            "октября",
+            "ноября",
+            "декабря",
+        ]),
+        months_abbr: [
+            "янв.",
+            "февр.",
+            "мар.",
+            "апр.",
+            "мая",
+            "июн.",
+            "июл.",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+        weekdays: [
+            "воскресенье",
+            "понедельник",
+            "вторник",
+            "среда",
+            "четверг",
+            "пятница",
+            "суббота",
+        ],
+        weekdays_abbr: ["вс", "пн", "вт", "ср", "чт", "пт", "сб"],
+        am_pm: ["AM", "PM"],
+        eras: ["до н. э.", "н. э."],
+        date_patterns: ["EEEE, d MMMM yyyy 'г'.", "d MMM yyyy 'г'.", "dd.MM.yyyy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "tr-TR",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "TRY",
+        currency_symbol: "₺",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        months: [
+            "Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül",
+            "Ekim", "Kasım", "Aralık",
+        ],
+        months_format: None,
+        months_abbr: [
+            "Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara",
+        ],
+        weekdays: [
+            "Pazar",
+            "Pazartesi",
+            "Salı",
+            "Çarşamba",
+            "Perşembe",
+            "Cuma",
+            "Cumartesi",
+        ],
+        weekdays_abbr: ["Paz", "Pzt", "Sal", "Çar", "Per", "Cum", "Cmt"],
+        am_pm: ["ÖÖ", "ÖS"],
+        eras: ["MÖ", "MS"],
+        date_patterns: ["d MMMM yyyy EEEE", "d MMM yyyy", "d.MM.yyyy"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "ja-JP",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "JPY",
+        currency_symbol: "¥",
+        currency_fractional_digits: 0,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        months_format: None,
+        months_abbr: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "日曜日",
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+        ],
+        weekdays_abbr: ["日", "月", "火", "水", "木", "金", "土"],
+        am_pm: ["午前", "午後"],
+        eras: ["紀元前", "西暦"],
+        date_patterns: ["yyyy'年'M'月'd'日'EEEE", "yyyy/MM/dd", "yyyy/MM/dd"],
+        time_patterns: ["H:mm:ss", "H:mm:ss", "H:mm"],
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "zh-CN",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "CNY",
+        currency_symbol: "¥",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        months_format: None,
+        months_abbr: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "星期日",
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+        ],
+        weekdays_abbr: ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+        am_pm: ["上午", "下午"],
+        eras: ["公元前", "公元"],
+        date_patterns: ["yyyy'年'M'月'd'日'EEEE", "yyyy'年'M'月'd'日'", "yyyy/M/d"],
+        time_patterns: ["HH:mm:ss", "HH:mm:ss", "HH:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "zh-TW",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "TWD",
+        currency_symbol: "NT$",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        months_format: None,
+        months_abbr: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "星期日",
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+        ],
+        weekdays_abbr: ["週日", "週一", "週二", "週三", "週四", "週五", "週六"],
+        am_pm: ["上午", "下午"],
+        eras: ["西元前", "西元"],
+        date_patterns: ["yyyy'年'M'月'd'日' EEEE", "yyyy'年'M'月'd'日'", "yyyy/M/d"],
+        time_patterns: ["a h:mm:ss", "a h:mm:ss", "a h:mm"],
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "ko-KR",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "KRW",
+        currency_symbol: "₩",
+        currency_fractional_digits: 0,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        months: [
+            "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+        ],
+        months_format: None,
+        months_abbr: [
+            "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+        ],
+        weekdays: [
+            "일요일",
+            "월요일",
+            "화요일",
+            "수요일",
+            "목요일",
+            "금요일",
+            "토요일",
+        ],
+        weekdays_abbr: ["일", "월", "화", "수", "목", "금", "토"],
+        am_pm: ["오전", "오후"],
+        eras: ["기원전", "서기"],
+        date_patterns: ["yyyy'년' M'월' d'일' EEEE", "yyyy. M. d.", "yy. M. d."],
+        time_patterns: ["a h:mm:ss", "a h:mm:ss", "a h:mm"],
+        first_weekday: 0,
+    },
+];
+
+/// Languages that are written from right to left.
+const RIGHT_TO_LEFT_LANGUAGES: &[&str] = &["ar", "dv", "fa", "he", "iw", "ps", "ur", "yi"];
+
+/// Scripts that are written from right to left.
+const RIGHT_TO_LEFT_SCRIPTS: &[&str] = &["Arab", "Hebr", "Syrc", "Thaa"];
+
+pub fn is_right_to_left(language: &str, script: &str) -> bool {
+    if !script.is_empty() {
+        return RIGHT_TO_LEFT_SCRIPTS
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(script));
+    }
+
+    RIGHT_TO_LEFT_LANGUAGES
+        .iter()
+        .any(|l| l.eq_ignore_ascii_case(language))
+}
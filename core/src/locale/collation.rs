@@ -0,0 +1,489 @@
+//! String comparison and case mapping, as done by `flash.globalization.Collator`
+//! and `flash.globalization.StringTools`.
+//!
+//! This is a small approximation of the Unicode Collation Algorithm: strings
+//! are compared by their letters first, then by their accents, and then by
+//! their case, width and kana type. Languages whose alphabets sort some
+//! accented letters as separate letters are tailored accordingly.
+
+use std::cmp::Ordering;
+
+/// The options of a `Collator`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CollatorOptions {
+    pub ignore_case: bool,
+    pub ignore_character_width: bool,
+    pub ignore_diacritics: bool,
+    pub ignore_kana_type: bool,
+    pub ignore_symbols: bool,
+    pub numeric_comparison: bool,
+}
+
+impl CollatorOptions {
+    /// The initial options of a collator in `CollatorMode.MATCHING`.
+    pub fn matching() -> Self {
+        Self {
+            ignore_case: true,
+            ignore_character_width: true,
+            ignore_diacritics: true,
+            ignore_kana_type: true,
+            ..Default::default()
+        }
+    }
+}
+
+const TERTIARY_UPPERCASE: u8 = 1 << 0;
+const TERTIARY_WIDE: u8 = 1 << 1;
+const TERTIARY_KATAKANA: u8 = 1 << 2;
+
+const CLASS_SYMBOL: u64 = 1 << 40;
+const CLASS_NUMBER: u64 = 2 << 40;
+const CLASS_LETTER: u64 = 3 << 40;
+
+#[derive(Clone, Copy, Debug)]
+struct Element {
+    primary: u64,
+    secondary: u8,
+    tertiary: u8,
+}
+
+#[derive(Clone, Copy)]
+enum Accent {
+    Grave = 1,
+    Acute,
+    Circumflex,
+    Tilde,
+    Diaeresis,
+    Ring,
+    Cedilla,
+    Caron,
+    Macron,
+    Breve,
+    Ogonek,
+    Dot,
+    DoubleAcute,
+    Stroke,
+}
+
+/// Precomposed lowercase letters of Latin-1 and Latin Extended-A.
+///
+/// Uppercase letters are lowercased before looking them up here.
+const ACCENTED_LETTERS: &[(char, char, Accent)] = {
+    use Accent::*;
+    &[
+        ('à', 'a', Grave),
+        ('á', 'a', Acute),
+        ('â', 'a', Circumflex),
+        ('ã', 'a', Tilde),
+        ('ä', 'a', Diaeresis),
+        ('å', 'a', Ring),
+        ('ā', 'a', Macron),
+        ('ă', 'a', Breve),
+        ('ą', 'a', Ogonek),
+        ('ç', 'c', Cedilla),
+        ('ć', 'c', Acute),
+        ('ĉ', 'c', Circumflex),
+        ('ċ', 'c', Dot),
+        ('č', 'c', Caron),
+        ('ď', 'd', Caron),
+        ('đ', 'd', Stroke),
+        ('è', 'e', Grave),
+        ('é', 'e', Acute),
+        ('ê', 'e', Circumflex),
+        ('ë', 'e', Diaeresis),
+        ('ē', 'e', Macron),
+        ('ĕ', 'e', Breve),
+        ('ė', 'e', Dot),
+        ('ę', 'e', Ogonek),
+        ('ě', 'e', Caron),
+        ('ĝ', 'g', Circumflex),
+        ('ğ', 'g', Breve),
+        ('ġ', 'g', Dot),
+        ('ģ', 'g', Cedilla),
+        ('ĥ', 'h', Circumflex),
+        ('ħ', 'h', Stroke),
+        ('ì', 'i', Grave),
+        ('í', 'i', Acute),
+        ('î', 'i', Circumflex),
+        ('ï', 'i', Diaeresis),
+        ('ĩ', 'i', Tilde),
+        ('ī', 'i', Macron),
+        ('ĭ', 'i', Breve),
+        ('į', 'i', Ogonek),
+        ('ĵ', 'j', Circumflex),
+        ('ķ', 'k', Cedilla),
+        ('ĺ', 'l', Acute),
+        ('ļ', 'l', Cedilla),
+        ('ľ', 'l', Caron),
+        ('ŀ', 'l', Dot),
+        ('ł', 'l', Stroke),
+        ('ñ', 'n', Tilde),
+        ('ń', 'n', Acute),
+        ('ņ', 'n', Cedilla),
+        ('ň', 'n', Caron),
+        ('ò', 'o', Grave),
+        ('ó', 'o', Acute),
+        ('ô', 'o', Circumflex),
+        ('õ', 'o', Tilde),
+        ('ö', 'o', Diaeresis),
+        ('ø', 'o', Stroke),
+        ('ō', 'o', Macron),
+        ('ŏ', 'o', Breve),
+        ('ő', 'o', DoubleAcute),
+        ('ŕ', 'r', Acute),
+        ('ŗ', 'r', Cedilla),
+        ('ř', 'r', Caron),
+        ('ś', 's', Acute),
+        ('ŝ', 's', Circumflex),
+        ('ş', 's', Cedilla),
+        ('š', 's', Caron),
+        ('ţ', 't', Cedilla),
+        ('ť', 't', Caron),
+        ('ŧ', 't', Stroke),
+        ('ù', 'u', Grave),
+        ('ú', 'u', Acute),
+        ('û', 'u', Circumflex),
+        ('ü', 'u', Diaeresis),
+        ('ũ', 'u', Tilde),
+        ('ū', 'u', Macron),
+        ('ŭ', 'u', Breve),
+        ('ů', 'u', Ring),
+        ('ű', 'u', DoubleAcute),
+        ('ų', 'u', Ogonek),
+        ('ŵ', 'w', Circumflex),
+        ('ý', 'y', Acute),
+        ('ÿ', 'y', Diaeresis),
+        ('ŷ', 'y', Circumflex),
+        ('ź', 'z', Acute),
+        ('ż', 'z', Dot),
+        ('ž', 'z', Caron),
+    ]
+};
+
+/// Letters that sort as a sequence of other letters.
+const EXPANSIONS: &[(char, &str)] = &[('ß', "ss"), ('æ', "ae"), ('œ', "oe"), ('ĳ', "ij")];
+
+/// Letters that a language sorts as separate letters after a base letter,
+/// rather than as an accented form of it.
+fn tailoring(language: &str) -> &'static [(char, char)] {
+    match language {
+        "sv" | "fi" => &[('å', 'z'), ('ä', 'z'), ('ö', 'z')],
+        "da" | "nb" | "nn" | "no" => &[('æ', 'z'), ('ø', 'z'), ('å', 'z')],
+        "es" => &[('ñ', 'n')],
+        "pl" => &[
+            ('ą', 'a'),
+            ('ć', 'c'),
+            ('ę', 'e'),
+            ('ł', 'l'),
+            ('ń', 'n'),
+            ('ó', 'o'),
+            ('ś', 's'),
+            ('ź', 'z'),
+            ('ż', 'z'),
+        ],
+        "cs" | "sk" => &[('č', 'c'), ('ř', 'r'), ('š', 's'), ('ž', 'z')],
+        "tr" | "az" => &[
+            ('ç', 'c'),
+            ('ğ', 'g'),
+            ('ı', 'h'),
+            ('ö', 'o'),
+            ('ş', 's'),
+            ('ü', 'u'),
+        ],
+        _ => &[],
+    }
+}
+
+fn letter_primary(base: char, tailoring: u64) -> u64 {
+    CLASS_LETTER | (base as u64) << 8 | tailoring
+}
+
+/// Whether a language uses the Turkish rules for dotted and dotless i.
+fn has_turkic_casing(language: &str) -> bool {
+    matches!(language, "tr" | "az")
+}
+
+pub fn to_lower_case(language: &str, text: &str) -> String {
+    if !has_turkic_casing(language) {
+        return text.to_lowercase();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            'I' if chars.peek() == Some(&'\u{307}') => {
+                chars.next();
+                result.push('i');
+            }
+            'I' => result.push('ı'),
+            'İ' => result.push('i'),
+            c => result.extend(c.to_lowercase()),
+        }
+    }
+    result
+}
+
+pub fn to_upper_case(language: &str, text: &str) -> String {
+    if !has_turkic_casing(language) {
+        return text.to_uppercase();
+    }
+
+    text.chars()
+        .flat_map(|c| match c {
+            'i' => either::Either::Left(std::iter::once('İ')),
+            c => either::Either::Right(c.to_uppercase()),
+        })
+        .collect()
+}
+
+struct ElementBuilder<'a> {
+    language: &'a str,
+    options: &'a CollatorOptions,
+    elements: Vec<Element>,
+}
+
+impl ElementBuilder<'_> {
+    fn push_letter(&mut self, c: char, mut tertiary: u8) {
+        let lower = if has_turkic_casing(self.language) && c == 'I' {
+            'ı'
+        } else if c == 'İ' {
+            // Outside of Turkish, this is a capital I with a dot above.
+            let accent = if has_turkic_casing(self.language) {
+                0
+            } else {
+                Accent::Dot as u8
+            };
+            self.push_element(
+                letter_primary('i', 0),
+                accent,
+                tertiary | TERTIARY_UPPERCASE,
+            );
+            return;
+        } else {
+            c.to_lowercase().next().unwrap_or(c)
+        };
+        if lower != c {
+            tertiary |= TERTIARY_UPPERCASE;
+        }
+
+        let tailored = tailoring(self.language)
+            .iter()
+            .position(|(letter, _)| *letter == lower);
+        if let Some(position) = tailored {
+            let base = tailoring(self.language)[position].1;
+            self.push_element(letter_primary(base, position as u64 + 1), 0, tertiary);
+        } else if let Some(&(_, expansion)) = EXPANSIONS.iter().find(|(c, _)| *c == lower) {
+            for base in expansion.chars() {
+                self.push_element(letter_primary(base, 0), 0, tertiary);
+            }
+        } else if let Some(&(_, base, accent)) =
+            ACCENTED_LETTERS.iter().find(|(c, _, _)| *c == lower)
+        {
+            self.push_element(letter_primary(base, 0), accent as u8, tertiary);
+        } else {
+            self.push_element(letter_primary(lower, 0), 0, tertiary);
+        }
+    }
+
+    fn push_element(&mut self, primary: u64, secondary: u8, tertiary: u8) {
+        let options = self.options;
+        let mut tertiary = tertiary;
+        if options.ignore_case {
+            tertiary &= !TERTIARY_UPPERCASE;
+        }
+        if options.ignore_character_width {
+            tertiary &= !TERTIARY_WIDE;
+        }
+        if options.ignore_kana_type {
+            tertiary &= !TERTIARY_KATAKANA;
+        }
+
+        self.elements.push(Element {
+            primary,
+            secondary: if options.ignore_diacritics {
+                0
+            } else {
+                secondary
+            },
+            tertiary,
+        });
+    }
+
+    fn push_number(&mut self, digits: &str, tertiary: u8) {
+        if !self.options.numeric_comparison {
+            for digit in digits.chars() {
+                self.push_element(CLASS_NUMBER | digit as u64, 0, tertiary);
+            }
+            return;
+        }
+
+        // Longer numbers are larger, so sort by the length first.
+        let digits = digits.trim_start_matches('0');
+        self.push_element(CLASS_NUMBER | (digits.len() as u64) << 8, 0, tertiary);
+        for digit in digits.chars() {
+            self.push_element(CLASS_NUMBER | digit as u64, 0, tertiary);
+        }
+    }
+
+    fn build(mut self, text: &str) -> Vec<Element> {
+        let mut chars = text.chars().peekable();
+        let mut digits = String::new();
+        let mut digits_tertiary = 0;
+
+        while let Some(c) = chars.next() {
+            let mut tertiary = 0;
+            let mut c = c;
+
+            // Fullwidth forms of ASCII.
+            if ('\u{ff01}'..='\u{ff5e}').contains(&c) {
+                c = char::from_u32(c as u32 - 0xfee0).unwrap_or(c);
+                tertiary |= TERTIARY_WIDE;
+            }
+
+            // Katakana sorts with the corresponding hiragana.
+            if ('\u{30a1}'..='\u{30f6}').contains(&c) {
+                c = char::from_u32(c as u32 - 0x60).unwrap_or(c);
+                tertiary |= TERTIARY_KATAKANA;
+            }
+
+            if let Some(digit) = c.to_digit(10) {
+                if digits.is_empty() {
+                    digits_tertiary = tertiary;
+                }
+                digits.push(char::from_digit(digit, 10).unwrap_or('0'));
+                continue;
+            } else if !digits.is_empty() {
+                self.push_number(&digits, digits_tertiary);
+                digits.clear();
+            }
+
+            if c.is_alphabetic() {
+                if self.language == "cs"
+                    && c.eq_ignore_ascii_case(&'c')
+                    && let Some(&next) = chars.peek().filter(|n| n.eq_ignore_ascii_case(&'h'))
+                {
+                    // In Czech, "ch" is a single letter sorted after "h".
+                    chars.next();
+                    if c == 'C' || next == 'H' {
+                        tertiary |= TERTIARY_UPPERCASE;
+                    }
+                    self.push_element(letter_primary('h', 1), 0, tertiary);
+                    continue;
+                }
+                self.push_letter(c, tertiary);
+            } else if !self.options.ignore_symbols {
+                self.push_element(CLASS_SYMBOL | (c as u64) << 8, 0, tertiary);
+            }
+        }
+
+        if !digits.is_empty() {
+            self.push_number(&digits, digits_tertiary);
+        }
+
+        self.elements
+    }
+}
+
+fn collation_elements(language: &str, text: &str, options: &CollatorOptions) -> Vec<Element> {
+    ElementBuilder {
+        language,
+        options,
+        elements: Vec::new(),
+    }
+    .build(text)
+}
+
+/// Compares two strings using the collation rules of a language.
+pub fn compare(language: &str, a: &str, b: &str, options: &CollatorOptions) -> Ordering {
+    let a = collation_elements(language, a, options);
+    let b = collation_elements(language, b, options);
+
+    a.iter()
+        .map(|e| e.primary)
+        .cmp(b.iter().map(|e| e.primary))
+        .then_with(|| {
+            a.iter()
+                .map(|e| e.secondary)
+                .cmp(b.iter().map(|e| e.secondary))
+        })
+        .then_with(|| {
+            a.iter()
+                .map(|e| e.tertiary)
+                .cmp(b.iter().map(|e| e.tertiary))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(language: &str, words: &[&str], options: &CollatorOptions) -> Vec<String> {
+        let mut words: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+        words.sort_by(|a, b| compare(language, a, b, options));
+        words
+    }
+
+    #[test]
+    fn levels() {
+        let options = CollatorOptions::default();
+        assert_eq!(
+            sorted("en", &["cote", "côté", "Cote", "coté", "côte"], &options),
+            ["cote", "Cote", "coté", "côte", "côté"]
+        );
+        assert_eq!(
+            compare("en", "Straße", "strasse", &options),
+            Ordering::Greater
+        );
+
+        let options = CollatorOptions::matching();
+        assert_eq!(compare("en", "Résumé", "resume", &options), Ordering::Equal);
+        assert_eq!(compare("en", "ＡＢＣ", "abc", &options), Ordering::Equal);
+        assert_eq!(compare("ja", "カナ", "かな", &options), Ordering::Equal);
+    }
+
+    #[test]
+    fn options() {
+        let numeric = CollatorOptions {
+            numeric_comparison: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            sorted("en", &["file10", "file9", "file010"], &numeric),
+            ["file9", "file10", "file010"]
+        );
+
+        let no_symbols = CollatorOptions {
+            ignore_symbols: true,
+            ..Default::default()
+        };
+        assert_eq!(compare("en", "co-op", "coop", &no_symbols), Ordering::Equal);
+        assert_eq!(
+            compare("en", "co-op", "coop", &Default::default()),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn tailorings() {
+        let options = CollatorOptions::default();
+        assert_eq!(compare("en", "öl", "zebra", &options), Ordering::Less);
+        assert_eq!(compare("sv", "öl", "zebra", &options), Ordering::Greater);
+        assert_eq!(
+            sorted("sv", &["ö", "å", "ä", "z"], &options),
+            ["z", "å", "ä", "ö"]
+        );
+        assert_eq!(compare("es", "ñu", "nz", &options), Ordering::Greater);
+        assert_eq!(compare("cs", "chata", "hrad", &options), Ordering::Greater);
+        assert_eq!(compare("cs", "chata", "ih", &options), Ordering::Less);
+        assert_eq!(compare("tr", "ılık", "ip", &options), Ordering::Less);
+    }
+
+    #[test]
+    fn case_mapping() {
+        assert_eq!(to_upper_case("en", "istanbul"), "ISTANBUL");
+        assert_eq!(to_upper_case("tr", "istanbul"), "İSTANBUL");
+        assert_eq!(to_lower_case("tr", "ISPARTA İZMİR"), "ısparta izmir");
+        assert_eq!(to_lower_case("en", "ÉCOLE"), "école");
+    }
+}
@@ -0,0 +1,354 @@
+//! Date and time formatting, as done by `flash.globalization.DateTimeFormatter`.
+//!
+//! Patterns use the syntax of Unicode Technical Standard #35, of which the
+//! subset documented for `DateTimeFormatter.setDateTimePattern` is supported.
+
+use super::cldr::LocaleData;
+use chrono::{DateTime, Datelike, FixedOffset, Offset, Timelike};
+
+/// A `DateTimeStyle`, used to pick the date or time half of a pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateTimeStyle {
+    Long,
+    Medium,
+    Short,
+    None,
+    Custom,
+}
+
+impl DateTimeStyle {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "long" => Self::Long,
+            "medium" => Self::Medium,
+            "short" => Self::Short,
+            "none" => Self::None,
+            "custom" => Self::Custom,
+            _ => return None,
+        })
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Medium => "medium",
+            Self::Short => "short",
+            Self::None => "none",
+            Self::Custom => "custom",
+        }
+    }
+
+    fn index(self) -> Option<usize> {
+        match self {
+            Self::Long => Some(0),
+            Self::Medium => Some(1),
+            Self::Short => Some(2),
+            Self::None | Self::Custom => None,
+        }
+    }
+}
+
+/// A `DateTimeNameStyle`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NameStyle {
+    Full,
+    LongAbbreviation,
+    ShortAbbreviation,
+}
+
+impl NameStyle {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "full" => Self::Full,
+            "longAbbreviation" => Self::LongAbbreviation,
+            "shortAbbreviation" => Self::ShortAbbreviation,
+            _ => return None,
+        })
+    }
+}
+
+/// A `DateTimeNameContext`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NameContext {
+    Format,
+    Standalone,
+}
+
+impl NameContext {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "format" => Self::Format,
+            "standalone" => Self::Standalone,
+            _ => return None,
+        })
+    }
+}
+
+/// The pattern used for a combination of date and time styles.
+///
+/// Returns `None` if either style is `Custom`, as that has no pattern of its own.
+pub fn style_pattern(
+    data: &LocaleData,
+    date_style: DateTimeStyle,
+    time_style: DateTimeStyle,
+) -> Option<String> {
+    if date_style == DateTimeStyle::Custom || time_style == DateTimeStyle::Custom {
+        return None;
+    }
+
+    let date = date_style.index().map(|i| data.date_patterns[i]);
+    let time = time_style.index().map(|i| data.time_patterns[i]);
+    Some(match (date, time) {
+        (Some(date), Some(time)) => format!("{date} {time}"),
+        (Some(date), None) => date.to_string(),
+        (None, Some(time)) => time.to_string(),
+        (None, None) => String::new(),
+    })
+}
+
+/// The short abbreviation of a name, which is its first letter.
+///
+/// Names that don't start with a letter, such as the numbered months of
+/// Japanese, are abbreviated instead.
+fn short_name<'a>(name: &'a str, abbreviation: &'a str) -> &'a str {
+    match name.chars().next() {
+        Some(c) if c.is_alphabetic() && !is_ideographic(c) => &name[..c.len_utf8()],
+        _ => abbreviation,
+    }
+}
+
+fn is_ideographic(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30ff}' | '\u{3400}'..='\u{9fff}' | '\u{ac00}'..='\u{d7af}')
+}
+
+pub fn month_names(data: &LocaleData, style: NameStyle, context: NameContext) -> Vec<&'static str> {
+    let full = match (context, data.months_format) {
+        (NameContext::Format, Some(months)) => months,
+        _ => data.months,
+    };
+
+    match style {
+        NameStyle::Full => full.to_vec(),
+        NameStyle::LongAbbreviation => data.months_abbr.to_vec(),
+        NameStyle::ShortAbbreviation => data
+            .months
+            .iter()
+            .zip(data.months_abbr)
+            .map(|(name, abbreviation)| short_name(name, abbreviation))
+            .collect(),
+    }
+}
+
+pub fn weekday_names(data: &LocaleData, style: NameStyle) -> Vec<&'static str> {
+    match style {
+        NameStyle::Full => data.weekdays.to_vec(),
+        NameStyle::LongAbbreviation => data.weekdays_abbr.to_vec(),
+        NameStyle::ShortAbbreviation => data
+            .weekdays
+            .iter()
+            .zip(data.weekdays_abbr)
+            .map(|(name, abbreviation)| short_name(name, abbreviation))
+            .collect(),
+    }
+}
+
+fn name_style(count: usize) -> NameStyle {
+    match count {
+        4 => NameStyle::Full,
+        5 => NameStyle::ShortAbbreviation,
+        _ => NameStyle::LongAbbreviation,
+    }
+}
+
+fn push_number(output: &mut String, value: impl Into<i64>, width: usize) {
+    let value = value.into();
+    if value < 0 {
+        output.push('-');
+    }
+    output.push_str(&format!("{:0width$}", value.unsigned_abs()));
+}
+
+fn push_offset(output: &mut String, offset: i32, separator: &str) {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let minutes = offset.unsigned_abs() / 60;
+    output.push_str(&format!(
+        "{sign}{:02}{separator}{:02}",
+        minutes / 60,
+        minutes % 60
+    ));
+}
+
+/// The week of the year, where the first week is the one containing January 1st.
+fn week_of_year(date: &DateTime<FixedOffset>, first_weekday: u32) -> u32 {
+    let weekday = date.weekday().num_days_from_sunday();
+    let days_into_week = (weekday + 7 - first_weekday) % 7;
+    let first_day_of_week = date.ordinal0() as i64 - days_into_week as i64;
+    // Days of the partial first week before the start of this week.
+    let first_week_length = first_day_of_week.rem_euclid(7);
+    ((first_day_of_week - first_week_length) / 7 + if first_week_length > 0 { 2 } else { 1 }) as u32
+}
+
+fn format_field(
+    output: &mut String,
+    data: &LocaleData,
+    date: &DateTime<FixedOffset>,
+    field: char,
+    count: usize,
+) {
+    let hour = date.hour();
+    match field {
+        'G' => output.push_str(data.eras[usize::from(date.year() > 0)]),
+        'y' => {
+            if count == 2 {
+                push_number(output, date.year().rem_euclid(100), 2);
+            } else {
+                push_number(output, date.year(), count);
+            }
+        }
+        'M' | 'L' => {
+            if count <= 2 {
+                push_number(output, date.month(), count);
+            } else {
+                let context = if field == 'M' {
+                    NameContext::Format
+                } else {
+                    NameContext::Standalone
+                };
+                let names = month_names(data, name_style(count), context);
+                output.push_str(names[date.month0() as usize]);
+            }
+        }
+        'd' => push_number(output, date.day(), count),
+        'D' => push_number(output, date.ordinal(), count),
+        'E' => {
+            let names = weekday_names(data, name_style(count));
+            output.push_str(names[date.weekday().num_days_from_sunday() as usize]);
+        }
+        'F' => push_number(output, date.day0() / 7 + 1, count),
+        'Q' => push_number(output, date.month0() / 3 + 1, count),
+        'w' => push_number(output, week_of_year(date, data.first_weekday), count),
+        'a' => output.push_str(data.am_pm[usize::from(hour >= 12)]),
+        'h' => push_number(output, (hour + 11) % 12 + 1, count),
+        'H' => push_number(output, hour, count),
+        'K' => push_number(output, hour % 12, count),
+        'k' => push_number(output, if hour == 0 { 24 } else { hour }, count),
+        'm' => push_number(output, date.minute(), count),
+        's' => push_number(output, date.second(), count),
+        'S' => {
+            let fraction = format!("{:09}", date.nanosecond() % 1_000_000_000);
+            let digits = count.min(fraction.len());
+            output.push_str(&fraction[..digits]);
+            output.extend(std::iter::repeat_n('0', count - digits));
+        }
+        'z' => {
+            output.push_str("GMT");
+            push_offset(output, date.offset().fix().local_minus_utc(), ":");
+        }
+        'Z' => push_offset(output, date.offset().fix().local_minus_utc(), ""),
+        _ => output.extend(std::iter::repeat_n(field, count)),
+    }
+}
+
+/// Formats a date with a pattern.
+pub fn format(data: &LocaleData, pattern: &str, date: &DateTime<FixedOffset>) -> String {
+    let mut output = String::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                output.push('\'');
+                continue;
+            }
+
+            // A quoted literal, where two quotes stand for a single one.
+            while let Some(c) = chars.next() {
+                if c == '\'' {
+                    if chars.peek() == Some(&'\'') {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                output.push(c);
+            }
+        } else if c.is_ascii_alphabetic() {
+            let mut count = 1;
+            while chars.peek() == Some(&c) {
+                chars.next();
+                count += 1;
+            }
+            format_field(&mut output, data, date, c, count);
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::cldr::LOCALES;
+    use chrono::TimeZone;
+
+    fn locale(name: &str) -> &'static LocaleData {
+        LOCALES.iter().find(|data| data.name == name).unwrap()
+    }
+
+    fn date() -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(20700)
+            .unwrap()
+            .with_ymd_and_hms(2001, 2, 3, 4, 5, 6)
+            .unwrap()
+    }
+
+    #[test]
+    fn styles() {
+        let en_us = locale("en-US");
+        let pattern = style_pattern(en_us, DateTimeStyle::Long, DateTimeStyle::Long).unwrap();
+        assert_eq!(
+            format(en_us, &pattern, &date()),
+            "Saturday, February 3, 2001 4:05:06 AM"
+        );
+
+        let pattern = style_pattern(en_us, DateTimeStyle::Short, DateTimeStyle::None).unwrap();
+        assert_eq!(format(en_us, &pattern, &date()), "2/3/01");
+
+        let de_de = locale("de-DE");
+        let pattern = style_pattern(de_de, DateTimeStyle::Medium, DateTimeStyle::Short).unwrap();
+        assert_eq!(format(de_de, &pattern, &date()), "03.02.2001 04:05");
+
+        assert_eq!(
+            style_pattern(en_us, DateTimeStyle::Custom, DateTimeStyle::Long),
+            None
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let en_us = locale("en-US");
+        let date = date();
+        assert_eq!(
+            format(en_us, "G yyyy yy MMMMM EEEEE", &date),
+            "AD 2001 01 F S"
+        );
+        assert_eq!(format(en_us, "D F Q w", &date), "34 1 1 5");
+        assert_eq!(format(en_us, "hh KK HH k SSS", &date), "04 04 04 4 000");
+        assert_eq!(format(en_us, "z Z", &date), "GMT+05:45 +0545");
+        assert_eq!(format(en_us, "'at' h 'o''clock'''", &date), "at 4 o'clock'");
+    }
+
+    #[test]
+    fn week_numbers() {
+        let offset = FixedOffset::east_opt(0).unwrap();
+        // January 1st 2001 was a Monday.
+        let sunday = offset.with_ymd_and_hms(2001, 1, 7, 0, 0, 0).unwrap();
+        assert_eq!(week_of_year(&sunday, 0), 2);
+        assert_eq!(week_of_year(&sunday, 1), 1);
+        let first = offset.with_ymd_and_hms(2001, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(week_of_year(&first, 0), 1);
+    }
+}
@@ -0,0 +1,500 @@
+//! Number and currency formatting, as done by `flash.globalization`.
+
+/// The largest number of fractional digits that can be formatted.
+const MAX_FRACTIONAL_DIGITS: i32 = 20;
+
+/// The properties of a `NumberFormatter` or `CurrencyFormatter`.
+pub struct NumberFormat<'a> {
+    pub decimal_separator: &'a str,
+    pub grouping_separator: &'a str,
+    pub grouping_pattern: &'a str,
+    pub negative_symbol: &'a str,
+    pub digits_type: u32,
+    pub fractional_digits: i32,
+    pub leading_zero: bool,
+    pub trailing_zeros: bool,
+    pub use_grouping: bool,
+}
+
+/// A parsed grouping pattern, such as `3;2;*`.
+struct GroupingPattern {
+    /// The sizes of the groups, starting from the decimal separator.
+    sizes: Vec<usize>,
+
+    /// Whether the last group size repeats.
+    repeat: bool,
+}
+
+impl GroupingPattern {
+    fn parse(pattern: &str) -> Option<Self> {
+        let mut sizes = Vec::new();
+        let mut repeat = false;
+
+        for part in pattern.split(';') {
+            if repeat {
+                // Nothing may follow the `*`.
+                return None;
+            } else if part == "*" {
+                repeat = true;
+            } else {
+                let size: usize = part.parse().ok()?;
+                if size == 0 {
+                    return None;
+                }
+                sizes.push(size);
+            }
+        }
+
+        if sizes.is_empty() {
+            return None;
+        }
+
+        Some(Self { sizes, repeat })
+    }
+}
+
+/// Whether a grouping pattern is valid, see `NumberFormatter.groupingPattern`.
+pub fn is_valid_grouping_pattern(pattern: &str) -> bool {
+    GroupingPattern::parse(pattern).is_some()
+}
+
+impl NumberFormat<'_> {
+    fn digit(&self, digit: u32) -> char {
+        char::from_u32(self.digits_type + digit)
+            .filter(|c| c.is_numeric())
+            .unwrap_or_else(|| char::from_digit(digit, 10).unwrap_or('0'))
+    }
+
+    /// Returns the value of a digit in any of the supported digit sets.
+    fn digit_value(&self, c: char) -> Option<u32> {
+        if let Some(digit) = c.to_digit(10) {
+            return Some(digit);
+        }
+
+        let digit = (c as u32).checked_sub(self.digits_type)?;
+        (digit < 10).then_some(digit)
+    }
+
+    fn group(&self, digits: &str) -> String {
+        let pattern = GroupingPattern::parse(self.grouping_pattern);
+        let Some(pattern) = pattern.filter(|_| self.use_grouping) else {
+            return digits.to_string();
+        };
+
+        let mut groups = Vec::new();
+        let mut rest = digits;
+        let mut sizes = pattern.sizes.iter();
+        let mut size = None;
+        loop {
+            size = sizes
+                .next()
+                .copied()
+                .or(if pattern.repeat { size } else { None });
+            match size {
+                Some(size) if rest.len() > size => {
+                    let (head, tail) = rest.split_at(rest.len() - size);
+                    groups.push(tail);
+                    rest = head;
+                }
+                _ => {
+                    groups.push(rest);
+                    break;
+                }
+            }
+        }
+
+        groups.reverse();
+        groups.join(self.grouping_separator)
+    }
+
+    fn localize_digits(&self, text: &str) -> String {
+        text.chars()
+            .map(|c| match c.to_digit(10) {
+                Some(digit) => self.digit(digit),
+                None => c,
+            })
+            .collect()
+    }
+
+    /// Formats the absolute value of a number.
+    fn format_magnitude(&self, value: f64, fractional_digits: i32) -> String {
+        if value.is_nan() {
+            return "NaN".to_string();
+        } else if value.is_infinite() {
+            return "∞".to_string();
+        }
+
+        let fractional_digits = fractional_digits.clamp(0, MAX_FRACTIONAL_DIGITS) as usize;
+        let formatted = format!("{:.*}", fractional_digits, value.abs());
+        let (integer, mut fraction) = formatted
+            .split_once('.')
+            .unwrap_or((formatted.as_str(), ""));
+
+        if !self.trailing_zeros {
+            fraction = fraction.trim_end_matches('0');
+        }
+
+        let mut result = String::new();
+        if integer != "0" || self.leading_zero || fraction.is_empty() {
+            result.push_str(&self.localize_digits(&self.group(integer)));
+        }
+        if !fraction.is_empty() {
+            result.push_str(self.decimal_separator);
+            result.push_str(&self.localize_digits(fraction));
+        }
+
+        result
+    }
+
+    /// Whether a number is displayed as negative after rounding.
+    fn is_negative(&self, value: f64, fractional_digits: i32) -> bool {
+        if value.is_nan() || value >= 0.0 {
+            return false;
+        }
+
+        let scale = 10f64.powi(fractional_digits.clamp(0, MAX_FRACTIONAL_DIGITS));
+        (value * scale).round() != 0.0
+    }
+
+    pub fn format_number(&self, value: f64, negative_format: u32) -> String {
+        self.format_with_fraction(value, self.fractional_digits, negative_format)
+    }
+
+    /// Formats an integer, which never has any fractional digits.
+    pub fn format_integer(&self, value: f64, negative_format: u32) -> String {
+        self.format_with_fraction(value, 0, negative_format)
+    }
+
+    fn format_with_fraction(
+        &self,
+        value: f64,
+        fractional_digits: i32,
+        negative_format: u32,
+    ) -> String {
+        let number = self.format_magnitude(value, fractional_digits);
+        if !self.is_negative(value, fractional_digits) {
+            return number;
+        }
+
+        let minus = self.negative_symbol;
+        match negative_format {
+            0 => format!("({number})"),
+            2 => format!("{minus} {number}"),
+            3 => format!("{number}{minus}"),
+            4 => format!("{number} {minus}"),
+            _ => format!("{minus}{number}"),
+        }
+    }
+
+    pub fn format_currency(
+        &self,
+        value: f64,
+        symbol: &str,
+        positive_format: u32,
+        negative_format: u32,
+    ) -> String {
+        let n = self.format_magnitude(value, self.fractional_digits);
+        let s = symbol;
+        let m = self.negative_symbol;
+
+        if !self.is_negative(value, self.fractional_digits) {
+            return match positive_format {
+                1 => format!("{n}{s}"),
+                2 => format!("{s} {n}"),
+                3 => format!("{n} {s}"),
+                _ => format!("{s}{n}"),
+            };
+        }
+
+        match negative_format {
+            0 => format!("({s}{n})"),
+            2 => format!("{s}{m}{n}"),
+            3 => format!("{s}{n}{m}"),
+            4 => format!("({n}{s})"),
+            5 => format!("{m}{n}{s}"),
+            6 => format!("{n}{m}{s}"),
+            7 => format!("{n}{s}{m}"),
+            8 => format!("{m}{n} {s}"),
+            9 => format!("{m}{s} {n}"),
+            10 => format!("{n} {s}{m}"),
+            11 => format!("{s} {n}{m}"),
+            12 => format!("{s} {m}{n}"),
+            13 => format!("{n}{m} {s}"),
+            14 => format!("({s} {n})"),
+            15 => format!("({n} {s})"),
+            _ => format!("{m}{s}{n}"),
+        }
+    }
+
+    fn is_grouping_char(&self, c: char) -> bool {
+        let separator = self.grouping_separator;
+        separator.starts_with(c)
+            || (separator.chars().all(char::is_whitespace) && c.is_whitespace())
+            || (separator == "\u{a0}" && c == ' ')
+    }
+
+    /// Find the longest number starting at `start` in `chars`.
+    ///
+    /// Returns the value and the index after the number.
+    fn scan_number(&self, chars: &[char], start: usize) -> Option<(f64, usize)> {
+        let decimal_separator: Vec<char> = self.decimal_separator.chars().collect();
+
+        let mut digits = String::new();
+        let mut seen_digit = false;
+        let mut seen_decimal = false;
+        let mut end = start;
+        let mut i = start;
+        while i < chars.len() {
+            let c = chars[i];
+            if let Some(digit) = self.digit_value(c) {
+                digits.push(char::from_digit(digit, 10).unwrap_or('0'));
+                seen_digit = true;
+                i += 1;
+                end = i;
+            } else if !seen_decimal
+                && !decimal_separator.is_empty()
+                && chars[i..].starts_with(&decimal_separator)
+                && chars
+                    .get(i + decimal_separator.len())
+                    .is_some_and(|&c| self.digit_value(c).is_some())
+            {
+                digits.push('.');
+                seen_decimal = true;
+                i += decimal_separator.len();
+            } else if seen_digit
+                && !seen_decimal
+                && self.is_grouping_char(c)
+                && chars
+                    .get(i + 1)
+                    .is_some_and(|&c| self.digit_value(c).is_some())
+            {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        if !seen_digit {
+            return None;
+        }
+
+        digits.parse().ok().map(|value| (value, end))
+    }
+
+    /// Parses a string that contains nothing but a number, see `NumberFormatter.parseNumber`.
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        let trimmed = text.trim();
+        let (body, negative) = strip_sign(trimmed, self.negative_symbol);
+
+        let chars: Vec<char> = body.chars().collect();
+        let (value, end) = self.scan_number(&chars, 0)?;
+        if end != chars.len() {
+            return None;
+        }
+
+        Some(if negative { -value } else { value })
+    }
+
+    /// Finds a number in a string, see `NumberFormatter.parse`.
+    ///
+    /// Returns the value and the UTF-16 range of the number in the string.
+    pub fn parse(&self, text: &str) -> Option<(f64, usize, usize)> {
+        let chars: Vec<char> = text.chars().collect();
+        let minus: Vec<char> = self.negative_symbol.chars().collect();
+
+        for start in 0..chars.len() {
+            let Some((value, end)) = self.scan_number(&chars, start) else {
+                continue;
+            };
+
+            let mut number_start = start;
+            let mut number_end = end;
+            let mut negative = false;
+
+            let before = chars[..start].iter().rev().skip_while(|c| **c == ' ');
+            let before_len = start - before.count();
+            let prefix = &chars[..start - before_len];
+            if prefix.ends_with(&minus) && !minus.is_empty() {
+                negative = true;
+                number_start = prefix.len() - minus.len();
+            } else if prefix.ends_with(&['(']) && chars[end..].starts_with(&[')']) {
+                negative = true;
+                number_start = prefix.len() - 1;
+                number_end = end + 1;
+            } else if !minus.is_empty() && chars[end..].starts_with(&minus) {
+                negative = true;
+                number_end = end + minus.len();
+            }
+
+            let value = if negative { -value } else { value };
+            return Some((
+                value,
+                utf16_len(&chars[..number_start]),
+                utf16_len(&chars[..number_end]),
+            ));
+        }
+
+        None
+    }
+
+    /// Parses a currency amount, see `CurrencyFormatter.parse`.
+    ///
+    /// Returns the value and the text surrounding it, which should be the
+    /// currency symbol or code.
+    pub fn parse_currency(&self, text: &str) -> Option<(f64, String)> {
+        let chars: Vec<char> = text.chars().collect();
+        let start = (0..chars.len()).find(|&i| self.scan_number(&chars, i).is_some())?;
+        let (value, end) = self.scan_number(&chars, start)?;
+
+        let mut negative = false;
+        let mut currency = String::new();
+        for part in [&chars[..start], &chars[end..]] {
+            let part: String = part.iter().collect();
+            let (part, is_negative) = strip_sign(part.trim(), self.negative_symbol);
+            negative |= is_negative;
+            currency.push_str(part.trim());
+        }
+
+        Some((if negative { -value } else { value }, currency))
+    }
+}
+
+/// Strips a leading or trailing minus sign, or the parentheses of a negative
+/// number in accounting notation.
+fn strip_sign<'a>(text: &'a str, negative_symbol: &str) -> (&'a str, bool) {
+    if let Some(inner) = text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        return (inner.trim(), true);
+    }
+
+    for minus in [negative_symbol, "-"] {
+        if minus.is_empty() {
+            continue;
+        }
+        if let Some(rest) = text.strip_prefix(minus) {
+            return (rest.trim_start(), true);
+        }
+        if let Some(rest) = text.strip_suffix(minus) {
+            return (rest.trim_end(), true);
+        }
+    }
+
+    (text, false)
+}
+
+fn utf16_len(chars: &[char]) -> usize {
+    chars.iter().map(|c| c.len_utf16()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The code point of the European zero digit, see `NationalDigitsType.EUROPEAN`.
+    const EUROPEAN_DIGITS: u32 = 0x30;
+
+    fn en_us() -> NumberFormat<'static> {
+        NumberFormat {
+            decimal_separator: ".",
+            grouping_separator: ",",
+            grouping_pattern: "3;*",
+            negative_symbol: "-",
+            digits_type: EUROPEAN_DIGITS,
+            fractional_digits: 2,
+            leading_zero: true,
+            trailing_zeros: true,
+            use_grouping: true,
+        }
+    }
+
+    #[test]
+    fn format_number() {
+        let format = en_us();
+        assert_eq!(format.format_number(1234567.891, 1), "1,234,567.89");
+        assert_eq!(format.format_number(-1234.5, 1), "-1,234.50");
+        assert_eq!(format.format_number(-1234.5, 0), "(1,234.50)");
+        assert_eq!(format.format_number(-1234.5, 4), "1,234.50 -");
+        assert_eq!(format.format_number(-0.001, 1), "0.00");
+        assert_eq!(format.format_integer(-42.0, 1), "-42");
+
+        let format = NumberFormat {
+            leading_zero: false,
+            trailing_zeros: false,
+            ..en_us()
+        };
+        assert_eq!(format.format_number(0.5, 1), ".5");
+        assert_eq!(format.format_number(3.0, 1), "3");
+    }
+
+    #[test]
+    fn grouping_patterns() {
+        let format = NumberFormat {
+            grouping_pattern: "3;2;*",
+            ..en_us()
+        };
+        assert_eq!(format.format_integer(123456789.0, 1), "12,34,56,789");
+
+        let format = NumberFormat {
+            grouping_pattern: "3",
+            ..en_us()
+        };
+        assert_eq!(format.format_integer(123456789.0, 1), "123456,789");
+
+        assert!(!is_valid_grouping_pattern("*;3"));
+        assert!(!is_valid_grouping_pattern("0;*"));
+        assert!(is_valid_grouping_pattern("3;2;*"));
+    }
+
+    #[test]
+    fn national_digits() {
+        let format = NumberFormat {
+            digits_type: 0x0660,
+            ..en_us()
+        };
+        assert_eq!(format.format_integer(120.0, 1), "١٢٠");
+        assert_eq!(format.parse_number("١٢٠"), Some(120.0));
+    }
+
+    #[test]
+    fn format_currency() {
+        let format = en_us();
+        assert_eq!(format.format_currency(1234.5, "$", 0, 1), "$1,234.50");
+        assert_eq!(format.format_currency(-1234.5, "$", 0, 1), "-$1,234.50");
+        assert_eq!(format.format_currency(-1234.5, "$", 0, 0), "($1,234.50)");
+
+        let format = NumberFormat {
+            decimal_separator: ",",
+            grouping_separator: ".",
+            ..en_us()
+        };
+        assert_eq!(format.format_currency(1234.5, "€", 3, 8), "1.234,50 €");
+        assert_eq!(format.format_currency(-1234.5, "€", 3, 8), "-1.234,50 €");
+    }
+
+    #[test]
+    fn parse_numbers() {
+        let format = en_us();
+        assert_eq!(format.parse_number(" 1,234.5 "), Some(1234.5));
+        assert_eq!(format.parse_number("-1,234.5"), Some(-1234.5));
+        assert_eq!(format.parse_number("(12)"), Some(-12.0));
+        assert_eq!(format.parse_number("12abc"), None);
+        assert_eq!(format.parse_number(""), None);
+
+        assert_eq!(format.parse("Total: -1,234.5 USD"), Some((-1234.5, 7, 15)));
+        assert_eq!(format.parse("(42)"), Some((-42.0, 0, 4)));
+        assert_eq!(format.parse("none"), None);
+    }
+
+    #[test]
+    fn parse_currency() {
+        let format = en_us();
+        assert_eq!(
+            format.parse_currency("-$1,234.50"),
+            Some((-1234.5, "$".to_string()))
+        );
+        assert_eq!(
+            format.parse_currency("USD 12"),
+            Some((12.0, "USD".to_string()))
+        );
+        assert_eq!(format.parse_currency("$"), None);
+    }
+}
@@ -0,0 +1,441 @@
+//! Microphones, shared by the AVM1 and AVM2 `Microphone` classes.
+//!
+//! A movie gets one `Microphone` object per capture device; its settings live here so that both
+//! VMs see the same state. A device only captures while the movie has a use for its audio - when
+//! it listens for `SampleDataEvent.SAMPLE_DATA` or plays the audio back through the speakers.
+//!
+//! Devices are muted until the user allows capturing audio. The backend is asked for permission
+//! the first time the movie needs a device, and the movie is told about the answer with a
+//! `Microphone.Unmuted` or `Microphone.Muted` status event.
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier, ExecutionReason, Object as Avm1Object,
+};
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::globals::slots::flash_events_event_dispatcher as dispatcher_slots;
+use crate::avm2::object::{ByteArrayObject, EventObject, Object as Avm2Object, TObject};
+use crate::avm2::{Activation as Avm2Activation, Avm2, Value as Avm2Value};
+use crate::backend::microphone::{MicrophoneBackend, MicrophonePermission};
+use crate::context::UpdateContext;
+use crate::string::AvmString;
+use gc_arena::Collect;
+use ruffle_common::duration::FloatDuration;
+use ruffle_macros::istr;
+use std::collections::BTreeMap;
+
+/// The rates a microphone can capture at, in kHz, along with the actual sample rate in Hz.
+const RATES: [(u32, u32); 6] = [
+    (5, 5512),
+    (8, 8000),
+    (11, 11025),
+    (16, 16000),
+    (22, 22050),
+    (44, 44100),
+];
+
+/// The settings of a microphone, as exposed to ActionScript.
+#[derive(Clone, Debug)]
+pub struct MicrophoneSettings {
+    /// How much the captured signal is amplified, from 0 to 100; 50 leaves it unchanged.
+    pub gain: f64,
+
+    /// The capture rate, in kHz. Always one of the supported rates.
+    rate: u32,
+
+    /// The activity level, from 0 to 100, below which the microphone is considered silent.
+    pub silence_level: f64,
+
+    /// How long, in milliseconds, the microphone has to stay silent to become inactive.
+    pub silence_timeout: i32,
+
+    pub use_echo_suppression: bool,
+
+    /// Whether the captured audio is played back through the speakers.
+    pub loop_back: bool,
+}
+
+impl MicrophoneSettings {
+    pub fn rate(&self) -> u32 {
+        self.rate
+    }
+
+    /// Sets the capture rate to the supported rate closest to `rate`, in kHz.
+    pub fn set_rate(&mut self, rate: i32) {
+        self.rate = RATES
+            .iter()
+            .map(|(khz, _)| *khz)
+            .min_by_key(|khz| (i64::from(*khz) - i64::from(rate)).abs())
+            .unwrap_or(8);
+    }
+
+    fn sample_rate(&self) -> u32 {
+        RATES
+            .iter()
+            .find(|(khz, _)| *khz == self.rate)
+            .map_or(8000, |(_, hz)| *hz)
+    }
+
+    fn volume(&self) -> f32 {
+        (self.gain / 50.0) as f32
+    }
+}
+
+impl Default for MicrophoneSettings {
+    fn default() -> Self {
+        Self {
+            gain: 50.0,
+            rate: 8,
+            silence_level: 10.0,
+            silence_timeout: 2000,
+            use_echo_suppression: false,
+            loop_back: false,
+        }
+    }
+}
+
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+struct Microphone<'gc> {
+    avm1_object: Option<Avm1Object<'gc>>,
+    avm2_object: Option<Avm2Object<'gc>>,
+
+    #[collect(require_static)]
+    settings: MicrophoneSettings,
+
+    /// The sample rate the device is capturing at, if it is capturing.
+    capture_rate: Option<u32>,
+
+    /// The volume the captured audio is played back at, if it is.
+    loop_back_volume: Option<f32>,
+
+    /// Whether the device failed to capture.
+    muted: bool,
+
+    /// The level of the latest captured audio, or `None` before any was captured.
+    activity_level: Option<f64>,
+
+    /// Whether the audio has been above the silence level recently.
+    active: bool,
+
+    /// For how long, in milliseconds, the audio has been below the silence level.
+    silent_for: f64,
+
+    /// The number of samples delivered to the movie so far.
+    position: f64,
+}
+
+impl Microphone<'_> {
+    /// Tracks the activity level against the silence settings, returning the new state if the
+    /// microphone just became active or inactive.
+    fn observe_activity(&mut self, level: f64, dt: f64) -> Option<bool> {
+        if level > self.settings.silence_level || self.settings.silence_level <= 0.0 {
+            self.silent_for = 0.0;
+            if !self.active {
+                self.active = true;
+                return Some(true);
+            }
+        } else {
+            self.silent_for += dt;
+            let timeout = f64::from(self.settings.silence_timeout.max(0));
+            if self.active && self.silent_for >= timeout {
+                self.active = false;
+                return Some(false);
+            }
+        }
+
+        None
+    }
+}
+
+/// Manages the microphones requested by the movie.
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+pub struct Microphones<'gc> {
+    devices: BTreeMap<usize, Microphone<'gc>>,
+
+    /// The permission to capture audio as of the last update, or `None` before the first one.
+    #[collect(require_static)]
+    permission: Option<MicrophonePermission>,
+
+    /// Whether the user was asked for permission to capture audio.
+    permission_requested: bool,
+}
+
+impl<'gc> Microphones<'gc> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves the index of a device as given by a movie, where `-1` means the default device.
+    pub fn device_index(backend: &dyn MicrophoneBackend, index: i32) -> Option<usize> {
+        if index < 0 {
+            return backend.default_device();
+        }
+
+        let index = index as usize;
+        (index < backend.names().len()).then_some(index)
+    }
+
+    pub fn avm1_object(&self, index: usize) -> Option<Avm1Object<'gc>> {
+        self.devices.get(&index).and_then(|mic| mic.avm1_object)
+    }
+
+    pub fn set_avm1_object(&mut self, index: usize, object: Avm1Object<'gc>) {
+        self.devices.entry(index).or_default().avm1_object = Some(object);
+    }
+
+    pub fn avm2_object(&self, index: usize) -> Option<Avm2Object<'gc>> {
+        self.devices.get(&index).and_then(|mic| mic.avm2_object)
+    }
+
+    pub fn set_avm2_object(&mut self, index: usize, object: Avm2Object<'gc>) {
+        self.devices.entry(index).or_default().avm2_object = Some(object);
+    }
+
+    pub fn settings(&self, index: usize) -> MicrophoneSettings {
+        self.devices
+            .get(&index)
+            .map(|mic| mic.settings.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn settings_mut(&mut self, index: usize) -> &mut MicrophoneSettings {
+        &mut self.devices.entry(index).or_default().settings
+    }
+
+    /// The level of the audio captured by the device, from 0 to 100, or -1 if the device hasn't
+    /// captured anything yet.
+    pub fn activity_level(&self, index: usize) -> f64 {
+        self.devices
+            .get(&index)
+            .and_then(|mic| mic.activity_level)
+            .unwrap_or(-1.0)
+    }
+
+    /// Stops playing back the microphones attached to movie clips in AVM1.
+    pub fn stop_avm1_loop_back(&mut self) {
+        for mic in self.devices.values_mut() {
+            if mic.avm1_object.is_some() {
+                mic.settings.loop_back = false;
+            }
+        }
+    }
+
+    /// Whether the device can't capture audio, either because the user didn't allow it or because
+    /// it failed to.
+    pub fn is_muted(&self, backend: &dyn MicrophoneBackend, index: usize) -> bool {
+        backend.permission() != MicrophonePermission::Allowed
+            || self.devices.get(&index).is_some_and(|mic| mic.muted)
+    }
+
+    /// Starts or stops capturing on every device depending on what the movie needs, and delivers
+    /// the audio captured since the last update.
+    pub fn update_microphones(context: &mut UpdateContext<'gc>, dt: FloatDuration) {
+        let indices: Vec<usize> = context.microphones.devices.keys().copied().collect();
+        let mut samples = Vec::new();
+
+        let permission = context.microphone.permission();
+        let previous = context.microphones.permission.replace(permission);
+        if previous.is_some_and(|previous| previous != permission) {
+            let code = match permission {
+                MicrophonePermission::Allowed => Some("Microphone.Unmuted"),
+                MicrophonePermission::Denied => Some("Microphone.Muted"),
+                MicrophonePermission::Undecided => None,
+            };
+            if let Some(code) = code {
+                for index in &indices {
+                    Self::dispatch_status(context, *index, code);
+                }
+            }
+        }
+
+        for index in indices {
+            Self::update_capture(context, index);
+
+            let Some(mic) = context.microphones.devices.get_mut(&index) else {
+                continue;
+            };
+            if mic.capture_rate.is_none() {
+                continue;
+            }
+
+            samples.clear();
+            context.microphone.read_samples(index, &mut samples);
+
+            let volume = mic.settings.volume();
+            let mut peak = 0.0f32;
+            for sample in &mut samples {
+                *sample = (*sample * volume).clamp(-1.0, 1.0);
+                peak = peak.max(sample.abs());
+            }
+
+            let level = if samples.is_empty() {
+                mic.activity_level.unwrap_or(0.0)
+            } else {
+                (f64::from(peak) * 100.0).round()
+            };
+            mic.activity_level = Some(level);
+            let activity = mic.observe_activity(level, dt.as_millis());
+
+            let position = mic.position;
+            mic.position += samples.len() as f64;
+
+            let avm1_object = mic.avm1_object;
+            let avm2_object = mic.avm2_object;
+
+            if let Some(activating) = activity {
+                if let Some(object) = avm2_object {
+                    let mut activation = Avm2Activation::from_nothing(context);
+                    let event = EventObject::activity_event(&mut activation, activating);
+                    Avm2::dispatch_event(activation.context, event, object);
+                }
+
+                if let Some(object) = avm1_object {
+                    let mut activation = Avm1Activation::from_stub(
+                        context,
+                        ActivationIdentifier::root("[Microphone]"),
+                    );
+                    let _ = object.call_method(
+                        istr!("onActivity"),
+                        &[activating.into()],
+                        &mut activation,
+                        ExecutionReason::Special,
+                    );
+                }
+            }
+
+            if let Some(object) = avm2_object
+                && !samples.is_empty()
+            {
+                let mut storage = ByteArrayStorage::new(context);
+                for sample in &samples {
+                    storage
+                        .write_float(*sample)
+                        .expect("Writing to a new ByteArray can't fail");
+                }
+                storage.set_position(0);
+
+                let data = ByteArrayObject::from_storage(context, storage);
+                let mut activation = Avm2Activation::from_nothing(context);
+                let event = EventObject::sample_data_event(&mut activation, position, data);
+                Avm2::dispatch_event(activation.context, event, object);
+            }
+        }
+    }
+
+    /// Starts capturing on a device if the movie needs its audio, and stops it otherwise.
+    fn update_capture(context: &mut UpdateContext<'gc>, index: usize) {
+        let listening = context
+            .microphones
+            .avm2_object(index)
+            .is_some_and(|object| has_sample_data_listener(context, object));
+
+        let Some(loop_back) = context
+            .microphones
+            .devices
+            .get(&index)
+            .map(|mic| mic.settings.loop_back)
+        else {
+            return;
+        };
+
+        let wanted = listening || loop_back;
+        let permission = context.microphone.permission();
+        if wanted
+            && permission == MicrophonePermission::Undecided
+            && !context.microphones.permission_requested
+        {
+            context.microphones.permission_requested = true;
+            context.microphone.request_permission();
+        }
+
+        let Some(mic) = context.microphones.devices.get_mut(&index) else {
+            return;
+        };
+        let wanted_rate = wanted
+            .then(|| mic.settings.sample_rate())
+            .filter(|_| !mic.muted && permission == MicrophonePermission::Allowed);
+
+        if wanted_rate != mic.capture_rate {
+            match wanted_rate {
+                Some(rate) => {
+                    if let Err(e) = context.microphone.start_capture(index, rate) {
+                        tracing::error!("Couldn't use microphone {index}: {e}");
+                        mic.muted = true;
+                    } else {
+                        mic.capture_rate = Some(rate);
+                    }
+                }
+                None => {
+                    context.microphone.stop_capture(index);
+                    mic.capture_rate = None;
+                }
+            }
+        }
+
+        let loop_back_volume = mic
+            .capture_rate
+            .filter(|_| mic.settings.loop_back)
+            .map(|_| mic.settings.volume());
+        if loop_back_volume != mic.loop_back_volume {
+            context.microphone.set_loop_back(index, loop_back_volume);
+            mic.loop_back_volume = loop_back_volume;
+        }
+    }
+}
+
+impl<'gc> Microphones<'gc> {
+    /// Tells the movie that the user allowed or refused capturing audio from the device.
+    fn dispatch_status(context: &mut UpdateContext<'gc>, index: usize, code: &str) {
+        let Some(mic) = context.microphones.devices.get(&index) else {
+            return;
+        };
+        let avm1_object = mic.avm1_object;
+        let avm2_object = mic.avm2_object;
+
+        if let Some(object) = avm2_object {
+            let mut activation = Avm2Activation::from_nothing(context);
+            let status_event_cls = activation.avm2().classes().statusevent;
+            let code = AvmString::new_utf8(activation.gc(), code);
+            let event = EventObject::from_class_and_args(
+                &mut activation,
+                status_event_cls,
+                &[
+                    istr!("status").into(),
+                    false.into(),
+                    false.into(),
+                    code.into(),
+                    istr!("status").into(),
+                ],
+            );
+            Avm2::dispatch_event(activation.context, event, object);
+        }
+
+        if let Some(object) = avm1_object {
+            let mut activation =
+                Avm1Activation::from_stub(context, ActivationIdentifier::root("[Microphone]"));
+            let object_proto = activation.prototypes().object;
+            let info = Avm1Object::new(&activation.context.strings, Some(object_proto));
+            let code = AvmString::new_utf8(activation.gc(), code);
+            let _ = info.set(istr!("code"), code.into(), &mut activation);
+            let _ = info.set(istr!("level"), istr!("status").into(), &mut activation);
+            let _ = object.call_method(
+                istr!("onStatus"),
+                &[info.into()],
+                &mut activation,
+                ExecutionReason::Special,
+            );
+        }
+    }
+}
+
+fn has_sample_data_listener<'gc>(context: &UpdateContext<'gc>, object: Avm2Object<'gc>) -> bool {
+    let Avm2Value::Object(dispatch_list) = object.get_slot(dispatcher_slots::DISPATCH_LIST) else {
+        return false;
+    };
+
+    let event = AvmString::new_utf8(context.gc(), "sampleData");
+    dispatch_list
+        .as_dispatch_mut(context.gc())
+        .is_some_and(|list| list.has_event_listener(event))
+}
@@ -0,0 +1,172 @@
+//! The application of an AIR movie, exposed to ActionScript as `NativeApplication`.
+
+use crate::avm2::Avm2;
+use crate::avm2::object::{EventObject, Object as Avm2Object};
+use crate::backend::ui::NativeWindowId;
+use crate::context::UpdateContext;
+use crate::events::{NativeWindowEvent, PlayerNotification};
+use gc_arena::Collect;
+use ruffle_common::duration::FloatDuration;
+
+/// How long, in seconds, the user has to be away before `userIdle` is dispatched by default.
+const DEFAULT_IDLE_THRESHOLD: u32 = 300;
+
+/// Tracks the state `NativeApplication` reports and the events it dispatches.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct NativeApplication<'gc> {
+    /// The AVM2 `NativeApplication` singleton, once the movie has accessed it.
+    object: Option<Avm2Object<'gc>>,
+
+    /// The arguments the application was launched with, dispatched in `InvokeEvent`.
+    #[collect(require_static)]
+    invoke_arguments: Vec<String>,
+
+    /// The contents of the `application.xml` of the application, if it has one.
+    #[collect(require_static)]
+    descriptor: Option<String>,
+
+    /// The window that has the focus, if it's a window of this application.
+    #[collect(require_static)]
+    focused_window: Option<NativeWindowId>,
+
+    /// Whether `activate` was dispatched last, rather than `deactivate`.
+    active: bool,
+
+    #[collect(require_static)]
+    time_since_input: FloatDuration,
+
+    /// How long, in seconds, the user has to be away before `userIdle` is dispatched.
+    idle_threshold: u32,
+
+    /// Whether `userIdle` was dispatched last, rather than `userPresent`.
+    idle: bool,
+
+    /// Whether the application exits once all of its windows are closed.
+    auto_exit: bool,
+}
+
+impl<'gc> NativeApplication<'gc> {
+    pub fn new(invoke_arguments: Vec<String>, descriptor: Option<String>) -> Self {
+        Self {
+            object: None,
+            invoke_arguments,
+            descriptor,
+            focused_window: Some(NativeWindowId::MAIN),
+            active: true,
+            time_since_input: FloatDuration::ZERO,
+            idle_threshold: DEFAULT_IDLE_THRESHOLD,
+            idle: false,
+            auto_exit: true,
+        }
+    }
+
+    /// Registers the `NativeApplication` singleton, so that events can be dispatched to it.
+    pub fn set_object(&mut self, object: Avm2Object<'gc>) {
+        self.object = Some(object);
+    }
+
+    pub fn invoke_arguments(&self) -> &[String] {
+        &self.invoke_arguments
+    }
+
+    pub fn descriptor(&self) -> Option<&str> {
+        self.descriptor.as_deref()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn idle_threshold(&self) -> u32 {
+        self.idle_threshold
+    }
+
+    pub fn set_idle_threshold(&mut self, idle_threshold: u32) {
+        self.idle_threshold = idle_threshold;
+    }
+
+    pub fn auto_exit(&self) -> bool {
+        self.auto_exit
+    }
+
+    pub fn set_auto_exit(&mut self, auto_exit: bool) {
+        self.auto_exit = auto_exit;
+    }
+
+    /// The time since the user last used the mouse, keyboard or touch screen.
+    pub fn time_since_input(&self) -> FloatDuration {
+        self.time_since_input
+    }
+
+    /// Keeps track of which window has the focus.
+    ///
+    /// Whether the application is active is only decided on the next update, so that moving
+    /// the focus between two windows doesn't deactivate it.
+    pub fn handle_window_event(&mut self, id: NativeWindowId, event: NativeWindowEvent) {
+        match event {
+            NativeWindowEvent::Activated => self.focused_window = Some(id),
+            NativeWindowEvent::Deactivated if self.focused_window == Some(id) => {
+                self.focused_window = None
+            }
+            _ => {}
+        }
+    }
+
+    /// Called when the user uses the mouse, keyboard or touch screen, dispatching
+    /// `userPresent` if they were idle.
+    pub fn user_input(context: &mut UpdateContext<'gc>) {
+        let application = &mut *context.native_application;
+        application.time_since_input = FloatDuration::ZERO;
+        if application.idle {
+            application.idle = false;
+            Self::dispatch(context, "userPresent", false);
+        }
+    }
+
+    /// Advances the idle time, and dispatches the events of any change in activation or idleness.
+    pub fn update(context: &mut UpdateContext<'gc>, dt: FloatDuration) {
+        let application = &mut *context.native_application;
+        let active = application.focused_window.is_some();
+        if application.active != active {
+            application.active = active;
+            let event_type = if active { "activate" } else { "deactivate" };
+            Self::dispatch(context, event_type, false);
+        }
+
+        let application = &mut *context.native_application;
+        application.time_since_input += dt;
+        let threshold = FloatDuration::from_secs(application.idle_threshold as f64);
+        if !application.idle && application.time_since_input >= threshold {
+            application.idle = true;
+            Self::dispatch(context, "userIdle", false);
+        }
+    }
+
+    /// Dispatches `exiting`, when the application is about to exit for any reason other than
+    /// `NativeApplication.exit`.
+    ///
+    /// Returns `false` if the movie prevented the application from exiting.
+    pub fn exiting(context: &mut UpdateContext<'gc>) -> bool {
+        !Self::dispatch(context, "exiting", true)
+    }
+
+    /// Called once every window of the application was closed, exiting if `autoExit` is set
+    /// and the movie doesn't prevent it.
+    pub fn windows_closed(context: &mut UpdateContext<'gc>) {
+        if context.native_application.auto_exit && Self::exiting(context) {
+            context.send_notification(PlayerNotification::ExitRequested { exit_code: 0 });
+        }
+    }
+
+    /// Dispatches an event to the `NativeApplication` singleton, returning whether it was
+    /// cancelled.
+    fn dispatch(context: &mut UpdateContext<'gc>, event_type: &str, cancelable: bool) -> bool {
+        let Some(object) = context.native_application.object else {
+            return false;
+        };
+        let event = EventObject::bare_event(context, event_type, false, cancelable);
+        Avm2::dispatch_event(context, event, object);
+        event.event().is_cancelled()
+    }
+}
@@ -0,0 +1,279 @@
+//! Programs run by AIR movies, exposed to ActionScript as `NativeProcess`.
+
+use crate::avm2::object::{ByteArrayObject, EventObject, Object as Avm2Object};
+use crate::avm2::{Activation as Avm2Activation, Avm2};
+use crate::backend::process::{NativeProcessStartupInfo, ProcessBackend};
+use crate::context::UpdateContext;
+use crate::string::AvmString;
+
+use async_channel::{Receiver, Sender, unbounded};
+use gc_arena::Collect;
+use slotmap::{SlotMap, new_key_type};
+
+new_key_type! {
+    pub struct NativeProcessHandle;
+}
+
+/// What a movie asks of a running program.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NativeProcessCommand {
+    /// Writes to the standard input of the program.
+    Write(Vec<u8>),
+
+    /// Closes the standard input of the program.
+    CloseInput,
+
+    /// Ends the program. Without `force`, the program is given a chance to exit on its own.
+    Exit { force: bool },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NativeProcessAction {
+    StandardOutput(NativeProcessHandle, Vec<u8>),
+    StandardError(NativeProcessHandle, Vec<u8>),
+    StandardOutputClosed(NativeProcessHandle),
+    StandardErrorClosed(NativeProcessHandle),
+    StandardInputClosed(NativeProcessHandle),
+
+    /// The program exited, with its exit code if it has one.
+    Exited(NativeProcessHandle, Option<i32>),
+}
+
+#[derive(Collect)]
+#[collect(no_drop)]
+struct NativeProcess<'gc> {
+    /// The `NativeProcess` object which started the program.
+    target: Avm2Object<'gc>,
+
+    standard_input: ByteArrayObject<'gc>,
+    standard_output: ByteArrayObject<'gc>,
+    standard_error: ByteArrayObject<'gc>,
+
+    #[collect(require_static)]
+    sender: Sender<NativeProcessCommand>,
+
+    /// Whether `closeInput` hasn't been called yet.
+    input_open: bool,
+}
+
+/// Manages the programs run by the movie.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct NativeProcesses<'gc> {
+    processes: SlotMap<NativeProcessHandle, NativeProcess<'gc>>,
+
+    #[collect(require_static)]
+    receiver: Receiver<NativeProcessAction>,
+    #[collect(require_static)]
+    sender: Sender<NativeProcessAction>,
+}
+
+impl<'gc> NativeProcesses<'gc> {
+    pub fn empty() -> Self {
+        let (sender, receiver) = unbounded();
+
+        Self {
+            processes: SlotMap::with_key(),
+            receiver,
+            sender,
+        }
+    }
+
+    /// Runs a program for the given `NativeProcess` object.
+    ///
+    /// What the program writes is appended to `standard_output` and `standard_error`, and what
+    /// the movie writes to `standard_input` is sent to the program on the next update.
+    pub fn start(
+        &mut self,
+        backend: &mut dyn ProcessBackend,
+        target: Avm2Object<'gc>,
+        [standard_input, standard_output, standard_error]: [ByteArrayObject<'gc>; 3],
+        info: NativeProcessStartupInfo,
+    ) {
+        let (sender, receiver) = unbounded();
+
+        let handle = self.processes.insert(NativeProcess {
+            target,
+            standard_input,
+            standard_output,
+            standard_error,
+            sender,
+            input_open: true,
+        });
+
+        // NOTE: This call will send NativeProcessAction::Exited to sender if the program can't run.
+        backend.start_process(info, handle, receiver, self.sender.clone());
+    }
+
+    fn find(&self, target: Avm2Object<'gc>) -> Option<NativeProcessHandle> {
+        self.processes
+            .iter()
+            .find(|(_, process)| Avm2Object::ptr_eq(process.target, target))
+            .map(|(handle, _)| handle)
+    }
+
+    pub fn is_running(&self, target: Avm2Object<'gc>) -> bool {
+        self.find(target).is_some()
+    }
+
+    /// Closes the standard input of the program, once everything written to it was sent.
+    pub fn close_input(&mut self, target: Avm2Object<'gc>) {
+        let Some(handle) = self.find(target) else {
+            return;
+        };
+        Self::flush_input(&mut self.processes[handle]);
+
+        let process = &mut self.processes[handle];
+        if process.input_open {
+            process.input_open = false;
+            Self::send(process, NativeProcessCommand::CloseInput);
+        }
+    }
+
+    pub fn exit(&mut self, target: Avm2Object<'gc>, force: bool) {
+        if let Some(handle) = self.find(target) {
+            Self::send(
+                &self.processes[handle],
+                NativeProcessCommand::Exit { force },
+            );
+        }
+    }
+
+    /// Stops every program, as when the movie is unloaded.
+    pub fn exit_all(&mut self) {
+        // NOTE: Dropping the senders makes the backend kill the programs.
+        self.processes.clear();
+    }
+
+    fn send(process: &NativeProcess<'gc>, command: NativeProcessCommand) {
+        // We use an unbounded channel, so this should only ever error if the program has
+        // already exited (the receiver was dropped)
+        if let Err(e) = process.sender.try_send(command) {
+            tracing::warn!("Failed to send command to native process: {:?}", e);
+        }
+    }
+
+    /// Sends what was written to the standard input of the program, returning how many bytes
+    /// were sent.
+    fn flush_input(process: &mut NativeProcess<'gc>) -> usize {
+        if !process.input_open {
+            return 0;
+        }
+
+        let data = {
+            let mut storage = process.standard_input.storage_mut();
+            let data = storage.bytes().to_vec();
+            storage.clear();
+            data
+        };
+        let length = data.len();
+        if length > 0 {
+            Self::send(process, NativeProcessCommand::Write(data));
+        }
+        length
+    }
+
+    /// Appends data to a stream the movie reads from, without moving its position.
+    fn append(stream: ByteArrayObject<'gc>, data: &[u8]) {
+        let mut storage = stream.storage_mut();
+        if storage.bytes_available() == 0 {
+            // Everything was read already, so there's no need to keep it around.
+            storage.clear();
+        }
+        let length = storage.len();
+        if let Err(e) = storage.write_at(data, length) {
+            tracing::warn!("Couldn't buffer output of native process: {:?}", e);
+        }
+    }
+
+    pub fn update_native_processes(context: &mut UpdateContext<'gc>) {
+        let mut written = vec![];
+        for (_, process) in context.native_processes.processes.iter_mut() {
+            let length = Self::flush_input(process);
+            if length > 0 {
+                written.push((process.target, length));
+            }
+        }
+
+        let mut actions = vec![];
+        while let Ok(action) = context.native_processes.receiver.try_recv() {
+            actions.push(action)
+        }
+
+        let mut activation = Avm2Activation::from_nothing(context);
+
+        for (target, length) in written {
+            let progress_evt = EventObject::progress_event(
+                &mut activation,
+                "standardInputProgress",
+                length,
+                length,
+            );
+            Avm2::dispatch_event(activation.context, progress_evt, target);
+        }
+
+        for action in actions {
+            let handle = match action {
+                NativeProcessAction::StandardOutput(handle, _)
+                | NativeProcessAction::StandardError(handle, _)
+                | NativeProcessAction::StandardOutputClosed(handle)
+                | NativeProcessAction::StandardErrorClosed(handle)
+                | NativeProcessAction::StandardInputClosed(handle)
+                | NativeProcessAction::Exited(handle, _) => handle,
+            };
+            let (target, standard_output, standard_error) =
+                match activation.context.native_processes.processes.get(handle) {
+                    Some(process) => (
+                        process.target,
+                        process.standard_output,
+                        process.standard_error,
+                    ),
+                    // The program must have been stopped before we could send the event.
+                    None => continue,
+                };
+
+            let event = match action {
+                NativeProcessAction::StandardOutput(_, data) => {
+                    Self::append(standard_output, &data);
+                    EventObject::progress_event(
+                        &mut activation,
+                        "standardOutputData",
+                        data.len(),
+                        0, // NOTE: bytesTotal is not used by standardOutputData event.
+                    )
+                }
+                NativeProcessAction::StandardError(_, data) => {
+                    Self::append(standard_error, &data);
+                    EventObject::progress_event(
+                        &mut activation,
+                        "standardErrorData",
+                        data.len(),
+                        0, // NOTE: bytesTotal is not used by standardErrorData event.
+                    )
+                }
+                NativeProcessAction::StandardOutputClosed(_) => {
+                    EventObject::bare_default_event(activation.context, "standardOutputClose")
+                }
+                NativeProcessAction::StandardErrorClosed(_) => {
+                    EventObject::bare_default_event(activation.context, "standardErrorClose")
+                }
+                NativeProcessAction::StandardInputClosed(_) => {
+                    EventObject::bare_default_event(activation.context, "standardInputClose")
+                }
+                NativeProcessAction::Exited(_, exit_code) => {
+                    activation.context.native_processes.processes.remove(handle);
+
+                    let class = activation.avm2().classes().nativeprocessexitevent;
+                    let args = [
+                        AvmString::new_utf8(activation.gc(), "exit").into(),
+                        false.into(),
+                        false.into(),
+                        exit_code.map_or(f64::NAN, f64::from).into(),
+                    ];
+                    EventObject::from_class_and_args(&mut activation, class, &args)
+                }
+            };
+            Avm2::dispatch_event(activation.context, event, target);
+        }
+    }
+}
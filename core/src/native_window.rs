@@ -0,0 +1,557 @@
+//! Windows of AIR applications, exposed to ActionScript as `NativeWindow`.
+//!
+//! The main window displays the player's stage. Every other window gets its own stage, which
+//! the renderer draws to that window, as told by the frontend with the ID of the window.
+
+use crate::avm2::object::{EventObject, Object as Avm2Object};
+use crate::avm2::{Activation as Avm2Activation, Avm2, Value as Avm2Value};
+use crate::backend::ui::{
+    NativeWindowBounds, NativeWindowDisplayState, NativeWindowId, NativeWindowOptions,
+};
+use crate::context::{RenderContext, UpdateContext};
+use crate::display_object::Stage;
+use crate::events::NativeWindowEvent;
+use crate::native_application::NativeApplication;
+use crate::player::MouseData;
+use crate::prelude::*;
+use crate::string::AvmString;
+use crate::vminterface::Instantiator;
+use gc_arena::Collect;
+use ruffle_render::commands::CommandList;
+use ruffle_render::transform::TransformStack;
+
+/// The size of a window when neither the movie nor the UI backend chose one.
+const DEFAULT_SIZE: (u32, u32) = (550, 400);
+
+#[derive(Collect)]
+#[collect(no_drop)]
+struct NativeWindow<'gc> {
+    #[collect(require_static)]
+    id: NativeWindowId,
+
+    /// The AVM2 `NativeWindow` object.
+    object: Avm2Object<'gc>,
+
+    stage: Stage<'gc>,
+
+    /// Whether the window has an alpha channel, rather than the stage's background color.
+    transparent: bool,
+
+    /// The last known bounds and display state, to tell which events to dispatch when the
+    /// window changes.
+    #[collect(require_static)]
+    bounds: NativeWindowBounds,
+    #[collect(require_static)]
+    display_state: NativeWindowDisplayState,
+
+    active: bool,
+
+    /// The mouse state of the window, while its input events aren't being handled.
+    mouse_data: MouseData<'gc>,
+    #[collect(require_static)]
+    mouse_position: Point<Twips>,
+}
+
+/// The stage and mouse state of the player, while they're swapped out for those of a window.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct MainInput<'gc> {
+    /// The window whose input events are being handled.
+    #[collect(require_static)]
+    id: NativeWindowId,
+
+    stage: Stage<'gc>,
+    mouse_data: MouseData<'gc>,
+    #[collect(require_static)]
+    mouse_position: Point<Twips>,
+}
+
+/// Manages the windows opened by the movie.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct NativeWindows<'gc> {
+    windows: Vec<NativeWindow<'gc>>,
+
+    #[collect(require_static)]
+    next_id: u32,
+
+    /// Whether the main window was closed, which may happen before its `NativeWindow` exists.
+    main_closed: bool,
+
+    main_input: Option<MainInput<'gc>>,
+}
+
+impl Default for NativeWindows<'_> {
+    fn default() -> Self {
+        Self {
+            windows: Vec::new(),
+            next_id: NativeWindowId::MAIN.0 + 1,
+            main_closed: false,
+            main_input: None,
+        }
+    }
+}
+
+impl<'gc> NativeWindows<'gc> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn get(&self, id: NativeWindowId) -> Option<&NativeWindow<'gc>> {
+        self.windows.iter().find(|window| window.id == id)
+    }
+
+    fn get_mut(&mut self, id: NativeWindowId) -> Option<&mut NativeWindow<'gc>> {
+        self.windows.iter_mut().find(|window| window.id == id)
+    }
+
+    /// The window of the given `NativeWindow` object, if it's open.
+    pub fn id_of(&self, object: Avm2Object<'gc>) -> Option<NativeWindowId> {
+        self.windows
+            .iter()
+            .find(|window| Avm2Object::ptr_eq(window.object, object))
+            .map(|window| window.id)
+    }
+
+    /// The `NativeWindow` object displaying the given stage, if it's open.
+    pub fn object_of_stage(&self, stage: Stage<'gc>) -> Option<Avm2Object<'gc>> {
+        self.windows
+            .iter()
+            .find(|window| DisplayObject::ptr_eq(window.stage.into(), stage.into()))
+            .map(|window| window.object)
+    }
+
+    pub fn is_active(&self, id: NativeWindowId) -> bool {
+        self.get(id).is_some_and(|window| window.active)
+    }
+
+    /// The `NativeWindow` objects of the open windows, in the order they were opened.
+    pub fn objects(&self) -> impl Iterator<Item = Avm2Object<'gc>> + '_ {
+        self.windows.iter().map(|window| window.object)
+    }
+
+    /// The `NativeWindow` object of the window that has the focus, if any.
+    pub fn active_object(&self) -> Option<Avm2Object<'gc>> {
+        self.windows
+            .iter()
+            .find(|window| window.active)
+            .map(|window| window.object)
+    }
+
+    /// The player's stage, even while the stage of another window stands in for it.
+    pub fn main_stage(context: &UpdateContext<'gc>) -> Stage<'gc> {
+        context
+            .native_windows
+            .main_input
+            .as_ref()
+            .map_or(context.stage, |main_input| main_input.stage)
+    }
+
+    /// Registers the `NativeWindow` object of the main window, which displays the player's stage.
+    pub fn open_main(context: &mut UpdateContext<'gc>, object: Avm2Object<'gc>) {
+        if context.native_windows.main_closed {
+            return;
+        }
+
+        let id = NativeWindowId::MAIN;
+        let stage = Self::main_stage(context);
+        let (width, height) = stage.stage_size();
+        let bounds = context
+            .ui
+            .native_window_bounds(id)
+            .unwrap_or(NativeWindowBounds {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            });
+        let display_state = context.ui.native_window_display_state(id);
+
+        context
+            .native_windows
+            .windows
+            .retain(|window| window.id != id);
+        // The main window is always the first one, even when its object was created last.
+        context.native_windows.windows.insert(
+            0,
+            NativeWindow {
+                id,
+                object,
+                stage,
+                transparent: false,
+                bounds,
+                display_state,
+                active: true,
+                mouse_data: MouseData::default(),
+                mouse_position: Point::ZERO,
+            },
+        );
+    }
+
+    /// Opens a new window for the given `NativeWindow` object, returning the stage it displays.
+    ///
+    /// The window still gets a stage if the UI backend can't open windows, it just isn't
+    /// displayed anywhere.
+    pub fn open(
+        context: &mut UpdateContext<'gc>,
+        object: Avm2Object<'gc>,
+        options: NativeWindowOptions,
+    ) -> Stage<'gc> {
+        let id = NativeWindowId(context.native_windows.next_id);
+        context.native_windows.next_id += 1;
+
+        let transparent = options.transparent;
+        let requested_bounds = options.bounds;
+        let opened = context.ui.open_native_window(id, options);
+        let bounds = opened
+            .then(|| context.ui.native_window_bounds(id))
+            .flatten()
+            .or(requested_bounds)
+            .unwrap_or(NativeWindowBounds {
+                x: 0,
+                y: 0,
+                width: DEFAULT_SIZE.0,
+                height: DEFAULT_SIZE.1,
+            });
+
+        let stage = Stage::empty(context.gc(), false, context.root_swf.clone());
+        stage.post_instantiation(context, None, Instantiator::Avm2, false);
+        stage.set_stage_size(context, bounds.width, bounds.height);
+
+        context.native_windows.windows.push(NativeWindow {
+            id,
+            object,
+            stage,
+            transparent,
+            bounds,
+            display_state: NativeWindowDisplayState::Normal,
+            active: false,
+            mouse_data: MouseData::default(),
+            mouse_position: Point::ZERO,
+        });
+
+        stage
+    }
+
+    /// Makes the stage and mouse state of a window the ones input events act on, by swapping
+    /// them with the player's.
+    ///
+    /// Returns `false` if the window isn't open, or isn't a window of its own.
+    pub fn enter_input(
+        &mut self,
+        id: NativeWindowId,
+        stage: &mut Stage<'gc>,
+        mouse_data: &mut MouseData<'gc>,
+        mouse_position: &mut Point<Twips>,
+    ) -> bool {
+        if id == NativeWindowId::MAIN || self.main_input.is_some() {
+            return false;
+        }
+        let Some(window) = self.windows.iter_mut().find(|window| window.id == id) else {
+            return false;
+        };
+
+        self.main_input = Some(MainInput {
+            id,
+            stage: std::mem::replace(stage, window.stage),
+            mouse_data: std::mem::replace(mouse_data, std::mem::take(&mut window.mouse_data)),
+            mouse_position: std::mem::replace(mouse_position, window.mouse_position),
+        });
+        true
+    }
+
+    /// Gives the player its stage and mouse state back after `enter_input`.
+    ///
+    /// The mouse state of the window is kept for its next input events, unless it was closed
+    /// in the meantime.
+    pub fn leave_input(
+        &mut self,
+        stage: &mut Stage<'gc>,
+        mouse_data: &mut MouseData<'gc>,
+        mouse_position: &mut Point<Twips>,
+    ) {
+        let Some(main_input) = self.main_input.take() else {
+            return;
+        };
+
+        *stage = main_input.stage;
+        let window_mouse_data = std::mem::replace(mouse_data, main_input.mouse_data);
+        let window_mouse_position = std::mem::replace(mouse_position, main_input.mouse_position);
+        if let Some(window) = self.get_mut(main_input.id) {
+            window.mouse_data = window_mouse_data;
+            window.mouse_position = window_mouse_position;
+        }
+    }
+
+    /// The bounds of a window, as last reported by the UI backend or set by the movie.
+    pub fn bounds(&self, id: NativeWindowId) -> NativeWindowBounds {
+        self.get(id).map(|window| window.bounds).unwrap_or_default()
+    }
+
+    /// Moves or resizes a window on behalf of the movie.
+    pub fn set_bounds(
+        context: &mut UpdateContext<'gc>,
+        id: NativeWindowId,
+        bounds: NativeWindowBounds,
+    ) {
+        context.ui.set_native_window_bounds(id, bounds);
+
+        // Without a window to move, the window is considered to be where the movie wants it.
+        let new_bounds = context.ui.native_window_bounds(id).unwrap_or(bounds);
+        Self::update_bounds(context, id, new_bounds);
+    }
+
+    pub fn display_state(&self, id: NativeWindowId) -> NativeWindowDisplayState {
+        self.get(id)
+            .map(|window| window.display_state)
+            .unwrap_or_default()
+    }
+
+    /// Closes a window, dispatching `close` to its `NativeWindow` object.
+    pub fn close(context: &mut UpdateContext<'gc>, id: NativeWindowId) {
+        if context.native_windows.get(id).is_some() {
+            context.ui.close_native_window(id);
+            Self::closed(context, id);
+        }
+    }
+
+    /// Forgets a window that has been closed, dispatching `deactivate` and `close` to its
+    /// `NativeWindow`.
+    ///
+    /// Once the last window is closed, the application may exit.
+    fn closed(context: &mut UpdateContext<'gc>, id: NativeWindowId) {
+        if id == NativeWindowId::MAIN {
+            context.native_windows.main_closed = true;
+        } else {
+            context.renderer.remove_window_target(id.0);
+        }
+
+        let index = context
+            .native_windows
+            .windows
+            .iter()
+            .position(|window| window.id == id);
+        if let Some(index) = index {
+            let window = context.native_windows.windows.remove(index);
+
+            if window.active {
+                let event = EventObject::bare_default_event(context, "deactivate");
+                Avm2::dispatch_event(context, event, window.object);
+            }
+            let event = EventObject::bare_default_event(context, "close");
+            Avm2::dispatch_event(context, event, window.object);
+        }
+
+        let native_windows = &context.native_windows;
+        let any_open = !native_windows.main_closed
+            || native_windows
+                .windows
+                .iter()
+                .any(|window| window.id != NativeWindowId::MAIN);
+        if !any_open {
+            NativeApplication::windows_closed(context);
+        }
+    }
+
+    /// Handles an event from a window, dispatching the matching events to its `NativeWindow`.
+    ///
+    /// Returns `false` if the movie prevented the window from closing.
+    pub fn handle_event(
+        context: &mut UpdateContext<'gc>,
+        id: NativeWindowId,
+        event: NativeWindowEvent,
+    ) -> bool {
+        let Some(window) = context.native_windows.get(id) else {
+            // The main window can be closed before its `NativeWindow` exists.
+            if id == NativeWindowId::MAIN && event == NativeWindowEvent::CloseRequested {
+                Self::closed(context, id);
+            }
+            return true;
+        };
+        let object = window.object;
+
+        match event {
+            NativeWindowEvent::BoundsChanged => {
+                let new_bounds = context.ui.native_window_bounds(id).unwrap_or(window.bounds);
+                Self::update_bounds(context, id, new_bounds);
+            }
+            NativeWindowEvent::Activated | NativeWindowEvent::Deactivated => {
+                let active = event == NativeWindowEvent::Activated;
+                let Some(window) = context.native_windows.get_mut(id) else {
+                    return true;
+                };
+                if window.active != active {
+                    window.active = active;
+                    let event_type = if active { "activate" } else { "deactivate" };
+                    let event = EventObject::bare_default_event(context, event_type);
+                    Avm2::dispatch_event(context, event, object);
+                }
+            }
+            NativeWindowEvent::CloseRequested => {
+                let event = EventObject::bare_event(context, "closing", false, true);
+                Avm2::dispatch_event(context, event, object);
+                if event.event().is_cancelled() {
+                    return false;
+                }
+                Self::close(context, id);
+            }
+        }
+
+        true
+    }
+
+    /// Updates the bounds of a window and reads its display state from the UI backend,
+    /// dispatching `displayStateChange`, `move` and `resize` for what changed.
+    fn update_bounds(
+        context: &mut UpdateContext<'gc>,
+        id: NativeWindowId,
+        new_bounds: NativeWindowBounds,
+    ) {
+        let new_display_state = context.ui.native_window_display_state(id);
+        let Some(window) = context.native_windows.get_mut(id) else {
+            return;
+        };
+        let object = window.object;
+        let stage = window.stage;
+        let old_bounds = window.bounds;
+        let old_display_state = window.display_state;
+        window.bounds = new_bounds;
+        window.display_state = new_display_state;
+
+        if id != NativeWindowId::MAIN {
+            stage.set_stage_size(context, new_bounds.width, new_bounds.height);
+            context
+                .renderer
+                .resize_window_target(id.0, new_bounds.width, new_bounds.height);
+        }
+
+        let mut activation = Avm2Activation::from_nothing(context);
+        if new_display_state != old_display_state {
+            let class = activation.avm2().classes().nativewindowdisplaystateevent;
+            let args = [
+                string_value(&mut activation, "displayStateChange"),
+                false.into(),
+                false.into(),
+                string_value(&mut activation, display_state_name(old_display_state)),
+                string_value(&mut activation, display_state_name(new_display_state)),
+            ];
+            let event = EventObject::from_class_and_args(&mut activation, class, &args);
+            Avm2::dispatch_event(activation.context, event, object);
+        }
+
+        let moved = (old_bounds.x, old_bounds.y) != (new_bounds.x, new_bounds.y);
+        let resized =
+            (old_bounds.width, old_bounds.height) != (new_bounds.width, new_bounds.height);
+        for (event_type, changed) in [("move", moved), ("resize", resized)] {
+            if !changed {
+                continue;
+            }
+            let class = activation.avm2().classes().nativewindowboundsevent;
+            let args = [
+                string_value(&mut activation, event_type),
+                false.into(),
+                false.into(),
+                bounds_to_rectangle(&mut activation, old_bounds),
+                bounds_to_rectangle(&mut activation, new_bounds),
+            ];
+            let event = EventObject::from_class_and_args(&mut activation, class, &args);
+            Avm2::dispatch_event(activation.context, event, object);
+        }
+    }
+
+    /// Calls `f` on the children of the stages of every window but the main one.
+    ///
+    /// These children aren't on the player's stage nor orphans, so they're run separately.
+    pub fn each_window_child(
+        context: &mut UpdateContext<'gc>,
+        mut f: impl FnMut(DisplayObject<'gc>, &mut UpdateContext<'gc>),
+    ) {
+        let stages: Vec<_> = context
+            .native_windows
+            .windows
+            .iter()
+            .filter(|window| window.id != NativeWindowId::MAIN)
+            .map(|window| window.stage)
+            .collect();
+
+        for stage in stages {
+            for child in stage.iter_render_list() {
+                f(child, context);
+            }
+        }
+    }
+
+    /// Draws the stages of every window but the main one to their windows.
+    pub fn render_windows(context: &mut UpdateContext<'gc>) {
+        let windows: Vec<_> = context
+            .native_windows
+            .windows
+            .iter()
+            .filter(|window| window.id != NativeWindowId::MAIN)
+            .map(|window| (window.id, window.stage, window.transparent))
+            .collect();
+
+        for (id, stage, transparent) in windows {
+            let (width, height) = stage.stage_size();
+            if width == 0 || height == 0 {
+                continue;
+            }
+
+            let background = if transparent {
+                Color::from_rgba(0)
+            } else {
+                stage.background_color().unwrap_or(Color::WHITE)
+            };
+
+            let mut cache_draws = vec![];
+            let mut transform_stack = TransformStack::new();
+            let mut render_context = RenderContext {
+                renderer: context.renderer,
+                commands: CommandList::new(),
+                cache_draws: &mut cache_draws,
+                gc_context: context.gc_context,
+                library: context.library,
+                transform_stack: &mut transform_stack,
+                is_offscreen: false,
+                use_bitmap_cache: true,
+                stage,
+            };
+            stage.render_viewport(&mut render_context);
+            let commands = render_context.commands;
+
+            context
+                .renderer
+                .submit_window_frame(id.0, background, commands, cache_draws);
+        }
+    }
+}
+
+pub fn display_state_name(state: NativeWindowDisplayState) -> &'static str {
+    match state {
+        NativeWindowDisplayState::Normal => "normal",
+        NativeWindowDisplayState::Minimized => "minimized",
+        NativeWindowDisplayState::Maximized => "maximized",
+    }
+}
+
+fn string_value<'gc>(activation: &mut Avm2Activation<'_, 'gc>, value: &str) -> Avm2Value<'gc> {
+    AvmString::new_utf8(activation.gc(), value).into()
+}
+
+fn bounds_to_rectangle<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc>,
+    bounds: NativeWindowBounds,
+) -> Avm2Value<'gc> {
+    let args = [
+        bounds.x.into(),
+        bounds.y.into(),
+        bounds.width.into(),
+        bounds.height.into(),
+    ];
+    activation
+        .avm2()
+        .classes()
+        .rectangle
+        .construct(activation, &args)
+        .expect("Rectangle constructor doesn't throw")
+}
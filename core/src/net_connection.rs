@@ -4,19 +4,34 @@ use crate::avm1::globals::netconnection::NetConnection as Avm1NetConnectionObjec
 use crate::avm2::object::{
     NetConnectionObject as Avm2NetConnectionObject, ResponderObject as Avm2ResponderObject,
 };
-use crate::avm2::{Activation as Avm2Activation, Avm2, EventObject as Avm2EventObject};
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, EventObject as Avm2EventObject, FunctionArgs,
+    Value as Avm2Value,
+};
 use crate::backend::navigator::{
     ErrorResponse, FetchReason, NavigatorBackend, OwnedFuture, Request,
 };
 use crate::context::UpdateContext;
 use crate::loader::Error;
+use crate::socket::{SocketHandle, Sockets};
+use crate::streams::{NetStream, NetStreamHandle};
+use crate::string::AvmString;
 use flash_lso::packet::{Header, Message, Packet};
-use flash_lso::types::{AMFVersion, Value as AmfValue};
+use flash_lso::types::{AMFVersion, Element, ObjectId, Value as AmfValue};
 use gc_arena::{Collect, DynamicRoot, Gc, Rootable};
+use rtmp::{Command, Session};
 use slotmap::{SlotMap, new_key_type};
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+mod rtmp;
+
+/// How long to wait for the socket of an RTMP connection to connect.
+const RTMP_CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
 
 new_key_type! {
     pub struct NetConnectionHandle;
@@ -98,6 +113,90 @@ impl NetConnectionObject<'_> {
     }
 }
 
+impl<'gc> NetConnectionObject<'gc> {
+    /// Dispatch a `netStatus` event (AVM2) or call `onStatus` (AVM1) with
+    /// the given info object properties.
+    fn dispatch_status<'a>(
+        self,
+        context: &mut UpdateContext<'gc>,
+        info: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) {
+        match self {
+            NetConnectionObject::Avm2(object) => {
+                let mut activation = Avm2Activation::from_nothing(context);
+                let event = Avm2EventObject::net_status_event(&mut activation, info);
+                Avm2::dispatch_event(activation.context, event, object.into());
+            }
+            NetConnectionObject::Avm1(object) => {
+                if let Err(e) = Avm1NetConnectionObject::on_status_info(context, object, info) {
+                    tracing::error!("Unhandled error sending connection callback: {e}");
+                }
+            }
+        }
+    }
+
+    /// Call a method of the client of this connection, on behalf of the
+    /// server, returning what it returned.
+    fn call_client(
+        self,
+        context: &mut UpdateContext<'gc>,
+        name: &str,
+        arguments: &[Rc<AmfValue>],
+    ) -> AmfValue {
+        match self {
+            NetConnectionObject::Avm2(object) => {
+                let mut activation = Avm2Activation::from_nothing(context);
+                let arguments: Vec<_> = arguments
+                    .iter()
+                    .map(|argument| {
+                        crate::avm2::amf::deserialize_value(&mut activation, argument)
+                            .unwrap_or(Avm2Value::Undefined)
+                    })
+                    .collect();
+                let client_name = AvmString::new_utf8(activation.gc(), "client");
+                let name = AvmString::new_utf8(activation.gc(), name);
+                let result = Avm2Value::from(object)
+                    .get_public_property(client_name, &mut activation)
+                    .and_then(|client| {
+                        client.call_public_property(
+                            name,
+                            FunctionArgs::from_slice(&arguments),
+                            &mut activation,
+                        )
+                    });
+
+                match result {
+                    Ok(value) => crate::avm2::amf::serialize_value(
+                        &mut activation,
+                        value,
+                        AMFVersion::AMF0,
+                        &mut Default::default(),
+                    )
+                    .unwrap_or(AmfValue::Undefined),
+                    Err(err) => {
+                        Avm2::uncaught_error(
+                            &mut activation,
+                            None,
+                            err,
+                            "Error running AVM2 NetConnection client method",
+                        );
+                        AmfValue::Undefined
+                    }
+                }
+            }
+            NetConnectionObject::Avm1(object) => {
+                match Avm1NetConnectionObject::call_client(context, object, name, arguments) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        tracing::error!("Unhandled error calling NetConnection client: {e}");
+                        AmfValue::Undefined
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl<'gc> From<Avm2NetConnectionObject<'gc>> for NetConnectionObject<'gc> {
     fn from(value: Avm2NetConnectionObject<'gc>) -> Self {
         NetConnectionObject::Avm2(value)
@@ -188,11 +287,369 @@ impl<'gc> NetConnections<'gc> {
         // No open event here
     }
 
+    /// Connect to an RTMP server over a socket.
+    ///
+    /// `arguments` are the extra arguments of `NetConnection.connect`, which
+    /// are passed on to the server's `connect` handler.
+    pub fn connect_to_rtmp<O: Into<NetConnectionObject<'gc>>>(
+        context: &mut UpdateContext<'gc>,
+        target: O,
+        url: String,
+        arguments: Vec<Rc<AmfValue>>,
+    ) {
+        let target = target.into();
+        let Some((host, port, app)) = Url::parse(&url).ok().and_then(|parsed| {
+            let host = parsed.host_str()?.to_string();
+            let port = parsed.port().unwrap_or(rtmp::DEFAULT_PORT);
+            let app = parsed.path().trim_start_matches('/').to_string();
+            Some((host, port, app))
+        }) else {
+            tracing::warn!("Invalid RTMP URL {url}");
+            target.dispatch_status(
+                context,
+                [("code", "NetConnection.Connect.Failed"), ("level", "error")],
+            );
+            return;
+        };
+
+        let string = |value: &str| Rc::new(AmfValue::String(value.to_string()));
+        let number = |value: f64| Rc::new(AmfValue::Number(value));
+        let flash_version = context.system.get_version_string(context.player_version);
+        let properties = [
+            ("app", string(&app)),
+            ("flashVer", string(&flash_version)),
+            ("swfUrl", string(context.root_swf.url())),
+            ("tcUrl", string(&url)),
+            ("fpad", Rc::new(AmfValue::Bool(false))),
+            ("capabilities", number(239.0)),
+            ("audioCodecs", number(3575.0)),
+            ("videoCodecs", number(252.0)),
+            ("videoFunction", number(1.0)),
+            ("pageUrl", Rc::new(AmfValue::Undefined)),
+            // Commands are always written in AMF0.
+            ("objectEncoding", number(0.0)),
+        ];
+        let command_object = AmfValue::Object(
+            ObjectId::INVALID,
+            properties
+                .into_iter()
+                .map(|(name, value)| Element::new(name, value))
+                .collect(),
+            None,
+        );
+
+        let connection = NetConnection {
+            object: target,
+            protocol: NetConnectionProtocol::Rtmp(Rtmp {
+                url,
+                socket: SocketHandle::default(),
+                session: Session::new(),
+                is_connected: false,
+                queued_commands: vec![(
+                    0,
+                    Command::new(
+                        "connect",
+                        Rtmp::CONNECT_TRANSACTION_ID as f64,
+                        command_object,
+                        arguments,
+                    ),
+                )],
+                next_transaction_id: Rtmp::CONNECT_TRANSACTION_ID + 1,
+                pending_calls: HashMap::from([(
+                    Rtmp::CONNECT_TRANSACTION_ID,
+                    PendingCall::Connect,
+                )]),
+                streams: HashMap::new(),
+            }),
+        };
+        let handle = context.net_connections.connections.insert(connection);
+
+        // NOTE: This will call `NetConnections::socket_connected` once connected.
+        let socket = context.sockets.connect_net_connection(
+            context.navigator,
+            handle,
+            host,
+            port,
+            RTMP_CONNECT_TIMEOUT,
+        );
+        if let Some(rtmp) = context.net_connections.rtmp_mut(handle) {
+            rtmp.socket = socket;
+        }
+
+        if let Some(existing_handle) = target.set_handle(Some(handle)) {
+            NetConnections::close(context, existing_handle, false);
+        }
+    }
+
+    /// Called when the socket of an RTMP connection connected.
+    pub fn socket_connected(context: &mut UpdateContext<'gc>, handle: NetConnectionHandle) {
+        if let Some(rtmp) = context.net_connections.rtmp_mut(handle) {
+            rtmp.session.start();
+            rtmp.flush(context.sockets);
+        }
+    }
+
+    /// Called when the socket of an RTMP connection failed to connect.
+    pub fn socket_failed(context: &mut UpdateContext<'gc>, handle: NetConnectionHandle) {
+        let Some(connection) = context.net_connections.connections.remove(handle) else {
+            return;
+        };
+
+        if let NetConnectionProtocol::Rtmp(rtmp) = &connection.protocol {
+            context.sockets.close(rtmp.socket);
+        }
+        connection.object.dispatch_status(
+            context,
+            [("code", "NetConnection.Connect.Failed"), ("level", "error")],
+        );
+    }
+
+    /// Called when the socket of an RTMP connection was closed by the server.
+    pub fn socket_closed(context: &mut UpdateContext<'gc>, handle: NetConnectionHandle) {
+        let Some(rtmp) = context.net_connections.rtmp_mut(handle) else {
+            return;
+        };
+
+        if rtmp.is_connected {
+            NetConnections::close(context, handle, false);
+        } else {
+            // The server hung up before accepting the connection.
+            NetConnections::socket_failed(context, handle);
+        }
+    }
+
+    /// Called when the socket of an RTMP connection received data.
+    pub fn socket_data(context: &mut UpdateContext<'gc>, handle: NetConnectionHandle, data: &[u8]) {
+        let Some(rtmp) = context.net_connections.rtmp_mut(handle) else {
+            return;
+        };
+
+        let messages = rtmp.session.receive(data);
+        rtmp.flush(context.sockets);
+
+        match messages {
+            Ok(messages) => {
+                for message in messages {
+                    NetConnections::handle_rtmp_message(context, handle, message);
+                }
+            }
+            Err(e) => {
+                tracing::error!("RTMP protocol error: {e}");
+                NetConnections::socket_closed(context, handle);
+            }
+        }
+    }
+
+    fn handle_rtmp_message(
+        context: &mut UpdateContext<'gc>,
+        handle: NetConnectionHandle,
+        message: rtmp::Message,
+    ) {
+        let flv_tag_type = match message.type_id {
+            rtmp::COMMAND_AMF0 | rtmp::COMMAND_AMF3 => {
+                match Command::parse(&message) {
+                    Ok(command) => {
+                        NetConnections::handle_rtmp_command(
+                            context,
+                            handle,
+                            message.stream_id,
+                            command,
+                        );
+                    }
+                    Err(e) => tracing::warn!("Ignoring invalid RTMP command: {e}"),
+                }
+                return;
+            }
+            rtmp::AUDIO => 8,
+            rtmp::VIDEO => 9,
+            rtmp::DATA_AMF0 | rtmp::DATA_AMF3 => 18,
+            _ => return,
+        };
+
+        // Media and data messages of a stream are exactly the bodies of the
+        // equivalent FLV tags.
+        let Some(stream) = context
+            .net_connections
+            .rtmp_mut(handle)
+            .and_then(|rtmp| rtmp.streams.get(&message.stream_id))
+            .cloned()
+        else {
+            return;
+        };
+        let mut data = &message.payload[..];
+        if message.type_id == rtmp::DATA_AMF3 && data.first() == Some(&0) {
+            data = &data[1..];
+        }
+        stream
+            .fetch(context)
+            .append_flv_tag(context, flv_tag_type, message.timestamp, data);
+    }
+
+    fn handle_rtmp_command(
+        context: &mut UpdateContext<'gc>,
+        handle: NetConnectionHandle,
+        stream_id: u32,
+        command: Command,
+    ) {
+        let Some(connection) = context.net_connections.connections.get_mut(handle) else {
+            return;
+        };
+        let NetConnectionProtocol::Rtmp(rtmp) = &mut connection.protocol else {
+            return;
+        };
+        let object = connection.object;
+        let info = command
+            .arguments
+            .first()
+            .cloned()
+            .unwrap_or_else(|| Rc::new(AmfValue::Null));
+
+        match command.name.as_str() {
+            "_result" | "_error" => {
+                let is_result = command.name == "_result";
+                let Some(call) = rtmp.pending_calls.remove(&(command.transaction_id as u32)) else {
+                    return;
+                };
+
+                match call {
+                    PendingCall::Connect => {
+                        rtmp.is_connected = is_result;
+                        let info = status_info(&info);
+                        object.dispatch_status(
+                            context,
+                            info.iter()
+                                .map(|(key, value)| (key.as_str(), value.as_str())),
+                        );
+                        if !is_result {
+                            NetConnections::close(context, handle, false);
+                        }
+                    }
+                    PendingCall::Responder(responder) => {
+                        let callback = if is_result {
+                            ResponderCallback::Result
+                        } else {
+                            ResponderCallback::Status
+                        };
+                        responder.call(context, callback, info);
+                    }
+                    PendingCall::CreateStream(stream, name) => {
+                        let (true, AmfValue::Number(stream_id)) = (is_result, &*info) else {
+                            tracing::warn!("RTMP server failed to create a stream");
+                            return;
+                        };
+                        let stream_id = *stream_id as u32;
+                        rtmp.streams.insert(stream_id, stream.clone());
+
+                        let stream = stream.fetch(context);
+                        let buffer_length = (stream.buffer_time() * 1000.0) as u32;
+                        if let Some(rtmp) = context.net_connections.rtmp_mut(handle) {
+                            rtmp.session.send_buffer_length(stream_id, buffer_length);
+                            rtmp.send_command(
+                                stream_id,
+                                Command::new(
+                                    "play",
+                                    0.0,
+                                    AmfValue::Null,
+                                    vec![Rc::new(AmfValue::String(name))],
+                                ),
+                            );
+                            rtmp.flush(context.sockets);
+                        }
+                        stream.play_live(context);
+                    }
+                }
+            }
+            "onStatus" if stream_id != 0 => {
+                let Some(stream) = rtmp.streams.get(&stream_id).cloned() else {
+                    return;
+                };
+                let info = status_info(&info);
+                stream.fetch(context).trigger_status_event(
+                    context,
+                    info.iter()
+                        .map(|(key, value)| (key.as_str(), value.as_str())),
+                );
+            }
+            "onStatus" => {
+                let info = status_info(&info);
+                object.dispatch_status(
+                    context,
+                    info.iter()
+                        .map(|(key, value)| (key.as_str(), value.as_str())),
+                );
+            }
+            "close" => NetConnections::close(context, handle, false),
+            name if stream_id == 0 => {
+                let result = object.call_client(context, name, &command.arguments);
+                if command.transaction_id != 0.0
+                    && let Some(rtmp) = context.net_connections.rtmp_mut(handle)
+                {
+                    rtmp.send_command(
+                        0,
+                        Command::new(
+                            "_result",
+                            command.transaction_id,
+                            AmfValue::Null,
+                            vec![Rc::new(result)],
+                        ),
+                    );
+                    rtmp.flush(context.sockets);
+                }
+            }
+            name => tracing::warn!("Ignoring RTMP command {name} sent to stream {stream_id}"),
+        }
+    }
+
+    /// Start playing a stream from an RTMP server, if the connection is one.
+    ///
+    /// Returns `false` if the connection isn't an RTMP connection, in which
+    /// case the stream should be played from a file instead.
+    pub fn play_rtmp_stream(
+        context: &mut UpdateContext<'gc>,
+        handle: NetConnectionHandle,
+        stream: NetStream<'gc>,
+        name: String,
+    ) -> bool {
+        if context.net_connections.rtmp_mut(handle).is_none() {
+            return false;
+        }
+
+        let stream = NetStreamHandle::stash(context, stream);
+        if let Some(rtmp) = context.net_connections.rtmp_mut(handle) {
+            let transaction_id = rtmp.next_transaction_id();
+            rtmp.pending_calls
+                .insert(transaction_id, PendingCall::CreateStream(stream, name));
+            rtmp.send_command(
+                0,
+                Command::new(
+                    "createStream",
+                    transaction_id as f64,
+                    AmfValue::Null,
+                    vec![],
+                ),
+            );
+            rtmp.flush(context.sockets);
+        }
+
+        true
+    }
+
+    fn rtmp_mut(&mut self, handle: NetConnectionHandle) -> Option<&mut Rtmp> {
+        match &mut self.connections.get_mut(handle)?.protocol {
+            NetConnectionProtocol::Rtmp(rtmp) => Some(rtmp),
+            _ => None,
+        }
+    }
+
     pub fn close(context: &mut UpdateContext<'gc>, handle: NetConnectionHandle, is_explicit: bool) {
         let Some(connection) = context.net_connections.connections.remove(handle) else {
             return;
         };
 
+        if let NetConnectionProtocol::Rtmp(rtmp) = &connection.protocol {
+            context.sockets.close(rtmp.socket);
+        }
+
         match connection.object {
             NetConnectionObject::Avm2(object) => {
                 let mut activation = Avm2Activation::from_nothing(context);
@@ -243,6 +700,10 @@ impl<'gc> NetConnections<'gc> {
         let player = context.player_handle();
         for (handle, connection) in context.net_connections.connections.iter_mut() {
             connection.update(handle, context.navigator, &player);
+
+            if let NetConnectionProtocol::Rtmp(rtmp) = &mut connection.protocol {
+                rtmp.flush(context.sockets);
+            }
         }
     }
 
@@ -348,23 +809,25 @@ pub struct NetConnection<'gc> {
 
 impl NetConnection<'_> {
     pub fn is_connected(&self) -> bool {
-        match self.protocol {
+        match &self.protocol {
             NetConnectionProtocol::Local => true,
             NetConnectionProtocol::FlashRemoting(_) => false,
+            NetConnectionProtocol::Rtmp(rtmp) => rtmp.is_connected,
         }
     }
 
     pub fn connected_proxy_type(&self) -> Option<&'static str> {
-        match self.protocol {
+        match &self.protocol {
             NetConnectionProtocol::Local => Some("none"),
             NetConnectionProtocol::FlashRemoting(_) => None,
+            NetConnectionProtocol::Rtmp(rtmp) => rtmp.is_connected.then_some("none"),
         }
     }
 
     pub fn far_id(&self) -> Option<&'static str> {
         match self.protocol {
             NetConnectionProtocol::Local => Some(""),
-            NetConnectionProtocol::FlashRemoting(_) => None,
+            NetConnectionProtocol::FlashRemoting(_) | NetConnectionProtocol::Rtmp(_) => None,
         }
     }
 
@@ -373,14 +836,14 @@ impl NetConnection<'_> {
             NetConnectionProtocol::Local => {
                 Some("0000000000000000000000000000000000000000000000000000000000000000")
             }
-            NetConnectionProtocol::FlashRemoting(_) => None,
+            NetConnectionProtocol::FlashRemoting(_) | NetConnectionProtocol::Rtmp(_) => None,
         }
     }
 
     pub fn near_id(&self) -> Option<&'static str> {
         match self.protocol {
             NetConnectionProtocol::Local => Some(""),
-            NetConnectionProtocol::FlashRemoting(_) => None,
+            NetConnectionProtocol::FlashRemoting(_) | NetConnectionProtocol::Rtmp(_) => None,
         }
     }
 
@@ -389,13 +852,13 @@ impl NetConnection<'_> {
             NetConnectionProtocol::Local => {
                 Some("0000000000000000000000000000000000000000000000000000000000000000")
             }
-            NetConnectionProtocol::FlashRemoting(_) => None,
+            NetConnectionProtocol::FlashRemoting(_) | NetConnectionProtocol::Rtmp(_) => None,
         }
     }
 
     pub fn protocol(&self) -> Option<&'static str> {
         match self.protocol {
-            NetConnectionProtocol::Local => Some("rtmp"),
+            NetConnectionProtocol::Local | NetConnectionProtocol::Rtmp(_) => Some("rtmp"),
             NetConnectionProtocol::FlashRemoting(_) => None,
         }
     }
@@ -404,6 +867,7 @@ impl NetConnection<'_> {
         match &self.protocol {
             NetConnectionProtocol::Local => Some("null".to_string()), // Yes, it's a string "null", not a real null.
             NetConnectionProtocol::FlashRemoting(remoting) => Some(remoting.url.to_string()),
+            NetConnectionProtocol::Rtmp(rtmp) => Some(rtmp.url.to_string()),
         }
     }
 
@@ -411,6 +875,7 @@ impl NetConnection<'_> {
         match &self.protocol {
             NetConnectionProtocol::Local => Some(false),
             NetConnectionProtocol::FlashRemoting(_) => None,
+            NetConnectionProtocol::Rtmp(rtmp) => rtmp.is_connected.then_some(false),
         }
     }
 
@@ -425,6 +890,7 @@ impl NetConnection<'_> {
             NetConnectionProtocol::FlashRemoting(remoting) => {
                 remoting.send(command, responder_handle, message)
             }
+            NetConnectionProtocol::Rtmp(rtmp) => rtmp.call(command, responder_handle, message),
         }
     }
 
@@ -441,6 +907,7 @@ impl NetConnection<'_> {
                     navigator.spawn_future(remoting.flush_queue(self_handle, player.clone()));
                 }
             }
+            NetConnectionProtocol::Rtmp(_) => {}
         }
     }
 
@@ -450,6 +917,8 @@ impl NetConnection<'_> {
             NetConnectionProtocol::FlashRemoting(remoting) => {
                 remoting.set_header(header);
             }
+            // RTMP has no headers.
+            NetConnectionProtocol::Rtmp(_) => {}
         }
     }
 }
@@ -461,6 +930,121 @@ pub enum NetConnectionProtocol {
 
     /// Flash Remoting protocol, caused by connecting to a `http://` address.
     FlashRemoting(FlashRemoting),
+
+    /// RTMP protocol, caused by connecting to a `rtmp://` address.
+    Rtmp(Rtmp),
+}
+
+/// A call to the server waiting for its `_result` or `_error`.
+#[derive(Debug)]
+enum PendingCall {
+    /// The `connect` call opening the connection.
+    Connect,
+
+    /// A `NetConnection.call`.
+    Responder(ResponderHandle),
+
+    /// A `createStream` call, made to play the named stream.
+    CreateStream(NetStreamHandle, String),
+}
+
+#[derive(Debug)]
+pub struct Rtmp {
+    url: String,
+    socket: SocketHandle,
+    session: Session,
+
+    /// Whether the server accepted the `connect` call.
+    is_connected: bool,
+
+    /// Commands waiting for the handshake to complete, with the message
+    /// stream to send them on.
+    queued_commands: Vec<(u32, Command)>,
+
+    next_transaction_id: u32,
+    pending_calls: HashMap<u32, PendingCall>,
+
+    /// The `NetStream`s playing over this connection, by message stream ID.
+    streams: HashMap<u32, NetStreamHandle>,
+}
+
+impl Rtmp {
+    const CONNECT_TRANSACTION_ID: u32 = 1;
+
+    fn next_transaction_id(&mut self) -> u32 {
+        let transaction_id = self.next_transaction_id;
+        self.next_transaction_id += 1;
+        transaction_id
+    }
+
+    pub fn call(
+        &mut self,
+        command: String,
+        responder_handle: Option<ResponderHandle>,
+        message: AmfValue,
+    ) {
+        let arguments = match message {
+            AmfValue::StrictArray(_, arguments) => arguments,
+            message => vec![Rc::new(message)],
+        };
+
+        // Calls that don't expect a response don't get a transaction ID.
+        let transaction_id = if let Some(responder_handle) = responder_handle {
+            let transaction_id = self.next_transaction_id();
+            self.pending_calls
+                .insert(transaction_id, PendingCall::Responder(responder_handle));
+            transaction_id
+        } else {
+            0
+        };
+
+        self.send_command(
+            0,
+            Command::new(command, transaction_id as f64, AmfValue::Null, arguments),
+        );
+    }
+
+    fn send_command(&mut self, stream_id: u32, command: Command) {
+        self.queued_commands.push((stream_id, command));
+    }
+
+    /// Write everything that's ready to be sent to the socket.
+    fn flush(&mut self, sockets: &mut Sockets<'_>) {
+        if self.session.is_established() {
+            for (stream_id, command) in self.queued_commands.drain(..) {
+                self.session.send_command(stream_id, &command);
+            }
+        }
+
+        let output = self.session.take_output();
+        if !output.is_empty() {
+            sockets.send(self.socket, output);
+        }
+    }
+}
+
+/// Flatten the info object of an RTMP status or result into the properties
+/// of a status event.
+///
+/// Only properties with primitive values are kept.
+fn status_info(info: &AmfValue) -> Vec<(String, String)> {
+    let elements = match info {
+        AmfValue::Object(_, elements, _) | AmfValue::ECMAArray(_, _, elements, _) => elements,
+        _ => return vec![],
+    };
+
+    elements
+        .iter()
+        .filter_map(|element| {
+            let value = match element.value() {
+                AmfValue::String(value) => value.clone(),
+                AmfValue::Number(value) => value.to_string(),
+                AmfValue::Bool(value) => value.to_string(),
+                _ => return None,
+            };
+            Some((element.name().to_string(), value))
+        })
+        .collect()
 }
 
 #[derive(Debug)]
@@ -0,0 +1,892 @@
+//! The RTMP protocol, as spoken by `NetConnection`s connected to `rtmp://`
+//! addresses.
+//!
+//! This only turns bytes into messages and back again; the connection itself
+//! is a socket opened through the `NavigatorBackend`.
+
+use crate::avm2::amf::amf_value_to_bytes;
+use flash_lso::amf0::read::AMF0Decoder;
+use flash_lso::types::{AMFVersion, Value as AmfValue};
+use std::collections::HashMap;
+use std::rc::Rc;
+use thiserror::Error;
+
+/// The port used by `rtmp://` URLs that don't specify one.
+pub const DEFAULT_PORT: u16 = 1935;
+
+/// The only version of the protocol in existence.
+const VERSION: u8 = 3;
+
+/// The size of the C1/C2 and S1/S2 handshake packets.
+const HANDSHAKE_SIZE: usize = 1536;
+
+/// The chunk size both ends use until told otherwise.
+const DEFAULT_CHUNK_SIZE: usize = 128;
+
+/// The largest chunk size allowed by the specification.
+const MAX_CHUNK_SIZE: u32 = 0xFF_FFFF;
+
+/// The window acknowledgement size we ask the server for.
+const DEFAULT_WINDOW_ACK_SIZE: u32 = 2_500_000;
+
+/// Timestamps at or above this value are written in an extended timestamp
+/// field.
+const EXTENDED_TIMESTAMP: u32 = 0xFF_FFFF;
+
+/// The chunk stream used for protocol control messages.
+const CONTROL_CHUNK_STREAM: u32 = 2;
+
+/// The chunk stream we send commands on.
+const COMMAND_CHUNK_STREAM: u32 = 3;
+
+pub const SET_CHUNK_SIZE: u8 = 1;
+pub const ABORT: u8 = 2;
+pub const ACKNOWLEDGEMENT: u8 = 3;
+pub const USER_CONTROL: u8 = 4;
+pub const WINDOW_ACK_SIZE: u8 = 5;
+pub const SET_PEER_BANDWIDTH: u8 = 6;
+pub const AUDIO: u8 = 8;
+pub const VIDEO: u8 = 9;
+pub const DATA_AMF3: u8 = 15;
+pub const COMMAND_AMF3: u8 = 17;
+pub const DATA_AMF0: u8 = 18;
+pub const COMMAND_AMF0: u8 = 20;
+pub const AGGREGATE: u8 = 22;
+
+const SET_BUFFER_LENGTH: u16 = 3;
+const PING_REQUEST: u16 = 6;
+const PING_RESPONSE: u16 = 7;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RtmpError {
+    #[error("Server requested unsupported RTMP version {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("Invalid chunk size {0}")]
+    InvalidChunkSize(u32),
+
+    #[error("Chunk stream {0} has no previous message header")]
+    UnknownChunkStream(u32),
+
+    #[error("Invalid AMF command")]
+    InvalidCommand,
+}
+
+/// A complete RTMP message, reassembled from its chunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub type_id: u8,
+
+    /// The message stream this belongs to; 0 is the `NetConnection` itself,
+    /// anything else is one of its `NetStream`s.
+    pub stream_id: u32,
+
+    /// The timestamp of the message, in milliseconds.
+    pub timestamp: u32,
+
+    pub payload: Vec<u8>,
+}
+
+impl Message {
+    fn control(type_id: u8, payload: Vec<u8>) -> Self {
+        Self {
+            type_id,
+            stream_id: 0,
+            timestamp: 0,
+            payload,
+        }
+    }
+
+    fn user_control(event: u16, data: &[u8]) -> Self {
+        let mut payload = event.to_be_bytes().to_vec();
+        payload.extend_from_slice(data);
+        Self::control(USER_CONTROL, payload)
+    }
+}
+
+/// An AMF-encoded remote procedure call, in either direction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Command {
+    pub name: String,
+
+    /// The ID of the call, used to pair `_result` and `_error` responses
+    /// with it. Calls that don't want a response use 0.
+    pub transaction_id: f64,
+
+    pub command_object: Rc<AmfValue>,
+
+    pub arguments: Vec<Rc<AmfValue>>,
+}
+
+impl Command {
+    pub fn new(
+        name: impl Into<String>,
+        transaction_id: f64,
+        command_object: AmfValue,
+        arguments: Vec<Rc<AmfValue>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            transaction_id,
+            command_object: Rc::new(command_object),
+            arguments,
+        }
+    }
+
+    /// Parse the payload of a `COMMAND_AMF0` or `COMMAND_AMF3` message.
+    pub fn parse(message: &Message) -> Result<Self, RtmpError> {
+        let mut values =
+            parse_amf_values(message.type_id == COMMAND_AMF3, &message.payload)?.into_iter();
+
+        let name = match values.next().as_deref() {
+            Some(AmfValue::String(name)) => name.clone(),
+            _ => return Err(RtmpError::InvalidCommand),
+        };
+        let transaction_id = match values.next().as_deref() {
+            Some(AmfValue::Number(transaction_id)) => *transaction_id,
+            _ => 0.0,
+        };
+        let command_object = values.next().unwrap_or_else(|| Rc::new(AmfValue::Null));
+
+        Ok(Self {
+            name,
+            transaction_id,
+            command_object,
+            arguments: values.collect(),
+        })
+    }
+
+    /// Encode this command as a `COMMAND_AMF0` message on the given stream.
+    pub fn to_message(&self, stream_id: u32) -> Message {
+        let mut payload = write_amf0(&AmfValue::String(self.name.clone()));
+        payload.extend(write_amf0(&AmfValue::Number(self.transaction_id)));
+        payload.extend(write_amf0(&self.command_object));
+        for argument in &self.arguments {
+            payload.extend(write_amf0(argument));
+        }
+
+        Message {
+            type_id: COMMAND_AMF0,
+            stream_id,
+            timestamp: 0,
+            payload,
+        }
+    }
+}
+
+/// Parse a sequence of AMF0 values, as found in command and data messages.
+///
+/// AMF3 messages only differ in starting with a format selector byte, the
+/// values themselves still being AMF0 (which can switch to AMF3 per value).
+pub fn parse_amf_values(is_amf3: bool, mut data: &[u8]) -> Result<Vec<Rc<AmfValue>>, RtmpError> {
+    if is_amf3 && data.first() == Some(&0) {
+        data = &data[1..];
+    }
+
+    let mut decoder = AMF0Decoder::default();
+    let mut values = Vec::new();
+    while !data.is_empty() {
+        let (rest, value) = decoder
+            .parse_single_element(data)
+            .map_err(|_| RtmpError::InvalidCommand)?;
+        values.push(Rc::new(AmfValue::clone(&value)));
+        data = rest;
+    }
+
+    Ok(values)
+}
+
+/// Encode a single AMF0 value.
+pub fn write_amf0(value: &AmfValue) -> Vec<u8> {
+    amf_value_to_bytes(value.clone(), AMFVersion::AMF0)
+        .unwrap_or_else(|| write_amf0(&AmfValue::Undefined))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Handshake {
+    /// Nothing was sent yet.
+    NotStarted,
+
+    /// C0 and C1 were sent, waiting for S0 and S1.
+    WaitingForS1,
+
+    /// C2 was sent, waiting for S2.
+    WaitingForS2,
+
+    /// Messages can be exchanged.
+    Done,
+}
+
+/// The message header of a chunk stream, as left by its last chunk.
+#[derive(Debug, Clone, Copy, Default)]
+struct ChunkHeader {
+    timestamp: u32,
+    timestamp_delta: u32,
+    length: u32,
+    type_id: u8,
+    stream_id: u32,
+
+    /// Whether the timestamp field of the last header overflowed, which
+    /// means every following chunk carries an extended timestamp too.
+    has_extended_timestamp: bool,
+}
+
+#[derive(Debug, Default)]
+struct ChunkStream {
+    header: ChunkHeader,
+
+    /// The part of the current message received so far.
+    payload: Vec<u8>,
+}
+
+/// The client end of an RTMP connection.
+#[derive(Debug)]
+pub struct Session {
+    handshake: Handshake,
+
+    /// Received bytes that don't make up a complete chunk yet.
+    input: Vec<u8>,
+
+    /// Bytes that should be written to the socket.
+    output: Vec<u8>,
+
+    incoming_chunk_size: usize,
+    outgoing_chunk_size: usize,
+    chunk_streams: HashMap<u32, ChunkStream>,
+
+    /// How many bytes the server may send before we have to acknowledge
+    /// them, or 0 if it never asked for acknowledgements.
+    window_ack_size: u32,
+
+    /// How many bytes of chunks were received, not counting the handshake.
+    bytes_received: u64,
+    bytes_acknowledged: u64,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self {
+            handshake: Handshake::NotStarted,
+            input: Vec::new(),
+            output: Vec::new(),
+            incoming_chunk_size: DEFAULT_CHUNK_SIZE,
+            outgoing_chunk_size: DEFAULT_CHUNK_SIZE,
+            chunk_streams: HashMap::new(),
+            window_ack_size: 0,
+            bytes_received: 0,
+            bytes_acknowledged: 0,
+        }
+    }
+
+    /// Begin the handshake, once the socket is connected.
+    pub fn start(&mut self) {
+        if self.handshake != Handshake::NotStarted {
+            return;
+        }
+
+        // C0 is the version, C1 is a timestamp, four zero bytes and random
+        // data. Servers only echo the random data back, so zeroes will do.
+        self.output.push(VERSION);
+        self.output.extend_from_slice(&[0; HANDSHAKE_SIZE]);
+        self.handshake = Handshake::WaitingForS1;
+    }
+
+    /// Whether the handshake completed, and messages can be sent.
+    pub fn is_established(&self) -> bool {
+        self.handshake == Handshake::Done
+    }
+
+    /// Take the bytes that should be written to the socket.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Process bytes read from the socket, returning every message they
+    /// completed.
+    ///
+    /// Protocol control messages are answered here and not returned.
+    pub fn receive(&mut self, data: &[u8]) -> Result<Vec<Message>, RtmpError> {
+        self.input.extend_from_slice(data);
+
+        if self.handshake == Handshake::WaitingForS1 {
+            if self.input.len() < 1 + HANDSHAKE_SIZE {
+                return Ok(vec![]);
+            }
+            if self.input[0] != VERSION {
+                return Err(RtmpError::UnsupportedVersion(self.input[0]));
+            }
+
+            // C2 echoes S1.
+            let s1 = self.input.drain(..1 + HANDSHAKE_SIZE).skip(1);
+            self.output.extend(s1);
+            self.handshake = Handshake::WaitingForS2;
+        }
+
+        if self.handshake == Handshake::WaitingForS2 {
+            if self.input.len() < HANDSHAKE_SIZE {
+                return Ok(vec![]);
+            }
+            self.input.drain(..HANDSHAKE_SIZE);
+            self.handshake = Handshake::Done;
+        }
+
+        if self.handshake != Handshake::Done {
+            return Ok(vec![]);
+        }
+
+        let input = std::mem::take(&mut self.input);
+        let mut position = 0;
+        let mut messages = Vec::new();
+        while let Some((length, message)) = self.read_chunk(&input[position..])? {
+            position += length;
+            if let Some(message) = message {
+                self.handle_message(message, &mut messages)?;
+            }
+        }
+        self.input = input;
+        self.input.drain(..position);
+        self.bytes_received += position as u64;
+
+        if self.window_ack_size > 0
+            && self.bytes_received - self.bytes_acknowledged >= self.window_ack_size as u64
+        {
+            self.bytes_acknowledged = self.bytes_received;
+            // The sequence number wraps around, like everything else does.
+            let sequence_number = self.bytes_received as u32;
+            self.send(&Message::control(
+                ACKNOWLEDGEMENT,
+                sequence_number.to_be_bytes().to_vec(),
+            ));
+        }
+
+        Ok(messages)
+    }
+
+    /// Read a single chunk from the start of `data`.
+    ///
+    /// Returns `None` if `data` doesn't hold a complete chunk yet, otherwise
+    /// the length of the chunk and the message it completed, if any.
+    fn read_chunk(&mut self, data: &[u8]) -> Result<Option<(usize, Option<Message>)>, RtmpError> {
+        let mut cursor = Cursor::new(data);
+        let Some((format, chunk_stream_id)) = cursor.basic_header() else {
+            return Ok(None);
+        };
+
+        let previous = self.chunk_streams.get(&chunk_stream_id);
+        if format != 0 && previous.is_none() {
+            return Err(RtmpError::UnknownChunkStream(chunk_stream_id));
+        }
+        let mut header = previous.map(|stream| stream.header).unwrap_or_default();
+        let received = previous.map_or(0, |stream| stream.payload.len());
+
+        // Every format but 3 carries a timestamp field, which is absolute
+        // for format 0 and a delta for the others.
+        let timestamp_field = match format {
+            0 => {
+                let Some((timestamp, length, type_id, stream_id)) = cursor.full_message_header()
+                else {
+                    return Ok(None);
+                };
+                header.length = length;
+                header.type_id = type_id;
+                header.stream_id = stream_id;
+                Some(timestamp)
+            }
+            1 => {
+                let (Some(delta), Some(length), Some(type_id)) =
+                    (cursor.u24(), cursor.u24(), cursor.u8())
+                else {
+                    return Ok(None);
+                };
+                header.length = length;
+                header.type_id = type_id;
+                Some(delta)
+            }
+            2 => {
+                let Some(delta) = cursor.u24() else {
+                    return Ok(None);
+                };
+                Some(delta)
+            }
+            _ => None,
+        };
+
+        let starts_message = format != 3 || received == 0;
+        if let Some(field) = timestamp_field {
+            header.has_extended_timestamp = field == EXTENDED_TIMESTAMP;
+        }
+        let timestamp_field = if header.has_extended_timestamp {
+            let Some(extended) = cursor.u32() else {
+                return Ok(None);
+            };
+            Some(extended)
+        } else {
+            timestamp_field
+        };
+
+        if starts_message {
+            let field = timestamp_field.unwrap_or(header.timestamp_delta);
+            if format == 0 {
+                header.timestamp = field;
+            } else {
+                header.timestamp = header.timestamp.wrapping_add(field);
+            }
+            header.timestamp_delta = field;
+        }
+
+        let received = if format == 3 { received } else { 0 };
+        let remaining = (header.length as usize).saturating_sub(received);
+        let Some(chunk) = cursor.take(remaining.min(self.incoming_chunk_size)) else {
+            return Ok(None);
+        };
+
+        let stream = self.chunk_streams.entry(chunk_stream_id).or_default();
+        stream.header = header;
+        if format != 3 {
+            stream.payload.clear();
+        }
+        stream.payload.extend_from_slice(chunk);
+
+        let message = if stream.payload.len() >= header.length as usize {
+            Some(Message {
+                type_id: header.type_id,
+                stream_id: header.stream_id,
+                timestamp: header.timestamp,
+                payload: std::mem::take(&mut stream.payload),
+            })
+        } else {
+            None
+        };
+
+        Ok(Some((cursor.position, message)))
+    }
+
+    fn handle_message(
+        &mut self,
+        message: Message,
+        messages: &mut Vec<Message>,
+    ) -> Result<(), RtmpError> {
+        let value = message
+            .payload
+            .get(..4)
+            .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+
+        match message.type_id {
+            SET_CHUNK_SIZE => {
+                let size = value.unwrap_or_default() & 0x7FFF_FFFF;
+                if size == 0 || size > MAX_CHUNK_SIZE {
+                    return Err(RtmpError::InvalidChunkSize(size));
+                }
+                self.incoming_chunk_size = size as usize;
+            }
+            ABORT => {
+                if let Some(stream) = value.and_then(|id| self.chunk_streams.get_mut(&id)) {
+                    stream.payload.clear();
+                }
+            }
+            ACKNOWLEDGEMENT => {}
+            WINDOW_ACK_SIZE => {
+                self.window_ack_size = value.unwrap_or_default();
+            }
+            SET_PEER_BANDWIDTH => {
+                // The peer expects us to answer with our own window size if
+                // it differs from what it asked for.
+                if let Some(size) = value
+                    && size != self.window_ack_size
+                {
+                    self.send(&Message::control(
+                        WINDOW_ACK_SIZE,
+                        DEFAULT_WINDOW_ACK_SIZE.to_be_bytes().to_vec(),
+                    ));
+                }
+            }
+            USER_CONTROL if message.payload.starts_with(&PING_REQUEST.to_be_bytes()) => {
+                self.send(&Message::user_control(PING_RESPONSE, &message.payload[2..]));
+            }
+            AGGREGATE => split_aggregate(&message, messages),
+            _ => messages.push(message),
+        }
+
+        Ok(())
+    }
+
+    /// Queue a message to be written to the socket.
+    pub fn send(&mut self, message: &Message) {
+        let chunk_stream_id = match message.type_id {
+            SET_CHUNK_SIZE..=SET_PEER_BANDWIDTH => CONTROL_CHUNK_STREAM,
+            _ => COMMAND_CHUNK_STREAM,
+        };
+        let has_extended_timestamp = message.timestamp >= EXTENDED_TIMESTAMP;
+
+        // Every message starts with a full header, and continues with
+        // headerless chunks.
+        write_basic_header(&mut self.output, 0, chunk_stream_id);
+        self.output
+            .extend_from_slice(&message.timestamp.min(EXTENDED_TIMESTAMP).to_be_bytes()[1..]);
+        self.output
+            .extend_from_slice(&(message.payload.len() as u32).to_be_bytes()[1..]);
+        self.output.push(message.type_id);
+        self.output
+            .extend_from_slice(&message.stream_id.to_le_bytes());
+
+        for (index, chunk) in message.payload.chunks(self.outgoing_chunk_size).enumerate() {
+            if index > 0 {
+                write_basic_header(&mut self.output, 3, chunk_stream_id);
+            }
+            if has_extended_timestamp {
+                self.output
+                    .extend_from_slice(&message.timestamp.to_be_bytes());
+            }
+            self.output.extend_from_slice(chunk);
+        }
+    }
+
+    /// Queue a command to be written to the socket.
+    pub fn send_command(&mut self, stream_id: u32, command: &Command) {
+        self.send(&command.to_message(stream_id));
+    }
+
+    /// Tell the server how many milliseconds of a stream we buffer.
+    pub fn send_buffer_length(&mut self, stream_id: u32, buffer_length: u32) {
+        let mut data = stream_id.to_be_bytes().to_vec();
+        data.extend_from_slice(&buffer_length.to_be_bytes());
+        self.send(&Message::user_control(SET_BUFFER_LENGTH, &data));
+    }
+}
+
+/// Split an aggregate message into the audio, video and data messages it's
+/// made of.
+///
+/// The sub-messages are laid out like FLV tags, and their timestamps are
+/// relative to the first one.
+fn split_aggregate(message: &Message, messages: &mut Vec<Message>) {
+    let mut cursor = Cursor::new(&message.payload);
+    let mut first_timestamp = None;
+
+    while let (Some(type_id), Some(length), Some(timestamp), Some(timestamp_extended), Some(_)) = (
+        cursor.u8(),
+        cursor.u24(),
+        cursor.u24(),
+        cursor.u8(),
+        cursor.u24(),
+    ) {
+        let Some(payload) = cursor.take(length as usize) else {
+            break;
+        };
+        // The size of the previous tag.
+        cursor.u32();
+
+        let timestamp = timestamp | ((timestamp_extended as u32) << 24);
+        let first_timestamp = *first_timestamp.get_or_insert(timestamp);
+        messages.push(Message {
+            type_id,
+            stream_id: message.stream_id,
+            timestamp: message
+                .timestamp
+                .wrapping_add(timestamp.wrapping_sub(first_timestamp)),
+            payload: payload.to_vec(),
+        });
+    }
+}
+
+fn write_basic_header(output: &mut Vec<u8>, format: u8, chunk_stream_id: u32) {
+    match chunk_stream_id {
+        2..=63 => output.push((format << 6) | chunk_stream_id as u8),
+        64..=319 => output.extend_from_slice(&[format << 6, (chunk_stream_id - 64) as u8]),
+        _ => {
+            let id = (chunk_stream_id - 64) as u16;
+            output.push((format << 6) | 1);
+            output.extend_from_slice(&id.to_le_bytes());
+        }
+    }
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.position..self.position + length)?;
+        self.position += length;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u24(&mut self) -> Option<u32> {
+        self.take(3)
+            .map(|bytes| u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Read the chunk format and chunk stream ID.
+    fn basic_header(&mut self) -> Option<(u8, u32)> {
+        let first = self.u8()?;
+        let chunk_stream_id = match first & 0x3F {
+            0 => 64 + self.u8()? as u32,
+            1 => {
+                let bytes = self.take(2)?;
+                64 + u16::from_le_bytes([bytes[0], bytes[1]]) as u32
+            }
+            id => id as u32,
+        };
+        Some((first >> 6, chunk_stream_id))
+    }
+
+    /// Read a format 0 message header: the timestamp, message length, type
+    /// and stream ID.
+    fn full_message_header(&mut self) -> Option<(u32, u32, u8, u32)> {
+        let timestamp = self.u24()?;
+        let length = self.u24()?;
+        let type_id = self.u8()?;
+        let bytes = self.take(4)?;
+        let stream_id = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        Some((timestamp, length, type_id, stream_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A session that went through the handshake.
+    fn established_session() -> Session {
+        let mut session = Session::new();
+        session.start();
+        let mut server = vec![VERSION];
+        server.extend_from_slice(&[1; HANDSHAKE_SIZE]);
+        server.extend_from_slice(&[0; HANDSHAKE_SIZE]);
+        assert_eq!(session.receive(&server), Ok(vec![]));
+        session.take_output();
+        session
+    }
+
+    fn message(type_id: u8, stream_id: u32, timestamp: u32, payload: Vec<u8>) -> Message {
+        Message {
+            type_id,
+            stream_id,
+            timestamp,
+            payload,
+        }
+    }
+
+    #[test]
+    fn handshake() {
+        let mut session = Session::new();
+        session.start();
+        assert_eq!(session.take_output().len(), 1 + HANDSHAKE_SIZE);
+
+        let mut s0_s1 = vec![VERSION];
+        s0_s1.extend((0..HANDSHAKE_SIZE).map(|i| i as u8));
+        assert_eq!(session.receive(&s0_s1[..100]), Ok(vec![]));
+        assert!(session.take_output().is_empty());
+        assert_eq!(session.receive(&s0_s1[100..]), Ok(vec![]));
+        assert_eq!(session.take_output(), s0_s1[1..]);
+        assert!(!session.is_established());
+
+        assert_eq!(session.receive(&[0; HANDSHAKE_SIZE]), Ok(vec![]));
+        assert!(session.is_established());
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let mut session = Session::new();
+        session.start();
+        assert_eq!(
+            session.receive(&[6; 1 + HANDSHAKE_SIZE]),
+            Err(RtmpError::UnsupportedVersion(6))
+        );
+    }
+
+    #[test]
+    fn reassemble_chunks() {
+        let mut sender = established_session();
+        let mut receiver = established_session();
+
+        let payload: Vec<u8> = (0..300).map(|i| i as u8).collect();
+        let sent = message(VIDEO, 1, 40, payload);
+        sender.send(&sent);
+        let bytes = sender.take_output();
+
+        // Feeding the chunks a byte at a time mustn't make a difference.
+        let mut received = Vec::new();
+        for byte in bytes {
+            received.extend(receiver.receive(&[byte]).unwrap());
+        }
+        assert_eq!(received, vec![sent]);
+    }
+
+    #[test]
+    fn compressed_headers() {
+        let mut session = established_session();
+
+        // A full header, then one reusing everything but the timestamp delta,
+        // then one reusing everything.
+        let mut bytes = vec![0x04, 0, 0, 10, 0, 0, 2, AUDIO, 1, 0, 0, 0, 0xAA, 0xBB];
+        bytes.extend_from_slice(&[0x84, 0, 0, 20, 0xCC, 0xDD]);
+        bytes.extend_from_slice(&[0xC4, 0xEE, 0xFF]);
+
+        assert_eq!(
+            session.receive(&bytes),
+            Ok(vec![
+                message(AUDIO, 1, 10, vec![0xAA, 0xBB]),
+                message(AUDIO, 1, 30, vec![0xCC, 0xDD]),
+                message(AUDIO, 1, 50, vec![0xEE, 0xFF]),
+            ])
+        );
+    }
+
+    #[test]
+    fn set_chunk_size() {
+        let mut session = established_session();
+
+        let mut bytes = vec![0x02, 0, 0, 0, 0, 0, 4, SET_CHUNK_SIZE, 0, 0, 0, 0];
+        bytes.extend_from_slice(&4096u32.to_be_bytes());
+        // A 200 byte message now fits in a single chunk.
+        bytes.extend_from_slice(&[0x05, 0, 0, 0, 0, 0, 200, VIDEO, 1, 0, 0, 0]);
+        bytes.extend_from_slice(&[7; 200]);
+
+        assert_eq!(
+            session.receive(&bytes),
+            Ok(vec![message(VIDEO, 1, 0, vec![7; 200])])
+        );
+    }
+
+    #[test]
+    fn answer_ping() {
+        let mut session = established_session();
+
+        let bytes = [
+            0x02,
+            0,
+            0,
+            0,
+            0,
+            0,
+            6,
+            USER_CONTROL,
+            0,
+            0,
+            0,
+            0,
+            0,
+            6,
+            1,
+            2,
+            3,
+            4,
+        ];
+        assert_eq!(session.receive(&bytes), Ok(vec![]));
+        assert_eq!(
+            session.take_output(),
+            [
+                0x02,
+                0,
+                0,
+                0,
+                0,
+                0,
+                6,
+                USER_CONTROL,
+                0,
+                0,
+                0,
+                0,
+                0,
+                7,
+                1,
+                2,
+                3,
+                4
+            ]
+        );
+    }
+
+    #[test]
+    fn acknowledge_window() {
+        let mut session = established_session();
+
+        let mut bytes = vec![0x02, 0, 0, 0, 0, 0, 4, WINDOW_ACK_SIZE, 0, 0, 0, 0];
+        bytes.extend_from_slice(&20u32.to_be_bytes());
+        assert_eq!(session.receive(&bytes), Ok(vec![]));
+        assert!(session.take_output().is_empty());
+
+        let bytes = [0x04, 0, 0, 0, 0, 0, 1, AUDIO, 1, 0, 0, 0, 0];
+        assert_eq!(
+            session.receive(&bytes),
+            Ok(vec![message(AUDIO, 1, 0, vec![0])])
+        );
+
+        let mut acknowledgement = vec![0x02, 0, 0, 0, 0, 0, 4, ACKNOWLEDGEMENT, 0, 0, 0, 0];
+        acknowledgement.extend_from_slice(&29u32.to_be_bytes());
+        assert_eq!(session.take_output(), acknowledgement);
+    }
+
+    #[test]
+    fn split_aggregate_messages() {
+        let mut payload = Vec::new();
+        for (type_id, timestamp, data) in [(VIDEO, 1000u32, [1, 2]), (AUDIO, 1020, [3, 4])] {
+            payload.push(type_id);
+            payload.extend_from_slice(&2u32.to_be_bytes()[1..]);
+            payload.extend_from_slice(&timestamp.to_be_bytes()[1..]);
+            payload.push(0);
+            payload.extend_from_slice(&[0, 0, 0]);
+            payload.extend_from_slice(&data);
+            payload.extend_from_slice(&13u32.to_be_bytes());
+        }
+
+        let mut messages = Vec::new();
+        split_aggregate(&message(AGGREGATE, 1, 50, payload), &mut messages);
+        assert_eq!(
+            messages,
+            vec![
+                message(VIDEO, 1, 50, vec![1, 2]),
+                message(AUDIO, 1, 70, vec![3, 4]),
+            ]
+        );
+    }
+
+    #[test]
+    fn command_round_trip() {
+        let command = Command::new(
+            "play",
+            4.0,
+            AmfValue::Null,
+            vec![Rc::new(AmfValue::String("video".to_string()))],
+        );
+
+        let message = command.to_message(1);
+        assert_eq!(message.type_id, COMMAND_AMF0);
+        assert_eq!(
+            message.payload,
+            [
+                &[0x02, 0, 4][..],
+                b"play",
+                &[0x00, 0x40, 0x10, 0, 0, 0, 0, 0, 0],
+                &[0x05],
+                &[0x02, 0, 5],
+                b"video",
+            ]
+            .concat()
+        );
+        assert_eq!(Command::parse(&message), Ok(command));
+    }
+}
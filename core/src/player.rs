@@ -5,21 +5,29 @@ use crate::avm1::Avm1;
 use crate::avm1::Object;
 use crate::avm1::Value;
 use crate::avm1::VariableDumper;
+use crate::avm1::debugger::Debugger as Avm1Debugger;
 use crate::avm1::{Activation, ActivationIdentifier};
+use crate::avm2::debugger::{Debugger, DebuggerConnection};
 use crate::avm2::object::EventObject as Avm2EventObject;
 use crate::avm2::{Activation as Avm2Activation, Avm2, CallStack, SharedObjectObject};
 use crate::backend::navigator::ErrorResponse;
 use crate::backend::navigator::FetchReason;
 use crate::backend::navigator::OwnedFuture;
 use crate::backend::navigator::SuccessResponse;
-use crate::backend::ui::FontDefinition;
+use crate::backend::ui::{FontDefinition, NativeWindowId};
 use crate::backend::{
     audio::{AudioBackend, AudioManager},
+    camera::CameraBackend,
+    filesystem::FileSystemBackend,
     log::LogBackend,
+    microphone::MicrophoneBackend,
     navigator::{NavigatorBackend, Request},
+    print::PrintBackend,
+    process::ProcessBackend,
     storage::StorageBackend,
     ui::{MouseCursor, UiBackend},
 };
+use crate::camera::Cameras;
 use crate::compatibility_rules::CompatibilityRules;
 use crate::compatibility_rules::UrlRewriteStage;
 use crate::config::Letterbox;
@@ -33,6 +41,7 @@ use crate::display_object::{
     TInteractiveObject, WindowMode,
 };
 use crate::events::GamepadButton;
+use crate::events::NativeWindowEvent;
 use crate::events::PlayerNotification;
 use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult, KeyCode, MouseButton, PlayerEvent};
 use crate::external::{ExternalInterface, ExternalInterfaceProvider, NullFsCommandProvider};
@@ -46,6 +55,10 @@ use crate::library::Library;
 use crate::limits::ExecutionLimit;
 use crate::loader::{LoadBehavior, LoadManager};
 use crate::local_connection::LocalConnections;
+use crate::microphone::Microphones;
+use crate::native_application::NativeApplication;
+use crate::native_process::NativeProcesses;
+use crate::native_window::NativeWindows;
 use crate::net_connection::NetConnections;
 use crate::orphan_manager::OrphanManager;
 use crate::prelude::*;
@@ -56,7 +69,9 @@ use crate::stub::StubCollection;
 use crate::system_properties::SystemProperties;
 use crate::tag_utils::SwfMovie;
 use crate::timer::Timers;
+use crate::touch::{TouchPhase, Touches};
 use crate::vminterface::Instantiator;
+use crate::worker::{WorkerIdentity, Workers};
 use async_channel::Sender;
 use enumset::EnumSet;
 use gc_arena::lock::GcRefLock;
@@ -109,7 +124,7 @@ impl StaticCallstack {
     }
 }
 
-#[derive(Collect)]
+#[derive(Collect, Default)]
 #[collect(no_drop)]
 pub struct MouseData<'gc> {
     /// The object that the mouse is currently hovering over.
@@ -198,6 +213,27 @@ struct GcRootData<'gc> {
 
     local_connections: LocalConnections<'gc>,
 
+    /// The worker this player runs as, and the other workers it knows of.
+    workers: Workers<'gc>,
+
+    /// Microphones requested by the movie.
+    microphones: Microphones<'gc>,
+
+    /// Cameras requested by the movie.
+    cameras: Cameras<'gc>,
+
+    /// The touch points on the screen.
+    touches: Touches<'gc>,
+
+    /// The windows opened by the movie.
+    native_windows: NativeWindows<'gc>,
+
+    /// The application of an AIR movie.
+    native_application: NativeApplication<'gc>,
+
+    /// The programs run by an AIR movie.
+    native_processes: NativeProcesses<'gc>,
+
     orphan_manager: OrphanManager<'gc>,
 
     /// Dynamic root for allowing handles to GC objects to exist outside of the GC.
@@ -241,6 +277,13 @@ impl<'gc> GcRootData<'gc> {
         &mut Sockets<'gc>,
         &mut NetConnections<'gc>,
         &mut LocalConnections<'gc>,
+        &mut Workers<'gc>,
+        &mut Microphones<'gc>,
+        &mut Cameras<'gc>,
+        &mut Touches<'gc>,
+        &mut NativeWindows<'gc>,
+        &mut NativeApplication<'gc>,
+        &mut NativeProcesses<'gc>,
         &mut OrphanManager<'gc>,
         &mut Vec<PostFrameCallback<'gc>>,
         &mut MouseData<'gc>,
@@ -266,6 +309,13 @@ impl<'gc> GcRootData<'gc> {
             &mut self.sockets,
             &mut self.net_connections,
             &mut self.local_connections,
+            &mut self.workers,
+            &mut self.microphones,
+            &mut self.cameras,
+            &mut self.touches,
+            &mut self.native_windows,
+            &mut self.native_application,
+            &mut self.native_processes,
             &mut self.orphan_manager,
             &mut self.post_frame_callbacks,
             &mut self.mouse_data,
@@ -316,6 +366,11 @@ pub struct Player {
     log: Box<dyn LogBackend>,
     ui: Box<dyn UiBackend>,
     video: Box<dyn VideoBackend>,
+    microphone: Box<dyn MicrophoneBackend>,
+    camera: Box<dyn CameraBackend>,
+    filesystem: Box<dyn FileSystemBackend>,
+    process: Box<dyn ProcessBackend>,
+    print: Box<dyn PrintBackend>,
 
     transform_stack: TransformStack,
 
@@ -579,7 +634,13 @@ impl Player {
         self.frame_accumulator += FloatDuration::from_secs(audio_skew);
 
         self.update_sockets();
+        self.update_native_processes();
         self.update_net_connections();
+        self.update_workers();
+        self.update_microphones(dt);
+        self.update_cameras(dt);
+        self.update_touches(dt);
+        self.update_native_application(dt);
         self.update_timers(dt);
         self.update(|context| {
             StreamManager::tick(context, dt);
@@ -1008,6 +1069,13 @@ impl Player {
     /// Handle an event sent into the player from the external windowing system
     /// or an HTML element.
     pub fn handle_event(&mut self, event: PlayerEvent) -> bool {
+        if !matches!(
+            event,
+            PlayerEvent::FocusGained | PlayerEvent::FocusLost | PlayerEvent::MouseLeave
+        ) {
+            self.update(|context| NativeApplication::user_input(context));
+        }
+
         match event {
             PlayerEvent::FocusGained | PlayerEvent::FocusLost => self.handle_focus_event(event),
             PlayerEvent::KeyDown { .. }
@@ -1022,6 +1090,56 @@ impl Player {
             | PlayerEvent::Ime { .. }
             | PlayerEvent::TextInput { .. }
             | PlayerEvent::TextControl { .. } => self.handle_input_event(event),
+            PlayerEvent::TouchBegin { .. }
+            | PlayerEvent::TouchMove { .. }
+            | PlayerEvent::TouchEnd { .. }
+            | PlayerEvent::TouchCancel { .. } => self.handle_touch_event(event),
+        }
+    }
+
+    /// Touch events are dispatched as touch or gesture events, depending on
+    /// `Multitouch.inputMode`. The primary touch point then drives the mouse,
+    /// as if the mouse was moved and clicked where the finger is.
+    fn handle_touch_event(&mut self, event: PlayerEvent) -> bool {
+        let (id, phase, pixels) = match event {
+            PlayerEvent::TouchBegin { id, x, y } => (id, TouchPhase::Begin, Some((x, y))),
+            PlayerEvent::TouchMove { id, x, y } => (id, TouchPhase::Move, Some((x, y))),
+            PlayerEvent::TouchEnd { id, x, y } => (id, TouchPhase::End, Some((x, y))),
+            PlayerEvent::TouchCancel { id } => (id, TouchPhase::Cancel, None),
+            _ => return false,
+        };
+
+        let primary_position = self.mutate_with_update_context(|context| {
+            let position =
+                pixels.map(|(x, y)| context.stage.inverse_view_matrix() * Point::from_pixels(x, y));
+            let touch = Touches::handle_touch(context, id, phase, position);
+            Self::run_actions(context);
+
+            let (is_primary, position) = touch?;
+            // Mouse events are in the same coordinates as touch events.
+            let position = context.stage.view_matrix() * position;
+            is_primary.then_some((position.x.to_pixels(), position.y.to_pixels()))
+        });
+        let Some(primary_position) = primary_position else {
+            return false;
+        };
+        let (x, y) = pixels.unwrap_or(primary_position);
+
+        let button = MouseButton::Left;
+        match phase {
+            TouchPhase::Begin => {
+                self.handle_input_event(PlayerEvent::MouseMove { x, y });
+                self.handle_input_event(PlayerEvent::MouseDown {
+                    x,
+                    y,
+                    button,
+                    index: None,
+                })
+            }
+            TouchPhase::Move => self.handle_input_event(PlayerEvent::MouseMove { x, y }),
+            TouchPhase::End | TouchPhase::Cancel => {
+                self.handle_input_event(PlayerEvent::MouseUp { x, y, button })
+            }
         }
     }
 
@@ -2022,6 +2140,7 @@ impl Player {
             Avm1::run_frame(context);
             AudioManager::update_sounds(context);
             LocalConnections::update_connections(context);
+            Avm2::run_sampler_callback(context);
 
             // Only run the current list of callbacks - any callbacks added during callback execution
             // will be run at the end of the *next* frame.
@@ -2085,6 +2204,14 @@ impl Player {
         self.renderer
             .submit_frame(background_color, commands, cache_draws);
 
+        self.render_native_windows();
+    }
+
+    /// Draws the windows of an AIR application other than the main one, without drawing the
+    /// main window, such as when it was closed.
+    pub fn render_native_windows(&mut self) {
+        self.mutate_with_update_context(|context| NativeWindows::render_windows(context));
+
         self.needs_render = false;
     }
 
@@ -2145,6 +2272,46 @@ impl Player {
         &mut *self.ui
     }
 
+    pub fn microphone(&self) -> &dyn MicrophoneBackend {
+        &*self.microphone
+    }
+
+    pub fn microphone_mut(&mut self) -> &mut dyn MicrophoneBackend {
+        &mut *self.microphone
+    }
+
+    pub fn camera(&self) -> &dyn CameraBackend {
+        &*self.camera
+    }
+
+    pub fn camera_mut(&mut self) -> &mut dyn CameraBackend {
+        &mut *self.camera
+    }
+
+    pub fn filesystem(&self) -> &dyn FileSystemBackend {
+        &*self.filesystem
+    }
+
+    pub fn filesystem_mut(&mut self) -> &mut dyn FileSystemBackend {
+        &mut *self.filesystem
+    }
+
+    pub fn process(&self) -> &dyn ProcessBackend {
+        &*self.process
+    }
+
+    pub fn process_mut(&mut self) -> &mut dyn ProcessBackend {
+        &mut *self.process
+    }
+
+    pub fn print(&self) -> &dyn PrintBackend {
+        &*self.print
+    }
+
+    pub fn print_mut(&mut self) -> &mut dyn PrintBackend {
+        &mut *self.print
+    }
+
     pub fn run_actions(context: &mut UpdateContext<'_>) {
         // Note that actions can queue further actions, so a while loop is necessary here.
         while let Some(action) = context.action_queue.pop_action() {
@@ -2256,6 +2423,13 @@ impl Player {
                 sockets,
                 net_connections,
                 local_connections,
+                workers,
+                microphones,
+                cameras,
+                touches,
+                native_windows,
+                native_application,
+                native_processes,
                 orphan_manager,
                 post_frame_callbacks,
                 mouse_data,
@@ -2288,6 +2462,11 @@ impl Player {
                 storage: this.storage.deref_mut(),
                 log: this.log.deref_mut(),
                 video: this.video.deref_mut(),
+                microphone: this.microphone.deref_mut(),
+                camera: this.camera.deref_mut(),
+                filesystem: this.filesystem.deref_mut(),
+                process: this.process.deref_mut(),
+                print: this.print.deref_mut(),
                 avm1_shared_objects,
                 avm2_shared_objects,
                 unbound_text_fields,
@@ -2313,6 +2492,13 @@ impl Player {
                 sockets,
                 net_connections,
                 local_connections,
+                workers,
+                microphones,
+                cameras,
+                touches,
+                native_windows,
+                native_application,
+                native_processes,
                 orphan_manager,
                 dynamic_root,
                 post_frame_callbacks,
@@ -2433,6 +2619,13 @@ impl Player {
         })
     }
 
+    /// Update programs run by the movie.
+    pub fn update_native_processes(&mut self) {
+        self.mutate_with_update_context(|context| {
+            NativeProcesses::update_native_processes(context);
+        })
+    }
+
     /// Update connected NetConnections.
     pub fn update_net_connections(&mut self) {
         self.mutate_with_update_context(|context| {
@@ -2440,6 +2633,91 @@ impl Player {
         })
     }
 
+    /// Dispatch events for anything other workers did since the last tick.
+    pub fn update_workers(&mut self) {
+        self.mutate_with_update_context(|context| {
+            Workers::update_workers(context);
+        })
+    }
+
+    /// Deliver the audio captured by microphones since the last tick.
+    pub fn update_microphones(&mut self, dt: FloatDuration) {
+        self.mutate_with_update_context(|context| {
+            Microphones::update_microphones(context, dt);
+        })
+    }
+
+    /// Read the frames captured by cameras since the last tick.
+    pub fn update_cameras(&mut self, dt: FloatDuration) {
+        self.mutate_with_update_context(|context| {
+            Cameras::update_cameras(context, dt);
+        })
+    }
+
+    /// Advance the clock used to recognize taps and gestures.
+    pub fn update_touches(&mut self, dt: FloatDuration) {
+        self.mutate_with_update_context(|context| {
+            context.touches.update_touches(dt);
+        })
+    }
+
+    /// Dispatch the `activate`, `deactivate` and `userIdle` events of an AIR application.
+    pub fn update_native_application(&mut self, dt: FloatDuration) {
+        self.update(|context| {
+            NativeApplication::update(context, dt);
+        })
+    }
+
+    /// Handle an event from a window of an AIR application.
+    ///
+    /// Returns `false` if the movie prevented the window from closing.
+    pub fn native_window_event(&mut self, id: NativeWindowId, event: NativeWindowEvent) -> bool {
+        self.update(|context| {
+            context.native_application.handle_window_event(id, event);
+            NativeWindows::handle_event(context, id, event)
+        })
+    }
+
+    /// Handle an input event from a window of an AIR application.
+    ///
+    /// Windows other than the main one have their own stage and mouse state, which stand in
+    /// for the player's while the event is handled.
+    pub fn handle_native_window_input(&mut self, id: NativeWindowId, event: PlayerEvent) -> bool {
+        if id == NativeWindowId::MAIN {
+            return self.handle_event(event);
+        }
+
+        let entered = self.enter_arena_mut(|_, gc_root, this| {
+            gc_root.native_windows.enter_input(
+                id,
+                &mut gc_root.stage,
+                &mut gc_root.mouse_data,
+                &mut this.mouse_position,
+            )
+        });
+        if !entered {
+            return false;
+        }
+
+        let handled = self.handle_event(event);
+
+        self.enter_arena_mut(|_, gc_root, this| {
+            gc_root.native_windows.leave_input(
+                &mut gc_root.stage,
+                &mut gc_root.mouse_data,
+                &mut this.mouse_position,
+            )
+        });
+        handled
+    }
+
+    /// Tell an AIR application that it's about to exit, other than by `NativeApplication.exit`.
+    ///
+    /// Returns `false` if the movie prevented the application from exiting.
+    pub fn application_exiting(&mut self) -> bool {
+        self.update(|context| NativeApplication::exiting(context))
+    }
+
     /// Returns whether this player consumes mouse wheel events.
     /// Used by web to prevent scrolling.
     pub fn should_prevent_scrolling(&mut self) -> bool {
@@ -2576,6 +2854,11 @@ pub struct PlayerBuilder {
     storage: Option<Box<dyn StorageBackend>>,
     ui: Option<Box<dyn UiBackend>>,
     video: Option<Box<dyn VideoBackend>>,
+    microphone: Option<Box<dyn MicrophoneBackend>>,
+    camera: Option<Box<dyn CameraBackend>>,
+    filesystem: Option<Box<dyn FileSystemBackend>>,
+    process: Option<Box<dyn ProcessBackend>>,
+    print: Option<Box<dyn PrintBackend>>,
 
     // Notifications
     notification_sender: Option<Sender<PlayerNotification>>,
@@ -2597,6 +2880,9 @@ pub struct PlayerBuilder {
     spoofed_url: Option<String>,
     compatibility_rules: CompatibilityRules,
     gamepad_button_mapping: HashMap<GamepadButton, KeyCode>,
+    max_touch_points: u32,
+    invoke_arguments: Vec<String>,
+    application_descriptor: Option<String>,
     player_version: Option<u8>,
     player_runtime: PlayerRuntime,
     player_mode: PlayerMode,
@@ -2608,6 +2894,9 @@ pub struct PlayerBuilder {
     #[cfg(feature = "known_stubs")]
     stub_report_output: Option<std::path::PathBuf>,
     avm2_optimizer_enabled: bool,
+    avm1_debugger: Option<DebuggerConnection>,
+    avm2_debugger: Option<DebuggerConnection>,
+    worker: Option<WorkerIdentity>,
     #[cfg(feature = "default_font")]
     default_font: bool,
 }
@@ -2629,6 +2918,11 @@ impl PlayerBuilder {
             storage: None,
             ui: None,
             video: None,
+            microphone: None,
+            camera: None,
+            filesystem: None,
+            process: None,
+            print: None,
 
             notification_sender: None,
 
@@ -2653,6 +2947,9 @@ impl PlayerBuilder {
             spoofed_url: None,
             compatibility_rules: CompatibilityRules::default(),
             gamepad_button_mapping: HashMap::new(),
+            max_touch_points: 0,
+            invoke_arguments: Vec::new(),
+            application_descriptor: None,
             player_version: None,
             player_runtime: PlayerRuntime::default(),
             player_mode: PlayerMode::default(),
@@ -2664,6 +2961,9 @@ impl PlayerBuilder {
             #[cfg(feature = "known_stubs")]
             stub_report_output: None,
             avm2_optimizer_enabled: true,
+            avm1_debugger: None,
+            avm2_debugger: None,
+            worker: None,
             #[cfg(feature = "default_font")]
             default_font: true,
         }
@@ -2739,6 +3039,51 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets the microphone backend of the player.
+    ///
+    /// Without one, movies will see no capture devices.
+    #[inline]
+    pub fn with_microphone(mut self, microphone: impl 'static + MicrophoneBackend) -> Self {
+        self.microphone = Some(Box::new(microphone));
+        self
+    }
+
+    /// Sets the camera backend of the player.
+    ///
+    /// Without one, movies will see no capture devices.
+    #[inline]
+    pub fn with_camera(mut self, camera: impl 'static + CameraBackend) -> Self {
+        self.camera = Some(Box::new(camera));
+        self
+    }
+
+    /// Sets the filesystem backend of the player.
+    ///
+    /// Without one, AIR movies get a filesystem that's discarded with the player.
+    #[inline]
+    pub fn with_filesystem(mut self, filesystem: impl 'static + FileSystemBackend) -> Self {
+        self.filesystem = Some(Box::new(filesystem));
+        self
+    }
+
+    /// Sets the process backend of the player.
+    ///
+    /// Without one, AIR movies can't run programs.
+    #[inline]
+    pub fn with_process(mut self, process: impl 'static + ProcessBackend) -> Self {
+        self.process = Some(Box::new(process));
+        self
+    }
+
+    /// Sets the print backend of the player.
+    ///
+    /// Without one, movies can't print.
+    #[inline]
+    pub fn with_print(mut self, print: impl 'static + PrintBackend) -> Self {
+        self.print = Some(Box::new(print));
+        self
+    }
+
     /// Sets the channel for player notifications.
     #[inline]
     pub fn with_notification_sender(mut self, sender: Sender<PlayerNotification>) -> Self {
@@ -2874,6 +3219,25 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets how many touch points the screen supports.
+    /// Movies are told that touch input is unsupported when this is 0, the default.
+    pub fn with_max_touch_points(mut self, max_touch_points: u32) -> Self {
+        self.max_touch_points = max_touch_points;
+        self
+    }
+
+    /// Sets the arguments an AIR application receives in its `InvokeEvent`.
+    pub fn with_invoke_arguments(mut self, invoke_arguments: Vec<String>) -> Self {
+        self.invoke_arguments = invoke_arguments;
+        self
+    }
+
+    /// Sets the contents of the `application.xml` descriptor of an AIR application.
+    pub fn with_application_descriptor(mut self, application_descriptor: String) -> Self {
+        self.application_descriptor = Some(application_descriptor);
+        self
+    }
+
     #[cfg(feature = "known_stubs")]
     /// Sets the output path for the stub report. When set, the player
     /// will write the report to this path and exit the process.
@@ -2887,12 +3251,33 @@ impl PlayerBuilder {
         self
     }
 
+    /// Attaches an AVM1 step debugger, controlled by a client through the
+    /// given connection. See `avm1::debugger` for the protocol.
+    pub fn with_avm1_debugger(mut self, connection: DebuggerConnection) -> Self {
+        self.avm1_debugger = Some(connection);
+        self
+    }
+
+    /// Attaches an AVM2 step debugger, controlled by a client through the
+    /// given connection. See `avm2::debugger` for the protocol.
+    pub fn with_avm2_debugger(mut self, connection: DebuggerConnection) -> Self {
+        self.avm2_debugger = Some(connection);
+        self
+    }
+
+    /// Runs the player as a background worker.
+    pub(crate) fn with_worker(mut self, identity: WorkerIdentity) -> Self {
+        self.worker = Some(identity);
+        self
+    }
+
     #[cfg(feature = "default_font")]
     pub fn with_default_font(mut self, value: bool) -> Self {
         self.default_font = value;
         self
     }
 
+    #[expect(clippy::too_many_arguments)]
     fn create_gc_root<'gc>(
         gc_context: &'gc Mutation<'gc>,
         player_version: u8,
@@ -2901,6 +3286,10 @@ impl PlayerBuilder {
         fake_movie: Arc<SwfMovie>,
         external_interface_provider: Option<Box<dyn ExternalInterfaceProvider>>,
         fs_command_provider: Box<dyn FsCommandProvider>,
+        worker: Option<WorkerIdentity>,
+        max_touch_points: u32,
+        invoke_arguments: Vec<String>,
+        application_descriptor: Option<String>,
     ) -> GcRoot<'gc> {
         let mut interner = AvmStringInterner::new(gc_context);
         let (avm1, avm2) = {
@@ -2943,6 +3332,13 @@ impl PlayerBuilder {
             sockets: Sockets::empty(),
             net_connections: NetConnections::default(),
             local_connections: LocalConnections::empty(),
+            workers: Workers::new(worker),
+            microphones: Microphones::new(),
+            cameras: Cameras::new(),
+            touches: Touches::new(max_touch_points),
+            native_windows: NativeWindows::new(),
+            native_application: NativeApplication::new(invoke_arguments, application_descriptor),
+            native_processes: NativeProcesses::empty(),
             orphan_manager: OrphanManager::default(),
             dynamic_root: DynamicRootSet::new(gc_context),
             post_frame_callbacks: Vec::new(),
@@ -2983,6 +3379,21 @@ impl PlayerBuilder {
         let video = self
             .video
             .unwrap_or_else(|| Box::new(null::NullVideoBackend::new()));
+        let microphone = self
+            .microphone
+            .unwrap_or_else(|| Box::new(microphone::NullMicrophoneBackend::new()));
+        let camera = self
+            .camera
+            .unwrap_or_else(|| Box::new(camera::NullCameraBackend::new()));
+        let filesystem = self
+            .filesystem
+            .unwrap_or_else(|| Box::new(filesystem::MemoryFileSystemBackend::new()));
+        let process = self
+            .process
+            .unwrap_or_else(|| Box::new(process::NullProcessBackend::new()));
+        let print = self
+            .print
+            .unwrap_or_else(|| Box::new(print::NullPrintBackend::new()));
 
         let player_version = self.player_version.unwrap_or(DEFAULT_PLAYER_VERSION);
         let language = ui.language();
@@ -3001,6 +3412,11 @@ impl PlayerBuilder {
                 storage,
                 ui,
                 video,
+                microphone,
+                camera,
+                filesystem,
+                process,
+                print,
 
                 // SWF info
                 swf: fake_movie.clone(),
@@ -3061,6 +3477,10 @@ impl PlayerBuilder {
                         fake_movie.clone(),
                         self.external_interface_provider,
                         self.fs_command_provider,
+                        self.worker,
+                        self.max_touch_points,
+                        self.invoke_arguments,
+                        self.application_descriptor,
                     )
                 }))),
             })
@@ -3112,6 +3532,12 @@ impl PlayerBuilder {
             context
                 .avm2
                 .set_optimizer_enabled(self.avm2_optimizer_enabled);
+            if let Some(connection) = self.avm1_debugger {
+                context.avm1.set_debugger(Avm1Debugger::new(connection));
+            }
+            if let Some(connection) = self.avm2_debugger {
+                context.avm2.set_debugger(Debugger::new(connection));
+            }
             Avm2::load_player_globals(context);
 
             let stage = context.stage;
@@ -3177,12 +3603,22 @@ pub struct DragObject<'gc> {
 fn run_mouse_pick<'gc>(
     context: &mut UpdateContext<'gc>,
     require_button_mode: bool,
+) -> Option<InteractiveObject<'gc>> {
+    let mouse_position = *context.mouse_position;
+    run_pick(context, mouse_position, require_button_mode)
+}
+
+/// Finds the interactive object at `position` on the stage, like the mouse would.
+pub(crate) fn run_pick<'gc>(
+    context: &mut UpdateContext<'gc>,
+    position: Point<Twips>,
+    require_button_mode: bool,
 ) -> Option<InteractiveObject<'gc>> {
     context.stage.iter_render_list().rev().find_map(|level| {
         level.as_interactive().and_then(|l| {
             if l.as_displayobject().movie().is_action_script_3() {
                 let pick = l
-                    .mouse_pick_avm2(context, *context.mouse_position, require_button_mode)
+                    .mouse_pick_avm2(context, position, require_button_mode)
                     .combine_with_parent(context.stage.into());
 
                 if let Avm2MousePick::Hit(target) = pick {
@@ -3197,7 +3633,7 @@ fn run_mouse_pick<'gc>(
                     None
                 }
             } else {
-                l.mouse_pick_avm1(context, *context.mouse_position, require_button_mode)
+                l.mouse_pick_avm1(context, position, require_button_mode)
             }
         })
     })
@@ -0,0 +1,161 @@
+//! Rendering of the pages printed by `PrintJob`s.
+
+use crate::backend::print::PrintPage;
+use crate::bitmap::bitmap_data::{BitmapData, IBitmapDrawable};
+use crate::bitmap::operations;
+use crate::context::UpdateContext;
+use crate::display_object::{DisplayObject, MovieClip, TDisplayObject};
+use crate::string::WStr;
+use ruffle_render::bitmap::PixelRegion;
+use ruffle_render::matrix::Matrix;
+use ruffle_render::quality::StageQuality;
+use ruffle_render::transform::Transform;
+use swf::{BlendMode, Rectangle, Twips};
+
+/// How many pixels a point of a page takes, so that printed pages aren't blurry.
+const PRINT_RESOLUTION: f64 = 4.0;
+
+/// The frames of a clip printed by `print`: the ones labelled `#p`, or every frame if none is.
+pub fn printable_frames(clip: MovieClip<'_>) -> Vec<u16> {
+    let frames = labelled_frames(clip, b"#p");
+    if frames.is_empty() {
+        (1..=clip.header_frames()).collect()
+    } else {
+        frames
+    }
+}
+
+/// The frame labelled `#b`, whose bounds are the area printed by `print` with `bmovie`.
+pub fn bounds_frame(clip: MovieClip<'_>) -> Option<u16> {
+    labelled_frames(clip, b"#b").first().copied()
+}
+
+fn labelled_frames(clip: MovieClip<'_>, label: &[u8]) -> Vec<u16> {
+    let mut frames: Vec<_> = clip
+        .labels_in_range(1, clip.header_frames().saturating_add(1))
+        .into_iter()
+        .filter(|(name, _)| name.eq_ignore_case(WStr::from_units(label)))
+        .map(|(_, frame)| frame)
+        .collect();
+    frames.dedup();
+    frames
+}
+
+/// Calls `f` with the clip showing each of `frames` in turn, then moves the clip back to
+/// the frame it was on.
+///
+/// The frames are only displayed: any action they would run is dropped, as Flash prints
+/// frames without playing them.
+pub fn for_each_frame<'gc>(
+    context: &mut UpdateContext<'gc>,
+    clip: MovieClip<'gc>,
+    frames: &[u16],
+    mut f: impl FnMut(&mut UpdateContext<'gc>, u16),
+) {
+    let current_frame = clip.current_frame();
+    let was_playing = clip.playing();
+    let action_queue = std::mem::take(&mut *context.action_queue);
+
+    for &frame in frames {
+        clip.goto_frame(context, frame, true);
+        f(context, frame);
+    }
+    clip.goto_frame(context, current_frame, !was_playing);
+
+    *context.action_queue = action_queue;
+}
+
+/// Renders `area` of a display object and adds it to the current print job, returning
+/// whether it could be.
+///
+/// `area` is in the coordinates of the object, and is printed from the top left corner of
+/// the printable area of the page, whose size in points is given. It's printed at its
+/// actual size (a pixel of the movie is a point of the page) with anything which doesn't
+/// fit cut off, unless `fit_to_page` is set, in which case it's scaled to fill the page
+/// while keeping its aspect ratio.
+pub fn add_page<'gc>(
+    context: &mut UpdateContext<'gc>,
+    object: DisplayObject<'gc>,
+    area: Rectangle<Twips>,
+    (page_width, page_height): (u32, u32),
+    fit_to_page: bool,
+    as_bitmap: bool,
+) -> bool {
+    if page_width == 0 || page_height == 0 {
+        return false;
+    }
+
+    let area_width = area.width().to_pixels();
+    let area_height = area.height().to_pixels();
+    let page_scale = if fit_to_page && area_width > 0.0 && area_height > 0.0 {
+        (page_width as f64 / area_width).min(page_height as f64 / area_height)
+    } else {
+        1.0
+    };
+
+    let pixel_width = (page_width as f64 * PRINT_RESOLUTION) as u32;
+    let pixel_height = (page_height as f64 * PRINT_RESOLUTION) as u32;
+    let scale = page_scale * PRINT_RESOLUTION;
+    let transform = Transform {
+        matrix: Matrix::create_box(
+            scale as f32,
+            scale as f32,
+            Twips::from_pixels(-area.x_min.to_pixels() * scale),
+            Twips::from_pixels(-area.y_min.to_pixels() * scale),
+        ),
+        ..Default::default()
+    };
+    let clip = Rectangle {
+        x_min: Twips::ZERO,
+        y_min: Twips::ZERO,
+        x_max: Twips::from_pixels(area_width * scale),
+        y_max: Twips::from_pixels(area_height * scale),
+    };
+
+    // Pages printed as bitmaps look like the movie on screen, while the others are
+    // rendered as sharply as we can.
+    let quality = if as_bitmap {
+        context.stage.quality()
+    } else {
+        StageQuality::High8x8
+    };
+
+    let target = BitmapData::new(context.gc(), pixel_width, pixel_height, false, 0xFFFFFFFF);
+    if operations::draw(
+        context,
+        target,
+        IBitmapDrawable::DisplayObject(object),
+        transform,
+        true,
+        BlendMode::Normal,
+        Some(clip),
+        quality,
+    )
+    .is_err()
+    {
+        tracing::warn!("Couldn't render page of print job");
+        return false;
+    }
+
+    let read = target.read_area(
+        PixelRegion::for_whole_size(pixel_width, pixel_height),
+        context.renderer,
+    );
+    let mut rgba = Vec::with_capacity(pixel_width as usize * pixel_height as usize * 4);
+    for y in 0..pixel_height {
+        for x in 0..pixel_width {
+            let color = read.get_pixel32_raw(x, y);
+            rgba.extend([color.red(), color.green(), color.blue(), 0xFF]);
+        }
+    }
+    drop(read);
+
+    context.print.add_page(PrintPage {
+        width: page_width,
+        height: page_height,
+        pixel_width,
+        pixel_height,
+        rgba,
+    });
+    true
+}
@@ -6,6 +6,7 @@ use crate::avm2::object::{EventObject, SocketObject};
 use crate::avm2::{Activation as Avm2Activation, Avm2};
 use crate::backend::navigator::NavigatorBackend;
 use crate::context::UpdateContext;
+use crate::net_connection::{NetConnectionHandle, NetConnections};
 use crate::string::AvmString;
 
 use async_channel::{Receiver, Sender, unbounded};
@@ -26,6 +27,9 @@ new_key_type! {
 enum SocketKind<'gc> {
     Avm2(SocketObject<'gc>),
     Avm1(Avm1Object<'gc>),
+
+    /// The socket of a `NetConnection` speaking RTMP.
+    NetConnection(#[collect(require_static)] NetConnectionHandle),
 }
 
 #[derive(Collect)]
@@ -146,6 +150,32 @@ impl<'gc> Sockets<'gc> {
         }
     }
 
+    pub fn connect_net_connection(
+        &mut self,
+        backend: &mut dyn NavigatorBackend,
+        target: NetConnectionHandle,
+        host: String,
+        port: u16,
+        timeout: Duration,
+    ) -> SocketHandle {
+        let (sender, receiver) = unbounded();
+
+        let socket = Socket::new(SocketKind::NetConnection(target), sender);
+        let handle = self.sockets.insert(socket);
+
+        // NOTE: This call will send SocketAction::Connect to sender with connection status.
+        backend.connect_socket(
+            sanitize_host(&host).to_string(),
+            port,
+            timeout,
+            handle,
+            receiver,
+            self.sender.clone(),
+        );
+
+        handle
+    }
+
     pub fn is_connected(&self, handle: SocketHandle) -> bool {
         if let Some(socket) = self.sockets.get(handle) {
             socket.connected.get()
@@ -196,6 +226,7 @@ impl<'gc> Sockets<'gc> {
                 target.read_buffer().clear();
                 target.write_buffer().clear();
             }
+            SocketKind::NetConnection(_) => {}
         }
     }
 
@@ -239,6 +270,9 @@ impl<'gc> Sockets<'gc> {
                                 ExecutionReason::Special,
                             );
                         }
+                        SocketKind::NetConnection(target) => {
+                            NetConnections::socket_connected(context, target);
+                        }
                     }
                 }
                 SocketAction::Connect(
@@ -277,6 +311,9 @@ impl<'gc> Sockets<'gc> {
                                 ExecutionReason::Special,
                             );
                         }
+                        SocketKind::NetConnection(target) => {
+                            NetConnections::socket_failed(context, target);
+                        }
                     }
                 }
                 SocketAction::Data(handle, data) => {
@@ -351,6 +388,9 @@ impl<'gc> Sockets<'gc> {
                                 }
                             }
                         }
+                        SocketKind::NetConnection(target) => {
+                            NetConnections::socket_data(context, target, &data);
+                        }
                     }
                 }
                 SocketAction::Close(handle) => {
@@ -391,6 +431,9 @@ impl<'gc> Sockets<'gc> {
                                 ExecutionReason::Special,
                             );
                         }
+                        SocketKind::NetConnection(target) => {
+                            NetConnections::socket_closed(context, target);
+                        }
                     }
                 }
             }
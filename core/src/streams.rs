@@ -14,19 +14,25 @@ use crate::backend::audio::{
     DecodeError, SoundInstanceHandle, SoundStreamInfo, SoundStreamWrapping,
 };
 use crate::backend::navigator::Request;
+use crate::camera::Camera;
 use crate::context::UpdateContext;
 use crate::display_object::{MovieClip, TDisplayObject};
 use crate::loader::Error;
+use crate::net_connection::{NetConnectionHandle, NetConnections};
 use crate::string::AvmString;
 use flv_rs::{
     AudioData as FlvAudioData, AudioDataType as FlvAudioDataType, Error as FlvError, FlvReader,
     FrameType as FlvFrameType, Header as FlvHeader, ScriptData as FlvScriptData,
     SoundFormat as FlvSoundFormat, SoundRate as FlvSoundRate, SoundSize as FlvSoundSize,
     SoundType as FlvSoundType, Tag as FlvTag, TagData as FlvTagData, Value as FlvValue,
-    VideoData as FlvVideoData, VideoPacket as FlvVideoPacket,
+    Variable as FlvVariable, VideoData as FlvVideoData, VideoPacket as FlvVideoPacket,
 };
 use gc_arena::barrier::unlock;
 use gc_arena::{Collect, DynamicRoot, Gc, Lock, Mutation, Rootable};
+use mp4_rs::{
+    AudioCodec as Mp4AudioCodec, Error as Mp4Error, Media as Mp4Media, Movie as Mp4Movie,
+    Sample as Mp4Sample, Track as Mp4Track, VideoCodec as Mp4VideoCodec,
+};
 use ruffle_common::buffer::{Buffer, Slice, Substream, SubstreamError};
 use ruffle_common::duration::FloatDuration;
 use ruffle_macros::istr;
@@ -35,11 +41,17 @@ use ruffle_video::VideoStreamHandle;
 use ruffle_video::frame::EncodedFrame;
 use std::cell::{Cell, RefCell};
 use std::cmp::max;
+use std::fmt::{Debug, Formatter};
 use std::io::{Seek, SeekFrom};
+use std::rc::Rc;
 use swf::{AudioCompression, SoundFormat, VideoCodec, VideoDeblocking};
 use thiserror::Error;
 use url::Url;
 
+/// How many audio samples of an MP4 are queued beyond the current stream
+/// time, to avoid audio underruns.
+const MP4_LOOKAHEAD_AUDIO_SAMPLES: usize = 5;
+
 #[derive(Debug, Error)]
 enum NetstreamError {
     #[error("Decoding failed because {0}")]
@@ -182,6 +194,28 @@ impl NetStreamHandle {
     }
 }
 
+impl Debug for NetStreamHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NetStreamHandle")
+    }
+}
+
+/// An action passed to `NetStream.appendBytesAction`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AppendBytesAction {
+    /// Flush the buffer and start over with a new FLV header, with the
+    /// timescale starting at 0 again.
+    ResetBegin,
+
+    /// Flush the buffer and continue with FLV tags from the position the
+    /// stream was just seeked to.
+    ResetSeek,
+
+    /// No more data will be appended, so the stream ends once the buffer
+    /// runs out.
+    EndSequence,
+}
+
 /// The current type of the data in the stream buffer.
 #[derive(Clone, Debug)]
 pub enum NetStreamType {
@@ -200,6 +234,124 @@ pub enum NetStreamType {
         /// frame IDs ourselves for various API related purposes.
         frame_id: u32,
     },
+
+    /// The stream is an MP4 (or F4V).
+    Mp4(Box<Mp4Stream>),
+}
+
+/// The playback state of an MP4 stream.
+///
+/// Unlike FLV, an MP4 describes all of its samples up front in its `moov`
+/// box, so playback works off of the sample tables rather than by reading the
+/// buffer in order.
+#[derive(Clone, Debug)]
+pub struct Mp4Stream {
+    movie: Rc<Mp4Movie>,
+
+    /// The index of the video track being played, if any.
+    video_track: Option<usize>,
+
+    /// The index of the audio track being played, if any.
+    audio_track: Option<usize>,
+
+    /// The video track's stream instance.
+    video_stream: Option<VideoStreamHandle>,
+
+    /// The index of the next video sample to be decoded.
+    next_video_sample: usize,
+
+    /// The index of the next audio sample to be queued into the audio stream.
+    next_audio_sample: usize,
+
+    /// The index of the last decoded frame.
+    frame_id: u32,
+}
+
+impl Mp4Stream {
+    /// Pick the tracks to play from a movie and register its video stream.
+    fn new(context: &mut UpdateContext<'_>, movie: Rc<Mp4Movie>) -> Self {
+        let mut video_track = movie.video_track();
+        let mut video_stream = None;
+        if let Some(index) = video_track {
+            let track = &movie.tracks[index];
+            match &track.media {
+                Mp4Media::Video {
+                    width,
+                    height,
+                    codec: Mp4VideoCodec::Avc { config },
+                } => {
+                    match context.video.register_video_stream(
+                        track.samples.len() as u32,
+                        (*width, *height),
+                        VideoCodec::H264,
+                        VideoDeblocking::UseVideoPacketValue,
+                    ) {
+                        Ok(stream_handle) => {
+                            if let Err(e) = context
+                                .video
+                                .configure_video_stream_decoder(stream_handle, config)
+                            {
+                                tracing::error!("Configuring MP4 video decoder failed: {}", e);
+                            }
+                            video_stream = Some(stream_handle);
+                        }
+                        Err(e) => {
+                            tracing::error!("Got error when registering MP4 video stream: {}", e);
+                            video_track = None;
+                        }
+                    }
+                }
+                media => {
+                    tracing::error!("Unsupported MP4 video track: {:?}", media);
+                    video_track = None;
+                }
+            }
+        }
+
+        let audio_track = movie.audio_track().filter(|&index| {
+            let media = &movie.tracks[index].media;
+            let supported = matches!(
+                media,
+                Mp4Media::Audio {
+                    codec: Mp4AudioCodec::Aac { .. } | Mp4AudioCodec::Mp3,
+                    ..
+                }
+            );
+            if !supported {
+                tracing::error!("Unsupported MP4 audio track: {:?}", media);
+            }
+            supported
+        });
+
+        Self {
+            movie,
+            video_track,
+            audio_track,
+            video_stream,
+            next_video_sample: 0,
+            next_audio_sample: 0,
+            frame_id: 0,
+        }
+    }
+
+    /// Move playback to the last keyframe at or before `offset`, and return
+    /// the time of that keyframe in milliseconds.
+    fn seek(&mut self, offset: f64) -> f64 {
+        let mut time = offset;
+        if let Some(index) = self.video_track {
+            let track = &self.movie.tracks[index];
+            self.next_video_sample = track.sync_sample_before(offset);
+            if let Some(sample) = track.samples.get(self.next_video_sample) {
+                time = track.time_to_ms(sample.decode_time);
+            }
+        }
+
+        if let Some(index) = self.audio_track {
+            self.next_audio_sample = self.movie.tracks[index].sample_after(time);
+        }
+
+        time
+    }
 }
 
 #[derive(Clone, Debug, Collect)]
@@ -229,6 +381,21 @@ pub struct NetStreamSource {
     /// separate preload step for that given format.
     preload_offset: Cell<usize>,
 
+    /// The position of the first top-level box of an MP4 that could be its
+    /// `moov` box, while waiting for that box to be downloaded.
+    ///
+    /// This lets each chunk of incoming data be scanned only once.
+    mp4_scan_offset: Cell<usize>,
+
+    /// The position of the first FLV tag that `buffer_length` hasn't looked
+    /// at yet, or 0 if it hasn't looked at any.
+    ///
+    /// This lets each chunk of incoming data be scanned only once.
+    flv_scan_offset: Cell<usize>,
+
+    /// The timestamp of the last complete FLV tag in the buffer.
+    flv_end_time: Cell<Option<i32>>,
+
     /// The current stream type, if known.
     stream_type: RefCell<Option<NetStreamType>>,
 
@@ -246,6 +413,14 @@ pub struct NetStreamSource {
 
     /// The currently playing sound stream
     sound_instance: Cell<Option<SoundInstanceHandle>>,
+
+    /// Whether the buffer ran out of data in data generation mode, and we're
+    /// waiting for more to be appended.
+    starved: Cell<bool>,
+
+    /// Whether `NetStream.Buffer.Full` was fired since the buffer was last
+    /// emptied.
+    buffer_full: Cell<bool>,
 }
 
 #[derive(Clone, Debug, Collect)]
@@ -254,8 +429,8 @@ pub struct NetStreamData<'gc> {
     /// Stream source.
     source: Lock<Gc<'gc, NetStreamSource>>,
 
-    /// The number of seconds of video data that should be buffered. This is
-    /// currently unsupported and changing it has no effect.
+    /// The number of seconds of video data that should be buffered before
+    /// `NetStream.Buffer.Full` is fired.
     buffer_time: Cell<f64>,
 
     /// The last decoded bitmap.
@@ -278,6 +453,19 @@ pub struct NetStreamData<'gc> {
 
     /// True if the stream should play when ticked.
     playing: Cell<bool>,
+
+    /// True if the stream is in data generation mode, i.e. its data is
+    /// provided by `NetStream.appendBytes` rather than downloaded.
+    data_generation: Cell<bool>,
+
+    /// The `NetConnection` this stream was created with.
+    ///
+    /// Streams of RTMP connections are played from the server rather than
+    /// downloaded.
+    connection: Cell<Option<NetConnectionHandle>>,
+
+    /// The `Camera` attached to this stream.
+    camera: Lock<Option<Camera<'gc>>>,
 }
 
 impl Default for NetStreamSource {
@@ -287,11 +475,16 @@ impl Default for NetStreamSource {
             offset: Cell::new(0),
             expected_length: Cell::new(Some(0)),
             preload_offset: Cell::new(0),
+            mp4_scan_offset: Cell::new(0),
+            flv_scan_offset: Cell::new(0),
+            flv_end_time: Cell::new(None),
             stream_type: RefCell::new(None),
             stream_time: Cell::new(0.0),
             queued_seek_time: Cell::new(None),
             audio_stream: RefCell::new(None),
             sound_instance: Cell::new(None),
+            starved: Cell::new(false),
+            buffer_full: Cell::new(false),
         }
     }
 }
@@ -322,6 +515,9 @@ impl<'gc> NetStream<'gc> {
                 url: RefCell::new(None),
                 attached_to: Lock::new(None),
                 playing: Cell::new(false),
+                data_generation: Cell::new(false),
+                connection: Cell::new(None),
+                camera: Lock::new(None),
             },
         ))
     }
@@ -330,6 +526,24 @@ impl<'gc> NetStream<'gc> {
         self.0.source.get()
     }
 
+    pub fn set_connection(self, connection: Option<NetConnectionHandle>) {
+        self.0.connection.set(connection);
+    }
+
+    /// Attaches a camera to this stream, or detaches the current one if
+    /// `camera` is `None`.
+    ///
+    /// Attached cameras keep capturing, but their video isn't published yet.
+    pub fn attach_camera(self, gc_context: &Mutation<'gc>, camera: Option<Camera<'gc>>) {
+        if let Some(old_camera) = self.0.camera.get() {
+            old_camera.remove_stream(gc_context, self);
+        }
+        if let Some(camera) = camera {
+            camera.add_stream(gc_context, self);
+        }
+        unlock!(Gc::write(gc_context, self.0), NetStreamData, camera).set(camera);
+    }
+
     pub fn set_client(self, gc_context: &Mutation<'gc>, new_client: Avm2Object<'gc>) {
         unlock!(Gc::write(gc_context, self.0), NetStreamData, avm2_client).set(Some(new_client));
     }
@@ -404,12 +618,129 @@ impl<'gc> NetStream<'gc> {
         );
     }
 
+    /// Fire `NetStream.Buffer.Full` once enough data is appended in data
+    /// generation mode to play for `bufferTime` seconds.
+    fn check_buffer_full(self, context: &mut UpdateContext<'gc>) {
+        let source = self.source();
+        if source.buffer_full.get() {
+            return;
+        }
+
+        if self.buffer_length() >= self.buffer_time() {
+            source.buffer_full.set(true);
+            self.trigger_status_event(
+                context,
+                [("code", "NetStream.Buffer.Full"), ("level", "status")],
+            );
+        }
+    }
+
+    /// The number of seconds of media buffered ahead of the playhead.
+    ///
+    /// This is only known for FLVs, as the samples of an MP4 aren't stored in
+    /// order.
+    pub fn buffer_length(self) -> f64 {
+        let source = self.source();
+        let slice = source.buffer.borrow().to_full_slice();
+        let buffer = slice.data();
+
+        let mut reader = match source.flv_scan_offset.get() {
+            0 => {
+                let mut reader = FlvReader::from_parts(&buffer, source.offset.get());
+                match &*source.stream_type.borrow() {
+                    Some(NetStreamType::Flv { .. }) => {}
+                    // The tags follow the header, which hasn't been parsed yet.
+                    None if buffer.starts_with(b"FLV") => {
+                        if FlvHeader::parse(&mut reader).is_err() {
+                            return 0.0;
+                        }
+                    }
+                    _ => return 0.0,
+                }
+                reader
+            }
+            offset => FlvReader::from_parts(&buffer, offset),
+        };
+
+        // Only look at the tags that were appended since the last call.
+        while let Ok(tag) = FlvTag::parse(&mut reader) {
+            source.flv_end_time.set(Some(tag.timestamp));
+        }
+        source.flv_scan_offset.set(reader.into_parts().1);
+
+        source.flv_end_time.get().map_or(0.0, |end_time| {
+            (end_time as f64 - source.stream_time.get()).max(0.0) / 1000.0
+        })
+    }
+
     /// Indicate that the buffer has finished loading and that no further data
     /// is expected to be downloaded to it.
     pub fn finish_buffer(self) {
         self.source().expected_length.set(None);
     }
 
+    /// Whether this stream is in data generation mode.
+    pub fn is_data_generation(self) -> bool {
+        self.0.data_generation.get()
+    }
+
+    /// Append data passed to `NetStream.appendBytes`.
+    ///
+    /// Only meaningful in data generation mode, where this is the only way
+    /// data gets into the buffer.
+    pub fn append_bytes(self, context: &mut UpdateContext<'gc>, data: &mut Vec<u8>) {
+        let source = self.source();
+        source.buffer.borrow_mut().append(data);
+
+        StreamManager::activate(context, self);
+
+        source.starved.set(false);
+        self.check_buffer_full(context);
+    }
+
+    /// Handle an action passed to `NetStream.appendBytesAction`.
+    pub fn append_bytes_action(self, context: &mut UpdateContext<'gc>, action: AppendBytesAction) {
+        match action {
+            AppendBytesAction::ResetBegin => {
+                self.reset_buffer(context);
+                StreamManager::activate(context, self);
+            }
+            AppendBytesAction::ResetSeek => {
+                self.discard_buffer(context);
+
+                let source = self.source();
+                source.expected_length.set(Some(0));
+                source.starved.set(false);
+                StreamManager::activate(context, self);
+            }
+            AppendBytesAction::EndSequence => {
+                self.finish_buffer();
+                StreamManager::activate(context, self);
+            }
+        }
+    }
+
+    /// Stop the audio of the stream and discard the data in its buffer.
+    ///
+    /// The stream type (and with it, the video decoder) is kept, as this is
+    /// used when seeking in data generation mode, where the data appended
+    /// next is made of tags from the same FLV.
+    fn discard_buffer(self, context: &mut UpdateContext<'gc>) {
+        let source = self.source();
+        if let Some(sound) = source.sound_instance.take() {
+            context.stop_sound(sound);
+            context.audio.stop_sound(sound);
+        }
+        source.audio_stream.replace(None);
+        source.buffer.replace(Buffer::new());
+        source.offset.set(0);
+        source.preload_offset.set(0);
+        source.mp4_scan_offset.set(0);
+        source.flv_scan_offset.set(0);
+        source.flv_end_time.set(None);
+        source.buffer_full.set(false);
+    }
+
     pub fn report_error(self, _error: Error) {
         // TODO: Report an `asyncError` to AVM1 or 2.
     }
@@ -473,13 +804,26 @@ impl<'gc> NetStream<'gc> {
     /// This function should be run during stream ticks and *not* called by AVM
     /// code to service seek requests.
     pub fn execute_seek(self, context: &mut UpdateContext<'gc>, offset: f64) {
+        let source = self.source();
+
+        if self.0.data_generation.get() {
+            // The movie is expected to call `appendBytesAction(RESET_SEEK)`
+            // and append data from the new position itself, when notified of
+            // the seek, so the old data has to be gone by then.
+            self.discard_buffer(context);
+            source.stream_time.set(offset);
+            self.trigger_status_event(
+                context,
+                [("code", "NetStream.Seek.Notify"), ("level", "status")],
+            );
+            return;
+        }
+
         self.trigger_status_event(
             context,
             [("code", "NetStream.Seek.Notify"), ("level", "status")],
         );
 
-        let source = self.source();
-
         // Ensure the container stream type is known before continuing.
         if source.stream_type.borrow().is_none() && !self.sniff_stream_type(context) {
             return;
@@ -572,6 +916,8 @@ impl<'gc> NetStream<'gc> {
                 .stream_position()
                 .expect("FLV reader stream position") as usize;
             source.offset.set(offset);
+        } else if let Some(NetStreamType::Mp4(stream)) = &mut *source.stream_type.borrow_mut() {
+            source.stream_time.set(stream.seek(offset));
         }
 
         if let Some(NetStreamKind::Avm2(_)) = self.0.avm_object.get() {
@@ -593,6 +939,15 @@ impl<'gc> NetStream<'gc> {
     /// available in the buffer.
     pub fn play(self, context: &mut UpdateContext<'gc>, name: Option<AvmString<'gc>>) {
         if let Some(name) = name {
+            if let Some(connection) = self.0.connection.get()
+                && NetConnections::play_rtmp_stream(context, connection, self, name.to_string())
+            {
+                // The server reports the stream starting.
+                return;
+            }
+
+            self.0.data_generation.set(false);
+
             let request = if let Ok(stream_url) = Url::parse(context.root_swf.url())
                 .and_then(|url| url.join(name.to_string().as_str()))
             {
@@ -602,6 +957,9 @@ impl<'gc> NetStream<'gc> {
             };
             self.0.url.replace(Some(request.url().to_string()));
             self.source().preload_offset.set(0);
+            self.source().mp4_scan_offset.set(0);
+            self.source().flv_scan_offset.set(0);
+            self.source().flv_end_time.set(None);
             let future = crate::loader::load_netstream(context, self, request);
 
             context.navigator.spawn_future(future);
@@ -616,6 +974,59 @@ impl<'gc> NetStream<'gc> {
         );
     }
 
+    /// Start playing media from this NetStream in data generation mode.
+    ///
+    /// Any data in the buffer is discarded. From now on, the stream plays
+    /// data passed to `append_bytes`, which must start with an FLV header.
+    pub fn play_data_generation(self, context: &mut UpdateContext<'gc>) {
+        self.reset_buffer(context);
+        self.0.url.replace(None);
+        self.0.data_generation.set(true);
+
+        self.0.playing.set(true);
+        StreamManager::activate(context, self);
+
+        self.trigger_status_event(
+            context,
+            [("code", "NetStream.Play.Start"), ("level", "status")],
+        );
+    }
+
+    /// Start playing media streamed by an RTMP server.
+    ///
+    /// Any data in the buffer is discarded. From now on, the stream plays
+    /// the messages passed to `append_flv_tag`.
+    pub fn play_live(self, context: &mut UpdateContext<'gc>) {
+        self.reset_buffer(context);
+        self.0.url.replace(None);
+        self.0.data_generation.set(true);
+        self.0.playing.set(true);
+
+        // The header of an FLV with both audio and video, followed by the
+        // size of the (nonexistent) previous tag.
+        let mut header = b"FLV\x01\x05\x00\x00\x00\x09\x00\x00\x00\x00".to_vec();
+        self.append_bytes(context, &mut header);
+    }
+
+    /// Append a tag to a stream started with `play_live`.
+    pub fn append_flv_tag(
+        self,
+        context: &mut UpdateContext<'gc>,
+        tag_type: u8,
+        timestamp: u32,
+        data: &[u8],
+    ) {
+        let mut tag = Vec::with_capacity(11 + data.len() + 4);
+        tag.push(tag_type);
+        tag.extend_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+        tag.extend_from_slice(&timestamp.to_be_bytes()[1..]);
+        tag.push((timestamp >> 24) as u8);
+        tag.extend_from_slice(&[0, 0, 0]);
+        tag.extend_from_slice(data);
+        tag.extend_from_slice(&(11 + data.len() as u32).to_be_bytes());
+        self.append_bytes(context, &mut tag);
+    }
+
     /// Pause stream playback.
     pub fn pause(self, context: &mut UpdateContext<'gc>, notify: bool) {
         // NOTE: We do not deactivate the stream here as there may be other
@@ -868,6 +1279,33 @@ impl<'gc> NetStream<'gc> {
                     }
                 }
             }
+            Some(_) if mp4_rs::sniff(&buffer) => {
+                // The `moov` box may well be at the end of the file, in which
+                // case nothing can be played until it's downloaded.
+                let mut moov_offset = source.mp4_scan_offset.get();
+                let found = mp4_rs::find_moov(&buffer, &mut moov_offset);
+                source.mp4_scan_offset.set(moov_offset);
+                match found.and_then(|()| Mp4Movie::parse_at(&buffer, moov_offset)) {
+                    Ok(movie) => {
+                        let movie = Rc::new(movie);
+                        let stream = Mp4Stream::new(context, movie.clone());
+                        source
+                            .stream_type
+                            .replace(Some(NetStreamType::Mp4(Box::new(stream))));
+                        self.mp4_metadata(context, &movie);
+                        true
+                    }
+                    Err(Mp4Error::EndOfData) => false,
+                    Err(e) => {
+                        //TODO: Fire an error event to AS & stop playing too
+                        tracing::error!("MP4 parsing failed: {}", e);
+                        source.preload_offset.set(3);
+                        false
+                    }
+                }
+            }
+            // An MP4 can't be told apart from an unknown format this early.
+            Some(_) if buffer.len() < 8 && source.expected_length.get().is_some() => false,
             Some(magic) => {
                 //Unrecognized signature
                 //TODO: Fire an error event to AS & stop playing too
@@ -1267,6 +1705,8 @@ impl<'gc> NetStream<'gc> {
                         .set(max(source.offset.get(), source.preload_offset.get()));
                 }
             }
+        } else if matches!(&*source.stream_type.borrow(), Some(NetStreamType::Mp4(_))) {
+            buffer_underrun = self.mp4_tick(context, &slice, max_time);
         }
 
         source.stream_time.set(max_time);
@@ -1275,8 +1715,20 @@ impl<'gc> NetStream<'gc> {
             tracing::error!("Error committing sound stream: {}", e);
         }
 
-        if buffer_underrun {
+        if buffer_underrun && self.0.data_generation.get() && source.expected_length.get().is_some()
+        {
+            // Keep playing whatever gets appended next, but only tell the
+            // movie once that it needs to append more.
+            source.buffer_full.set(false);
+            if !source.starved.replace(true) {
+                self.trigger_status_event(
+                    context,
+                    [("code", "NetStream.Buffer.Empty"), ("level", "status")],
+                );
+            }
+        } else if buffer_underrun {
             let is_end_of_video = source.expected_length.get().is_none();
+            source.buffer_full.set(false);
 
             self.trigger_status_event(
                 context,
@@ -1306,6 +1758,287 @@ impl<'gc> NetStream<'gc> {
         }
     }
 
+    /// Decode the video and queue up the audio of an MP4 up to `max_time`.
+    ///
+    /// Returns true if the stream ran out of data, either because the samples
+    /// due haven't been downloaded yet or because the movie ended.
+    fn mp4_tick(self, context: &mut UpdateContext<'gc>, slice: &Slice, max_time: f64) -> bool {
+        let source = self.source();
+        let mut stream_type = source.stream_type.borrow_mut();
+        let Some(NetStreamType::Mp4(stream)) = &mut *stream_type else {
+            unreachable!()
+        };
+        let movie = stream.movie.clone();
+        let mut buffer_underrun = false;
+
+        if let (Some(index), Some(video_handle)) = (stream.video_track, stream.video_stream) {
+            let track = &movie.tracks[index];
+            while let Some(sample) = track.samples.get(stream.next_video_sample) {
+                if track.time_to_ms(sample.decode_time) >= max_time {
+                    break;
+                }
+
+                let Some(data) = Self::mp4_sample_data(slice, sample) else {
+                    buffer_underrun = true;
+                    break;
+                };
+
+                let data = data.data();
+                let encoded_frame = EncodedFrame {
+                    codec: VideoCodec::H264,
+                    data: &data,
+                    frame_id: stream.frame_id,
+                };
+
+                match context.video.decode_video_stream_frame(
+                    video_handle,
+                    encoded_frame,
+                    context.renderer,
+                ) {
+                    Ok(bitmap_info) => {
+                        self.0.last_decoded_bitmap.replace(Some(bitmap_info));
+                        if let Some(mc) = self.0.attached_to.get() {
+                            mc.invalidate_cached_bitmap();
+                            *context.needs_render = true;
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Decoding video frame {} failed: {}", stream.frame_id, e);
+                    }
+                }
+
+                stream.next_video_sample += 1;
+                stream.frame_id += 1;
+            }
+        }
+
+        if let Some(index) = stream.audio_track {
+            let track = &movie.tracks[index];
+            let end = (track.sample_after(max_time) + MP4_LOOKAHEAD_AUDIO_SAMPLES)
+                .min(track.samples.len());
+            let audio_stream = &mut *source.audio_stream.borrow_mut();
+            let (substream, sound_stream_head) =
+                audio_stream.get_or_insert_with(|| Self::mp4_audio_stream(track));
+            let is_aac = sound_stream_head.stream_format.compression == AudioCompression::Aac;
+
+            while stream.next_audio_sample < end {
+                let sample = &track.samples[stream.next_audio_sample];
+                let Some(data) = Self::mp4_sample_data(slice, sample) else {
+                    // Running out of lookahead samples is fine.
+                    buffer_underrun |= track.time_to_ms(sample.decode_time) < max_time;
+                    break;
+                };
+
+                // The AAC decoder expects the packet type in front of each
+                // frame, as it would be in an FLV audio tag.
+                let mut audio_buffer = substream.buffer().clone();
+                let start = audio_buffer.len();
+                if is_aac {
+                    audio_buffer.extend_from_slice(&[1]);
+                }
+                audio_buffer.extend_from_slice(&data.data());
+
+                let chunk = audio_buffer.get(start..).expect("appended audio data");
+                if let Err(e) = substream.append(chunk) {
+                    tracing::error!("Error queueing MP4 audio: {}", e);
+                    break;
+                }
+
+                stream.next_audio_sample += 1;
+            }
+        }
+
+        let video_ended = stream
+            .video_track
+            .is_none_or(|index| stream.next_video_sample >= movie.tracks[index].samples.len());
+        if video_ended && max_time >= movie.duration_ms() {
+            buffer_underrun = true;
+        }
+
+        buffer_underrun
+    }
+
+    /// The data of an MP4 sample, if it's been downloaded.
+    fn mp4_sample_data(slice: &Slice, sample: &Mp4Sample) -> Option<Slice> {
+        let start = usize::try_from(sample.offset).ok()?;
+        let end = start.checked_add(sample.size as usize)?;
+
+        slice.get(start..end)
+    }
+
+    /// Create the audio stream for an MP4 audio track.
+    ///
+    /// The stream gets its own buffer, as samples are copied into it along
+    /// with the extra data the decoders expect.
+    fn mp4_audio_stream(track: &Mp4Track) -> (Substream, SoundStreamInfo) {
+        let Mp4Media::Audio {
+            sample_rate,
+            channels,
+            codec,
+        } = &track.media
+        else {
+            unreachable!()
+        };
+
+        let mut buffer = Buffer::new();
+        let mut substream = Substream::new(buffer.clone());
+        let compression = match codec {
+            Mp4AudioCodec::Aac { config, .. } => {
+                buffer.extend_from_slice(&[0]);
+                buffer.extend_from_slice(config);
+                substream
+                    .append(buffer.to_full_slice())
+                    .expect("first chunk of the substream");
+                AudioCompression::Aac
+            }
+            _ => AudioCompression::Mp3,
+        };
+
+        let sound_stream_head = SoundStreamInfo {
+            wrapping: SoundStreamWrapping::Unwrapped,
+            stream_format: SoundFormat {
+                compression,
+                sample_rate: u16::try_from(*sample_rate).unwrap_or(44_100),
+                is_stereo: *channels > 1,
+                is_16_bit: true,
+            },
+            num_samples_per_block: 0,
+            latency_seek: 0,
+        };
+
+        (substream, sound_stream_head)
+    }
+
+    /// Call `onMetaData` with what Flash Player reports for an MP4.
+    fn mp4_metadata(self, context: &mut UpdateContext<'gc>, movie: &Mp4Movie) {
+        fn variable<'a>(name: &'a [u8], data: FlvValue<'a>) -> FlvVariable<'a> {
+            FlvVariable { name, data }
+        }
+
+        let mut metadata = vec![
+            variable(b"duration", FlvValue::Number(movie.duration_ms() / 1000.0)),
+            variable(b"moovposition", FlvValue::Number(movie.moov_offset as f64)),
+        ];
+
+        if let Some(track) = movie.video_track().map(|index| &movie.tracks[index])
+            && let Mp4Media::Video {
+                width,
+                height,
+                codec,
+            } = &track.media
+        {
+            metadata.push(variable(b"width", FlvValue::Number(*width as f64)));
+            metadata.push(variable(b"height", FlvValue::Number(*height as f64)));
+            if track.duration > 0 {
+                let frame_rate = track.samples.len() as f64 * 1000.0 / track.duration_ms();
+                metadata.push(variable(b"videoframerate", FlvValue::Number(frame_rate)));
+            }
+            if let Mp4VideoCodec::Avc { config } = codec {
+                metadata.push(variable(b"videocodecid", FlvValue::String(b"avc1")));
+                if let (Some(profile), Some(level)) = (config.get(1), config.get(3)) {
+                    metadata.push(variable(b"avcprofile", FlvValue::Number(*profile as f64)));
+                    metadata.push(variable(b"avclevel", FlvValue::Number(*level as f64)));
+                }
+            }
+
+            let seekpoints = track
+                .samples
+                .iter()
+                .filter(|sample| sample.is_sync)
+                .map(|sample| {
+                    FlvValue::Object(vec![
+                        variable(
+                            b"time",
+                            FlvValue::Number(track.time_to_ms(sample.decode_time) / 1000.0),
+                        ),
+                        variable(b"offset", FlvValue::Number(sample.offset as f64)),
+                    ])
+                })
+                .collect();
+            metadata.push(variable(b"seekpoints", FlvValue::StrictArray(seekpoints)));
+        }
+
+        if let Some(track) = movie.audio_track().map(|index| &movie.tracks[index])
+            && let Mp4Media::Audio {
+                sample_rate,
+                channels,
+                codec,
+            } = &track.media
+        {
+            metadata.push(variable(
+                b"audiosamplerate",
+                FlvValue::Number(*sample_rate as f64),
+            ));
+            metadata.push(variable(
+                b"audiochannels",
+                FlvValue::Number(*channels as f64),
+            ));
+            match codec {
+                Mp4AudioCodec::Aac { object_type, .. } => {
+                    metadata.push(variable(b"audiocodecid", FlvValue::String(b"mp4a")));
+                    metadata.push(variable(b"aacaot", FlvValue::Number(*object_type as f64)));
+                }
+                Mp4AudioCodec::Mp3 => {
+                    metadata.push(variable(b"audiocodecid", FlvValue::String(b".mp3")));
+                }
+                Mp4AudioCodec::Unknown(_) => {}
+            }
+        }
+
+        let trackinfo = movie
+            .tracks
+            .iter()
+            .map(|track| {
+                let sample_type: &[u8] = match &track.media {
+                    Mp4Media::Video {
+                        codec: Mp4VideoCodec::Avc { .. },
+                        ..
+                    } => b"avc1",
+                    Mp4Media::Audio {
+                        codec: Mp4AudioCodec::Aac { .. },
+                        ..
+                    } => b"mp4a",
+                    Mp4Media::Audio {
+                        codec: Mp4AudioCodec::Mp3,
+                        ..
+                    } => b".mp3",
+                    Mp4Media::Video {
+                        codec: Mp4VideoCodec::Unknown(kind),
+                        ..
+                    }
+                    | Mp4Media::Audio {
+                        codec: Mp4AudioCodec::Unknown(kind),
+                        ..
+                    }
+                    | Mp4Media::Other(kind) => kind,
+                };
+
+                FlvValue::Object(vec![
+                    variable(b"length", FlvValue::Number(track.duration as f64)),
+                    variable(b"timescale", FlvValue::Number(track.timescale as f64)),
+                    variable(b"language", FlvValue::String(track.language.as_bytes())),
+                    variable(
+                        b"sampledescription",
+                        FlvValue::StrictArray(vec![FlvValue::Object(vec![variable(
+                            b"sampletype",
+                            FlvValue::String(sample_type),
+                        )])]),
+                    ),
+                ])
+            })
+            .collect();
+        metadata.push(variable(b"trackinfo", FlvValue::StrictArray(trackinfo)));
+
+        let avm_object = self.0.avm_object.get();
+        // Any errors while trying to lookup or call AVM2 properties are silently swallowed.
+        let _ = self.handle_script_data(
+            avm_object,
+            context,
+            b"onMetaData",
+            FlvValue::EcmaArray(metadata),
+        );
+    }
+
     pub fn last_decoded_bitmap(self) -> Option<BitmapInfo> {
         self.0.last_decoded_bitmap.borrow().clone()
     }
@@ -0,0 +1,990 @@
+//! Touch input.
+//!
+//! Depending on `Multitouch.inputMode`, touch points are dispatched to AVM2 as `TouchEvent`s or
+//! recognized as gestures. Either way, the primary touch point (the first finger to touch the
+//! screen) also drives the mouse, so that movies made for the mouse keep working.
+
+use crate::avm2::object::EventObject;
+use crate::avm2::{Activation as Avm2Activation, Avm2};
+use crate::context::UpdateContext;
+use crate::display_object::{InteractiveObject, TDisplayObject, TInteractiveObject};
+use crate::player::run_pick;
+use crate::string::{FromWStr, WStr};
+use gc_arena::Collect;
+use ruffle_common::duration::FloatDuration;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use swf::{Point, Twips};
+
+/// How far, in pixels, a finger may move while tapping.
+const TAP_DISTANCE: f64 = 10.0;
+
+/// How long, in milliseconds, a tap may last.
+const TAP_DURATION: f64 = 300.0;
+
+/// How far, in pixels, a finger has to move to swipe.
+const SWIPE_DISTANCE: f64 = 50.0;
+
+/// How long, in milliseconds, a swipe may last.
+const SWIPE_DURATION: f64 = 500.0;
+
+/// How much the distance between two fingers has to change, relative to their initial
+/// distance, to start zooming.
+const ZOOM_THRESHOLD: f64 = 0.1;
+
+/// How far, in degrees, two fingers have to turn to start rotating.
+const ROTATE_THRESHOLD: f64 = 10.0;
+
+/// How far, in pixels, two fingers have to move together to start panning.
+const PAN_THRESHOLD: f64 = 10.0;
+
+/// Which events touch input produces, as set by `Multitouch.inputMode`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MultitouchInputMode {
+    /// Touch input only drives the mouse.
+    None,
+
+    /// Touch points are dispatched as `TouchEvent`s.
+    TouchPoint,
+
+    /// Touch points are recognized as gestures, dispatched as `GestureEvent`s.
+    #[default]
+    Gesture,
+}
+
+pub struct ParseEnumError;
+
+impl Display for MultitouchInputMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Match string values returned by AS.
+        let s = match *self {
+            MultitouchInputMode::None => "none",
+            MultitouchInputMode::TouchPoint => "touchPoint",
+            MultitouchInputMode::Gesture => "gesture",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromWStr for MultitouchInputMode {
+    type Err = ParseEnumError;
+
+    fn from_wstr(s: &WStr) -> Result<Self, Self::Err> {
+        if s == b"none" {
+            Ok(MultitouchInputMode::None)
+        } else if s == b"touchPoint" {
+            Ok(MultitouchInputMode::TouchPoint)
+        } else if s == b"gesture" {
+            Ok(MultitouchInputMode::Gesture)
+        } else {
+            Err(ParseEnumError)
+        }
+    }
+}
+
+/// What happened to a touch point.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TouchPhase {
+    Begin,
+    Move,
+    End,
+
+    /// The touch point was lifted without being considered a tap.
+    Cancel,
+}
+
+/// The gestures reported by `TransformGestureEvent`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransformGesture {
+    Zoom,
+    Rotate,
+    Pan,
+    Swipe,
+}
+
+impl TransformGesture {
+    /// The gestures made with two fingers, which have a beginning and an end.
+    const CONTINUOUS: [TransformGesture; 3] = [Self::Zoom, Self::Rotate, Self::Pan];
+
+    pub fn event_type(self) -> &'static str {
+        match self {
+            TransformGesture::Zoom => "gestureZoom",
+            TransformGesture::Rotate => "gestureRotate",
+            TransformGesture::Pan => "gesturePan",
+            TransformGesture::Swipe => "gestureSwipe",
+        }
+    }
+}
+
+/// The progress of a gesture, as reported by `GestureEvent.phase`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GesturePhase {
+    Begin,
+    Update,
+    End,
+
+    /// The gesture is reported by a single event.
+    All,
+}
+
+impl Display for GesturePhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match *self {
+            GesturePhase::Begin => "begin",
+            GesturePhase::Update => "update",
+            GesturePhase::End => "end",
+            GesturePhase::All => "all",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A gesture recognized from touch input.
+///
+/// Positions are in stage pixels. The values of a transform gesture are relative to its
+/// previous event: the product of the scales of a zoom, or the sum of the rotations of a
+/// rotation, is how much it changed since it began.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gesture {
+    Transform {
+        kind: TransformGesture,
+        phase: GesturePhase,
+        x: f64,
+        y: f64,
+        scale: f64,
+        rotation: f64,
+        offset_x: f64,
+        offset_y: f64,
+    },
+
+    /// Two fingers tapped at once.
+    TwoFingerTap { x: f64, y: f64 },
+
+    /// A finger tapped while another one is held.
+    PressAndTap {
+        x: f64,
+        y: f64,
+        tap_x: f64,
+        tap_y: f64,
+    },
+}
+
+impl Gesture {
+    fn transform(kind: TransformGesture, phase: GesturePhase, x: f64, y: f64) -> Self {
+        Gesture::Transform {
+            kind,
+            phase,
+            x,
+            y,
+            scale: 1.0,
+            rotation: 0.0,
+            offset_x: 0.0,
+            offset_y: 0.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TrackedPoint {
+    x: f64,
+    y: f64,
+    start_x: f64,
+    start_y: f64,
+    start_time: f64,
+
+    /// Whether other fingers touched the screen while this one did.
+    multi: bool,
+}
+
+impl TrackedPoint {
+    fn has_moved(&self) -> bool {
+        (self.x - self.start_x).hypot(self.y - self.start_y) > TAP_DISTANCE
+    }
+}
+
+/// Where two fingers are relative to each other.
+#[derive(Clone, Copy, Debug)]
+struct PinchGeometry {
+    /// The point between both fingers.
+    x: f64,
+    y: f64,
+
+    distance: f64,
+
+    /// The angle of the line from the first to the second finger, in degrees.
+    angle: f64,
+}
+
+impl PinchGeometry {
+    fn new(first: &TrackedPoint, second: &TrackedPoint) -> Self {
+        let dx = second.x - first.x;
+        let dy = second.y - first.y;
+        Self {
+            x: (first.x + second.x) / 2.0,
+            y: (first.y + second.y) / 2.0,
+            distance: dx.hypot(dy),
+            angle: dy.atan2(dx).to_degrees(),
+        }
+    }
+}
+
+/// Two fingers on the screen.
+#[derive(Clone, Copy, Debug)]
+struct Pinch {
+    first: u32,
+    second: u32,
+    start_time: f64,
+
+    /// Whether the first finger was held before the second one touched the screen.
+    pressed: bool,
+
+    start: PinchGeometry,
+    last: PinchGeometry,
+
+    /// Which of the continuous gestures are in progress.
+    active: [bool; 3],
+}
+
+impl Pinch {
+    fn contains(&self, id: u32) -> bool {
+        self.first == id || self.second == id
+    }
+
+    fn is_transforming(&self) -> bool {
+        self.active.contains(&true)
+    }
+
+    /// Ends the gestures in progress.
+    fn end(&self, gestures: &mut Vec<Gesture>) {
+        for (kind, active) in TransformGesture::CONTINUOUS.into_iter().zip(self.active) {
+            if active {
+                gestures.push(Gesture::transform(
+                    kind,
+                    GesturePhase::End,
+                    self.last.x,
+                    self.last.y,
+                ));
+            }
+        }
+    }
+}
+
+/// A two finger tap waiting for its second finger to be lifted.
+#[derive(Clone, Copy, Debug)]
+struct PendingTap {
+    id: u32,
+    x: f64,
+    y: f64,
+    start_time: f64,
+}
+
+/// Recognizes gestures from the movement of touch points.
+///
+/// Positions are in stage pixels, and times in milliseconds.
+#[derive(Clone, Debug, Default)]
+pub struct GestureRecognizer {
+    points: BTreeMap<u32, TrackedPoint>,
+    pinch: Option<Pinch>,
+    pending_tap: Option<PendingTap>,
+}
+
+impl GestureRecognizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn begin(&mut self, id: u32, x: f64, y: f64, time: f64) -> Vec<Gesture> {
+        let mut gestures = Vec::new();
+        self.points.insert(
+            id,
+            TrackedPoint {
+                x,
+                y,
+                start_x: x,
+                start_y: y,
+                start_time: time,
+                multi: false,
+            },
+        );
+
+        if self.points.len() > 1 {
+            for point in self.points.values_mut() {
+                point.multi = true;
+            }
+        }
+
+        // Gestures are made with exactly two fingers; a third one stops them.
+        if let Some(pinch) = self.pinch.take() {
+            pinch.end(&mut gestures);
+        }
+        self.pending_tap = None;
+
+        if let [(&first, first_point), (&second, second_point)] =
+            self.points.iter().collect::<Vec<_>>()[..]
+        {
+            let (first, second, first_point, second_point) = if second == id {
+                (first, second, first_point, second_point)
+            } else {
+                (second, first, second_point, first_point)
+            };
+            let geometry = PinchGeometry::new(first_point, second_point);
+            self.pinch = Some(Pinch {
+                first,
+                second,
+                start_time: time,
+                pressed: time - first_point.start_time > TAP_DURATION,
+                start: geometry,
+                last: geometry,
+                active: [false; 3],
+            });
+        }
+
+        gestures
+    }
+
+    pub fn update(&mut self, id: u32, x: f64, y: f64) -> Vec<Gesture> {
+        let mut gestures = Vec::new();
+        let Some(point) = self.points.get_mut(&id) else {
+            return gestures;
+        };
+        point.x = x;
+        point.y = y;
+
+        let Some(pinch) = self.pinch.as_mut().filter(|pinch| pinch.contains(id)) else {
+            return gestures;
+        };
+
+        let geometry = PinchGeometry::new(&self.points[&pinch.first], &self.points[&pinch.second]);
+        let (start, last) = (pinch.start, pinch.last);
+
+        for (kind, active) in TransformGesture::CONTINUOUS
+            .into_iter()
+            .zip(pinch.active.iter_mut())
+        {
+            // Gestures begin once the fingers moved far enough, and report all of the
+            // movement up to that point.
+            let from = if *active { last } else { start };
+            let scale = if from.distance > 0.0 {
+                geometry.distance / from.distance
+            } else {
+                1.0
+            };
+            let rotation = angle_difference(from.angle, geometry.angle);
+            let offset_x = geometry.x - from.x;
+            let offset_y = geometry.y - from.y;
+
+            let phase = if *active {
+                GesturePhase::Update
+            } else {
+                let started = match kind {
+                    TransformGesture::Zoom => (scale - 1.0).abs() > ZOOM_THRESHOLD,
+                    TransformGesture::Rotate => rotation.abs() > ROTATE_THRESHOLD,
+                    TransformGesture::Pan => offset_x.hypot(offset_y) > PAN_THRESHOLD,
+                    TransformGesture::Swipe => false,
+                };
+                if !started {
+                    continue;
+                }
+                *active = true;
+                GesturePhase::Begin
+            };
+
+            let (scale, rotation, offset_x, offset_y) = match kind {
+                TransformGesture::Zoom => (scale, 0.0, 0.0, 0.0),
+                TransformGesture::Rotate => (1.0, rotation, 0.0, 0.0),
+                TransformGesture::Pan | TransformGesture::Swipe => (1.0, 0.0, offset_x, offset_y),
+            };
+            gestures.push(Gesture::Transform {
+                kind,
+                phase,
+                x: geometry.x,
+                y: geometry.y,
+                scale,
+                rotation,
+                offset_x,
+                offset_y,
+            });
+        }
+
+        pinch.last = geometry;
+        gestures
+    }
+
+    pub fn end(&mut self, id: u32, x: f64, y: f64, time: f64) -> Vec<Gesture> {
+        let mut gestures = Vec::new();
+        let Some(mut point) = self.points.remove(&id) else {
+            return gestures;
+        };
+        point.x = x;
+        point.y = y;
+
+        if let Some(pinch) = self.pinch.filter(|pinch| pinch.contains(id)) {
+            self.pinch = None;
+            pinch.end(&mut gestures);
+
+            let other_id = if pinch.first == id {
+                pinch.second
+            } else {
+                pinch.first
+            };
+            let other = self.points[&other_id];
+            let is_tap = !pinch.is_transforming()
+                && time - pinch.start_time <= TAP_DURATION
+                && !point.has_moved()
+                && !other.has_moved();
+
+            if is_tap && pinch.pressed {
+                if id == pinch.second {
+                    gestures.push(Gesture::PressAndTap {
+                        x: other.x,
+                        y: other.y,
+                        tap_x: point.x,
+                        tap_y: point.y,
+                    });
+                }
+            } else if is_tap {
+                self.pending_tap = Some(PendingTap {
+                    id: other_id,
+                    x: pinch.last.x,
+                    y: pinch.last.y,
+                    start_time: pinch.start_time,
+                });
+            }
+        } else if let Some(tap) = self.pending_tap.take() {
+            if tap.id == id && time - tap.start_time <= TAP_DURATION && !point.has_moved() {
+                gestures.push(Gesture::TwoFingerTap { x: tap.x, y: tap.y });
+            }
+        } else if !point.multi && time - point.start_time <= SWIPE_DURATION {
+            let dx = point.x - point.start_x;
+            let dy = point.y - point.start_y;
+            let (offset_x, offset_y) = if dx.abs() >= SWIPE_DISTANCE && dx.abs() >= dy.abs() * 2.0 {
+                (dx.signum(), 0.0)
+            } else if dy.abs() >= SWIPE_DISTANCE && dy.abs() >= dx.abs() * 2.0 {
+                (0.0, dy.signum())
+            } else {
+                (0.0, 0.0)
+            };
+
+            if offset_x != 0.0 || offset_y != 0.0 {
+                gestures.push(Gesture::Transform {
+                    kind: TransformGesture::Swipe,
+                    phase: GesturePhase::All,
+                    x: point.start_x,
+                    y: point.start_y,
+                    scale: 1.0,
+                    rotation: 0.0,
+                    offset_x,
+                    offset_y,
+                });
+            }
+        }
+
+        gestures
+    }
+
+    pub fn cancel(&mut self, id: u32) -> Vec<Gesture> {
+        let mut gestures = Vec::new();
+        self.points.remove(&id);
+        if let Some(pinch) = self.pinch.filter(|pinch| pinch.contains(id)) {
+            self.pinch = None;
+            pinch.end(&mut gestures);
+        }
+        if self.pending_tap.is_some_and(|tap| tap.id == id) {
+            self.pending_tap = None;
+        }
+        gestures
+    }
+}
+
+/// The difference between two angles in degrees, between -180 and 180.
+fn angle_difference(from: f64, to: f64) -> f64 {
+    let difference = (to - from) % 360.0;
+    if difference > 180.0 {
+        difference - 360.0
+    } else if difference <= -180.0 {
+        difference + 360.0
+    } else {
+        difference
+    }
+}
+
+#[derive(Collect)]
+#[collect(no_drop)]
+struct TouchPoint<'gc> {
+    #[collect(require_static)]
+    position: Point<Twips>,
+
+    /// The object under the touch point.
+    target: Option<InteractiveObject<'gc>>,
+
+    /// The object the touch point began on, which is tapped if the touch point ends on it.
+    begin_target: Option<InteractiveObject<'gc>>,
+}
+
+/// The touch points on the screen, and the gestures they make.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct Touches<'gc> {
+    #[collect(require_static)]
+    input_mode: MultitouchInputMode,
+
+    /// How many touch points the screen supports, or 0 if it doesn't support touch input.
+    #[collect(require_static)]
+    max_touch_points: u32,
+
+    points: BTreeMap<u32, TouchPoint<'gc>>,
+
+    /// The touch point driving the mouse.
+    #[collect(require_static)]
+    primary: Option<u32>,
+
+    #[collect(require_static)]
+    recognizer: GestureRecognizer,
+
+    /// The objects targeted by the zoom, rotate and pan gestures in progress.
+    gesture_targets: [Option<InteractiveObject<'gc>>; 3],
+
+    /// The time since the player started, in milliseconds, used to tell taps and swipes
+    /// apart from slower movements.
+    #[collect(require_static)]
+    time: f64,
+}
+
+impl<'gc> Touches<'gc> {
+    pub fn new(max_touch_points: u32) -> Self {
+        Self {
+            input_mode: MultitouchInputMode::default(),
+            max_touch_points,
+            points: BTreeMap::new(),
+            primary: None,
+            recognizer: GestureRecognizer::new(),
+            gesture_targets: [None; 3],
+            time: 0.0,
+        }
+    }
+
+    pub fn input_mode(&self) -> MultitouchInputMode {
+        self.input_mode
+    }
+
+    pub fn set_input_mode(&mut self, input_mode: MultitouchInputMode) {
+        self.input_mode = input_mode;
+    }
+
+    pub fn max_touch_points(&self) -> u32 {
+        self.max_touch_points
+    }
+
+    pub fn update_touches(&mut self, dt: FloatDuration) {
+        self.time += dt.as_millis();
+    }
+
+    /// Handles a touch point changing, dispatching the resulting touch or gesture events.
+    ///
+    /// `position` is the position on the stage, and may be `None` when the touch point is
+    /// cancelled. Returns whether the touch point is the primary touch point, along with its
+    /// position, so that the caller can drive the mouse.
+    pub fn handle_touch(
+        context: &mut UpdateContext<'gc>,
+        id: u32,
+        phase: TouchPhase,
+        position: Option<Point<Twips>>,
+    ) -> Option<(bool, Point<Twips>)> {
+        let touches = &mut *context.touches;
+        let position = match (position, touches.points.get(&id)) {
+            (Some(position), _) => position,
+            (None, Some(point)) => point.position,
+            (None, None) => return None,
+        };
+
+        if phase == TouchPhase::Begin {
+            if touches.points.is_empty() {
+                touches.primary = Some(id);
+            }
+            touches.points.insert(
+                id,
+                TouchPoint {
+                    position,
+                    target: None,
+                    begin_target: None,
+                },
+            );
+        } else if !touches.points.contains_key(&id) {
+            // A touch point we never saw beginning.
+            return None;
+        }
+        let is_primary = touches.primary == Some(id);
+
+        let (x, y) = (position.x.to_pixels(), position.y.to_pixels());
+        let time = touches.time;
+        let gestures = match phase {
+            TouchPhase::Begin => touches.recognizer.begin(id, x, y, time),
+            TouchPhase::Move => touches.recognizer.update(id, x, y),
+            TouchPhase::End => touches.recognizer.end(id, x, y, time),
+            TouchPhase::Cancel => touches.recognizer.cancel(id),
+        };
+
+        match context.touches.input_mode {
+            MultitouchInputMode::TouchPoint => {
+                Self::dispatch_touch(context, id, phase, position, is_primary)
+            }
+            MultitouchInputMode::Gesture => {
+                for gesture in gestures {
+                    Self::dispatch_gesture(context, gesture);
+                }
+            }
+            MultitouchInputMode::None => {}
+        }
+
+        let touches = &mut *context.touches;
+        if let Some(point) = touches.points.get_mut(&id) {
+            point.position = position;
+        }
+        if matches!(phase, TouchPhase::End | TouchPhase::Cancel) {
+            touches.points.remove(&id);
+            if touches.points.is_empty() {
+                touches.primary = None;
+            }
+        }
+
+        Some((is_primary, position))
+    }
+
+    fn dispatch_touch(
+        context: &mut UpdateContext<'gc>,
+        id: u32,
+        phase: TouchPhase,
+        position: Point<Twips>,
+        is_primary: bool,
+    ) {
+        let target = run_pick(context, position, false);
+        let Some(point) = context.touches.points.get_mut(&id) else {
+            return;
+        };
+        let previous_target = std::mem::replace(&mut point.target, target);
+        let begin_target = point.begin_target;
+
+        let dispatch = |context: &mut UpdateContext<'gc>,
+                        event_type,
+                        target: Option<InteractiveObject<'gc>>,
+                        related_object,
+                        bubbles| {
+            Self::dispatch_touch_event(
+                context,
+                event_type,
+                target,
+                (id, is_primary),
+                position,
+                related_object,
+                bubbles,
+            )
+        };
+
+        match phase {
+            TouchPhase::Begin => {
+                if let Some(point) = context.touches.points.get_mut(&id) {
+                    point.begin_target = target;
+                }
+                dispatch(context, "touchBegin", target, None, true);
+            }
+            TouchPhase::Move => {
+                if previous_target != target {
+                    if previous_target.is_some() {
+                        dispatch(context, "touchOut", previous_target, target, true);
+                        dispatch(context, "touchRollOut", previous_target, target, false);
+                    }
+                    if target.is_some() {
+                        dispatch(context, "touchOver", target, previous_target, true);
+                        dispatch(context, "touchRollOver", target, previous_target, false);
+                    }
+                }
+                dispatch(context, "touchMove", target, None, true);
+            }
+            TouchPhase::End => {
+                dispatch(context, "touchEnd", target, None, true);
+                if target == begin_target {
+                    dispatch(context, "touchTap", target, None, true);
+                }
+            }
+            TouchPhase::Cancel => {
+                dispatch(context, "touchEnd", target, None, true);
+            }
+        }
+    }
+
+    /// Dispatches a touch event to `target`, or to the stage when the touch point isn't over
+    /// any object.
+    fn dispatch_touch_event(
+        context: &mut UpdateContext<'gc>,
+        event_type: &'static str,
+        target: Option<InteractiveObject<'gc>>,
+        touch_point: (u32, bool),
+        position: Point<Twips>,
+        related_object: Option<InteractiveObject<'gc>>,
+        bubbles: bool,
+    ) {
+        let target = target.map_or(context.stage.into(), |target| target.as_displayobject());
+        if !target.movie().is_action_script_3() {
+            return;
+        }
+        let Some(object) = target.object2() else {
+            return;
+        };
+
+        let mut activation = Avm2Activation::from_nothing(context);
+        let event = EventObject::touch_event(
+            &mut activation,
+            event_type,
+            target,
+            touch_point,
+            position,
+            related_object,
+            bubbles,
+        );
+        Avm2::dispatch_event(activation.context, event, object.into());
+    }
+
+    fn dispatch_gesture(context: &mut UpdateContext<'gc>, gesture: Gesture) {
+        let (x, y) = match gesture {
+            Gesture::Transform { x, y, .. }
+            | Gesture::TwoFingerTap { x, y }
+            | Gesture::PressAndTap { x, y, .. } => (x, y),
+        };
+
+        // A gesture in progress keeps the target it began on.
+        let slot = match gesture {
+            Gesture::Transform { kind, .. } => TransformGesture::CONTINUOUS
+                .iter()
+                .position(|continuous| *continuous == kind),
+            _ => None,
+        };
+        let phase = match gesture {
+            Gesture::Transform { phase, .. } => phase,
+            _ => GesturePhase::All,
+        };
+        let target = match (slot, phase) {
+            (Some(slot), GesturePhase::Update | GesturePhase::End) => {
+                context.touches.gesture_targets[slot]
+            }
+            _ => run_pick(context, Point::from_pixels(x, y), false),
+        };
+        if let Some(slot) = slot {
+            context.touches.gesture_targets[slot] = match phase {
+                GesturePhase::End => None,
+                _ => target,
+            };
+        }
+
+        let target = target.map_or(context.stage.into(), |target| target.as_displayobject());
+        if !target.movie().is_action_script_3() {
+            return;
+        }
+        let Some(object) = target.object2() else {
+            return;
+        };
+
+        let mut activation = Avm2Activation::from_nothing(context);
+        let event = EventObject::gesture_event(&mut activation, target, gesture);
+        Avm2::dispatch_event(activation.context, event, object.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(gestures: &[Gesture]) -> Vec<(TransformGesture, GesturePhase)> {
+        gestures
+            .iter()
+            .filter_map(|gesture| match *gesture {
+                Gesture::Transform { kind, phase, .. } => Some((kind, phase)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn swipe() {
+        let mut recognizer = GestureRecognizer::new();
+        assert!(recognizer.begin(0, 100.0, 100.0, 0.0).is_empty());
+        assert!(recognizer.update(0, 130.0, 105.0).is_empty());
+        let gestures = recognizer.end(0, 180.0, 110.0, 200.0);
+        assert_eq!(
+            gestures,
+            vec![Gesture::Transform {
+                kind: TransformGesture::Swipe,
+                phase: GesturePhase::All,
+                x: 100.0,
+                y: 100.0,
+                scale: 1.0,
+                rotation: 0.0,
+                offset_x: 1.0,
+                offset_y: 0.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn slow_or_short_movement_is_not_a_swipe() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.begin(0, 100.0, 100.0, 0.0);
+        assert!(recognizer.end(0, 100.0, 20.0, 1000.0).is_empty());
+
+        recognizer.begin(1, 100.0, 100.0, 0.0);
+        assert!(recognizer.end(1, 120.0, 100.0, 100.0).is_empty());
+    }
+
+    #[test]
+    fn zoom() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.begin(0, 100.0, 100.0, 0.0);
+        recognizer.begin(1, 200.0, 100.0, 0.0);
+
+        // Too small to begin zooming.
+        assert!(recognizer.update(1, 205.0, 100.0).is_empty());
+
+        let gestures = recognizer.update(1, 250.0, 100.0);
+        assert_eq!(
+            kinds(&gestures),
+            vec![
+                (TransformGesture::Zoom, GesturePhase::Begin),
+                (TransformGesture::Pan, GesturePhase::Begin)
+            ]
+        );
+        let Gesture::Transform { scale, .. } = gestures[0] else {
+            unreachable!();
+        };
+        assert_eq!(scale, 1.5);
+
+        let gestures = recognizer.update(1, 400.0, 100.0);
+        let Gesture::Transform { scale, .. } = gestures[0] else {
+            unreachable!();
+        };
+        assert_eq!(scale, 2.0);
+
+        let gestures = recognizer.end(0, 100.0, 100.0, 500.0);
+        assert_eq!(
+            kinds(&gestures),
+            vec![
+                (TransformGesture::Zoom, GesturePhase::End),
+                (TransformGesture::Pan, GesturePhase::End)
+            ]
+        );
+    }
+
+    #[test]
+    fn rotate() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.begin(0, 100.0, 100.0, 0.0);
+        recognizer.begin(1, 200.0, 100.0, 0.0);
+
+        // Turn the second finger around the first one by 45 degrees, clockwise on screen.
+        let distance = 100.0 / 2f64.sqrt();
+        let gestures = recognizer.update(1, 100.0 + distance, 100.0 + distance);
+        let rotations: Vec<_> = gestures
+            .iter()
+            .filter_map(|gesture| match *gesture {
+                Gesture::Transform {
+                    kind: TransformGesture::Rotate,
+                    phase,
+                    rotation,
+                    ..
+                } => Some((phase, rotation)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(rotations.len(), 1);
+        assert_eq!(rotations[0].0, GesturePhase::Begin);
+        assert!((rotations[0].1 - 45.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pan() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.begin(0, 100.0, 100.0, 0.0);
+        recognizer.begin(1, 200.0, 100.0, 0.0);
+
+        // Move both fingers down, one after the other.
+        assert!(recognizer.update(0, 100.0, 106.0).is_empty());
+        assert!(recognizer.update(1, 200.0, 106.0).is_empty());
+        assert!(recognizer.update(0, 100.0, 112.0).is_empty());
+        let gestures = recognizer.update(1, 200.0, 112.0);
+        assert_eq!(
+            gestures,
+            vec![Gesture::Transform {
+                kind: TransformGesture::Pan,
+                phase: GesturePhase::Begin,
+                x: 150.0,
+                y: 112.0,
+                scale: 1.0,
+                rotation: 0.0,
+                offset_x: 0.0,
+                offset_y: 12.0,
+            }]
+        );
+
+        let gestures = recognizer.update(0, 100.0, 122.0);
+        assert_eq!(
+            kinds(&gestures),
+            vec![(TransformGesture::Pan, GesturePhase::Update)]
+        );
+        let Gesture::Transform { offset_y, .. } = gestures[0] else {
+            unreachable!();
+        };
+        assert_eq!(offset_y, 5.0);
+    }
+
+    #[test]
+    fn two_finger_tap() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.begin(0, 100.0, 100.0, 0.0);
+        recognizer.begin(1, 200.0, 100.0, 50.0);
+        assert!(recognizer.end(0, 100.0, 100.0, 150.0).is_empty());
+        assert_eq!(
+            recognizer.end(1, 200.0, 100.0, 200.0),
+            vec![Gesture::TwoFingerTap { x: 150.0, y: 100.0 }]
+        );
+    }
+
+    #[test]
+    fn press_and_tap() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.begin(0, 100.0, 100.0, 0.0);
+        recognizer.begin(1, 200.0, 100.0, 1000.0);
+        assert_eq!(
+            recognizer.end(1, 200.0, 100.0, 1100.0),
+            vec![Gesture::PressAndTap {
+                x: 100.0,
+                y: 100.0,
+                tap_x: 200.0,
+                tap_y: 100.0,
+            }]
+        );
+
+        // The held finger doesn't swipe when it's lifted.
+        assert!(recognizer.end(0, 300.0, 100.0, 1200.0).is_empty());
+    }
+
+    #[test]
+    fn cancel_ends_gestures() {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.begin(0, 100.0, 100.0, 0.0);
+        recognizer.begin(1, 200.0, 100.0, 0.0);
+        recognizer.update(1, 300.0, 100.0);
+        assert_eq!(
+            kinds(&recognizer.cancel(0)),
+            vec![
+                (TransformGesture::Zoom, GesturePhase::End),
+                (TransformGesture::Pan, GesturePhase::End)
+            ]
+        );
+        assert!(recognizer.end(1, 300.0, 100.0, 100.0).is_empty());
+    }
+
+    #[test]
+    fn angle_difference_wraps() {
+        assert_eq!(angle_difference(170.0, -170.0), 20.0);
+        assert_eq!(angle_difference(-170.0, 170.0), -20.0);
+        assert_eq!(angle_difference(10.0, 40.0), 30.0);
+    }
+}
@@ -0,0 +1,861 @@
+//! Background workers and the primitives they share.
+//!
+//! Every background worker (`flash.system.Worker`) is a separate headless `Player`, with its own
+//! GC arena and AVM2, running the SWF it was created from on its own thread. Everything that more
+//! than one worker can see - the state and shared properties of a worker, `MessageChannel` queues,
+//! `Mutex` and `Condition` - lives outside of the GC arenas, behind the thread-safe handles in this
+//! module.
+
+use crate::avm2::Avm2;
+use crate::avm2::bytearray::SharedByteArray;
+use crate::avm2::object::{EventObject, MessageChannelObject, WorkerObject};
+use crate::backend::log::LogBackend;
+use crate::context::UpdateContext;
+use crate::player::{PlayerBuilder, PlayerRuntime};
+use crate::tag_utils::SwfMovie;
+use gc_arena::Collect;
+use ruffle_common::duration::FloatDuration;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use web_time::Instant;
+
+/// Whether this platform can run background workers.
+pub const WORKERS_SUPPORTED: bool = cfg!(not(target_family = "wasm"));
+
+static NEXT_WORKER_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Locks a mutex shared between workers.
+///
+/// A worker that panicked while holding the lock never leaves the data in a
+/// state that the others can't use, so poisoning is ignored.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn wait<'a, T>(condvar: &Condvar, guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+    condvar.wait(guard).unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Waits for the condition variable to be signalled, unless the deadline has
+/// already passed.
+///
+/// Returns whether the deadline had passed.
+fn wait_until<'a, T>(
+    condvar: &Condvar,
+    guard: MutexGuard<'a, T>,
+    deadline: Option<Instant>,
+) -> (MutexGuard<'a, T>, bool) {
+    let Some(deadline) = deadline else {
+        return (wait(condvar, guard), false);
+    };
+
+    let now = Instant::now();
+    if now >= deadline {
+        return (guard, true);
+    }
+
+    let guard = condvar
+        .wait_timeout(guard, deadline - now)
+        .unwrap_or_else(PoisonError::into_inner)
+        .0;
+    (guard, false)
+}
+
+/// Why a worker stopped waiting for a `Condition`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaitError {
+    /// The worker doesn't own the mutex of the condition.
+    NotOwner,
+
+    /// The deadline of the worker passed before it was notified and owned the
+    /// mutex again, so it doesn't own the mutex anymore.
+    DeadlinePassed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkerState {
+    New,
+    Running,
+    Terminated,
+}
+
+impl WorkerState {
+    /// The `flash.system.WorkerState` constant for this state.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WorkerState::New => "new",
+            WorkerState::Running => "running",
+            WorkerState::Terminated => "terminated",
+        }
+    }
+}
+
+/// A value handed from one worker to another, either as a shared property
+/// or through a `MessageChannel`.
+#[derive(Clone)]
+pub enum SharedValue {
+    /// Any other value, copied using AMF3.
+    Serialized(Vec<u8>),
+
+    /// A shareable `ByteArray`, whose bytes are shared rather than copied.
+    ByteArray(SharedByteArray),
+
+    Worker(WorkerHandle),
+    MessageChannel(MessageChannelHandle),
+    Mutex(MutexHandle),
+    Condition(ConditionHandle),
+}
+
+/// A worker, as seen from any worker.
+#[derive(Clone)]
+pub struct WorkerHandle(Arc<WorkerData>);
+
+struct WorkerData {
+    id: u32,
+    is_primordial: bool,
+    state: Mutex<WorkerState>,
+    shared_properties: Mutex<HashMap<String, SharedValue>>,
+
+    /// The movie a background worker runs, until it's started.
+    movie: Mutex<Option<SwfMovie>>,
+}
+
+impl WorkerHandle {
+    fn new(is_primordial: bool, state: WorkerState, movie: Option<SwfMovie>) -> Self {
+        Self(Arc::new(WorkerData {
+            id: NEXT_WORKER_ID.fetch_add(1, Ordering::Relaxed),
+            is_primordial,
+            state: Mutex::new(state),
+            shared_properties: Mutex::new(HashMap::new()),
+            movie: Mutex::new(movie),
+        }))
+    }
+
+    /// Creates a background worker that will run the given movie once started.
+    pub fn background(movie: SwfMovie) -> Self {
+        Self::new(false, WorkerState::New, Some(movie))
+    }
+
+    fn id(&self) -> u32 {
+        self.0.id
+    }
+
+    pub fn is_primordial(&self) -> bool {
+        self.0.is_primordial
+    }
+
+    pub fn state(&self) -> WorkerState {
+        *lock(&self.0.state)
+    }
+
+    fn set_state(&self, state: WorkerState) {
+        *lock(&self.0.state) = state;
+    }
+
+    pub fn shared_property(&self, key: &str) -> Option<SharedValue> {
+        lock(&self.0.shared_properties).get(key).cloned()
+    }
+
+    pub fn set_shared_property(&self, key: String, value: Option<SharedValue>) {
+        let mut shared_properties = lock(&self.0.shared_properties);
+        match value {
+            Some(value) => shared_properties.insert(key, value),
+            None => shared_properties.remove(&key),
+        };
+    }
+
+    /// Stops a background worker after its current frame.
+    ///
+    /// Returns whether the worker was running.
+    pub fn terminate(&self) -> bool {
+        if self.is_primordial() {
+            return false;
+        }
+
+        let mut state = lock(&self.0.state);
+        let was_running = *state == WorkerState::Running;
+        *state = WorkerState::Terminated;
+        lock(&self.0.movie).take();
+        was_running
+    }
+}
+
+impl PartialEq for WorkerHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageChannelState {
+    Open,
+    Closing,
+    Closed,
+}
+
+impl MessageChannelState {
+    /// The `flash.system.MessageChannelState` constant for this state.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MessageChannelState::Open => "open",
+            MessageChannelState::Closing => "closing",
+            MessageChannelState::Closed => "closed",
+        }
+    }
+}
+
+/// A one-way queue of messages from one worker to another.
+#[derive(Clone)]
+pub struct MessageChannelHandle(Arc<MessageChannelData>);
+
+struct MessageChannelData {
+    sender: u32,
+    receiver: u32,
+    queue: Mutex<MessageQueue>,
+
+    /// Signalled whenever a message is sent or received, or the channel closes.
+    changed: Condvar,
+}
+
+struct MessageQueue {
+    state: MessageChannelState,
+    messages: VecDeque<SharedValue>,
+
+    /// How many messages were ever sent through the channel.
+    sent: usize,
+}
+
+impl MessageChannelHandle {
+    pub fn new(sender: &WorkerHandle, receiver: &WorkerHandle) -> Self {
+        Self(Arc::new(MessageChannelData {
+            sender: sender.id(),
+            receiver: receiver.id(),
+            queue: Mutex::new(MessageQueue {
+                state: MessageChannelState::Open,
+                messages: VecDeque::new(),
+                sent: 0,
+            }),
+            changed: Condvar::new(),
+        }))
+    }
+
+    pub fn is_receiver(&self, worker: &WorkerHandle) -> bool {
+        self.0.receiver == worker.id()
+    }
+
+    pub fn state(&self) -> MessageChannelState {
+        lock(&self.0.queue).state
+    }
+
+    pub fn message_available(&self) -> bool {
+        !lock(&self.0.queue).messages.is_empty()
+    }
+
+    /// How many messages were ever sent through the channel.
+    pub fn sent_count(&self) -> usize {
+        lock(&self.0.queue).sent
+    }
+
+    /// How many messages were sent and already received.
+    pub fn received_count(&self) -> usize {
+        let queue = lock(&self.0.queue);
+        queue.sent - queue.messages.len()
+    }
+
+    /// Queues a message, first waiting for the queue to shrink below
+    /// `queue_limit` if one is given.
+    ///
+    /// Returns `false` if the channel is no longer open.
+    pub fn send(&self, value: SharedValue, queue_limit: Option<usize>) -> bool {
+        let mut queue = lock(&self.0.queue);
+        if let Some(queue_limit) = queue_limit.filter(|_| self.0.sender != self.0.receiver) {
+            while queue.state == MessageChannelState::Open && queue.messages.len() >= queue_limit {
+                queue = wait(&self.0.changed, queue);
+            }
+        }
+
+        if queue.state != MessageChannelState::Open {
+            return false;
+        }
+
+        queue.messages.push_back(value);
+        queue.sent += 1;
+        self.0.changed.notify_all();
+        true
+    }
+
+    /// Takes the oldest message, optionally waiting for one to be sent.
+    pub fn receive(&self, block: bool) -> Option<SharedValue> {
+        let mut queue = lock(&self.0.queue);
+        loop {
+            if let Some(value) = queue.messages.pop_front() {
+                if queue.state == MessageChannelState::Closing && queue.messages.is_empty() {
+                    queue.state = MessageChannelState::Closed;
+                }
+                self.0.changed.notify_all();
+                return Some(value);
+            }
+
+            if !block || queue.state != MessageChannelState::Open {
+                return None;
+            }
+
+            queue = wait(&self.0.changed, queue);
+        }
+    }
+
+    /// Closes the channel. Messages that were already sent can still be received.
+    pub fn close(&self) {
+        let mut queue = lock(&self.0.queue);
+        if queue.state == MessageChannelState::Open {
+            queue.state = if queue.messages.is_empty() {
+                MessageChannelState::Closed
+            } else {
+                MessageChannelState::Closing
+            };
+            self.0.changed.notify_all();
+        }
+    }
+}
+
+/// A reentrant lock owned by at most one worker at a time.
+#[derive(Clone)]
+pub struct MutexHandle(Arc<MutexData>);
+
+struct MutexData {
+    owner: Mutex<MutexOwner>,
+
+    /// Signalled whenever the mutex is released.
+    released: Condvar,
+}
+
+#[derive(Default)]
+struct MutexOwner {
+    worker: Option<u32>,
+
+    /// How many times the owner locked the mutex.
+    count: u32,
+}
+
+impl MutexHandle {
+    pub fn new() -> Self {
+        Self(Arc::new(MutexData {
+            owner: Mutex::new(MutexOwner::default()),
+            released: Condvar::new(),
+        }))
+    }
+
+    /// Takes ownership of the mutex, waiting for any other worker that owns it,
+    /// but no longer than until the deadline.
+    ///
+    /// Returns `false` if the deadline passed first.
+    pub fn lock(&self, worker: &WorkerHandle, deadline: Option<Instant>) -> bool {
+        let mut owner = lock(&self.0.owner);
+        while owner.worker.is_some_and(|id| id != worker.id()) {
+            let (guard, deadline_passed) = wait_until(&self.0.released, owner, deadline);
+            if deadline_passed {
+                return false;
+            }
+            owner = guard;
+        }
+        owner.worker = Some(worker.id());
+        owner.count += 1;
+        true
+    }
+
+    /// Takes ownership of the mutex if no other worker owns it.
+    pub fn try_lock(&self, worker: &WorkerHandle) -> bool {
+        let mut owner = lock(&self.0.owner);
+        if owner.worker.is_some_and(|id| id != worker.id()) {
+            return false;
+        }
+        owner.worker = Some(worker.id());
+        owner.count += 1;
+        true
+    }
+
+    /// Releases one lock of the mutex.
+    ///
+    /// Returns `false` if the worker doesn't own the mutex.
+    pub fn unlock(&self, worker: &WorkerHandle) -> bool {
+        let mut owner = lock(&self.0.owner);
+        if owner.worker != Some(worker.id()) {
+            return false;
+        }
+        owner.count -= 1;
+        if owner.count == 0 {
+            owner.worker = None;
+            self.0.released.notify_all();
+        }
+        true
+    }
+}
+
+impl Default for MutexHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A condition variable, used together with the mutex it was created for.
+#[derive(Clone)]
+pub struct ConditionHandle(Arc<ConditionData>);
+
+struct ConditionData {
+    mutex: MutexHandle,
+
+    /// Signalled whenever a waiting worker is notified.
+    signal: Condvar,
+
+    /// How many workers are waiting and haven't been notified yet.
+    ///
+    /// This and `notified` are only accessed while holding the lock of `mutex`.
+    waiting: AtomicUsize,
+
+    /// How many notifications haven't been picked up by a waiting worker yet.
+    notified: AtomicUsize,
+}
+
+impl ConditionHandle {
+    pub fn new(mutex: MutexHandle) -> Self {
+        Self(Arc::new(ConditionData {
+            mutex,
+            signal: Condvar::new(),
+            waiting: AtomicUsize::new(0),
+            notified: AtomicUsize::new(0),
+        }))
+    }
+
+    pub fn mutex(&self) -> &MutexHandle {
+        &self.0.mutex
+    }
+
+    /// Releases the mutex and waits until notified, or until the timeout
+    /// passed. The mutex is then owned again, as many times as before.
+    ///
+    /// Waiting never goes on past the deadline, even if no timeout is given.
+    ///
+    /// Returns whether the worker was notified.
+    pub fn wait(
+        &self,
+        worker: &WorkerHandle,
+        timeout: Option<Duration>,
+        deadline: Option<Instant>,
+    ) -> Result<bool, WaitError> {
+        let mutex = &self.0.mutex.0;
+        let mut owner = lock(&mutex.owner);
+        if owner.worker != Some(worker.id()) {
+            return Err(WaitError::NotOwner);
+        }
+
+        let count = std::mem::take(&mut owner.count);
+        owner.worker = None;
+        mutex.released.notify_all();
+
+        self.0.waiting.fetch_add(1, Ordering::Relaxed);
+        let timeout = timeout.map(|timeout| Instant::now() + timeout);
+        let notified = loop {
+            if self.0.notified.load(Ordering::Relaxed) > 0 {
+                self.0.notified.fetch_sub(1, Ordering::Relaxed);
+                break true;
+            }
+
+            let now = Instant::now();
+            if deadline.is_some_and(|deadline| now >= deadline) {
+                self.0.waiting.fetch_sub(1, Ordering::Relaxed);
+                return Err(WaitError::DeadlinePassed);
+            }
+            if timeout.is_some_and(|timeout| now >= timeout) {
+                self.0.waiting.fetch_sub(1, Ordering::Relaxed);
+                break false;
+            }
+
+            let until = match (timeout, deadline) {
+                (Some(timeout), Some(deadline)) => Some(timeout.min(deadline)),
+                (timeout, deadline) => timeout.or(deadline),
+            };
+            owner = wait_until(&self.0.signal, owner, until).0;
+        };
+
+        while owner.worker.is_some() {
+            let (guard, deadline_passed) = wait_until(&mutex.released, owner, deadline);
+            if deadline_passed {
+                return Err(WaitError::DeadlinePassed);
+            }
+            owner = guard;
+        }
+        owner.worker = Some(worker.id());
+        owner.count = count;
+
+        Ok(notified)
+    }
+
+    /// Wakes up one waiting worker, or all of them.
+    ///
+    /// Returns `false` if the worker doesn't own the mutex.
+    pub fn notify(&self, worker: &WorkerHandle, all: bool) -> bool {
+        let owner = lock(&self.0.mutex.0.owner);
+        if owner.worker != Some(worker.id()) {
+            return false;
+        }
+
+        let waiting = self.0.waiting.load(Ordering::Relaxed);
+        let woken = if all { waiting } else { waiting.min(1) };
+        if woken > 0 {
+            self.0.waiting.fetch_sub(woken, Ordering::Relaxed);
+            self.0.notified.fetch_add(woken, Ordering::Relaxed);
+            self.0.signal.notify_all();
+        }
+        true
+    }
+}
+
+enum LogMessage {
+    Trace(String),
+    Warning(String),
+}
+
+/// Forwards the log output of a background worker to the primordial worker.
+struct WorkerLogBackend(Sender<LogMessage>);
+
+impl LogBackend for WorkerLogBackend {
+    fn avm_trace(&self, message: &str) {
+        let _ = self.0.send(LogMessage::Trace(message.to_string()));
+    }
+
+    fn avm_warning(&self, message: &str) {
+        let _ = self.0.send(LogMessage::Warning(message.to_string()));
+    }
+}
+
+/// The worker that a background worker's `Player` runs as.
+pub struct WorkerIdentity {
+    handle: WorkerHandle,
+    log: Sender<LogMessage>,
+}
+
+/// Workers started by a player, terminated along with it.
+#[derive(Default)]
+struct StartedWorkers(Vec<WorkerHandle>);
+
+impl Drop for StartedWorkers {
+    fn drop(&mut self) {
+        for worker in &self.0 {
+            worker.terminate();
+        }
+    }
+}
+
+/// The worker a player runs as, and the objects through which it sees other workers.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct Workers<'gc> {
+    #[collect(require_static)]
+    current: WorkerHandle,
+
+    /// The `Worker.current` object.
+    current_object: Option<WorkerObject<'gc>>,
+
+    /// `Worker` objects that may still change state.
+    workers: Vec<WorkerObject<'gc>>,
+
+    /// `MessageChannel` objects that may still receive messages or change state.
+    channels: Vec<MessageChannelObject<'gc>>,
+
+    #[collect(require_static)]
+    log: Sender<LogMessage>,
+
+    /// Log output of all background workers, only read by the primordial worker.
+    #[collect(require_static)]
+    log_receiver: Option<Receiver<LogMessage>>,
+
+    #[collect(require_static)]
+    started: StartedWorkers,
+}
+
+impl<'gc> Workers<'gc> {
+    pub fn new(identity: Option<WorkerIdentity>) -> Self {
+        let (current, log, log_receiver) = match identity {
+            Some(identity) => (identity.handle, identity.log, None),
+            None => {
+                let (log, log_receiver) = channel();
+                (
+                    WorkerHandle::new(true, WorkerState::Running, None),
+                    log,
+                    Some(log_receiver),
+                )
+            }
+        };
+
+        Self {
+            current,
+            current_object: None,
+            workers: Vec::new(),
+            channels: Vec::new(),
+            log,
+            log_receiver,
+            started: StartedWorkers::default(),
+        }
+    }
+
+    /// The worker this player runs as.
+    pub fn current(&self) -> &WorkerHandle {
+        &self.current
+    }
+
+    /// The time by which `Mutex.lock` and `Condition.wait` have to give up.
+    ///
+    /// Nothing is rendered while the primordial worker is blocked, so it can't block for longer
+    /// than the script timeout, after which Flash Player terminates the script. Background
+    /// workers may block forever.
+    pub fn blocking_deadline(context: &UpdateContext<'gc>) -> Option<Instant> {
+        context
+            .workers
+            .current
+            .is_primordial()
+            .then(|| context.update_start + context.max_execution_duration)
+    }
+
+    pub fn current_object(&self) -> Option<WorkerObject<'gc>> {
+        self.current_object
+    }
+
+    pub fn set_current_object(&mut self, object: WorkerObject<'gc>) {
+        self.current_object = Some(object);
+        self.track_worker(object);
+    }
+
+    /// Dispatches `workerState` events to the given object from now on.
+    pub fn track_worker(&mut self, object: WorkerObject<'gc>) {
+        if object.seen_state() != WorkerState::Terminated {
+            self.workers.push(object);
+        }
+    }
+
+    /// Dispatches `channelMessage` and `channelState` events to the given object from now on.
+    pub fn track_channel(&mut self, object: MessageChannelObject<'gc>) {
+        if object.seen_state() != MessageChannelState::Closed {
+            self.channels.push(object);
+        }
+    }
+
+    /// Starts running a background worker on its own thread.
+    pub fn start(&mut self, worker: &WorkerHandle, player_version: u8, runtime: PlayerRuntime) {
+        let Some(movie) = lock(&worker.0.movie).take() else {
+            return;
+        };
+
+        let identity = WorkerIdentity {
+            handle: worker.clone(),
+            log: self.log.clone(),
+        };
+        let result = std::thread::Builder::new()
+            .name("Worker".to_string())
+            .spawn(move || run_worker(movie, identity, player_version, runtime));
+
+        match result {
+            Ok(_) => self.started.0.push(worker.clone()),
+            Err(e) => {
+                tracing::error!("Couldn't start worker: {e}");
+                worker.set_state(WorkerState::Terminated);
+            }
+        }
+    }
+
+    /// Forwards the log output of background workers, and dispatches events
+    /// for anything other workers did since the last update.
+    pub fn update_workers(context: &mut UpdateContext<'gc>) {
+        if let Some(log_receiver) = &context.workers.log_receiver {
+            for message in log_receiver.try_iter() {
+                match message {
+                    LogMessage::Trace(message) => context.log.avm_trace(&message),
+                    LogMessage::Warning(message) => context.log.avm_warning(&message),
+                }
+            }
+        }
+
+        for worker in context.workers.workers.clone() {
+            if worker.observe_state() {
+                let event = EventObject::bare_default_event(context, "workerState");
+                Avm2::dispatch_event(context, event, worker.into());
+            }
+        }
+        context
+            .workers
+            .workers
+            .retain(|worker| worker.seen_state() != WorkerState::Terminated);
+
+        let current = context.workers.current.clone();
+        for channel in context.workers.channels.clone() {
+            if channel.handle().is_receiver(&current) {
+                while channel.observe_message() {
+                    let event = EventObject::bare_default_event(context, "channelMessage");
+                    Avm2::dispatch_event(context, event, channel.into());
+                }
+            }
+
+            if channel.observe_state() {
+                let event = EventObject::bare_default_event(context, "channelState");
+                Avm2::dispatch_event(context, event, channel.into());
+            }
+        }
+        context
+            .workers
+            .channels
+            .retain(|channel| channel.seen_state() != MessageChannelState::Closed);
+    }
+}
+
+fn run_worker(
+    movie: SwfMovie,
+    identity: WorkerIdentity,
+    player_version: u8,
+    runtime: PlayerRuntime,
+) {
+    let worker = identity.handle.clone();
+    let player = PlayerBuilder::new()
+        .with_movie(movie)
+        .with_autoplay(true)
+        .with_log(WorkerLogBackend(identity.log.clone()))
+        .with_player_version(Some(player_version))
+        .with_player_runtime(runtime)
+        .with_worker(identity)
+        .build();
+
+    {
+        let mut state = lock(&worker.0.state);
+        if *state != WorkerState::New {
+            return;
+        }
+        *state = WorkerState::Running;
+    }
+
+    let mut last_tick = Instant::now();
+    while worker.state() == WorkerState::Running {
+        let mut player = lock(&player);
+        let now = Instant::now();
+        player.tick(FloatDuration::from_secs((now - last_tick).as_secs_f64()));
+        last_tick = now;
+        let time_til_next_frame = player.time_til_next_frame();
+        drop(player);
+
+        std::thread::sleep(time_til_next_frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker() -> WorkerHandle {
+        WorkerHandle::new(false, WorkerState::Running, None)
+    }
+
+    fn message(value: u8) -> SharedValue {
+        SharedValue::Serialized(vec![value])
+    }
+
+    fn received(channel: &MessageChannelHandle) -> Option<Vec<u8>> {
+        match channel.receive(false)? {
+            SharedValue::Serialized(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn channel_delivers_messages_in_order() {
+        let channel = MessageChannelHandle::new(&worker(), &worker());
+        assert!(channel.send(message(1), None));
+        assert!(channel.send(message(2), None));
+        assert_eq!(channel.sent_count(), 2);
+        assert_eq!(received(&channel), Some(vec![1]));
+        assert_eq!(received(&channel), Some(vec![2]));
+        assert_eq!(received(&channel), None);
+        assert_eq!(channel.received_count(), 2);
+    }
+
+    #[test]
+    fn closed_channel_keeps_queued_messages() {
+        let channel = MessageChannelHandle::new(&worker(), &worker());
+        assert!(channel.send(message(1), None));
+        channel.close();
+        assert_eq!(channel.state(), MessageChannelState::Closing);
+        assert!(!channel.send(message(2), None));
+        assert_eq!(received(&channel), Some(vec![1]));
+        assert_eq!(channel.state(), MessageChannelState::Closed);
+    }
+
+    #[test]
+    fn blocking_receive_waits_for_other_worker() {
+        let channel = MessageChannelHandle::new(&worker(), &worker());
+        let sender = channel.clone();
+        let thread = std::thread::spawn(move || sender.send(message(1), None));
+        assert!(
+            matches!(channel.receive(true), Some(SharedValue::Serialized(bytes)) if bytes == [1])
+        );
+        assert!(thread.join().unwrap());
+    }
+
+    #[test]
+    fn mutex_is_reentrant_and_exclusive() {
+        let (first, second) = (worker(), worker());
+        let mutex = MutexHandle::new();
+        assert!(mutex.lock(&first, None));
+        assert!(mutex.try_lock(&first));
+        assert!(!mutex.try_lock(&second));
+        assert!(!mutex.unlock(&second));
+        assert!(mutex.unlock(&first));
+        assert!(!mutex.try_lock(&second));
+        assert!(mutex.unlock(&first));
+        assert!(mutex.try_lock(&second));
+    }
+
+    #[test]
+    fn condition_wakes_waiting_worker() {
+        let (first, second) = (worker(), worker());
+        let condition = ConditionHandle::new(MutexHandle::new());
+        assert_eq!(condition.wait(&first, None, None), Err(WaitError::NotOwner));
+
+        assert!(condition.mutex().lock(&first, None));
+        let notifier = condition.clone();
+        let thread = std::thread::spawn(move || {
+            assert!(notifier.mutex().lock(&second, None));
+            let notified = notifier.notify(&second, false);
+            notifier.mutex().unlock(&second);
+            notified
+        });
+        assert_eq!(condition.wait(&first, None, None), Ok(true));
+        assert!(thread.join().unwrap());
+        assert!(condition.mutex().unlock(&first));
+    }
+
+    #[test]
+    fn condition_wait_times_out() {
+        let first = worker();
+        let condition = ConditionHandle::new(MutexHandle::new());
+        assert!(condition.mutex().lock(&first, None));
+        let timeout = Some(Duration::from_millis(10));
+        assert_eq!(condition.wait(&first, timeout, None), Ok(false));
+        assert!(condition.mutex().unlock(&first));
+    }
+
+    #[test]
+    fn blocking_gives_up_at_deadline() {
+        let (first, second) = (worker(), worker());
+        let condition = ConditionHandle::new(MutexHandle::new());
+        assert!(condition.mutex().lock(&first, None));
+
+        let deadline = Some(Instant::now() + Duration::from_millis(10));
+        assert!(!condition.mutex().lock(&second, deadline));
+
+        let deadline = Some(Instant::now() + Duration::from_millis(10));
+        assert_eq!(
+            condition.wait(&first, None, deadline),
+            Err(WaitError::DeadlinePassed)
+        );
+        assert!(!condition.mutex().unlock(&first));
+    }
+}
@@ -4,6 +4,7 @@ file-picker-filter-supported = All Supported Files
 file-picker-filter-swf = SWF (*.swf)
 file-picker-filter-spl = FutureSplash Animator (*.spl)
 file-picker-filter-ruf = Ruffle Bundle (*.ruf)
+file-picker-filter-air = AIR Application (*.air)
 file-picker-filter-all = All Files
 
 file-picker-select-root-movie-title = Select Root Movie
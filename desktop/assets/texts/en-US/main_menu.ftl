@@ -15,6 +15,7 @@ file-menu-close = Close
 file-menu-reload = Reload
 file-menu-recents = Recents
 file-menu-recents-empty = No recent entries
+file-menu-shared-objects = Saved Data...
 file-menu-preferences = Preferences...
 file-menu-exit = Exit
 file-menu-export = Export...
@@ -43,6 +44,7 @@ debug-menu-open-movie = View Movie
 debug-menu-open-movie-list = Show Known Movies
 debug-menu-open-domain-list = Show Domains
 debug-menu-search-display-objects = Search Display Objects...
+debug-menu-open-avm1-debugger = AVM1 Debugger...
 
 view-menu = View
 view-menu-fullscreen = Full Screen
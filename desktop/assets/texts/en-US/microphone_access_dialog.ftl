@@ -0,0 +1,6 @@
+microphone-access-dialog-title = Requesting Microphone Access
+
+microphone-access-dialog-message = The current movie is attempting to capture audio from your microphone. Do you want to allow it?
+
+microphone-access-dialog-allow = Allow
+microphone-access-dialog-deny = Deny
@@ -0,0 +1,7 @@
+native-process-dialog-title = Requesting to Run a Program
+
+native-process-dialog-message = The current movie is attempting to run the following program. Do you want to allow it?
+native-process-dialog-allow-remember-message = Do not ask again for this program for the duration of this session
+
+native-process-dialog-allow = Allow
+native-process-dialog-allow-remember = Allow and Remember
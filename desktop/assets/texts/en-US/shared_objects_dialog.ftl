@@ -0,0 +1,29 @@
+shared-objects-dialog = Saved Data
+shared-objects-dialog-domain = Domain
+shared-objects-dialog-name = Name
+shared-objects-dialog-size = Size (bytes)
+shared-objects-dialog-refresh = Refresh
+shared-objects-dialog-only-current-domain = Only show saved data of { $domain }
+shared-objects-dialog-no-entries = There is no saved data
+shared-objects-dialog-not-selected = Nothing to show
+shared-objects-dialog-unreadable = This saved data can't be read.
+shared-objects-dialog-memory-storage = Saved data is currently kept in memory and won't be listed here, change the storage backend in the preferences to store it on disk.
+shared-objects-dialog-running-movie-warning = A running movie may overwrite changes to its saved data, close or reload it after editing.
+
+shared-objects-dialog-revert = Revert
+shared-objects-dialog-import = Import...
+shared-objects-dialog-import-new = Import New...
+shared-objects-dialog-import-new-no-movie = Open a movie to import saved data for it
+shared-objects-dialog-export = Export...
+shared-objects-dialog-delete = Delete
+shared-objects-dialog-confirm-delete = Delete this saved data permanently?
+shared-objects-dialog-filter-sol = Flash Local Shared Objects
+
+shared-objects-dialog-saved = Changes saved.
+shared-objects-dialog-deleted = Saved data deleted.
+shared-objects-dialog-imported = Saved data imported.
+shared-objects-dialog-exported = Saved data exported.
+shared-objects-dialog-invalid-file = The file is not valid saved data.
+shared-objects-dialog-already-exists = The movie already has saved data with this name.
+shared-objects-dialog-encoding-failed = The edited saved data couldn't be encoded.
+shared-objects-dialog-io-error = The file couldn't be accessed: { $error }
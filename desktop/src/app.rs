@@ -1,16 +1,18 @@
 use crate::custom_event::{OpenType, RuffleEvent};
 use crate::gui::{GuiController, MENU_HEIGHT};
+use crate::native_window::NativeWindows;
 use crate::player::{LaunchOptions, PlayerController};
 use crate::preferences::GlobalPreferences;
 use crate::util::{
     get_screen_size, gilrs_button_to_gamepad_button, plot_stats_in_tracy,
-    winit_input_to_ruffle_key_descriptor, winit_to_ruffle_text_control,
+    winit_to_ruffle_key_events, winit_to_ruffle_mouse_button,
 };
 use anyhow::Error;
 use gilrs::{Event, EventType, Gilrs};
 use ruffle_core::FloatDuration;
 use ruffle_core::PlayerEvent;
-use ruffle_core::events::{ImeEvent, ImeNotification, PlayerNotification};
+use ruffle_core::backend::ui::NativeWindowId;
+use ruffle_core::events::{ImeEvent, ImeNotification, NativeWindowEvent, PlayerNotification};
 use ruffle_core::swf::HeaderExt;
 use ruffle_frontend_utils::content::ContentDescriptor;
 use ruffle_render::backend::ViewportDimensions;
@@ -68,7 +70,20 @@ impl MainWindow {
         }
         match event {
             WindowEvent::CloseRequested => {
-                event_loop.exit();
+                // With a movie, the window is closed by the player, which asks to exit once
+                // the movie has no windows left.
+                if self.player.get().is_none() {
+                    event_loop.exit();
+                } else {
+                    self.player.native_window_event(
+                        NativeWindowId::MAIN,
+                        NativeWindowEvent::CloseRequested,
+                    );
+                }
+            }
+            WindowEvent::Moved(_) => {
+                self.player
+                    .native_window_event(NativeWindowId::MAIN, NativeWindowEvent::BoundsChanged);
             }
             WindowEvent::Resized(size) => {
                 // TODO: Change this when winit adds a `Window::minimized` or `WindowEvent::Minimize`.
@@ -82,6 +97,8 @@ impl MainWindow {
                         scale_factor: viewport_scale_factor,
                     });
                 }
+                self.player
+                    .native_window_event(NativeWindowId::MAIN, NativeWindowEvent::BoundsChanged);
                 self.gui.window().request_redraw();
                 if matches!(self.loaded, LoadingState::WaitingForResize) {
                     self.loaded = LoadingState::Loaded;
@@ -109,9 +126,13 @@ impl MainWindow {
             }
             WindowEvent::Focused(true) => {
                 self.player.handle_event(PlayerEvent::FocusGained);
+                self.player
+                    .native_window_event(NativeWindowId::MAIN, NativeWindowEvent::Activated);
             }
             WindowEvent::Focused(false) => {
                 self.player.handle_event(PlayerEvent::FocusLost);
+                self.player
+                    .native_window_event(NativeWindowId::MAIN, NativeWindowEvent::Deactivated);
             }
             WindowEvent::MouseInput { button, state, .. } => {
                 if self.gui.is_context_menu_visible() {
@@ -119,14 +140,8 @@ impl MainWindow {
                 }
 
                 use ruffle_core::events::MouseButton as RuffleMouseButton;
-                use winit::event::MouseButton;
                 let (x, y) = self.gui.window_to_movie_position(self.mouse_pos);
-                let button = match button {
-                    MouseButton::Left => RuffleMouseButton::Left,
-                    MouseButton::Right => RuffleMouseButton::Right,
-                    MouseButton::Middle => RuffleMouseButton::Middle,
-                    _ => RuffleMouseButton::Unknown,
-                };
+                let button = winit_to_ruffle_mouse_button(button);
                 let event = match state {
                     // TODO We should get information about click index from the OS,
                     //   but winit does not support that yet.
@@ -172,6 +187,23 @@ impl MainWindow {
                 self.player.handle_event(event);
                 self.check_redraw();
             }
+            WindowEvent::Touch(touch) => {
+                if self.gui.is_context_menu_visible() || !self.player.is_touch_enabled() {
+                    return;
+                }
+
+                use winit::event::TouchPhase;
+                let id = touch.id as u32;
+                let (x, y) = self.gui.window_to_movie_position(touch.location);
+                let event = match touch.phase {
+                    TouchPhase::Started => PlayerEvent::TouchBegin { id, x, y },
+                    TouchPhase::Moved => PlayerEvent::TouchMove { id, x, y },
+                    TouchPhase::Ended => PlayerEvent::TouchEnd { id, x, y },
+                    TouchPhase::Cancelled => PlayerEvent::TouchCancel { id },
+                };
+                self.player.handle_event(event);
+                self.check_redraw();
+            }
             WindowEvent::CursorEntered { .. } => {
                 if let Some(mut player) = self.player.get() {
                     player.set_mouse_in_stage(true);
@@ -207,26 +239,9 @@ impl MainWindow {
                         .send_event(RuffleEvent::ExitFullScreen);
                 }
 
-                let key = winit_input_to_ruffle_key_descriptor(&event);
-                match event.state {
-                    ElementState::Pressed => {
-                        self.player.handle_event(PlayerEvent::KeyDown { key });
-                        if let Some(control_code) =
-                            winit_to_ruffle_text_control(&event, self.modifiers)
-                        {
-                            self.player
-                                .handle_event(PlayerEvent::TextControl { code: control_code });
-                        } else if let Some(text) = event.text {
-                            for codepoint in text.chars() {
-                                self.player
-                                    .handle_event(PlayerEvent::TextInput { codepoint });
-                            }
-                        }
-                    }
-                    ElementState::Released => {
-                        self.player.handle_event(PlayerEvent::KeyUp { key });
-                    }
-                };
+                for event in winit_to_ruffle_key_events(event, self.modifiers) {
+                    self.player.handle_event(event);
+                }
                 self.check_redraw();
             }
             WindowEvent::Ime(ime) => match ime {
@@ -245,6 +260,96 @@ impl MainWindow {
         }
     }
 
+    /// Handles an event from a window opened by the movie.
+    fn native_window_event(&mut self, window_id: WindowId, event: WindowEvent) {
+        let Some(native_windows) = self.player.native_windows() else {
+            return;
+        };
+        let Some(id) = native_windows.find(window_id) else {
+            return;
+        };
+
+        let event = match event {
+            WindowEvent::Resized(_) | WindowEvent::Moved(_) => NativeWindowEvent::BoundsChanged,
+            WindowEvent::Focused(true) => NativeWindowEvent::Activated,
+            WindowEvent::Focused(false) => NativeWindowEvent::Deactivated,
+            WindowEvent::CloseRequested => NativeWindowEvent::CloseRequested,
+            event => {
+                for event in self.native_window_input(&native_windows, id, event) {
+                    self.player.handle_native_window_input(id, event);
+                }
+                self.check_redraw();
+                return;
+            }
+        };
+        self.player.native_window_event(id, event);
+        self.gui.window().request_redraw();
+    }
+
+    /// Converts an input event from a window opened by the movie to the player events it
+    /// produces, in the coordinates of the window's stage.
+    fn native_window_input(
+        &mut self,
+        native_windows: &NativeWindows,
+        id: NativeWindowId,
+        event: WindowEvent,
+    ) -> Vec<PlayerEvent> {
+        // NOTE: The stages of these windows are the size of the windows, without any scaling.
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                native_windows.set_mouse_position(id, position);
+                vec![PlayerEvent::MouseMove {
+                    x: position.x,
+                    y: position.y,
+                }]
+            }
+            WindowEvent::CursorLeft { .. } => vec![PlayerEvent::MouseLeave],
+            WindowEvent::MouseInput { button, state, .. } => {
+                let position = native_windows.mouse_position(id);
+                let (x, y) = (position.x, position.y);
+                let button = winit_to_ruffle_mouse_button(button);
+                vec![match state {
+                    ElementState::Pressed => PlayerEvent::MouseDown {
+                        x,
+                        y,
+                        button,
+                        index: None,
+                    },
+                    ElementState::Released => PlayerEvent::MouseUp { x, y, button },
+                }]
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                use ruffle_core::events::MouseWheelDelta;
+                use winit::event::MouseScrollDelta;
+                let delta = match delta {
+                    MouseScrollDelta::LineDelta(_, dy) => MouseWheelDelta::Lines(dy.into()),
+                    MouseScrollDelta::PixelDelta(pos) => MouseWheelDelta::Pixels(pos.y),
+                };
+                vec![PlayerEvent::MouseWheel { delta }]
+            }
+            WindowEvent::Touch(_) if !self.player.is_touch_enabled() => vec![],
+            WindowEvent::Touch(touch) => {
+                use winit::event::TouchPhase;
+                let id = touch.id as u32;
+                let (x, y) = (touch.location.x, touch.location.y);
+                vec![match touch.phase {
+                    TouchPhase::Started => PlayerEvent::TouchBegin { id, x, y },
+                    TouchPhase::Moved => PlayerEvent::TouchMove { id, x, y },
+                    TouchPhase::Ended => PlayerEvent::TouchEnd { id, x, y },
+                    TouchPhase::Cancelled => PlayerEvent::TouchCancel { id },
+                }]
+            }
+            WindowEvent::ModifiersChanged(new_modifiers) => {
+                self.modifiers = new_modifiers;
+                vec![]
+            }
+            WindowEvent::KeyboardInput { event, .. } => {
+                winit_to_ruffle_key_events(event, self.modifiers)
+            }
+            _ => vec![],
+        }
+    }
+
     fn on_metadata(&mut self, swf_header: HeaderExt) {
         let height_offset = if self.gui.window().fullscreen().is_some() || self.no_gui {
             0.0
@@ -257,8 +362,14 @@ impl MainWindow {
         let should_resize = !self.gui.window().is_maximized();
 
         let (viewport_size, state) = if should_resize {
-            let movie_width = swf_header.stage_size().width().to_pixels();
-            let movie_height = swf_header.stage_size().height().to_pixels();
+            // The descriptor of an AIR application may size the window differently from the SWF.
+            let descriptor = self.player.application_descriptor();
+            let movie_width = descriptor
+                .and_then(|descriptor| descriptor.width)
+                .map_or_else(|| swf_header.stage_size().width().to_pixels(), f64::from);
+            let movie_height = descriptor
+                .and_then(|descriptor| descriptor.height)
+                .map_or_else(|| swf_header.stage_size().height().to_pixels(), f64::from);
 
             let window_size: Size = match (self.preferred_width, self.preferred_height) {
                 (None, None) => LogicalSize::new(movie_width, movie_height + height_offset).into(),
@@ -381,7 +492,20 @@ impl MainWindow {
 
     fn check_redraw(&self) {
         let player = self.player.get();
-        if player.map(|p| p.needs_render()).unwrap_or_default() || self.gui.needs_render() {
+        let player_needs_render = player.as_ref().is_some_and(|p| p.needs_render());
+
+        // Once the main window was closed, it's hidden and never redrawn, but the other
+        // windows of the movie still need to be.
+        if self.gui.window().is_visible() == Some(false) {
+            if let Some(mut player) = player
+                && player_needs_render
+            {
+                player.render_native_windows();
+            }
+            return;
+        }
+
+        if player_needs_render || self.gui.needs_render() {
             self.gui.window().request_redraw();
         }
     }
@@ -394,6 +518,9 @@ pub struct App {
     event_loop_proxy: EventLoopProxy<RuffleEvent>,
     preferences: GlobalPreferences,
     font_database: fontdb::Database,
+
+    /// The exit code requested by the movie, if any.
+    exit_code: i32,
 }
 
 /// Enters the tokio runtime context.
@@ -427,10 +554,16 @@ impl App {
                 event_loop_proxy,
                 font_database,
                 preferences,
+                exit_code: 0,
             },
             event_loop,
         ))
     }
+
+    /// The exit code the process should exit with.
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
 }
 
 impl ApplicationHandler<RuffleEvent> for App {
@@ -604,6 +737,14 @@ impl ApplicationHandler<RuffleEvent> for App {
                 main_window.gui.export_bundle();
             }
 
+            (Some(main_window), RuffleEvent::OpenNativeWindow(id)) => {
+                if let Some(native_windows) = main_window.player.native_windows()
+                    && let Some(target) = native_windows.create_window(event_loop, id)
+                {
+                    main_window.player.add_native_window_target(id, target);
+                }
+            }
+
             (Some(main_window), RuffleEvent::EnterFullScreen) => {
                 if let Some(mut player) = main_window.player.get()
                     && player.is_playing()
@@ -644,10 +785,20 @@ impl ApplicationHandler<RuffleEvent> for App {
                     PlayerNotification::ImeNotification(ImeNotification::ImeNotReady) => {
                         main_window.gui.set_ime_allowed(false);
                     }
+                    PlayerNotification::ExitRequested { exit_code } => {
+                        self.exit_code = exit_code;
+                        event_loop.exit();
+                    }
                 }
             }
 
-            (_, RuffleEvent::ExitRequested) => {
+            (Some(main_window), RuffleEvent::ExitRequested) => {
+                if main_window.player.application_exiting() {
+                    event_loop.exit();
+                }
+            }
+
+            (None, RuffleEvent::ExitRequested) => {
                 event_loop.exit();
             }
 
@@ -658,13 +809,17 @@ impl ApplicationHandler<RuffleEvent> for App {
     fn window_event(
         &mut self,
         event_loop: &ActiveEventLoop,
-        _window_id: WindowId,
+        window_id: WindowId,
         event: WindowEvent,
     ) {
         enter_runtime!(self);
 
         if let Some(main_window) = &mut self.main_window {
-            main_window.window_event(event_loop, event);
+            if main_window.gui.window().id() == window_id {
+                main_window.window_event(event_loop, event);
+            } else {
+                main_window.native_window_event(window_id, event);
+            }
         }
     }
 
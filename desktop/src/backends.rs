@@ -1,10 +1,16 @@
 mod external_interface;
 mod fscommand;
+mod microphone;
 mod navigator;
+mod print;
+mod process;
 mod ui;
 
 pub use external_interface::DesktopExternalInterfaceProvider;
 pub use fscommand::DesktopFSCommandProvider;
+pub use microphone::DesktopMicrophoneBackend;
 pub use navigator::DesktopNavigatorInterface;
 pub use navigator::PathAllowList;
+pub use print::DesktopPrintBackend;
+pub use process::DesktopProcessBackend;
 pub use ui::DesktopUiBackend;
@@ -0,0 +1,103 @@
+use crate::cli::MicrophoneAccessMode;
+use crate::custom_event::RuffleEvent;
+use crate::gui::DialogDescriptor;
+use crate::gui::dialogs::microphone_access_dialog::{
+    MicrophoneAccessDialogConfiguration, MicrophoneAccessDialogResult,
+};
+use ruffle_core::backend::microphone::{MicrophoneBackend, MicrophoneError, MicrophonePermission};
+use ruffle_frontend_utils::backends::microphone::CpalMicrophoneBackend;
+use std::cell::{Cell, RefCell};
+use tokio::sync::oneshot;
+use winit::event_loop::EventLoopProxy;
+
+/// Captures audio from the input devices of the system, once the user allows it.
+pub struct DesktopMicrophoneBackend {
+    inner: CpalMicrophoneBackend,
+
+    event_loop: EventLoopProxy<RuffleEvent>,
+
+    permission: Cell<MicrophonePermission>,
+
+    /// The answer of the dialog asking for permission, while it's open.
+    answer: RefCell<Option<oneshot::Receiver<MicrophoneAccessDialogResult>>>,
+}
+
+impl DesktopMicrophoneBackend {
+    pub fn new(event_loop: EventLoopProxy<RuffleEvent>, access_mode: MicrophoneAccessMode) -> Self {
+        let permission = match access_mode {
+            MicrophoneAccessMode::Allow => MicrophonePermission::Allowed,
+            MicrophoneAccessMode::Deny => MicrophonePermission::Denied,
+            MicrophoneAccessMode::Ask => MicrophonePermission::Undecided,
+        };
+
+        Self {
+            inner: CpalMicrophoneBackend::new(),
+            event_loop,
+            permission: Cell::new(permission),
+            answer: RefCell::new(None),
+        }
+    }
+}
+
+impl MicrophoneBackend for DesktopMicrophoneBackend {
+    fn names(&self) -> Vec<String> {
+        self.inner.names()
+    }
+
+    fn default_device(&self) -> Option<usize> {
+        self.inner.default_device()
+    }
+
+    fn permission(&self) -> MicrophonePermission {
+        let mut answer = self.answer.borrow_mut();
+        if let Some(receiver) = answer.as_mut() {
+            let permission = match receiver.try_recv() {
+                Ok(MicrophoneAccessDialogResult::Allow) => MicrophonePermission::Allowed,
+                Ok(MicrophoneAccessDialogResult::Deny) => MicrophonePermission::Denied,
+                Err(oneshot::error::TryRecvError::Empty) => return self.permission.get(),
+                // The dialog was closed without an answer.
+                Err(oneshot::error::TryRecvError::Closed) => MicrophonePermission::Denied,
+            };
+            self.permission.set(permission);
+            *answer = None;
+        }
+
+        self.permission.get()
+    }
+
+    fn request_permission(&mut self) {
+        if self.permission.get() != MicrophonePermission::Undecided
+            || self.answer.get_mut().is_some()
+        {
+            return;
+        }
+
+        let (notifier, receiver) = oneshot::channel();
+        *self.answer.get_mut() = Some(receiver);
+        let _ = self.event_loop.send_event(RuffleEvent::OpenDialog(
+            DialogDescriptor::MicrophoneAccess(MicrophoneAccessDialogConfiguration::new(notifier)),
+        ));
+    }
+
+    fn start_capture(&mut self, index: usize, sample_rate: u32) -> Result<(), MicrophoneError> {
+        if self.permission.get() != MicrophonePermission::Allowed {
+            return Err(MicrophoneError::Capture(
+                "The user didn't allow capturing audio".to_string(),
+            ));
+        }
+
+        self.inner.start_capture(index, sample_rate)
+    }
+
+    fn stop_capture(&mut self, index: usize) {
+        self.inner.stop_capture(index);
+    }
+
+    fn read_samples(&mut self, index: usize, samples: &mut Vec<f32>) {
+        self.inner.read_samples(index, samples);
+    }
+
+    fn set_loop_back(&mut self, index: usize, volume: Option<f32>) {
+        self.inner.set_loop_back(index, volume);
+    }
+}
@@ -45,6 +45,10 @@ impl PathAllowList {
             allowed_path_prefixes.push(root_content_path.clone());
         }
 
+        Self::from_paths(allowed_path_prefixes)
+    }
+
+    pub fn from_paths(allowed_path_prefixes: Vec<PathBuf>) -> Self {
         Self {
             allowed_path_prefixes: Arc::new(Mutex::new(allowed_path_prefixes)),
         }
@@ -0,0 +1,97 @@
+use rfd::{MessageButtons, MessageDialog, MessageDialogResult, MessageLevel};
+use ruffle_core::backend::print::{PrintBackend, PrintJobSettings, PrintPage};
+use ruffle_frontend_utils::backends::print::{PrintFormat, write_print_job};
+use std::path::PathBuf;
+use url::Url;
+
+/// A print backend writing every print job to a PDF document, which is then opened by the
+/// system to print it.
+pub struct DesktopPrintBackend {
+    settings: PrintJobSettings,
+
+    /// Where the documents of print jobs are written.
+    directory: PathBuf,
+
+    pages: Vec<PrintPage>,
+}
+
+impl DesktopPrintBackend {
+    pub fn new(settings: PrintJobSettings, directory: PathBuf) -> Self {
+        Self {
+            settings,
+            directory,
+            pages: Vec::new(),
+        }
+    }
+}
+
+impl PrintBackend for DesktopPrintBackend {
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    fn start_job(&mut self) -> Option<PrintJobSettings> {
+        self.pages.clear();
+
+        // Like the print dialog of Flash, this lets the user refuse to print, in which case
+        // the job fails to start.
+        let message = format!(
+            "The SWF file wants to print. The pages will be saved as a PDF document in {:?}, \
+            which is then opened to print it.",
+            self.directory
+        );
+        let confirm = MessageDialog::new()
+            .set_title("Print?")
+            .set_level(MessageLevel::Info)
+            .set_description(message)
+            .set_buttons(MessageButtons::OkCancel)
+            .show()
+            == MessageDialogResult::Ok;
+        if !confirm {
+            tracing::info!("SWF tried to print, but the user declined the request");
+            return None;
+        }
+
+        Some(self.settings)
+    }
+
+    fn add_page(&mut self, page: PrintPage) {
+        self.pages.push(page);
+    }
+
+    fn send(&mut self) {
+        let pages = std::mem::take(&mut self.pages);
+        if pages.is_empty() {
+            return;
+        }
+
+        let directory = self.directory.clone();
+        let name = format!(
+            "print-{}.pdf",
+            chrono::Local::now().format("%Y-%m-%d_%H-%M-%S%.3f")
+        );
+
+        // NOTE: Compressing large pages takes a while, so it's done off the event loop.
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = std::fs::create_dir_all(&directory) {
+                tracing::error!("Couldn't create print directory {directory:?}: {e}");
+                return;
+            }
+
+            let path = directory.join(name);
+            if let Err(e) = write_print_job(&path, PrintFormat::Pdf, &pages) {
+                tracing::error!("Couldn't write print job to {path:?}: {e}");
+                return;
+            }
+
+            // The document is opened with the application of the system for it, which
+            // shows its print dialog when asked to.
+            let Ok(url) = Url::from_file_path(&path) else {
+                return;
+            };
+            if let Err(e) = webbrowser::open(url.as_str()) {
+                tracing::error!("Couldn't open print job {path:?}: {e}");
+            }
+        });
+    }
+}
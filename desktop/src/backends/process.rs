@@ -0,0 +1,250 @@
+use crate::backends::PathAllowList;
+use crate::custom_event::RuffleEvent;
+use crate::gui::DialogDescriptor;
+use crate::gui::dialogs::native_process_dialog::{
+    NativeProcessDialogConfiguration, NativeProcessDialogResult,
+};
+use async_channel::{Receiver, Sender};
+use ruffle_core::backend::filesystem::SpecialDirectory;
+use ruffle_core::backend::process::{NativeProcessStartupInfo, ProcessBackend};
+use ruffle_core::native_process::{NativeProcessAction, NativeProcessCommand, NativeProcessHandle};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, Command};
+use tokio::sync::oneshot;
+use winit::event_loop::EventLoopProxy;
+
+pub struct DesktopProcessBackend {
+    event_loop: EventLoopProxy<RuffleEvent>,
+
+    /// Programs which may run without asking the user first.
+    allow_list: PathAllowList,
+
+    /// Where the files of the application are, if they're on disk.
+    application_directory: Option<PathBuf>,
+
+    /// Where the other special directories of the movie's filesystem are, if it's on disk.
+    filesystem_root: Option<PathBuf>,
+}
+
+impl DesktopProcessBackend {
+    pub fn new(
+        event_loop: EventLoopProxy<RuffleEvent>,
+        allow_list: PathAllowList,
+        application_directory: Option<PathBuf>,
+        filesystem_root: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            event_loop,
+            allow_list,
+            application_directory,
+            filesystem_root,
+        }
+    }
+
+    /// The real path of a sandbox path.
+    ///
+    /// Paths outside of the special directories are taken to be paths of the system, as
+    /// that's the only way for a movie to refer to the programs installed on it.
+    fn real_path(&self, path: &str) -> Option<PathBuf> {
+        let root = match SpecialDirectory::containing(path) {
+            Some(SpecialDirectory::Application) => {
+                let rest = &path[SpecialDirectory::Application.path().len()..];
+                return Some(
+                    self.application_directory
+                        .as_ref()?
+                        .join(rest.trim_start_matches('/')),
+                );
+            }
+            Some(_) => self.filesystem_root.as_ref()?,
+            None => return Some(PathBuf::from(path)),
+        };
+        Some(root.join(&path[1..]))
+    }
+}
+
+impl ProcessBackend for DesktopProcessBackend {
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    fn start_process(
+        &mut self,
+        info: NativeProcessStartupInfo,
+        handle: NativeProcessHandle,
+        receiver: Receiver<NativeProcessCommand>,
+        sender: Sender<NativeProcessAction>,
+    ) {
+        let executable = self.real_path(&info.executable);
+        let working_directory = info
+            .working_directory
+            .as_deref()
+            .map(|path| self.real_path(path));
+        let allow_list = self.allow_list.clone();
+        let event_loop = self.event_loop.clone();
+
+        tokio::spawn(async move {
+            let child = match (executable, working_directory) {
+                (None, _) | (_, Some(None)) => {
+                    tracing::warn!("SWF tried to run {}, which isn't on disk", info.executable);
+                    None
+                }
+                (Some(executable), working_directory) => {
+                    let allowed = allow_list.is_path_allowed(&executable)
+                        || ask_for_permission(&event_loop, &allow_list, &executable, &info).await;
+                    if allowed {
+                        spawn(executable, working_directory.flatten(), &info.arguments)
+                    } else {
+                        tracing::warn!(
+                            "SWF tried to run {executable:?}, but running it is not allowed"
+                        );
+                        None
+                    }
+                }
+            };
+
+            let exit_code = match child {
+                Some(child) => run(child, handle, receiver, &sender).await,
+                None => None,
+            };
+            let _ = sender
+                .send(NativeProcessAction::Exited(handle, exit_code))
+                .await;
+        });
+    }
+}
+
+async fn ask_for_permission(
+    event_loop: &EventLoopProxy<RuffleEvent>,
+    allow_list: &PathAllowList,
+    executable: &Path,
+    info: &NativeProcessStartupInfo,
+) -> bool {
+    let (notifier, receiver) = oneshot::channel();
+    let _ = event_loop.send_event(RuffleEvent::OpenDialog(DialogDescriptor::NativeProcess(
+        NativeProcessDialogConfiguration::new(
+            notifier,
+            allow_list.clone(),
+            executable.to_path_buf(),
+            info.arguments.clone(),
+        ),
+    )));
+
+    receiver.await == Ok(NativeProcessDialogResult::Allow)
+}
+
+fn spawn(
+    executable: PathBuf,
+    working_directory: Option<PathBuf>,
+    arguments: &[String],
+) -> Option<Child> {
+    let mut command = Command::new(&executable);
+    command
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    if let Some(working_directory) = working_directory {
+        command.current_dir(working_directory);
+    }
+
+    command
+        .spawn()
+        .inspect_err(|e| tracing::error!("Couldn't run {executable:?}: {e}"))
+        .ok()
+}
+
+/// Forwards the input and output of a program until it exits, returning its exit code.
+async fn run(
+    mut child: Child,
+    handle: NativeProcessHandle,
+    receiver: Receiver<NativeProcessCommand>,
+    sender: &Sender<NativeProcessAction>,
+) -> Option<i32> {
+    let mut stdin = child.stdin.take();
+    let stdout = child.stdout.take()?;
+    let stderr = child.stderr.take()?;
+
+    let output = read_output(stdout, sender, |data| {
+        NativeProcessAction::StandardOutput(handle, data)
+    });
+    let output = async {
+        output.await;
+        let _ = sender
+            .send(NativeProcessAction::StandardOutputClosed(handle))
+            .await;
+    };
+    let error = read_output(stderr, sender, |data| {
+        NativeProcessAction::StandardError(handle, data)
+    });
+    let error = async {
+        error.await;
+        let _ = sender
+            .send(NativeProcessAction::StandardErrorClosed(handle))
+            .await;
+    };
+
+    let commands = async {
+        while let Ok(command) = receiver.recv().await {
+            match command {
+                NativeProcessCommand::Write(data) => {
+                    let Some(input) = &mut stdin else {
+                        continue;
+                    };
+                    if let Err(e) = input.write_all(&data).await {
+                        tracing::warn!("Couldn't write to native process: {e}");
+                    }
+                }
+                // Without `force`, closing the input is the only portable way to ask a
+                // program to exit.
+                NativeProcessCommand::CloseInput | NativeProcessCommand::Exit { force: false } => {
+                    // NOTE: Dropping stdin closes it.
+                    if stdin.take().is_some() {
+                        let _ = sender
+                            .send(NativeProcessAction::StandardInputClosed(handle))
+                            .await;
+                    }
+                }
+                NativeProcessCommand::Exit { force: true } => return,
+            }
+        }
+        // NOTE: Otherwise the player is gone, so nobody is waiting for the program anymore.
+    };
+
+    let exit = async {
+        tokio::join!(output, error);
+        child.wait().await
+    };
+
+    let status = tokio::select! {
+        status = exit => status.ok(),
+        _ = commands => None,
+    };
+    match status {
+        Some(status) => status.code(),
+        None => {
+            let _ = child.kill().await;
+            None
+        }
+    }
+}
+
+async fn read_output(
+    mut stream: impl AsyncRead + Unpin,
+    sender: &Sender<NativeProcessAction>,
+    action: impl Fn(Vec<u8>) -> NativeProcessAction,
+) {
+    let mut buffer = [0; 4096];
+    loop {
+        match stream.read(&mut buffer).await {
+            Err(_) | Ok(0) => break,
+            Ok(read) => {
+                if sender.send(action(buffer[..read].to_vec())).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,10 @@ use crate::cli::OpenUrlMode;
 use crate::custom_event::RuffleEvent;
 use crate::gui::dialogs::message_dialog::MessageDialogConfiguration;
 use crate::gui::{DialogDescriptor, FilePicker, LocalizableText};
+use crate::native_window::{
+    NativeWindows, set_window_always_in_front, set_window_bounds, set_window_display_state,
+    set_window_size_limits, start_window_resize, window_bounds, window_display_state,
+};
 use crate::preferences::GlobalPreferences;
 use anyhow::{Error, Result, anyhow};
 use chrono::{DateTime, Utc};
@@ -11,8 +15,10 @@ use rfd::{
     AsyncFileDialog, FileHandle, MessageButtons, MessageDialog, MessageDialogResult, MessageLevel,
 };
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter, FontDefinition,
-    FullscreenError, LanguageIdentifier, MouseCursor, UiBackend,
+    ClipboardData, ClipboardFormat, DialogLoaderError, DialogResultFuture, FileDialogResult,
+    FileFilter, FontDefinition, FullscreenError, LanguageIdentifier, MouseCursor,
+    NativeWindowBounds, NativeWindowDisplayState, NativeWindowEdge, NativeWindowId,
+    NativeWindowOptions, UiBackend,
 };
 use ruffle_core::font::{FontFileData, FontQuery};
 use std::fs::File;
@@ -153,12 +159,22 @@ pub struct DesktopUiBackend {
     clipboard: Clipboard,
     window: Arc<Window>,
 
+    /// The data last copied by the movie, including formats the system clipboard can't hold.
+    copied_data: Vec<ClipboardData>,
+
+    /// The text on the system clipboard right after `copied_data` was copied.
+    /// Once it changes, another application has replaced the clipboard contents.
+    copied_text: Option<String>,
+
     event_loop: EventLoopProxy<RuffleEvent>,
     cursor_visible: bool,
     preferences: GlobalPreferences,
     preferred_cursor: MouseCursor,
     font_database: Rc<fontdb::Database>,
     file_picker: FilePicker,
+
+    /// The windows opened by the movie besides `window`.
+    native_windows: NativeWindows,
 }
 
 impl DesktopUiBackend {
@@ -168,6 +184,7 @@ impl DesktopUiBackend {
         font_database: Rc<fontdb::Database>,
         preferences: GlobalPreferences,
         file_picker: FilePicker,
+        native_windows: NativeWindows,
     ) -> Result<Self, Error> {
         // The window handle is only relevant to linux/wayland
         // If it fails it'll fallback to x11 or wlr-data-control
@@ -177,13 +194,25 @@ impl DesktopUiBackend {
             event_loop,
             cursor_visible: true,
             clipboard,
+            copied_data: Vec::new(),
+            copied_text: None,
             preferences,
             preferred_cursor: MouseCursor::Arrow,
             font_database,
             file_picker,
+            native_windows,
         })
     }
 
+    /// The data last copied by the movie, if it's still on the clipboard.
+    fn copied_data(&mut self) -> &[ClipboardData] {
+        if self.clipboard.get() != self.copied_text {
+            self.copied_data.clear();
+            self.copied_text = None;
+        }
+        &self.copied_data
+    }
+
     pub fn cursor(&self) -> egui::CursorIcon {
         if self.cursor_visible {
             match self.preferred_cursor {
@@ -211,12 +240,54 @@ impl UiBackend for DesktopUiBackend {
         self.preferred_cursor = cursor;
     }
 
-    fn clipboard_content(&mut self) -> String {
-        self.clipboard.get().unwrap_or_default()
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        let mut formats: Vec<_> = self
+            .copied_data()
+            .iter()
+            .map(ClipboardData::format)
+            .collect();
+        if !formats.contains(&ClipboardFormat::Text)
+            && self.clipboard.get().is_some_and(|text| !text.is_empty())
+        {
+            formats.push(ClipboardFormat::Text);
+        }
+        formats
+    }
+
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData> {
+        if format == ClipboardFormat::Text {
+            return self.clipboard.get().map(ClipboardData::Text);
+        }
+        ClipboardData::find(self.copied_data(), format).cloned()
     }
 
-    fn set_clipboard_content(&mut self, content: String) {
-        self.clipboard.set_text(content);
+    fn set_clipboard_data(&mut self, data: Vec<ClipboardData>) {
+        // The system clipboard only holds text and images, other formats stay within Ruffle.
+        let text = match ClipboardData::find(&data, ClipboardFormat::Text) {
+            Some(ClipboardData::Text(text)) => Some(text.clone()),
+            _ => match ClipboardData::find(&data, ClipboardFormat::Urls) {
+                Some(ClipboardData::Urls(urls)) => Some(urls.join("\n")),
+                _ => None,
+            },
+        };
+        if let Some(text) = text {
+            self.clipboard.set_text(text);
+        } else if let Some(ClipboardData::Bitmap {
+            width,
+            height,
+            rgba,
+        }) = ClipboardData::find(&data, ClipboardFormat::Bitmap)
+        {
+            let size = [*width as usize, *height as usize];
+            let image = egui::ColorImage::from_rgba_unmultiplied(size, rgba);
+            self.clipboard.set_image(&image);
+        } else {
+            // Nothing else should be pasted alongside formats that stay within Ruffle.
+            self.clipboard.set_text(String::new());
+        }
+
+        self.copied_text = self.clipboard.get();
+        self.copied_data = data;
     }
 
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), FullscreenError> {
@@ -389,6 +460,120 @@ impl UiBackend for DesktopUiBackend {
     }
 
     fn close_file_dialog(&mut self) {}
+
+    fn open_native_window(&mut self, id: NativeWindowId, options: NativeWindowOptions) -> bool {
+        self.native_windows.add(id, options);
+        self.event_loop
+            .send_event(RuffleEvent::OpenNativeWindow(id))
+            .is_ok()
+    }
+
+    fn close_native_window(&mut self, id: NativeWindowId) {
+        if id == NativeWindowId::MAIN {
+            // The player keeps running until the movie exits, which it may do once its other
+            // windows are closed.
+            self.window.set_visible(false);
+        } else {
+            self.native_windows.remove(id);
+        }
+    }
+
+    fn native_window_bounds(&self, id: NativeWindowId) -> Option<NativeWindowBounds> {
+        if id == NativeWindowId::MAIN {
+            window_bounds(&self.window)
+        } else {
+            self.native_windows.bounds(id)
+        }
+    }
+
+    fn set_native_window_bounds(&mut self, id: NativeWindowId, bounds: NativeWindowBounds) {
+        if id == NativeWindowId::MAIN {
+            set_window_bounds(&self.window, bounds);
+        } else {
+            self.native_windows.set_bounds(id, bounds);
+        }
+    }
+
+    fn native_window_display_state(&self, id: NativeWindowId) -> NativeWindowDisplayState {
+        if id == NativeWindowId::MAIN {
+            window_display_state(&self.window)
+        } else {
+            self.native_windows.display_state(id)
+        }
+    }
+
+    fn set_native_window_display_state(
+        &mut self,
+        id: NativeWindowId,
+        state: NativeWindowDisplayState,
+    ) {
+        if id == NativeWindowId::MAIN {
+            set_window_display_state(&self.window, state);
+        } else {
+            self.native_windows.set_display_state(id, state);
+        }
+    }
+
+    fn activate_native_window(&mut self, id: NativeWindowId) {
+        if id == NativeWindowId::MAIN {
+            self.window.focus_window();
+        } else {
+            self.native_windows.activate(id);
+        }
+    }
+
+    fn set_native_window_visible(&mut self, id: NativeWindowId, visible: bool) {
+        if id == NativeWindowId::MAIN {
+            self.window.set_visible(visible);
+        } else {
+            self.native_windows.set_visible(id, visible);
+        }
+    }
+
+    fn set_native_window_title(&mut self, id: NativeWindowId, title: &str) {
+        if id == NativeWindowId::MAIN {
+            self.window.set_title(title);
+        } else {
+            self.native_windows.set_title(id, title);
+        }
+    }
+
+    fn set_native_window_always_in_front(&mut self, id: NativeWindowId, always_in_front: bool) {
+        if id == NativeWindowId::MAIN {
+            set_window_always_in_front(&self.window, always_in_front);
+        } else {
+            self.native_windows.set_always_in_front(id, always_in_front);
+        }
+    }
+
+    fn set_native_window_size_limits(
+        &mut self,
+        id: NativeWindowId,
+        min: (u32, u32),
+        max: (u32, u32),
+    ) {
+        if id == NativeWindowId::MAIN {
+            set_window_size_limits(&self.window, min, max);
+        } else {
+            self.native_windows.set_size_limits(id, min, max);
+        }
+    }
+
+    fn start_native_window_move(&mut self, id: NativeWindowId) -> bool {
+        if id == NativeWindowId::MAIN {
+            self.window.drag_window().is_ok()
+        } else {
+            self.native_windows.start_move(id)
+        }
+    }
+
+    fn start_native_window_resize(&mut self, id: NativeWindowId, edge: NativeWindowEdge) -> bool {
+        if id == NativeWindowId::MAIN {
+            start_window_resize(&self.window, edge)
+        } else {
+            self.native_windows.start_resize(id, edge)
+        }
+    }
 }
 
 fn load_font_from_file(
@@ -3,11 +3,13 @@ use crate::preferences::storage::StorageBackend;
 use anyhow::{Error, anyhow};
 use clap::{Parser, ValueEnum};
 use ruffle_core::backend::navigator::SocketMode;
+use ruffle_core::backend::print::PrintJobSettings;
 use ruffle_core::config::Letterbox;
 use ruffle_core::events::{GamepadButton, KeyCode};
 use ruffle_core::{LoadBehavior, PlayerRuntime, StageAlign, StageScaleMode};
 use ruffle_render::quality::StageQuality;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
+use std::net::SocketAddr;
 use std::path::Path;
 use std::str::FromStr;
 use std::time::Duration;
@@ -147,6 +149,11 @@ pub struct Opt {
     #[clap(long = "socket-allow", number_of_values = 1, action = clap::ArgAction::Append)]
     pub socket_allow: Vec<String>,
 
+    /// Add a program that AIR applications may run without asking first.
+    /// Programs in a directory may be allowed by adding the directory.
+    #[clap(long = "process-allow", number_of_values = 1, action = clap::ArgAction::Append)]
+    pub process_allow: Vec<std::path::PathBuf>,
+
     /// Define how to deal with TCP Socket connections.
     #[clap(long = "tcp-connections")]
     pub tcp_connections: Option<SocketMode>,
@@ -199,12 +206,37 @@ pub struct Opt {
     #[clap(long, default_value = "ask")]
     pub filesystem_access_mode: FilesystemAccessMode,
 
+    /// How to handle movies capturing audio from the microphone.
+    #[clap(long, default_value = "ask")]
+    pub microphone_access_mode: MicrophoneAccessMode,
+
+    /// The size of the paper movies print on.
+    #[clap(long, default_value = "letter")]
+    pub paper_size: PaperSize,
+
+    /// Print to a PDF or PNG file, depending on its extension, instead of opening the
+    /// printed pages to print them.
+    /// PNG images of jobs with several pages are numbered.
+    #[clap(long)]
+    pub print_output: Option<std::path::PathBuf>,
+
     /// Provide a dummy (completely empty) External Interface to the movie.
     /// This may break some movies that expect an External Interface to be functional,
     /// but may fix others that always require an External Interface.
     #[clap(long)]
     pub dummy_external_interface: bool,
 
+    /// The number of simultaneous touch points to report to the movie.
+    /// Touch input is only delivered to the movie if this is set. Otherwise, touches are
+    /// left to the system, which turns them into mouse input.
+    #[clap(long, default_value = "0")]
+    pub max_touch_points: u32,
+
+    /// Arguments to pass to an AIR application in its `InvokeEvent`, given after `--`.
+    /// For example: ruffle game.swf -- --level 2
+    #[clap(last = true)]
+    pub invoke_arguments: Vec<String>,
+
     /// Hides the menu bar (the bar at the top of the window).
     #[clap(long)]
     pub no_gui: bool,
@@ -243,6 +275,18 @@ pub struct Opt {
     /// (like inlining constant pool entries) can't be disabled.
     #[clap(long)]
     pub no_avm2_optimizer: bool,
+
+    /// Listen for AVM1 debugger clients on the given address, for example 127.0.0.1:7936.
+    /// Clients exchange newline-delimited JSON messages with the player, allowing them to
+    /// set breakpoints, step through actions and inspect registers, scopes and variables.
+    #[clap(long, value_name = "ADDRESS")]
+    pub avm1_debugger: Option<SocketAddr>,
+
+    /// Listen for AVM2 debugger clients on the given address, for example 127.0.0.1:7935.
+    /// Clients exchange newline-delimited JSON messages with the player, allowing them to
+    /// set breakpoints, step through code and inspect the call stack and variables.
+    #[clap(long, value_name = "ADDRESS")]
+    pub avm2_debugger: Option<SocketAddr>,
 }
 
 fn parse_movie_file_or_url(path: &str) -> Result<Url, Error> {
@@ -501,3 +545,33 @@ pub enum FilesystemAccessMode {
     /// Ask the user before accessing the filesystem non-interactively.
     Ask,
 }
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum MicrophoneAccessMode {
+    /// Always allow capturing audio from the microphone.
+    Allow,
+
+    /// Keep the microphone muted.
+    Deny,
+
+    /// Ask the user the first time a movie wants to capture audio.
+    Ask,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum PaperSize {
+    /// US Letter, 8.5 by 11 inches.
+    Letter,
+
+    /// A4, 210 by 297 millimeters.
+    A4,
+}
+
+impl PaperSize {
+    pub fn print_job_settings(self) -> PrintJobSettings {
+        match self {
+            PaperSize::Letter => PrintJobSettings::LETTER,
+            PaperSize::A4 => PrintJobSettings::A4,
+        }
+    }
+}
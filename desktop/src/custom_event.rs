@@ -1,5 +1,6 @@
 //! Custom event type for desktop ruffle
 
+use ruffle_core::backend::ui::NativeWindowId;
 use ruffle_core::events::PlayerNotification;
 use ruffle_frontend_utils::content::ContentDescriptor;
 
@@ -48,4 +49,7 @@ pub enum RuffleEvent {
 
     /// Export Ruffle Bundle from currently playing content and open save dialog.
     ExportBundle,
+
+    /// The movie opened a window, which needs to be created.
+    OpenNativeWindow(NativeWindowId),
 }
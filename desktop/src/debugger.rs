@@ -0,0 +1,124 @@
+//! TCP transport for the AVM1 and AVM2 step debuggers.
+//!
+//! A single client at a time can connect to the configured address and
+//! exchange newline-delimited JSON messages with the player. The protocols
+//! themselves are documented in the `avm1::debugger` and `avm2::debugger`
+//! modules of `ruffle_core`.
+
+use ruffle_core::DebuggerConnection;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// How often to check whether the player or the client went away.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Sent on behalf of a client that disconnected, so that the player doesn't
+/// stay paused forever and the next client doesn't inherit its breakpoints.
+const DETACH_COMMAND: &str = r#"{"command":"detach"}"#;
+
+/// Start listening for clients of the debugger of the given VM (`"AVM1"` or
+/// `"AVM2"`) on the given address.
+///
+/// The listener stops once the player owning the returned connection is
+/// dropped.
+pub fn listen(vm: &'static str, address: SocketAddr) -> std::io::Result<DebuggerConnection> {
+    let listener = TcpListener::bind(address)?;
+    listener.set_nonblocking(true)?;
+    tracing::info!("{vm} debugger listening on {address}");
+
+    let (command_sender, commands) = mpsc::channel();
+    let (events, event_receiver) = mpsc::channel();
+
+    thread::Builder::new()
+        .name(format!("{vm} debugger"))
+        .spawn(move || {
+            loop {
+                match listener.accept() {
+                    Ok((stream, peer)) => {
+                        tracing::info!("{vm} debugger client connected from {peer}");
+                        let player_alive = serve(vm, stream, &command_sender, &event_receiver);
+                        tracing::info!("{vm} debugger client disconnected");
+                        if !player_alive {
+                            return;
+                        }
+                    }
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                        // Nobody is there to read events, so drop them.
+                        if let Err(RecvTimeoutError::Disconnected) =
+                            event_receiver.recv_timeout(POLL_INTERVAL)
+                        {
+                            return;
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("{vm} debugger stopped listening: {e}");
+                        return;
+                    }
+                }
+            }
+        })?;
+
+    Ok(DebuggerConnection { commands, events })
+}
+
+/// Forward messages between a client and the player until either of them
+/// goes away. Returns `false` if the player went away.
+fn serve(
+    vm: &str,
+    stream: TcpStream,
+    commands: &Sender<String>,
+    events: &Receiver<String>,
+) -> bool {
+    let reader = match stream
+        .set_nonblocking(false)
+        .and_then(|_| stream.try_clone())
+    {
+        Ok(reader) => reader,
+        Err(e) => {
+            tracing::error!("Couldn't set up {vm} debugger client: {e}");
+            return true;
+        }
+    };
+
+    // Don't deliver replies meant for a previous client.
+    while events.try_recv().is_ok() {}
+
+    let commands = commands.clone();
+    let reader_thread = thread::spawn(move || {
+        for line in BufReader::new(reader).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+
+            if !line.trim().is_empty() && commands.send(line).is_err() {
+                return;
+            }
+        }
+
+        let _ = commands.send(DETACH_COMMAND.to_string());
+    });
+
+    let mut writer = stream;
+    let mut player_alive = true;
+    while !reader_thread.is_finished() {
+        match events.recv_timeout(POLL_INTERVAL) {
+            Ok(event) => {
+                if writeln!(writer, "{event}").is_err() {
+                    break;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                player_alive = false;
+                break;
+            }
+        }
+    }
+
+    // This also unblocks the reader thread if it's still waiting for input.
+    let _ = writer.shutdown(Shutdown::Both);
+    player_alive
+}
@@ -3,11 +3,14 @@ mod bookmarks_dialog;
 pub mod export_bundle_dialog;
 pub mod filesystem_access_dialog;
 pub mod message_dialog;
+pub mod microphone_access_dialog;
+pub mod native_process_dialog;
 pub mod network_access_dialog;
 mod open_dialog;
 mod open_url_dialog;
 mod preferences_dialog;
 pub mod select_path_dialog;
+mod shared_objects_dialog;
 mod volume_controls;
 
 use crate::custom_event::RuffleEvent;
@@ -17,6 +20,8 @@ use bookmarks_dialog::{BookmarkAddDialog, BookmarksDialog};
 use export_bundle_dialog::{ExportBundleDialog, ExportBundleDialogConfiguration};
 use filesystem_access_dialog::{FilesystemAccessDialog, FilesystemAccessDialogConfiguration};
 use message_dialog::{MessageDialog, MessageDialogConfiguration};
+use microphone_access_dialog::{MicrophoneAccessDialog, MicrophoneAccessDialogConfiguration};
+use native_process_dialog::{NativeProcessDialog, NativeProcessDialogConfiguration};
 use network_access_dialog::{NetworkAccessDialog, NetworkAccessDialogConfiguration};
 use open_dialog::OpenDialog;
 use open_url_dialog::OpenUrlDialog;
@@ -24,6 +29,7 @@ use preferences_dialog::PreferencesDialog;
 use ruffle_core::Player;
 use ruffle_frontend_utils::content::ContentDescriptor;
 use select_path_dialog::{SelectPathDialog, SelectPathDialogConfiguration};
+use shared_objects_dialog::SharedObjectsDialog;
 use std::{collections::VecDeque, sync::Weak};
 use unic_langid::LanguageIdentifier;
 use volume_controls::VolumeControls;
@@ -42,6 +48,7 @@ pub struct Dialogs {
     message_dialog: Option<MessageDialog>,
     export_bundle_dialog: Option<ExportBundleDialog>,
     pick_path_dialog: Option<SelectPathDialog>,
+    shared_objects_dialog: Option<SharedObjectsDialog>,
 
     // Use a queue for the following dialogs in order to:
     //  1. support handling multiple instances of them,
@@ -50,6 +57,9 @@ pub struct Dialogs {
     network_access_dialog_queue: VecDeque<NetworkAccessDialog>,
     filesystem_access_dialog: Option<FilesystemAccessDialog>,
     filesystem_access_dialog_queue: VecDeque<FilesystemAccessDialogConfiguration>,
+    native_process_dialog: Option<NativeProcessDialog>,
+    native_process_dialog_queue: VecDeque<NativeProcessDialogConfiguration>,
+    microphone_access_dialog: Option<MicrophoneAccessDialog>,
 
     open_dialog: OpenDialog,
     is_open_dialog_visible: bool,
@@ -67,6 +77,8 @@ pub enum DialogDescriptor {
     ShowMessage(MessageDialogConfiguration),
     NetworkAccess(NetworkAccessDialogConfiguration),
     FilesystemAccess(FilesystemAccessDialogConfiguration),
+    NativeProcess(NativeProcessDialogConfiguration),
+    MicrophoneAccess(MicrophoneAccessDialogConfiguration),
     ExportBundle(Box<ExportBundleDialogConfiguration>),
     SelectPath(SelectPathDialogConfiguration),
 }
@@ -88,10 +100,14 @@ impl Dialogs {
             message_dialog: None,
             export_bundle_dialog: None,
             pick_path_dialog: None,
+            shared_objects_dialog: None,
 
             network_access_dialog_queue: VecDeque::new(),
             filesystem_access_dialog: None,
             filesystem_access_dialog_queue: VecDeque::new(),
+            native_process_dialog: None,
+            native_process_dialog_queue: VecDeque::new(),
+            microphone_access_dialog: None,
 
             open_dialog: OpenDialog::new(
                 player_options,
@@ -124,6 +140,9 @@ impl Dialogs {
         self.network_access_dialog_queue.clear();
         self.filesystem_access_dialog = None;
         self.filesystem_access_dialog_queue.clear();
+        self.native_process_dialog = None;
+        self.native_process_dialog_queue.clear();
+        self.microphone_access_dialog = None;
     }
 
     pub fn recreate_open_dialog(
@@ -161,6 +180,15 @@ impl Dialogs {
         ))
     }
 
+    pub fn open_shared_objects(&mut self, movie_url: Option<&url::Url>) {
+        self.shared_objects_dialog = Some(SharedObjectsDialog::new(
+            self.preferences.clone(),
+            self.picker.clone(),
+            self.saved_launch_options().save_directory.clone(),
+            movie_url,
+        ));
+    }
+
     pub fn open_volume_controls(&mut self) {
         self.is_volume_visible = true;
     }
@@ -191,6 +219,12 @@ impl Dialogs {
             DialogDescriptor::FilesystemAccess(config) => {
                 self.filesystem_access_dialog_queue.push_back(config)
             }
+            DialogDescriptor::NativeProcess(config) => {
+                self.native_process_dialog_queue.push_back(config)
+            }
+            DialogDescriptor::MicrophoneAccess(config) => {
+                self.microphone_access_dialog = Some(MicrophoneAccessDialog::new(config));
+            }
             DialogDescriptor::ExportBundle(config) => {
                 self.export_bundle_dialog =
                     Some(ExportBundleDialog::new(*config, self.file_picker()))
@@ -217,8 +251,11 @@ impl Dialogs {
         self.show_message_dialog(locale, egui_ctx);
         self.show_network_access_dialog(locale, egui_ctx);
         self.show_filesystem_access_dialog(locale, egui_ctx);
+        self.show_native_process_dialog(locale, egui_ctx);
+        self.show_microphone_access_dialog(locale, egui_ctx);
         self.show_export_bundle_dialog(locale, egui_ctx);
         self.show_pick_path_dialog(locale, egui_ctx);
+        self.show_shared_objects_dialog(locale, egui_ctx);
     }
 
     fn show_open_dialog(&mut self, locale: &LanguageIdentifier, egui_ctx: &egui::Context) {
@@ -250,6 +287,21 @@ impl Dialogs {
         }
     }
 
+    fn show_shared_objects_dialog(
+        &mut self,
+        locale: &LanguageIdentifier,
+        egui_ctx: &egui::Context,
+    ) {
+        let keep_open = if let Some(dialog) = &mut self.shared_objects_dialog {
+            dialog.show(locale, egui_ctx)
+        } else {
+            true
+        };
+        if !keep_open {
+            self.shared_objects_dialog = None;
+        }
+    }
+
     fn show_bookmark_add_dialog(&mut self, locale: &LanguageIdentifier, egui_ctx: &egui::Context) {
         let keep_open = if let Some(dialog) = &mut self.bookmark_add_dialog {
             dialog.show(locale, egui_ctx)
@@ -341,6 +393,43 @@ impl Dialogs {
         }
     }
 
+    fn show_native_process_dialog(
+        &mut self,
+        locale: &LanguageIdentifier,
+        egui_ctx: &egui::Context,
+    ) {
+        let keep_open = if let Some(dialog) = &mut self.native_process_dialog {
+            dialog.show(locale, egui_ctx)
+        } else {
+            true
+        };
+        if !keep_open {
+            self.native_process_dialog = None;
+        }
+
+        if self.native_process_dialog.is_none() {
+            self.native_process_dialog = self
+                .native_process_dialog_queue
+                .pop_front()
+                .map(NativeProcessDialog::new);
+        }
+    }
+
+    fn show_microphone_access_dialog(
+        &mut self,
+        locale: &LanguageIdentifier,
+        egui_ctx: &egui::Context,
+    ) {
+        let keep_open = if let Some(dialog) = &mut self.microphone_access_dialog {
+            dialog.show(locale, egui_ctx)
+        } else {
+            true
+        };
+        if !keep_open {
+            self.microphone_access_dialog = None;
+        }
+    }
+
     fn show_export_bundle_dialog(&mut self, locale: &LanguageIdentifier, egui_ctx: &egui::Context) {
         let keep_open = if let Some(dialog) = &mut self.export_bundle_dialog {
             dialog.show(locale, egui_ctx)
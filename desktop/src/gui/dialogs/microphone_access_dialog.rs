@@ -0,0 +1,87 @@
+use crate::gui::text;
+use egui::{Align2, Ui, Window};
+use tokio::sync::oneshot::Sender;
+use unic_langid::LanguageIdentifier;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum MicrophoneAccessDialogResult {
+    Allow,
+    Deny,
+}
+
+pub struct MicrophoneAccessDialogConfiguration {
+    notifier: Option<Sender<MicrophoneAccessDialogResult>>,
+}
+
+impl MicrophoneAccessDialogConfiguration {
+    pub fn new(notifier: Sender<MicrophoneAccessDialogResult>) -> Self {
+        Self {
+            notifier: Some(notifier),
+        }
+    }
+}
+
+pub struct MicrophoneAccessDialog {
+    config: MicrophoneAccessDialogConfiguration,
+}
+
+impl Drop for MicrophoneAccessDialog {
+    fn drop(&mut self) {
+        self.respond(MicrophoneAccessDialogResult::Deny);
+    }
+}
+
+impl MicrophoneAccessDialog {
+    pub fn new(config: MicrophoneAccessDialogConfiguration) -> Self {
+        Self { config }
+    }
+
+    fn respond(&mut self, result: MicrophoneAccessDialogResult) {
+        if let Some(notifier) = std::mem::take(&mut self.config.notifier) {
+            let _ = notifier.send(result);
+        }
+    }
+
+    pub fn show(&mut self, locale: &LanguageIdentifier, egui_ctx: &egui::Context) -> bool {
+        let mut keep_open = true;
+        let mut should_close = false;
+
+        Window::new(text(locale, "microphone-access-dialog-title"))
+            .open(&mut keep_open)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .collapsible(false)
+            .resizable(false)
+            .show(egui_ctx, |ui| {
+                should_close = self.render_window_contents(locale, ui);
+            });
+
+        keep_open && !should_close
+    }
+
+    pub fn render_window_contents(&mut self, locale: &LanguageIdentifier, ui: &mut Ui) -> bool {
+        let mut should_close = false;
+
+        ui.label(text(locale, "microphone-access-dialog-message"));
+        ui.label("");
+
+        ui.horizontal(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .button(text(locale, "microphone-access-dialog-allow"))
+                    .clicked()
+                {
+                    self.respond(MicrophoneAccessDialogResult::Allow);
+                    should_close = true;
+                }
+                if ui
+                    .button(text(locale, "microphone-access-dialog-deny"))
+                    .clicked()
+                {
+                    should_close = true;
+                }
+            })
+        });
+
+        should_close
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{backends::PathAllowList, gui::text};
+use egui::{Align2, Ui, Window};
+use std::path::PathBuf;
+use tokio::sync::oneshot::Sender;
+use unic_langid::LanguageIdentifier;
+
+#[derive(PartialEq, Eq, Clone)]
+pub enum NativeProcessDialogResult {
+    Allow,
+    Cancel,
+}
+
+pub struct NativeProcessDialogConfiguration {
+    notifier: Option<Sender<NativeProcessDialogResult>>,
+
+    /// Collection of already allowed programs that can be updated.
+    allow_list: PathAllowList,
+
+    /// Path of the program to run.
+    executable: PathBuf,
+
+    /// Arguments the program is run with.
+    arguments: Vec<String>,
+}
+
+impl NativeProcessDialogConfiguration {
+    pub fn new(
+        notifier: Sender<NativeProcessDialogResult>,
+        allow_list: PathAllowList,
+        executable: PathBuf,
+        arguments: Vec<String>,
+    ) -> Self {
+        Self {
+            notifier: Some(notifier),
+            allow_list,
+            executable,
+            arguments,
+        }
+    }
+}
+
+pub struct NativeProcessDialog {
+    config: NativeProcessDialogConfiguration,
+
+    /// Whether the user already allowed this program to run.
+    allowed: bool,
+
+    /// Whether the user wants to allow the program to run again without asking.
+    remember_access: bool,
+}
+
+impl Drop for NativeProcessDialog {
+    fn drop(&mut self) {
+        self.respond(NativeProcessDialogResult::Cancel);
+    }
+}
+
+impl NativeProcessDialog {
+    pub fn new(config: NativeProcessDialogConfiguration) -> Self {
+        // This check needs to be done as late as possible, because we want the
+        // user's decision to apply to every future dialog,
+        // not only those requested after the decision.
+        let allowed = config.allow_list.is_path_allowed(&config.executable);
+
+        let mut dialog = Self {
+            config,
+            allowed,
+            remember_access: false,
+        };
+        if allowed {
+            dialog.respond(NativeProcessDialogResult::Allow);
+        }
+        dialog
+    }
+
+    fn respond(&mut self, result: NativeProcessDialogResult) {
+        if let Some(notifier) = std::mem::take(&mut self.config.notifier) {
+            let _ = notifier.send(result);
+        }
+    }
+
+    pub fn show(&mut self, locale: &LanguageIdentifier, egui_ctx: &egui::Context) -> bool {
+        if self.allowed {
+            return false;
+        }
+
+        let mut keep_open = true;
+        let mut should_close = false;
+
+        Window::new(text(locale, "native-process-dialog-title"))
+            .open(&mut keep_open)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .collapsible(false)
+            .resizable(false)
+            .show(egui_ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    should_close = self.render_window_contents(locale, ui)
+                });
+            });
+
+        keep_open && !should_close
+    }
+
+    pub fn render_window_contents(&mut self, locale: &LanguageIdentifier, ui: &mut Ui) -> bool {
+        let mut should_close = false;
+
+        ui.label(text(locale, "native-process-dialog-message"));
+        ui.label("");
+        ui.monospace(self.config.executable.to_string_lossy());
+        for argument in &self.config.arguments {
+            ui.monospace(format!("  {argument}"));
+        }
+        ui.label("");
+
+        ui.checkbox(
+            &mut self.remember_access,
+            text(locale, "native-process-dialog-allow-remember-message"),
+        );
+        ui.label("");
+
+        ui.horizontal(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let primary_text = if self.remember_access {
+                    text(locale, "native-process-dialog-allow-remember")
+                } else {
+                    text(locale, "native-process-dialog-allow")
+                };
+                if ui.button(primary_text).clicked() {
+                    if self.remember_access {
+                        self.config
+                            .allow_list
+                            .add_allowed_path_prefix(self.config.executable.clone());
+                    }
+                    self.respond(NativeProcessDialogResult::Allow);
+                    should_close = true;
+                }
+                if ui.button(text(locale, "dialog-cancel")).clicked() {
+                    should_close = true;
+                }
+            })
+        });
+
+        should_close
+    }
+}
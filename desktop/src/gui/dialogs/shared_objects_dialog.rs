@@ -0,0 +1,925 @@
+use crate::gui::{FilePicker, text, text_with_args};
+use crate::preferences::GlobalPreferences;
+use crate::preferences::storage::StorageBackend;
+use egui::{
+    Align2, Button, Checkbox, CollapsingHeader, DragValue, Id, Label, Layout, Sense, Ui, Widget,
+    Window,
+};
+use egui_extras::{Column, TableBuilder};
+use flash_lso::types::{Lso, Value};
+use fluent_templates::fluent_bundle::FluentValue;
+use ruffle_core::backend::storage::shared_object_location;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use tokio::io::AsyncWriteExt;
+use unic_langid::LanguageIdentifier;
+use url::Url;
+use walkdir::WalkDir;
+
+/// A `.sol` file in the save directory.
+struct SharedObjectEntry {
+    /// The name of the shared object, that is its path relative to the save directory,
+    /// without the extension.
+    name: String,
+    domain: String,
+    size: u64,
+}
+
+fn find_shared_objects(directory: &Path) -> Vec<SharedObjectEntry> {
+    let mut entries: Vec<_> = WalkDir::new(directory)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|extension| extension == "sol")
+        })
+        .filter_map(|entry| {
+            let relative_path = entry.path().strip_prefix(directory).ok()?;
+            let components: Vec<_> = relative_path
+                .with_extension("")
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            Some(SharedObjectEntry {
+                name: components.join("/"),
+                domain: components.first()?.clone(),
+                size: entry.metadata().map(|metadata| metadata.len()).unwrap_or(0),
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+/// The name a `.sol` file imported as new saved data of the movie at `movie_url` gets,
+/// matching what `SharedObject.getLocal(file_stem)` would use, or `None` if the movie
+/// couldn't use it.
+fn imported_name(movie_url: &Url, file_stem: &str) -> Option<String> {
+    let (host, movie_path) = shared_object_location(movie_url);
+    let name = format!("{host}/{movie_path}/{file_stem}");
+    // The core refuses names with such segments, see `SharedObject.getLocal`.
+    (!file_stem.is_empty() && !name.split('/').any(|s| s.starts_with('.'))).then_some(name)
+}
+
+struct SelectedSharedObject {
+    name: String,
+
+    /// The contents of the shared object, or `None` if they couldn't be read.
+    lso: Option<Lso>,
+
+    /// Whether the contents are still being read.
+    loading: bool,
+
+    /// Whether the contents were edited since they were last saved.
+    modified: bool,
+}
+
+/// The result of the last action on the saved data.
+enum Status {
+    Saved,
+    Deleted,
+    Imported,
+    Exported,
+    InvalidFile,
+    AlreadyExists,
+    EncodingFailed,
+    IoError(String),
+}
+
+/// The result of a file system operation, which is done in the background.
+enum Update {
+    /// The save directory was listed.
+    Entries(Vec<SharedObjectEntry>),
+
+    /// A shared object was read, its contents being `None` if they couldn't be parsed.
+    Loaded(String, Option<Lso>),
+
+    /// A shared object couldn't be saved, so its edits are still pending.
+    SaveFailed(String, Status),
+
+    /// A shared object was deleted.
+    Deleted(String),
+
+    /// A shared object was imported, and should be shown.
+    Imported(String),
+
+    /// Any other operation finished.
+    Done(Status),
+}
+
+impl From<std::io::Error> for Status {
+    fn from(error: std::io::Error) -> Self {
+        match error.kind() {
+            ErrorKind::AlreadyExists => Status::AlreadyExists,
+            _ => Status::IoError(error.to_string()),
+        }
+    }
+}
+
+fn parse(bytes: &[u8]) -> Option<Lso> {
+    flash_lso::read::Reader::default().parse(bytes).ok()
+}
+
+pub struct SharedObjectsDialog {
+    picker: FilePicker,
+    preferences: GlobalPreferences,
+
+    /// Where shared objects are stored, see `DiskStorageBackend`.
+    directory: PathBuf,
+
+    /// The URL of the current movie, if there's one.
+    movie_url: Option<Url>,
+
+    /// The domain of the current movie, if there's one.
+    domain: Option<String>,
+    only_current_domain: bool,
+
+    entries: Vec<SharedObjectEntry>,
+    selected: Option<SelectedSharedObject>,
+    confirm_delete: bool,
+
+    status: Option<Status>,
+
+    /// Results of file system operations, which are applied on the next frame.
+    updates: Arc<Mutex<Vec<Update>>>,
+}
+
+impl SharedObjectsDialog {
+    pub fn new(
+        preferences: GlobalPreferences,
+        picker: FilePicker,
+        directory: PathBuf,
+        movie_url: Option<&Url>,
+    ) -> Self {
+        let domain = movie_url.map(|url| shared_object_location(url).0.to_string());
+        let dialog = Self {
+            picker,
+            preferences,
+            directory,
+            movie_url: movie_url.cloned(),
+            only_current_domain: domain.is_some(),
+            domain,
+            entries: Vec::new(),
+            selected: None,
+            confirm_delete: false,
+            status: None,
+            updates: Arc::new(Mutex::new(Vec::new())),
+        };
+        dialog.refresh();
+        dialog
+    }
+
+    fn path_of(&self, name: &str) -> PathBuf {
+        self.directory.join(format!("{name}.sol"))
+    }
+
+    /// Runs a file system operation in the background, applying its result once it's done.
+    fn spawn(&self, operation: impl Future<Output = Option<Update>> + Send + 'static) {
+        let updates = self.updates.clone();
+        tokio::spawn(async move {
+            if let Some(update) = operation.await {
+                updates.lock().expect("Non-poisoned updates").push(update);
+            }
+        });
+    }
+
+    fn refresh(&self) {
+        let directory = self.directory.clone();
+        self.spawn(async move {
+            tokio::task::spawn_blocking(move || find_shared_objects(&directory))
+                .await
+                .ok()
+                .map(Update::Entries)
+        });
+    }
+
+    fn select(&mut self, name: String) {
+        let path = self.path_of(&name);
+        self.spawn({
+            let name = name.clone();
+            async move {
+                let lso = tokio::fs::read(path)
+                    .await
+                    .ok()
+                    .and_then(|bytes| parse(&bytes));
+                Some(Update::Loaded(name, lso))
+            }
+        });
+        self.selected = Some(SelectedSharedObject {
+            name,
+            lso: None,
+            loading: true,
+            modified: false,
+        });
+        self.confirm_delete = false;
+        self.status = None;
+    }
+
+    fn apply_updates(&mut self) {
+        let updates = std::mem::take(&mut *self.updates.lock().expect("Non-poisoned updates"));
+        for update in updates {
+            match update {
+                Update::Entries(entries) => self.entries = entries,
+                Update::Loaded(name, lso) => {
+                    if let Some(selected) = &mut self.selected
+                        && selected.name == name
+                        && selected.loading
+                    {
+                        selected.lso = lso;
+                        selected.loading = false;
+                    }
+                }
+                Update::SaveFailed(name, status) => {
+                    if let Some(selected) = &mut self.selected
+                        && selected.name == name
+                    {
+                        selected.modified = true;
+                    }
+                    self.status = Some(status);
+                }
+                Update::Deleted(name) => {
+                    if self
+                        .selected
+                        .as_ref()
+                        .is_some_and(|selected| selected.name == name)
+                    {
+                        self.selected = None;
+                    }
+                    self.status = Some(Status::Deleted);
+                    self.refresh();
+                }
+                Update::Imported(name) => {
+                    self.refresh();
+                    self.select(name);
+                    self.status = Some(Status::Imported);
+                }
+                Update::Done(status) => {
+                    if matches!(status, Status::Saved) {
+                        self.refresh();
+                    }
+                    self.status = Some(status);
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, locale: &LanguageIdentifier, egui_ctx: &egui::Context) -> bool {
+        let mut keep_open = true;
+
+        self.apply_updates();
+
+        Window::new(text(locale, "shared-objects-dialog"))
+            .open(&mut keep_open)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(600.0)
+            .default_height(450.0)
+            .show(egui_ctx, |ui| {
+                egui::TopBottomPanel::top("shared-objects-dialog-top-panel")
+                    .resizable(true)
+                    .min_height(120.0)
+                    .show_inside(ui, |ui| {
+                        self.show_filter(locale, ui);
+                        if self.visible_entries().next().is_some() {
+                            self.show_entry_table(locale, ui);
+                        } else {
+                            ui.centered_and_justified(|ui| {
+                                ui.label(text(locale, "shared-objects-dialog-no-entries"));
+                            });
+                        }
+                    });
+
+                if let Some(status) = &self.status {
+                    egui::TopBottomPanel::bottom("shared-objects-dialog-status-panel").show_inside(
+                        ui,
+                        |ui| {
+                            ui.label(status_text(locale, status));
+                        },
+                    );
+                }
+
+                self.show_entry_panel(locale, ui);
+            });
+
+        keep_open
+    }
+
+    fn visible_entries(&self) -> impl Iterator<Item = &SharedObjectEntry> {
+        let domain = self.domain.as_deref().filter(|_| self.only_current_domain);
+        self.entries
+            .iter()
+            .filter(move |entry| domain.is_none_or(|domain| entry.domain == domain))
+    }
+
+    fn show_filter(&mut self, locale: &LanguageIdentifier, ui: &mut Ui) {
+        if self.preferences.storage_backend() == StorageBackend::Memory {
+            ui.label(text(locale, "shared-objects-dialog-memory-storage"));
+        }
+
+        ui.horizontal(|ui| {
+            if let Some(domain) = &self.domain {
+                Checkbox::new(
+                    &mut self.only_current_domain,
+                    text_with_args(
+                        locale,
+                        "shared-objects-dialog-only-current-domain",
+                        &HashMap::from([(
+                            "domain".into(),
+                            FluentValue::String(domain.clone().into()),
+                        )]),
+                    ),
+                )
+                .ui(ui);
+            }
+
+            ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                if Button::new(text(locale, "shared-objects-dialog-refresh"))
+                    .ui(ui)
+                    .clicked()
+                {
+                    self.refresh();
+                }
+                if ui
+                    .add_enabled(
+                        self.movie_url.is_some(),
+                        Button::new(text(locale, "shared-objects-dialog-import-new")),
+                    )
+                    .on_disabled_hover_text(text(
+                        locale,
+                        "shared-objects-dialog-import-new-no-movie",
+                    ))
+                    .clicked()
+                {
+                    self.import_new(locale);
+                }
+            });
+        });
+    }
+
+    fn show_entry_table(&mut self, locale: &LanguageIdentifier, ui: &mut Ui) {
+        let text_height = egui::TextStyle::Body
+            .resolve(ui.style())
+            .size
+            .max(ui.spacing().interact_size.y);
+
+        let mut clicked = None;
+
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .column(Column::auto())
+            .column(Column::remainder())
+            .column(Column::auto())
+            .sense(Sense::click())
+            .header(20.0, |mut header| {
+                header.col(|ui| {
+                    ui.strong(text(locale, "shared-objects-dialog-domain"));
+                });
+                header.col(|ui| {
+                    ui.strong(text(locale, "shared-objects-dialog-name"));
+                });
+                header.col(|ui| {
+                    ui.strong(text(locale, "shared-objects-dialog-size"));
+                });
+            })
+            .body(|mut body| {
+                for entry in self.visible_entries() {
+                    body.row(text_height, |mut row| {
+                        if let Some(selected) = &self.selected {
+                            row.set_selected(entry.name == selected.name);
+                        }
+
+                        let name = entry
+                            .name
+                            .strip_prefix(&entry.domain)
+                            .unwrap_or(&entry.name)
+                            .trim_start_matches('/');
+                        for label in [entry.domain.as_str(), name, &entry.size.to_string()] {
+                            row.col(|ui| {
+                                ui.add(
+                                    Label::new(label)
+                                        .selectable(false)
+                                        .wrap_mode(egui::TextWrapMode::Extend),
+                                );
+                            });
+                        }
+
+                        if row.response().clicked() {
+                            clicked = Some(entry.name.clone());
+                        }
+                    });
+                }
+            });
+
+        if let Some(name) = clicked {
+            self.select(name);
+        }
+    }
+
+    fn show_entry_panel(&mut self, locale: &LanguageIdentifier, ui: &mut Ui) {
+        let Some(selected) = &mut self.selected else {
+            ui.vertical_centered_justified(|ui| {
+                ui.label(text(locale, "shared-objects-dialog-not-selected"));
+            });
+            return;
+        };
+
+        egui::TopBottomPanel::bottom("shared-objects-dialog-bottom-panel").show_inside(ui, |ui| {
+            ui.add(
+                Label::new(text(locale, "shared-objects-dialog-running-movie-warning"))
+                    .wrap_mode(egui::TextWrapMode::Wrap),
+            );
+            ui.separator();
+        });
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, true])
+            .max_height(ui.available_height() - 40.0)
+            .show(ui, |ui| match &mut selected.lso {
+                _ if selected.loading => {
+                    ui.spinner();
+                }
+                Some(lso) => {
+                    let id = Id::new("shared-objects-dialog-tree").with(&selected.name);
+                    for element in &mut lso.body {
+                        selected.modified |= show_value(
+                            ui,
+                            id.with(&element.name),
+                            &element.name,
+                            &mut element.value,
+                        );
+                    }
+                }
+                None => {
+                    ui.label(text(locale, "shared-objects-dialog-unreadable"));
+                }
+            });
+
+        ui.separator();
+        ui.horizontal(|ui| self.show_entry_actions(locale, ui));
+    }
+
+    fn show_entry_actions(&mut self, locale: &LanguageIdentifier, ui: &mut Ui) {
+        let Some(selected) = &self.selected else {
+            return;
+        };
+
+        if self.confirm_delete {
+            ui.label(text(locale, "shared-objects-dialog-confirm-delete"));
+            ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                if Button::new(text(locale, "cancel")).ui(ui).clicked() {
+                    self.confirm_delete = false;
+                }
+                if Button::new(text(locale, "shared-objects-dialog-delete"))
+                    .ui(ui)
+                    .clicked()
+                {
+                    self.delete();
+                }
+            });
+            return;
+        }
+
+        let (modified, readable) = (selected.modified, selected.lso.is_some());
+        if ui
+            .add_enabled(modified, Button::new(text(locale, "save")))
+            .clicked()
+        {
+            self.save();
+        }
+        if ui
+            .add_enabled(
+                modified,
+                Button::new(text(locale, "shared-objects-dialog-revert")),
+            )
+            .clicked()
+            && let Some(selected) = self.selected.take()
+        {
+            self.select(selected.name);
+        }
+
+        ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+            if Button::new(text(locale, "shared-objects-dialog-delete"))
+                .ui(ui)
+                .clicked()
+            {
+                self.confirm_delete = true;
+            }
+            if ui
+                .add_enabled(
+                    readable,
+                    Button::new(text(locale, "shared-objects-dialog-export")),
+                )
+                .clicked()
+            {
+                self.export(locale);
+            }
+            if Button::new(text(locale, "shared-objects-dialog-import"))
+                .ui(ui)
+                .clicked()
+            {
+                self.import(locale);
+            }
+        });
+    }
+
+    fn save(&mut self) {
+        let Some(selected) = &mut self.selected else {
+            return;
+        };
+        let Some(lso) = &mut selected.lso else {
+            return;
+        };
+        let Ok(bytes) = flash_lso::write::write_to_bytes(lso) else {
+            self.status = Some(Status::EncodingFailed);
+            return;
+        };
+
+        // Further edits made while saving are saved separately.
+        selected.modified = false;
+        let name = selected.name.clone();
+        let path = self.path_of(&name);
+        self.spawn(async move {
+            Some(match tokio::fs::write(path, bytes).await {
+                Ok(()) => Update::Done(Status::Saved),
+                Err(e) => Update::SaveFailed(name, e.into()),
+            })
+        });
+    }
+
+    fn delete(&mut self) {
+        let Some(selected) = &self.selected else {
+            return;
+        };
+        self.confirm_delete = false;
+
+        let name = selected.name.clone();
+        let path = self.path_of(&name);
+        self.spawn(async move {
+            Some(match tokio::fs::remove_file(path).await {
+                Ok(()) => Update::Deleted(name),
+                Err(e) => {
+                    tracing::warn!("Couldn't delete shared object {name}: {e}");
+                    Update::Done(e.into())
+                }
+            })
+        });
+    }
+
+    fn pick_sol_file(
+        &self,
+        locale: &LanguageIdentifier,
+        title: &'static str,
+    ) -> Option<impl Future<Output = Option<rfd::FileHandle>> + Send + 'static> {
+        let dialog = rfd::AsyncFileDialog::new()
+            .add_filter(text(locale, "shared-objects-dialog-filter-sol"), &["sol"])
+            .set_title(text(locale, title));
+        self.picker.show_dialog(dialog, |d| d.pick_file())
+    }
+
+    /// Replaces the selected shared object with a `.sol` file chosen by the user.
+    fn import(&mut self, locale: &LanguageIdentifier) {
+        let Some(selected) = &self.selected else {
+            return;
+        };
+        let Some(selected_file) = self.pick_sol_file(locale, "shared-objects-dialog-import") else {
+            return;
+        };
+
+        let name = selected.name.clone();
+        let path = self.path_of(&name);
+        self.spawn(async move {
+            let handle = selected_file.await?;
+            Some(match copy_sol_file(handle.path(), &path, true).await {
+                Ok(true) => Update::Imported(name),
+                Ok(false) => Update::Done(Status::InvalidFile),
+                Err(e) => Update::Done(e.into()),
+            })
+        });
+    }
+
+    /// Adds a `.sol` file chosen by the user to the saved data of the current movie, under
+    /// the name the movie would load it with.
+    fn import_new(&mut self, locale: &LanguageIdentifier) {
+        let Some(movie_url) = self.movie_url.clone() else {
+            return;
+        };
+        let Some(selected_file) = self.pick_sol_file(locale, "shared-objects-dialog-import-new")
+        else {
+            return;
+        };
+
+        let directory = self.directory.clone();
+        self.spawn(async move {
+            let handle = selected_file.await?;
+            let file_stem = handle.path().file_stem()?.to_string_lossy().into_owned();
+            let Some(name) = imported_name(&movie_url, &file_stem) else {
+                return Some(Update::Done(Status::InvalidFile));
+            };
+            let path = directory.join(format!("{name}.sol"));
+            Some(match copy_sol_file(handle.path(), &path, false).await {
+                Ok(true) => Update::Imported(name),
+                Ok(false) => Update::Done(Status::InvalidFile),
+                Err(e) => Update::Done(e.into()),
+            })
+        });
+    }
+
+    /// Writes the selected shared object, including unsaved edits, to a `.sol` file chosen
+    /// by the user.
+    fn export(&mut self, locale: &LanguageIdentifier) {
+        let Some(selected) = &mut self.selected else {
+            return;
+        };
+        let Some(lso) = &mut selected.lso else {
+            return;
+        };
+        let Ok(bytes) = flash_lso::write::write_to_bytes(lso) else {
+            self.status = Some(Status::EncodingFailed);
+            return;
+        };
+
+        let file_name = selected.name.rsplit('/').next().unwrap_or_default();
+        let dialog = rfd::AsyncFileDialog::new()
+            .add_filter(text(locale, "shared-objects-dialog-filter-sol"), &["sol"])
+            .set_title(text(locale, "shared-objects-dialog-export"))
+            .set_file_name(format!("{file_name}.sol"));
+        let Some(selected_file) = self.picker.show_dialog(dialog, |d| d.save_file()) else {
+            return;
+        };
+
+        self.spawn(async move {
+            let handle = selected_file.await?;
+            Some(Update::Done(
+                match tokio::fs::write(handle.path(), bytes).await {
+                    Ok(()) => Status::Exported,
+                    Err(e) => e.into(),
+                },
+            ))
+        });
+    }
+}
+
+/// Copies a `.sol` file into the save directory as it is, returning `false` without copying
+/// it if Flash Player couldn't read it. Unless `replace` is set, an existing shared object
+/// is never overwritten.
+async fn copy_sol_file(source: &Path, destination: &Path, replace: bool) -> std::io::Result<bool> {
+    let bytes = tokio::fs::read(source).await?;
+    if parse(&bytes).is_none() {
+        return Ok(false);
+    }
+    if let Some(parent) = destination.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut options = tokio::fs::OpenOptions::new();
+    if replace {
+        options.write(true).create(true).truncate(true);
+    } else {
+        options.write(true).create_new(true);
+    }
+    options.open(destination).await?.write_all(&bytes).await?;
+    Ok(true)
+}
+
+fn status_text(locale: &LanguageIdentifier, status: &Status) -> Cow<'static, str> {
+    let id = match status {
+        Status::Saved => "shared-objects-dialog-saved",
+        Status::Deleted => "shared-objects-dialog-deleted",
+        Status::Imported => "shared-objects-dialog-imported",
+        Status::Exported => "shared-objects-dialog-exported",
+        Status::InvalidFile => "shared-objects-dialog-invalid-file",
+        Status::AlreadyExists => "shared-objects-dialog-already-exists",
+        Status::EncodingFailed => "shared-objects-dialog-encoding-failed",
+        Status::IoError(error) => {
+            return text_with_args(
+                locale,
+                "shared-objects-dialog-io-error",
+                &HashMap::from([("error".into(), FluentValue::String(error.clone().into()))]),
+            );
+        }
+    };
+    text(locale, id)
+}
+
+/// The ActionScript type of a value.
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Number(_) => "Number",
+        Value::Integer(_) => "int",
+        Value::Bool(_) => "Boolean",
+        Value::String(_) => "String",
+        Value::Null => "null",
+        Value::Undefined | Value::Unsupported => "undefined",
+        Value::Date(..) => "Date",
+        Value::XML(..) => "XML",
+        Value::ByteArray(_) => "ByteArray",
+        Value::ECMAArray(..) | Value::StrictArray(..) => "Array",
+        Value::Object(..) | Value::Custom(..) => "Object",
+        Value::Dictionary(..) => "flash.utils.Dictionary",
+        Value::VectorInt(..) => "Vector.<int>",
+        Value::VectorUInt(..) => "Vector.<uint>",
+        Value::VectorDouble(..) => "Vector.<Number>",
+        Value::VectorObject(..) => "Vector.<*>",
+        Value::Reference(_) | Value::Amf3ObjectReference(_) => "Reference",
+        Value::AMF3(value) => type_name(value),
+    }
+}
+
+/// A one line description of a value, for values which can't be edited.
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Number(value) => value.to_string(),
+        Value::Integer(value) => value.to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::String(value) => format!("{value:?}"),
+        Value::Date(time, _) => format_date(*time),
+        Value::XML(content, _) => content.clone(),
+        Value::ByteArray(bytes) => format!("ByteArray ({} bytes)", bytes.len()),
+        Value::ECMAArray(_, dense, sparse, _) => format!("Array ({})", dense.len() + sparse.len()),
+        Value::StrictArray(_, values) => format!("Array ({})", values.len()),
+        Value::AMF3(value) => summary(value),
+        _ => type_name(value).to_string(),
+    }
+}
+
+fn format_date(time: f64) -> String {
+    chrono::DateTime::from_timestamp_millis(time as i64)
+        .map(|date| date.to_rfc3339())
+        .unwrap_or_else(|| time.to_string())
+}
+
+/// Shows a value as a tree, returning whether it was edited.
+fn show_value(ui: &mut Ui, id: Id, name: &str, value: &mut Rc<Value>) -> bool {
+    // Values referenced from several places can't be edited in only one of them.
+    let Some(value) = Rc::get_mut(value) else {
+        ui.label(format!("{name}: {}", summary(value)));
+        return false;
+    };
+
+    let label = format!("{name}: {}", type_name(value));
+    match value {
+        Value::Number(number) => show_leaf(ui, name, |ui| DragValue::new(number).ui(ui)),
+        Value::Integer(integer) => show_leaf(ui, name, |ui| {
+            // AMF3 integers are 29 bits long.
+            DragValue::new(integer)
+                .range(-(1 << 28)..=(1 << 28) - 1)
+                .ui(ui)
+        }),
+        Value::Bool(boolean) => show_leaf(ui, name, |ui| ui.checkbox(boolean, "")),
+        Value::String(string) => show_leaf(ui, name, |ui| ui.text_edit_singleline(string)),
+        Value::XML(content, _) => show_leaf(ui, name, |ui| ui.text_edit_multiline(content)),
+        Value::Date(time, _) => show_leaf(ui, name, |ui| {
+            let response = DragValue::new(time).ui(ui);
+            ui.label(format_date(*time));
+            response
+        }),
+        Value::AMF3(value) => show_value(ui, id, name, value),
+        Value::Object(_, elements, class) => {
+            let label = match class {
+                Some(class) if !class.name.is_empty() => format!("{name}: {}", class.name),
+                _ => label,
+            };
+            show_children(ui, id, label, |ui| {
+                let mut changed = false;
+                for element in elements {
+                    changed |= show_value(
+                        ui,
+                        id.with(&element.name),
+                        &element.name,
+                        &mut element.value,
+                    );
+                }
+                changed
+            })
+        }
+        Value::ECMAArray(_, dense, sparse, _) => show_children(ui, id, label, |ui| {
+            let mut changed = false;
+            for (index, value) in dense.iter_mut().enumerate() {
+                changed |= show_value(ui, id.with(index), &index.to_string(), value);
+            }
+            for element in sparse {
+                changed |= show_value(
+                    ui,
+                    id.with(&element.name),
+                    &element.name,
+                    &mut element.value,
+                );
+            }
+            changed
+        }),
+        Value::StrictArray(_, values) | Value::VectorObject(_, values, _, _) => {
+            show_children(ui, id, label, |ui| {
+                let mut changed = false;
+                for (index, value) in values.iter_mut().enumerate() {
+                    changed |= show_value(ui, id.with(index), &index.to_string(), value);
+                }
+                changed
+            })
+        }
+        Value::VectorInt(values, _) => show_numbers(ui, id, label, values),
+        Value::VectorUInt(values, _) => show_numbers(ui, id, label, values),
+        Value::VectorDouble(values, _) => show_numbers(ui, id, label, values),
+        Value::Dictionary(_, entries, _) => show_children(ui, id, label, |ui| {
+            let mut changed = false;
+            for (index, (key, value)) in entries.iter_mut().enumerate() {
+                changed |= show_value(ui, id.with(index), &summary(key), value);
+            }
+            changed
+        }),
+        _ => {
+            ui.label(format!("{name}: {}", summary(value)));
+            false
+        }
+    }
+}
+
+fn show_leaf(ui: &mut Ui, name: &str, add: impl FnOnce(&mut Ui) -> egui::Response) -> bool {
+    ui.horizontal(|ui| {
+        ui.label(format!("{name}:"));
+        add(ui).changed()
+    })
+    .inner
+}
+
+fn show_children(ui: &mut Ui, id: Id, label: String, add: impl FnOnce(&mut Ui) -> bool) -> bool {
+    CollapsingHeader::new(label)
+        .id_salt(id)
+        .show(ui, add)
+        .body_returned
+        .unwrap_or(false)
+}
+
+fn show_numbers<T: egui::emath::Numeric>(
+    ui: &mut Ui,
+    id: Id,
+    label: String,
+    values: &mut [T],
+) -> bool {
+    show_children(ui, id, label, |ui| {
+        let mut changed = false;
+        for (index, value) in values.iter_mut().enumerate() {
+            changed |= show_leaf(ui, &index.to_string(), |ui| DragValue::new(value).ui(ui));
+        }
+        changed
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).expect("url in test should parse")
+    }
+
+    #[test]
+    fn imported_name_of_local_movies() {
+        assert_eq!(
+            imported_name(&url("file:///home/user/game.swf"), "save").as_deref(),
+            Some("localhost/home/user/game.swf/save")
+        );
+        assert_eq!(
+            imported_name(&url("file:///C:/games/game.swf"), "save").as_deref(),
+            Some("localhost/games/game.swf/save")
+        );
+        assert_eq!(
+            imported_name(&url("app:/game.swf"), "save").as_deref(),
+            Some("localhost/game.swf/save")
+        );
+    }
+
+    #[test]
+    fn imported_name_of_remote_movies() {
+        assert_eq!(
+            imported_name(&url("https://games.example.com/dir/game.swf?v=2"), "save").as_deref(),
+            Some("games.example.com/dir/game.swf/save")
+        );
+    }
+
+    #[test]
+    fn imported_name_rejects_hidden_segments() {
+        assert_eq!(imported_name(&url("file:///game.swf"), ".save"), None);
+        assert_eq!(imported_name(&url("file:///game.swf"), ""), None);
+    }
+
+    #[test]
+    fn summary_of_values() {
+        assert_eq!(summary(&Value::String("abc".to_string())), "\"abc\"");
+        assert_eq!(
+            summary(&Value::ByteArray(vec![1, 2])),
+            "ByteArray (2 bytes)"
+        );
+        assert_eq!(
+            summary(&Value::AMF3(Rc::new(Value::VectorInt(vec![1], false)))),
+            "Vector.<int>"
+        );
+    }
+}
@@ -175,6 +175,12 @@ impl MenuBar {
                                 player.debug_ui().queue_message(DebugMessage::SearchForDisplayObject);
                             }
                         }
+                        if Button::new(text(locale, "debug-menu-open-avm1-debugger")).ui(ui).clicked() {
+                            ui.close();
+                            if let Some(player) = &mut player {
+                                player.debug_ui().queue_message(DebugMessage::ShowAvm1Debugger);
+                            }
+                        }
                     });
                 });
                 ui.menu_button(text(locale, "help-menu"), |ui| {
@@ -301,6 +307,15 @@ impl MenuBar {
             }
             ui.separator();
 
+            if Button::new(text(locale, "file-menu-shared-objects"))
+                .ui(ui)
+                .clicked()
+            {
+                ui.close();
+                let movie_url = self.currently_opened.as_ref().map(|(desc, _)| &desc.url);
+                dialogs.open_shared_objects(movie_url);
+            }
+
             if Button::new(text(locale, "file-menu-preferences"))
                 .ui(ui)
                 .clicked()
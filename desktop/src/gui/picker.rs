@@ -6,6 +6,7 @@ use crate::gui::dialogs::select_path_dialog::{
 };
 use crate::preferences::GlobalPreferences;
 use rfd::AsyncFileDialog;
+use ruffle_frontend_utils::air::AirApplication;
 use ruffle_frontend_utils::bundle::Bundle;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -64,11 +65,12 @@ impl FilePicker {
         let mut dialog = AsyncFileDialog::new()
             .add_filter(
                 text(locale, "file-picker-filter-supported"),
-                &["swf", "spl", "ruf"],
+                &["swf", "spl", "ruf", "air"],
             )
             .add_filter(text(locale, "file-picker-filter-swf"), &["swf"])
             .add_filter(text(locale, "file-picker-filter-spl"), &["spl"])
             .add_filter(text(locale, "file-picker-filter-ruf"), &["ruf"])
+            .add_filter(text(locale, "file-picker-filter-air"), &["air"])
             .add_filter(text(locale, "file-picker-filter-all"), &["*"])
             .set_title(text(locale, "file-picker-title-open-file"));
 
@@ -99,6 +101,7 @@ impl FilePicker {
     ///
     /// This can return:
     ///  * a Ruffle bundle as a directory (`/dir/bundle_dir`, `/dir/bundle_dir`),
+    ///  * an unpacked AIR application (`/dir/app_dir`, `/dir/app_dir`),
     ///  * a multi-asset SWF movie (`/dir/root_dir`, `/dir/root_dir/root_movie.swf`).
     pub async fn pick_ruffle_directory_and_content(
         &self,
@@ -117,6 +120,16 @@ impl FilePicker {
             }
         }
 
+        // ...or an AIR application...
+        match AirApplication::from_path(&directory) {
+            Ok(_) => {
+                return Some((directory.clone(), directory));
+            }
+            Err(err) => {
+                tracing::info!("The opened directory is not an AIR application: {err}");
+            }
+        }
+
         // If there's only one SWF in the directory, treat is as the root...
         let mut files: Vec<DirEntry> = WalkDir::new(&directory)
             .into_iter()
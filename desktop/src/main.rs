@@ -11,8 +11,10 @@ mod backends;
 mod cli;
 mod custom_event;
 mod dbus;
+mod debugger;
 mod gui;
 mod log;
+mod native_window;
 mod player;
 mod preferences;
 #[cfg(feature = "tracy")]
@@ -165,7 +167,8 @@ fn main() -> Result<(), Error> {
     subscriber.init();
 
     let result = App::new(preferences).and_then(|(mut app, event_loop)| {
-        event_loop.run_app(&mut app).context("Event loop failure")
+        event_loop.run_app(&mut app).context("Event loop failure")?;
+        Ok(app.exit_code())
     });
 
     #[cfg(windows)]
@@ -173,7 +176,15 @@ fn main() -> Result<(), Error> {
         eprintln!("{:?}", error)
     }
 
-    result
+    let exit_code = result?;
+    if exit_code != 0 {
+        // Exiting skips destructors, so make sure the logs are written first.
+        drop(_file_guard);
+        drop(_stdout_guard);
+        std::process::exit(exit_code);
+    }
+
+    Ok(())
 }
 
 /// Move logs from config directory into proper log directory.
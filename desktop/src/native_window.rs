@@ -0,0 +1,362 @@
+//! Windows opened by AIR movies, in addition to the main window.
+//!
+//! The UI backend asks for windows to be opened, but only the app can create them, as that
+//! needs the event loop. Until then, whatever the movie sets is kept and applied once the
+//! window exists.
+//!
+//! Once created, a window is handed to the renderer as a target of its own, which the player
+//! draws the stage of the window to.
+
+use ruffle_core::backend::ui::{
+    NativeWindowBounds, NativeWindowDisplayState, NativeWindowEdge, NativeWindowId,
+    NativeWindowOptions,
+};
+use ruffle_render_wgpu::descriptors::Descriptors;
+use ruffle_render_wgpu::target::SwapChainTarget;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event_loop::ActiveEventLoop;
+use winit::window::{
+    ResizeDirection, Window, WindowAttributes, WindowButtons, WindowId, WindowLevel,
+};
+
+/// A window opened by the movie.
+struct NativeWindow {
+    options: NativeWindowOptions,
+    visible: bool,
+    always_in_front: bool,
+    size_limits: Option<((u32, u32), (u32, u32))>,
+    display_state: NativeWindowDisplayState,
+
+    /// Where the mouse was last seen in the window.
+    mouse_position: PhysicalPosition<f64>,
+
+    /// The window, once the app has created it.
+    window: Option<Arc<Window>>,
+}
+
+/// The windows opened by the movie, shared between the UI backend and the app.
+#[derive(Clone)]
+pub struct NativeWindows {
+    descriptors: Arc<Descriptors>,
+    windows: Arc<Mutex<HashMap<NativeWindowId, NativeWindow>>>,
+}
+
+impl NativeWindows {
+    pub fn new(descriptors: Arc<Descriptors>) -> Self {
+        Self {
+            descriptors,
+            windows: Default::default(),
+        }
+    }
+
+    /// Registers a window, which is created by the next call to `create_window`.
+    pub fn add(&self, id: NativeWindowId, options: NativeWindowOptions) {
+        self.windows.lock().expect("Native windows lock").insert(
+            id,
+            NativeWindow {
+                options,
+                visible: false,
+                always_in_front: false,
+                size_limits: None,
+                display_state: NativeWindowDisplayState::Normal,
+                mouse_position: PhysicalPosition::default(),
+                window: None,
+            },
+        );
+    }
+
+    /// Closes a window by dropping it.
+    pub fn remove(&self, id: NativeWindowId) {
+        self.windows
+            .lock()
+            .expect("Native windows lock")
+            .remove(&id);
+    }
+
+    /// Creates a window registered with `add`, with everything the movie set so far.
+    ///
+    /// Returns the render target of the window, to be added to the renderer.
+    pub fn create_window(
+        &self,
+        event_loop: &ActiveEventLoop,
+        id: NativeWindowId,
+    ) -> Option<SwapChainTarget> {
+        let mut windows = self.windows.lock().expect("Native windows lock");
+        let native_window = windows.get_mut(&id)?;
+        if native_window.window.is_some() {
+            return None;
+        }
+
+        let options = &native_window.options;
+        let mut buttons = WindowButtons::CLOSE;
+        if options.minimizable {
+            buttons |= WindowButtons::MINIMIZE;
+        }
+        if options.maximizable {
+            buttons |= WindowButtons::MAXIMIZE;
+        }
+        let mut attributes = WindowAttributes::default()
+            .with_title(options.title.clone())
+            .with_visible(native_window.visible)
+            .with_decorations(options.system_chrome)
+            .with_transparent(options.transparent)
+            .with_resizable(options.resizable)
+            .with_enabled_buttons(buttons)
+            .with_window_level(window_level(native_window.always_in_front))
+            .with_maximized(native_window.display_state == NativeWindowDisplayState::Maximized);
+        if let Some(bounds) = options.bounds {
+            attributes = attributes
+                .with_position(PhysicalPosition::new(bounds.x, bounds.y))
+                .with_inner_size(PhysicalSize::new(bounds.width, bounds.height));
+        }
+        if let Some((min, max)) = native_window.size_limits {
+            attributes = attributes
+                .with_min_inner_size(PhysicalSize::new(min.0, min.1))
+                .with_max_inner_size(PhysicalSize::new(max.0, max.1));
+        }
+
+        let window = match event_loop.create_window(attributes) {
+            Ok(window) => Arc::new(window),
+            Err(e) => {
+                tracing::error!("Couldn't open native window: {e}");
+                return None;
+            }
+        };
+        if native_window.display_state == NativeWindowDisplayState::Minimized {
+            window.set_minimized(true);
+        }
+
+        let transparent = options.transparent;
+        native_window.window = Some(window.clone());
+        self.create_target(window, transparent)
+    }
+
+    fn create_target(&self, window: Arc<Window>, transparent: bool) -> Option<SwapChainTarget> {
+        let size = window.inner_size();
+        let surface = match self.descriptors.wgpu_instance.create_surface(window) {
+            Ok(surface) => surface,
+            Err(e) => {
+                tracing::error!("Couldn't create surface for native window: {e}");
+                return None;
+            }
+        };
+
+        let mut target = SwapChainTarget::new(
+            surface,
+            &self.descriptors.adapter,
+            (size.width.max(1), size.height.max(1)),
+            &self.descriptors.device,
+        );
+        if transparent {
+            target.make_transparent(&self.descriptors.adapter, &self.descriptors.device);
+        }
+        Some(target)
+    }
+
+    /// The window displaying the given winit window, if it's one of these.
+    pub fn find(&self, window_id: WindowId) -> Option<NativeWindowId> {
+        let windows = self.windows.lock().expect("Native windows lock");
+        windows.iter().find_map(|(id, native_window)| {
+            let window = native_window.window.as_ref()?;
+            (window.id() == window_id).then_some(*id)
+        })
+    }
+
+    pub fn mouse_position(&self, id: NativeWindowId) -> PhysicalPosition<f64> {
+        let windows = self.windows.lock().expect("Native windows lock");
+        windows
+            .get(&id)
+            .map(|native_window| native_window.mouse_position)
+            .unwrap_or_default()
+    }
+
+    pub fn set_mouse_position(&self, id: NativeWindowId, position: PhysicalPosition<f64>) {
+        let mut windows = self.windows.lock().expect("Native windows lock");
+        if let Some(native_window) = windows.get_mut(&id) {
+            native_window.mouse_position = position;
+        }
+    }
+
+    /// Runs `f` on a window if it was created.
+    fn with_window<R>(&self, id: NativeWindowId, f: impl FnOnce(&Window) -> R) -> Option<R> {
+        let windows = self.windows.lock().expect("Native windows lock");
+        let window = windows.get(&id)?.window.as_ref()?;
+        Some(f(window))
+    }
+
+    /// Updates what the movie set for a window, and applies it if the window was created.
+    fn update(
+        &self,
+        id: NativeWindowId,
+        update: impl FnOnce(&mut NativeWindow),
+        apply: impl FnOnce(&Window),
+    ) {
+        let mut windows = self.windows.lock().expect("Native windows lock");
+        let Some(native_window) = windows.get_mut(&id) else {
+            return;
+        };
+        update(native_window);
+        if let Some(window) = &native_window.window {
+            apply(window);
+        }
+    }
+
+    pub fn bounds(&self, id: NativeWindowId) -> Option<NativeWindowBounds> {
+        self.with_window(id, window_bounds).flatten().or_else(|| {
+            let windows = self.windows.lock().expect("Native windows lock");
+            windows.get(&id)?.options.bounds
+        })
+    }
+
+    pub fn set_bounds(&self, id: NativeWindowId, bounds: NativeWindowBounds) {
+        self.update(
+            id,
+            |native_window| native_window.options.bounds = Some(bounds),
+            |window| set_window_bounds(window, bounds),
+        );
+    }
+
+    pub fn display_state(&self, id: NativeWindowId) -> NativeWindowDisplayState {
+        self.with_window(id, window_display_state)
+            .unwrap_or_default()
+    }
+
+    pub fn set_display_state(&self, id: NativeWindowId, state: NativeWindowDisplayState) {
+        self.update(
+            id,
+            |native_window| native_window.display_state = state,
+            |window| set_window_display_state(window, state),
+        );
+    }
+
+    pub fn set_visible(&self, id: NativeWindowId, visible: bool) {
+        self.update(
+            id,
+            |native_window| native_window.visible = visible,
+            |window| window.set_visible(visible),
+        );
+    }
+
+    pub fn set_title(&self, id: NativeWindowId, title: &str) {
+        self.update(
+            id,
+            |native_window| native_window.options.title = title.to_string(),
+            |window| window.set_title(title),
+        );
+    }
+
+    pub fn set_always_in_front(&self, id: NativeWindowId, always_in_front: bool) {
+        self.update(
+            id,
+            |native_window| native_window.always_in_front = always_in_front,
+            |window| set_window_always_in_front(window, always_in_front),
+        );
+    }
+
+    pub fn set_size_limits(&self, id: NativeWindowId, min: (u32, u32), max: (u32, u32)) {
+        self.update(
+            id,
+            |native_window| native_window.size_limits = Some((min, max)),
+            |window| set_window_size_limits(window, min, max),
+        );
+    }
+
+    pub fn activate(&self, id: NativeWindowId) {
+        self.with_window(id, Window::focus_window);
+    }
+
+    pub fn start_move(&self, id: NativeWindowId) -> bool {
+        self.with_window(id, |window| window.drag_window().is_ok())
+            .unwrap_or(false)
+    }
+
+    pub fn start_resize(&self, id: NativeWindowId, edge: NativeWindowEdge) -> bool {
+        self.with_window(id, |window| start_window_resize(window, edge))
+            .unwrap_or(false)
+    }
+}
+
+fn window_level(always_in_front: bool) -> WindowLevel {
+    if always_in_front {
+        WindowLevel::AlwaysOnTop
+    } else {
+        WindowLevel::Normal
+    }
+}
+
+fn resize_direction(edge: NativeWindowEdge) -> ResizeDirection {
+    match edge {
+        NativeWindowEdge::Top => ResizeDirection::North,
+        NativeWindowEdge::Bottom => ResizeDirection::South,
+        NativeWindowEdge::Left => ResizeDirection::West,
+        NativeWindowEdge::Right => ResizeDirection::East,
+        NativeWindowEdge::TopLeft => ResizeDirection::NorthWest,
+        NativeWindowEdge::TopRight => ResizeDirection::NorthEast,
+        NativeWindowEdge::BottomLeft => ResizeDirection::SouthWest,
+        NativeWindowEdge::BottomRight => ResizeDirection::SouthEast,
+    }
+}
+
+/// The position and size of the contents of a window.
+pub fn window_bounds(window: &Window) -> Option<NativeWindowBounds> {
+    let position = window.inner_position().ok()?;
+    let size = window.inner_size();
+    Some(NativeWindowBounds {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+    })
+}
+
+pub fn set_window_bounds(window: &Window, bounds: NativeWindowBounds) {
+    // Windows are positioned by their frame, so the position is offset by its size.
+    let (frame_x, frame_y) = match (window.inner_position(), window.outer_position()) {
+        (Ok(inner), Ok(outer)) => (inner.x - outer.x, inner.y - outer.y),
+        _ => (0, 0),
+    };
+    window.set_outer_position(PhysicalPosition::new(
+        bounds.x - frame_x,
+        bounds.y - frame_y,
+    ));
+    let _ = window.request_inner_size(PhysicalSize::new(bounds.width, bounds.height));
+}
+
+pub fn window_display_state(window: &Window) -> NativeWindowDisplayState {
+    if window.is_minimized().unwrap_or(false) {
+        NativeWindowDisplayState::Minimized
+    } else if window.is_maximized() {
+        NativeWindowDisplayState::Maximized
+    } else {
+        NativeWindowDisplayState::Normal
+    }
+}
+
+pub fn set_window_display_state(window: &Window, state: NativeWindowDisplayState) {
+    match state {
+        NativeWindowDisplayState::Normal => {
+            window.set_minimized(false);
+            window.set_maximized(false);
+        }
+        NativeWindowDisplayState::Minimized => window.set_minimized(true),
+        NativeWindowDisplayState::Maximized => {
+            window.set_minimized(false);
+            window.set_maximized(true);
+        }
+    }
+}
+
+pub fn set_window_size_limits(window: &Window, min: (u32, u32), max: (u32, u32)) {
+    window.set_min_inner_size(Some(PhysicalSize::new(min.0, min.1)));
+    window.set_max_inner_size(Some(PhysicalSize::new(max.0, max.1)));
+}
+
+pub fn set_window_always_in_front(window: &Window, always_in_front: bool) {
+    window.set_window_level(window_level(always_in_front));
+}
+
+pub fn start_window_resize(window: &Window, edge: NativeWindowEdge) -> bool {
+    window.drag_resize_window(resize_direction(edge)).is_ok()
+}
@@ -1,22 +1,31 @@
 use crate::backends::{
-    DesktopExternalInterfaceProvider, DesktopFSCommandProvider, DesktopNavigatorInterface,
-    DesktopUiBackend, PathAllowList,
+    DesktopExternalInterfaceProvider, DesktopFSCommandProvider, DesktopMicrophoneBackend,
+    DesktopNavigatorInterface, DesktopPrintBackend, DesktopProcessBackend, DesktopUiBackend,
+    PathAllowList,
 };
 use crate::cli::FilesystemAccessMode;
 use crate::cli::GameModePreference;
+use crate::cli::MicrophoneAccessMode;
+use crate::cli::PaperSize;
 use crate::custom_event::RuffleEvent;
 use crate::gui::{FilePicker, MovieView};
+use crate::native_window::NativeWindows;
 use crate::preferences::GlobalPreferences;
 use crate::{CALLSTACK, RENDER_INFO, SWF_INFO};
 use anyhow::anyhow;
+use ruffle_core::backend::filesystem::SpecialDirectory;
 use ruffle_core::backend::navigator::{OwnedFuture, SocketMode};
+use ruffle_core::backend::ui::NativeWindowId;
 use ruffle_core::config::Letterbox;
-use ruffle_core::events::{GamepadButton, KeyCode};
+use ruffle_core::events::{GamepadButton, KeyCode, NativeWindowEvent};
 use ruffle_core::font::DefaultFont;
-use ruffle_core::{LoadBehavior, Player, PlayerBuilder, PlayerEvent};
+use ruffle_core::{LoadBehavior, Player, PlayerBuilder, PlayerEvent, PlayerRuntime};
+use ruffle_frontend_utils::air::descriptor::ApplicationDescriptor;
+use ruffle_frontend_utils::air::{AirApplication, AirApplicationError};
 use ruffle_frontend_utils::backends::audio::CpalAudioBackend;
 use ruffle_frontend_utils::backends::navigator::{ExternalNavigatorBackend, FutureSpawner};
-use ruffle_frontend_utils::bundle::source::BundleSourceError;
+use ruffle_frontend_utils::backends::print::{FilePrintBackend, PrintFormat};
+use ruffle_frontend_utils::bundle::source::{BundleSource, BundleSourceError};
 use ruffle_frontend_utils::bundle::{Bundle, BundleError};
 use ruffle_frontend_utils::content::{ContentDescriptor, PlayingContent};
 use ruffle_frontend_utils::player_options::PlayerOptions;
@@ -26,10 +35,13 @@ use ruffle_render::quality::StageQuality;
 use ruffle_render_wgpu::backend::WgpuRenderBackend;
 use ruffle_render_wgpu::clap::PowerPreference;
 use ruffle_render_wgpu::descriptors::Descriptors;
+use ruffle_render_wgpu::target::SwapChainTarget;
+use std::any::Any;
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
@@ -45,12 +57,20 @@ pub struct LaunchOptions {
     pub proxy: Option<Url>,
     pub socket_allowed: HashSet<String>,
     pub tcp_connections: Option<SocketMode>,
+    pub process_allowed: Vec<PathBuf>,
+    pub paper_size: PaperSize,
+    pub print_output: Option<PathBuf>,
     pub fullscreen: bool,
     pub save_directory: PathBuf,
     pub cache_directory: PathBuf,
     pub filesystem_access_mode: FilesystemAccessMode,
+    pub microphone_access_mode: MicrophoneAccessMode,
     pub gamepad_button_mapping: HashMap<GamepadButton, KeyCode>,
+    pub max_touch_points: u32,
+    pub invoke_arguments: Vec<String>,
     pub avm2_optimizer_enabled: bool,
+    pub avm1_debugger: Option<SocketAddr>,
+    pub avm2_debugger: Option<SocketAddr>,
 }
 
 impl From<&GlobalPreferences> for LaunchOptions {
@@ -97,10 +117,18 @@ impl From<&GlobalPreferences> for LaunchOptions {
             save_directory: value.cli.save_directory.clone(),
             cache_directory: value.cli.cache_directory.clone(),
             filesystem_access_mode: value.cli.filesystem_access_mode,
+            microphone_access_mode: value.cli.microphone_access_mode,
             socket_allowed: HashSet::from_iter(value.cli.socket_allow.iter().cloned()),
             tcp_connections: value.cli.tcp_connections,
+            process_allowed: value.cli.process_allow.clone(),
+            paper_size: value.cli.paper_size,
+            print_output: value.cli.print_output.clone(),
             gamepad_button_mapping: HashMap::from_iter(value.cli.gamepad_button.iter().cloned()),
+            max_touch_points: value.cli.max_touch_points,
+            invoke_arguments: value.cli.invoke_arguments.clone(),
             avm2_optimizer_enabled: !value.cli.no_avm2_optimizer,
+            avm1_debugger: value.cli.avm1_debugger,
+            avm2_debugger: value.cli.avm2_debugger,
         }
     }
 }
@@ -111,6 +139,15 @@ struct ActivePlayer {
     id: PlayerId,
     player: Arc<Mutex<Player>>,
 
+    /// The windows opened by the movie besides the main one.
+    native_windows: NativeWindows,
+
+    /// The descriptor of the AIR application being played, if any.
+    application_descriptor: Option<ApplicationDescriptor>,
+
+    /// Whether touch input is delivered to the movie, see `LaunchOptions::max_touch_points`.
+    touch_enabled: bool,
+
     #[cfg(target_os = "linux")]
     _gamemode_session: crate::dbus::GameModeSession,
 }
@@ -139,6 +176,10 @@ impl ActivePlayer {
                 tracing::error!("Unable to create audio device: {}", e);
             }
         };
+        builder = builder.with_microphone(DesktopMicrophoneBackend::new(
+            event_loop.clone(),
+            opt.microphone_access_mode,
+        ));
 
         let mut content = PlayingContent::DirectFile(content_descriptor.clone());
         if content_descriptor.url.scheme() == "file"
@@ -166,6 +207,20 @@ impl ActivePlayer {
                     tracing::error!("Couldn't open bundle at {path:?}: {e}");
                 }
             }
+
+            match AirApplication::from_path(&path) {
+                Ok(application) => {
+                    tracing::info!("Opening AIR application at {path:?}");
+                    content =
+                        PlayingContent::Air(content_descriptor.clone(), Box::new(application));
+                }
+                Err(AirApplicationError::NotAnApplication) => {
+                    // This isn't an AIR application, carry on opening it as is
+                }
+                Err(e) => {
+                    tracing::error!("Couldn't open AIR application at {path:?}: {e}");
+                }
+            }
         }
 
         let recent_limit = preferences.recent_limit();
@@ -191,14 +246,44 @@ impl ActivePlayer {
                     proxy: opt.proxy.clone(),
                     socket_allowed: opt.socket_allowed.clone(),
                     tcp_connections: opt.tcp_connections,
+                    process_allowed: opt.process_allowed.clone(),
+                    paper_size: opt.paper_size,
+                    print_output: opt.print_output.clone(),
                     fullscreen: opt.fullscreen,
                     save_directory: opt.save_directory.clone(),
                     cache_directory: opt.cache_directory.clone(),
                     filesystem_access_mode: opt.filesystem_access_mode,
+                    microphone_access_mode: opt.microphone_access_mode,
                     gamepad_button_mapping: opt.gamepad_button_mapping.clone(),
+                    max_touch_points: opt.max_touch_points,
+                    invoke_arguments: opt.invoke_arguments.clone(),
                     avm2_optimizer_enabled: opt.avm2_optimizer_enabled,
+                    avm1_debugger: opt.avm1_debugger,
+                    avm2_debugger: opt.avm2_debugger,
                 })
             }
+            PlayingContent::Air(..) => {
+                let mut opt = opt.clone();
+                opt.player.player_runtime =
+                    Some(opt.player.player_runtime.unwrap_or(PlayerRuntime::AIR));
+                Cow::Owned(opt)
+            }
+        };
+        let application_descriptor = match &content {
+            PlayingContent::Air(_, application) => Some(application.descriptor().clone()),
+            _ => None,
+        };
+        let application_directory = match &content {
+            PlayingContent::Air(_, application) => match application.source() {
+                BundleSource::Directory(path) => Some(path.clone()),
+                _ => None,
+            },
+            PlayingContent::DirectFile(_) => content
+                .initial_swf_url()
+                .to_file_path()
+                .ok()
+                .and_then(|path| path.parent().map(Path::to_path_buf)),
+            PlayingContent::Bundle(..) => None,
         };
 
         let future_spawner = WinitExecutor {
@@ -208,7 +293,7 @@ impl ActivePlayer {
         let movie_url = content.initial_swf_url().clone();
         let readable_name = content.name();
         let initial_allow_list = PathAllowList::new(content_descriptor);
-        let navigator = ExternalNavigatorBackend::new(
+        let mut navigator = ExternalNavigatorBackend::new(
             opt.player
                 .base
                 .to_owned()
@@ -228,6 +313,13 @@ impl ActivePlayer {
                 opt.filesystem_access_mode,
             ),
         );
+        let filesystem_root = preferences
+            .storage_backend()
+            .filesystem_root(&opt, &readable_name);
+        if let Some(root) = &filesystem_root {
+            let path = &SpecialDirectory::ApplicationStorage.path()[1..];
+            navigator = navigator.with_application_storage_directory(root.join(path));
+        }
 
         if cfg!(feature = "external_video") && preferences.openh264_enabled() {
             #[cfg(feature = "external_video")]
@@ -269,6 +361,7 @@ impl ActivePlayer {
             GameModePreference::Off => false,
         };
 
+        let native_windows = NativeWindows::new(descriptors.clone());
         let renderer = WgpuRenderBackend::new(descriptors, movie_view)
             .map_err(|e| anyhow!(e.to_string()))
             .expect("Couldn't create wgpu rendering backend");
@@ -284,6 +377,50 @@ impl ActivePlayer {
             builder = builder.with_gamepad_button_mapping(opt.gamepad_button_mapping.clone());
         }
 
+        builder = builder
+            .with_max_touch_points(opt.max_touch_points)
+            .with_invoke_arguments(opt.invoke_arguments.clone());
+
+        let descriptor_xml = match &application_descriptor {
+            Some(descriptor) => Some(descriptor.xml.clone()),
+            None => read_application_descriptor(&movie_url),
+        };
+        if let Some(descriptor) = descriptor_xml {
+            builder = builder.with_application_descriptor(descriptor);
+        }
+
+        if let Some(filesystem) = preferences
+            .storage_backend()
+            .create_filesystem_backend(&opt, &readable_name)
+        {
+            builder = builder.with_filesystem(filesystem);
+        }
+
+        builder = builder.with_process(DesktopProcessBackend::new(
+            event_loop.clone(),
+            PathAllowList::from_paths(opt.process_allowed.clone()),
+            application_directory,
+            filesystem_root,
+        ));
+
+        let print_settings = opt.paper_size.print_job_settings();
+        match &opt.print_output {
+            Some(path) => {
+                let format = PrintFormat::from_path(path).unwrap_or_else(|| {
+                    tracing::warn!("Unknown print output format of {path:?}, printing to PDF");
+                    PrintFormat::Pdf
+                });
+                builder =
+                    builder.with_print(FilePrintBackend::new(path.clone(), format, print_settings));
+            }
+            None => {
+                builder = builder.with_print(DesktopPrintBackend::new(
+                    print_settings,
+                    opt.cache_directory.join("print"),
+                ));
+            }
+        }
+
         let (notification_sender, notification_recv) = async_channel::unbounded();
 
         let event_loop2 = event_loop.clone();
@@ -312,6 +449,7 @@ impl ActivePlayer {
                     font_database,
                     preferences,
                     file_picker,
+                    native_windows.clone(),
                 )
                 .expect("Couldn't create ui backend"),
             )
@@ -335,6 +473,21 @@ impl ActivePlayer {
             .with_player_runtime(opt.player.player_runtime.unwrap_or_default())
             .with_frame_rate(opt.player.frame_rate)
             .with_avm2_optimizer_enabled(opt.avm2_optimizer_enabled);
+
+        if let Some(address) = opt.avm1_debugger {
+            match crate::debugger::listen("AVM1", address) {
+                Ok(connection) => builder = builder.with_avm1_debugger(connection),
+                Err(e) => tracing::error!("Couldn't start AVM1 debugger on {address}: {e}"),
+            }
+        }
+
+        if let Some(address) = opt.avm2_debugger {
+            match crate::debugger::listen("AVM2", address) {
+                Ok(connection) => builder = builder.with_avm2_debugger(connection),
+                Err(e) => tracing::error!("Couldn't start AVM2 debugger on {address}: {e}"),
+            }
+        }
+
         let player = builder.build();
 
         window.set_title(&format!("Ruffle - {readable_name}"));
@@ -415,12 +568,25 @@ impl ActivePlayer {
         Self {
             id: player_id,
             player,
+            native_windows,
+            application_descriptor,
+            touch_enabled: opt.max_touch_points > 0,
             #[cfg(target_os = "linux")]
             _gamemode_session: crate::dbus::GameModeSession::new(gamemode_enable),
         }
     }
 }
 
+/// Reads the `application.xml` of an AIR application, which is either next to its SWF
+/// or where an AIR package keeps it.
+fn read_application_descriptor(movie_url: &Url) -> Option<String> {
+    let path = movie_url.to_file_path().ok()?;
+    let directory = path.parent()?;
+    ["application.xml", "META-INF/AIR/application.xml"]
+        .iter()
+        .find_map(|name| std::fs::read_to_string(directory.join(name)).ok())
+}
+
 /// Owner of a Ruffle Player (via ActivePlayer),
 /// responsible for either creating, destroying or communicating with that player.
 pub struct PlayerController {
@@ -499,6 +665,67 @@ impl PlayerController {
         false
     }
 
+    /// The windows opened by the current movie besides the main one.
+    pub fn native_windows(&self) -> Option<NativeWindows> {
+        self.player
+            .as_ref()
+            .map(|player| player.native_windows.clone())
+    }
+
+    /// Lets the player draw to a window opened by the movie besides the main one.
+    pub fn add_native_window_target(&self, id: NativeWindowId, target: SwapChainTarget) {
+        if let Some(mut player) = self.get() {
+            let renderer =
+                <dyn Any>::downcast_mut::<WgpuRenderBackend<MovieView>>(player.renderer_mut())
+                    .expect("Renderer must be correct type");
+            renderer.add_window_target(id.0, target);
+        }
+    }
+
+    /// The descriptor of the AIR application being played, if any.
+    pub fn application_descriptor(&self) -> Option<&ApplicationDescriptor> {
+        self.player
+            .as_ref()
+            .and_then(|player| player.application_descriptor.as_ref())
+    }
+
+    /// Whether touch input should be sent to the player.
+    ///
+    /// When it isn't, the system already turns touches into mouse input, which the player
+    /// would otherwise emulate a second time.
+    pub fn is_touch_enabled(&self) -> bool {
+        self.player
+            .as_ref()
+            .is_some_and(|player| player.touch_enabled)
+    }
+
+    /// Returns `false` if the movie prevented the window from closing.
+    pub fn native_window_event(&self, id: NativeWindowId, event: NativeWindowEvent) -> bool {
+        match self.get() {
+            Some(mut player) => player.native_window_event(id, event),
+            None => true,
+        }
+    }
+
+    /// Handles an input event from a window opened by the movie.
+    pub fn handle_native_window_input(&self, id: NativeWindowId, event: PlayerEvent) -> bool {
+        if let Some(mut player) = self.get()
+            && player.is_playing()
+        {
+            return player.handle_native_window_input(id, event);
+        }
+
+        false
+    }
+
+    /// Returns `false` if the movie prevented the application from exiting.
+    pub fn application_exiting(&self) -> bool {
+        match self.get() {
+            Some(mut player) => player.application_exiting(),
+            None => true,
+        }
+    }
+
     pub fn poll(&self, task: PlayerRunnable) {
         // Only run the task if it matches our current player;
         // otherwise it is stale, and should be cancelled (which
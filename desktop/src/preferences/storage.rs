@@ -1,6 +1,8 @@
 use crate::player::LaunchOptions;
 use ruffle_core::backend::storage::MemoryStorageBackend;
+use ruffle_frontend_utils::backends::filesystem::DiskFileSystemBackend;
 use ruffle_frontend_utils::backends::storage::DiskStorageBackend;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 #[derive(clap::ValueEnum, Copy, Clone, PartialEq, Eq, Debug, Default)]
@@ -39,4 +41,45 @@ impl StorageBackend {
             StorageBackend::Memory => Box::new(MemoryStorageBackend::new()),
         }
     }
+
+    /// The directory of the filesystem of AIR movies, a directory per movie next to the save
+    /// directory.
+    ///
+    /// Returns `None` when nothing should be persisted.
+    pub fn filesystem_root(self, opt: &LaunchOptions, movie_name: &str) -> Option<PathBuf> {
+        match self {
+            StorageBackend::Disk => {
+                let directory_name: String = movie_name
+                    .chars()
+                    .map(|c| {
+                        if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                            c
+                        } else {
+                            '_'
+                        }
+                    })
+                    .collect();
+                Some(
+                    opt.save_directory
+                        .parent()
+                        .unwrap_or(&opt.save_directory)
+                        .join("AIR")
+                        .join(directory_name.trim_start_matches('.')),
+                )
+            }
+            StorageBackend::Memory => None,
+        }
+    }
+
+    /// Creates the filesystem of AIR movies, in the directory given by `filesystem_root`.
+    ///
+    /// Returns `None` when nothing should be persisted, so the player's in-memory filesystem is used.
+    pub fn create_filesystem_backend(
+        self,
+        opt: &LaunchOptions,
+        movie_name: &str,
+    ) -> Option<DiskFileSystemBackend> {
+        self.filesystem_root(opt, movie_name)
+            .map(DiskFileSystemBackend::new)
+    }
 }
@@ -1,13 +1,14 @@
 use anyhow::{Error, anyhow};
 use gilrs::Button;
+use ruffle_core::PlayerEvent;
 use ruffle_core::events::{
-    GamepadButton, KeyDescriptor, KeyLocation, LogicalKey, NamedKey as RuffleNamedKey, PhysicalKey,
-    TextControlCode,
+    GamepadButton, KeyDescriptor, KeyLocation, LogicalKey, MouseButton as RuffleMouseButton,
+    NamedKey as RuffleNamedKey, PhysicalKey, TextControlCode,
 };
 use std::path::Path;
 use url::Url;
 use winit::dpi::PhysicalSize;
-use winit::event::{KeyEvent, Modifiers};
+use winit::event::{ElementState, KeyEvent, Modifiers, MouseButton};
 use winit::keyboard::{
     Key, KeyCode as WinitKeyCode, KeyLocation as WinitKeyLocation, NamedKey,
     PhysicalKey as WinitPhysicalKey,
@@ -61,6 +62,36 @@ pub fn winit_to_ruffle_text_control(
     }
 }
 
+/// Converts a winit key event to the Ruffle events it produces: the key press or release,
+/// and then the text control or the text typed, if any.
+pub fn winit_to_ruffle_key_events(event: KeyEvent, modifiers: Modifiers) -> Vec<PlayerEvent> {
+    let key = winit_input_to_ruffle_key_descriptor(&event);
+    match event.state {
+        ElementState::Pressed => {
+            let mut events = vec![PlayerEvent::KeyDown { key }];
+            if let Some(code) = winit_to_ruffle_text_control(&event, modifiers) {
+                events.push(PlayerEvent::TextControl { code });
+            } else if let Some(text) = event.text {
+                events.extend(
+                    text.chars()
+                        .map(|codepoint| PlayerEvent::TextInput { codepoint }),
+                );
+            }
+            events
+        }
+        ElementState::Released => vec![PlayerEvent::KeyUp { key }],
+    }
+}
+
+pub fn winit_to_ruffle_mouse_button(button: MouseButton) -> RuffleMouseButton {
+    match button {
+        MouseButton::Left => RuffleMouseButton::Left,
+        MouseButton::Right => RuffleMouseButton::Right,
+        MouseButton::Middle => RuffleMouseButton::Middle,
+        _ => RuffleMouseButton::Unknown,
+    }
+}
+
 pub fn winit_input_to_ruffle_key_descriptor(event: &KeyEvent) -> KeyDescriptor {
     let physical_key = map_physical_key(event);
     let logical_key = map_logical_key(event);
@@ -0,0 +1,147 @@
+use crate::air::descriptor::{
+    APPLICATION_DESCRIPTOR_PATH, ApplicationDescriptor, ApplicationDescriptorParseError,
+};
+use crate::bundle::source::{BundleSource, BundleSourceError};
+use std::ffi::OsStr;
+use std::fs::File;
+use std::path::Path;
+use url::Url;
+
+pub mod descriptor;
+
+/// The scheme of URLs pointing into the application, such as `app:/game.swf`.
+pub const APPLICATION_SCHEME: &str = "app";
+
+/// The scheme of URLs pointing into the private storage of the application.
+pub const APPLICATION_STORAGE_SCHEME: &str = "app-storage";
+
+#[derive(Debug, thiserror::Error)]
+pub enum AirApplicationError {
+    #[error("Invalid application.xml: {0}")]
+    InvalidDescriptor(#[from] ApplicationDescriptorParseError),
+
+    #[error("Missing or corrupt application.xml")]
+    MissingDescriptor,
+
+    #[error("Invalid application source: {0}")]
+    InvalidSource(#[from] BundleSourceError),
+
+    #[error("Not an AIR application")]
+    NotAnApplication,
+}
+
+/// An AIR application, either packaged as an `.air` file or unpacked into a directory.
+///
+/// Both are laid out the same way: the files of the application are at the root, and the
+/// descriptor is in `META-INF/AIR/application.xml`.
+pub struct AirApplication {
+    source: BundleSource,
+    descriptor: ApplicationDescriptor,
+    content_url: Url,
+}
+
+impl AirApplication {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<AirApplication, AirApplicationError> {
+        let path = path.as_ref();
+
+        let source = if path.is_dir() && path.join(APPLICATION_DESCRIPTOR_PATH).is_file() {
+            BundleSource::Directory(path.to_owned())
+        } else if path.is_file() && path.extension() == Some(OsStr::new("air")) {
+            BundleSource::from_reader(
+                File::open(path).map_err(|e| AirApplicationError::InvalidSource(e.into()))?,
+            )?
+        } else {
+            return Err(AirApplicationError::NotAnApplication);
+        };
+
+        Self::from_source(source)
+    }
+
+    pub fn from_source(source: BundleSource) -> Result<AirApplication, AirApplicationError> {
+        let descriptor_file = source
+            .read_file(APPLICATION_DESCRIPTOR_PATH)
+            .map_err(|_| AirApplicationError::MissingDescriptor)?;
+        let descriptor_text = String::from_utf8(descriptor_file)
+            .map_err(|_| AirApplicationError::MissingDescriptor)?;
+        let descriptor = ApplicationDescriptor::parse(&descriptor_text)?;
+        let content_url = Url::parse(&format!("{APPLICATION_SCHEME}:/"))
+            .and_then(|root| root.join(descriptor.content.trim_start_matches('/')))
+            .map_err(|_| ApplicationDescriptorParseError::MissingContent)?;
+
+        Ok(AirApplication {
+            source,
+            descriptor,
+            content_url,
+        })
+    }
+
+    pub fn source(&self) -> &BundleSource {
+        &self.source
+    }
+
+    pub fn descriptor(&self) -> &ApplicationDescriptor {
+        &self.descriptor
+    }
+
+    /// The `app:` URL of the SWF to run.
+    pub fn content_url(&self) -> &Url {
+        &self.content_url
+    }
+
+    /// The name to show for the application.
+    pub fn name(&self) -> &str {
+        let descriptor = &self.descriptor;
+        descriptor
+            .name
+            .as_deref()
+            .or(descriptor.filename.as_deref())
+            .unwrap_or(&descriptor.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::air::descriptor::APPLICATION_DESCRIPTOR_PATH;
+    use crate::air::{AirApplication, AirApplicationError};
+    use tempfile::tempdir;
+
+    const DESCRIPTOR: &str = "<application><id>com.example.Game</id><filename>Game</filename>\
+        <initialWindow><content>bin/game swf.swf</content></initialWindow></application>";
+
+    #[test]
+    fn from_path_directory_without_descriptor() {
+        let tmp_dir = tempdir().unwrap();
+        let result = AirApplication::from_path(tmp_dir.path());
+        drop(tmp_dir);
+        assert!(matches!(result, Err(AirApplicationError::NotAnApplication)))
+    }
+
+    #[test]
+    fn from_path_directory_valid() {
+        let tmp_dir = tempdir().unwrap();
+        let descriptor_path = tmp_dir.path().join(APPLICATION_DESCRIPTOR_PATH);
+        std::fs::create_dir_all(descriptor_path.parent().unwrap()).unwrap();
+        std::fs::write(&descriptor_path, DESCRIPTOR).unwrap();
+        std::fs::create_dir(tmp_dir.path().join("bin")).unwrap();
+        std::fs::write(tmp_dir.path().join("bin/game swf.swf"), "FWS").unwrap();
+
+        let result = AirApplication::from_path(tmp_dir.path()).unwrap();
+        assert_eq!(result.name(), "Game");
+        assert_eq!(result.content_url().as_str(), "app:/bin/game%20swf.swf");
+        assert_eq!(
+            result.source().read_file("bin/game swf.swf").unwrap(),
+            b"FWS".to_vec()
+        );
+        drop(tmp_dir);
+    }
+
+    #[test]
+    fn from_path_air_not_a_zip() {
+        let tmp_dir = tempdir().unwrap();
+        let path = tmp_dir.path().join("game.air");
+        std::fs::write(&path, [0, 1, 2, 3]).unwrap();
+        let result = AirApplication::from_path(&path);
+        drop(tmp_dir);
+        assert!(matches!(result, Err(AirApplicationError::InvalidSource(_))))
+    }
+}
@@ -0,0 +1,208 @@
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+/// Where the descriptor is, relative to the root of the application.
+pub const APPLICATION_DESCRIPTOR_PATH: &str = "META-INF/AIR/application.xml";
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ApplicationDescriptorParseError {
+    #[error("File is not valid XML: {0}")]
+    InvalidXml(String),
+
+    #[error("Missing <application> element")]
+    MissingApplication,
+
+    #[error("Missing <id> element")]
+    MissingId,
+
+    #[error("Missing <initialWindow><content> element")]
+    MissingContent,
+}
+
+/// The parts of an AIR `application.xml` that are needed to run the application.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplicationDescriptor {
+    pub id: String,
+    pub filename: Option<String>,
+    pub name: Option<String>,
+
+    /// `<versionNumber>`, or `<version>` in descriptors older than AIR 2.5.
+    pub version: Option<String>,
+
+    /// The path of the SWF to run, relative to the root of the application.
+    pub content: String,
+
+    pub title: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+
+    /// The descriptor itself, which the application can read back.
+    pub xml: String,
+}
+
+impl ApplicationDescriptor {
+    pub fn parse(input: &str) -> Result<Self, ApplicationDescriptorParseError> {
+        let mut reader = Reader::from_str(input);
+        reader.config_mut().trim_text(true);
+
+        let mut found_application = false;
+        let mut id = None;
+        let mut filename = None;
+        let mut name = None;
+        let mut version = None;
+        let mut content = None;
+        let mut title = None;
+        let mut width = None;
+        let mut height = None;
+
+        let mut path: Vec<Vec<u8>> = Vec::new();
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| ApplicationDescriptorParseError::InvalidXml(e.to_string()))?;
+            let text = match event {
+                Event::Start(start) => {
+                    if path.is_empty() && start.local_name().as_ref() == b"application" {
+                        found_application = true;
+                    }
+                    path.push(start.local_name().as_ref().to_vec());
+                    continue;
+                }
+                Event::End(_) => {
+                    path.pop();
+                    continue;
+                }
+                Event::Text(text) => text
+                    .unescape()
+                    .map_err(|e| ApplicationDescriptorParseError::InvalidXml(e.to_string()))?
+                    .into_owned(),
+                Event::CData(data) => String::from_utf8_lossy(&data.into_inner()).into_owned(),
+                Event::Eof => break,
+                _ => continue,
+            };
+
+            let names: Vec<&[u8]> = path.iter().map(|name| name.as_slice()).collect();
+            let field = match names.as_slice() {
+                [b"application", b"id"] => &mut id,
+                [b"application", b"filename"] => &mut filename,
+                // The name may be localized, in which case the first language is used.
+                [b"application", b"name"] | [b"application", b"name", b"text"] => &mut name,
+                [b"application", b"versionNumber"] | [b"application", b"version"] => &mut version,
+                [b"application", b"initialWindow", b"content"] => &mut content,
+                [b"application", b"initialWindow", b"title"] => &mut title,
+                [b"application", b"initialWindow", b"width"] => {
+                    width = width.or(text.parse().ok());
+                    continue;
+                }
+                [b"application", b"initialWindow", b"height"] => {
+                    height = height.or(text.parse().ok());
+                    continue;
+                }
+                _ => continue,
+            };
+            if field.is_none() {
+                *field = Some(text);
+            }
+        }
+
+        if !found_application {
+            return Err(ApplicationDescriptorParseError::MissingApplication);
+        }
+
+        Ok(Self {
+            id: id.ok_or(ApplicationDescriptorParseError::MissingId)?,
+            filename,
+            name,
+            version,
+            content: content.ok_or(ApplicationDescriptorParseError::MissingContent)?,
+            title,
+            width,
+            height,
+            xml: input.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::air::descriptor::{ApplicationDescriptor, ApplicationDescriptorParseError};
+
+    #[test]
+    fn invalid_xml() {
+        assert!(matches!(
+            ApplicationDescriptor::parse("<application><id></application>"),
+            Err(ApplicationDescriptorParseError::InvalidXml(_))
+        ));
+    }
+
+    #[test]
+    fn missing_application() {
+        assert_eq!(
+            ApplicationDescriptor::parse("<bundle><id>foo</id></bundle>"),
+            Err(ApplicationDescriptorParseError::MissingApplication)
+        );
+    }
+
+    #[test]
+    fn missing_id() {
+        assert_eq!(
+            ApplicationDescriptor::parse(
+                "<application><initialWindow><content>game.swf</content></initialWindow></application>"
+            ),
+            Err(ApplicationDescriptorParseError::MissingId)
+        );
+    }
+
+    #[test]
+    fn missing_content() {
+        assert_eq!(
+            ApplicationDescriptor::parse("<application><id>com.example.Game</id></application>"),
+            Err(ApplicationDescriptorParseError::MissingContent)
+        );
+    }
+
+    #[test]
+    fn valid() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+            <application xmlns="http://ns.adobe.com/air/application/33.1">
+                <id>com.example.Game</id>
+                <filename>Game</filename>
+                <name>
+                    <text xml:lang="en">Cool Game!</text>
+                    <text xml:lang="fr">Jeu cool !</text>
+                </name>
+                <versionNumber>1.2.3</versionNumber>
+                <initialWindow>
+                    <content><![CDATA[bin/game.swf]]></content>
+                    <title>Cool &amp; Game</title>
+                    <width>800</width>
+                    <height>600</height>
+                </initialWindow>
+            </application>"#;
+        assert_eq!(
+            ApplicationDescriptor::parse(xml),
+            Ok(ApplicationDescriptor {
+                id: "com.example.Game".to_string(),
+                filename: Some("Game".to_string()),
+                name: Some("Cool Game!".to_string()),
+                version: Some("1.2.3".to_string()),
+                content: "bin/game.swf".to_string(),
+                title: Some("Cool & Game".to_string()),
+                width: Some(800),
+                height: Some(600),
+                xml: xml.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn old_version() {
+        let descriptor = ApplicationDescriptor::parse(
+            "<application><id>foo</id><version>v1</version>\
+            <initialWindow><content>foo.swf</content></initialWindow></application>",
+        )
+        .unwrap();
+        assert_eq!(descriptor.version, Some("v1".to_string()));
+        assert_eq!(descriptor.width, None);
+    }
+}
@@ -1,6 +1,12 @@
 #[cfg(feature = "cpal")]
 pub mod audio;
+#[cfg(feature = "fs")]
+pub mod filesystem;
+#[cfg(feature = "cpal")]
+pub mod microphone;
 #[cfg(feature = "navigator")]
 pub mod navigator;
+#[cfg(feature = "print")]
+pub mod print;
 #[cfg(feature = "fs")]
 pub mod storage;
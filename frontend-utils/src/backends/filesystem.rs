@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use ruffle_core::backend::filesystem::{
+    FileMetadata, FileSystemBackend, FileSystemError, SpecialDirectory,
+};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// A filesystem stored in a real directory, with one subdirectory per special directory.
+pub struct DiskFileSystemBackend {
+    root: PathBuf,
+}
+
+impl DiskFileSystemBackend {
+    pub fn new(root: PathBuf) -> Self {
+        for dir in SpecialDirectory::ALL {
+            let path = root.join(&dir.path()[1..]);
+            if let Err(e) = fs::create_dir_all(&path) {
+                tracing::warn!("Unable to create filesystem dir {path:?}: {e}");
+            }
+        }
+
+        Self { root }
+    }
+
+    /// The real path of a sandbox path.
+    ///
+    /// Sandbox paths are normalized by the player, but they're checked again to make sure
+    /// they can't escape the root.
+    fn real_path(&self, path: &str) -> Result<PathBuf, FileSystemError> {
+        let mut real_path = self.root.clone();
+        for component in path.split('/').filter(|c| !c.is_empty()) {
+            if component == "." || component == ".." || Path::new(component).is_absolute() {
+                return Err(FileSystemError::AccessDenied);
+            }
+            real_path.push(component);
+        }
+        Ok(real_path)
+    }
+}
+
+fn convert_error(error: std::io::Error) -> FileSystemError {
+    match error.kind() {
+        ErrorKind::NotFound => FileSystemError::NotFound,
+        ErrorKind::AlreadyExists => FileSystemError::AlreadyExists,
+        ErrorKind::NotADirectory => FileSystemError::NotADirectory,
+        ErrorKind::IsADirectory => FileSystemError::NotAFile,
+        ErrorKind::DirectoryNotEmpty => FileSystemError::DirectoryNotEmpty,
+        ErrorKind::PermissionDenied => FileSystemError::AccessDenied,
+        _ => FileSystemError::Io(error.to_string()),
+    }
+}
+
+impl FileSystemBackend for DiskFileSystemBackend {
+    fn metadata(&self, path: &str) -> Result<FileMetadata, FileSystemError> {
+        let metadata = fs::metadata(self.real_path(path)?).map_err(convert_error)?;
+        Ok(FileMetadata {
+            is_directory: metadata.is_dir(),
+            size: if metadata.is_dir() { 0 } else { metadata.len() },
+            creation_time: metadata.created().ok().map(DateTime::<Utc>::from),
+            modification_time: metadata.modified().ok().map(DateTime::<Utc>::from),
+        })
+    }
+
+    fn read(&self, path: &str) -> Result<Vec<u8>, FileSystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_dir() {
+            return Err(FileSystemError::NotAFile);
+        }
+        fs::read(real_path).map_err(convert_error)
+    }
+
+    fn write(&mut self, path: &str, data: &[u8]) -> Result<(), FileSystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_dir() {
+            return Err(FileSystemError::NotAFile);
+        }
+        fs::write(real_path, data).map_err(convert_error)
+    }
+
+    fn create_directory(&mut self, path: &str) -> Result<(), FileSystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_file() {
+            return Err(FileSystemError::AlreadyExists);
+        }
+        fs::create_dir_all(real_path).map_err(convert_error)
+    }
+
+    fn read_directory(&self, path: &str) -> Result<Vec<String>, FileSystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_file() {
+            return Err(FileSystemError::NotADirectory);
+        }
+        fs::read_dir(real_path)
+            .map_err(convert_error)?
+            .map(|entry| {
+                let entry = entry.map_err(convert_error)?;
+                Ok(entry.file_name().to_string_lossy().into_owned())
+            })
+            .collect()
+    }
+
+    fn remove(&mut self, path: &str, recursive: bool) -> Result<(), FileSystemError> {
+        let real_path = self.real_path(path)?;
+        let metadata = fs::metadata(&real_path).map_err(convert_error)?;
+        let result = if !metadata.is_dir() {
+            fs::remove_file(real_path)
+        } else if recursive {
+            fs::remove_dir_all(real_path)
+        } else {
+            fs::remove_dir(real_path)
+        };
+        result.map_err(convert_error)
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> Result<(), FileSystemError> {
+        let to = self.real_path(to)?;
+        if to.exists() {
+            return Err(FileSystemError::AlreadyExists);
+        }
+        fs::rename(self.real_path(from)?, to).map_err(convert_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn special_directories_are_created() {
+        let tmp_dir = tempdir().unwrap();
+        let backend = DiskFileSystemBackend::new(tmp_dir.path().join("app"));
+        let success = SpecialDirectory::ALL
+            .into_iter()
+            .all(|dir| backend.metadata(dir.path()).is_ok_and(|m| m.is_directory));
+        drop(tmp_dir);
+        assert!(success);
+    }
+
+    #[test]
+    fn write_read_and_remove() {
+        let tmp_dir = tempdir().unwrap();
+        let mut backend = DiskFileSystemBackend::new(tmp_dir.path().to_path_buf());
+        let written = backend.write("/app-storage/file.txt", b"data");
+        let read = backend.read("/app-storage/file.txt");
+        let on_disk = fs::read(tmp_dir.path().join("app-storage").join("file.txt"));
+        let listing = backend.read_directory("/app-storage");
+        let removed = backend.remove("/app-storage/file.txt", false);
+        let exists = backend.exists("/app-storage/file.txt");
+        drop(tmp_dir);
+
+        assert_eq!(written, Ok(()));
+        assert_eq!(read.unwrap(), b"data");
+        assert_eq!(on_disk.unwrap(), b"data");
+        assert_eq!(listing.unwrap(), vec!["file.txt"]);
+        assert_eq!(removed, Ok(()));
+        assert!(!exists);
+    }
+
+    #[test]
+    fn paths_cannot_escape_root() {
+        let tmp_dir = tempdir().unwrap();
+        let backend = DiskFileSystemBackend::new(tmp_dir.path().join("app"));
+        let result = backend.read("/user/../../secret");
+        drop(tmp_dir);
+        assert_eq!(result, Err(FileSystemError::AccessDenied));
+    }
+}
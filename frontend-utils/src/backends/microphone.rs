@@ -0,0 +1,359 @@
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, Sample, SampleFormat, SizedSample};
+use ruffle_core::backend::microphone::{MicrophoneBackend, MicrophoneError};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// The longest amount of captured audio kept around, in seconds.
+///
+/// Anything older is dropped, so that a movie that stops reading doesn't make us
+/// hoard audio forever.
+const MAX_BUFFERED_SECONDS: usize = 2;
+
+/// Captured audio, as mono samples at the rate requested by the movie.
+#[derive(Default)]
+struct CapturedAudio {
+    /// Audio not read by the movie yet.
+    samples: VecDeque<f32>,
+
+    /// Audio not played back yet, if the microphone is looped back.
+    loop_back: Option<VecDeque<f32>>,
+}
+
+type SharedAudio = Arc<Mutex<CapturedAudio>>;
+
+struct Capture {
+    _stream: cpal::Stream,
+    sample_rate: u32,
+    audio: SharedAudio,
+    loop_back: Option<LoopBack>,
+}
+
+struct LoopBack {
+    _stream: cpal::Stream,
+    volume: Arc<Mutex<f32>>,
+}
+
+/// Microphone backend capturing audio from the input devices of the system.
+pub struct CpalMicrophoneBackend {
+    devices: Vec<cpal::Device>,
+    names: Vec<String>,
+    default_device: Option<usize>,
+    captures: HashMap<usize, Capture>,
+}
+
+impl CpalMicrophoneBackend {
+    pub fn new() -> Self {
+        let host = cpal::default_host();
+        let devices: Vec<_> = host
+            .input_devices()
+            .map(|devices| devices.collect())
+            .unwrap_or_default();
+        let names: Vec<_> = devices
+            .iter()
+            .map(|device| device.name().unwrap_or_default())
+            .collect();
+
+        let default_name = host
+            .default_input_device()
+            .and_then(|device| device.name().ok());
+        let default_device = default_name
+            .and_then(|default_name| names.iter().position(|name| *name == default_name))
+            .or_else(|| (!devices.is_empty()).then_some(0));
+
+        Self {
+            devices,
+            names,
+            default_device,
+            captures: HashMap::new(),
+        }
+    }
+}
+
+impl Default for CpalMicrophoneBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MicrophoneBackend for CpalMicrophoneBackend {
+    fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    fn default_device(&self) -> Option<usize> {
+        self.default_device
+    }
+
+    fn start_capture(&mut self, index: usize, sample_rate: u32) -> Result<(), MicrophoneError> {
+        self.stop_capture(index);
+
+        let device = self
+            .devices
+            .get(index)
+            .ok_or(MicrophoneError::NoDevice(index))?;
+        let audio = SharedAudio::default();
+        let stream = build_input_stream(device, sample_rate, audio.clone())
+            .map_err(|e| MicrophoneError::Capture(e.to_string()))?;
+        stream
+            .play()
+            .map_err(|e| MicrophoneError::Capture(e.to_string()))?;
+
+        self.captures.insert(
+            index,
+            Capture {
+                _stream: stream,
+                sample_rate,
+                audio,
+                loop_back: None,
+            },
+        );
+        Ok(())
+    }
+
+    fn stop_capture(&mut self, index: usize) {
+        self.captures.remove(&index);
+    }
+
+    fn read_samples(&mut self, index: usize, samples: &mut Vec<f32>) {
+        if let Some(capture) = self.captures.get(&index) {
+            samples.extend(lock(&capture.audio).samples.drain(..));
+        }
+    }
+
+    fn set_loop_back(&mut self, index: usize, volume: Option<f32>) {
+        let Some(capture) = self.captures.get_mut(&index) else {
+            return;
+        };
+
+        match (volume, &capture.loop_back) {
+            (Some(volume), Some(loop_back)) => *lock(&loop_back.volume) = volume,
+            (Some(volume), None) => {
+                lock(&capture.audio).loop_back = Some(VecDeque::new());
+                let volume = Arc::new(Mutex::new(volume));
+                match build_loop_back_stream(capture, volume.clone()) {
+                    Ok(stream) => {
+                        capture.loop_back = Some(LoopBack {
+                            _stream: stream,
+                            volume,
+                        });
+                    }
+                    Err(e) => {
+                        tracing::error!("Couldn't play the microphone back: {e}");
+                        lock(&capture.audio).loop_back = None;
+                    }
+                }
+            }
+            (None, _) => {
+                capture.loop_back = None;
+                lock(&capture.audio).loop_back = None;
+            }
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Converts a stream of samples from one sample rate to another by linear interpolation.
+struct Resampler {
+    /// How far to advance in the input for every output sample.
+    step: f64,
+
+    /// The position of the next output sample, relative to the last sample of the
+    /// previous input.
+    position: f64,
+
+    /// The last sample of the previous input.
+    previous: f32,
+}
+
+impl Resampler {
+    fn new(from: u32, to: u32) -> Self {
+        Self {
+            step: f64::from(from) / f64::from(to),
+            position: 0.0,
+            previous: 0.0,
+        }
+    }
+
+    /// Resamples the next chunk of a stream.
+    fn process(&mut self, input: &[f32], mut output: impl FnMut(f32)) {
+        let Some(last) = input.last() else {
+            return;
+        };
+
+        let at = |index: usize| {
+            if index == 0 {
+                self.previous
+            } else {
+                input[index - 1]
+            }
+        };
+        while self.position < input.len() as f64 {
+            let index = self.position as usize;
+            let fraction = (self.position - index as f64) as f32;
+            output(at(index) + (at(index + 1) - at(index)) * fraction);
+            self.position += self.step;
+        }
+
+        self.position -= input.len() as f64;
+        self.previous = *last;
+    }
+}
+
+/// Starts capturing from `device`, converting the audio to mono at `sample_rate`.
+fn build_input_stream(
+    device: &cpal::Device,
+    sample_rate: u32,
+    audio: SharedAudio,
+) -> Result<cpal::Stream, Box<dyn std::error::Error>> {
+    let config = device.default_input_config()?;
+    let sample_format = config.sample_format();
+    let config = cpal::StreamConfig::from(config);
+
+    let stream = match sample_format {
+        SampleFormat::F32 => input_stream::<f32>(device, &config, sample_rate, audio),
+        SampleFormat::I16 => input_stream::<i16>(device, &config, sample_rate, audio),
+        SampleFormat::U16 => input_stream::<u16>(device, &config, sample_rate, audio),
+        _ => return Err(format!("Unsupported sample format {sample_format:?}").into()),
+    }?;
+    Ok(stream)
+}
+
+fn input_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    sample_rate: u32,
+    audio: SharedAudio,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let channels = usize::from(config.channels.max(1));
+    let mut resampler = Resampler::new(config.sample_rate.0, sample_rate);
+    let max_buffered = sample_rate as usize * MAX_BUFFERED_SECONDS;
+    let mut mono = Vec::new();
+    let mut resampled = Vec::new();
+
+    device.build_input_stream(
+        config,
+        move |data: &[T], _| {
+            mono.clear();
+            mono.extend(data.chunks(channels).map(|frame| {
+                frame.iter().map(|s| f32::from_sample(*s)).sum::<f32>() / frame.len() as f32
+            }));
+
+            resampled.clear();
+            resampler.process(&mono, |sample| resampled.push(sample));
+
+            let mut audio = lock(&audio);
+            let CapturedAudio { samples, loop_back } = &mut *audio;
+            for queue in std::iter::once(samples).chain(loop_back) {
+                queue.extend(&resampled);
+                let excess = queue.len().saturating_sub(max_buffered);
+                queue.drain(..excess);
+            }
+        },
+        |err| tracing::error!("Microphone stream error: {}", err),
+        None,
+    )
+}
+
+/// Starts playing the audio captured by `capture` through the default output device.
+fn build_loop_back_stream(
+    capture: &Capture,
+    volume: Arc<Mutex<f32>>,
+) -> Result<cpal::Stream, Box<dyn std::error::Error>> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or("No audio output device")?;
+    let config = device.default_output_config()?;
+    let sample_format = config.sample_format();
+    let config = cpal::StreamConfig::from(config);
+
+    let audio = capture.audio.clone();
+    let sample_rate = capture.sample_rate;
+    let stream = match sample_format {
+        SampleFormat::F32 => output_stream::<f32>(&device, &config, sample_rate, audio, volume),
+        SampleFormat::I16 => output_stream::<i16>(&device, &config, sample_rate, audio, volume),
+        SampleFormat::U16 => output_stream::<u16>(&device, &config, sample_rate, audio, volume),
+        _ => return Err(format!("Unsupported sample format {sample_format:?}").into()),
+    }?;
+    stream.play()?;
+    Ok(stream)
+}
+
+fn output_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    sample_rate: u32,
+    audio: SharedAudio,
+    volume: Arc<Mutex<f32>>,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = usize::from(config.channels.max(1));
+    let mut resampler = Resampler::new(sample_rate, config.sample_rate.0);
+    let mut input = Vec::new();
+    let mut output = VecDeque::new();
+
+    device.build_output_stream(
+        config,
+        move |data: &mut [T], _| {
+            let frames = data.len() / channels;
+            let volume = *lock(&volume);
+
+            // Only take as much input as is needed to fill this buffer.
+            let needed = ((frames.saturating_sub(output.len())) as f64 * resampler.step).ceil();
+            input.clear();
+            if let Some(samples) = &mut lock(&audio).loop_back {
+                let count = (needed as usize).min(samples.len());
+                input.extend(samples.drain(..count));
+            }
+            resampler.process(&input, |sample| output.push_back(sample));
+
+            for frame in data.chunks_mut(channels) {
+                let sample = output.pop_front().unwrap_or(0.0) * volume;
+                frame.fill(T::from_sample(sample.clamp(-1.0, 1.0)));
+            }
+        },
+        |err| tracing::error!("Microphone loop back stream error: {}", err),
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Resampler;
+
+    fn resample(resampler: &mut Resampler, input: &[f32]) -> Vec<f32> {
+        let mut output = Vec::new();
+        resampler.process(input, |sample| output.push(sample));
+        output
+    }
+
+    #[test]
+    fn resampling_to_the_same_rate_delays_by_one_sample() {
+        let mut resampler = Resampler::new(8000, 8000);
+        assert_eq!(resample(&mut resampler, &[0.5, 1.0]), [0.0, 0.5]);
+        assert_eq!(resample(&mut resampler, &[0.25]), [1.0]);
+    }
+
+    #[test]
+    fn upsampling_interpolates_across_chunks() {
+        let mut resampler = Resampler::new(1, 2);
+        assert_eq!(resample(&mut resampler, &[1.0]), [0.0, 0.5]);
+        assert_eq!(resample(&mut resampler, &[0.0]), [1.0, 0.5]);
+    }
+
+    #[test]
+    fn downsampling_skips_samples() {
+        let mut resampler = Resampler::new(2, 1);
+        assert_eq!(resample(&mut resampler, &[1.0, 2.0, 3.0, 4.0]), [0.0, 2.0]);
+        assert_eq!(resample(&mut resampler, &[5.0, 6.0]), [4.0]);
+    }
+}
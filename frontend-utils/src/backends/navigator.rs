@@ -1,7 +1,8 @@
 mod fetch;
 
+use crate::air::{APPLICATION_SCHEME, APPLICATION_STORAGE_SCHEME};
 use crate::backends::navigator::fetch::{Response, ResponseBody};
-use crate::content::PlayingContent;
+use crate::content::{PlayingContent, join_url_path};
 use async_channel::{Receiver, Sender, TryRecvError};
 use async_io::Timer;
 use futures_lite::FutureExt;
@@ -18,7 +19,7 @@ use std::fs::File;
 use std::future::Future;
 use std::io;
 use std::io::ErrorKind;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -63,6 +64,9 @@ pub struct ExternalNavigatorBackend<F: FutureSpawner<Error>, I: NavigatorInterfa
 
     content: Rc<PlayingContent>,
 
+    /// Where `app-storage:` URLs point to.
+    application_storage_directory: Option<PathBuf>,
+
     interface: I,
 }
 
@@ -129,9 +133,22 @@ impl<F: FutureSpawner<Error>, I: NavigatorInterface> ExternalNavigatorBackend<F,
             socket_allowed,
             socket_mode,
             content,
+            application_storage_directory: None,
             interface,
         }
     }
+
+    /// Sets the directory where the `app-storage:` URLs of an AIR application point to.
+    pub fn with_application_storage_directory(mut self, directory: PathBuf) -> Self {
+        self.application_storage_directory = Some(directory);
+        self
+    }
+}
+
+/// Reads a file from the private storage of an AIR application.
+fn read_application_storage_file(directory: Option<&Path>, url: &Url) -> io::Result<Vec<u8>> {
+    let directory = directory.ok_or(ErrorKind::NotFound)?;
+    std::fs::read(join_url_path(directory, url)?)
 }
 
 impl<F: FutureSpawner<Error> + 'static, I: NavigatorInterface> NavigatorBackend
@@ -197,9 +214,10 @@ impl<F: FutureSpawner<Error> + 'static, I: NavigatorInterface> NavigatorBackend
         let client = self.client.clone();
 
         match processed_url.scheme() {
-            "file" => {
+            "file" | APPLICATION_SCHEME | APPLICATION_STORAGE_SCHEME => {
                 let content = self.content.clone();
                 let interface = self.interface.clone();
+                let application_storage_directory = self.application_storage_directory.clone();
                 Box::pin(async move {
                     // We send the original url (including query parameters)
                     // back to ruffle_core in the `Response`
@@ -209,7 +227,14 @@ impl<F: FutureSpawner<Error> + 'static, I: NavigatorInterface> NavigatorBackend
                     // when we actually load a filesystem url, strip them out.
                     processed_url.set_query(None);
 
-                    let contents = content.get_local_file(&processed_url, interface).await;
+                    let contents = if processed_url.scheme() == APPLICATION_STORAGE_SCHEME {
+                        read_application_storage_file(
+                            application_storage_directory.as_deref(),
+                            &processed_url,
+                        )
+                    } else {
+                        content.get_local_file(&processed_url, interface).await
+                    };
 
                     let response: Box<dyn SuccessResponse> = Box::new(Response {
                         url: response_url.to_string(),
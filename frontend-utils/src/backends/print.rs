@@ -0,0 +1,330 @@
+use flate2::Compression;
+use flate2::write::ZlibEncoder;
+use ruffle_core::backend::print::{PrintBackend, PrintJobSettings, PrintPage};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// The kind of files print jobs are written to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrintFormat {
+    /// A single PDF document with every page.
+    Pdf,
+
+    /// A PNG image per page.
+    Png,
+}
+
+impl PrintFormat {
+    /// The format of a file, judging by its extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        if extension.eq_ignore_ascii_case("pdf") {
+            Some(Self::Pdf)
+        } else if extension.eq_ignore_ascii_case("png") {
+            Some(Self::Png)
+        } else {
+            None
+        }
+    }
+}
+
+/// Writes the pages of a print job to `path`, returning the files which were written.
+///
+/// PNG images of jobs with several pages are numbered, so `job.png` becomes `job-1.png`,
+/// `job-2.png` and so on.
+pub fn write_print_job(
+    path: &Path,
+    format: PrintFormat,
+    pages: &[PrintPage],
+) -> io::Result<Vec<PathBuf>> {
+    match format {
+        PrintFormat::Pdf => {
+            let mut file = BufWriter::new(File::create(path)?);
+            write_pdf(&mut file, pages)?;
+            file.flush()?;
+            Ok(vec![path.to_path_buf()])
+        }
+        PrintFormat::Png if pages.len() == 1 => {
+            write_png(BufWriter::new(File::create(path)?), &pages[0])?;
+            Ok(vec![path.to_path_buf()])
+        }
+        PrintFormat::Png => {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let mut paths = Vec::with_capacity(pages.len());
+            for (index, page) in pages.iter().enumerate() {
+                let page_path = path.with_file_name(format!("{stem}-{}.png", index + 1));
+                write_png(BufWriter::new(File::create(&page_path)?), page)?;
+                paths.push(page_path);
+            }
+            Ok(paths)
+        }
+    }
+}
+
+/// Writes a PDF document showing each page as an image filling it.
+pub fn write_pdf(writer: &mut impl Write, pages: &[PrintPage]) -> io::Result<()> {
+    let mut pdf = PdfWriter::default();
+    pdf.buffer
+        .extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+    // Objects 1 and 2 are the catalog and the page tree, followed by the page, the content
+    // stream and the image of each page.
+    let page_id = |index: usize| 3 + index * 3;
+    pdf.object(1, b"<< /Type /Catalog /Pages 2 0 R >>");
+    let kids: Vec<_> = (0..pages.len())
+        .map(|index| format!("{} 0 R", page_id(index)))
+        .collect();
+    pdf.object(
+        2,
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        )
+        .as_bytes(),
+    );
+
+    for (index, page) in pages.iter().enumerate() {
+        let id = page_id(index);
+        pdf.object(
+            id,
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /XObject << /Page {} 0 R >> >> /Contents {} 0 R >>",
+                page.width,
+                page.height,
+                id + 2,
+                id + 1
+            )
+            .as_bytes(),
+        );
+
+        let contents = format!("q {} 0 0 {} 0 0 cm /Page Do Q", page.width, page.height);
+        pdf.stream(id + 1, "", contents.as_bytes());
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        for pixel in page.rgba.chunks_exact(4) {
+            encoder.write_all(&pixel[..3])?;
+        }
+        let image = encoder.finish()?;
+        pdf.stream(
+            id + 2,
+            &format!(
+                "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB \
+                 /BitsPerComponent 8 /Filter /FlateDecode ",
+                page.pixel_width, page.pixel_height
+            ),
+            &image,
+        );
+    }
+
+    pdf.finish(writer)
+}
+
+/// Writes a page as a PNG image.
+pub fn write_png(writer: impl Write, page: &PrintPage) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(writer, page.pixel_width, page.pixel_height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    // The page is printed at its size in points, which are 1/72 of an inch.
+    let pixels_per_meter = (page.pixel_width as f64 / page.width as f64 * 72.0 / 0.0254) as u32;
+    encoder.set_pixel_dims(Some(png::PixelDimensions {
+        xppu: pixels_per_meter,
+        yppu: pixels_per_meter,
+        unit: png::Unit::Meter,
+    }));
+
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&page.rgba)
+        .map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+#[derive(Default)]
+struct PdfWriter {
+    buffer: Vec<u8>,
+
+    /// The offset of each object, by object number minus one.
+    offsets: Vec<usize>,
+}
+
+impl PdfWriter {
+    fn start_object(&mut self, id: usize) {
+        if self.offsets.len() < id {
+            self.offsets.resize(id, 0);
+        }
+        self.offsets[id - 1] = self.buffer.len();
+        self.buffer
+            .extend_from_slice(format!("{id} 0 obj\n").as_bytes());
+    }
+
+    fn object(&mut self, id: usize, content: &[u8]) {
+        self.start_object(id);
+        self.buffer.extend_from_slice(content);
+        self.buffer.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn stream(&mut self, id: usize, dictionary: &str, data: &[u8]) {
+        self.start_object(id);
+        self.buffer.extend_from_slice(
+            format!("<< {dictionary}/Length {} >>\nstream\n", data.len()).as_bytes(),
+        );
+        self.buffer.extend_from_slice(data);
+        self.buffer.extend_from_slice(b"\nendstream\nendobj\n");
+    }
+
+    fn finish(mut self, writer: &mut impl Write) -> io::Result<()> {
+        let xref_offset = self.buffer.len();
+        let size = self.offsets.len() + 1;
+        self.buffer
+            .extend_from_slice(format!("xref\n0 {size}\n0000000000 65535 f \n").as_bytes());
+        for offset in &self.offsets {
+            self.buffer
+                .extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        self.buffer.extend_from_slice(
+            format!("trailer\n<< /Size {size} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n")
+                .as_bytes(),
+        );
+        writer.write_all(&self.buffer)
+    }
+}
+
+/// A print backend writing the pages of every print job to a file.
+///
+/// Every job overwrites the files of the previous one.
+pub struct FilePrintBackend {
+    path: PathBuf,
+    format: PrintFormat,
+    settings: PrintJobSettings,
+    pages: Vec<PrintPage>,
+}
+
+impl FilePrintBackend {
+    pub fn new(path: PathBuf, format: PrintFormat, settings: PrintJobSettings) -> Self {
+        Self {
+            path,
+            format,
+            settings,
+            pages: Vec::new(),
+        }
+    }
+}
+
+impl PrintBackend for FilePrintBackend {
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    fn start_job(&mut self) -> Option<PrintJobSettings> {
+        self.pages.clear();
+        Some(self.settings)
+    }
+
+    fn add_page(&mut self, page: PrintPage) {
+        self.pages.push(page);
+    }
+
+    fn send(&mut self) {
+        let pages = std::mem::take(&mut self.pages);
+        if pages.is_empty() {
+            return;
+        }
+
+        match write_print_job(&self.path, self.format, &pages) {
+            Ok(paths) => tracing::info!("Printed {} page(s) to {paths:?}", pages.len()),
+            Err(e) => tracing::error!("Couldn't print to {:?}: {e}", self.path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(width: u32, height: u32, color: [u8; 4]) -> PrintPage {
+        let (pixel_width, pixel_height) = (width * 2, height * 2);
+        PrintPage {
+            width,
+            height,
+            pixel_width,
+            pixel_height,
+            rgba: color.repeat((pixel_width * pixel_height) as usize),
+        }
+    }
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(
+            PrintFormat::from_path(Path::new("coupon.PDF")),
+            Some(PrintFormat::Pdf)
+        );
+        assert_eq!(
+            PrintFormat::from_path(Path::new("/tmp/coupon.png")),
+            Some(PrintFormat::Png)
+        );
+        assert_eq!(PrintFormat::from_path(Path::new("coupon")), None);
+    }
+
+    #[test]
+    fn pdf_has_every_page() {
+        let mut pdf = Vec::new();
+        write_pdf(
+            &mut pdf,
+            &[page(612, 792, [255; 4]), page(792, 612, [0, 0, 0, 255])],
+        )
+        .unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("/Kids [3 0 R 6 0 R] /Count 2"));
+        assert!(text.contains("/MediaBox [0 0 612 792]"));
+        assert!(text.contains("/MediaBox [0 0 792 612]"));
+        assert!(text.contains("/Width 1584 /Height 1224"));
+    }
+
+    #[test]
+    fn pdf_cross_reference_table_points_at_objects() {
+        let mut pdf = Vec::new();
+        write_pdf(&mut pdf, &[page(10, 10, [255; 4])]).unwrap();
+
+        // Everything after the last stream is text.
+        let end_of_streams = pdf
+            .windows(10)
+            .rposition(|window| window == b"endstream\n")
+            .unwrap();
+        let tail = std::str::from_utf8(&pdf[end_of_streams..]).unwrap();
+        let start_xref: usize = tail
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|rest| rest.lines().next())
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(pdf[start_xref..].starts_with(b"xref\n0 6\n"));
+
+        let xref = std::str::from_utf8(&pdf[start_xref..]).unwrap();
+        for (index, entry) in xref.lines().skip(3).take(5).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", index + 1).as_bytes()));
+        }
+    }
+
+    #[test]
+    fn png_round_trips() {
+        let page = page(3, 2, [10, 20, 30, 255]);
+        let mut data = Vec::new();
+        write_png(&mut data, &page).unwrap();
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(data));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!((info.width, info.height), (6, 4));
+        assert_eq!(pixels, page.rgba);
+    }
+}
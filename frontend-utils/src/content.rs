@@ -1,3 +1,4 @@
+use crate::air::{APPLICATION_SCHEME, AirApplication};
 use crate::bundle::Bundle;
 use std::fmt::{Debug, Formatter};
 use url::Url;
@@ -57,6 +58,7 @@ impl ContentDescriptor {
 pub enum PlayingContent {
     DirectFile(ContentDescriptor),
     Bundle(ContentDescriptor, Box<Bundle>),
+    Air(ContentDescriptor, Box<AirApplication>),
 }
 
 impl Debug for PlayingContent {
@@ -71,6 +73,11 @@ impl Debug for PlayingContent {
                 .field(desc)
                 .field(&"_")
                 .finish(),
+            PlayingContent::Air(desc, _) => f
+                .debug_tuple("PlayingContent::Air")
+                .field(desc)
+                .field(&"_")
+                .finish(),
         }
     }
 }
@@ -80,6 +87,7 @@ impl PlayingContent {
         match self {
             PlayingContent::DirectFile(desc) => &desc.url,
             PlayingContent::Bundle(_, bundle) => &bundle.information().url,
+            PlayingContent::Air(_, application) => application.content_url(),
         }
     }
 
@@ -87,6 +95,7 @@ impl PlayingContent {
         match self {
             PlayingContent::DirectFile(desc) => crate::url_to_readable_name(&desc.url).to_string(),
             PlayingContent::Bundle(_, bundle) => bundle.information().name.to_string(),
+            PlayingContent::Air(_, application) => application.name().to_string(),
         }
     }
 
@@ -98,8 +107,8 @@ impl PlayingContent {
     ) -> Result<Vec<u8>, std::io::Error> {
         use std::io::{ErrorKind, Read};
 
-        match self {
-            PlayingContent::DirectFile(_) => {
+        match (self, url.scheme()) {
+            (PlayingContent::DirectFile(_) | PlayingContent::Air(..), "file") => {
                 let path = url
                     .to_file_path()
                     .map_err(|_| std::io::Error::other("Could not turn url into file path"))?;
@@ -108,23 +117,65 @@ impl PlayingContent {
                 file.read_to_end(&mut result)?;
                 Ok(result)
             }
-            PlayingContent::Bundle(_, bundle) => {
-                if url.scheme() != "file" {
-                    return Err(ErrorKind::NotFound.into());
-                }
-                let mut path = String::new();
-                if let Some(segments) = url.path_segments() {
-                    for segment in segments {
-                        path.push('/');
-                        path.push_str(
-                            urlencoding::decode(segment)
-                                .map_err(std::io::Error::other)?
-                                .as_ref(),
-                        );
-                    }
-                }
-                bundle.source().read_content(&path)
+            // Without a package, the application is the directory of the SWF.
+            (PlayingContent::DirectFile(desc), APPLICATION_SCHEME) => {
+                let swf_path = desc
+                    .url
+                    .to_file_path()
+                    .map_err(|_| std::io::Error::other("Could not turn url into file path"))?;
+                let directory = swf_path.parent().ok_or(ErrorKind::NotFound)?;
+                let path = join_url_path(directory, url)?;
+                let mut result = vec![];
+                let mut file = interface.open_file(&path).await?;
+                file.read_to_end(&mut result)?;
+                Ok(result)
+            }
+            (PlayingContent::Bundle(_, bundle), "file") => {
+                bundle.source().read_content(&url_path(url)?)
             }
+            (PlayingContent::Air(_, application), APPLICATION_SCHEME) => {
+                application.source().read_file(&url_path(url)?)
+            }
+            _ => Err(ErrorKind::NotFound.into()),
+        }
+    }
+}
+
+/// The decoded path of a URL, such as `/foo bar/baz.swf`.
+#[cfg(feature = "navigator")]
+fn url_path(url: &Url) -> Result<String, std::io::Error> {
+    let mut path = String::new();
+    if let Some(segments) = url.path_segments() {
+        for segment in segments {
+            path.push('/');
+            path.push_str(
+                urlencoding::decode(segment)
+                    .map_err(std::io::Error::other)?
+                    .as_ref(),
+            );
+        }
+    }
+    Ok(path)
+}
+
+/// Appends the decoded path of a URL to a directory, refusing paths that would escape it.
+#[cfg(feature = "navigator")]
+pub(crate) fn join_url_path(
+    directory: &std::path::Path,
+    url: &Url,
+) -> Result<std::path::PathBuf, std::io::Error> {
+    use std::path::{Component, Path};
+
+    let mut path = directory.to_owned();
+    for segment in url_path(url)?.split('/').filter(|s| !s.is_empty()) {
+        let mut components = Path::new(segment).components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return Err(std::io::ErrorKind::NotFound.into());
         }
+        path.push(segment);
     }
+    Ok(path)
 }
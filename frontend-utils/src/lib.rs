@@ -1,3 +1,4 @@
+pub mod air;
 pub mod bookmarks;
 pub mod bundle;
 pub mod parse;
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("the MP4 parser ran out of data")]
+    EndOfData,
+
+    #[error(
+        "the MP4 cannot be read as its length exceeds the maximum memory size for this architecture"
+    )]
+    PointerTooBig,
+
+    #[error("the MP4 contains a box with an invalid size")]
+    InvalidBoxSize,
+
+    #[error("the MP4 does not contain a required {0} box")]
+    MissingBox(&'static str),
+
+    #[error("the MP4 contains a {0} box of unknown version {1}")]
+    UnknownBoxVersion(&'static str, u8),
+
+    #[error("the MP4 contains a track with an inconsistent sample table")]
+    InvalidSampleTable,
+
+    #[error("the MP4 contains a track with a timescale of 0")]
+    InvalidTimescale,
+}
@@ -0,0 +1,15 @@
+//! A demuxer for ISO base media files (MP4 and F4V).
+//!
+//! Only the structure of the file is parsed, i.e. the `moov` box and the
+//! sample tables in it. Sample data is left in place, so that callers can
+//! fetch it from their own buffer as it downloads.
+
+mod error;
+mod movie;
+mod reader;
+mod sample;
+
+pub use error::Error;
+pub use movie::{AudioCodec, Media, Movie, Track, VideoCodec, find_moov, sniff};
+pub use reader::{BoxHeader, FourCc, Mp4Reader};
+pub use sample::Sample;
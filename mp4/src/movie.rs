@@ -0,0 +1,739 @@
+use crate::error::Error;
+use crate::reader::{FourCc, Mp4Reader};
+use crate::sample::{Sample, SampleTables};
+
+/// Check if the given data looks like the start of an MP4 file.
+pub fn sniff(data: &[u8]) -> bool {
+    data.get(4..8) == Some(b"ftyp")
+}
+
+/// Look for the `moov` box of a (possibly partially downloaded) MP4 file
+/// by scanning its top-level boxes, starting with the one at `offset`.
+///
+/// Once the `moov` box is fully contained in the data, `offset` is left
+/// pointing at it. Otherwise, `EndOfData` is returned and `offset` is moved
+/// to the first box that could be the `moov` box, so that the scan can be
+/// resumed from there as more data arrives. Boxes are skipped without
+/// having to be downloaded, which lets `mdat` come first.
+pub fn find_moov(data: &[u8], offset: &mut usize) -> Result<(), Error> {
+    loop {
+        let mut reader = Mp4Reader::from_source(data.get(*offset..).ok_or(Error::EndOfData)?);
+        let header = reader.read_box_header()?;
+        let content_length = match header.content_length {
+            Some(length) => length,
+            // The last box extends to the end of the file.
+            None if &header.kind == b"moov" => reader.remaining(),
+            None => return Err(Error::MissingBox("moov")),
+        };
+
+        if &header.kind == b"moov" {
+            return reader.read(content_length).map(|_| ());
+        }
+
+        *offset = offset
+            .checked_add(reader.position() + content_length)
+            .ok_or(Error::PointerTooBig)?;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VideoCodec {
+    /// H.264, along with its `AVCDecoderConfigurationRecord`.
+    Avc {
+        config: Vec<u8>,
+    },
+
+    Unknown(FourCc),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AudioCodec {
+    /// AAC, along with its `AudioSpecificConfig`.
+    Aac {
+        config: Vec<u8>,
+        object_type: u8,
+    },
+
+    Mp3,
+
+    Unknown(FourCc),
+}
+
+/// What a track contains, according to its first sample description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Media {
+    Video {
+        width: u16,
+        height: u16,
+        codec: VideoCodec,
+    },
+    Audio {
+        sample_rate: u32,
+        channels: u16,
+        codec: AudioCodec,
+    },
+    Other(FourCc),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    pub id: u32,
+
+    /// The number of time units per second in this track.
+    pub timescale: u32,
+
+    /// The length of the track, in units of its timescale.
+    pub duration: u64,
+
+    /// The ISO 639-2/T code of the language of the track, e.g. `eng`.
+    pub language: String,
+
+    /// The size the track is displayed at, in pixels.
+    pub width: f64,
+    pub height: f64,
+
+    pub media: Media,
+
+    /// Every sample of the track, in decoding order.
+    pub samples: Vec<Sample>,
+}
+
+impl Track {
+    /// Convert a time in units of the timescale of this track to
+    /// milliseconds.
+    pub fn time_to_ms(&self, time: u64) -> f64 {
+        time as f64 * 1000.0 / self.timescale as f64
+    }
+
+    /// Convert a time in milliseconds to units of the timescale of this
+    /// track.
+    pub fn ms_to_time(&self, time: f64) -> u64 {
+        (time.max(0.0) * self.timescale as f64 / 1000.0) as u64
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        self.time_to_ms(self.duration)
+    }
+
+    /// The index of the last sync sample decoded at or before the given time,
+    /// falling back to the first sample.
+    pub fn sync_sample_before(&self, time_ms: f64) -> usize {
+        let time = self.ms_to_time(time_ms);
+        let end = self
+            .samples
+            .partition_point(|sample| sample.decode_time <= time);
+
+        self.samples[..end]
+            .iter()
+            .rposition(|sample| sample.is_sync)
+            .unwrap_or(0)
+    }
+
+    /// The index of the first sample decoded at or after the given time. This
+    /// is the number of samples if there is none.
+    pub fn sample_after(&self, time_ms: f64) -> usize {
+        let time = self.ms_to_time(time_ms);
+
+        self.samples
+            .partition_point(|sample| sample.decode_time < time)
+    }
+}
+
+/// The structure of an MP4 file, as described by its `moov` box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Movie {
+    /// The number of time units per second in the movie header.
+    pub timescale: u32,
+
+    /// The length of the movie, in units of its timescale.
+    pub duration: u64,
+
+    /// The position of the `moov` box in the file.
+    pub moov_offset: usize,
+
+    pub tracks: Vec<Track>,
+}
+
+impl Movie {
+    /// Parse the structure of an MP4 file from its (possibly partially
+    /// downloaded) data.
+    ///
+    /// `EndOfData` is returned if the `moov` box isn't fully contained in the
+    /// data yet. Fragmented files and edit lists are not supported.
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        let mut moov_offset = 0;
+        find_moov(data, &mut moov_offset)?;
+        Self::parse_at(data, moov_offset)
+    }
+
+    /// Parse the structure of an MP4 file whose complete `moov` box starts at
+    /// `moov_offset`, as found by [`find_moov`].
+    pub fn parse_at(data: &[u8], moov_offset: usize) -> Result<Self, Error> {
+        let mut reader = Mp4Reader::from_source(data);
+        reader.skip(moov_offset)?;
+        let header = reader.read_box_header()?;
+        if &header.kind != b"moov" {
+            return Err(Error::MissingBox("moov"));
+        }
+
+        let moov = reader.read_box_contents(&header)?;
+        Self::parse_moov(moov, moov_offset)
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        if self.timescale == 0 {
+            return 0.0;
+        }
+
+        self.duration as f64 * 1000.0 / self.timescale as f64
+    }
+
+    /// The index of the first video track, if any.
+    pub fn video_track(&self) -> Option<usize> {
+        self.tracks
+            .iter()
+            .position(|track| matches!(track.media, Media::Video { .. }))
+    }
+
+    /// The index of the first audio track, if any.
+    pub fn audio_track(&self) -> Option<usize> {
+        self.tracks
+            .iter()
+            .position(|track| matches!(track.media, Media::Audio { .. }))
+    }
+
+    fn parse_moov(moov: Mp4Reader<'_>, moov_offset: usize) -> Result<Self, Error> {
+        let mut header = None;
+        let mut tracks = Vec::new();
+
+        for item in moov.boxes() {
+            let (kind, mut reader) = item?;
+            match &kind {
+                b"mvhd" => {
+                    let (version, _) = reader.read_full_box_header()?;
+                    header = Some(match version {
+                        0 => {
+                            reader.skip(8)?;
+                            (reader.read_u32()?, reader.read_u32()? as u64)
+                        }
+                        1 => {
+                            reader.skip(16)?;
+                            (reader.read_u32()?, reader.read_u64()?)
+                        }
+                        version => return Err(Error::UnknownBoxVersion("mvhd", version)),
+                    });
+                }
+                b"trak" => tracks.push(parse_trak(reader)?),
+                _ => {}
+            }
+        }
+
+        let (timescale, duration) = header.ok_or(Error::MissingBox("mvhd"))?;
+
+        Ok(Self {
+            timescale,
+            duration,
+            moov_offset,
+            tracks,
+        })
+    }
+}
+
+fn parse_trak(trak: Mp4Reader<'_>) -> Result<Track, Error> {
+    let mut id_and_size = None;
+    let mut track = None;
+
+    for item in trak.boxes() {
+        let (kind, mut reader) = item?;
+        match &kind {
+            b"tkhd" => {
+                let (version, _) = reader.read_full_box_header()?;
+                let id = match version {
+                    0 => {
+                        reader.skip(8)?;
+                        let id = reader.read_u32()?;
+                        reader.skip(8)?;
+                        id
+                    }
+                    1 => {
+                        reader.skip(16)?;
+                        let id = reader.read_u32()?;
+                        reader.skip(12)?;
+                        id
+                    }
+                    version => return Err(Error::UnknownBoxVersion("tkhd", version)),
+                };
+
+                // Reserved, layer, alternate group, volume, reserved and matrix.
+                reader.skip(52)?;
+                let width = reader.read_u32()? as f64 / 65536.0;
+                let height = reader.read_u32()? as f64 / 65536.0;
+                id_and_size = Some((id, width, height));
+            }
+            b"mdia" => track = Some(parse_mdia(reader)?),
+            _ => {}
+        }
+    }
+
+    let (id, width, height) = id_and_size.ok_or(Error::MissingBox("tkhd"))?;
+    let mut track = track.ok_or(Error::MissingBox("mdia"))?;
+    track.id = id;
+    track.width = width;
+    track.height = height;
+
+    Ok(track)
+}
+
+fn parse_mdia(mdia: Mp4Reader<'_>) -> Result<Track, Error> {
+    let mut header = None;
+    let mut handler = None;
+    let mut stbl = None;
+
+    for item in mdia.boxes() {
+        let (kind, mut reader) = item?;
+        match &kind {
+            b"mdhd" => {
+                let (version, _) = reader.read_full_box_header()?;
+                let (timescale, duration) = match version {
+                    0 => {
+                        reader.skip(8)?;
+                        (reader.read_u32()?, reader.read_u32()? as u64)
+                    }
+                    1 => {
+                        reader.skip(16)?;
+                        (reader.read_u32()?, reader.read_u64()?)
+                    }
+                    version => return Err(Error::UnknownBoxVersion("mdhd", version)),
+                };
+                if timescale == 0 {
+                    return Err(Error::InvalidTimescale);
+                }
+
+                let language = match reader.read_u16()? {
+                    0 => "und".to_string(),
+                    packed => [10, 5, 0]
+                        .into_iter()
+                        .map(|shift| (((packed >> shift) & 0x1F) as u8 + 0x60) as char)
+                        .collect(),
+                };
+                header = Some((timescale, duration, language));
+            }
+            b"hdlr" => {
+                reader.read_full_box_header()?;
+                reader.skip(4)?;
+                handler = Some(reader.read_fourcc()?);
+            }
+            b"minf" => {
+                for item in reader.boxes() {
+                    let (kind, reader) = item?;
+                    if &kind == b"stbl" {
+                        stbl = Some(parse_stbl(reader)?);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (timescale, duration, language) = header.ok_or(Error::MissingBox("mdhd"))?;
+    let handler = handler.ok_or(Error::MissingBox("hdlr"))?;
+    let (description, samples) = stbl.ok_or(Error::MissingBox("stbl"))?;
+    let media = match (&handler, description) {
+        (b"vide", Some(SampleDescription::Video(media)))
+        | (b"soun", Some(SampleDescription::Audio(media))) => media,
+        _ => Media::Other(handler),
+    };
+
+    Ok(Track {
+        id: 0,
+        timescale,
+        duration,
+        language,
+        width: 0.0,
+        height: 0.0,
+        media,
+        samples,
+    })
+}
+
+enum SampleDescription {
+    Video(Media),
+    Audio(Media),
+}
+
+fn parse_stbl(stbl: Mp4Reader<'_>) -> Result<(Option<SampleDescription>, Vec<Sample>), Error> {
+    let mut description = None;
+    let mut tables = SampleTables::default();
+
+    for item in stbl.boxes() {
+        let (kind, mut reader) = item?;
+        if &kind == b"stsd" {
+            reader.read_full_box_header()?;
+            reader.skip(4)?;
+
+            // Only the first sample description is used for the whole track.
+            if let Some(item) = reader.boxes().next() {
+                let (kind, reader) = item?;
+                description = parse_sample_entry(kind, reader)?;
+            }
+        } else {
+            tables.parse_box(kind, &mut reader)?;
+        }
+    }
+
+    Ok((description, tables.into_samples()?))
+}
+
+fn parse_sample_entry(
+    kind: FourCc,
+    mut reader: Mp4Reader<'_>,
+) -> Result<Option<SampleDescription>, Error> {
+    match &kind {
+        b"avc1" | b"avc3" | b"encv" => {
+            // Reserved, data reference index and pre-defined fields.
+            reader.skip(24)?;
+            let width = reader.read_u16()?;
+            let height = reader.read_u16()?;
+            // Resolution, frame count, compressor name, depth.
+            reader.skip(50)?;
+
+            let mut codec = VideoCodec::Unknown(kind);
+            for item in reader.boxes() {
+                let (kind, mut reader) = item?;
+                if &kind == b"avcC" {
+                    codec = VideoCodec::Avc {
+                        config: reader.read(reader.remaining())?.to_vec(),
+                    };
+                }
+            }
+
+            Ok(Some(SampleDescription::Video(Media::Video {
+                width,
+                height,
+                codec,
+            })))
+        }
+        b"mp4a" | b".mp3" | b"enca" => {
+            reader.skip(8)?;
+            let version = reader.read_u16()?;
+            reader.skip(6)?;
+            let mut channels = reader.read_u16()?;
+            reader.skip(6)?;
+            let mut sample_rate = reader.read_u32()? >> 16;
+            match version {
+                0 => {}
+                1 => reader.skip(16)?,
+                2 => {
+                    reader.skip(4)?;
+                    sample_rate = f64::from_bits(reader.read_u64()?) as u32;
+                    channels = reader.read_u32()? as u16;
+                    reader.skip(20)?;
+                }
+                version => return Err(Error::UnknownBoxVersion("mp4a", version as u8)),
+            }
+
+            let codec = if &kind == b".mp3" {
+                AudioCodec::Mp3
+            } else {
+                find_esds(reader)?.unwrap_or(AudioCodec::Unknown(kind))
+            };
+
+            Ok(Some(SampleDescription::Audio(Media::Audio {
+                sample_rate,
+                channels,
+                codec,
+            })))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Find the `esds` box among the children of an audio sample entry, which
+/// QuickTime files wrap in a `wave` box.
+fn find_esds(reader: Mp4Reader<'_>) -> Result<Option<AudioCodec>, Error> {
+    for item in reader.boxes() {
+        let (kind, mut reader) = item?;
+        match &kind {
+            b"esds" => {
+                reader.read_full_box_header()?;
+                return parse_es_descriptor(&mut reader);
+            }
+            b"wave" => {
+                if let Some(codec) = find_esds(reader)? {
+                    return Ok(Some(codec));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(None)
+}
+
+/// Read the tag and contents of an MPEG-4 descriptor.
+fn read_descriptor<'a>(reader: &mut Mp4Reader<'a>) -> Result<(u8, Mp4Reader<'a>), Error> {
+    let tag = reader.read_u8()?;
+    let mut length = 0;
+    for _ in 0..4 {
+        let byte = reader.read_u8()?;
+        length = (length << 7) | (byte & 0x7F) as usize;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    Ok((tag, Mp4Reader::from_source(reader.read(length)?)))
+}
+
+fn parse_es_descriptor(reader: &mut Mp4Reader<'_>) -> Result<Option<AudioCodec>, Error> {
+    let (tag, mut es) = read_descriptor(reader)?;
+    if tag != 3 {
+        return Ok(None);
+    }
+
+    es.skip(2)?;
+    let flags = es.read_u8()?;
+    if flags & 0x80 != 0 {
+        es.skip(2)?;
+    }
+    if flags & 0x40 != 0 {
+        let length = es.read_u8()?;
+        es.skip(length as usize)?;
+    }
+    if flags & 0x20 != 0 {
+        es.skip(2)?;
+    }
+
+    while es.remaining() > 0 {
+        let (tag, mut decoder_config) = read_descriptor(&mut es)?;
+        if tag != 4 {
+            continue;
+        }
+
+        let object_type_indication = decoder_config.read_u8()?;
+        // Stream type, buffer size and bitrates.
+        decoder_config.skip(12)?;
+
+        let mut config = Vec::new();
+        while decoder_config.remaining() > 0 {
+            let (tag, mut specific_info) = read_descriptor(&mut decoder_config)?;
+            if tag == 5 {
+                config = specific_info.read(specific_info.remaining())?.to_vec();
+            }
+        }
+
+        return Ok(Some(match object_type_indication {
+            0x40 | 0x66..=0x68 => {
+                let object_type = match config.first().map(|byte| byte >> 3) {
+                    Some(31) => {
+                        32 + (((config[0] & 0x7) << 3) | (config.get(1).unwrap_or(&0) >> 5))
+                    }
+                    Some(object_type) => object_type,
+                    None => 0,
+                };
+                AudioCodec::Aac {
+                    config,
+                    object_type,
+                }
+            }
+            0x69 | 0x6B => AudioCodec::Mp3,
+            _ => AudioCodec::Unknown(*b"mp4a"),
+        }));
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::movie::{AudioCodec, Media, Movie, VideoCodec, find_moov, sniff};
+
+    fn mp4_box(kind: &[u8; 4], contents: &[u8]) -> Vec<u8> {
+        let mut data = ((contents.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(contents);
+        data
+    }
+
+    fn full_box(kind: &[u8; 4], contents: &[u8]) -> Vec<u8> {
+        mp4_box(kind, &[&[0, 0, 0, 0], contents].concat())
+    }
+
+    fn u32s(values: &[u32]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect()
+    }
+
+    fn track(id: u32, handler: &[u8; 4], sample_entry: Vec<u8>) -> Vec<u8> {
+        let tkhd = full_box(
+            b"tkhd",
+            &[
+                u32s(&[0, 0, id, 0, 0, 0, 0]),
+                vec![0; 8 + 36],
+                u32s(&[320 << 16, 240 << 16]),
+            ]
+            .concat(),
+        );
+        // Timescale of 1000, duration of 3 samples of 10 ms each, language "eng".
+        let mdhd = full_box(
+            b"mdhd",
+            &[u32s(&[0, 0, 1000, 30]), vec![0x15, 0xC7, 0, 0]].concat(),
+        );
+        let hdlr = full_box(b"hdlr", &[u32s(&[0]), handler.to_vec()].concat());
+        let stbl = mp4_box(
+            b"stbl",
+            &[
+                full_box(b"stsd", &[u32s(&[1]), sample_entry].concat()),
+                full_box(b"stts", &u32s(&[1, 3, 10])),
+                full_box(b"stss", &u32s(&[2, 1, 3])),
+                full_box(b"stsz", &u32s(&[0, 3, 4, 5, 6])),
+                full_box(b"stsc", &u32s(&[1, 1, 3, 1])),
+                full_box(b"stco", &u32s(&[1, 100])),
+            ]
+            .concat(),
+        );
+        let minf = mp4_box(b"minf", &stbl);
+        let mdia = mp4_box(b"mdia", &[mdhd, hdlr, minf].concat());
+
+        mp4_box(b"trak", &[tkhd, mdia].concat())
+    }
+
+    fn avc1() -> Vec<u8> {
+        mp4_box(
+            b"avc1",
+            &[
+                vec![0; 24],
+                vec![1, 64, 0, 240],
+                vec![0; 50],
+                mp4_box(b"avcC", &[1, 2, 3]),
+            ]
+            .concat(),
+        )
+    }
+
+    fn mp4a() -> Vec<u8> {
+        let esds = full_box(
+            b"esds",
+            &[
+                vec![3, 22, 0, 1, 0],
+                vec![4, 17, 0x40, 0x15],
+                vec![0; 11],
+                vec![5, 2, 0x12, 0x10],
+            ]
+            .concat(),
+        );
+        mp4_box(
+            b"mp4a",
+            &[
+                vec![0; 16],
+                vec![0, 2, 0, 16, 0, 0, 0, 0],
+                u32s(&[44100 << 16]),
+                esds,
+            ]
+            .concat(),
+        )
+    }
+
+    fn movie() -> Vec<u8> {
+        let mvhd = full_box(b"mvhd", &[u32s(&[0, 0, 1000, 30]), vec![0; 80]].concat());
+        let moov = mp4_box(
+            b"moov",
+            &[mvhd, track(1, b"vide", avc1()), track(2, b"soun", mp4a())].concat(),
+        );
+
+        [mp4_box(b"ftyp", b"isom"), moov].concat()
+    }
+
+    #[test]
+    fn sniff_ftyp() {
+        assert!(sniff(&movie()));
+        assert!(!sniff(b"FLV\x01\x05"));
+    }
+
+    #[test]
+    fn parse_movie() {
+        let movie = Movie::parse(&movie()).unwrap();
+        assert_eq!(movie.timescale, 1000);
+        assert_eq!(movie.duration_ms(), 30.0);
+        assert_eq!(movie.moov_offset, 12);
+        assert_eq!(movie.tracks.len(), 2);
+
+        let video = &movie.tracks[movie.video_track().unwrap()];
+        assert_eq!(video.id, 1);
+        assert_eq!(video.language, "eng");
+        assert_eq!(video.width, 320.0);
+        assert_eq!(
+            video.media,
+            Media::Video {
+                width: 320,
+                height: 240,
+                codec: VideoCodec::Avc {
+                    config: vec![1, 2, 3]
+                },
+            }
+        );
+        assert_eq!(video.samples.len(), 3);
+        assert_eq!(video.samples[2].offset, 109);
+
+        let audio = &movie.tracks[movie.audio_track().unwrap()];
+        assert_eq!(
+            audio.media,
+            Media::Audio {
+                sample_rate: 44100,
+                channels: 2,
+                codec: AudioCodec::Aac {
+                    config: vec![0x12, 0x10],
+                    object_type: 2,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn seek_in_track() {
+        let movie = Movie::parse(&movie()).unwrap();
+        let video = &movie.tracks[0];
+
+        assert_eq!(video.sync_sample_before(0.0), 0);
+        assert_eq!(video.sync_sample_before(15.0), 0);
+        assert_eq!(video.sync_sample_before(25.0), 2);
+        assert_eq!(video.sample_after(15.0), 2);
+        assert_eq!(video.sample_after(100.0), 3);
+    }
+
+    #[test]
+    fn incomplete_moov() {
+        let data = movie();
+
+        assert_eq!(Movie::parse(&data[..data.len() - 1]), Err(Error::EndOfData));
+        assert_eq!(Movie::parse(&data[..12]), Err(Error::EndOfData));
+    }
+
+    #[test]
+    fn find_moov_as_data_arrives() {
+        let mdat = mp4_box(b"mdat", &[0; 100]);
+        let data = [mp4_box(b"ftyp", b"isom"), mdat, movie()[12..].to_vec()].concat();
+        let mut offset = 0;
+
+        // The `mdat` box is skipped as soon as its header is downloaded.
+        assert_eq!(find_moov(&data[..16], &mut offset), Err(Error::EndOfData));
+        assert_eq!(offset, 12);
+        assert_eq!(find_moov(&data[..30], &mut offset), Err(Error::EndOfData));
+        assert_eq!(offset, 120);
+        assert_eq!(
+            find_moov(&data[..data.len() - 1], &mut offset),
+            Err(Error::EndOfData)
+        );
+        assert_eq!(offset, 120);
+
+        assert_eq!(find_moov(&data, &mut offset), Ok(()));
+        assert_eq!(offset, 120);
+        assert_eq!(
+            Movie::parse_at(&data, offset).map(|movie| movie.moov_offset),
+            Ok(120)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::error::Error;
+
+/// The type of a box, e.g. `moov`.
+pub type FourCc = [u8; 4];
+
+/// The header of a box, read by `Mp4Reader::read_box_header`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoxHeader {
+    pub kind: FourCc,
+
+    /// The position of the first byte of the box header.
+    pub start: usize,
+
+    /// The length of the box contents, excluding the header.
+    ///
+    /// `None` indicates that the box extends to the end of the file.
+    pub content_length: Option<usize>,
+}
+
+/// A reader over the boxes of an MP4 file.
+pub struct Mp4Reader<'a> {
+    source: &'a [u8],
+
+    position: usize,
+}
+
+impl<'a> Mp4Reader<'a> {
+    pub fn from_source(source: &'a [u8]) -> Self {
+        Mp4Reader {
+            source,
+            position: 0,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.source.len().saturating_sub(self.position)
+    }
+
+    /// Read a certain number of bytes from the buffer.
+    ///
+    /// If the requested number of bytes are not available, `EndOfData` is
+    /// returned and the reader does not advance.
+    pub fn read(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let start = self.position;
+        let end = self
+            .position
+            .checked_add(count)
+            .ok_or(Error::PointerTooBig)?;
+        if end > self.source.len() {
+            return Err(Error::EndOfData);
+        }
+
+        self.position = end;
+
+        Ok(&self.source[start..end])
+    }
+
+    /// Advance the reader without looking at the skipped data.
+    ///
+    /// This may advance past the end of the buffer, in which case all further
+    /// reads yield `EndOfData`.
+    pub fn skip(&mut self, count: usize) -> Result<(), Error> {
+        self.position = self
+            .position
+            .checked_add(count)
+            .ok_or(Error::PointerTooBig)?;
+
+        Ok(())
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read(1)?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_be_bytes(
+            self.read(2)?.try_into().expect("two bytes"),
+        ))
+    }
+
+    pub fn read_u24(&mut self) -> Result<u32, Error> {
+        let bytes = self.read(3)?;
+
+        Ok(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(
+            self.read(4)?.try_into().expect("four bytes"),
+        ))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_be_bytes(
+            self.read(8)?.try_into().expect("eight bytes"),
+        ))
+    }
+
+    pub fn read_fourcc(&mut self) -> Result<FourCc, Error> {
+        Ok(self.read(4)?.try_into().expect("four bytes"))
+    }
+
+    /// Read the version and flags of a full box.
+    pub fn read_full_box_header(&mut self) -> Result<(u8, u32), Error> {
+        let version = self.read_u8()?;
+        let flags = self.read_u24()?;
+
+        Ok((version, flags))
+    }
+
+    /// Read the header of the next box, leaving the reader at the start of its
+    /// contents.
+    ///
+    /// The reader retains its prior position if the header couldn't be read.
+    pub fn read_box_header(&mut self) -> Result<BoxHeader, Error> {
+        let start = self.position;
+
+        let ret = (|| {
+            let size = self.read_u32()?;
+            let kind = self.read_fourcc()?;
+            let size = match size {
+                0 => None,
+                1 => Some(self.read_u64()?),
+                size => Some(size as u64),
+            };
+
+            let header_length = (self.position - start) as u64;
+            let content_length = match size {
+                Some(size) if size < header_length => return Err(Error::InvalidBoxSize),
+                Some(size) => {
+                    Some(usize::try_from(size - header_length).map_err(|_| Error::PointerTooBig)?)
+                }
+                None => None,
+            };
+
+            Ok(BoxHeader {
+                kind,
+                start,
+                content_length,
+            })
+        })();
+
+        if ret.is_err() {
+            self.position = start;
+        }
+
+        ret
+    }
+
+    /// Read the contents of a box whose header was just read.
+    pub fn read_box_contents(&mut self, header: &BoxHeader) -> Result<Mp4Reader<'a>, Error> {
+        let length = header.content_length.unwrap_or(self.remaining());
+
+        Ok(Mp4Reader::from_source(self.read(length)?))
+    }
+
+    /// Iterate over the boxes in the rest of this reader, which is expected to
+    /// contain nothing but boxes.
+    pub fn boxes(mut self) -> impl Iterator<Item = Result<(FourCc, Mp4Reader<'a>), Error>> {
+        std::iter::from_fn(move || {
+            if self.remaining() == 0 {
+                return None;
+            }
+
+            let ret = self
+                .read_box_header()
+                .and_then(|header| Ok((header.kind, self.read_box_contents(&header)?)));
+            if ret.is_err() {
+                // Don't yield the same error forever.
+                self.position = self.source.len();
+            }
+
+            Some(ret)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::reader::{BoxHeader, Mp4Reader};
+
+    #[test]
+    fn read_box_header() {
+        let data = [0, 0, 0, 12, b'f', b'r', b'e', b'e', 1, 2, 3, 4];
+        let mut reader = Mp4Reader::from_source(&data);
+
+        assert_eq!(
+            reader.read_box_header(),
+            Ok(BoxHeader {
+                kind: *b"free",
+                start: 0,
+                content_length: Some(4)
+            })
+        );
+        assert_eq!(reader.position(), 8);
+    }
+
+    #[test]
+    fn read_large_box_header() {
+        let data = [
+            0, 0, 0, 1, b'm', b'd', b'a', b't', 0, 0, 0, 0, 0, 0, 0, 20, 1, 2, 3, 4,
+        ];
+        let mut reader = Mp4Reader::from_source(&data);
+
+        assert_eq!(
+            reader.read_box_header(),
+            Ok(BoxHeader {
+                kind: *b"mdat",
+                start: 0,
+                content_length: Some(4)
+            })
+        );
+    }
+
+    #[test]
+    fn read_box_header_short() {
+        let data = [0, 0, 0, 1, b'm', b'd', b'a', b't', 0, 0];
+        let mut reader = Mp4Reader::from_source(&data);
+
+        assert_eq!(reader.read_box_header(), Err(Error::EndOfData));
+        assert_eq!(reader.position(), 0);
+    }
+
+    #[test]
+    fn read_box_header_invalid_size() {
+        let data = [0, 0, 0, 4, b'f', b'r', b'e', b'e'];
+        let mut reader = Mp4Reader::from_source(&data);
+
+        assert_eq!(reader.read_box_header(), Err(Error::InvalidBoxSize));
+    }
+}
@@ -0,0 +1,393 @@
+use crate::error::Error;
+use crate::reader::{FourCc, Mp4Reader};
+
+/// A single sample (i.e. a video frame, or a block of audio) of a track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    /// The position of the sample data in the file.
+    pub offset: u64,
+
+    /// The length of the sample data.
+    pub size: u32,
+
+    /// When the sample is decoded, in units of the track's timescale.
+    pub decode_time: u64,
+
+    /// How much later than its decode time the sample is presented, in units
+    /// of the track's timescale.
+    pub composition_offset: i32,
+
+    /// Whether decoding can start at this sample, i.e. whether it's a
+    /// keyframe.
+    pub is_sync: bool,
+}
+
+/// The raw tables of an `stbl` box, which together describe every sample of a
+/// track.
+#[derive(Default)]
+pub(crate) struct SampleTables {
+    /// `stts`: pairs of sample count and duration.
+    time_to_sample: Vec<(u32, u32)>,
+
+    /// `ctts`: pairs of sample count and composition offset.
+    composition_offsets: Vec<(u32, i32)>,
+
+    /// `stss`: one-based indices of sync samples, if not every sample is one.
+    sync_samples: Option<Vec<u32>>,
+
+    /// `stsz`/`stz2`: the size of every sample.
+    sample_sizes: SampleSizes,
+
+    /// `stsc`: triples of first chunk (one-based), samples per chunk and
+    /// sample description index.
+    sample_to_chunk: Vec<(u32, u32, u32)>,
+
+    /// `stco`/`co64`: the position of every chunk in the file.
+    chunk_offsets: Vec<u64>,
+}
+
+/// The sizes of the samples of a track.
+enum SampleSizes {
+    /// Every sample has the same size. The sizes aren't expanded, as the
+    /// count isn't backed by any data.
+    Constant {
+        size: u32,
+        count: u32,
+    },
+
+    Varying(Vec<u32>),
+}
+
+impl SampleSizes {
+    fn len(&self) -> usize {
+        match self {
+            SampleSizes::Constant { count, .. } => *count as usize,
+            SampleSizes::Varying(sizes) => sizes.len(),
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<u32> {
+        match self {
+            SampleSizes::Constant { size, .. } => (index < self.len()).then_some(*size),
+            SampleSizes::Varying(sizes) => sizes.get(index).copied(),
+        }
+    }
+}
+
+impl Default for SampleSizes {
+    fn default() -> Self {
+        SampleSizes::Varying(Vec::new())
+    }
+}
+
+/// Allocate room for `count` entries of `entry_size` bytes each, without
+/// trusting `count` any further than the data backs it up.
+fn with_capacity_for<T>(reader: &Mp4Reader<'_>, count: u32, entry_size: usize) -> Vec<T> {
+    Vec::with_capacity((count as usize).min(reader.remaining() / entry_size))
+}
+
+impl SampleTables {
+    /// Parse a table box of the `stbl` box, ignoring unknown ones.
+    pub(crate) fn parse_box(
+        &mut self,
+        kind: FourCc,
+        reader: &mut Mp4Reader<'_>,
+    ) -> Result<(), Error> {
+        match &kind {
+            b"stts" => {
+                reader.read_full_box_header()?;
+                let count = reader.read_u32()?;
+                self.time_to_sample = with_capacity_for(reader, count, 8);
+                for _ in 0..count {
+                    self.time_to_sample
+                        .push((reader.read_u32()?, reader.read_u32()?));
+                }
+            }
+            b"ctts" => {
+                // Version 0 offsets are unsigned, version 1 ones are signed.
+                // Either way, offsets over `i32::MAX` don't make sense.
+                reader.read_full_box_header()?;
+                let count = reader.read_u32()?;
+                self.composition_offsets = with_capacity_for(reader, count, 8);
+                for _ in 0..count {
+                    self.composition_offsets
+                        .push((reader.read_u32()?, reader.read_u32()? as i32));
+                }
+            }
+            b"stss" => {
+                reader.read_full_box_header()?;
+                let count = reader.read_u32()?;
+                let mut sync_samples = with_capacity_for(reader, count, 4);
+                for _ in 0..count {
+                    sync_samples.push(reader.read_u32()?);
+                }
+                self.sync_samples = Some(sync_samples);
+            }
+            b"stsz" => {
+                reader.read_full_box_header()?;
+                let sample_size = reader.read_u32()?;
+                let count = reader.read_u32()?;
+                if sample_size != 0 {
+                    self.sample_sizes = SampleSizes::Constant {
+                        size: sample_size,
+                        count,
+                    };
+                } else {
+                    let mut sample_sizes = with_capacity_for(reader, count, 4);
+                    for _ in 0..count {
+                        sample_sizes.push(reader.read_u32()?);
+                    }
+                    self.sample_sizes = SampleSizes::Varying(sample_sizes);
+                }
+            }
+            b"stz2" => {
+                reader.read_full_box_header()?;
+                reader.read_u24()?;
+                let field_size = reader.read_u8()?;
+                let count = reader.read_u32()?;
+                let mut sample_sizes = with_capacity_for(reader, count, 1);
+                match field_size {
+                    4 => {
+                        for index in 0..count {
+                            if index % 2 == 0 {
+                                let byte = reader.read_u8()?;
+                                sample_sizes.push((byte >> 4) as u32);
+                                if index + 1 < count {
+                                    sample_sizes.push((byte & 0xF) as u32);
+                                }
+                            }
+                        }
+                    }
+                    8 => {
+                        for _ in 0..count {
+                            sample_sizes.push(reader.read_u8()? as u32);
+                        }
+                    }
+                    16 => {
+                        for _ in 0..count {
+                            sample_sizes.push(reader.read_u16()? as u32);
+                        }
+                    }
+                    _ => return Err(Error::InvalidSampleTable),
+                }
+                self.sample_sizes = SampleSizes::Varying(sample_sizes);
+            }
+            b"stsc" => {
+                reader.read_full_box_header()?;
+                let count = reader.read_u32()?;
+                self.sample_to_chunk = with_capacity_for(reader, count, 12);
+                for _ in 0..count {
+                    self.sample_to_chunk.push((
+                        reader.read_u32()?,
+                        reader.read_u32()?,
+                        reader.read_u32()?,
+                    ));
+                }
+            }
+            b"stco" => {
+                reader.read_full_box_header()?;
+                let count = reader.read_u32()?;
+                self.chunk_offsets = with_capacity_for(reader, count, 4);
+                for _ in 0..count {
+                    self.chunk_offsets.push(reader.read_u32()? as u64);
+                }
+            }
+            b"co64" => {
+                reader.read_full_box_header()?;
+                let count = reader.read_u32()?;
+                self.chunk_offsets = with_capacity_for(reader, count, 8);
+                for _ in 0..count {
+                    self.chunk_offsets.push(reader.read_u64()?);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Combine the tables into a list of samples, in decoding order.
+    pub(crate) fn into_samples(self) -> Result<Vec<Sample>, Error> {
+        // Constant size samples need at least one chunk each, so don't trust
+        // their count any further than the chunk offsets back it up.
+        let capacity = match &self.sample_sizes {
+            SampleSizes::Constant { count, .. } => (*count as usize).min(self.chunk_offsets.len()),
+            SampleSizes::Varying(sizes) => sizes.len(),
+        };
+        let mut samples = Vec::with_capacity(capacity);
+
+        // Lay the samples out in their chunks.
+        let mut sizes =
+            (0..self.sample_sizes.len()).map_while(|index| self.sample_sizes.get(index));
+        for (index, &(first_chunk, samples_per_chunk, _)) in self.sample_to_chunk.iter().enumerate()
+        {
+            let first_chunk = first_chunk
+                .checked_sub(1)
+                .ok_or(Error::InvalidSampleTable)?;
+            let end_chunk = match self.sample_to_chunk.get(index + 1) {
+                Some(&(next_first_chunk, _, _)) => next_first_chunk
+                    .checked_sub(1)
+                    .ok_or(Error::InvalidSampleTable)?,
+                None => self.chunk_offsets.len() as u32,
+            };
+
+            for chunk in first_chunk..end_chunk {
+                let mut offset = *self
+                    .chunk_offsets
+                    .get(chunk as usize)
+                    .ok_or(Error::InvalidSampleTable)?;
+                for _ in 0..samples_per_chunk {
+                    let Some(size) = sizes.next() else {
+                        break;
+                    };
+
+                    samples.push(Sample {
+                        offset,
+                        size,
+                        decode_time: 0,
+                        composition_offset: 0,
+                        is_sync: self.sync_samples.is_none(),
+                    });
+                    offset = offset
+                        .checked_add(size as u64)
+                        .ok_or(Error::InvalidSampleTable)?;
+                }
+            }
+        }
+
+        if samples.len() != self.sample_sizes.len() {
+            return Err(Error::InvalidSampleTable);
+        }
+
+        let mut decode_time = 0;
+        let durations = self
+            .time_to_sample
+            .iter()
+            .flat_map(|&(count, duration)| std::iter::repeat_n(duration, count as usize));
+        for (sample, duration) in samples.iter_mut().zip(durations) {
+            sample.decode_time = decode_time;
+            decode_time += duration as u64;
+        }
+
+        let offsets = self
+            .composition_offsets
+            .iter()
+            .flat_map(|&(count, offset)| std::iter::repeat_n(offset, count as usize));
+        for (sample, offset) in samples.iter_mut().zip(offsets) {
+            sample.composition_offset = offset;
+        }
+
+        for &index in self.sync_samples.iter().flatten() {
+            if let Some(sample) = index
+                .checked_sub(1)
+                .and_then(|index| samples.get_mut(index as usize))
+            {
+                sample.is_sync = true;
+            }
+        }
+
+        Ok(samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::sample::{Sample, SampleSizes, SampleTables};
+
+    #[test]
+    fn lay_out_samples() {
+        let tables = SampleTables {
+            time_to_sample: vec![(3, 10)],
+            composition_offsets: vec![(1, 0), (2, 20)],
+            sync_samples: Some(vec![1]),
+            sample_sizes: SampleSizes::Varying(vec![4, 5, 6]),
+            sample_to_chunk: vec![(1, 2, 1), (2, 1, 1)],
+            chunk_offsets: vec![100, 200],
+        };
+
+        assert_eq!(
+            tables.into_samples(),
+            Ok(vec![
+                Sample {
+                    offset: 100,
+                    size: 4,
+                    decode_time: 0,
+                    composition_offset: 0,
+                    is_sync: true,
+                },
+                Sample {
+                    offset: 104,
+                    size: 5,
+                    decode_time: 10,
+                    composition_offset: 20,
+                    is_sync: false,
+                },
+                Sample {
+                    offset: 200,
+                    size: 6,
+                    decode_time: 20,
+                    composition_offset: 20,
+                    is_sync: false,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn missing_chunks() {
+        let tables = SampleTables {
+            sample_sizes: SampleSizes::Varying(vec![4, 5, 6]),
+            sample_to_chunk: vec![(1, 2, 1)],
+            chunk_offsets: vec![100],
+            ..Default::default()
+        };
+
+        assert!(tables.into_samples().is_err());
+    }
+
+    #[test]
+    fn constant_sample_size() {
+        let tables = SampleTables {
+            sample_sizes: SampleSizes::Constant { size: 8, count: 3 },
+            sample_to_chunk: vec![(1, 3, 1)],
+            chunk_offsets: vec![100],
+            ..Default::default()
+        };
+
+        let offsets: Vec<_> = tables
+            .into_samples()
+            .unwrap()
+            .iter()
+            .map(|sample| (sample.offset, sample.size))
+            .collect();
+        assert_eq!(offsets, [(100, 8), (108, 8), (116, 8)]);
+    }
+
+    #[test]
+    fn too_many_constant_size_samples() {
+        let tables = SampleTables {
+            sample_sizes: SampleSizes::Constant {
+                size: 8,
+                count: u32::MAX,
+            },
+            sample_to_chunk: vec![(1, 2, 1)],
+            chunk_offsets: vec![100],
+            ..Default::default()
+        };
+
+        assert_eq!(tables.into_samples(), Err(Error::InvalidSampleTable));
+    }
+
+    #[test]
+    fn sample_offset_overflow() {
+        let tables = SampleTables {
+            sample_sizes: SampleSizes::Varying(vec![u32::MAX, 1]),
+            sample_to_chunk: vec![(1, 2, 1)],
+            chunk_offsets: vec![u64::MAX - 10],
+            ..Default::default()
+        };
+
+        assert_eq!(tables.into_samples(), Err(Error::InvalidSampleTable));
+    }
+}
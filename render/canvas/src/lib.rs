@@ -13,6 +13,7 @@ use ruffle_render::bitmap::{
 use ruffle_render::commands::{CommandHandler, CommandList, RenderBlendMode};
 use ruffle_render::error::Error;
 use ruffle_render::matrix::Matrix;
+use ruffle_render::matrix3d::Matrix3D;
 use ruffle_render::quality::StageQuality;
 use ruffle_render::shape_utils::{DistilledShape, DrawCommand, LineScaleMode, LineScales};
 use ruffle_render::transform::Transform;
@@ -625,6 +626,47 @@ impl CommandHandler for WebCanvasRenderBackend {
         self.clear_color_filter();
     }
 
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        matrix: Matrix3D,
+        color_transform: ColorTransform,
+        smoothing: bool,
+    ) {
+        let (width, height) = {
+            let canvas = &as_bitmap_data(&bitmap).canvas;
+            (canvas.width() as f64, canvas.height() as f64)
+        };
+        if width == 0.0 || height == 0.0 {
+            return;
+        }
+
+        // Canvas can't draw with perspective, so approximate it with the affine
+        // transform passing through three of the projected corners.
+        let (x0, y0, _) = matrix.transform_point(0.0, 0.0, 0.0);
+        let (x1, y1, _) = matrix.transform_point(width, 0.0, 0.0);
+        let (x2, y2, _) = matrix.transform_point(0.0, height, 0.0);
+        let matrix = Matrix {
+            a: ((x1 - x0) / width) as f32,
+            b: ((y1 - y0) / width) as f32,
+            c: ((x2 - x0) / height) as f32,
+            d: ((y2 - y0) / height) as f32,
+            tx: Twips::from_pixels(x0),
+            ty: Twips::from_pixels(y0),
+        };
+
+        self.render_bitmap(
+            bitmap,
+            Transform {
+                matrix,
+                color_transform,
+                perspective_projection: None,
+            },
+            smoothing,
+            PixelSnapping::Never,
+        );
+    }
+
     fn render_stage3d(&mut self, _bitmap: BitmapHandle, _transform: Transform) {
         panic!("Stage3D should not have been created on canvas backend")
     }
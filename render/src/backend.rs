@@ -77,6 +77,26 @@ pub trait RenderBackend: Any {
         cache_entries: Vec<BitmapCacheEntry>,
     );
 
+    /// Draws a frame to a window other than the main one, such as a secondary window of an AIR
+    /// application, in the same way `submit_frame` draws to the main one.
+    ///
+    /// `window` is the ID the frontend gave the window when handing it to the backend. Backends
+    /// that don't know about the window draw nothing.
+    fn submit_window_frame(
+        &mut self,
+        _window: u32,
+        _clear: swf::Color,
+        _commands: CommandList,
+        _cache_entries: Vec<BitmapCacheEntry>,
+    ) {
+    }
+
+    /// Resizes a window drawn to with `submit_window_frame`.
+    fn resize_window_target(&mut self, _window: u32, _width: u32, _height: u32) {}
+
+    /// Stops drawing to a window drawn to with `submit_window_frame`, once it's closed.
+    fn remove_window_target(&mut self, _window: u32) {}
+
     fn create_empty_texture(
         &mut self,
         width: NonZeroU32,
@@ -1,9 +1,10 @@
 use crate::backend::ShapeHandle;
 use crate::bitmap::{BitmapHandle, PixelSnapping};
 use crate::matrix::Matrix;
+use crate::matrix3d::Matrix3D;
 use crate::pixel_bender::PixelBenderShaderHandle;
 use crate::transform::Transform;
-use swf::{BlendMode, Color};
+use swf::{BlendMode, Color, ColorTransform};
 
 pub trait CommandHandler {
     fn render_bitmap(
@@ -13,6 +14,15 @@ pub trait CommandHandler {
         smoothing: bool,
         pixel_snapping: PixelSnapping,
     );
+    /// Draws a bitmap with a 3D transform, mapping its pixels to the `z = 0`
+    /// plane of `matrix` (which may include a perspective projection).
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        matrix: Matrix3D,
+        color_transform: ColorTransform,
+        smoothing: bool,
+    );
     fn render_stage3d(&mut self, bitmap: BitmapHandle, transform: Transform);
     fn render_shape(&mut self, shape: ShapeHandle, transform: Transform);
     fn render_alpha_mask(&mut self, maskee_commands: CommandList, mask_commands: CommandList);
@@ -67,6 +77,12 @@ impl CommandList {
                     smoothing,
                     pixel_snapping,
                 } => handler.render_bitmap(bitmap, transform, smoothing, pixel_snapping),
+                Command::RenderBitmapProjected {
+                    bitmap,
+                    matrix,
+                    color_transform,
+                    smoothing,
+                } => handler.render_bitmap_projected(bitmap, matrix, color_transform, smoothing),
                 Command::RenderShape { shape, transform } => handler.render_shape(shape, transform),
                 Command::RenderStage3D { bitmap, transform } => {
                     handler.render_stage3d(bitmap, transform)
@@ -111,6 +127,24 @@ impl CommandHandler for CommandList {
         }
     }
 
+    #[inline]
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        matrix: Matrix3D,
+        color_transform: ColorTransform,
+        smoothing: bool,
+    ) {
+        if self.maskers_in_progress <= 1 {
+            self.commands.push(Command::RenderBitmapProjected {
+                bitmap,
+                matrix,
+                color_transform,
+                smoothing,
+            });
+        }
+    }
+
     #[inline]
     fn render_stage3d(&mut self, bitmap: BitmapHandle, transform: Transform) {
         if self.maskers_in_progress <= 1 {
@@ -205,6 +239,12 @@ pub enum Command {
         smoothing: bool,
         pixel_snapping: PixelSnapping,
     },
+    RenderBitmapProjected {
+        bitmap: BitmapHandle,
+        matrix: Matrix3D,
+        color_transform: ColorTransform,
+        smoothing: bool,
+    },
     RenderStage3D {
         bitmap: BitmapHandle,
         transform: Transform,
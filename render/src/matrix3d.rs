@@ -47,3 +47,357 @@ impl Matrix3D {
         }
     }
 }
+
+/// The components of a 3D transform, as exposed by the `z`, `rotationX`,
+/// `scaleZ`, etc. properties of display objects.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix3DComponents {
+    /// Translation in pixels.
+    pub translation: [f64; 3],
+
+    /// Rotation around the X, Y and Z axes in radians.
+    pub rotation: [f64; 3],
+
+    /// Scale along the X, Y and Z axes.
+    pub scale: [f64; 3],
+}
+
+impl Default for Matrix3DComponents {
+    fn default() -> Self {
+        Self {
+            translation: [0.0; 3],
+            rotation: [0.0; 3],
+            scale: [1.0; 3],
+        }
+    }
+}
+
+impl Matrix3D {
+    pub const IDENTITY: Self = Self {
+        raw_data: [
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, //
+        ],
+    };
+
+    /// Returns the element at the given row and column.
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        self.raw_data[column * 4 + row]
+    }
+
+    #[inline]
+    fn set(&mut self, row: usize, column: usize, value: f64) {
+        self.raw_data[column * 4 + row] = value;
+    }
+
+    pub fn translation(x: f64, y: f64, z: f64) -> Self {
+        let mut matrix = Self::IDENTITY;
+        matrix.raw_data[12] = x;
+        matrix.raw_data[13] = y;
+        matrix.raw_data[14] = z;
+        matrix
+    }
+
+    pub fn scale(x: f64, y: f64, z: f64) -> Self {
+        let mut matrix = Self::IDENTITY;
+        matrix.raw_data[0] = x;
+        matrix.raw_data[5] = y;
+        matrix.raw_data[10] = z;
+        matrix
+    }
+
+    /// A rotation around the X axis, `angle` in radians.
+    pub fn rotation_x(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut matrix = Self::IDENTITY;
+        matrix.set(1, 1, cos);
+        matrix.set(1, 2, -sin);
+        matrix.set(2, 1, sin);
+        matrix.set(2, 2, cos);
+        matrix
+    }
+
+    /// A rotation around the Y axis, `angle` in radians.
+    pub fn rotation_y(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut matrix = Self::IDENTITY;
+        matrix.set(0, 0, cos);
+        matrix.set(0, 2, sin);
+        matrix.set(2, 0, -sin);
+        matrix.set(2, 2, cos);
+        matrix
+    }
+
+    /// A rotation around the Z axis, `angle` in radians.
+    pub fn rotation_z(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut matrix = Self::IDENTITY;
+        matrix.set(0, 0, cos);
+        matrix.set(0, 1, -sin);
+        matrix.set(1, 0, sin);
+        matrix.set(1, 1, cos);
+        matrix
+    }
+
+    /// Builds a matrix that scales, then rotates around the X, Y and Z axes
+    /// (in that order), then translates.
+    pub fn recompose(components: &Matrix3DComponents) -> Self {
+        let [tx, ty, tz] = components.translation;
+        let [rx, ry, rz] = components.rotation;
+        let [sx, sy, sz] = components.scale;
+        Self::translation(tx, ty, tz)
+            * Self::rotation_z(rz)
+            * Self::rotation_y(ry)
+            * Self::rotation_x(rx)
+            * Self::scale(sx, sy, sz)
+    }
+
+    /// Splits this matrix into the components it would be recomposed from.
+    ///
+    /// Any skew or projection in the matrix is lost.
+    pub fn decompose(&self) -> Matrix3DComponents {
+        let translation = [self.raw_data[12], self.raw_data[13], self.raw_data[14]];
+
+        let mut columns = [[0.0; 3]; 3];
+        let mut scale = [0.0; 3];
+        for (i, column) in columns.iter_mut().enumerate() {
+            *column = [self.get(0, i), self.get(1, i), self.get(2, i)];
+            scale[i] = column.iter().map(|v| v * v).sum::<f64>().sqrt();
+        }
+
+        let [c0, c1, c2] = columns;
+        let determinant = c0[0] * (c1[1] * c2[2] - c1[2] * c2[1])
+            - c1[0] * (c0[1] * c2[2] - c0[2] * c2[1])
+            + c2[0] * (c0[1] * c1[2] - c0[2] * c1[1]);
+        if determinant < 0.0 {
+            scale[0] = -scale[0];
+        }
+
+        for (column, scale) in columns.iter_mut().zip(scale) {
+            if scale != 0.0 {
+                column.iter_mut().for_each(|v| *v /= scale);
+            }
+        }
+
+        // `columns[column][row]` now holds the rotation Rz * Ry * Rx.
+        let sin_y = (-columns[0][2]).clamp(-1.0, 1.0);
+        let rotation_y = sin_y.asin();
+        let (rotation_x, rotation_z) = if sin_y.abs() < 1.0 - 1e-9 {
+            (
+                columns[1][2].atan2(columns[2][2]),
+                columns[0][1].atan2(columns[0][0]),
+            )
+        } else {
+            // Gimbal lock: only the sum (or difference) of the X and Z rotations
+            // is known, attribute all of it to X.
+            ((-columns[2][1]).atan2(columns[1][1]), 0.0)
+        };
+
+        Matrix3DComponents {
+            translation,
+            rotation: [rotation_x, rotation_y, rotation_z],
+            scale,
+        }
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.raw_data;
+        let mut inv = [0.0; 16];
+
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+
+        let determinant = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        inv.iter_mut().for_each(|v| *v /= determinant);
+        Some(Self { raw_data: inv })
+    }
+
+    /// Transforms the given point, dividing the result by its `w` component.
+    pub fn transform_point(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let m = &self.raw_data;
+        let out_x = m[0] * x + m[4] * y + m[8] * z + m[12];
+        let out_y = m[1] * x + m[5] * y + m[9] * z + m[13];
+        let out_z = m[2] * x + m[6] * y + m[10] * z + m[14];
+        let w = m[3] * x + m[7] * y + m[11] * z + m[15];
+        (out_x / w, out_y / w, out_z / w)
+    }
+
+    /// Finds the point on the `z = 0` plane which this matrix maps to (`x`, `y`),
+    /// ignoring the resulting `z`.
+    pub fn inverse_transform_plane_point(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        // Restricted to the plane, the matrix is the homography made of rows and
+        // columns 0, 1 and 3.
+        let h = |r: usize, c: usize| self.get([0, 1, 3][r], [0, 1, 3][c]);
+        let cofactor = |r0, r1, c0, c1| h(r0, c0) * h(r1, c1) - h(r0, c1) * h(r1, c0);
+
+        let inverse = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let determinant =
+            h(0, 0) * inverse[0][0] + h(0, 1) * inverse[1][0] + h(0, 2) * inverse[2][0];
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let [out_x, out_y, w] = inverse.map(|row| row[0] * x + row[1] * y + row[2]);
+        if w == 0.0 {
+            return None;
+        }
+        Some((out_x / w, out_y / w))
+    }
+}
+
+impl std::ops::Mul for Matrix3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut raw_data = [0.0; 16];
+        for column in 0..4 {
+            for row in 0..4 {
+                raw_data[column * 4 + row] =
+                    (0..4).map(|i| self.get(row, i) * rhs.get(i, column)).sum();
+            }
+        }
+        Self { raw_data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn assert_matrix_eq(left: Matrix3D, right: Matrix3D) {
+        for (l, r) in left.raw_data.iter().zip(right.raw_data.iter()) {
+            assert!((l - r).abs() < 1e-6, "{left:?} != {right:?}");
+        }
+    }
+
+    #[test]
+    fn recompose_decompose_roundtrip() {
+        let components = Matrix3DComponents {
+            translation: [10.0, -20.0, 30.0],
+            rotation: [0.3, -0.7, 1.2],
+            scale: [2.0, 0.5, 1.5],
+        };
+        let decomposed = Matrix3D::recompose(&components).decompose();
+        for i in 0..3 {
+            assert!((decomposed.translation[i] - components.translation[i]).abs() < 1e-9);
+            assert!((decomposed.rotation[i] - components.rotation[i]).abs() < 1e-9);
+            assert!((decomposed.scale[i] - components.scale[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rotation_z_matches_2d_rotation() {
+        let matrix = Matrix3D::rotation_z(0.5);
+        assert_matrix_eq(matrix, Matrix3D::from_matrix(Matrix::rotate(0.5)));
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = Matrix3D::recompose(&Matrix3DComponents {
+            translation: [5.0, 6.0, 7.0],
+            rotation: [FRAC_PI_2, 0.25, -1.0],
+            scale: [3.0, 2.0, 1.0],
+        });
+        let inverse = matrix.inverse().unwrap();
+        assert_matrix_eq(matrix * inverse, Matrix3D::IDENTITY);
+        assert_eq!(Matrix3D::scale(0.0, 1.0, 1.0).inverse(), None);
+    }
+
+    #[test]
+    fn inverse_transform_plane_point() {
+        let mut projection = Matrix3D::IDENTITY;
+        projection.raw_data[11] = 1.0 / 500.0;
+        let matrix = projection * Matrix3D::rotation_y(0.8) * Matrix3D::translation(3.0, 4.0, 0.0);
+
+        let (x, y, _) = matrix.transform_point(12.0, -7.0, 0.0);
+        let (local_x, local_y) = matrix.inverse_transform_plane_point(x, y).unwrap();
+        assert!((local_x - 12.0).abs() < 1e-9);
+        assert!((local_y + 7.0).abs() < 1e-9);
+    }
+}
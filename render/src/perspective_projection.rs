@@ -23,6 +23,12 @@ impl Default for PerspectiveProjection {
 impl PerspectiveProjection {
     const DEG2RAD: f64 = PI / 180.0;
 
+    /// The width used to derive the focal length from the field of view
+    /// for projections which don't belong to a display object on a stage,
+    /// including the stage's own projection.
+    /// This is the width the default `center` of (250, 250) is the middle of.
+    pub const DEFAULT_STAGE_WIDTH: f32 = 500.0;
+
     pub fn from_focal_length(focal_length: f64, width: f64) -> Self {
         Self {
             field_of_view: f64::atan((width / 2.0) / focal_length) / Self::DEG2RAD * 2.0,
@@ -63,4 +69,45 @@ impl PerspectiveProjection {
             ],
         }
     }
+
+    /// The matrix projecting points in 3D space onto the `z = 0` plane, as seen
+    /// from `focal_length` in front of `center`.
+    pub fn projection_matrix(&self, width: f32) -> Matrix3D {
+        let focal_length = self.focal_length(width) as f64;
+        let (center_x, center_y) = self.center;
+
+        let mut projection = Matrix3D::IDENTITY;
+        projection.raw_data[0] = focal_length;
+        projection.raw_data[5] = focal_length;
+        projection.raw_data[10] = 0.0;
+        projection.raw_data[11] = 1.0;
+        projection.raw_data[15] = focal_length;
+
+        Matrix3D::translation(center_x, center_y, 0.0)
+            * projection
+            * Matrix3D::translation(-center_x, -center_y, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn projection_matrix() {
+        let projection = PerspectiveProjection {
+            field_of_view: 90.0,
+            center: (100.0, 50.0),
+        };
+        // With a field of view of 90 degrees, the focal length is half the width.
+        let matrix = projection.projection_matrix(500.0);
+
+        // Points on the `z = 0` plane are unaffected.
+        let (x, y, _) = matrix.transform_point(30.0, 40.0, 0.0);
+        assert!((x - 30.0).abs() < 1e-9 && (y - 40.0).abs() < 1e-9);
+
+        // Points further away move towards the center.
+        let (x, y, _) = matrix.transform_point(350.0, 50.0, 250.0);
+        assert!((x - 225.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+    }
 }
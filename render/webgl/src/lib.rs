@@ -14,6 +14,7 @@ use ruffle_render::bitmap::{
 use ruffle_render::commands::{CommandHandler, CommandList, RenderBlendMode};
 use ruffle_render::error::Error as BitmapError;
 use ruffle_render::matrix::Matrix;
+use ruffle_render::matrix3d::Matrix3D;
 use ruffle_render::quality::StageQuality;
 use ruffle_render::shape_utils::{DistilledShape, GradientType};
 use ruffle_render::tessellator::{
@@ -25,7 +26,7 @@ use std::any::Any;
 use std::borrow::Cow;
 use std::num::NonZeroU32;
 use std::sync::Arc;
-use swf::{BlendMode, Color, Twips};
+use swf::{BlendMode, Color, ColorTransform, Twips};
 use thiserror::Error;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{
@@ -982,6 +983,83 @@ impl WebGlRenderBackend {
         self.gl
             .draw_elements_with_i32(MODE, count, Gl::UNSIGNED_INT, 0);
     }
+
+    fn draw_bitmap(
+        &mut self,
+        bitmap: &BitmapHandle,
+        world_matrix: [[f32; 4]; 4],
+        color_transform: &ColorTransform,
+        smoothing: bool,
+    ) {
+        self.set_stencil_state();
+        let entry = as_registry_data(bitmap);
+        // Adjust the quad draw to use the target bitmap.
+        let quad = &self.bitmap_quad_draws;
+        let draw = &quad[0];
+        let bitmap_matrix = if let DrawType::Bitmap(BitmapDraw { matrix, .. }) = &draw.draw_type {
+            matrix
+        } else {
+            unreachable!()
+        };
+
+        let mult_color = color_transform.mult_rgba_normalized();
+        let add_color = color_transform.add_rgba_normalized();
+
+        self.bind_vertex_array(Some(&draw.vao));
+
+        let program = &self.bitmap_program;
+
+        // Set common render state, while minimizing unnecessary state changes.
+        // TODO: Using designated layout specifiers in WebGL2/OpenGL ES 3, we could guarantee that uniforms
+        // are in the same location between shaders, and avoid changing them unless necessary.
+        if !std::ptr::eq(program, self.active_program) {
+            self.gl.use_program(Some(&program.program));
+            self.active_program = program as *const ShaderProgram;
+
+            program.uniform_matrix4fv(&self.gl, ShaderUniform::ViewMatrix, &self.view_matrix);
+
+            self.mult_color = None;
+            self.add_color = None;
+        }
+
+        program.uniform_matrix4fv(&self.gl, ShaderUniform::WorldMatrix, &world_matrix);
+        if Some(mult_color) != self.mult_color {
+            program.uniform4fv(&self.gl, ShaderUniform::MultColor, &mult_color);
+            self.mult_color = Some(mult_color);
+        }
+        if Some(add_color) != self.add_color {
+            program.uniform4fv(&self.gl, ShaderUniform::AddColor, &add_color);
+            self.add_color = Some(add_color);
+        }
+
+        program.uniform_matrix3fv(&self.gl, ShaderUniform::TextureMatrix, bitmap_matrix);
+
+        // Bind texture.
+        self.gl.active_texture(Gl::TEXTURE0);
+        self.gl.bind_texture(Gl::TEXTURE_2D, Some(&entry.texture));
+        program.uniform1i(&self.gl, ShaderUniform::BitmapTexture, 0);
+
+        // Set texture parameters.
+        let filter = if smoothing {
+            Gl::LINEAR as i32
+        } else {
+            Gl::NEAREST as i32
+        };
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, filter);
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, filter);
+
+        let wrap = Gl::CLAMP_TO_EDGE as i32;
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, wrap);
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, wrap);
+
+        // Draw the triangles.
+        self.gl
+            .draw_elements_with_i32(Gl::TRIANGLE_FAN, draw.num_indices, Gl::UNSIGNED_INT, 0);
+    }
 }
 
 fn same_blend_mode(first: Option<&RenderBlendMode>, second: &RenderBlendMode) -> bool {
@@ -1259,16 +1337,7 @@ impl CommandHandler for WebGlRenderBackend {
         smoothing: bool,
         pixel_snapping: PixelSnapping,
     ) {
-        self.set_stencil_state();
         let entry = as_registry_data(&bitmap);
-        // Adjust the quad draw to use the target bitmap.
-        let quad = &self.bitmap_quad_draws;
-        let draw = &quad[0];
-        let bitmap_matrix = if let DrawType::Bitmap(BitmapDraw { matrix, .. }) = &draw.draw_type {
-            matrix
-        } else {
-            unreachable!()
-        };
 
         // Scale the quad to the bitmap's dimensions.
         let mut matrix = transform.matrix;
@@ -1287,63 +1356,25 @@ impl CommandHandler for WebGlRenderBackend {
             ],
         ];
 
-        let mult_color = transform.color_transform.mult_rgba_normalized();
-        let add_color = transform.color_transform.add_rgba_normalized();
-
-        self.bind_vertex_array(Some(&draw.vao));
-
-        let program = &self.bitmap_program;
-
-        // Set common render state, while minimizing unnecessary state changes.
-        // TODO: Using designated layout specifiers in WebGL2/OpenGL ES 3, we could guarantee that uniforms
-        // are in the same location between shaders, and avoid changing them unless necessary.
-        if !std::ptr::eq(program, self.active_program) {
-            self.gl.use_program(Some(&program.program));
-            self.active_program = program as *const ShaderProgram;
-
-            program.uniform_matrix4fv(&self.gl, ShaderUniform::ViewMatrix, &self.view_matrix);
-
-            self.mult_color = None;
-            self.add_color = None;
-        }
-
-        program.uniform_matrix4fv(&self.gl, ShaderUniform::WorldMatrix, &world_matrix);
-        if Some(mult_color) != self.mult_color {
-            program.uniform4fv(&self.gl, ShaderUniform::MultColor, &mult_color);
-            self.mult_color = Some(mult_color);
-        }
-        if Some(add_color) != self.add_color {
-            program.uniform4fv(&self.gl, ShaderUniform::AddColor, &add_color);
-            self.add_color = Some(add_color);
-        }
-
-        program.uniform_matrix3fv(&self.gl, ShaderUniform::TextureMatrix, bitmap_matrix);
-
-        // Bind texture.
-        self.gl.active_texture(Gl::TEXTURE0);
-        self.gl.bind_texture(Gl::TEXTURE_2D, Some(&entry.texture));
-        program.uniform1i(&self.gl, ShaderUniform::BitmapTexture, 0);
+        self.draw_bitmap(&bitmap, world_matrix, &transform.color_transform, smoothing);
+    }
 
-        // Set texture parameters.
-        let filter = if smoothing {
-            Gl::LINEAR as i32
-        } else {
-            Gl::NEAREST as i32
-        };
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, filter);
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, filter);
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        matrix: Matrix3D,
+        color_transform: ColorTransform,
+        smoothing: bool,
+    ) {
+        let entry = as_registry_data(&bitmap);
 
-        let wrap = Gl::CLAMP_TO_EDGE as i32;
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, wrap);
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, wrap);
+        // Scale the quad to the bitmap's dimensions.
+        let matrix = matrix * Matrix3D::scale(entry.width as f64, entry.height as f64, 1.0);
+        let world_matrix = std::array::from_fn(|column| {
+            std::array::from_fn(|row| matrix.raw_data[column * 4 + row] as f32)
+        });
 
-        // Draw the triangles.
-        self.gl
-            .draw_elements_with_i32(Gl::TRIANGLE_FAN, draw.num_indices, Gl::UNSIGNED_INT, 0);
+        self.draw_bitmap(&bitmap, world_matrix, &color_transform, smoothing);
     }
 
     fn render_shape(&mut self, shape: ShapeHandle, transform: Transform) {
@@ -0,0 +1,68 @@
+// NOTE: The `shader_filter_common.wgsl` source is prepended to this before compilation.
+
+struct Filter {
+    /// The weights of the matrix, row by row, packed four to a vector.
+    matrix: array<vec4<f32>, 64>,
+    /// The premultiplied color of pixels outside of the source, when not clamping.
+    default_color: vec4<f32>,
+    /// The area of the texture the filter reads from, in pixels (left, top, right, bottom).
+    source_rect: vec4<i32>,
+    columns: u32,
+    rows: u32,
+    divisor: f32,
+    bias: f32,
+    is_clamped: u32,
+    preserve_alpha: u32,
+}
+
+@group(0) @binding(0) var texture: texture_2d<f32>;
+@group(0) @binding(1) var<uniform> filter_args: Filter;
+
+@vertex
+fn main_vertex(in: filter__VertexInput) -> filter__VertexOutput {
+    return filter__main_vertex(in);
+}
+
+fn weight(index: u32) -> f32 {
+    return filter_args.matrix[index / 4u][index % 4u];
+}
+
+fn sample_source(coord: vec2<i32>) -> vec4<f32> {
+    let rect = filter_args.source_rect;
+    var pos = coord;
+    if (pos.x < rect.x || pos.y < rect.y || pos.x >= rect.z || pos.y >= rect.w) {
+        if (filter_args.is_clamped == 0u) {
+            return filter_args.default_color;
+        }
+        pos = clamp(pos, rect.xy, rect.zw - vec2<i32>(1, 1));
+    }
+    return textureLoad(texture, pos, 0);
+}
+
+@fragment
+fn main_fragment(in: filter__VertexOutput) -> @location(0) vec4<f32> {
+    let preserve_alpha = filter_args.preserve_alpha > 0u;
+    let size = vec2<f32>(textureDimensions(texture));
+    let center = vec2<i32>(floor(in.uv * size));
+    let columns = i32(filter_args.columns);
+    let rows = i32(filter_args.rows);
+
+    var total = vec4<f32>(0.0);
+    for (var y = 0; y < rows; y++) {
+        for (var x = 0; x < columns; x++) {
+            var color = sample_source(center + vec2<i32>(x - columns / 2, y - rows / 2));
+            // When preserving alpha, only the (unmultiplied) color channels are convolved.
+            if (preserve_alpha && color.a > 0.0) {
+                color = vec4<f32>(color.rgb / color.a, color.a);
+            }
+            total += color * weight(u32(y * columns + x));
+        }
+    }
+
+    let result = saturate(total / filter_args.divisor + vec4<f32>(filter_args.bias / 255.0));
+    if (preserve_alpha) {
+        let alpha = sample_source(center).a;
+        return vec4<f32>(result.rgb * alpha, alpha);
+    }
+    return vec4<f32>(min(result.rgb, vec3<f32>(result.a)), result.a);
+}
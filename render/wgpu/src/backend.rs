@@ -2,7 +2,7 @@ use crate::buffer_builder::BufferBuilder;
 use crate::buffer_pool::{BufferPool, TexturePool};
 use crate::context3d::WgpuContext3D;
 use crate::dynamic_transforms::DynamicTransforms;
-use crate::filters::FilterSource;
+use crate::filters::{ConvolutionFilter, FilterSource};
 use crate::mesh::{CommonGradient, Mesh, PendingDraw};
 use crate::pixel_bender::{ShaderMode, run_pixelbender_shader_impl};
 use crate::surface::{LayerRef, Surface};
@@ -32,6 +32,7 @@ use ruffle_render::tessellator::ShapeTessellator;
 use std::any::Any;
 use std::borrow::Cow;
 use std::cell::Cell;
+use std::collections::HashMap;
 use std::num::NonZeroU32;
 use std::sync::Arc;
 use swf::Color;
@@ -76,6 +77,16 @@ pub struct WgpuRenderBackend<T: RenderTarget> {
     pub(crate) offscreen_buffer_pool: Arc<BufferPool<wgpu::Buffer, BufferDimensions>>,
     dynamic_transforms: DynamicTransforms,
     active_frame: ActiveFrame,
+
+    /// Windows other than the main one, drawn to with `submit_window_frame`.
+    windows: HashMap<u32, WindowTarget>,
+}
+
+/// A window drawn to in addition to the main target.
+struct WindowTarget {
+    target: SwapChainTarget,
+    surface: Surface,
+    texture_pool: TexturePool,
 }
 
 impl WgpuRenderBackend<SwapChainTarget> {
@@ -248,9 +259,113 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
             offscreen_buffer_pool: Arc::new(offscreen_buffer_pool),
             dynamic_transforms: transforms,
             active_frame,
+            windows: HashMap::new(),
         })
     }
 
+    /// Adds a window, which is then drawn to by `submit_window_frame` with the same ID.
+    pub fn add_window_target(&mut self, window: u32, target: SwapChainTarget) {
+        let surface = Surface::new(
+            &self.descriptors,
+            self.surface.quality(),
+            target.width(),
+            target.height(),
+            target.format(),
+        );
+        self.windows.insert(
+            window,
+            WindowTarget {
+                target,
+                surface,
+                texture_pool: TexturePool::new(),
+            },
+        );
+    }
+
+    /// Draws the contents of `cacheAsBitmap` display objects to their textures.
+    fn draw_cache_entries(&mut self, cache_entries: Vec<BitmapCacheEntry>) {
+        for entry in cache_entries {
+            let texture = as_texture(&entry.handle);
+            let mut surface = Surface::new(
+                &self.descriptors,
+                self.surface.quality(),
+                texture.texture.width(),
+                texture.texture.height(),
+                wgpu::TextureFormat::Rgba8Unorm,
+            );
+            if entry.filters.is_empty() {
+                surface.draw_commands(
+                    RenderTargetMode::ExistingWithColor(
+                        texture.texture.clone(),
+                        wgpu::Color {
+                            r: f64::from(entry.clear.r) / 255.0,
+                            g: f64::from(entry.clear.g) / 255.0,
+                            b: f64::from(entry.clear.b) / 255.0,
+                            a: f64::from(entry.clear.a) / 255.0,
+                        },
+                    ),
+                    &self.descriptors,
+                    &self.meshes,
+                    entry.commands,
+                    &mut self.active_frame.staging_belt,
+                    &self.dynamic_transforms,
+                    &mut self.active_frame.command_encoder,
+                    LayerRef::None,
+                    &mut self.offscreen_texture_pool,
+                );
+            } else {
+                // We're relying on there being no impotent filters here,
+                // so that we can safely start by using the actual CAB texture.
+                // It's guaranteed that at least one filter would have used it and moved the target to something else,
+                // letting us safely copy back to it later.
+                let mut target = surface.draw_commands(
+                    RenderTargetMode::ExistingWithColor(
+                        texture.texture.clone(),
+                        wgpu::Color {
+                            r: f64::from(entry.clear.r) / 255.0,
+                            g: f64::from(entry.clear.g) / 255.0,
+                            b: f64::from(entry.clear.b) / 255.0,
+                            a: f64::from(entry.clear.a) / 255.0,
+                        },
+                    ),
+                    &self.descriptors,
+                    &self.meshes,
+                    entry.commands,
+                    &mut self.active_frame.staging_belt,
+                    &self.dynamic_transforms,
+                    &mut self.active_frame.command_encoder,
+                    LayerRef::None,
+                    &mut self.offscreen_texture_pool,
+                );
+                for filter in entry.filters {
+                    target = self.descriptors.filters.apply(
+                        &self.descriptors,
+                        &mut self.active_frame.command_encoder,
+                        &mut self.offscreen_texture_pool,
+                        &mut self.active_frame.staging_belt,
+                        FilterSource::for_entire_texture(target.color_texture()),
+                        filter,
+                    );
+                }
+                run_copy_pipeline(
+                    &self.descriptors,
+                    target.color_texture().format(),
+                    texture.texture.format(),
+                    &texture.texture.create_view(&Default::default()),
+                    target.color_view(),
+                    target.whole_frame_bind_group(&self.descriptors),
+                    target.globals(),
+                    target.color_texture().sample_count(),
+                    &mut self.active_frame.command_encoder,
+                );
+            }
+            // Periodically flush GPU work to prevent OOM when many cache entries
+            // accumulate (e.g. when a large container's cacheAsBitmap is skipped
+            // but its hundreds of children each have their own bitmap caches).
+            self.active_frame.maybe_flush(&self.descriptors);
+        }
+    }
+
     fn register_shape_internal(
         &mut self,
         shape: DistilledShape,
@@ -476,6 +591,15 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
             self.surface.size().height,
             self.target.format(),
         );
+        for window in self.windows.values_mut() {
+            window.surface = Surface::new(
+                &self.descriptors,
+                quality,
+                window.surface.size().width,
+                window.surface.size().height,
+                window.target.format(),
+            );
+        }
     }
 
     fn viewport_dimensions(&self) -> ViewportDimensions {
@@ -517,86 +641,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
             }
         };
 
-        for entry in cache_entries {
-            let texture = as_texture(&entry.handle);
-            let mut surface = Surface::new(
-                &self.descriptors,
-                self.surface.quality(),
-                texture.texture.width(),
-                texture.texture.height(),
-                wgpu::TextureFormat::Rgba8Unorm,
-            );
-            if entry.filters.is_empty() {
-                surface.draw_commands(
-                    RenderTargetMode::ExistingWithColor(
-                        texture.texture.clone(),
-                        wgpu::Color {
-                            r: f64::from(entry.clear.r) / 255.0,
-                            g: f64::from(entry.clear.g) / 255.0,
-                            b: f64::from(entry.clear.b) / 255.0,
-                            a: f64::from(entry.clear.a) / 255.0,
-                        },
-                    ),
-                    &self.descriptors,
-                    &self.meshes,
-                    entry.commands,
-                    &mut self.active_frame.staging_belt,
-                    &self.dynamic_transforms,
-                    &mut self.active_frame.command_encoder,
-                    LayerRef::None,
-                    &mut self.offscreen_texture_pool,
-                );
-            } else {
-                // We're relying on there being no impotent filters here,
-                // so that we can safely start by using the actual CAB texture.
-                // It's guaranteed that at least one filter would have used it and moved the target to something else,
-                // letting us safely copy back to it later.
-                let mut target = surface.draw_commands(
-                    RenderTargetMode::ExistingWithColor(
-                        texture.texture.clone(),
-                        wgpu::Color {
-                            r: f64::from(entry.clear.r) / 255.0,
-                            g: f64::from(entry.clear.g) / 255.0,
-                            b: f64::from(entry.clear.b) / 255.0,
-                            a: f64::from(entry.clear.a) / 255.0,
-                        },
-                    ),
-                    &self.descriptors,
-                    &self.meshes,
-                    entry.commands,
-                    &mut self.active_frame.staging_belt,
-                    &self.dynamic_transforms,
-                    &mut self.active_frame.command_encoder,
-                    LayerRef::None,
-                    &mut self.offscreen_texture_pool,
-                );
-                for filter in entry.filters {
-                    target = self.descriptors.filters.apply(
-                        &self.descriptors,
-                        &mut self.active_frame.command_encoder,
-                        &mut self.offscreen_texture_pool,
-                        &mut self.active_frame.staging_belt,
-                        FilterSource::for_entire_texture(target.color_texture()),
-                        filter,
-                    );
-                }
-                run_copy_pipeline(
-                    &self.descriptors,
-                    target.color_texture().format(),
-                    texture.texture.format(),
-                    &texture.texture.create_view(&Default::default()),
-                    target.color_view(),
-                    target.whole_frame_bind_group(&self.descriptors),
-                    target.globals(),
-                    target.color_texture().sample_count(),
-                    &mut self.active_frame.command_encoder,
-                );
-            }
-            // Periodically flush GPU work to prevent OOM when many cache entries
-            // accumulate (e.g. when a large container's cacheAsBitmap is skipped
-            // but its hundreds of children each have their own bitmap caches).
-            self.active_frame.maybe_flush(&self.descriptors);
-        }
+        self.draw_cache_entries(cache_entries);
 
         self.surface.draw_commands_and_copy_to(
             frame_output.view(),
@@ -622,6 +667,85 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
         self.offscreen_texture_pool = TexturePool::new();
     }
 
+    #[instrument(level = "debug", skip_all)]
+    fn submit_window_frame(
+        &mut self,
+        window: u32,
+        clear: Color,
+        commands: CommandList,
+        cache_entries: Vec<BitmapCacheEntry>,
+    ) {
+        let Some(mut window_target) = self.windows.remove(&window) else {
+            return;
+        };
+
+        match window_target.target.get_next_texture() {
+            Ok(frame_output) => {
+                self.draw_cache_entries(cache_entries);
+
+                window_target.surface.draw_commands_and_copy_to(
+                    frame_output.view(),
+                    RenderTargetMode::FreshWithColor(wgpu::Color {
+                        r: f64::from(clear.r) / 255.0,
+                        g: f64::from(clear.g) / 255.0,
+                        b: f64::from(clear.b) / 255.0,
+                        a: f64::from(clear.a) / 255.0,
+                    }),
+                    &self.descriptors,
+                    &mut self.active_frame.staging_belt,
+                    &self.dynamic_transforms,
+                    &mut self.active_frame.command_encoder,
+                    &self.meshes,
+                    commands,
+                    LayerRef::None,
+                    &mut window_target.texture_pool,
+                );
+                self.active_frame.staging_belt.finish();
+
+                self.active_frame.submit_for_target(
+                    &self.descriptors,
+                    &window_target.target,
+                    frame_output,
+                );
+                self.offscreen_texture_pool = TexturePool::new();
+            }
+            Err(e) => {
+                tracing::warn!("Couldn't begin new render frame for window {window}: {}", e);
+                // Attempt to recreate the swap chain in this case.
+                let (width, height) = (window_target.target.width(), window_target.target.height());
+                window_target
+                    .target
+                    .resize(&self.descriptors.device, width, height);
+            }
+        }
+
+        self.windows.insert(window, window_target);
+    }
+
+    fn resize_window_target(&mut self, window: u32, width: u32, height: u32) {
+        let Some(window) = self.windows.get_mut(&window) else {
+            return;
+        };
+        let limit = self.descriptors.limits.max_texture_dimension_2d;
+        let width = width.clamp(1, limit);
+        let height = height.clamp(1, limit);
+        window
+            .target
+            .resize(&self.descriptors.device, width, height);
+        window.surface = Surface::new(
+            &self.descriptors,
+            window.surface.quality(),
+            width,
+            height,
+            window.target.format(),
+        );
+        window.texture_pool = TexturePool::new();
+    }
+
+    fn remove_window_target(&mut self, window: u32) {
+        self.windows.remove(&window);
+    }
+
     #[instrument(level = "debug", skip_all)]
     fn register_bitmap(&mut self, bitmap: Bitmap<'_>) -> Result<BitmapHandle, BitmapError> {
         let mut bitmap = bitmap.to_rgba();
@@ -777,16 +901,18 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
     }
 
     fn is_filter_supported(&self, filter: &Filter) -> bool {
-        matches!(
-            filter,
+        match filter {
             Filter::BlurFilter(_)
-                | Filter::GlowFilter(_)
-                | Filter::DropShadowFilter(_)
-                | Filter::ColorMatrixFilter(_)
-                | Filter::ShaderFilter(_)
-                | Filter::BevelFilter(_)
-                | Filter::DisplacementMapFilter(_)
-        )
+            | Filter::GlowFilter(_)
+            | Filter::DropShadowFilter(_)
+            | Filter::ColorMatrixFilter(_)
+            | Filter::ShaderFilter(_)
+            | Filter::BevelFilter(_)
+            | Filter::DisplacementMapFilter(_)
+            | Filter::GradientGlowFilter(_)
+            | Filter::GradientBevelFilter(_) => true,
+            Filter::ConvolutionFilter(filter) => ConvolutionFilter::is_supported(filter),
+        }
     }
 
     fn is_offscreen_supported(&self) -> bool {
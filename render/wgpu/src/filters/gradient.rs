@@ -0,0 +1,377 @@
+use crate::backend::RenderTargetMode;
+use crate::buffer_pool::TexturePool;
+use crate::descriptors::Descriptors;
+use crate::filters::blur::BlurFilter;
+use crate::filters::{
+    FilterSource, FilterVertexWithBlur, FilterVertexWithDoubleBlur,
+    VERTEX_BUFFERS_DESCRIPTION_FILTERS_WITH_BLUR,
+    VERTEX_BUFFERS_DESCRIPTION_FILTERS_WITH_DOUBLE_BLUR,
+};
+use crate::surface::target::CommandTarget;
+use crate::utils::SampleCountMap;
+use bytemuck::{Pod, Zeroable};
+use std::sync::OnceLock;
+use swf::{Color, GradientFilter as GradientFilterArgs, GradientRecord};
+use wgpu::util::StagingBelt;
+
+/// The effect of a filter that is colored by a gradient.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientFilterType {
+    Glow,
+    Bevel,
+}
+
+impl GradientFilterType {
+    fn shader(self, descriptors: &Descriptors) -> &wgpu::ShaderModule {
+        match self {
+            GradientFilterType::Glow => &descriptors.shaders.gradient_glow_filter,
+            GradientFilterType::Bevel => &descriptors.shaders.gradient_bevel_filter,
+        }
+    }
+
+    fn vertex_buffers(self) -> &'static [wgpu::VertexBufferLayout<'static>] {
+        match self {
+            GradientFilterType::Glow => &VERTEX_BUFFERS_DESCRIPTION_FILTERS_WITH_BLUR,
+            GradientFilterType::Bevel => &VERTEX_BUFFERS_DESCRIPTION_FILTERS_WITH_DOUBLE_BLUR,
+        }
+    }
+
+    fn vertices_size(self) -> u64 {
+        match self {
+            GradientFilterType::Glow => std::mem::size_of::<[FilterVertexWithBlur; 4]>() as u64,
+            GradientFilterType::Bevel => {
+                std::mem::size_of::<[FilterVertexWithDoubleBlur; 4]>() as u64
+            }
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable, PartialEq)]
+struct GradientUniform {
+    gradient: [[f32; 4]; 256],
+    strength: f32,
+    filter_type: u32, // 0 outer, 1 inner, 2 full
+    knockout: u32,    // a wasteful bool, but we need to be aligned anyway
+    _padding: u32,
+}
+
+/// Applies a `GradientGlowFilter` or a `GradientBevelFilter`.
+///
+/// Both filters blur the alpha of the source, then color it with the gradient
+/// ramp of the filter; they only differ in how the blurred alpha is sampled.
+pub struct GradientFilter {
+    filter_type: GradientFilterType,
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_layout: wgpu::PipelineLayout,
+    vertex_buffer: wgpu::Buffer,
+    uniform_buffer: wgpu::Buffer,
+    vertices_size: wgpu::BufferSize,
+    uniform_size: wgpu::BufferSize,
+    pipeline: SampleCountMap<OnceLock<wgpu::RenderPipeline>>,
+}
+
+impl GradientFilter {
+    pub fn new(device: &wgpu::Device, filter_type: GradientFilterType) -> Self {
+        let uniform_size = std::mem::size_of::<GradientUniform>() as u64;
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::NonFiltering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(uniform_size),
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+            ],
+            label: create_debug_label!("Gradient {:?} filter binds", filter_type).as_deref(),
+        });
+
+        let vertices_size = filter_type.vertices_size();
+        let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: vertices_size,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: uniform_size,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        Self {
+            filter_type,
+            pipeline: Default::default(),
+            pipeline_layout,
+            vertex_buffer,
+            uniform_buffer,
+            bind_group_layout,
+            uniform_size: wgpu::BufferSize::new(uniform_size).expect("Definitely not zero."),
+            vertices_size: wgpu::BufferSize::new(vertices_size).expect("Definitely not zero."),
+        }
+    }
+
+    fn pipeline(&self, descriptors: &Descriptors, msaa_sample_count: u32) -> &wgpu::RenderPipeline {
+        self.pipeline.get_or_init(msaa_sample_count, || {
+            let label = create_debug_label!(
+                "Gradient {:?} Filter ({} msaa)",
+                self.filter_type,
+                msaa_sample_count
+            );
+            let shader = self.filter_type.shader(descriptors);
+            descriptors
+                .device
+                .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: label.as_deref(),
+                    layout: Some(&self.pipeline_layout),
+                    vertex: wgpu::VertexState {
+                        module: shader,
+                        entry_point: Some("main_vertex"),
+                        buffers: self.filter_type.vertex_buffers(),
+                        compilation_options: Default::default(),
+                    },
+                    primitive: wgpu::PrimitiveState {
+                        topology: wgpu::PrimitiveTopology::TriangleList,
+                        strip_index_format: None,
+                        front_face: wgpu::FrontFace::Ccw,
+                        cull_mode: None,
+                        polygon_mode: wgpu::PolygonMode::default(),
+                        unclipped_depth: false,
+                        conservative: false,
+                    },
+                    depth_stencil: None,
+                    multisample: wgpu::MultisampleState {
+                        count: msaa_sample_count,
+                        mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    },
+                    fragment: Some(wgpu::FragmentState {
+                        module: shader,
+                        entry_point: Some("main_fragment"),
+                        targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+                        compilation_options: Default::default(),
+                    }),
+                    multiview: None,
+                    cache: None,
+                })
+        })
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    pub fn apply(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        staging_belt: &mut StagingBelt,
+        source: &FilterSource,
+        filter: &GradientFilterArgs,
+        blur_filter: &BlurFilter,
+    ) -> CommandTarget {
+        let sample_count = source.texture.sample_count();
+        let format = source.texture.format();
+        let pipeline = self.pipeline(descriptors, sample_count);
+        let blurred = blur_filter.apply(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            staging_belt,
+            source,
+            &filter.inner_blur_filter(),
+        );
+        let blurred_texture = if let Some(blurred) = &blurred {
+            blurred.ensure_cleared(draw_encoder);
+            blurred.color_texture()
+        } else {
+            source.texture
+        };
+        let source_view = source.texture.create_view(&Default::default());
+        let blurred_view = blurred_texture.create_view(&Default::default());
+        let distance = filter.distance.to_f32();
+        let angle = filter.angle.to_f32();
+        let offset = (angle.cos() * distance, angle.sin() * distance);
+
+        let target = CommandTarget::new(
+            descriptors,
+            texture_pool,
+            wgpu::Extent3d {
+                width: source.size.0,
+                height: source.size.1,
+                depth_or_array_layers: 1,
+            },
+            format,
+            sample_count,
+            RenderTargetMode::FreshWithColor(wgpu::Color::TRANSPARENT),
+            draw_encoder,
+        );
+        staging_belt
+            .write_buffer(
+                draw_encoder,
+                &self.uniform_buffer,
+                0,
+                self.uniform_size,
+                &descriptors.device,
+            )
+            .copy_from_slice(bytemuck::cast_slice(&[GradientUniform {
+                gradient: gradient_ramp(&filter.colors),
+                strength: filter.strength.to_f32(),
+                filter_type: if filter.is_on_top() {
+                    2
+                } else if filter.is_inner() {
+                    1
+                } else {
+                    0
+                },
+                knockout: if filter.is_knockout() { 1 } else { 0 },
+                _padding: 0,
+            }]));
+        let glow_vertices;
+        let bevel_vertices;
+        let vertices: &[u8] = match self.filter_type {
+            GradientFilterType::Glow => {
+                // Like a drop shadow, the glow is moved away from the light.
+                glow_vertices = source.vertices_with_blur_offset((-offset.0, -offset.1));
+                bytemuck::cast_slice(&glow_vertices)
+            }
+            GradientFilterType::Bevel => {
+                bevel_vertices = source.vertices_with_highlight_and_shadow(offset);
+                bytemuck::cast_slice(&bevel_vertices)
+            }
+        };
+        staging_belt
+            .write_buffer(
+                draw_encoder,
+                &self.vertex_buffer,
+                0,
+                self.vertices_size,
+                &descriptors.device,
+            )
+            .copy_from_slice(vertices);
+        let filter_group = descriptors
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: create_debug_label!("Filter group").as_deref(),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&source_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(
+                            descriptors.bitmap_samplers.get_sampler(false, false),
+                        ),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: self.uniform_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: wgpu::BindingResource::TextureView(&blurred_view),
+                    },
+                ],
+            });
+        let mut render_pass = draw_encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: create_debug_label!("Gradient {:?} filter", self.filter_type).as_deref(),
+            color_attachments: &[target.color_attachments()],
+            ..Default::default()
+        });
+        render_pass.set_pipeline(pipeline);
+
+        render_pass.set_bind_group(0, &filter_group, &[]);
+
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_index_buffer(
+            descriptors.quad.indices.slice(..),
+            wgpu::IndexFormat::Uint32,
+        );
+        render_pass.draw_indexed(0..6, 0, 0..1);
+        target
+    }
+}
+
+/// Builds the gradient ramp of a filter, mapping each of the 256 ratios
+/// to the premultiplied color of the gradient at that ratio.
+fn gradient_ramp(records: &[GradientRecord]) -> [[f32; 4]; 256] {
+    let mut ramp = [[0.0; 4]; 256];
+    let (Some(first), Some(last)) = (records.first(), records.last()) else {
+        return ramp;
+    };
+    let to_array = |color: &Color| {
+        [
+            f32::from(color.r),
+            f32::from(color.g),
+            f32::from(color.b),
+            f32::from(color.a),
+        ]
+    };
+
+    for (ratio, entry) in ramp.iter_mut().enumerate() {
+        let color = match records
+            .iter()
+            .position(|record| usize::from(record.ratio) >= ratio)
+        {
+            // Before the first record or after the last one, the gradient is a solid color.
+            Some(0) => to_array(&first.color),
+            None => to_array(&last.color),
+            Some(next) => {
+                let start = &records[next - 1];
+                let end = &records[next];
+                let t = (ratio - usize::from(start.ratio)) as f32
+                    / (f32::from(end.ratio) - f32::from(start.ratio));
+                let start = to_array(&start.color);
+                let end = to_array(&end.color);
+                std::array::from_fn(|i| start[i] + (end[i] - start[i]) * t)
+            }
+        };
+        let alpha = color[3] / 255.0;
+        *entry = [
+            color[0] / 255.0 * alpha,
+            color[1] / 255.0 * alpha,
+            color[2] / 255.0 * alpha,
+            alpha,
+        ];
+    }
+
+    ramp
+}
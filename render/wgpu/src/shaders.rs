@@ -22,6 +22,9 @@ pub struct Shaders {
     pub glow_filter: wgpu::ShaderModule,
     pub bevel_filter: wgpu::ShaderModule,
     pub displacement_map_filter: wgpu::ShaderModule,
+    pub convolution_filter: wgpu::ShaderModule,
+    pub gradient_glow_filter: wgpu::ShaderModule,
+    pub gradient_bevel_filter: wgpu::ShaderModule,
 }
 
 impl Shaders {
@@ -63,6 +66,21 @@ impl Shaders {
             "filter/displacement_map.wgsl",
             include_str!("../shaders/filter/displacement_map.wgsl"),
         );
+        let convolution_filter = make_filter_shader(
+            device,
+            "filter/convolution.wgsl",
+            include_str!("../shaders/filter/convolution.wgsl"),
+        );
+        let gradient_glow_filter = make_filter_shader(
+            device,
+            "filter/gradient_glow.wgsl",
+            include_str!("../shaders/filter/gradient_glow.wgsl"),
+        );
+        let gradient_bevel_filter = make_filter_shader(
+            device,
+            "filter/gradient_bevel.wgsl",
+            include_str!("../shaders/filter/gradient_bevel.wgsl"),
+        );
         let gradient_shader = make_shader(
             device,
             "gradient.wgsl",
@@ -99,6 +117,9 @@ impl Shaders {
             glow_filter,
             bevel_filter,
             displacement_map_filter,
+            convolution_filter,
+            gradient_glow_filter,
+            gradient_bevel_filter,
         }
     }
 }
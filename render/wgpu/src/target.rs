@@ -98,6 +98,19 @@ impl SwapChainTarget {
             window_surface: surface,
         }
     }
+
+    /// Lets what's behind the window show through where its frames are transparent, if the
+    /// surface supports it.
+    pub fn make_transparent(&mut self, adapter: &wgpu::Adapter, device: &wgpu::Device) {
+        let capabilities = self.window_surface.get_capabilities(adapter);
+        if capabilities
+            .alpha_modes
+            .contains(&wgpu::CompositeAlphaMode::PreMultiplied)
+        {
+            self.surface_config.alpha_mode = wgpu::CompositeAlphaMode::PreMultiplied;
+            self.window_surface.configure(device, &self.surface_config);
+        }
+    }
 }
 
 impl RenderTarget for SwapChainTarget {
@@ -1,4 +1,4 @@
-use crate::{BlurFilter, BlurFilterFlags, Fixed8, Fixed16, GradientRecord};
+use crate::{BlurFilter, BlurFilterFlags, Fixed8, Fixed16, GradientRecord, Rectangle, Twips};
 use bitflags::bitflags;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -39,6 +39,29 @@ impl GradientFilter {
         self.distance *= Fixed16::from_f32(y);
     }
 
+    pub fn calculate_dest_rect(&self, source_rect: Rectangle<Twips>) -> Rectangle<Twips> {
+        let mut result = self.inner_blur_filter().calculate_dest_rect(source_rect);
+        let distance = self.distance.to_f64();
+        let angle = self.angle.to_f64();
+        let x = Twips::from_pixels(angle.cos() * distance);
+        let y = Twips::from_pixels(angle.sin() * distance);
+        if x < Twips::ZERO {
+            result.x_min += x;
+            result.x_max -= x;
+        } else {
+            result.x_max += x;
+            result.x_min -= x;
+        }
+        if y < Twips::ZERO {
+            result.y_min += y;
+            result.y_max -= y;
+        } else {
+            result.y_max += y;
+            result.y_min -= y;
+        }
+        result
+    }
+
     pub fn inner_blur_filter(&self) -> BlurFilter {
         BlurFilter {
             blur_x: self.blur_x,
@@ -67,3 +90,51 @@ impl GradientFilterFlags {
         flags
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(blur: f64, distance: f64, angle: f64) -> GradientFilter {
+        GradientFilter {
+            colors: vec![],
+            blur_x: Fixed16::from_f64(blur),
+            blur_y: Fixed16::from_f64(blur),
+            angle: Fixed16::from_f64(angle),
+            distance: Fixed16::from_f64(distance),
+            strength: Fixed8::ONE,
+            flags: GradientFilterFlags::from_passes(1),
+        }
+    }
+
+    fn rect(x_min: f64, y_min: f64, x_max: f64, y_max: f64) -> Rectangle<Twips> {
+        Rectangle {
+            x_min: Twips::from_pixels(x_min),
+            y_min: Twips::from_pixels(y_min),
+            x_max: Twips::from_pixels(x_max),
+            y_max: Twips::from_pixels(y_max),
+        }
+    }
+
+    #[test]
+    fn dest_rect_includes_blur() {
+        let source = rect(0.0, 0.0, 20.0, 10.0);
+        assert_eq!(
+            filter(4.0, 0.0, 0.0).calculate_dest_rect(source),
+            rect(-4.0, -4.0, 24.0, 14.0)
+        );
+    }
+
+    #[test]
+    fn dest_rect_includes_distance_on_both_sides() {
+        let source = rect(0.0, 0.0, 20.0, 10.0);
+        assert_eq!(
+            filter(0.0, 6.0, 0.0).calculate_dest_rect(source),
+            rect(-6.0, 0.0, 26.0, 10.0)
+        );
+        assert_eq!(
+            filter(2.0, -6.0, 0.0).calculate_dest_rect(source),
+            rect(-8.0, -2.0, 28.0, 12.0)
+        );
+    }
+}
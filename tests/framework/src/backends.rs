@@ -1,9 +1,13 @@
 mod audio;
 mod log;
 mod navigator;
+mod print;
+mod process;
 mod ui;
 
 pub use audio::TestAudioBackend;
 pub use log::TestLogBackend;
 pub use navigator::TestNavigatorBackend;
+pub use print::TestPrintBackend;
+pub use process::{TestProcessBackend, TestProgram};
 pub use ui::TestUiBackend;
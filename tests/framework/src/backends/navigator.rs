@@ -76,10 +76,14 @@ impl SuccessResponse for TestResponse {
 ///
 /// These are formatted as query params, rather than domains/whole URLs, so that real/real-invalid
 /// URLs can be used in Flash Player when writing tests
+///
+/// Sockets connected to the RTMP port are served by the mock RTMP server when the test has an
+/// `rtmp.flv` file, and all other sockets replay the events of its `socket.json` file.
 pub struct TestNavigatorBackend {
     spawner: NullSpawner,
     relative_base_path: VfsPath,
     socket_events: Option<Vec<SocketEvent>>,
+    rtmp_flv: Option<Vec<u8>>,
     log: Option<TestLogBackend>,
 }
 
@@ -88,12 +92,14 @@ impl TestNavigatorBackend {
         path: VfsPath,
         executor: &NullExecutor,
         socket_events: Option<Vec<SocketEvent>>,
+        rtmp_flv: Option<Vec<u8>>,
         log: Option<TestLogBackend>,
     ) -> Result<Self, std::io::Error> {
         Ok(Self {
             spawner: executor.spawner(),
             relative_base_path: path,
             socket_events,
+            rtmp_flv,
             log,
         })
     }
@@ -287,7 +293,30 @@ impl NavigatorBackend for TestNavigatorBackend {
             log.avm_trace(&format!("    Host: {host}; Port: {port}"));
         }
 
-        if let Some(events) = self.socket_events.clone() {
+        if port == rtmp_mocket::DEFAULT_PORT
+            && let Some(flv) = &self.rtmp_flv
+        {
+            let mut server = rtmp_mocket::Server::new(flv).expect("rtmp.flv should be an FLV file");
+            self.spawn_future(Box::pin(async move {
+                sender
+                    .try_send(SocketAction::Connect(handle, ConnectionState::Connected))
+                    .expect("working channel send");
+
+                while let Ok(data) = receiver.recv().await {
+                    if let Err(e) = server.receive(&data) {
+                        panic!("Mock RTMP server failed: {e}");
+                    }
+                    let output = server.take_output();
+                    if !output.is_empty() {
+                        sender
+                            .try_send(SocketAction::Data(handle, output))
+                            .expect("working channel send");
+                    }
+                }
+
+                Ok(())
+            }));
+        } else if let Some(events) = self.socket_events.clone() {
             self.spawn_future(Box::pin(async move {
                 sender
                     .try_send(SocketAction::Connect(handle, ConnectionState::Connected))
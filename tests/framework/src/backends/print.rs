@@ -0,0 +1,43 @@
+use crate::backends::TestLogBackend;
+use ruffle_core::backend::log::LogBackend;
+use ruffle_core::backend::print::{PrintBackend, PrintJobSettings, PrintPage};
+
+/// A print backend printing on US Letter sheets, which traces the pages of every job sent.
+pub struct TestPrintBackend {
+    log: TestLogBackend,
+    pages: Vec<PrintPage>,
+}
+
+impl TestPrintBackend {
+    pub fn new(log: TestLogBackend) -> Self {
+        Self {
+            log,
+            pages: Vec::new(),
+        }
+    }
+}
+
+impl PrintBackend for TestPrintBackend {
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    fn start_job(&mut self) -> Option<PrintJobSettings> {
+        self.pages.clear();
+        Some(PrintJobSettings::LETTER)
+    }
+
+    fn add_page(&mut self, page: PrintPage) {
+        self.pages.push(page);
+    }
+
+    fn send(&mut self) {
+        let pages = std::mem::take(&mut self.pages);
+        self.log
+            .avm_trace(&format!("Printed {} page(s)", pages.len()));
+        for page in pages {
+            self.log
+                .avm_trace(&format!("Page: {}x{}", page.width, page.height));
+        }
+    }
+}
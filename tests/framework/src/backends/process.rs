@@ -0,0 +1,119 @@
+use async_channel::{Receiver, Sender};
+use ruffle_core::backend::process::{NativeProcessStartupInfo, ProcessBackend};
+use ruffle_core::native_process::{NativeProcessAction, NativeProcessCommand, NativeProcessHandle};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::thread;
+
+/// A simulated program, for use in tests.
+///
+/// It writes its output as soon as it starts, and then exits once its standard input is
+/// closed.
+#[derive(Clone, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct TestProgram {
+    /// What the program writes to its standard output when it starts.
+    output: String,
+
+    /// What the program writes to its standard error when it starts.
+    error: String,
+
+    /// Whether the program writes what it reads back to its standard output, like `cat`.
+    echo: bool,
+
+    exit_code: i32,
+}
+
+impl TestProgram {
+    fn run(
+        self,
+        handle: NativeProcessHandle,
+        receiver: Receiver<NativeProcessCommand>,
+        sender: Sender<NativeProcessAction>,
+    ) {
+        let send = |action| sender.send_blocking(action).is_ok();
+
+        if !self.output.is_empty()
+            && !send(NativeProcessAction::StandardOutput(
+                handle,
+                self.output.into_bytes(),
+            ))
+        {
+            return;
+        }
+        if !self.error.is_empty()
+            && !send(NativeProcessAction::StandardError(
+                handle,
+                self.error.into_bytes(),
+            ))
+        {
+            return;
+        }
+
+        loop {
+            match receiver.recv_blocking() {
+                Ok(NativeProcessCommand::Write(data)) => {
+                    if self.echo && !send(NativeProcessAction::StandardOutput(handle, data)) {
+                        return;
+                    }
+                }
+                Ok(
+                    NativeProcessCommand::CloseInput | NativeProcessCommand::Exit { force: false },
+                ) => {
+                    if !send(NativeProcessAction::StandardInputClosed(handle)) {
+                        return;
+                    }
+                    break;
+                }
+                // A killed program doesn't get to close its streams.
+                Ok(NativeProcessCommand::Exit { force: true }) => {
+                    send(NativeProcessAction::Exited(handle, None));
+                    return;
+                }
+                // The player is gone.
+                Err(_) => return,
+            }
+        }
+
+        let _ = send(NativeProcessAction::StandardOutputClosed(handle))
+            && send(NativeProcessAction::StandardErrorClosed(handle))
+            && send(NativeProcessAction::Exited(handle, Some(self.exit_code)));
+    }
+}
+
+/// A process backend running simulated programs, each on its own thread.
+///
+/// Other programs are refused, as if the user didn't allow them to run.
+pub struct TestProcessBackend {
+    programs: HashMap<String, TestProgram>,
+}
+
+impl TestProcessBackend {
+    pub fn new(programs: HashMap<String, TestProgram>) -> Self {
+        Self { programs }
+    }
+}
+
+impl ProcessBackend for TestProcessBackend {
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    fn start_process(
+        &mut self,
+        info: NativeProcessStartupInfo,
+        handle: NativeProcessHandle,
+        receiver: Receiver<NativeProcessCommand>,
+        sender: Sender<NativeProcessAction>,
+    ) {
+        match self.programs.get(&info.executable) {
+            Some(program) => {
+                let program = program.clone();
+                thread::spawn(move || program.run(handle, receiver, sender));
+            }
+            None => sender
+                .try_send(NativeProcessAction::Exited(handle, None))
+                .expect("working channel send"),
+        }
+    }
+}
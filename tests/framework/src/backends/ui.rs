@@ -3,9 +3,12 @@ use std::collections::HashMap;
 use crate::test::Font;
 use chrono::{DateTime, Utc};
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter, FontDefinition,
-    FullscreenError, LanguageIdentifier, MouseCursor, US_ENGLISH, UiBackend,
+    ClipboardData, ClipboardFormat, DialogLoaderError, DialogResultFuture, FileDialogResult,
+    FileFilter, FontDefinition, FullscreenError, LanguageIdentifier, MouseCursor,
+    NativeWindowBounds, NativeWindowDisplayState, NativeWindowId, NativeWindowOptions, US_ENGLISH,
+    UiBackend,
 };
+use ruffle_core::events::NativeWindowEvent;
 use ruffle_core::font::{FontFileData, FontQuery};
 use url::Url;
 
@@ -71,6 +74,25 @@ impl FileDialogResult for TestFileDialogResult {
     }
 }
 
+/// A simulated window of an AIR application, which is never displayed.
+#[derive(Default)]
+struct TestWindow {
+    bounds: Option<NativeWindowBounds>,
+    display_state: NativeWindowDisplayState,
+    size_limits: Option<((u32, u32), (u32, u32))>,
+}
+
+impl TestWindow {
+    /// Clamps the size of some bounds to the size limits of the window, like a window manager would.
+    fn limit(&self, mut bounds: NativeWindowBounds) -> NativeWindowBounds {
+        if let Some((min, max)) = self.size_limits {
+            bounds.width = bounds.width.max(min.0).min(max.0.max(min.0));
+            bounds.height = bounds.height.max(min.1).min(max.1.max(min.1));
+        }
+        bounds
+    }
+}
+
 /// This is an implementation of [`UiBackend`], designed for use in tests
 ///
 /// Fundamentally, this is mostly the same as [`NullUiBackend`] with the following differences:
@@ -79,10 +101,14 @@ impl FileDialogResult for TestFileDialogResult {
 /// * Attempting to display a file save dialog with a file name hint of "debug-success.txt" will simulate successfully selecting a destination
 ///   otherwise a user cancellation will be simulated
 /// * Simulated in-memory clipboard
+/// * Simulated windows, which queue the events a window manager would send, see [`TestUiBackend::take_window_events`]
 pub struct TestUiBackend {
     fonts: HashMap<FontQuery, Font>,
     font_sorts: HashMap<FontQuery, Vec<FontQuery>>,
-    clipboard: String,
+    clipboard: Vec<ClipboardData>,
+    windows: HashMap<NativeWindowId, TestWindow>,
+    focused_window: Option<NativeWindowId>,
+    window_events: Vec<(NativeWindowId, NativeWindowEvent)>,
 }
 
 impl TestUiBackend {
@@ -93,9 +119,39 @@ impl TestUiBackend {
         Self {
             fonts,
             font_sorts,
-            clipboard: "".to_string(),
+            clipboard: Vec::new(),
+            windows: HashMap::new(),
+            focused_window: Some(NativeWindowId::MAIN),
+            window_events: Vec::new(),
+        }
+    }
+
+    /// The events the simulated windows got since the last call, which the player should
+    /// handle.
+    pub fn take_window_events(&mut self) -> Vec<(NativeWindowId, NativeWindowEvent)> {
+        std::mem::take(&mut self.window_events)
+    }
+
+    /// Takes the focus away from the windows, as if the user switched to another application.
+    pub fn deactivate_windows(&mut self) {
+        if let Some(focused_window) = self.focused_window.take() {
+            self.window_events
+                .push((focused_window, NativeWindowEvent::Deactivated));
         }
     }
+
+    /// The window with the given ID, if it's open. The main window is always open.
+    fn window_mut(&mut self, id: NativeWindowId) -> Option<&mut TestWindow> {
+        if id == NativeWindowId::MAIN {
+            Some(self.windows.entry(id).or_default())
+        } else {
+            self.windows.get_mut(&id)
+        }
+    }
+
+    fn send_window_event(&mut self, id: NativeWindowId, event: NativeWindowEvent) {
+        self.window_events.push((id, event));
+    }
 }
 
 impl UiBackend for TestUiBackend {
@@ -107,12 +163,16 @@ impl UiBackend for TestUiBackend {
 
     fn set_mouse_cursor(&mut self, _cursor: MouseCursor) {}
 
-    fn clipboard_content(&mut self) -> String {
-        self.clipboard.clone()
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        self.clipboard.iter().map(ClipboardData::format).collect()
     }
 
-    fn set_clipboard_content(&mut self, content: String) {
-        self.clipboard = content;
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData> {
+        ClipboardData::find(&self.clipboard, format).cloned()
+    }
+
+    fn set_clipboard_data(&mut self, data: Vec<ClipboardData>) {
+        self.clipboard = data;
     }
 
     fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), FullscreenError> {
@@ -201,4 +261,80 @@ impl UiBackend for TestUiBackend {
     }
 
     fn close_file_dialog(&mut self) {}
+
+    fn open_native_window(&mut self, id: NativeWindowId, options: NativeWindowOptions) -> bool {
+        self.windows.insert(
+            id,
+            TestWindow {
+                bounds: options.bounds,
+                ..Default::default()
+            },
+        );
+        true
+    }
+
+    fn close_native_window(&mut self, id: NativeWindowId) {
+        self.windows.remove(&id);
+        if self.focused_window == Some(id) {
+            self.focused_window = None;
+        }
+    }
+
+    fn native_window_bounds(&self, id: NativeWindowId) -> Option<NativeWindowBounds> {
+        self.windows.get(&id)?.bounds
+    }
+
+    fn set_native_window_bounds(&mut self, id: NativeWindowId, bounds: NativeWindowBounds) {
+        if let Some(window) = self.window_mut(id) {
+            window.bounds = Some(window.limit(bounds));
+        }
+    }
+
+    fn native_window_display_state(&self, id: NativeWindowId) -> NativeWindowDisplayState {
+        self.windows
+            .get(&id)
+            .map(|window| window.display_state)
+            .unwrap_or_default()
+    }
+
+    fn set_native_window_display_state(
+        &mut self,
+        id: NativeWindowId,
+        state: NativeWindowDisplayState,
+    ) {
+        if let Some(window) = self.window_mut(id)
+            && window.display_state != state
+        {
+            window.display_state = state;
+            self.send_window_event(id, NativeWindowEvent::BoundsChanged);
+        }
+    }
+
+    fn activate_native_window(&mut self, id: NativeWindowId) {
+        if self.focused_window == Some(id) || self.window_mut(id).is_none() {
+            return;
+        }
+        if let Some(focused_window) = self.focused_window.replace(id) {
+            self.send_window_event(focused_window, NativeWindowEvent::Deactivated);
+        }
+        self.send_window_event(id, NativeWindowEvent::Activated);
+    }
+
+    fn set_native_window_size_limits(
+        &mut self,
+        id: NativeWindowId,
+        min: (u32, u32),
+        max: (u32, u32),
+    ) {
+        if let Some(window) = self.window_mut(id) {
+            window.size_limits = Some((min, max));
+            if let Some(bounds) = window.bounds {
+                let limited = window.limit(bounds);
+                if limited != bounds {
+                    window.bounds = Some(limited);
+                    self.send_window_event(id, NativeWindowEvent::BoundsChanged);
+                }
+            }
+        }
+    }
 }
@@ -77,6 +77,7 @@ pub struct TestOptions {
     pub approximations: Option<Approximations>,
     pub player_options: PlayerOptions,
     pub log_fetch: bool,
+    pub log_print: bool,
     pub required_features: RequiredFeatures,
     pub fonts: HashMap<String, FontOptions>,
     pub font_sorts: HashMap<String, FontSortOptions>,
@@ -100,6 +101,7 @@ impl Default for TestOptions {
             approximations: None,
             player_options: PlayerOptions::default(),
             log_fetch: false,
+            log_print: false,
             required_features: RequiredFeatures::default(),
             fonts: Default::default(),
             font_sorts: Default::default(),
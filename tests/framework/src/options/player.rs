@@ -1,11 +1,18 @@
-use crate::backends::TestAudioBackend;
+use crate::backends::{TestAudioBackend, TestProcessBackend, TestProgram};
 use crate::environment::{Environment, RenderInterface};
 use crate::options::RenderOptions;
+use crate::util::read_bytes;
+use anyhow::anyhow;
+use ruffle_core::backend::camera::FrameSequenceCameraBackend;
+use ruffle_core::backend::microphone::SyntheticMicrophoneBackend;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{PlayerBuilder, PlayerMode, PlayerRuntime};
 use ruffle_render::backend::{RenderBackend, ViewportDimensions};
+use ruffle_render::bitmap::{Bitmap, BitmapFormat};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::time::Duration;
+use vfs::VfsPath;
 
 #[derive(Clone, Deserialize, Default)]
 #[serde(default, deny_unknown_fields)]
@@ -15,6 +22,16 @@ pub struct PlayerOptions {
     with_renderer: Option<RenderOptions>,
     with_audio: bool,
     with_video: bool,
+    with_microphone: bool,
+
+    /// Makes the test microphone ask for permission before capturing, with this answer.
+    microphone_permission: Option<bool>,
+    with_camera: Option<String>,
+
+    /// The programs `NativeProcess` may run, by path.
+    with_processes: Option<HashMap<String, TestProgram>>,
+
+    max_touch_points: u32,
     runtime: PlayerRuntime,
     version: Option<u8>,
     mode: Option<PlayerMode>,
@@ -22,7 +39,11 @@ pub struct PlayerOptions {
 }
 
 impl PlayerOptions {
-    pub fn setup(&self, mut player_builder: PlayerBuilder) -> anyhow::Result<PlayerBuilder> {
+    pub fn setup(
+        &self,
+        mut player_builder: PlayerBuilder,
+        test_directory: &VfsPath,
+    ) -> anyhow::Result<PlayerBuilder> {
         if let Some(max_execution_duration) = self.max_execution_duration {
             player_builder = player_builder.with_max_execution_duration(max_execution_duration);
         }
@@ -35,7 +56,26 @@ impl PlayerOptions {
             player_builder = player_builder.with_audio(TestAudioBackend::default());
         }
 
+        if self.with_microphone {
+            let mut microphone = SyntheticMicrophoneBackend::new();
+            if let Some(allow) = self.microphone_permission {
+                microphone = microphone.asking(allow);
+            }
+            player_builder = player_builder.with_microphone(microphone);
+        }
+
+        if let Some(frames_directory) = &self.with_camera {
+            let frames = load_camera_frames(&test_directory.join(frames_directory)?)?;
+            player_builder =
+                player_builder.with_camera(FrameSequenceCameraBackend::new("Test Camera", frames));
+        }
+
+        if let Some(programs) = &self.with_processes {
+            player_builder = player_builder.with_process(TestProcessBackend::new(programs.clone()));
+        }
+
         player_builder = player_builder
+            .with_max_touch_points(self.max_touch_points)
             .with_player_runtime(self.runtime)
             .with_player_version(self.version)
             // Assume flashplayerdebugger is used in tests
@@ -103,3 +143,27 @@ impl PlayerOptions {
         }
     }
 }
+
+/// Loads the PNG images of a directory, sorted by name, as camera frames.
+fn load_camera_frames(directory: &VfsPath) -> anyhow::Result<Vec<Bitmap<'static>>> {
+    let mut paths = directory
+        .read_dir()?
+        .filter(|path| path.extension().as_deref() == Some("png"))
+        .collect::<Vec<_>>();
+    paths.sort_by_key(|path| path.filename());
+
+    paths
+        .iter()
+        .map(|path| {
+            let image = image::load_from_memory(&read_bytes(path)?)
+                .map_err(|e| anyhow!("Couldn't load camera frame {}: {e}", path.as_str()))?
+                .into_rgba8();
+            Ok(Bitmap::new(
+                image.width(),
+                image.height(),
+                BitmapFormat::Rgba,
+                image.into_raw(),
+            ))
+        })
+        .collect()
+}
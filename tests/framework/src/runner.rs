@@ -2,20 +2,22 @@ mod automation;
 mod image_test;
 mod trace;
 
-use crate::backends::{TestLogBackend, TestNavigatorBackend, TestUiBackend};
+use crate::backends::{TestLogBackend, TestNavigatorBackend, TestPrintBackend, TestUiBackend};
 use crate::environment::RenderInterface;
 use crate::fs_commands::{FsCommand, TestFsCommandProvider};
 use crate::image_trigger::ImageTrigger;
 use crate::options::TestOptions;
 use crate::options::image_comparison::ImageComparison;
 use crate::options::known_failure::KnownFailure;
-use crate::runner::automation::perform_automated_event;
+use crate::runner::automation::{dispatch_window_events, perform_automated_event};
 use crate::runner::image_test::capture_and_compare_image;
 use crate::runner::trace::compare_trace_output;
 use crate::test::Test;
 use anyhow::{Result, anyhow};
 use ruffle_core::FloatDuration;
+use ruffle_core::backend::filesystem::MemoryFileSystemBackend;
 use ruffle_core::backend::navigator::NullExecutor;
+use ruffle_core::events::PlayerNotification;
 use ruffle_core::limits::ExecutionLimit;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{Player, PlayerBuilder};
@@ -47,6 +49,7 @@ pub struct TestRunner {
     frame_time_duration: Duration,
     log: TestLogBackend,
     fs_commands: mpsc::Receiver<FsCommand>,
+    notifications: async_channel::Receiver<PlayerNotification>,
     render_interface: Option<Box<dyn RenderInterface>>,
     images: HashMap<String, ImageComparison>,
     remaining_iterations: u32,
@@ -60,8 +63,10 @@ impl TestRunner {
         movie: SwfMovie,
         injector: InputInjector,
         socket_events: Option<Vec<SocketEvent>>,
+        rtmp_flv: Option<Vec<u8>>,
         renderer: Option<(Box<dyn RenderInterface>, Box<dyn RenderBackend>)>,
         viewport_dimensions: ViewportDimensions,
+        configure: impl FnOnce(PlayerBuilder) -> PlayerBuilder,
     ) -> Result<Self> {
         if test.options.num_frames.is_none() && test.options.num_ticks.is_none() {
             return Err(anyhow!(
@@ -82,18 +87,22 @@ impl TestRunner {
 
         let log = TestLogBackend::new(test.options.log_warnings);
         let (fs_command_provider, fs_commands) = TestFsCommandProvider::new();
+        let (notification_sender, notifications) = async_channel::unbounded();
         let navigator = TestNavigatorBackend::new(
             test.root_path.clone(),
             &executor,
             socket_events,
+            rtmp_flv,
             test.options.log_fetch.then(|| log.clone()),
         )?;
 
         let mut builder = PlayerBuilder::new()
             .with_log(log.clone())
             .with_navigator(navigator)
+            .with_filesystem(MemoryFileSystemBackend::new())
             .with_max_execution_duration(Duration::from_secs(300))
             .with_fs_commands(Box::new(fs_command_provider))
+            .with_notification_sender(notification_sender)
             .with_ui(TestUiBackend::new(test.fonts()?, test.font_sorts()))
             .with_viewport_dimensions(
                 viewport_dimensions.width,
@@ -101,6 +110,10 @@ impl TestRunner {
                 viewport_dimensions.scale_factor,
             );
 
+        if test.options.log_print {
+            builder = builder.with_print(TestPrintBackend::new(log.clone()));
+        }
+
         let render_interface = if let Some((interface, backend)) = renderer {
             builder = builder.with_boxed_renderer(backend);
             Some(interface)
@@ -108,11 +121,13 @@ impl TestRunner {
             None
         };
 
+        builder = configure(builder);
+
         // Test player options may override anything set above
         let player = test
             .options
             .player_options
-            .setup(builder)?
+            .setup(builder, &test.root_path)?
             .with_movie(movie)
             .with_autoplay(true) //.tick() requires playback
             .build();
@@ -140,6 +155,7 @@ impl TestRunner {
             frame_time_duration,
             log,
             fs_commands,
+            notifications,
             images,
             remaining_iterations,
             current_iteration: 0,
@@ -272,6 +288,17 @@ impl TestRunner {
             let mut player = self.player.lock().unwrap();
             perform_automated_event(evt, &mut player);
         });
+        dispatch_window_events(&mut self.player.lock().unwrap());
+
+        while let Ok(notification) = self.notifications.try_recv() {
+            match notification {
+                // Like quitting with an fscommand, an AIR application exiting ends the test.
+                PlayerNotification::ExitRequested { .. } => {
+                    self.remaining_iterations = 0;
+                }
+                PlayerNotification::ImeNotification(_) => {}
+            }
+        }
         // Rendering has side-effects (such as processing 'DisplayObject.scrollRect' updates)
         self.player.lock().unwrap().render();
 
@@ -1,21 +1,110 @@
+use crate::backends::TestUiBackend;
+use ruffle_core::backend::ui::{NativeWindowBounds, NativeWindowDisplayState, NativeWindowId};
 use ruffle_core::events::{
-    ImeEvent, KeyDescriptor, KeyLocation, LogicalKey, NamedKey, PhysicalKey,
+    ImeEvent, KeyDescriptor, KeyLocation, LogicalKey, NamedKey, NativeWindowEvent, PhysicalKey,
     TextControlCode as RuffleTextControlCode,
 };
 use ruffle_core::events::{MouseButton as RuffleMouseButton, MouseWheelDelta};
 use ruffle_core::{Player, PlayerEvent};
 use ruffle_input_format::{
     AutomatedEvent, AutomatedKey, MouseButton as InputMouseButton,
+    NativeWindowAction as InputNativeWindowAction,
+    NativeWindowDisplayState as InputNativeWindowDisplayState,
     TextControlCode as InputTextControlCode,
 };
+use std::any::Any;
 
 pub fn perform_automated_event(evt: &AutomatedEvent, player: &mut Player) {
-    if let AutomatedEvent::SetClipboardText { text } = evt {
-        player.ui_mut().set_clipboard_content(text.to_owned());
-        return;
+    match evt {
+        AutomatedEvent::SetClipboardText { text } => {
+            player.ui_mut().set_clipboard_content(text.to_owned());
+            return;
+        }
+        AutomatedEvent::NativeWindow { window, action } => {
+            perform_native_window_action(NativeWindowId(*window), action, player);
+            return;
+        }
+        AutomatedEvent::NativeWindowInput { window, event } => {
+            player.handle_native_window_input(NativeWindowId(*window), player_event(event));
+            return;
+        }
+        _ => {}
+    }
+
+    let handled = player.handle_event(player_event(evt));
+
+    #[expect(clippy::single_match)]
+    match evt {
+        AutomatedEvent::MouseDown {
+            assert_handled: Some(assert_handled),
+            ..
+        } => {
+            if handled != assert_handled.value {
+                panic!(
+                    "Event handled status assertion failed: \n\
+                            \x20   expected to be handled: {}\n\
+                            \x20   was handled: {}\n\
+                            \x20   message: {}",
+                    assert_handled.value, handled, assert_handled.message
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The simulated UI backend of the player, which plays the part of the window manager.
+pub fn test_ui_backend(player: &mut Player) -> &mut TestUiBackend {
+    <dyn Any>::downcast_mut::<TestUiBackend>(player.ui_mut()).expect("Tests use TestUiBackend")
+}
+
+/// Lets the player handle the events the simulated windows got.
+pub fn dispatch_window_events(player: &mut Player) {
+    for (id, event) in test_ui_backend(player).take_window_events() {
+        player.native_window_event(id, event);
+    }
+}
+
+fn perform_native_window_action(
+    id: NativeWindowId,
+    action: &InputNativeWindowAction,
+    player: &mut Player,
+) {
+    match action {
+        InputNativeWindowAction::Activate => player.ui_mut().activate_native_window(id),
+        InputNativeWindowAction::Deactivate => test_ui_backend(player).deactivate_windows(),
+        InputNativeWindowAction::SetBounds {
+            x,
+            y,
+            width,
+            height,
+        } => {
+            let bounds = NativeWindowBounds {
+                x: *x,
+                y: *y,
+                width: *width,
+                height: *height,
+            };
+            player.ui_mut().set_native_window_bounds(id, bounds);
+            player.native_window_event(id, NativeWindowEvent::BoundsChanged);
+        }
+        InputNativeWindowAction::SetDisplayState { state } => {
+            let state = match state {
+                InputNativeWindowDisplayState::Normal => NativeWindowDisplayState::Normal,
+                InputNativeWindowDisplayState::Minimized => NativeWindowDisplayState::Minimized,
+                InputNativeWindowDisplayState::Maximized => NativeWindowDisplayState::Maximized,
+            };
+            player.ui_mut().set_native_window_display_state(id, state);
+        }
+        InputNativeWindowAction::Close => {
+            player.native_window_event(id, NativeWindowEvent::CloseRequested);
+        }
     }
+    dispatch_window_events(player);
+}
 
-    let handled = player.handle_event(match evt {
+fn player_event(evt: &AutomatedEvent) -> PlayerEvent {
+    match evt {
         AutomatedEvent::MouseDown {
             pos, btn, index, ..
         } => PlayerEvent::MouseDown {
@@ -93,26 +182,26 @@ pub fn perform_automated_event(evt: &AutomatedEvent, player: &mut Player) {
             PlayerEvent::Ime(ImeEvent::Preedit(text.clone(), *cursor))
         }
         AutomatedEvent::ImeCommit { text } => PlayerEvent::Ime(ImeEvent::Commit(text.clone())),
-        AutomatedEvent::Wait | AutomatedEvent::SetClipboardText { .. } => unreachable!(),
-    });
-
-    #[expect(clippy::single_match)]
-    match evt {
-        AutomatedEvent::MouseDown {
-            assert_handled: Some(assert_handled),
-            ..
-        } => {
-            if handled != assert_handled.value {
-                panic!(
-                    "Event handled status assertion failed: \n\
-                            \x20   expected to be handled: {}\n\
-                            \x20   was handled: {}\n\
-                            \x20   message: {}",
-                    assert_handled.value, handled, assert_handled.message
-                );
-            }
-        }
-        _ => {}
+        AutomatedEvent::TouchBegin { id, pos } => PlayerEvent::TouchBegin {
+            id: *id,
+            x: pos.0,
+            y: pos.1,
+        },
+        AutomatedEvent::TouchMove { id, pos } => PlayerEvent::TouchMove {
+            id: *id,
+            x: pos.0,
+            y: pos.1,
+        },
+        AutomatedEvent::TouchEnd { id, pos } => PlayerEvent::TouchEnd {
+            id: *id,
+            x: pos.0,
+            y: pos.1,
+        },
+        AutomatedEvent::TouchCancel { id } => PlayerEvent::TouchCancel { id: *id },
+        AutomatedEvent::Wait
+        | AutomatedEvent::SetClipboardText { .. }
+        | AutomatedEvent::NativeWindow { .. }
+        | AutomatedEvent::NativeWindowInput { .. } => unreachable!(),
     }
 }
 
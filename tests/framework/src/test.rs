@@ -6,6 +6,7 @@ use crate::options::known_failure::KnownFailure;
 use crate::runner::TestRunner;
 use crate::util::read_bytes;
 use anyhow::{Result, anyhow};
+use ruffle_core::PlayerBuilder;
 use ruffle_core::font::{FontQuery, FontType};
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_input_format::InputInjector;
@@ -24,6 +25,7 @@ pub struct Test {
     pub swf_path: VfsPath,
     pub input_path: VfsPath,
     pub socket_path: VfsPath,
+    pub rtmp_path: VfsPath,
     pub output_path: VfsPath,
     pub root_path: VfsPath,
     pub name: String,
@@ -34,6 +36,7 @@ impl Test {
         let swf_path = test_dir.join("test.swf")?;
         let input_path = test_dir.join("input.json")?;
         let socket_path = test_dir.join("socket.json")?;
+        let rtmp_path = test_dir.join("rtmp.flv")?;
         let output_path = options.output_path(&test_dir)?;
 
         Ok(Self {
@@ -41,6 +44,7 @@ impl Test {
             swf_path,
             input_path,
             socket_path,
+            rtmp_path,
             output_path,
             root_path: test_dir,
             name,
@@ -48,6 +52,16 @@ impl Test {
     }
 
     pub fn create_test_runner(&self, environment: &impl Environment) -> Result<TestRunner> {
+        self.create_test_runner_with(environment, |builder| builder)
+    }
+
+    /// Creates a test runner, letting the caller configure the player
+    /// beyond what the test options allow.
+    pub fn create_test_runner_with(
+        &self,
+        environment: &impl Environment,
+        configure: impl FnOnce(PlayerBuilder) -> PlayerBuilder,
+    ) -> Result<TestRunner> {
         let movie = self.movie()?;
         let viewport_dimensions = self.options.player_options.viewport_dimensions(&movie);
         let renderer = self
@@ -57,13 +71,16 @@ impl Test {
 
         let injector = self.input_injector()?;
         let socket_events = self.socket_events()?;
+        let rtmp_flv = self.rtmp_flv()?;
         let runner = TestRunner::new(
             self,
             movie,
             injector,
             socket_events,
+            rtmp_flv,
             renderer,
             viewport_dimensions,
+            configure,
         )?;
         Ok(runner)
     }
@@ -86,6 +103,15 @@ impl Test {
         })
     }
 
+    /// The FLV file streamed by the mock RTMP server, if the test uses one.
+    fn rtmp_flv(&self) -> Result<Option<Vec<u8>>> {
+        Ok(if self.rtmp_path.is_file()? {
+            Some(read_bytes(&self.rtmp_path)?)
+        } else {
+            None
+        })
+    }
+
     fn input_injector(&self) -> Result<InputInjector> {
         Ok(if self.input_path.is_file()? {
             InputInjector::from_reader(&read_bytes(&self.input_path)?[..])
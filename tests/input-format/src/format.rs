@@ -163,6 +163,73 @@ pub enum AutomatedEvent {
 
     /// Commit text using IME.
     ImeCommit { text: String },
+
+    /// Put a finger down on the screen.
+    ///
+    /// Touch points are identified by `id`, which stays the same until the
+    /// finger is lifted. Positions are scaled like mouse positions.
+    TouchBegin { id: u32, pos: MousePosition },
+
+    /// Move a finger that is touching the screen.
+    TouchMove { id: u32, pos: MousePosition },
+
+    /// Lift a finger from the screen.
+    TouchEnd { id: u32, pos: MousePosition },
+
+    /// Cancel a touch, e.g. because the system took over the gesture.
+    TouchCancel { id: u32 },
+
+    /// Do something to a window of an AIR application, like the user or the
+    /// window manager would.
+    ///
+    /// The main window is window `0`, the windows opened by the movie are
+    /// numbered from `1` in the order they were opened.
+    NativeWindow {
+        window: u32,
+        action: NativeWindowAction,
+    },
+
+    /// Send an input event to a window opened by an AIR movie, instead of the
+    /// main window.
+    ///
+    /// Positions are in pixels of the window, which isn't scaled.
+    NativeWindowInput {
+        window: u32,
+        event: Box<AutomatedEvent>,
+    },
+}
+
+/// Something done to a window of an AIR application.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum NativeWindowAction {
+    /// Give the keyboard focus to the window.
+    Activate,
+
+    /// Take the keyboard focus away from the application, as if the user
+    /// switched to another one.
+    Deactivate,
+
+    /// Move or resize the window.
+    SetBounds {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    },
+
+    /// Minimize, maximize or restore the window.
+    SetDisplayState { state: NativeWindowDisplayState },
+
+    /// Click the close button of the window.
+    Close,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub enum NativeWindowDisplayState {
+    Normal,
+    Minimized,
+    Maximized,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
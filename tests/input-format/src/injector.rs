@@ -104,7 +104,13 @@ impl InputInjector {
                     | AutomatedEvent::ImePreedit { .. }
                     | AutomatedEvent::ImeCommit { .. }
                     | AutomatedEvent::FocusGained
-                    | AutomatedEvent::FocusLost => {}
+                    | AutomatedEvent::FocusLost
+                    | AutomatedEvent::TouchBegin { .. }
+                    | AutomatedEvent::TouchMove { .. }
+                    | AutomatedEvent::TouchEnd { .. }
+                    | AutomatedEvent::TouchCancel { .. }
+                    | AutomatedEvent::NativeWindow { .. }
+                    | AutomatedEvent::NativeWindowInput { .. } => {}
                     AutomatedEvent::MouseDown { btn, .. } => {
                         self.buttons |= (*btn).into();
                     }
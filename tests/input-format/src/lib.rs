@@ -1,5 +1,8 @@
 mod format;
 mod injector;
 
-pub use format::{AutomatedEvent, AutomatedKey, MouseButton, TextControlCode};
+pub use format::{
+    AutomatedEvent, AutomatedKey, MouseButton, NativeWindowAction, NativeWindowDisplayState,
+    TextControlCode,
+};
 pub use injector::{InputInjector, MouseButtons};
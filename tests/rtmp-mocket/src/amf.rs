@@ -0,0 +1,48 @@
+//! Reading and writing the AMF0 values of RTMP commands.
+
+use anyhow::{Error, anyhow};
+use flash_lso::amf0::read::AMF0Decoder;
+use flash_lso::types::{AMFVersion, Element, Lso, ObjectId, Value};
+use std::rc::Rc;
+
+pub fn object<'a>(properties: impl IntoIterator<Item = (&'a str, Value)>) -> Value {
+    Value::Object(
+        ObjectId::INVALID,
+        properties
+            .into_iter()
+            .map(|(name, value)| Element::new(name, Rc::new(value)))
+            .collect(),
+        None,
+    )
+}
+
+pub fn string(value: &str) -> Value {
+    Value::String(value.to_string())
+}
+
+/// Read every value of a command or data message.
+pub fn read_values(mut data: &[u8]) -> Result<Vec<Rc<Value>>, Error> {
+    let mut decoder = AMF0Decoder::default();
+    let mut values = Vec::new();
+    while !data.is_empty() {
+        let (rest, value) = decoder
+            .parse_single_element(data)
+            .map_err(|_| anyhow!("Invalid AMF0 data"))?;
+        values.push(Rc::new(Value::clone(&value)));
+        data = rest;
+    }
+    Ok(values)
+}
+
+pub fn write_value(output: &mut Vec<u8>, value: &Value) -> Result<(), Error> {
+    let element = Element::new("", Rc::new(value.clone()));
+    let mut lso = Lso::new(vec![element], "", AMFVersion::AMF0);
+    let bytes = flash_lso::write::write_to_bytes(&mut lso)
+        .map_err(|_| anyhow!("Couldn't serialize {value:?}"))?;
+    // `flash-lso` only writes whole LSO files: strip the header, the name of the element and the
+    // padding after it.
+    output.extend_from_slice(
+        &bytes[flash_lso::write::header_length(&lso.header) + 8..bytes.len() - 1],
+    );
+    Ok(())
+}
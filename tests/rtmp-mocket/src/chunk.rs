@@ -0,0 +1,330 @@
+//! The server end of the RTMP handshake and chunk stream.
+//!
+//! This only turns bytes into messages and back again, so that it can serve a TCP connection as
+//! well as the in-memory sockets of the test framework.
+
+use anyhow::{Error, anyhow};
+use std::collections::HashMap;
+
+const VERSION: u8 = 3;
+const HANDSHAKE_SIZE: usize = 1536;
+const EXTENDED_TIMESTAMP: u32 = 0xFF_FFFF;
+
+pub const SET_CHUNK_SIZE: u8 = 1;
+pub const USER_CONTROL: u8 = 4;
+pub const WINDOW_ACK_SIZE: u8 = 5;
+pub const SET_PEER_BANDWIDTH: u8 = 6;
+pub const AUDIO: u8 = 8;
+pub const COMMAND_AMF3: u8 = 17;
+pub const COMMAND_AMF0: u8 = 20;
+
+#[derive(Debug, Clone)]
+pub struct Message {
+    pub type_id: u8,
+    pub stream_id: u32,
+    pub timestamp: u32,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Header {
+    timestamp: u32,
+    timestamp_delta: u32,
+    length: u32,
+    type_id: u8,
+    stream_id: u32,
+    has_extended_timestamp: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Handshake {
+    /// Waiting for C0 and C1.
+    WaitingForC1,
+
+    /// S0, S1 and S2 were sent, waiting for C2.
+    WaitingForC2,
+
+    /// Messages can be exchanged.
+    Done,
+}
+
+pub struct Connection {
+    handshake: Handshake,
+
+    /// Received bytes that don't make up a complete chunk yet.
+    input: Vec<u8>,
+
+    /// Bytes that should be written to the client.
+    output: Vec<u8>,
+
+    incoming_chunk_size: usize,
+    outgoing_chunk_size: usize,
+    headers: HashMap<u32, Header>,
+    payloads: HashMap<u32, Vec<u8>>,
+}
+
+impl Default for Connection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Connection {
+    pub fn new() -> Self {
+        Self {
+            handshake: Handshake::WaitingForC1,
+            input: Vec::new(),
+            output: Vec::new(),
+            incoming_chunk_size: 128,
+            outgoing_chunk_size: 128,
+            headers: HashMap::new(),
+            payloads: HashMap::new(),
+        }
+    }
+
+    /// Take the bytes that should be written to the client.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Process bytes sent by the client, returning every message they completed.
+    pub fn receive(&mut self, data: &[u8]) -> Result<Vec<Message>, Error> {
+        self.input.extend_from_slice(data);
+
+        if self.handshake == Handshake::WaitingForC1 {
+            if self.input.len() < 1 + HANDSHAKE_SIZE {
+                return Ok(vec![]);
+            }
+            if self.input[0] != VERSION {
+                return Err(anyhow!("Unsupported RTMP version {}", self.input[0]));
+            }
+
+            // S0, S1, and S2 echoing C1.
+            let c1: Vec<u8> = self.input.drain(..1 + HANDSHAKE_SIZE).skip(1).collect();
+            self.output.push(VERSION);
+            self.output.extend_from_slice(&[0; HANDSHAKE_SIZE]);
+            self.output.extend_from_slice(&c1);
+            self.handshake = Handshake::WaitingForC2;
+        }
+
+        if self.handshake == Handshake::WaitingForC2 {
+            if self.input.len() < HANDSHAKE_SIZE {
+                return Ok(vec![]);
+            }
+            let c2: Vec<u8> = self.input.drain(..HANDSHAKE_SIZE).collect();
+            if c2 != [0; HANDSHAKE_SIZE] {
+                tracing::warn!("C2 doesn't echo S1");
+            }
+            self.handshake = Handshake::Done;
+            tracing::info!("Handshake completed");
+        }
+
+        let input = std::mem::take(&mut self.input);
+        let mut position = 0;
+        let mut messages = Vec::new();
+        while let Some((length, message)) = self.read_chunk(&input[position..])? {
+            position += length;
+            let Some(message) = message else {
+                continue;
+            };
+
+            if message.type_id == SET_CHUNK_SIZE && message.payload.len() >= 4 {
+                let size = u32::from_be_bytes([
+                    message.payload[0],
+                    message.payload[1],
+                    message.payload[2],
+                    message.payload[3],
+                ]) & 0x7FFF_FFFF;
+                tracing::info!("Client set chunk size to {size}");
+                self.incoming_chunk_size = size as usize;
+                continue;
+            }
+            messages.push(message);
+        }
+        self.input = input;
+        self.input.drain(..position);
+
+        Ok(messages)
+    }
+
+    /// Read a single chunk from the start of `data`.
+    ///
+    /// Returns `None` if `data` doesn't hold a complete chunk yet, otherwise the length of the
+    /// chunk and the message it completed, if any.
+    fn read_chunk(&mut self, data: &[u8]) -> Result<Option<(usize, Option<Message>)>, Error> {
+        let mut reader = Reader { data, position: 0 };
+        let Some(first) = reader.u8() else {
+            return Ok(None);
+        };
+
+        let format = first >> 6;
+        let chunk_stream_id = match first & 0x3F {
+            0 => {
+                let Some(id) = reader.u8() else {
+                    return Ok(None);
+                };
+                64 + id as u32
+            }
+            1 => {
+                let Some(bytes) = reader.take(2) else {
+                    return Ok(None);
+                };
+                64 + u16::from_le_bytes([bytes[0], bytes[1]]) as u32
+            }
+            id => id as u32,
+        };
+
+        let mut header = match (format, self.headers.get(&chunk_stream_id)) {
+            (0, _) => Header::default(),
+            (_, Some(header)) => *header,
+            (_, None) => {
+                return Err(anyhow!(
+                    "Chunk stream {chunk_stream_id} has no previous message header"
+                ));
+            }
+        };
+        let received = self.payloads.get(&chunk_stream_id).map_or(0, Vec::len);
+
+        let timestamp_field = match format {
+            0 => {
+                let (Some(timestamp), Some(length), Some(type_id), Some(stream_id)) =
+                    (reader.u24(), reader.u24(), reader.u8(), reader.take(4))
+                else {
+                    return Ok(None);
+                };
+                header.length = length;
+                header.type_id = type_id;
+                header.stream_id =
+                    u32::from_le_bytes([stream_id[0], stream_id[1], stream_id[2], stream_id[3]]);
+                Some(timestamp)
+            }
+            1 => {
+                let (Some(delta), Some(length), Some(type_id)) =
+                    (reader.u24(), reader.u24(), reader.u8())
+                else {
+                    return Ok(None);
+                };
+                header.length = length;
+                header.type_id = type_id;
+                Some(delta)
+            }
+            2 => {
+                let Some(delta) = reader.u24() else {
+                    return Ok(None);
+                };
+                Some(delta)
+            }
+            _ => None,
+        };
+        if let Some(field) = timestamp_field {
+            header.has_extended_timestamp = field == EXTENDED_TIMESTAMP;
+        }
+        let timestamp_field = if header.has_extended_timestamp {
+            let Some(extended) = reader.u32() else {
+                return Ok(None);
+            };
+            Some(extended)
+        } else {
+            timestamp_field
+        };
+
+        if format != 3 || received == 0 {
+            let field = timestamp_field.unwrap_or(header.timestamp_delta);
+            header.timestamp = if format == 0 {
+                field
+            } else {
+                header.timestamp.wrapping_add(field)
+            };
+            header.timestamp_delta = field;
+        }
+
+        let received = if format == 3 { received } else { 0 };
+        let remaining = (header.length as usize).saturating_sub(received);
+        let Some(chunk) = reader.take(remaining.min(self.incoming_chunk_size)) else {
+            return Ok(None);
+        };
+
+        self.headers.insert(chunk_stream_id, header);
+        let payload = self.payloads.entry(chunk_stream_id).or_default();
+        if format != 3 {
+            payload.clear();
+        }
+        payload.extend_from_slice(chunk);
+
+        let message = (payload.len() >= header.length as usize).then(|| Message {
+            type_id: header.type_id,
+            stream_id: header.stream_id,
+            timestamp: header.timestamp,
+            payload: std::mem::take(payload),
+        });
+
+        Ok(Some((reader.position, message)))
+    }
+
+    pub fn write_message(&mut self, chunk_stream_id: u32, message: &Message) {
+        let has_extended_timestamp = message.timestamp >= EXTENDED_TIMESTAMP;
+        self.output.push(chunk_stream_id as u8);
+        self.output
+            .extend_from_slice(&message.timestamp.min(EXTENDED_TIMESTAMP).to_be_bytes()[1..]);
+        self.output
+            .extend_from_slice(&(message.payload.len() as u32).to_be_bytes()[1..]);
+        self.output.push(message.type_id);
+        self.output
+            .extend_from_slice(&message.stream_id.to_le_bytes());
+
+        for (index, chunk) in message.payload.chunks(self.outgoing_chunk_size).enumerate() {
+            if index > 0 {
+                self.output.push(0xC0 | chunk_stream_id as u8);
+            }
+            if has_extended_timestamp {
+                self.output
+                    .extend_from_slice(&message.timestamp.to_be_bytes());
+            }
+            self.output.extend_from_slice(chunk);
+        }
+    }
+
+    pub fn write_control(&mut self, type_id: u8, payload: Vec<u8>) {
+        self.write_message(
+            2,
+            &Message {
+                type_id,
+                stream_id: 0,
+                timestamp: 0,
+                payload,
+            },
+        )
+    }
+
+    pub fn set_chunk_size(&mut self, size: u32) {
+        self.write_control(SET_CHUNK_SIZE, size.to_be_bytes().to_vec());
+        self.outgoing_chunk_size = size as usize;
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.position..self.position + length)?;
+        self.position += length;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u24(&mut self) -> Option<u32> {
+        self.take(3)
+            .map(|bytes| u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
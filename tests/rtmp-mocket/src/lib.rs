@@ -0,0 +1,238 @@
+//! A minimal RTMP server for testing `NetConnection` and `NetStream`.
+//!
+//! It answers `connect` and `createStream`, streams the tags of an FLV file in response to
+//! `play`, and echoes the first argument of any other call.
+//!
+//! The server doesn't do any I/O itself: it's fed the bytes sent by the client and produces the
+//! bytes to send back, so that it can serve both a TCP connection and the sockets of the test
+//! framework.
+
+mod amf;
+mod chunk;
+
+use crate::chunk::{
+    AUDIO, COMMAND_AMF0, COMMAND_AMF3, Connection, Message, SET_PEER_BANDWIDTH, USER_CONTROL,
+    WINDOW_ACK_SIZE,
+};
+use anyhow::{Error, anyhow};
+use flash_lso::types::Value;
+use flv_rs::{FlvReader, Header, Tag};
+use std::io::Seek;
+
+/// The port used by `rtmp://` URLs that don't specify one.
+pub const DEFAULT_PORT: u16 = 1935;
+
+const STREAM_BEGIN: u16 = 0;
+const STREAM_EOF: u16 = 1;
+
+/// The size of an FLV tag header, including the size of the previous tag before it.
+const TAG_HEADER_SIZE: usize = 15;
+
+/// An audio, video or script data tag read from an FLV file.
+struct FlvTag {
+    type_id: u8,
+    timestamp: u32,
+    data: Vec<u8>,
+}
+
+fn read_flv_tags(data: &[u8]) -> Result<Vec<FlvTag>, Error> {
+    let mut reader = FlvReader::from_source(data);
+    Header::parse(&mut reader).map_err(|e| anyhow!("Not an FLV file: {e}"))?;
+
+    let mut tags = Vec::new();
+    loop {
+        let start = reader.stream_position()? as usize;
+        let tag = match Tag::parse(&mut reader) {
+            Ok(tag) => tag,
+            Err(flv_rs::Error::EndOfData) => break,
+            Err(e) => return Err(anyhow!("Invalid FLV tag at {start}: {e}")),
+        };
+        let end = reader.stream_position()? as usize;
+
+        // Tags are sent as they are, so only their position is of interest.
+        tags.push(FlvTag {
+            type_id: data[start + 4] & 0x1F,
+            timestamp: tag.timestamp as u32,
+            data: data[start + TAG_HEADER_SIZE..end].to_vec(),
+        });
+    }
+
+    Ok(tags)
+}
+
+pub struct Server {
+    connection: Connection,
+    tags: Vec<FlvTag>,
+    next_stream_id: u32,
+}
+
+impl Server {
+    /// Create a server streaming the tags of an FLV file.
+    pub fn new(flv: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            connection: Connection::new(),
+            tags: read_flv_tags(flv)?,
+            next_stream_id: 1,
+        })
+    }
+
+    /// Take the bytes that should be written to the client.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        self.connection.take_output()
+    }
+
+    /// Process bytes sent by the client.
+    pub fn receive(&mut self, data: &[u8]) -> Result<(), Error> {
+        for message in self.connection.receive(data)? {
+            self.handle_message(message)?;
+        }
+        Ok(())
+    }
+
+    fn handle_message(&mut self, message: Message) -> Result<(), Error> {
+        let payload = match message.type_id {
+            COMMAND_AMF0 => &message.payload[..],
+            COMMAND_AMF3 => message.payload.get(1..).unwrap_or_default(),
+            type_id => {
+                tracing::debug!("Ignoring message of type {type_id}");
+                return Ok(());
+            }
+        };
+
+        let values = amf::read_values(payload)?;
+        let Some(Value::String(name)) = values.first().map(|value| &**value) else {
+            tracing::warn!("Received a command without a name: {values:?}");
+            return Ok(());
+        };
+        let transaction_id = match values.get(1).map(|value| &**value) {
+            Some(Value::Number(transaction_id)) => *transaction_id,
+            _ => 0.0,
+        };
+        let arguments = values.get(3..).unwrap_or_default();
+        tracing::info!(
+            "Received {name} on stream {}: {arguments:?}",
+            message.stream_id
+        );
+
+        match name.as_str() {
+            "connect" => {
+                self.connection
+                    .write_control(WINDOW_ACK_SIZE, 2_500_000u32.to_be_bytes().to_vec());
+                let mut bandwidth = 2_500_000u32.to_be_bytes().to_vec();
+                // Dynamic limit type.
+                bandwidth.push(2);
+                self.connection.write_control(SET_PEER_BANDWIDTH, bandwidth);
+                self.connection.set_chunk_size(4096);
+
+                self.write_command(
+                    0,
+                    &[
+                        amf::string("_result"),
+                        Value::Number(transaction_id),
+                        amf::object([
+                            ("fmsVer", amf::string("FMS/3,5,7,7009")),
+                            ("capabilities", Value::Number(31.0)),
+                        ]),
+                        amf::object([
+                            ("level", amf::string("status")),
+                            ("code", amf::string("NetConnection.Connect.Success")),
+                            ("description", amf::string("Connection succeeded.")),
+                            ("objectEncoding", Value::Number(0.0)),
+                        ]),
+                    ],
+                )?;
+            }
+            "createStream" => {
+                self.write_command(
+                    0,
+                    &[
+                        amf::string("_result"),
+                        Value::Number(transaction_id),
+                        Value::Null,
+                        Value::Number(self.next_stream_id as f64),
+                    ],
+                )?;
+                self.next_stream_id += 1;
+            }
+            "play" => self.play(message.stream_id)?,
+            "deleteStream" | "closeStream" => {}
+            _ if transaction_id != 0.0 => self.write_command(
+                0,
+                &[
+                    amf::string("_result"),
+                    Value::Number(transaction_id),
+                    Value::Null,
+                    arguments
+                        .first()
+                        .map_or(Value::Undefined, |value| Value::clone(value)),
+                ],
+            )?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn write_command(&mut self, stream_id: u32, values: &[Value]) -> Result<(), Error> {
+        let mut payload = Vec::new();
+        for value in values {
+            amf::write_value(&mut payload, value)?;
+        }
+        self.connection.write_message(
+            3,
+            &Message {
+                type_id: COMMAND_AMF0,
+                stream_id,
+                timestamp: 0,
+                payload,
+            },
+        );
+        Ok(())
+    }
+
+    fn write_status(&mut self, stream_id: u32, level: &str, code: &str) -> Result<(), Error> {
+        tracing::info!("Sending {code} on stream {stream_id}");
+        self.write_command(
+            stream_id,
+            &[
+                amf::string("onStatus"),
+                Value::Number(0.0),
+                Value::Null,
+                amf::object([
+                    ("level", amf::string(level)),
+                    ("code", amf::string(code)),
+                    ("description", amf::string(code)),
+                ]),
+            ],
+        )
+    }
+
+    fn write_user_control(&mut self, event_type: u16, stream_id: u32) {
+        let mut payload = event_type.to_be_bytes().to_vec();
+        payload.extend_from_slice(&stream_id.to_be_bytes());
+        self.connection.write_control(USER_CONTROL, payload);
+    }
+
+    fn play(&mut self, stream_id: u32) -> Result<(), Error> {
+        self.write_user_control(STREAM_BEGIN, stream_id);
+        self.write_status(stream_id, "status", "NetStream.Play.Reset")?;
+        self.write_status(stream_id, "status", "NetStream.Play.Start")?;
+
+        for tag in &self.tags {
+            let chunk_stream_id = if tag.type_id == AUDIO { 4 } else { 5 };
+            self.connection.write_message(
+                chunk_stream_id,
+                &Message {
+                    type_id: tag.type_id,
+                    stream_id,
+                    timestamp: tag.timestamp,
+                    payload: tag.data.clone(),
+                },
+            );
+        }
+        tracing::info!("Sent {} tags on stream {stream_id}", self.tags.len());
+
+        self.write_user_control(STREAM_EOF, stream_id);
+        self.write_status(stream_id, "status", "NetStream.Play.Stop")
+    }
+}
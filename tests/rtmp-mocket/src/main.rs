@@ -0,0 +1,65 @@
+//! A minimal RTMP server for manually testing `NetConnection` and `NetStream`.
+//!
+//! It accepts a single connection, and serves it like the test framework does for tests with an
+//! `rtmp.flv` file.
+
+use anyhow::Error;
+use clap::Parser;
+use rtmp_mocket::{DEFAULT_PORT, Server};
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+
+/// An FLV file without any tags.
+static EMPTY_FLV: &[u8] = b"FLV\x01\x05\x00\x00\x00\x09\x00\x00\x00\x00";
+
+#[derive(Parser, Debug)]
+struct Opt {
+    /// Port to listen on.
+    #[clap(long, default_value_t = DEFAULT_PORT)]
+    port: u16,
+
+    /// Path to an FLV file to stream when a client plays any stream.
+    #[clap(name = "FILE")]
+    file_path: Option<PathBuf>,
+}
+
+fn main() -> Result<(), Error> {
+    let opt = Opt::parse();
+
+    let subscriber = tracing_subscriber::fmt::Subscriber::builder()
+        .with_env_filter(
+            EnvFilter::builder()
+                .with_default_directive(LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        .finish();
+    // Ignore error if it's already been set
+    let _ = tracing::subscriber::set_global_default(subscriber);
+
+    let mut server = match &opt.file_path {
+        Some(path) => Server::new(&std::fs::read(path)?)?,
+        None => Server::new(EMPTY_FLV)?,
+    };
+
+    let listener = TcpListener::bind(("0.0.0.0", opt.port))?;
+    tracing::info!("Listening on {}", listener.local_addr()?);
+    let (mut stream, addr) = listener.accept()?;
+    tracing::info!("Incoming connection from {}", addr);
+
+    let mut buffer = [0; 4096];
+    loop {
+        let length = match stream.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(length) => length,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        server.receive(&buffer[..length])?;
+        stream.write_all(&server.take_output())?;
+    }
+
+    tracing::info!("Client disconnected");
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use super::{Client, run_debugger_test};
+use ruffle_test_framework::environment::Environment;
+use serde_json::{Value, json};
+
+/// The activation running the function `add` of the frame script.
+const ADD: &str = "[Frame] / add(number, number)";
+
+/// The most actions the frame script runs before calling `add`.
+const MAX_STEPS: usize = 30;
+
+/// Receive a `paused` event, checking its reason and activation.
+fn expect_paused(client: &Client, reason: &str, activation: &str) -> Result<Value, String> {
+    let paused = client.expect("paused")?;
+    if paused["reason"] != reason || paused["activation"] != activation {
+        return Err(format!(
+            "Expected a {reason} pause in {activation}, got {paused}"
+        ));
+    }
+    Ok(paused)
+}
+
+/// Send an inspection command, and return the event answering it.
+fn inspect(client: &Client, command: &str) -> Result<Value, String> {
+    client.send(json!({ "command": command }))?;
+    client.expect(command)
+}
+
+fn number(value: u32) -> Value {
+    json!({ "type": "Number", "value": value.to_string() })
+}
+
+/// Drives the debugger through `tests/swfs/avm1/debugger`, whose two frames
+/// loop. The script of the first frame defines `add(a, b)`, and then calls it
+/// to increment `total`. A breakpoint on that script has already been
+/// requested.
+fn run_client(client: Client) -> Result<(), String> {
+    let set = client.expect("breakpoint_set")?;
+    let paused = expect_paused(&client, "breakpoint", "[Frame]")?;
+    if paused["id"] != set["id"] {
+        return Err(format!("Paused at the wrong breakpoint: {paused}"));
+    }
+    let script_offset = paused["offset"].clone();
+
+    // Step through the frame script, one action at a time, until it calls
+    // `add`.
+    let mut steps = 0;
+    let entry = loop {
+        client.resume("step_in")?;
+        let paused = client.expect("paused")?;
+        if paused["reason"] != "step" {
+            return Err(format!("Expected a step, got {paused}"));
+        }
+        if paused["activation"] == ADD {
+            break paused;
+        }
+        steps += 1;
+        if steps == MAX_STEPS {
+            return Err("Never stepped into add".to_string());
+        }
+    };
+
+    let call_stack = inspect(&client, "call_stack")?;
+    if call_stack["frames"] != json!(["add(number, number)", "[Frame]"]) {
+        return Err(format!("Unexpected call stack: {call_stack}"));
+    }
+
+    let scopes = inspect(&client, "scopes")?;
+    let locals = &scopes["scopes"][0];
+    if locals["kind"] != "Local"
+        || locals["variables"]["a"] != number(0)
+        || locals["variables"]["b"] != number(1)
+    {
+        return Err(format!("Unexpected scopes: {scopes}"));
+    }
+
+    // The function doesn't use registers, so the global ones are shown.
+    let registers = inspect(&client, "registers")?;
+    if registers["registers"].as_array().map(Vec::len) != Some(4) {
+        return Err(format!("Unexpected registers: {registers}"));
+    }
+
+    let constant_pool = inspect(&client, "constant_pool")?;
+    if constant_pool["constants"] != json!([]) {
+        return Err(format!("Unexpected constant pool: {constant_pool}"));
+    }
+
+    client.send(json!({ "command": "evaluate", "expression": "b" }))?;
+    let value = client.expect("value")?;
+    if value["expression"] != "b" || value["value"] != number(1) {
+        return Err(format!("Evaluated b wrongly: {value}"));
+    }
+
+    client.resume("step_over")?;
+    let paused = expect_paused(&client, "step", ADD)?;
+    if paused["offset"].as_u64() <= entry["offset"].as_u64() {
+        return Err(format!("Stepped over to the wrong action: {paused}"));
+    }
+
+    // Stepping out stops right after the call, with its result pushed on the
+    // stack, ready to be stored in `total`.
+    client.resume("step_out")?;
+    let paused = expect_paused(&client, "step", "[Frame]")?;
+    if paused["action"] != "SetVariable" {
+        return Err(format!("Stepped out to the wrong action: {paused}"));
+    }
+    let operands = inspect(&client, "operands")?;
+    if operands["operands"] != json!([{ "type": "String", "value": "total" }, number(1)]) {
+        return Err(format!("Unexpected operands: {operands}"));
+    }
+
+    // Replace the frame script breakpoint by one on its first action.
+    client.send(json!({ "command": "remove_breakpoint", "id": set["id"] }))?;
+    client.expect("breakpoint_removed")?;
+    client.send(json!({ "command": "set_breakpoint", "offset": script_offset }))?;
+    let set = client.expect("breakpoint_set")?;
+    client.resume("continue")?;
+
+    // The breakpoint is hit again the next time the first frame runs.
+    // Detaching resumes execution and removes it, and the next client
+    // starts afresh.
+    let paused = expect_paused(&client, "breakpoint", "[Frame]")?;
+    if paused["id"] != set["id"] || paused["offset"] != script_offset {
+        return Err(format!("Paused at the wrong breakpoint: {paused}"));
+    }
+    client.resume("detach")?;
+
+    client.send(json!({ "command": "list_breakpoints" }))?;
+    client.send(json!({ "command": "pause" }))?;
+    let breakpoints = client.expect("breakpoints")?;
+    if breakpoints["breakpoints"] != json!([]) {
+        return Err(format!("Breakpoints survived detaching: {breakpoints}"));
+    }
+    let paused = client.expect("paused")?;
+    if paused["reason"] != "pause" {
+        return Err(format!("Expected a pause, got {paused}"));
+    }
+    client.resume("continue")?;
+
+    Ok(())
+}
+
+pub fn avm1_debugger(environment: &impl Environment) -> Result<(), libtest_mimic::Failed> {
+    run_debugger_test(
+        environment,
+        "avm1_debugger",
+        "tests/swfs/avm1/debugger/",
+        10,
+        |builder, connection| builder.with_avm1_debugger(connection),
+        // Set the breakpoint before any code runs, so that the first frame hits it.
+        |client| client.send(json!({ "command": "set_breakpoint", "clip": "_level0", "frame": 1 })),
+        run_client,
+    )
+}
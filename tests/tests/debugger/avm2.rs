@@ -0,0 +1,94 @@
+use super::{Client, run_debugger_test};
+use ruffle_test_framework::environment::Environment;
+use serde_json::{Value, json};
+
+/// Receive a `paused` event, checking its reason and method.
+fn expect_paused(client: &Client, reason: &str, method: &str) -> Result<Value, String> {
+    let paused = client.expect("paused")?;
+    if paused["reason"] != reason || paused["method"] != method {
+        return Err(format!(
+            "Expected a {reason} pause in {method}, got {paused}"
+        ));
+    }
+    Ok(paused)
+}
+
+/// Drives the debugger through `tests/swfs/avm2/debugger`, which calls
+/// `onEnterFrame` (lines 12 to 19) every frame, which in turn calls `add`.
+/// A breakpoint on line 13 has already been requested.
+fn run_client(client: Client) -> Result<(), String> {
+    let set = client.expect("breakpoint_set")?;
+    let paused = expect_paused(&client, "breakpoint", "Test/onEnterFrame")?;
+    if paused["id"] != set["id"] || paused["file"] != "Test.as" || paused["line"] != 13 {
+        return Err(format!("Paused at the wrong breakpoint: {paused}"));
+    }
+
+    client.send(json!({ "command": "locals" }))?;
+    let locals = client.expect("locals")?;
+    let names: Vec<_> = locals["locals"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|local| local["name"].clone())
+        .collect();
+    if names != [json!("this"), json!("event"), json!("total")] {
+        return Err(format!("Unexpected locals: {locals}"));
+    }
+
+    client.resume("step_over")?;
+    let paused = expect_paused(&client, "step", "Test/onEnterFrame")?;
+    if paused["line"] != 14 {
+        return Err(format!("Stepped over to the wrong line: {paused}"));
+    }
+
+    client.resume("step_in")?;
+    expect_paused(&client, "step", "Test/add")?;
+
+    client.send(json!({ "command": "call_stack" }))?;
+    let call_stack = client.expect("call_stack")?;
+    if call_stack["frames"][0]["method"] != "Test/add"
+        || call_stack["frames"][1]["method"] != "Test/onEnterFrame"
+    {
+        return Err(format!("Unexpected call stack: {call_stack}"));
+    }
+
+    client.resume("step_out")?;
+    let paused = expect_paused(&client, "step", "Test/onEnterFrame")?;
+    if paused["line"] != 15 {
+        return Err(format!("Stepped out to the wrong line: {paused}"));
+    }
+
+    client.resume("continue")?;
+
+    // The breakpoint is hit again on the next frame. Detaching resumes
+    // execution and removes it, and the next client starts afresh.
+    expect_paused(&client, "breakpoint", "Test/onEnterFrame")?;
+    client.resume("detach")?;
+
+    client.send(json!({ "command": "list_breakpoints" }))?;
+    client.send(json!({ "command": "pause" }))?;
+    let breakpoints = client.expect("breakpoints")?;
+    if breakpoints["breakpoints"] != json!([]) {
+        return Err(format!("Breakpoints survived detaching: {breakpoints}"));
+    }
+    let paused = client.expect("paused")?;
+    if paused["reason"] != "pause" {
+        return Err(format!("Expected a pause, got {paused}"));
+    }
+    client.resume("continue")?;
+
+    Ok(())
+}
+
+pub fn avm2_debugger(environment: &impl Environment) -> Result<(), libtest_mimic::Failed> {
+    run_debugger_test(
+        environment,
+        "avm2_debugger",
+        "tests/swfs/avm2/debugger/",
+        10,
+        |builder, connection| builder.with_avm2_debugger(connection),
+        // Set the breakpoint before any code runs, so that the first call hits it.
+        |client| client.send(json!({ "command": "set_breakpoint", "file": "Test.as", "line": 13 })),
+        run_client,
+    )
+}
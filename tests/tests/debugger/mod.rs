@@ -0,0 +1,111 @@
+//! Tests driving the step debuggers through a client, like an IDE would.
+
+mod avm1;
+mod avm2;
+
+pub use avm1::avm1_debugger;
+pub use avm2::avm2_debugger;
+
+use ruffle_core::{DebuggerConnection, PlayerBuilder};
+use ruffle_test_framework::environment::Environment;
+use ruffle_test_framework::options::TestOptions;
+use ruffle_test_framework::runner::TestStatus;
+use ruffle_test_framework::test::Test;
+use ruffle_test_framework::vfs::{PhysicalFS, VfsPath};
+use serde_json::{Value, json};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, sleep};
+use std::time::Duration;
+
+/// How long the client waits for the player before giving up.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The client side of a debugger connection.
+struct Client {
+    commands: Sender<String>,
+    events: Receiver<String>,
+}
+
+impl Client {
+    fn send(&self, command: Value) -> Result<(), String> {
+        self.commands
+            .send(command.to_string())
+            .map_err(|_| "The player went away".to_string())
+    }
+
+    fn receive(&self) -> Result<Value, String> {
+        let line = self
+            .events
+            .recv_timeout(TIMEOUT)
+            .map_err(|e| format!("No event received: {e}"))?;
+        serde_json::from_str(&line).map_err(|e| format!("Malformed event {line}: {e}"))
+    }
+
+    /// Receive the next event, which must be of the given kind.
+    fn expect(&self, event: &str) -> Result<Value, String> {
+        let received = self.receive()?;
+        if received["event"] != event {
+            return Err(format!("Expected {event} event, got {received}"));
+        }
+        Ok(received)
+    }
+
+    /// Send a command that resumes execution, and wait until it does.
+    fn resume(&self, command: &str) -> Result<(), String> {
+        self.send(json!({ "command": command }))?;
+        self.expect("resumed")?;
+        Ok(())
+    }
+}
+
+/// Run the movie in `directory` for `num_frames` frames with a debugger
+/// attached by `attach`, while `run_client` drives it from another thread.
+///
+/// `setup` sends the first commands before any code runs.
+fn run_debugger_test(
+    environment: &impl Environment,
+    name: &str,
+    directory: &str,
+    num_frames: u32,
+    attach: impl FnOnce(PlayerBuilder, DebuggerConnection) -> PlayerBuilder,
+    setup: impl FnOnce(&Client) -> Result<(), String>,
+    run_client: fn(Client) -> Result<(), String>,
+) -> Result<(), libtest_mimic::Failed> {
+    let (commands, player_commands) = mpsc::channel();
+    let (player_events, events) = mpsc::channel();
+    let connection = DebuggerConnection {
+        commands: player_commands,
+        events: player_events,
+    };
+
+    let test = &Test::from_options(
+        TestOptions {
+            num_frames: Some(num_frames),
+            ..Default::default()
+        },
+        VfsPath::new(PhysicalFS::new(directory)),
+        name.to_string(),
+    )?;
+    let mut runner =
+        test.create_test_runner_with(environment, |builder| attach(builder, connection))?;
+
+    let client = Client { commands, events };
+    setup(&client)?;
+
+    // The player blocks while paused, so the client needs its own thread.
+    let client = thread::spawn(move || run_client(client));
+
+    loop {
+        match runner.tick()? {
+            TestStatus::Continue => {}
+            TestStatus::Sleep(duration) => sleep(duration),
+            TestStatus::Finished => break,
+        }
+    }
+
+    client
+        .join()
+        .map_err(|_| "The debugger client panicked")??;
+
+    Ok(())
+}
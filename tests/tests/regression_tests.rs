@@ -2,6 +2,7 @@
 //!
 //! Trace output can be compared with correct output from the official Flash Player.
 
+use crate::debugger::{avm1_debugger, avm2_debugger};
 use crate::environment::NativeEnvironment;
 use crate::external_interface::tests::{external_interface_avm1, external_interface_avm2};
 use crate::shared_object::{shared_object_avm1, shared_object_avm2, shared_object_self_ref_avm1};
@@ -17,6 +18,7 @@ use std::borrow::Cow;
 use std::path::PathBuf;
 use std::thread::sleep;
 
+mod debugger;
 mod environment;
 mod external_interface;
 mod shared_object;
@@ -85,6 +87,12 @@ fn main() {
     runner.with_additional_test(Trial::test("external_interface_avm2", || {
         external_interface_avm2(&NativeEnvironment)
     }));
+    runner.with_additional_test(Trial::test("avm1_debugger", || {
+        avm1_debugger(&NativeEnvironment)
+    }));
+    runner.with_additional_test(Trial::test("avm2_debugger", || {
+        avm2_debugger(&NativeEnvironment)
+    }));
 
     runner.run()
 }
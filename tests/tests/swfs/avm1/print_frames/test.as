@@ -0,0 +1,16 @@
+// `clip` has 4 frames, frames 2 and 4 are labelled `#p` and trace when their scripts run.
+
+clip.stop();
+trace("before print: " + clip._currentframe);
+print(clip, "bmax");
+trace("after print: " + clip._currentframe);
+printAsBitmap(clip, "bframe");
+print(_root, "bmovie");
+
+pj = new PrintJob();
+trace(pj.start());
+trace(pj.addPage("clip", null, null, 3));
+trace(pj.addPage("clip", {xMin: 0, xMax: 100, yMin: 0, yMax: 100}, null, 9));
+trace(pj.addPage("clip"));
+pj.send();
+trace("after PrintJob: " + clip._currentframe);
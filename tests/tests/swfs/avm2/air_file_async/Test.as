@@ -0,0 +1,139 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.events.FileListEvent;
+	import flash.events.IOErrorEvent;
+	import flash.events.OutputProgressEvent;
+	import flash.events.ProgressEvent;
+	import flash.filesystem.File;
+	import flash.filesystem.FileMode;
+	import flash.filesystem.FileStream;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends Sprite {
+		private var directory:File = File.applicationStorageDirectory.resolvePath("async");
+		private var readFile:File = directory.resolvePath("read.txt");
+		private var writeFile:File = directory.resolvePath("write.txt");
+		private var missing:File = directory.resolvePath("missing.txt");
+
+		public function Test() {
+			var stream:FileStream = new FileStream();
+			stream.open(readFile, FileMode.WRITE);
+			stream.writeUTFBytes("async data");
+			stream.close();
+
+			readAsync();
+		}
+
+		private function readAsync():void {
+			trace("// read");
+			var stream:FileStream = new FileStream();
+			stream.addEventListener(ProgressEvent.PROGRESS, function(event:ProgressEvent):void {
+				trace("progress: " + event.bytesLoaded + "/" + event.bytesTotal);
+			});
+			stream.addEventListener(Event.COMPLETE, function(event:Event):void {
+				trace("complete: " + stream.readUTFBytes(stream.bytesAvailable));
+				stream.close();
+				trace("closing");
+			});
+			stream.addEventListener(Event.CLOSE, function(event:Event):void {
+				trace("close");
+				writeAsync();
+			});
+			stream.openAsync(readFile, FileMode.READ);
+			trace("opened, bytesAvailable: " + stream.bytesAvailable);
+			try {
+				stream.readByte();
+			} catch (e:Error) {
+				trace("read before load: " + getQualifiedClassName(e) + " " + e.errorID);
+			}
+		}
+
+		private function writeAsync():void {
+			trace("// write");
+			var stream:FileStream = new FileStream();
+			stream.addEventListener(Event.COMPLETE, function(event:Event):void {
+				trace("unexpected complete");
+			});
+			stream.addEventListener(OutputProgressEvent.OUTPUT_PROGRESS, function(event:OutputProgressEvent):void {
+				trace("outputProgress: " + event.bytesPending + "/" + event.bytesTotal);
+				trace("written: " + readAll(writeFile));
+				stream.close();
+			});
+			stream.addEventListener(Event.CLOSE, function(event:Event):void {
+				trace("close");
+				openMissing();
+			});
+			stream.openAsync(writeFile, FileMode.WRITE);
+			stream.writeUTFBytes("abc");
+			trace("opened, exists: " + writeFile.exists + ", size: " + writeFile.size);
+		}
+
+		private function openMissing():void {
+			trace("// open missing");
+			var stream:FileStream = new FileStream();
+			stream.addEventListener(IOErrorEvent.IO_ERROR, function(event:IOErrorEvent):void {
+				trace("ioError: " + event.errorID + " " + event.text);
+				listDirectory();
+			});
+			stream.openAsync(missing, FileMode.READ);
+			trace("opened");
+		}
+
+		private function listDirectory():void {
+			trace("// getDirectoryListingAsync");
+			directory.addEventListener(FileListEvent.DIRECTORY_LISTING, function(event:FileListEvent):void {
+				directory.removeEventListener(FileListEvent.DIRECTORY_LISTING, arguments.callee);
+				trace("directoryListing: " + names(event.files));
+				deleteMissing();
+			});
+			directory.getDirectoryListingAsync();
+			trace("listing");
+		}
+
+		private function deleteMissing():void {
+			trace("// deleteFileAsync");
+			missing.addEventListener(IOErrorEvent.IO_ERROR, function(event:IOErrorEvent):void {
+				trace("ioError: " + event.errorID);
+				copy();
+			});
+			missing.deleteFileAsync();
+			trace("deleting");
+		}
+
+		private function copy():void {
+			trace("// copyToAsync");
+			readFile.addEventListener(Event.COMPLETE, function(event:Event):void {
+				trace("complete: " + names(directory.getDirectoryListing()));
+				deleteDirectory();
+			});
+			readFile.copyToAsync(directory.resolvePath("copy.txt"));
+			trace("copying");
+		}
+
+		private function deleteDirectory():void {
+			trace("// deleteDirectoryAsync");
+			directory.addEventListener(Event.COMPLETE, function(event:Event):void {
+				trace("complete, exists: " + directory.exists);
+			});
+			directory.deleteDirectoryAsync(true);
+			trace("deleting, exists: " + directory.exists);
+		}
+
+		private static function readAll(file:File):String {
+			var stream:FileStream = new FileStream();
+			stream.open(file, FileMode.READ);
+			var text:String = stream.readUTFBytes(stream.bytesAvailable);
+			stream.close();
+			return text;
+		}
+
+		private static function names(files:Array):String {
+			var result:Array = [];
+			for each (var file:File in files) {
+				result.push(file.name);
+			}
+			return result.join(", ");
+		}
+	}
+}
@@ -0,0 +1,188 @@
+package {
+	import flash.display.Sprite;
+	import flash.filesystem.File;
+	import flash.filesystem.FileMode;
+	import flash.filesystem.FileStream;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var storage:File = File.applicationStorageDirectory;
+			trace("storage: " + storage.nativePath + " " + storage.url);
+
+			trace("// resolvePath");
+			var notes:File = storage.resolvePath("dir/sub/../notes.txt");
+			trace(notes.nativePath + " " + notes.url + " " + notes.name);
+			trace(storage.resolvePath("./dir/./notes.txt").nativePath);
+			trace(storage.resolvePath("dir\\sub\\..\\..\\other.txt").nativePath);
+			trace(storage.resolvePath("../../../..").nativePath);
+			trace(storage.resolvePath("/documents/a.txt").nativePath);
+			trace(notes.parent.nativePath);
+			trace(storage.getRelativePath(notes));
+			trace(notes.getRelativePath(storage));
+			trace(notes.getRelativePath(storage, true));
+			trace(new File("app-storage:/dir/with%20space.txt").nativePath);
+
+			trace("// write");
+			trace("exists: " + notes.exists);
+			var stream:FileStream = new FileStream();
+			stream.open(notes, FileMode.WRITE);
+			stream.writeUTFBytes("hello");
+			trace("position: " + stream.position);
+			stream.close();
+			trace("exists: " + notes.exists + ", isDirectory: " + notes.isDirectory + ", size: " + notes.size);
+			trace("parent isDirectory: " + notes.parent.isDirectory);
+
+			trace("// append");
+			stream.open(notes, FileMode.APPEND);
+			stream.writeUTFBytes(" world");
+			stream.close();
+			trace(readAll(notes));
+
+			trace("// update");
+			stream.open(notes, FileMode.UPDATE);
+			trace("bytesAvailable: " + stream.bytesAvailable);
+			stream.position = 6;
+			stream.writeUTFBytes("there");
+			stream.position = 0;
+			trace(stream.readUTFBytes(stream.bytesAvailable));
+			stream.close();
+			trace(readAll(notes));
+
+			trace("// binary");
+			var data:File = storage.resolvePath("dir/data.bin");
+			stream.open(data, FileMode.WRITE);
+			stream.writeInt(-2);
+			stream.writeUTF("utf");
+			stream.writeDouble(1.5);
+			stream.close();
+			trace("size: " + data.size);
+			stream.open(data, FileMode.READ);
+			trace(stream.readInt() + " " + stream.readUTF() + " " + stream.readDouble());
+			stream.close();
+
+			trace("// listing");
+			storage.resolvePath("dir/sub").createDirectory();
+			trace(listing(storage.resolvePath("dir")));
+
+			trace("// copy and move");
+			notes.copyTo(storage.resolvePath("dir/sub/copy.txt"));
+			trace(readAll(storage.resolvePath("dir/sub/copy.txt")));
+			data.moveTo(storage.resolvePath("moved.bin"));
+			trace("moved: " + data.exists + " " + storage.resolvePath("moved.bin").exists);
+			trace(listing(storage));
+
+			trace("// errors");
+			attempt("deleteFile missing", function():void {
+				storage.resolvePath("missing.txt").deleteFile();
+			});
+			attempt("open missing", function():void {
+				stream.open(storage.resolvePath("missing.txt"), FileMode.READ);
+			});
+			attempt("open directory", function():void {
+				stream.open(storage.resolvePath("dir"), FileMode.READ);
+			});
+			attempt("deleteFile directory", function():void {
+				storage.resolvePath("dir").deleteFile();
+			});
+			attempt("deleteDirectory file", function():void {
+				notes.deleteDirectory();
+			});
+			attempt("deleteDirectory not empty", function():void {
+				storage.resolvePath("dir").deleteDirectory();
+			});
+			attempt("createDirectory over file", function():void {
+				notes.createDirectory();
+			});
+			attempt("createDirectory in app", function():void {
+				File.applicationDirectory.resolvePath("new").createDirectory();
+			});
+			attempt("write to app", function():void {
+				stream.open(File.applicationDirectory.resolvePath("new.txt"), FileMode.WRITE);
+			});
+			attempt("delete storage", function():void {
+				storage.deleteDirectory(true);
+			});
+			attempt("copy to existing", function():void {
+				notes.copyTo(storage.resolvePath("moved.bin"));
+			});
+			attempt("copy into itself", function():void {
+				storage.resolvePath("dir").copyTo(storage.resolvePath("dir/sub/dir"));
+			});
+			attempt("copy missing", function():void {
+				storage.resolvePath("missing.txt").copyTo(storage.resolvePath("copy.txt"));
+			});
+			attempt("listing of file", function():void {
+				notes.getDirectoryListing();
+			});
+			attempt("read in append mode", function():void {
+				stream.open(notes, FileMode.APPEND);
+				try {
+					stream.readByte();
+				} finally {
+					stream.close();
+				}
+			});
+			attempt("write in read mode", function():void {
+				stream.open(notes, FileMode.READ);
+				try {
+					stream.writeByte(0);
+				} finally {
+					stream.close();
+				}
+			});
+			attempt("read closed", function():void {
+				stream.readByte();
+			});
+			attempt("read past end", function():void {
+				stream.open(notes, FileMode.READ);
+				try {
+					stream.readUTFBytes(100);
+				} finally {
+					stream.close();
+				}
+			});
+			attempt("invalid mode", function():void {
+				stream.open(notes, "bogus");
+			});
+			attempt("invalid path", function():void {
+				new File("relative/path");
+			});
+			attempt("uninitialized", function():void {
+				new File().exists;
+			});
+
+			trace("// overwrite and delete");
+			notes.copyTo(storage.resolvePath("moved.bin"), true);
+			trace(readAll(storage.resolvePath("moved.bin")));
+			storage.resolvePath("dir").deleteDirectory(true);
+			trace("dir exists: " + storage.resolvePath("dir").exists);
+			trace(listing(storage));
+		}
+
+		private static function readAll(file:File):String {
+			var stream:FileStream = new FileStream();
+			stream.open(file, FileMode.READ);
+			var text:String = stream.readUTFBytes(stream.bytesAvailable);
+			stream.close();
+			return text;
+		}
+
+		private static function listing(directory:File):String {
+			var names:Array = [];
+			for each (var file:File in directory.getDirectoryListing()) {
+				names.push(file.name + (file.isDirectory ? "/" : ""));
+			}
+			return names.join(", ");
+		}
+
+		private static function attempt(label:String, operation:Function):void {
+			try {
+				operation();
+				trace(label + ": ok");
+			} catch (e:Error) {
+				trace(label + ": " + getQualifiedClassName(e) + " " + e.errorID);
+			}
+		}
+	}
+}
@@ -0,0 +1,49 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.media.Camera;
+	import flash.media.Video;
+
+	// The test camera alternates between a red and a blue 4x4 frame.
+	public class Test extends Sprite {
+		private var camera:Camera;
+		private var bitmapData:BitmapData = new BitmapData(4, 4, true, 0);
+		private var lastColor:uint = 0;
+		private var changes:int = 0;
+
+		public function Test() {
+			camera = Camera.getCamera();
+			trace("name: " + camera.name);
+			// Capture faster than the movie runs, so that every frame brings a new image.
+			camera.setMode(4, 4, 60);
+
+			camera.drawToBitmapData(bitmapData);
+			trace("before capture: " + bitmapData.getPixel32(0, 0).toString(16));
+
+			// Capture starts once the camera is attached to something.
+			var video:Video = new Video(4, 4);
+			video.attachCamera(camera);
+			addChild(video);
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			camera.drawToBitmapData(bitmapData);
+			var color:uint = bitmapData.getPixel32(0, 0);
+			if (color == lastColor) {
+				return;
+			}
+			lastColor = color;
+
+			trace("frame: " + color.toString(16) + " size: " + camera.width + "x" + camera.height +
+				" activityLevel: " + camera.activityLevel);
+
+			changes++;
+			if (changes == 3) {
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+	}
+}
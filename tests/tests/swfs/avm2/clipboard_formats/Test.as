@@ -0,0 +1,84 @@
+package {
+	import flash.desktop.Clipboard;
+	import flash.desktop.ClipboardFormats;
+	import flash.desktop.ClipboardTransferMode;
+	import flash.display.BitmapData;
+	import flash.display.Sprite;
+	import flash.utils.ByteArray;
+	import flash.utils.setTimeout;
+
+	public class Test extends Sprite {
+		private var clipboard:Clipboard = Clipboard.generalClipboard;
+		private var calls:int = 0;
+
+		public function Test() {
+			trace("// general clipboard");
+			clipboard.clear();
+			trace("formats: " + clipboard.formats.length);
+			trace(clipboard.setData(ClipboardFormats.TEXT_FORMAT, "Hello"));
+			trace(clipboard.setData(ClipboardFormats.HTML_FORMAT, "<b>Hello</b>"));
+			trace("formats: " + clipboard.formats);
+			trace(clipboard.getData(ClipboardFormats.TEXT_FORMAT));
+			trace(clipboard.getData(ClipboardFormats.HTML_FORMAT));
+			trace(clipboard.setData(ClipboardFormats.TEXT_FORMAT, 5));
+			trace(clipboard.getData(ClipboardFormats.TEXT_FORMAT));
+
+			trace(clipboard.setData(ClipboardFormats.URL_FORMAT, "http://example.com/a"));
+			trace(clipboard.getData(ClipboardFormats.URL_FORMAT));
+			clipboard.clearData(ClipboardFormats.HTML_FORMAT);
+			trace("formats: " + clipboard.formats);
+			trace("hasFormat(html): " + clipboard.hasFormat(ClipboardFormats.HTML_FORMAT));
+
+			var bitmap:BitmapData = new BitmapData(2, 2, true, 0xFFFF0000);
+			trace(clipboard.setData(ClipboardFormats.BITMAP_FORMAT, bitmap));
+			var copy:BitmapData = clipboard.getData(ClipboardFormats.BITMAP_FORMAT) as BitmapData;
+			trace("copy: " + (copy != bitmap) + " " + copy.width + "x" + copy.height + " " + copy.getPixel32(1, 1).toString(16));
+
+			trace(clipboard.setData("custom", {a: 1}));
+			trace("formats: " + clipboard.formats);
+			trace("custom.a: " + clipboard.getData("custom").a);
+
+			trace("// deferred data on the general clipboard");
+			trace(clipboard.setDataHandler(ClipboardFormats.TEXT_FORMAT, function():String {
+				calls++;
+				return "Deferred";
+			}));
+			trace("formats: " + clipboard.formats);
+			trace("calls: " + calls);
+			setTimeout(afterRender, 100);
+
+			trace("// deferred data on another clipboard");
+			var own:Clipboard = new Clipboard();
+			var ownCalls:int = 0;
+			trace(own.setDataHandler("lazy", function():Object {
+				ownCalls++;
+				return "Lazy";
+			}));
+			trace(own.setDataHandler("none", null));
+			trace("formats: " + own.formats);
+			trace("calls: " + ownCalls);
+			trace(own.getData("lazy"));
+			trace(own.getData("lazy"));
+			trace("calls: " + ownCalls);
+
+			trace("// transfer modes");
+			var bytes:ByteArray = new ByteArray();
+			bytes.writeUTFBytes("abc");
+			own.setData("bytes", bytes);
+			trace("original: " + (own.getData("bytes", ClipboardTransferMode.ORIGINAL_PREFERRED) === bytes));
+			trace("original only: " + (own.getData("bytes", ClipboardTransferMode.ORIGINAL_ONLY) === bytes));
+			var clone:ByteArray = own.getData("bytes", ClipboardTransferMode.CLONE_ONLY) as ByteArray;
+			trace("clone: " + (clone === bytes) + " " + clone.length + " " + clone.position);
+			own.clear();
+			trace("formats: " + own.formats.length);
+		}
+
+		private function afterRender():void {
+			trace("// after the handler was rendered");
+			trace("calls: " + calls);
+			trace("formats: " + clipboard.formats);
+			trace(clipboard.getData(ClipboardFormats.TEXT_FORMAT));
+			trace("calls: " + calls);
+		}
+	}
+}
@@ -0,0 +1,85 @@
+package {
+	import flash.display.Sprite;
+	import flash.globalization.Collator;
+	import flash.globalization.CollatorMode;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var collator:Collator = new Collator("en-US");
+			describe("new Collator(\"en-US\")", collator);
+			compare(collator, "a", "b");
+			compare(collator, "b", "a");
+			compare(collator, "a", "B");
+			compare(collator, "a", "A");
+			compare(collator, "resume", "résumé");
+			compare(collator, "file9", "file10");
+
+			trace("// ignoreCase = true");
+			collator.ignoreCase = true;
+			compare(collator, "a", "A");
+			compare(collator, "Apple", "apple");
+			compare(collator, "resume", "Résumé");
+
+			trace("// ignoreDiacritics = true");
+			collator.ignoreDiacritics = true;
+			compare(collator, "resume", "Résumé");
+			compare(collator, "naïve", "NAIVE");
+			collator.ignoreCase = false;
+			trace("// ignoreCase = false");
+			compare(collator, "resume", "Résumé");
+			compare(collator, "resume", "résumé");
+
+			trace("// numericComparison = true");
+			collator.numericComparison = true;
+			compare(collator, "file9", "file10");
+			compare(collator, "file10", "file9");
+			compare(collator, "file2b", "file2a");
+			compare(collator, "2", "10");
+
+			var matching:Collator = new Collator("en-US", CollatorMode.MATCHING);
+			describe("new Collator(\"en-US\", CollatorMode.MATCHING)", matching);
+			compare(matching, "Résumé", "resume");
+			compare(matching, "Straße", "STRASSE");
+			compare(matching, "resume", "resumes");
+
+			var turkish:Collator = new Collator("tr-TR");
+			describe("new Collator(\"tr-TR\")", turkish);
+			compare(turkish, "ılık", "ip");
+			compare(turkish, "çay", "dam");
+
+			var unknown:Collator = new Collator("xx-YY");
+			describe("new Collator(\"xx-YY\")", unknown);
+			unknown.ignoreCase = true;
+			trace("lastOperationStatus after setting ignoreCase: " + unknown.lastOperationStatus);
+
+			var fallback:Collator = new Collator("fr");
+			describe("new Collator(\"fr\")", fallback);
+			compare(fallback, "cote", "côte");
+			trace("lastOperationStatus after compare: " + fallback.lastOperationStatus);
+
+			trace("// Errors");
+			try {
+				new Collator("en-US", "unknown");
+			} catch (e:ArgumentError) {
+				trace("new Collator(\"en-US\", \"unknown\"): ArgumentError " + e.errorID);
+			}
+			try {
+				collator.compare(null, "a");
+			} catch (e:TypeError) {
+				trace("compare(null, \"a\"): TypeError " + e.errorID);
+			}
+		}
+
+		private static function describe(name:String, collator:Collator):void {
+			trace("// " + name);
+			trace("requestedLocaleIDName: " + collator.requestedLocaleIDName + ", actualLocaleIDName: " + collator.actualLocaleIDName + ", lastOperationStatus: " + collator.lastOperationStatus);
+			trace("ignoreCase: " + collator.ignoreCase + ", ignoreDiacritics: " + collator.ignoreDiacritics + ", ignoreCharacterWidth: " + collator.ignoreCharacterWidth + ", ignoreKanaType: " + collator.ignoreKanaType + ", ignoreSymbols: " + collator.ignoreSymbols + ", numericComparison: " + collator.numericComparison);
+		}
+
+		private static function compare(collator:Collator, a:String, b:String):void {
+			var result:int = collator.compare(a, b);
+			var sign:String = result < 0 ? "negative" : (result > 0 ? "positive" : "zero");
+			trace("compare(\"" + a + "\", \"" + b + "\"): " + sign + ", equals: " + collator.equals(a, b));
+		}
+	}
+}
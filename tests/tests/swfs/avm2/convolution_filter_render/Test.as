@@ -0,0 +1,42 @@
+package {
+	import flash.display.Bitmap;
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.filters.ConvolutionFilter;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0x808080);
+			graphics.drawRect(0, 0, 200, 40);
+			graphics.endFill();
+
+			// No filter, for reference.
+			addBitmap(0, null);
+			// Identity.
+			addBitmap(1, new ConvolutionFilter(3, 3, [0, 0, 0, 0, 1, 0, 0, 0, 0]));
+			// Horizontal box blur, clamped to the edges.
+			addBitmap(2, new ConvolutionFilter(3, 1, [1, 1, 1], 3));
+			// Horizontal box blur, with green outside of the edges.
+			addBitmap(3, new ConvolutionFilter(3, 1, [1, 1, 1], 3, 0, true, false, 0x00FF00, 1));
+			// Bias.
+			addBitmap(4, new ConvolutionFilter(1, 1, [1], 1, 64));
+			// Divisor.
+			addBitmap(5, new ConvolutionFilter(1, 1, [2], 3));
+		}
+
+		private function addBitmap(index:int, filter:ConvolutionFilter):void {
+			// Red on the left half, blue on the right half.
+			var data:BitmapData = new BitmapData(16, 16, false, 0xFF0000);
+			data.fillRect(new Rectangle(8, 0, 8, 16), 0x0000FF);
+
+			var bitmap:Bitmap = new Bitmap(data);
+			bitmap.x = 10 + index * 30;
+			bitmap.y = 10;
+			if (filter) {
+				bitmap.filters = [filter];
+			}
+			addChild(bitmap);
+		}
+	}
+}
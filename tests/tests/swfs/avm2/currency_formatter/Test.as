@@ -0,0 +1,66 @@
+package {
+	import flash.display.Sprite;
+	import flash.globalization.CurrencyFormatter;
+	import flash.globalization.CurrencyParseResult;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var cf:CurrencyFormatter = new CurrencyFormatter("en-US");
+			describe(cf);
+			trace("format(1234.5): " + cf.format(1234.5));
+			trace("format(1234.5, true): " + cf.format(1234.5, true));
+			trace("format(-1234.5, true): " + cf.format(-1234.5, true));
+			trace("format(-0.001, true): " + cf.format(-0.001, true));
+
+			cf.negativeCurrencyFormat = 0;
+			trace("negativeCurrencyFormat = 0, format(-1234.5, true): " + cf.format(-1234.5, true));
+			cf.negativeCurrencyFormat = 14;
+			trace("negativeCurrencyFormat = 14, format(-1234.5, true): " + cf.format(-1234.5, true));
+			try {
+				cf.negativeCurrencyFormat = 16;
+			} catch (e:ArgumentError) {
+				trace("negativeCurrencyFormat = 16: " + e.errorID);
+			}
+			cf.positiveCurrencyFormat = 3;
+			trace("positiveCurrencyFormat = 3, format(1234.5, true): " + cf.format(1234.5, true));
+			try {
+				cf.positiveCurrencyFormat = 4;
+			} catch (e:ArgumentError) {
+				trace("positiveCurrencyFormat = 4: " + e.errorID);
+			}
+
+			trace("formattingWithCurrencySymbolIsSafe(\"USD\"): " + cf.formattingWithCurrencySymbolIsSafe("USD"));
+			trace("formattingWithCurrencySymbolIsSafe(\"EUR\"): " + cf.formattingWithCurrencySymbolIsSafe("EUR"));
+			cf.setCurrency("EUR", "€");
+			trace("setCurrency(\"EUR\", \"€\"), format(1234.5, true): " + cf.format(1234.5, true));
+			trace("formattingWithCurrencySymbolIsSafe(\"EUR\"): " + cf.formattingWithCurrencySymbolIsSafe("EUR"));
+
+			cf = new CurrencyFormatter("en-US");
+			showParse(cf, "-$1,234.50");
+			showParse(cf, "USD 12");
+			showParse(cf, "$");
+
+			cf = new CurrencyFormatter("de-DE");
+			describe(cf);
+			trace("format(1234.5): " + cf.format(1234.5));
+			trace("format(-1234.5, true): " + cf.format(-1234.5, true));
+			showParse(cf, "-1.234,50 €");
+
+			cf = new CurrencyFormatter("ja-JP");
+			describe(cf);
+			trace("format(1234.56, true): " + cf.format(1234.56, true));
+		}
+
+		private function describe(cf:CurrencyFormatter):void {
+			trace("// new CurrencyFormatter(\"" + cf.requestedLocaleIDName + "\")");
+			trace("actualLocaleIDName: " + cf.actualLocaleIDName + ", lastOperationStatus: " + cf.lastOperationStatus);
+			trace("currencyISOCode: " + cf.currencyISOCode + ", currencySymbol: " + cf.currencySymbol + ", fractionalDigits: " + cf.fractionalDigits + ", trailingZeros: " + cf.trailingZeros);
+			trace("positiveCurrencyFormat: " + cf.positiveCurrencyFormat + ", negativeCurrencyFormat: " + cf.negativeCurrencyFormat);
+		}
+
+		private function showParse(cf:CurrencyFormatter, text:String):void {
+			var result:CurrencyParseResult = cf.parse(text);
+			trace("parse(\"" + text + "\"): " + result.value + ", \"" + result.currencyString + "\", " + cf.lastOperationStatus);
+		}
+	}
+}
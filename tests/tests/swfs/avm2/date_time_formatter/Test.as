@@ -0,0 +1,68 @@
+package {
+	import flash.display.Sprite;
+	import flash.globalization.DateTimeFormatter;
+	import flash.globalization.DateTimeNameContext;
+	import flash.globalization.DateTimeNameStyle;
+	import flash.globalization.DateTimeStyle;
+
+	public class Test extends Sprite {
+		// Saturday, February 3rd 2001 at 04:05:06 UTC, which is 09:50:06 in the timezone of the tests (UTC+05:45).
+		private var date:Date = new Date(Date.UTC(2001, 1, 3, 4, 5, 6));
+
+		public function Test() {
+			var dtf:DateTimeFormatter = new DateTimeFormatter("en-US");
+			describe(dtf);
+			trace("format: " + dtf.format(date));
+
+			dtf.setDateTimeStyles(DateTimeStyle.SHORT, DateTimeStyle.NONE);
+			describe(dtf);
+			dtf.setDateTimeStyles(DateTimeStyle.MEDIUM, DateTimeStyle.SHORT);
+			describe(dtf);
+			try {
+				dtf.setDateTimeStyles(DateTimeStyle.CUSTOM, DateTimeStyle.LONG);
+			} catch (e:ArgumentError) {
+				trace("setDateTimeStyles(custom, long): " + e.errorID);
+			}
+
+			dtf.setDateTimePattern("yyyy-MM-dd'T'HH:mm:ss Z");
+			describe(dtf);
+			trace("format: " + dtf.format(date));
+			dtf.setDateTimePattern("EEEEE MMMMM G D w Q 'o''clock'");
+			describe(dtf);
+
+			trace("getFirstWeekday(): " + dtf.getFirstWeekday());
+			trace("getMonthNames(): " + dtf.getMonthNames());
+			trace("getMonthNames(longAbbreviation): " + dtf.getMonthNames(DateTimeNameStyle.LONG_ABBREVIATION));
+			trace("getWeekdayNames(): " + dtf.getWeekdayNames());
+			trace("getWeekdayNames(shortAbbreviation): " + dtf.getWeekdayNames(DateTimeNameStyle.SHORT_ABBREVIATION));
+			try {
+				dtf.getMonthNames("bogus");
+			} catch (e:ArgumentError) {
+				trace("getMonthNames(bogus): " + e.errorID);
+			}
+
+			dtf = new DateTimeFormatter("de-DE");
+			describe(dtf);
+			trace("getFirstWeekday(): " + dtf.getFirstWeekday());
+
+			dtf = new DateTimeFormatter("ru-RU", DateTimeStyle.LONG, DateTimeStyle.NONE);
+			describe(dtf);
+			trace("getMonthNames(full, format)[1]: " + dtf.getMonthNames(DateTimeNameStyle.FULL, DateTimeNameContext.FORMAT)[1]);
+			trace("getMonthNames(full, standalone)[1]: " + dtf.getMonthNames(DateTimeNameStyle.FULL, DateTimeNameContext.STANDALONE)[1]);
+
+			dtf = new DateTimeFormatter("ja-JP");
+			describe(dtf);
+			trace("getMonthNames(shortAbbreviation): " + dtf.getMonthNames(DateTimeNameStyle.SHORT_ABBREVIATION));
+
+			describe(new DateTimeFormatter("xx-YY", DateTimeStyle.SHORT, DateTimeStyle.SHORT));
+		}
+
+		private function describe(dtf:DateTimeFormatter):void {
+			trace("// " + dtf.requestedLocaleIDName);
+			trace("actualLocaleIDName: " + dtf.actualLocaleIDName + ", lastOperationStatus: " + dtf.lastOperationStatus);
+			trace("getDateStyle(): " + dtf.getDateStyle() + ", getTimeStyle(): " + dtf.getTimeStyle());
+			trace("getDateTimePattern(): " + dtf.getDateTimePattern());
+			trace("formatUTC: " + dtf.formatUTC(date));
+		}
+	}
+}
@@ -0,0 +1,25 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.Event;
+
+	public class Test extends Sprite {
+		public var frames:int = 0;
+
+		public function Test() {
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		public function onEnterFrame(event:Event):void {
+			var total:int = frames;
+			total = add(total, 1);
+			frames = total;
+			if (frames == 6) {
+				trace("frames: " + frames);
+			}
+		}
+
+		public function add(value:int, amount:int):int {
+			return value + amount;
+		}
+	}
+}
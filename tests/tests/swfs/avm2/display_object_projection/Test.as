@@ -0,0 +1,86 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.Sprite;
+	import flash.events.MouseEvent;
+	import flash.geom.PerspectiveProjection;
+	import flash.geom.Point;
+	import flash.geom.Vector3D;
+
+	public class Test extends Sprite {
+		public function Test() {
+			// A focal length of 100 on a 200 pixels wide stage.
+			var projection:PerspectiveProjection = new PerspectiveProjection();
+			projection.fieldOfView = 90;
+			projection.projectionCenter = new Point(100, 100);
+			transform.perspectiveProjection = projection;
+
+			// Twice as far as the focal length: drawn at half size around the projection center.
+			var far:Sprite = square("far", 0xFF0000, 50);
+			far.x = 100;
+			far.y = 100;
+			far.z = 100;
+			addChild(far);
+
+			// Half as far as the focal length: drawn at twice the size.
+			var near:Sprite = square("near", 0x00FF00, 10);
+			near.x = 120;
+			near.y = 70;
+			near.z = -50;
+			addChild(near);
+
+			trace("// far.local3DToGlobal");
+			tracePoint(far.local3DToGlobal(new Vector3D(50, 50, 0)));
+			tracePoint(far.local3DToGlobal(new Vector3D(-50, -50, 0)));
+			tracePoint(far.local3DToGlobal(new Vector3D(50, 0, -100)));
+
+			trace("// near.local3DToGlobal");
+			tracePoint(near.local3DToGlobal(new Vector3D(10, 10, 0)));
+			tracePoint(near.local3DToGlobal(new Vector3D(0, 0, 50)));
+
+			trace("// far.globalToLocal3D");
+			traceVector(far.globalToLocal3D(new Point(75, 125)));
+			traceVector(far.globalToLocal3D(new Point(100, 100)));
+
+			trace("// near.globalToLocal3D");
+			traceVector(near.globalToLocal3D(new Point(130, 30)));
+
+			trace("// localToGlobal / globalToLocal");
+			tracePoint(far.localToGlobal(new Point(-50, -50)));
+			tracePoint(far.globalToLocal(new Point(125, 75)));
+
+			trace("// hitTestPoint");
+			trace("far (80, 80, true)", far.hitTestPoint(80, 80, true));
+			trace("far (60, 60, true)", far.hitTestPoint(60, 60, true));
+			trace("far (60, 60, false)", far.hitTestPoint(60, 60, false));
+			trace("near (150, 50, true)", near.hitTestPoint(150, 50, true));
+			trace("near (110, 50, true)", near.hitTestPoint(110, 50, true));
+
+			trace("// BitmapData.draw");
+			var bitmapData:BitmapData = new BitmapData(200, 200, false, 0xFFFFFF);
+			bitmapData.draw(this);
+			for each (var position:Array in [[100, 100], [60, 60], [115, 65], [140, 40]]) {
+				trace(position, bitmapData.getPixel(position[0], position[1]).toString(16));
+			}
+		}
+
+		private function square(name:String, color:uint, half:Number):Sprite {
+			var sprite:Sprite = new Sprite();
+			sprite.name = name;
+			sprite.graphics.beginFill(color);
+			sprite.graphics.drawRect(-half, -half, half * 2, half * 2);
+			sprite.graphics.endFill();
+			sprite.addEventListener(MouseEvent.MOUSE_DOWN, function(event:MouseEvent):void {
+				trace("mouseDown", sprite.name, Math.round(event.localX), Math.round(event.localY));
+			});
+			return sprite;
+		}
+
+		private function tracePoint(point:Point):void {
+			trace(Math.round(point.x), Math.round(point.y));
+		}
+
+		private function traceVector(vector:Vector3D):void {
+			trace(Math.round(vector.x), Math.round(vector.y), Math.round(vector.z));
+		}
+	}
+}
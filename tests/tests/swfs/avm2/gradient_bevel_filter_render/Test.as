@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.filters.BitmapFilter;
+	import flash.filters.BitmapFilterType;
+	import flash.filters.GradientBevelFilter;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0x808080);
+			graphics.drawRect(0, 0, 260, 60);
+			graphics.endFill();
+
+			addSquare(0, bevel(BitmapFilterType.INNER, false));
+			addSquare(1, bevel(BitmapFilterType.INNER, true));
+			addSquare(2, bevel(BitmapFilterType.OUTER, false));
+			addSquare(3, bevel(BitmapFilterType.FULL, false));
+		}
+
+		// An unblurred bevel lit from the left, with a white highlight and a
+		// black shadow, and a transparent middle of the gradient.
+		private function bevel(type:String, knockout:Boolean):BitmapFilter {
+			return new GradientBevelFilter(4, 0, [0xFFFFFF, 0x000000, 0x000000], [1, 0, 1], [0, 128, 255], 0, 0, 1, 1, type, knockout);
+		}
+
+		private function addSquare(index:int, filter:BitmapFilter):void {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, 20, 20);
+			shape.graphics.endFill();
+			shape.x = 20 + index * 60;
+			shape.y = 20;
+			shape.filters = [filter];
+			addChild(shape);
+		}
+	}
+}
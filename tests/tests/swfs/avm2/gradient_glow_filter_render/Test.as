@@ -0,0 +1,38 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.filters.BitmapFilter;
+	import flash.filters.BitmapFilterType;
+	import flash.filters.GradientGlowFilter;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0x808080);
+			graphics.drawRect(0, 0, 320, 60);
+			graphics.endFill();
+
+			addSquare(0, glow(BitmapFilterType.OUTER, false));
+			addSquare(1, glow(BitmapFilterType.OUTER, true));
+			addSquare(2, glow(BitmapFilterType.INNER, false));
+			addSquare(3, glow(BitmapFilterType.INNER, true));
+			addSquare(4, glow(BitmapFilterType.FULL, false));
+		}
+
+		// An unblurred glow, moved 6 pixels to the right, that is green
+		// where the source is opaque.
+		private function glow(type:String, knockout:Boolean):BitmapFilter {
+			return new GradientGlowFilter(6, 0, [0x0000FF, 0x00FF00], [0, 1], [0, 255], 0, 0, 1, 1, type, knockout);
+		}
+
+		private function addSquare(index:int, filter:BitmapFilter):void {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, 20, 20);
+			shape.graphics.endFill();
+			shape.x = 20 + index * 60;
+			shape.y = 20;
+			shape.filters = [filter];
+			addChild(shape);
+		}
+	}
+}
@@ -0,0 +1,92 @@
+package {
+	import flash.display.GraphicsEndFill;
+	import flash.display.GraphicsGradientFill;
+	import flash.display.GraphicsPath;
+	import flash.display.GraphicsSolidFill;
+	import flash.display.IGraphicsData;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var parentSprite:Sprite = new Sprite();
+			parentSprite.graphics.beginFill(0xFF0000, 0.5);
+			parentSprite.graphics.drawRect(10, 20, 30, 40);
+			parentSprite.graphics.endFill();
+
+			var matrix:Matrix = new Matrix();
+			matrix.createGradientBox(100, 40, 0, 10, 20);
+			parentSprite.graphics.beginGradientFill("linear", [0xFF0000, 0x0000FF], [1, 0.5], [0, 255], matrix);
+			parentSprite.graphics.drawRect(10, 20, 100, 40);
+			parentSprite.graphics.endFill();
+
+			// An empty fill isn't read back.
+			parentSprite.graphics.beginFill(0x00FF00);
+			parentSprite.graphics.endFill();
+
+			var child:Shape = new Shape();
+			child.x = 100;
+			child.y = 50;
+			child.scaleX = 2;
+			child.graphics.beginFill(0x0000FF);
+			child.graphics.moveTo(0, 0);
+			child.graphics.lineTo(20, 0);
+			child.graphics.lineTo(0, 20);
+			child.graphics.lineTo(0, 0);
+			child.graphics.endFill();
+			parentSprite.addChild(child);
+
+			dump("readGraphicsData(false)", parentSprite.graphics.readGraphicsData(false));
+			// The graphics of children are read in their own coordinates.
+			var recursive:Vector.<IGraphicsData> = parentSprite.graphics.readGraphicsData(true);
+			dump("readGraphicsData(true)", recursive);
+
+			var copy:Shape = new Shape();
+			copy.graphics.drawGraphicsData(recursive);
+			var copied:Vector.<IGraphicsData> = copy.graphics.readGraphicsData(false);
+			dump("copy.readGraphicsData(false)", copied);
+			trace("round trip is identical:", describe(copied) == describe(recursive));
+			trace("copy bounds:", copy.getBounds(copy));
+		}
+
+		private function dump(label:String, data:Vector.<IGraphicsData>):void {
+			trace("// " + label + ": " + data.length);
+			trace(describe(data));
+		}
+
+		private function describe(data:Vector.<IGraphicsData>):String {
+			var lines:Array = [];
+			for each (var item:IGraphicsData in data) {
+				if (item is GraphicsSolidFill) {
+					var solid:GraphicsSolidFill = GraphicsSolidFill(item);
+					lines.push(["GraphicsSolidFill", solid.color.toString(16), round(solid.alpha)].join(" "));
+				} else if (item is GraphicsGradientFill) {
+					var gradient:GraphicsGradientFill = GraphicsGradientFill(item);
+					lines.push([
+						"GraphicsGradientFill", gradient.type, gradient.colors, gradient.alphas.map(roundValue),
+						gradient.ratios, round(gradient.matrix.a), round(gradient.matrix.d),
+						gradient.matrix.tx, gradient.matrix.ty, gradient.spreadMethod
+					].join(" "));
+				} else if (item is GraphicsPath) {
+					var path:GraphicsPath = GraphicsPath(item);
+					lines.push(["GraphicsPath", path.commands, path.data, path.winding].join(" "));
+				} else if (item is GraphicsEndFill) {
+					lines.push("GraphicsEndFill");
+				} else {
+					lines.push(getQualifiedClassName(item));
+				}
+			}
+			return lines.join("\n");
+		}
+
+		private function roundValue(value:Number, index:int, array:Array):Number {
+			return round(value);
+		}
+
+		private function round(value:Number):Number {
+			return Math.round(value * 10000) / 10000;
+		}
+	}
+}
@@ -0,0 +1,52 @@
+package {
+	import flash.display.Sprite;
+	import flash.globalization.LocaleID;
+
+	public class Test extends Sprite {
+		public function Test() {
+			trace("LocaleID.DEFAULT: " + LocaleID.DEFAULT);
+			describe("en-US");
+			describe("zh-hant_tw@collation=stroke");
+			describe("en-US-POSIX");
+			describe("ar-EG");
+
+			trace("// determinePreferredLocales");
+			preferred(new <String>["fr-CA", "en-US"], new <String>["en-GB", "fr-FR", "en-US", "de-DE"]);
+			preferred(new <String>["zh-Hant-TW"], new <String>["zh-CN", "zh-TW", "zh-Hant-TW"]);
+			preferred(new <String>["en-US", "en-GB"], new <String>["en-GB", "en-US"]);
+			preferred(new <String>["ja-JP"], new <String>["en-US"]);
+			preferred(new <String>[], new <String>["en-US"]);
+
+			trace("// Errors");
+			try {
+				LocaleID.determinePreferredLocales(null, new <String>["en-US"]);
+			} catch (e:TypeError) {
+				trace("determinePreferredLocales(null, have): TypeError " + e.errorID);
+			}
+			try {
+				LocaleID.determinePreferredLocales(new <String>["en-US"], null);
+			} catch (e:TypeError) {
+				trace("determinePreferredLocales(want, null): TypeError " + e.errorID);
+			}
+			try {
+				new LocaleID(null);
+			} catch (e:TypeError) {
+				trace("new LocaleID(null): TypeError " + e.errorID);
+			}
+		}
+
+		private static function describe(name:String):void {
+			var id:LocaleID = new LocaleID(name);
+			trace("// new LocaleID(\"" + name + "\")");
+			trace("name: " + id.name + ", lastOperationStatus: " + id.lastOperationStatus);
+			trace("getLanguage: " + id.getLanguage() + ", lastOperationStatus: " + id.lastOperationStatus);
+			trace("getScript: " + id.getScript() + ", getRegion: " + id.getRegion() + ", getVariant: " + id.getVariant());
+			trace("getKeysAndValues().collation: " + id.getKeysAndValues().collation + ", isRightToLeft: " + id.isRightToLeft());
+		}
+
+		private static function preferred(want:Vector.<String>, have:Vector.<String>):void {
+			var result:Vector.<String> = LocaleID.determinePreferredLocales(want, have);
+			trace("want [" + want.join(", ") + "], have [" + have.join(", ") + "]: [" + result.join(", ") + "] (" + result.length + ")");
+		}
+	}
+}
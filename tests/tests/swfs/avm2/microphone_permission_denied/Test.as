@@ -0,0 +1,27 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.SampleDataEvent;
+	import flash.events.StatusEvent;
+	import flash.media.Microphone;
+
+	public class Test extends Sprite {
+		private var mic:Microphone;
+
+		public function Test() {
+			mic = Microphone.getMicrophone();
+			trace("muted: " + mic.muted);
+
+			mic.addEventListener(StatusEvent.STATUS, onStatus);
+			mic.addEventListener(SampleDataEvent.SAMPLE_DATA, onSampleData);
+		}
+
+		private function onStatus(e:StatusEvent):void {
+			trace("status: " + e.code + " (" + e.level + "), muted: " + mic.muted);
+		}
+
+		private function onSampleData(e:SampleDataEvent):void {
+			trace("sampleData: samples=" + e.data.length / 4);
+			mic.removeEventListener(SampleDataEvent.SAMPLE_DATA, onSampleData);
+		}
+	}
+}
@@ -0,0 +1,40 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.ActivityEvent;
+	import flash.events.SampleDataEvent;
+	import flash.media.Microphone;
+
+	// The test microphone produces a 440 Hz sine wave with an amplitude of 0.5.
+	public class Test extends Sprite {
+		private var mic:Microphone;
+		private var events:int = 0;
+
+		public function Test() {
+			mic = Microphone.getMicrophone();
+			trace("name: " + mic.name);
+			trace("rate: " + mic.rate);
+			trace("activityLevel: " + mic.activityLevel);
+
+			mic.addEventListener(ActivityEvent.ACTIVITY, onActivity);
+			mic.addEventListener(SampleDataEvent.SAMPLE_DATA, onSampleData);
+		}
+
+		private function onActivity(e:ActivityEvent):void {
+			trace("activity: " + e.activating + ", activityLevel: " + mic.activityLevel);
+		}
+
+		private function onSampleData(e:SampleDataEvent):void {
+			var samples:Array = [];
+			for (var i:int = 0; i < 4; i++) {
+				samples.push(Math.round(e.data.readFloat() * 1000) / 1000);
+			}
+			trace("sampleData: position=" + e.position + " samples=" + e.data.length / 4 + " first=" + samples);
+
+			events++;
+			if (events == 3) {
+				// Capture stops with the last listener.
+				mic.removeEventListener(SampleDataEvent.SAMPLE_DATA, onSampleData);
+			}
+		}
+	}
+}
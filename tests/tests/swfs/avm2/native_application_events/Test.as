@@ -0,0 +1,62 @@
+package {
+	import flash.desktop.NativeApplication;
+	import flash.display.NativeWindow;
+	import flash.display.NativeWindowInitOptions;
+	import flash.display.Sprite;
+	import flash.events.Event;
+
+	public class Test extends Sprite {
+		private var app:NativeApplication = NativeApplication.nativeApplication;
+		private var preventedExit:Boolean = false;
+
+		public function Test() {
+			app.addEventListener(Event.ACTIVATE, onEvent);
+			app.addEventListener(Event.DEACTIVATE, onEvent);
+			app.addEventListener(Event.EXITING, onExiting);
+
+			trace("autoExit: " + app.autoExit);
+			app.autoExit = false;
+			trace("autoExit: " + app.autoExit);
+
+			listen(stage.nativeWindow, "main");
+			// Moving the focus to another window of the application doesn't deactivate it.
+			openWindow("second").activate();
+		}
+
+		private function openWindow(name:String):NativeWindow {
+			var window:NativeWindow = new NativeWindow(new NativeWindowInitOptions());
+			listen(window, name);
+			return window;
+		}
+
+		private function listen(window:NativeWindow, name:String):void {
+			window.addEventListener(Event.CLOSE, function(e:Event):void {
+				trace(name + " " + e.type + ", opened windows: " + app.openedWindows.length);
+				if (name == "second") {
+					trace("// autoExit = true, open third window");
+					app.autoExit = true;
+					openWindow("third");
+				}
+			});
+		}
+
+		private function onEvent(e:Event):void {
+			trace("application " + e.type);
+		}
+
+		private function onExiting(e:Event):void {
+			trace("application " + e.type + ", cancelable: " + e.cancelable);
+			if (!preventedExit) {
+				preventedExit = true;
+				trace("// prevent exiting, open fourth window");
+				e.preventDefault();
+				openWindow("fourth");
+				return;
+			}
+			// The test ends once the application exits, so this never runs.
+			stage.addEventListener(Event.ENTER_FRAME, function(e:Event):void {
+				trace("still running");
+			});
+		}
+	}
+}
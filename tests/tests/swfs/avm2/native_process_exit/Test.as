@@ -0,0 +1,52 @@
+package {
+	import flash.desktop.NativeProcess;
+	import flash.desktop.NativeProcessStartupInfo;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.events.NativeProcessExitEvent;
+	import flash.filesystem.File;
+
+	// `/bin/wait` exits with code 0 once its input is closed, and
+	// `/bin/forbidden` isn't allowed to run.
+	public class Test extends Sprite {
+		private var process:NativeProcess = new NativeProcess();
+		private var step:int = 0;
+
+		public function Test() {
+			process.addEventListener(Event.STANDARD_OUTPUT_CLOSE, onEvent);
+			process.addEventListener(Event.STANDARD_ERROR_CLOSE, onEvent);
+			process.addEventListener(Event.STANDARD_INPUT_CLOSE, onEvent);
+			process.addEventListener(NativeProcessExitEvent.EXIT, onExit);
+
+			trace("// exit()");
+			start("/bin/wait");
+			process.exit();
+		}
+
+		private function start(path:String):void {
+			var info:NativeProcessStartupInfo = new NativeProcessStartupInfo();
+			info.executable = new File(path);
+			process.start(info);
+			trace("running: " + process.running);
+		}
+
+		private function onEvent(e:Event):void {
+			trace(e.type);
+		}
+
+		private function onExit(e:NativeProcessExitEvent):void {
+			trace(e.type + ": exitCode=" + e.exitCode);
+			trace("running: " + process.running);
+
+			step++;
+			if (step == 1) {
+				trace("// exit(true)");
+				start("/bin/wait");
+				process.exit(true);
+			} else if (step == 2) {
+				trace("// refused");
+				start("/bin/forbidden");
+			}
+		}
+	}
+}
@@ -0,0 +1,56 @@
+package {
+	import flash.desktop.NativeProcess;
+	import flash.desktop.NativeProcessStartupInfo;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.events.NativeProcessExitEvent;
+	import flash.events.ProgressEvent;
+	import flash.filesystem.File;
+
+	// `/bin/greet` writes "hello" and "oops" to its standard output and error,
+	// echoes its input back, and exits with code 3 once its input is closed.
+	public class Test extends Sprite {
+		private var process:NativeProcess = new NativeProcess();
+
+		public function Test() {
+			trace("isSupported: " + NativeProcess.isSupported);
+
+			process.addEventListener(ProgressEvent.STANDARD_OUTPUT_DATA, onOutputData);
+			process.addEventListener(ProgressEvent.STANDARD_ERROR_DATA, onErrorData);
+			process.addEventListener(Event.STANDARD_OUTPUT_CLOSE, onEvent);
+			process.addEventListener(Event.STANDARD_ERROR_CLOSE, onEvent);
+			process.addEventListener(Event.STANDARD_INPUT_CLOSE, onEvent);
+			process.addEventListener(NativeProcessExitEvent.EXIT, onExit);
+
+			var info:NativeProcessStartupInfo = new NativeProcessStartupInfo();
+			info.executable = new File("/bin/greet");
+			process.start(info);
+			trace("running: " + process.running);
+		}
+
+		private function onOutputData(e:ProgressEvent):void {
+			trace(e.type + ": bytesLoaded=" + e.bytesLoaded + " bytesAvailable=" + process.standardOutput.bytesAvailable);
+			var output:String = process.standardOutput.readUTFBytes(process.standardOutput.bytesAvailable);
+			trace("  " + output);
+
+			if (output == "hello") {
+				process.standardInput.writeUTFBytes("ping");
+				process.closeInput();
+			}
+		}
+
+		private function onErrorData(e:ProgressEvent):void {
+			trace(e.type + ": bytesLoaded=" + e.bytesLoaded);
+			trace("  " + process.standardError.readUTFBytes(process.standardError.bytesAvailable));
+		}
+
+		private function onEvent(e:Event):void {
+			trace(e.type);
+		}
+
+		private function onExit(e:NativeProcessExitEvent):void {
+			trace(e.type + ": exitCode=" + e.exitCode);
+			trace("running: " + process.running);
+		}
+	}
+}
@@ -0,0 +1,88 @@
+package {
+	import flash.display.NativeWindow;
+	import flash.display.NativeWindowInitOptions;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.events.KeyboardEvent;
+	import flash.events.MouseEvent;
+	import flash.events.NativeWindowBoundsEvent;
+	import flash.events.NativeWindowDisplayStateEvent;
+	import flash.geom.Point;
+	import flash.geom.Rectangle;
+
+	public class Test extends Sprite {
+		private var window:NativeWindow;
+		private var preventedClose:Boolean = false;
+
+		public function Test() {
+			listen(stage.nativeWindow, "main");
+			stage.addEventListener(MouseEvent.MOUSE_DOWN, function(e:MouseEvent):void {
+				trace("main stage " + e.type + " at " + e.stageX + ", " + e.stageY);
+			});
+			stage.addEventListener(KeyboardEvent.KEY_DOWN, function(e:KeyboardEvent):void {
+				trace("main stage " + e.type + ": " + e.keyCode);
+			});
+
+			window = new NativeWindow(new NativeWindowInitOptions());
+			listen(window, "second");
+			trace("second bounds: " + window.bounds);
+			window.bounds = new Rectangle(10, 20, 200, 100);
+			// Applied by the window manager, which reports the new size later.
+			window.minSize = new Point(250, 150);
+
+			var button:Sprite = new Sprite();
+			button.graphics.beginFill(0xFF0000);
+			button.graphics.drawRect(0, 0, 50, 50);
+			button.graphics.endFill();
+			button.addEventListener(MouseEvent.MOUSE_OVER, onButtonMouse);
+			button.addEventListener(MouseEvent.MOUSE_OUT, onButtonMouse);
+			button.addEventListener(MouseEvent.MOUSE_DOWN, onButtonMouse);
+			button.addEventListener(MouseEvent.CLICK, onButtonMouse);
+			window.stage.addChild(button);
+			window.stage.addEventListener(KeyboardEvent.KEY_DOWN, function(e:KeyboardEvent):void {
+				trace("second stage " + e.type + ": " + e.keyCode);
+			});
+
+			trace("// activate");
+			window.activate();
+			trace("second active: " + window.active);
+		}
+
+		private function listen(window:NativeWindow, name:String):void {
+			var onEvent:Function = function(e:Event):void {
+				trace(name + " " + e.type);
+			};
+			var onBounds:Function = function(e:NativeWindowBoundsEvent):void {
+				trace(name + " " + e.type + ": " + e.beforeBounds + " -> " + e.afterBounds);
+			};
+			var onDisplayState:Function = function(e:NativeWindowDisplayStateEvent):void {
+				trace(name + " " + e.type + ": " + e.beforeDisplayState + " -> " + e.afterDisplayState);
+				if (e.type == NativeWindowDisplayStateEvent.DISPLAY_STATE_CHANGE && e.afterDisplayState == "maximized") {
+					trace("// restore");
+					window.restore();
+				}
+			};
+			window.addEventListener(Event.ACTIVATE, onEvent);
+			window.addEventListener(Event.DEACTIVATE, onEvent);
+			window.addEventListener(NativeWindowBoundsEvent.MOVE, onBounds);
+			window.addEventListener(NativeWindowBoundsEvent.RESIZE, onBounds);
+			window.addEventListener(NativeWindowDisplayStateEvent.DISPLAY_STATE_CHANGING, onDisplayState);
+			window.addEventListener(NativeWindowDisplayStateEvent.DISPLAY_STATE_CHANGE, onDisplayState);
+			window.addEventListener(Event.CLOSING, function(e:Event):void {
+				trace(name + " " + e.type);
+				if (name == "main" && !preventedClose) {
+					preventedClose = true;
+					trace("// prevent closing");
+					e.preventDefault();
+				}
+			});
+			window.addEventListener(Event.CLOSE, function(e:Event):void {
+				trace(name + " " + e.type + ", closed: " + window.closed);
+			});
+		}
+
+		private function onButtonMouse(e:MouseEvent):void {
+			trace("button " + e.type + " at " + e.stageX + ", " + e.stageY);
+		}
+	}
+}
@@ -0,0 +1,34 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.NetStatusEvent;
+	import flash.net.NetConnection;
+	import flash.net.Responder;
+
+	public class Test extends Sprite {
+		private var connection:NetConnection = new NetConnection();
+
+		public function Test() {
+			connection.addEventListener(NetStatusEvent.NET_STATUS, onStatus);
+			connection.connect("rtmp://localhost/app");
+			trace("connected: " + connection.connected);
+		}
+
+		private function onStatus(event:NetStatusEvent):void {
+			trace("status: " + event.info.code + " (" + event.info.level + ")");
+			trace("connected: " + connection.connected);
+			if (event.info.code == "NetConnection.Connect.Success") {
+				connection.call("echo", new Responder(onResult, onError), "hello");
+			}
+		}
+
+		private function onResult(result:Object):void {
+			trace("result: " + result);
+			connection.close();
+			trace("connected: " + connection.connected);
+		}
+
+		private function onError(info:Object):void {
+			trace("error: " + info);
+		}
+	}
+}
@@ -0,0 +1,94 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.NetStatusEvent;
+	import flash.net.NetConnection;
+	import flash.net.NetStream;
+	import flash.net.NetStreamAppendBytesAction;
+	import flash.net.URLLoader;
+	import flash.net.URLLoaderDataFormat;
+	import flash.net.URLRequest;
+	import flash.utils.ByteArray;
+
+	// `cues.flv` is made of an `onMetaData` tag followed by `onCue` tags
+	// "a" to "e", at 0, 0.5, 1, 1.5 and 2 seconds.
+	public class Test extends MovieClip {
+		private var ns:NetStream;
+		private var header:ByteArray;
+		private var tags:Array;
+		private var seeked:Boolean = false;
+
+		public function Test() {
+			var nc:NetConnection = new NetConnection();
+			nc.connect(null);
+
+			ns = new NetStream(nc);
+			ns.client = {
+				onMetaData: function(info:Object):void {
+					trace("metadata: duration=" + info.duration);
+				},
+				onCue: function(name:String):void {
+					trace("cue: " + name);
+				}
+			};
+			ns.addEventListener(NetStatusEvent.NET_STATUS, onStatus);
+
+			var loader:URLLoader = new URLLoader();
+			loader.dataFormat = URLLoaderDataFormat.BINARY;
+			loader.addEventListener(Event.COMPLETE, function(e:Event):void {
+				split(loader.data);
+				start();
+			});
+			loader.load(new URLRequest("cues.flv"));
+		}
+
+		// Splits an FLV into its header and its tags, each followed by the
+		// size of the previous tag.
+		private function split(data:ByteArray):void {
+			header = new ByteArray();
+			data.readBytes(header, 0, 13);
+
+			tags = [];
+			while (data.bytesAvailable > 0) {
+				var size:uint = (data[data.position + 1] << 16) | (data[data.position + 2] << 8) | data[data.position + 3];
+				var tag:ByteArray = new ByteArray();
+				data.readBytes(tag, 0, 11 + size + 4);
+				tags.push(tag);
+			}
+		}
+
+		private function start():void {
+			trace("bufferTime: " + ns.bufferTime);
+			ns.bufferTime = 0.5;
+			trace("bufferTime: " + ns.bufferTime);
+
+			ns.play(null);
+			ns.appendBytesAction(NetStreamAppendBytesAction.RESET_BEGIN);
+			ns.appendBytes(header);
+			ns.appendBytes(tags[0]);
+			ns.appendBytes(tags[1]);
+			trace("bufferLength: " + ns.bufferLength);
+			ns.appendBytes(tags[2]);
+			trace("bufferLength: " + ns.bufferLength);
+			ns.appendBytes(tags[3]);
+			trace("bufferLength: " + ns.bufferLength);
+		}
+
+		private function onStatus(e:NetStatusEvent):void {
+			trace("status: " + e.info.code);
+
+			if (e.info.code == "NetStream.Buffer.Empty" && !seeked) {
+				// Skip to the last two cues once the first ones are played.
+				seeked = true;
+				ns.seek(1.5);
+			} else if (e.info.code == "NetStream.Seek.Notify") {
+				trace("time: " + ns.time);
+				ns.appendBytesAction(NetStreamAppendBytesAction.RESET_SEEK);
+				ns.appendBytes(tags[4]);
+				trace("bufferLength: " + ns.bufferLength);
+				ns.appendBytes(tags[5]);
+				ns.appendBytesAction(NetStreamAppendBytesAction.END_SEQUENCE);
+			}
+		}
+	}
+}
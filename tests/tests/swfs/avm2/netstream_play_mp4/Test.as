@@ -0,0 +1,48 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.NetStatusEvent;
+	import flash.net.NetConnection;
+	import flash.net.NetStream;
+
+	// `audio.mp4` is made of an MP3 track of 40 frames, whose `mdat` box
+	// comes before its `moov` box.
+	public class Test extends MovieClip {
+		public function Test() {
+			var nc:NetConnection = new NetConnection();
+			nc.connect(null);
+
+			var ns:NetStream = new NetStream(nc);
+			ns.client = {
+				onMetaData: onMetaData
+			};
+			ns.addEventListener(NetStatusEvent.NET_STATUS, onStatus);
+			ns.play("audio.mp4");
+		}
+
+		private function onMetaData(info:Object):void {
+			trace("onMetaData");
+
+			var keys:Array = [];
+			for (var key:String in info) {
+				keys.push(key);
+			}
+			keys.sort();
+
+			for each (key in keys) {
+				if (key != "trackinfo") {
+					trace("  " + key + ": " + info[key]);
+				}
+			}
+
+			for each (var track:Object in info.trackinfo) {
+				trace("  track: length=" + track.length + " timescale=" + track.timescale +
+					" language=" + track.language +
+					" sampletype=" + track.sampledescription[0].sampletype);
+			}
+		}
+
+		private function onStatus(e:NetStatusEvent):void {
+			trace("status: " + e.info.code);
+		}
+	}
+}
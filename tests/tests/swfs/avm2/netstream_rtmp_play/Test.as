@@ -0,0 +1,54 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.events.NetStatusEvent;
+	import flash.net.NetConnection;
+	import flash.net.NetStream;
+
+	public class Test extends Sprite {
+		private var connection:NetConnection = new NetConnection();
+		private var stream:NetStream;
+		private var statuses:Array = [];
+		private var metadata:Array = [];
+		private var frames:int = 0;
+
+		public function Test() {
+			connection.addEventListener(NetStatusEvent.NET_STATUS, onConnectionStatus);
+			connection.connect("rtmp://localhost/app");
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onConnectionStatus(event:NetStatusEvent):void {
+			trace("connection: " + event.info.code);
+			if (event.info.code != "NetConnection.Connect.Success") {
+				return;
+			}
+
+			stream = new NetStream(connection);
+			stream.client = {
+				onMetaData: function(info:Object):void {
+					metadata.push("duration=" + info.duration + " answer=" + info.answer);
+				}
+			};
+			stream.addEventListener(NetStatusEvent.NET_STATUS, onStreamStatus);
+			stream.play("test");
+		}
+
+		private function onStreamStatus(event:NetStatusEvent):void {
+			// Buffer events depend on when ticks happen, so only the play
+			// events are compared.
+			if (event.info.code.indexOf("NetStream.Play.") == 0) {
+				statuses.push(event.info.code);
+			}
+		}
+
+		private function onEnterFrame(event:Event):void {
+			frames++;
+			if (frames == 10) {
+				trace("statuses: " + statuses.join(", "));
+				trace("metadata: " + metadata.join(", "));
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+	}
+}
@@ -0,0 +1,77 @@
+package {
+	import flash.display.Sprite;
+	import flash.globalization.LocaleID;
+	import flash.globalization.NumberFormatter;
+	import flash.globalization.NumberParseResult;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var nf:NumberFormatter = new NumberFormatter("en-US");
+			describe(nf);
+			trace("formatNumber(1234567.891): " + nf.formatNumber(1234567.891));
+			trace("formatNumber(-1234.5): " + nf.formatNumber(-1234.5));
+			trace("formatNumber(-0.001): " + nf.formatNumber(-0.001));
+			trace("formatInt(-42): " + nf.formatInt(-42));
+			trace("formatUint(4000000000): " + nf.formatUint(4000000000));
+			trace("formatNumber(0.5): " + nf.formatNumber(0.5));
+
+			nf.leadingZero = false;
+			trace("leadingZero = false, formatNumber(0.5): " + nf.formatNumber(0.5));
+			nf.trailingZeros = true;
+			trace("trailingZeros = true, formatNumber(3): " + nf.formatNumber(3));
+			nf.fractionalDigits = 0;
+			trace("fractionalDigits = 0, formatNumber(2.6): " + nf.formatNumber(2.6));
+			nf.fractionalDigits = 2;
+
+			nf.negativeNumberFormat = 0;
+			trace("negativeNumberFormat = 0, formatNumber(-3): " + nf.formatNumber(-3));
+			nf.negativeNumberFormat = 4;
+			trace("negativeNumberFormat = 4, formatNumber(-3): " + nf.formatNumber(-3));
+			try {
+				nf.negativeNumberFormat = 5;
+			} catch (e:ArgumentError) {
+				trace("negativeNumberFormat = 5: " + e.errorID);
+			}
+
+			nf.groupingPattern = "3;2;*";
+			trace("groupingPattern = 3;2;*: " + nf.lastOperationStatus + ", formatInt(123456789): " + nf.formatInt(123456789));
+			nf.groupingPattern = "*;3";
+			trace("groupingPattern = *;3: " + nf.lastOperationStatus + ", groupingPattern: " + nf.groupingPattern);
+			nf.useGrouping = false;
+			trace("useGrouping = false, formatInt(123456789): " + nf.formatInt(123456789));
+
+			nf = new NumberFormatter("en-US");
+			trace("parseNumber(\" 1,234.5 \"): " + nf.parseNumber(" 1,234.5 ") + ", " + nf.lastOperationStatus);
+			trace("parseNumber(\"(12)\"): " + nf.parseNumber("(12)") + ", " + nf.lastOperationStatus);
+			trace("parseNumber(\"12abc\"): " + nf.parseNumber("12abc") + ", " + nf.lastOperationStatus);
+			var result:NumberParseResult = nf.parse("Total: -1,234.5 USD");
+			trace("parse(\"Total: -1,234.5 USD\"): " + result.value + " [" + result.startIndex + ", " + result.endIndex + "], " + nf.lastOperationStatus);
+			result = nf.parse("none");
+			trace("parse(\"none\"): " + result.value + " [" + result.startIndex + ", " + result.endIndex + "], " + nf.lastOperationStatus);
+
+			nf = new NumberFormatter("de-DE");
+			describe(nf);
+			trace("formatNumber(1234567.891): " + nf.formatNumber(1234567.891));
+			trace("parseNumber(\"1.234,5\"): " + nf.parseNumber("1.234,5"));
+
+			nf = new NumberFormatter("fr-FR");
+			describe(nf);
+			trace("formatNumber(-1234.5): " + showSpaces(nf.formatNumber(-1234.5)));
+
+			describe(new NumberFormatter("fr-CA"));
+			describe(new NumberFormatter("xx-YY"));
+			describe(new NumberFormatter(LocaleID.DEFAULT));
+		}
+
+		private function describe(nf:NumberFormatter):void {
+			trace("// new NumberFormatter(\"" + nf.requestedLocaleIDName + "\")");
+			trace("actualLocaleIDName: " + nf.actualLocaleIDName + ", lastOperationStatus: " + nf.lastOperationStatus);
+			trace("decimalSeparator: " + nf.decimalSeparator + ", groupingSeparator: " + showSpaces(nf.groupingSeparator) + ", groupingPattern: " + nf.groupingPattern);
+			trace("fractionalDigits: " + nf.fractionalDigits + ", leadingZero: " + nf.leadingZero + ", trailingZeros: " + nf.trailingZeros + ", negativeNumberFormat: " + nf.negativeNumberFormat);
+		}
+
+		private function showSpaces(s:String):String {
+			return s.split("\u00A0").join("<nbsp>");
+		}
+	}
+}
@@ -0,0 +1,39 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Rectangle;
+	import flash.printing.PrintJob;
+	import flash.printing.PrintJobOptions;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("isSupported: " + PrintJob.isSupported);
+
+			var job:PrintJob = new PrintJob();
+			try {
+				job.addPage(this);
+			} catch (e:Error) {
+				trace("addPage before start: " + e.errorID);
+			}
+
+			trace("start: " + job.start());
+			trace(job.paperWidth, job.paperHeight, job.pageWidth, job.pageHeight, job.orientation);
+
+			var square:Sprite = new Sprite();
+			square.graphics.beginFill(0xFF0000);
+			square.graphics.drawRect(0, 0, 100, 100);
+			job.addPage(square);
+			job.addPage(square, new Rectangle(0, 0, 50, 50), new PrintJobOptions(true));
+			job.send();
+
+			// A job which was sent can't get any more pages, nor be sent again.
+			try {
+				job.addPage(square);
+			} catch (e:Error) {
+				trace("addPage after send: " + e.errorID);
+			}
+			job.send();
+			trace("done");
+		}
+	}
+}
@@ -0,0 +1,130 @@
+package {
+	import flash.display.Sprite;
+	import flash.sampler.*;
+	import flash.utils.ByteArray;
+
+	public class Test extends Sprite {
+		public function Test() {
+			testInvocationCount();
+			testSize();
+			testObjectSamples();
+		}
+
+		private function testInvocationCount():void {
+			trace("// Invocation counts");
+			var counter:Counter = new Counter();
+			counter.increment();
+
+			startSampling();
+			counter = new Counter();
+			counter.increment();
+			counter.increment();
+			counter.increment();
+			counter.value;
+			counter.value;
+			counter.value = 5;
+			pauseSampling();
+			counter.increment();
+
+			trace("  increment: " + getInvocationCount(counter, new QName("", "increment")));
+			trace("  value getter: " + getGetterInvocationCount(counter, new QName("", "value")));
+			trace("  value setter: " + getSetterInvocationCount(counter, new QName("", "value")));
+			trace("  constructor: " + getInvocationCount(counter, null));
+			trace("  missing: " + getInvocationCount(counter, new QName("", "missing")));
+			trace("  isGetterSetter: " + isGetterSetter(counter, new QName("", "value")) + " " +
+				isGetterSetter(counter, new QName("", "increment")));
+
+			stopSampling();
+			trace("  after stop: " + getInvocationCount(counter, new QName("", "increment")));
+		}
+
+		private function testSize():void {
+			trace("// Sizes");
+			trace("  undefined: " + getSize(undefined) + ", null: " + getSize(null));
+
+			var small:Array = [];
+			var large:Array = [];
+			for (var i:int = 0; i < 100; i++) {
+				large.push(i);
+			}
+			trace("  arrays: " + (getSize(small) > 0) + " " + (getSize(large) > getSize(small)));
+
+			var empty:ByteArray = new ByteArray();
+			var full:ByteArray = new ByteArray();
+			full.length = 1000;
+			trace("  byte arrays: " + (getSize(full) - getSize(empty) >= 1000));
+
+			trace("  strings: " + (getSize("a much longer string than the other") > getSize("short")));
+		}
+
+		private function testObjectSamples():void {
+			trace("// Object samples");
+			startSampling();
+			var first:Counter = new Counter();
+			var second:Counter = new Counter();
+			var parts:Array = "a,b,c".split(",");
+			var bytes:ByteArray = new ByteArray();
+			var vector:Vector.<int> = Vector.<int>([1, 2, 3]).concat();
+			var children:XMLList = new XML("<a><b/><b/></a>").b;
+			pauseSampling();
+
+			var counters:Array = [];
+			var types:Array = [];
+			var seen:Object = {};
+			var newBeforeDelete:Boolean = true;
+			var ordered:Boolean = true;
+			var lastTime:Number = 0;
+			for each (var sample:Sample in getSamples()) {
+				if (sample.time < lastTime) {
+					ordered = false;
+				}
+				lastTime = sample.time;
+
+				if (sample is NewObjectSample) {
+					var created:NewObjectSample = NewObjectSample(sample);
+					seen[created.id] = true;
+					if (created.type == Counter) {
+						counters.push(created);
+					}
+					if (types.indexOf(created.type) == -1) {
+						types.push(created.type);
+					}
+				} else if (sample is DeleteObjectSample) {
+					if (!seen[DeleteObjectSample(sample).id]) {
+						newBeforeDelete = false;
+					}
+				}
+			}
+
+			trace("  counters: " + counters.length);
+			trace("  ids increase: " + (counters[0].id < counters[1].id));
+			trace("  objects: " + (counters[0].object === first) + " " + (counters[1].object === second));
+			trace("  sizes: " + (counters[0].size > 0));
+			trace("  native allocations: " + (types.indexOf(Array) != -1) + " " +
+				(types.indexOf(ByteArray) != -1) + " " + (types.indexOf(Vector.<int>) != -1) + " " +
+				(types.indexOf(XML) != -1) + " " + (types.indexOf(XMLList) != -1));
+			trace("  ordered by time: " + ordered);
+			trace("  new before delete: " + newBeforeDelete);
+
+			clearSamples();
+			trace("  after clear: " + getSampleCount());
+			stopSampling();
+		}
+	}
+}
+
+class Counter {
+	private var _value:int = 0;
+
+	public function increment():void {
+		_value++;
+	}
+
+	public function get value():int {
+		return _value;
+	}
+
+	public function set value(value:int):void {
+		_value = value;
+	}
+}
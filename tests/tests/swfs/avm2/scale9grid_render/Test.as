@@ -0,0 +1,83 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.display.Graphics;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0xFFFFFF);
+			graphics.drawRect(0, 0, 300, 250);
+			graphics.endFill();
+
+			var sprite:Sprite = new Sprite();
+			drawPattern(sprite.graphics);
+			sprite.scale9Grid = new Rectangle(10, 10, 20, 20);
+			sprite.x = 10;
+			sprite.y = 10;
+			sprite.scaleX = 3;
+			sprite.scaleY = 2;
+			addChild(sprite);
+			describe("sprite", sprite);
+
+			var clip:MovieClip = new MovieClip();
+			drawPattern(clip.graphics);
+			clip.scale9Grid = new Rectangle(10, 10, 20, 20);
+			clip.x = 150;
+			clip.y = 10;
+			clip.scaleX = 2;
+			clip.scaleY = 3;
+			addChild(clip);
+			describe("clip", clip);
+
+			var shape:Shape = new Shape();
+			drawPattern(shape.graphics);
+			shape.scale9Grid = new Rectangle(10, 10, 20, 20);
+			shape.x = 10;
+			shape.y = 110;
+			shape.scaleX = 2.5;
+			shape.scaleY = 2;
+			addChild(shape);
+			describe("shape", shape);
+
+			var container:Sprite = new Sprite();
+			var child:Shape = new Shape();
+			drawPattern(child.graphics);
+			container.addChild(child);
+			container.scale9Grid = new Rectangle(10, 10, 20, 20);
+			container.x = 130;
+			container.y = 140;
+			container.scaleX = 1.5;
+			container.scaleY = 2.5;
+			addChild(container);
+			describe("container", container);
+		}
+
+		private function describe(name:String, object:*):void {
+			trace("// " + name);
+			trace(object.scale9Grid);
+			trace(object.width + "x" + object.height);
+		}
+
+		// A 40x40 square, with a different color in each cell of a
+		// (10, 10, 20, 20) scale9Grid.
+		private function drawPattern(g:Graphics):void {
+			fill(g, 0xFF0000, 0, 0, 10, 10);
+			fill(g, 0xFF00FF, 10, 0, 20, 10);
+			fill(g, 0x00FF00, 30, 0, 10, 10);
+			fill(g, 0x800000, 0, 10, 10, 20);
+			fill(g, 0x000080, 10, 10, 20, 20);
+			fill(g, 0x008000, 30, 10, 10, 20);
+			fill(g, 0x0000FF, 0, 30, 10, 10);
+			fill(g, 0x00FFFF, 10, 30, 20, 10);
+			fill(g, 0xFFFF00, 30, 30, 10, 10);
+		}
+
+		private function fill(g:Graphics, color:uint, x:Number, y:Number, width:Number, height:Number):void {
+			g.beginFill(color);
+			g.drawRect(x, y, width, height);
+			g.endFill();
+		}
+	}
+}
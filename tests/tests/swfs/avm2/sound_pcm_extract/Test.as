@@ -0,0 +1,85 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.events.IOErrorEvent;
+	import flash.media.Sound;
+	import flash.net.URLRequest;
+	import flash.utils.ByteArray;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var pcm:ByteArray = new ByteArray();
+			for (var i:int = 0; i < 8; i++) {
+				pcm.writeFloat(i / 8);
+				pcm.writeFloat(-i / 8);
+			}
+			pcm.position = 0;
+
+			var sound:Sound = new Sound();
+			sound.loadPCMFromByteArray(pcm, 8, "float", true, 44100);
+
+			trace("// extract(4) from the start");
+			traceExtract(sound, 4, 0);
+			trace("// extract(4) continuing");
+			traceExtract(sound, 4, -1);
+			trace("// extract(3) from 2");
+			traceExtract(sound, 3, 2);
+			trace("// extract(4) from 6");
+			traceExtract(sound, 4, 6);
+			trace("// extract(4) past the end");
+			traceExtract(sound, 4, 8);
+
+			trace("// loadPCMFromByteArray with a bad format");
+			try {
+				new Sound().loadPCMFromByteArray(pcm, 1, "double");
+			} catch (e:Error) {
+				trace(e);
+			}
+
+			trace("// loadCompressedDataFromByteArray with bad data");
+			var garbage:ByteArray = new ByteArray();
+			garbage.writeUTFBytes("This is not an MP3 file.");
+			garbage.position = 0;
+			try {
+				new Sound().loadCompressedDataFromByteArray(garbage, garbage.length);
+			} catch (e:Error) {
+				trace(e);
+			}
+
+			trace("// close() while loading");
+			var loading:Sound = new Sound();
+			loading.addEventListener(Event.COMPLETE, function(event:Event):void {
+				trace("complete");
+			});
+			loading.addEventListener(IOErrorEvent.IO_ERROR, function(event:IOErrorEvent):void {
+				trace("ioError");
+			});
+			loading.load(new URLRequest("missing.mp3"));
+			loading.close();
+			try {
+				loading.close();
+			} catch (e:Error) {
+				trace(e);
+			}
+			try {
+				loading.load(new URLRequest("missing.mp3"));
+			} catch (e:Error) {
+				trace(e);
+			}
+		}
+
+		private function traceExtract(sound:Sound, length:int, position:Number):void {
+			var bytes:ByteArray = new ByteArray();
+			var extracted:Number = sound.extract(bytes, length, position);
+			trace("extracted", extracted, "length", bytes.length);
+			bytes.position = 0;
+			while (bytes.bytesAvailable > 0) {
+				trace(round(bytes.readFloat()), round(bytes.readFloat()));
+			}
+		}
+
+		private function round(value:Number):Number {
+			return Math.round(value * 1000) / 1000;
+		}
+	}
+}
@@ -0,0 +1,62 @@
+package {
+	import flash.display.Sprite;
+	import flash.globalization.StringTools;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var english:StringTools = new StringTools("en-US");
+			describe("new StringTools(\"en-US\")", english);
+			convert(english, "istanbul");
+			convert(english, "Iğdır");
+			convert(english, "İZMİR");
+			convert(english, "ISPARTA");
+			convert(english, "Straße");
+			convert(english, "ÉCOLE");
+
+			var turkish:StringTools = new StringTools("tr-TR");
+			describe("new StringTools(\"tr-TR\")", turkish);
+			convert(turkish, "istanbul");
+			convert(turkish, "Iğdır");
+			convert(turkish, "İZMİR");
+			convert(turkish, "ISPARTA");
+			convert(turkish, "iı");
+
+			var fallback:StringTools = new StringTools("tr");
+			describe("new StringTools(\"tr\")", fallback);
+			convert(fallback, "istanbul");
+			trace("lastOperationStatus after converting: " + fallback.lastOperationStatus);
+
+			var unknown:StringTools = new StringTools("xx-YY");
+			describe("new StringTools(\"xx-YY\")", unknown);
+			convert(unknown, "istanbul");
+
+			trace("// Errors");
+			try {
+				english.toUpperCase(null);
+			} catch (e:TypeError) {
+				trace("toUpperCase(null): TypeError " + e.errorID);
+			}
+			try {
+				english.toLowerCase(null);
+			} catch (e:TypeError) {
+				trace("toLowerCase(null): TypeError " + e.errorID);
+			}
+			try {
+				new StringTools(null);
+			} catch (e:TypeError) {
+				trace("new StringTools(null): TypeError " + e.errorID);
+			}
+		}
+
+		private static function describe(name:String, tools:StringTools):void {
+			trace("// " + name);
+			trace("requestedLocaleIDName: " + tools.requestedLocaleIDName + ", actualLocaleIDName: " + tools.actualLocaleIDName + ", lastOperationStatus: " + tools.lastOperationStatus);
+		}
+
+		private static function convert(tools:StringTools, s:String):void {
+			var upper:String = tools.toUpperCase(s);
+			var lower:String = tools.toLowerCase(s);
+			trace(s + ": toUpperCase: " + upper + " (" + upper.length + "), toLowerCase: " + lower + " (" + lower.length + ")");
+		}
+	}
+}
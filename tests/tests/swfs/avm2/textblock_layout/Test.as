@@ -0,0 +1,159 @@
+package {
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.events.EventDispatcher;
+	import flash.geom.Rectangle;
+	import flash.text.engine.ContentElement;
+	import flash.text.engine.ElementFormat;
+	import flash.text.engine.FontDescription;
+	import flash.text.engine.GraphicElement;
+	import flash.text.engine.GroupElement;
+	import flash.text.engine.TextBlock;
+	import flash.text.engine.TextElement;
+	import flash.text.engine.TextLine;
+	import flash.text.engine.TextLineMirrorRegion;
+
+	public class Test extends Sprite {
+		private var format:ElementFormat = new ElementFormat(new FontDescription("Noto Sans"), 20);
+
+		public function Test() {
+			testLineBreaking();
+			testAtoms();
+			testGraphic();
+			testMirrorRegions();
+		}
+
+		private function describe(block:TextBlock, line:TextLine):void {
+			if (line == null) {
+				trace("  null, " + block.textLineCreationResult);
+			} else {
+				trace("  [" + line.textBlockBeginIndex + ", " + line.rawTextLength + "] \"" +
+					block.content.rawText.substr(line.textBlockBeginIndex, line.rawTextLength).replace("\u2028", "\\u2028") +
+					"\", " + block.textLineCreationResult);
+			}
+		}
+
+		private function layout(block:TextBlock, width:Number):void {
+			var line:TextLine = block.createTextLine(null, width);
+			while (line != null) {
+				describe(block, line);
+				line = block.createTextLine(line, width);
+			}
+			describe(block, line);
+		}
+
+		private function testLineBreaking():void {
+			trace("// Words");
+			var block:TextBlock = new TextBlock(new TextElement("one two three four five six", format));
+			layout(block, 110);
+
+			trace("// Forced break");
+			block = new TextBlock(new TextElement("one\u2028two", format));
+			layout(block, 110);
+
+			trace("// Word longer than the line");
+			block = new TextBlock(new TextElement("abcdefghij", format));
+			var line:TextLine = block.createTextLine(null, 40);
+			trace("  " + block.textLineCreationResult);
+			trace("  broken inside: " + (line.rawTextLength > 0 && line.rawTextLength < 10));
+			line = block.createTextLine(line, 200);
+			trace("  rest: " + (line.textBlockBeginIndex + line.rawTextLength) + ", " + block.textLineCreationResult);
+
+			trace("// Insufficient width");
+			block = new TextBlock(new TextElement("abc", format));
+			trace("  " + block.createTextLine(null, 1) + ", " + block.textLineCreationResult);
+			line = block.createTextLine(null, 1, 0, true);
+			trace("  " + line.rawTextLength + ", " + block.textLineCreationResult);
+		}
+
+		private function testAtoms():void {
+			trace("// Atoms");
+			var block:TextBlock = new TextBlock(new TextElement("one two three", format));
+			var first:TextLine = block.createTextLine(null, 110);
+			var line:TextLine = block.createTextLine(first, 110);
+			trace("  atomCount: " + line.atomCount);
+			trace("  char 7: " + line.getAtomIndexAtCharIndex(7) + ", char 8: " + line.getAtomIndexAtCharIndex(8) +
+				", char 12: " + line.getAtomIndexAtCharIndex(12) + ", char 13: " + line.getAtomIndexAtCharIndex(13));
+			trace("  atom 1: " + line.getAtomTextBlockBeginIndex(1) + " to " + line.getAtomTextBlockEndIndex(1));
+
+			var contiguous:Boolean = true;
+			var centered:Boolean = true;
+			var hit:Boolean = true;
+			addChild(line);
+			line.x = 10;
+			line.y = 50;
+			for (var i:int = 0; i < line.atomCount; i++) {
+				var bounds:Rectangle = line.getAtomBounds(i);
+				if (bounds.width <= 0) {
+					contiguous = false;
+				}
+				if (i + 1 < line.atomCount && Math.abs(bounds.right - line.getAtomBounds(i + 1).left) > 0.5) {
+					contiguous = false;
+				}
+				var center:Number = line.getAtomCenter(i);
+				if (Math.abs(center - (bounds.left + bounds.width / 2)) > 0.01) {
+					centered = false;
+				}
+				if (line.getAtomIndexAtPoint(line.x + center, line.y - 5) != i) {
+					hit = false;
+				}
+			}
+			trace("  contiguous: " + contiguous + ", centered: " + centered + ", hit: " + hit);
+			trace("  outside: " + line.getAtomIndexAtPoint(line.x - 20, line.y - 5));
+			trace("  width: " + (Math.abs(line.getAtomBounds(line.atomCount - 1).right - line.getAtomBounds(0).left - line.textWidth) < 1));
+			trace("  word boundaries: " + line.getAtomWordBoundaryOnLeft(0) + " " + line.getAtomWordBoundaryOnLeft(1));
+			try {
+				line.getAtomBounds(line.atomCount);
+			} catch (e:RangeError) {
+				trace("  " + e.errorID);
+			}
+			removeChild(line);
+		}
+
+		private function testGraphic():void {
+			trace("// Graphic");
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, 30, 40);
+			var elements:Vector.<ContentElement> = new <ContentElement>[
+				new TextElement("ab", format),
+				new GraphicElement(shape, 30, 40, format),
+				new TextElement("cd", format)
+			];
+			var block:TextBlock = new TextBlock(new GroupElement(elements));
+			var line:TextLine = block.createTextLine(null, 500);
+			trace("  atomCount: " + line.atomCount + ", hasGraphicElement: " + line.hasGraphicElement);
+			trace("  graphic: " + (line.getAtomGraphic(2) == shape) + ", text: " + line.getAtomGraphic(1));
+			trace("  totalAscent: " + line.totalAscent);
+			var bounds:Rectangle = line.getAtomBounds(2);
+			trace("  width: " + (Math.abs(bounds.width - 30) < 1));
+			trace("  position: " + (shape.x == bounds.x) + ", " + shape.y);
+		}
+
+		private function testMirrorRegions():void {
+			trace("// Mirror regions");
+			var mirrorA:EventDispatcher = new EventDispatcher();
+			var mirrorB:EventDispatcher = new EventDispatcher();
+			var hello:TextElement = new TextElement("hello ", format, mirrorA);
+			var world:TextElement = new TextElement("world", format, mirrorB);
+			var elements:Vector.<ContentElement> = new <ContentElement>[hello, world, new TextElement("!", format)];
+			var block:TextBlock = new TextBlock(new GroupElement(elements));
+			var line:TextLine = block.createTextLine(null, 500);
+
+			var regions:Vector.<TextLineMirrorRegion> = line.mirrorRegions;
+			trace("  count: " + regions.length);
+			trace("  first: " + (regions[0].element == hello) + " " + (regions[0].mirror == mirrorA) + " " +
+				(regions[0].textLine == line));
+			trace("  second: " + (regions[1].element == world) + " " + (regions[1].mirror == mirrorB));
+			trace("  links: " + regions[0].previousRegion + " " + (regions[0].nextRegion == regions[1]) + " " +
+				(regions[1].previousRegion == regions[0]) + " " + regions[1].nextRegion);
+			trace("  bounds: " + (regions[0].bounds.width > 0) + " " +
+				(Math.abs(regions[0].bounds.right - regions[1].bounds.left) < 0.5));
+			trace("  lookup: " + (line.getMirrorRegion(mirrorB) == regions[1]) + " " +
+				line.getMirrorRegion(new EventDispatcher()));
+
+			block = new TextBlock(new TextElement("plain", format));
+			trace("  without mirrors: " + block.createTextLine(null, 500).mirrorRegions);
+		}
+	}
+}
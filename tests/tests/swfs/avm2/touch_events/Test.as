@@ -0,0 +1,64 @@
+package {
+	import flash.display.DisplayObject;
+	import flash.display.Sprite;
+	import flash.events.MouseEvent;
+	import flash.events.TouchEvent;
+	import flash.ui.Multitouch;
+	import flash.ui.MultitouchInputMode;
+
+	public class Test extends Sprite {
+		private static const TOUCH_EVENTS:Array = [
+			TouchEvent.TOUCH_BEGIN, TouchEvent.TOUCH_MOVE, TouchEvent.TOUCH_END, TouchEvent.TOUCH_TAP,
+			TouchEvent.TOUCH_OVER, TouchEvent.TOUCH_OUT, TouchEvent.TOUCH_ROLL_OVER, TouchEvent.TOUCH_ROLL_OUT
+		];
+
+		public function Test() {
+			Multitouch.inputMode = MultitouchInputMode.TOUCH_POINT;
+			trace("inputMode: " + Multitouch.inputMode);
+			trace("supportsTouchEvents: " + Multitouch.supportsTouchEvents + ", maxTouchPoints: " + Multitouch.maxTouchPoints);
+
+			var box:Sprite = new Sprite();
+			box.name = "box";
+			box.graphics.beginFill(0xFF0000);
+			box.graphics.drawRect(0, 0, 100, 100);
+			box.x = 100;
+			box.y = 100;
+			addChild(box);
+
+			for each (var type:String in TOUCH_EVENTS) {
+				box.addEventListener(type, onTouch);
+				stage.addEventListener(type, onStageTouch);
+			}
+
+			box.addEventListener(MouseEvent.MOUSE_DOWN, onMouse);
+			box.addEventListener(MouseEvent.MOUSE_UP, onMouse);
+			box.addEventListener(MouseEvent.CLICK, onMouse);
+		}
+
+		private function describe(object:DisplayObject):String {
+			return object == stage ? "stage" : (object == null ? "null" : object.name);
+		}
+
+		private function onTouch(event:TouchEvent):void {
+			var line:String = event.type + " " + describe(event.target as DisplayObject) +
+				" id=" + event.touchPointID + " primary=" + event.isPrimaryTouchPoint +
+				" local=" + event.localX + "," + event.localY;
+			if (event.type == TouchEvent.TOUCH_OVER || event.type == TouchEvent.TOUCH_OUT ||
+					event.type == TouchEvent.TOUCH_ROLL_OVER || event.type == TouchEvent.TOUCH_ROLL_OUT) {
+				line += " related=" + describe(event.relatedObject);
+			}
+			trace(line);
+		}
+
+		// Events targeting objects bubble to the stage, and were already traced there.
+		private function onStageTouch(event:TouchEvent):void {
+			if (event.target == stage) {
+				onTouch(event);
+			}
+		}
+
+		private function onMouse(event:MouseEvent):void {
+			trace(event.type + " " + describe(event.target as DisplayObject) + " local=" + event.localX + "," + event.localY);
+		}
+	}
+}
@@ -0,0 +1,54 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.GestureEvent;
+	import flash.events.MouseEvent;
+	import flash.events.PressAndTapGestureEvent;
+	import flash.events.TransformGestureEvent;
+	import flash.ui.Multitouch;
+	import flash.ui.MultitouchInputMode;
+
+	public class Test extends Sprite {
+		public function Test() {
+			Multitouch.inputMode = MultitouchInputMode.GESTURE;
+			trace("inputMode: " + Multitouch.inputMode);
+
+			var box:Sprite = new Sprite();
+			box.name = "box";
+			box.graphics.beginFill(0x00FF00);
+			box.graphics.drawRect(0, 0, 450, 350);
+			box.x = 50;
+			box.y = 50;
+			addChild(box);
+
+			box.addEventListener(TransformGestureEvent.GESTURE_ZOOM, onTransform);
+			box.addEventListener(TransformGestureEvent.GESTURE_PAN, onTransform);
+			box.addEventListener(TransformGestureEvent.GESTURE_ROTATE, onTransform);
+			box.addEventListener(TransformGestureEvent.GESTURE_SWIPE, onTransform);
+			box.addEventListener(GestureEvent.GESTURE_TWO_FINGER_TAP, onGesture);
+			box.addEventListener(PressAndTapGestureEvent.GESTURE_PRESS_AND_TAP, onPressAndTap);
+
+			box.addEventListener(MouseEvent.MOUSE_DOWN, onMouse);
+			box.addEventListener(MouseEvent.MOUSE_UP, onMouse);
+			box.addEventListener(MouseEvent.CLICK, onMouse);
+		}
+
+		private function onTransform(event:TransformGestureEvent):void {
+			trace(event.type + " " + event.phase + " local=" + event.localX + "," + event.localY +
+				" scale=" + event.scaleX + "," + event.scaleY + " rotation=" + event.rotation +
+				" offset=" + event.offsetX + "," + event.offsetY);
+		}
+
+		private function onGesture(event:GestureEvent):void {
+			trace(event.type + " " + event.phase + " local=" + event.localX + "," + event.localY);
+		}
+
+		private function onPressAndTap(event:PressAndTapGestureEvent):void {
+			trace(event.type + " " + event.phase + " local=" + event.localX + "," + event.localY +
+				" tap=" + event.tapLocalX + "," + event.tapLocalY);
+		}
+
+		private function onMouse(event:MouseEvent):void {
+			trace(event.type + " " + event.target.name + " local=" + event.localX + "," + event.localY);
+		}
+	}
+}
@@ -0,0 +1,87 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.system.MessageChannel;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+
+	public class Test extends Sprite {
+		private var toWorker:MessageChannel;
+		private var fromWorker:MessageChannel;
+
+		public function Test() {
+			if (!Worker.current.isPrimordial) {
+				runBackground();
+				return;
+			}
+
+			var worker:Worker = WorkerDomain.current.createWorker(loaderInfo.bytes);
+			toWorker = Worker.current.createMessageChannel(worker);
+			fromWorker = worker.createMessageChannel(Worker.current);
+			var done:MessageChannel = worker.createMessageChannel(Worker.current);
+			worker.setSharedProperty("toWorker", toWorker);
+			worker.setSharedProperty("fromWorker", fromWorker);
+			worker.setSharedProperty("done", done);
+
+			toWorker.addEventListener(Event.CHANNEL_STATE, function(e:Event):void {
+				trace("toWorker channelState: " + toWorker.state);
+			});
+			fromWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+				trace("channelMessage: " + fromWorker.receive());
+			});
+			fromWorker.addEventListener(Event.CHANNEL_STATE, function(e:Event):void {
+				trace("fromWorker channelState: " + fromWorker.state);
+			});
+
+			trace("toWorker state: " + toWorker.state);
+			trace("toWorker messageAvailable: " + toWorker.messageAvailable);
+			toWorker.send("hello");
+			toWorker.send(42);
+			toWorker.send({x: 1});
+			toWorker.send([1, 2, 3]);
+			toWorker.send("close");
+			trace("toWorker messageAvailable before start: " + toWorker.messageAvailable);
+
+			worker.start();
+			trace("received: " + done.receive(true));
+
+			trace("toWorker messageAvailable: " + toWorker.messageAvailable);
+			trace("toWorker state: " + toWorker.state);
+			trace("fromWorker state: " + fromWorker.state);
+			trace("fromWorker messageAvailable: " + fromWorker.messageAvailable);
+
+			toWorker.close();
+			trace("toWorker state after close: " + toWorker.state);
+			toWorker.send("ignored");
+			trace("toWorker messageAvailable after sending: " + toWorker.messageAvailable);
+		}
+
+		private function runBackground():void {
+			toWorker = Worker.current.getSharedProperty("toWorker");
+			fromWorker = Worker.current.getSharedProperty("fromWorker");
+			var done:MessageChannel = Worker.current.getSharedProperty("done");
+
+			toWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+				while (toWorker.messageAvailable) {
+					var message:* = toWorker.receive();
+					if (message === "close") {
+						fromWorker.close();
+						done.send("done");
+						return;
+					}
+					fromWorker.send(describe(message));
+				}
+			});
+		}
+
+		private static function describe(message:*):String {
+			if (message is Array) {
+				return "array " + message;
+			}
+			if (typeof message == "object") {
+				return "object x=" + message.x;
+			}
+			return typeof message + " " + message;
+		}
+	}
+}
@@ -0,0 +1,91 @@
+package {
+	import flash.concurrent.Condition;
+	import flash.concurrent.Mutex;
+	import flash.display.Sprite;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends Sprite {
+		public function Test() {
+			if (!Worker.current.isPrimordial) {
+				runBackground();
+				return;
+			}
+
+			trace("Mutex.isSupported: " + Mutex.isSupported);
+			trace("Condition.isSupported: " + Condition.isSupported);
+
+			trace("// Mutex");
+			var mutex:Mutex = new Mutex();
+			attempt("unlock without owning", function():void {
+				mutex.unlock();
+			});
+			trace("tryLock: " + mutex.tryLock());
+			trace("tryLock again: " + mutex.tryLock());
+			mutex.lock();
+			mutex.unlock();
+			mutex.unlock();
+			mutex.unlock();
+			attempt("unlock too often", function():void {
+				mutex.unlock();
+			});
+
+			trace("// Condition");
+			var condition:Condition = new Condition(mutex);
+			trace("mutex: " + (condition.mutex == mutex));
+			attempt("wait without owning", function():void {
+				condition.wait(10);
+			});
+			attempt("notify without owning", function():void {
+				condition.notify();
+			});
+			attempt("notifyAll without owning", function():void {
+				condition.notifyAll();
+			});
+
+			mutex.lock();
+			attempt("wait with a negative timeout", function():void {
+				condition.wait(-2);
+			});
+			condition.notify();
+			trace("notify with nobody waiting");
+			trace("wait without being notified: " + condition.wait(10));
+			trace("tryLock after waiting: " + mutex.tryLock());
+			mutex.unlock();
+
+			trace("// With a background worker");
+			var worker:Worker = WorkerDomain.current.createWorker(loaderInfo.bytes);
+			worker.setSharedProperty("mutex", mutex);
+			worker.setSharedProperty("condition", condition);
+			worker.start();
+
+			// The background worker can only take the mutex once this worker waits.
+			trace("wait: " + condition.wait(10000));
+			trace("value: " + worker.getSharedProperty("value"));
+			trace("tryLock after waiting: " + mutex.tryLock());
+			mutex.unlock();
+			mutex.unlock();
+			worker.terminate();
+		}
+
+		private function runBackground():void {
+			var mutex:Mutex = Worker.current.getSharedProperty("mutex");
+			var condition:Condition = Worker.current.getSharedProperty("condition");
+
+			mutex.lock();
+			Worker.current.setSharedProperty("value", "set while owning the mutex");
+			condition.notify();
+			mutex.unlock();
+		}
+
+		private static function attempt(name:String, fn:Function):void {
+			try {
+				fn();
+				trace(name + ": no error");
+			} catch (e:Error) {
+				trace(name + ": " + getQualifiedClassName(e) + " " + e.errorID);
+			}
+		}
+	}
+}
@@ -0,0 +1,56 @@
+package {
+	import flash.concurrent.Condition;
+	import flash.concurrent.Mutex;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.system.MessageChannel;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+
+	public class Test extends Sprite {
+		private var worker:Worker;
+		private var held:Mutex;
+		private var condition:Condition;
+		private var frame:int = 0;
+
+		public function Test() {
+			if (!Worker.current.isPrimordial) {
+				// Take the mutex and never give it back.
+				Worker.current.getSharedProperty("held").lock();
+				Worker.current.getSharedProperty("locked").send("locked");
+				return;
+			}
+
+			held = new Mutex();
+			condition = new Condition(new Mutex());
+
+			worker = WorkerDomain.current.createWorker(loaderInfo.bytes);
+			var locked:MessageChannel = worker.createMessageChannel(Worker.current);
+			worker.setSharedProperty("held", held);
+			worker.setSharedProperty("locked", locked);
+			worker.start();
+			trace("received: " + locked.receive(true));
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			frame++;
+			if (frame == 1) {
+				trace("// Mutex.lock() while a background worker owns the mutex");
+				trace("tryLock: " + held.tryLock());
+				held.lock();
+				trace("unreachable");
+			} else if (frame == 2) {
+				trace("// Condition.wait() without a timeout");
+				condition.mutex.lock();
+				condition.wait();
+				trace("unreachable");
+			} else if (frame == 3) {
+				trace("// Still running");
+				trace("tryLock: " + held.tryLock());
+				worker.terminate();
+			}
+		}
+	}
+}
@@ -0,0 +1,71 @@
+package {
+	import flash.display.Sprite;
+	import flash.system.MessageChannel;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+	import flash.utils.ByteArray;
+
+	public class Test extends Sprite {
+		public function Test() {
+			if (!Worker.current.isPrimordial) {
+				runBackground();
+				return;
+			}
+
+			var shared:ByteArray = new ByteArray();
+			trace("shareable: " + shared.shareable);
+			shared.shareable = true;
+			trace("shareable: " + shared.shareable);
+			shared.writeByte(1);
+			shared.writeByte(2);
+
+			var copied:ByteArray = new ByteArray();
+			copied.writeByte(1);
+			copied.writeByte(2);
+
+			trace("// Within the primordial worker");
+			Worker.current.setSharedProperty("shared", shared);
+			var sharedAgain:ByteArray = Worker.current.getSharedProperty("shared");
+			trace("same object: " + (sharedAgain == shared));
+			trace("shareable: " + sharedAgain.shareable);
+			sharedAgain[0] = 10;
+			trace("shared[0]: " + shared[0]);
+
+			trace("// With a background worker");
+			var worker:Worker = WorkerDomain.current.createWorker(loaderInfo.bytes);
+			var done:MessageChannel = worker.createMessageChannel(Worker.current);
+			worker.setSharedProperty("done", done);
+			worker.setSharedProperty("shared", shared);
+			worker.setSharedProperty("copied", copied);
+			worker.start();
+
+			trace("received: " + done.receive(true));
+			trace("shared: " + bytes(shared));
+			trace("copied: " + bytes(copied));
+			trace("copied in the worker: " + bytes(worker.getSharedProperty("copied")));
+			worker.terminate();
+		}
+
+		private function runBackground():void {
+			var shared:ByteArray = Worker.current.getSharedProperty("shared");
+			shared[1] = 20;
+			shared.position = shared.length;
+			shared.writeByte(30);
+
+			var copied:ByteArray = Worker.current.getSharedProperty("copied");
+			copied[1] = 20;
+			Worker.current.setSharedProperty("copied", copied);
+
+			var done:MessageChannel = Worker.current.getSharedProperty("done");
+			done.send("done");
+		}
+
+		private static function bytes(bytes:ByteArray):String {
+			var values:Array = [];
+			for (var i:int = 0; i < bytes.length; i++) {
+				values.push(bytes[i]);
+			}
+			return values.join(",") + " (shareable: " + bytes.shareable + ")";
+		}
+	}
+}
@@ -0,0 +1,69 @@
+package {
+	import flash.display.Sprite;
+	import flash.system.MessageChannel;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+
+	public class Test extends Sprite {
+		public function Test() {
+			if (!Worker.current.isPrimordial) {
+				runBackground();
+				return;
+			}
+
+			var current:Worker = Worker.current;
+			trace("// Own shared properties");
+			trace("missing: " + current.getSharedProperty("missing"));
+
+			var object:Object = {name: "object", values: [1, 2, 3]};
+			current.setSharedProperty("number", 1.5);
+			current.setSharedProperty("string", "text");
+			current.setSharedProperty("object", object);
+			current.setSharedProperty("null", null);
+			trace("number: " + current.getSharedProperty("number"));
+			trace("string: " + current.getSharedProperty("string"));
+			trace("null: " + current.getSharedProperty("null"));
+
+			var copy:Object = current.getSharedProperty("object");
+			trace("object: " + copy.name + " " + copy.values);
+			trace("same object: " + (copy == object));
+			trace("same as last copy: " + (copy == current.getSharedProperty("object")));
+			copy.name = "changed";
+			object.values.push(4);
+			copy = current.getSharedProperty("object");
+			trace("object after changes: " + copy.name + " " + copy.values);
+
+			current.setSharedProperty("string", undefined);
+			trace("string after removing: " + current.getSharedProperty("string"));
+
+			trace("// Background worker");
+			var worker:Worker = WorkerDomain.current.createWorker(loaderInfo.bytes);
+			var done:MessageChannel = worker.createMessageChannel(current);
+			worker.setSharedProperty("done", done);
+			worker.setSharedProperty("input", {count: 2, items: ["a", "b"]});
+			worker.setSharedProperty("primordial", current);
+			worker.start();
+
+			trace("received: " + done.receive(true));
+			var output:Object = worker.getSharedProperty("output");
+			trace("output: " + output.count + " " + output.items);
+			trace("input seen by the worker: " + worker.getSharedProperty("input").items);
+			trace("set on the primordial worker: " + current.getSharedProperty("fromBackground"));
+			worker.terminate();
+		}
+
+		private function runBackground():void {
+			var current:Worker = Worker.current;
+			var input:Object = current.getSharedProperty("input");
+			input.count++;
+			input.items.push("c");
+			current.setSharedProperty("output", input);
+
+			var primordial:Worker = current.getSharedProperty("primordial");
+			primordial.setSharedProperty("fromBackground", "hello from the background");
+
+			var done:MessageChannel = current.getSharedProperty("done");
+			done.send("done");
+		}
+	}
+}
@@ -0,0 +1,51 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.system.MessageChannel;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+	import flash.system.WorkerState;
+
+	public class Test extends Sprite {
+		private var worker:Worker;
+
+		public function Test() {
+			if (!Worker.current.isPrimordial) {
+				// Let the primordial worker know that this worker is running.
+				var started:MessageChannel = Worker.current.getSharedProperty("started");
+				started.send("started");
+				return;
+			}
+
+			trace("Worker.isSupported: " + Worker.isSupported);
+			trace("WorkerDomain.isSupported: " + WorkerDomain.isSupported);
+			trace("current isPrimordial: " + Worker.current.isPrimordial);
+			trace("current state: " + Worker.current.state);
+			trace("Worker.current == Worker.current: " + (Worker.current == Worker.current));
+
+			worker = WorkerDomain.current.createWorker(loaderInfo.bytes);
+			trace("created isPrimordial: " + worker.isPrimordial);
+			trace("created state: " + worker.state);
+			worker.addEventListener(Event.WORKER_STATE, onWorkerState);
+
+			var channel:MessageChannel = worker.createMessageChannel(Worker.current);
+			worker.setSharedProperty("started", channel);
+			worker.start();
+
+			trace("received: " + channel.receive(true));
+			trace("state after receiving: " + worker.state);
+		}
+
+		private function onWorkerState(e:Event):void {
+			trace("workerState: " + worker.state);
+			if (worker.state == WorkerState.RUNNING) {
+				trace("terminate: " + worker.terminate());
+				trace("state after terminate: " + worker.state);
+			} else if (worker.state == WorkerState.TERMINATED) {
+				trace("terminate again: " + worker.terminate());
+				trace("terminate primordial: " + Worker.current.terminate());
+				trace("primordial state: " + Worker.current.state);
+			}
+		}
+	}
+}
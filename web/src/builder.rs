@@ -714,6 +714,7 @@ impl RuffleInstanceBuilder {
             .with_frame_rate(self.frame_rate)
             .with_page_url(window.location().href().ok())
             .with_gamepad_button_mapping(self.gamepad_button_mapping.clone())
+            .with_max_touch_points(window.navigator().max_touch_points().max(0) as u32)
             .build();
 
         let player_weak = Arc::downgrade(&core);
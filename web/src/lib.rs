@@ -130,6 +130,7 @@ struct RuffleInstance {
     mouse_leave_callback: Option<JsCallback<PointerEvent>>,
     mouse_down_callback: Option<JsCallback<PointerEvent>>,
     mouse_up_callback: Option<JsCallback<PointerEvent>>,
+    touch_cancel_callback: Option<JsCallback<PointerEvent>>,
     mouse_wheel_callback: Option<JsCallback<WheelEvent>>,
     key_down_callback: Option<JsCallback<KeyboardEvent>>,
     key_up_callback: Option<JsCallback<KeyboardEvent>>,
@@ -524,6 +525,7 @@ impl RuffleHandle {
             mouse_leave_callback: None,
             mouse_down_callback: None,
             mouse_up_callback: None,
+            touch_cancel_callback: None,
             mouse_wheel_callback: None,
             key_down_callback: None,
             key_up_callback: None,
@@ -570,9 +572,16 @@ impl RuffleHandle {
                 false,
                 move |js_event: PointerEvent| {
                     let _ = ruffle.with_instance(move |instance| {
-                        let event = PlayerEvent::MouseMove {
-                            x: f64::from(js_event.offset_x()) * instance.device_pixel_ratio,
-                            y: f64::from(js_event.offset_y()) * instance.device_pixel_ratio,
+                        let x = f64::from(js_event.offset_x()) * instance.device_pixel_ratio;
+                        let y = f64::from(js_event.offset_y()) * instance.device_pixel_ratio;
+                        let event = if js_event.pointer_type() == "touch" {
+                            PlayerEvent::TouchMove {
+                                id: js_event.pointer_id() as u32,
+                                x,
+                                y,
+                            }
+                        } else {
+                            PlayerEvent::MouseMove { x, y }
                         };
                         let _ = instance.with_core_mut(|core| {
                             core.handle_event(event);
@@ -628,6 +637,19 @@ impl RuffleHandle {
                                 .set_pointer_capture(js_event.pointer_id());
                         }
                         let device_pixel_ratio = instance.device_pixel_ratio;
+                        let x = f64::from(js_event.offset_x()) * device_pixel_ratio;
+                        let y = f64::from(js_event.offset_y()) * device_pixel_ratio;
+
+                        // The core emulates the mouse for the primary touch point.
+                        if js_event.pointer_type() == "touch" {
+                            let id = js_event.pointer_id() as u32;
+                            let _ = instance.with_core_mut(|core| {
+                                core.handle_event(PlayerEvent::TouchBegin { id, x, y });
+                            });
+                            js_event.prevent_default();
+                            return;
+                        }
+
                         let button = match js_event.button() {
                             0 => MouseButton::Left,
                             1 => MouseButton::Middle,
@@ -635,8 +657,8 @@ impl RuffleHandle {
                             _ => MouseButton::Unknown,
                         };
                         let event = PlayerEvent::MouseDown {
-                            x: f64::from(js_event.offset_x()) * device_pixel_ratio,
-                            y: f64::from(js_event.offset_y()) * device_pixel_ratio,
+                            x,
+                            y,
                             button,
                             // TODO The index should be provided by the browser, not calculated.
                             index: None,
@@ -666,15 +688,25 @@ impl RuffleHandle {
                                 .unchecked_ref::<Element>()
                                 .release_pointer_capture(js_event.pointer_id());
                         }
-                        let event = PlayerEvent::MouseUp {
-                            x: f64::from(js_event.offset_x()) * instance.device_pixel_ratio,
-                            y: f64::from(js_event.offset_y()) * instance.device_pixel_ratio,
-                            button: match js_event.button() {
-                                0 => MouseButton::Left,
-                                1 => MouseButton::Middle,
-                                2 => MouseButton::Right,
-                                _ => MouseButton::Unknown,
-                            },
+                        let x = f64::from(js_event.offset_x()) * instance.device_pixel_ratio;
+                        let y = f64::from(js_event.offset_y()) * instance.device_pixel_ratio;
+                        let event = if js_event.pointer_type() == "touch" {
+                            PlayerEvent::TouchEnd {
+                                id: js_event.pointer_id() as u32,
+                                x,
+                                y,
+                            }
+                        } else {
+                            PlayerEvent::MouseUp {
+                                x,
+                                y,
+                                button: match js_event.button() {
+                                    0 => MouseButton::Left,
+                                    1 => MouseButton::Middle,
+                                    2 => MouseButton::Right,
+                                    _ => MouseButton::Unknown,
+                                },
+                            }
                         };
                         let _ = instance.with_core_mut(|core| {
                             core.handle_event(event);
@@ -687,6 +719,26 @@ impl RuffleHandle {
                 },
             ));
 
+            // Create touch cancel handler.
+            instance.touch_cancel_callback = Some(JsCallback::register(
+                &player.canvas,
+                "pointercancel",
+                false,
+                move |js_event: PointerEvent| {
+                    if js_event.pointer_type() != "touch" {
+                        return;
+                    }
+                    let _ = ruffle.with_instance(|instance| {
+                        let event = PlayerEvent::TouchCancel {
+                            id: js_event.pointer_id() as u32,
+                        };
+                        let _ = instance.with_core_mut(|core| {
+                            core.handle_event(event);
+                        });
+                    });
+                },
+            ));
+
             // Create mouse wheel handler.
             instance.mouse_wheel_callback = Some(JsCallback::register(
                 &player.canvas,
@@ -3,7 +3,8 @@ mod font_renderer;
 use super::JavascriptPlayer;
 use rfd::{AsyncFileDialog, FileHandle};
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter,
+    ClipboardData, ClipboardFormat, DialogLoaderError, DialogResultFuture, FileDialogResult,
+    FileFilter,
 };
 use ruffle_core::backend::ui::{
     FontDefinition, FullscreenError, LanguageIdentifier, MouseCursor, US_ENGLISH, UiBackend,
@@ -212,10 +213,22 @@ impl UiBackend for WebUiBackend {
         self.update_mouse_cursor();
     }
 
-    fn clipboard_content(&mut self) -> String {
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        if self.clipboard_content.is_empty() {
+            Vec::new()
+        } else {
+            vec![ClipboardFormat::Text]
+        }
+    }
+
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData> {
         // On web, clipboard content is not directly accessible due to security restrictions,
-        // but pasting from the clipboard is supported via the JS `paste` event
-        self.clipboard_content.to_owned()
+        // but pasting from the clipboard is supported via the JS `paste` event.
+        // Only text is delivered that way.
+        match format {
+            ClipboardFormat::Text => Some(ClipboardData::Text(self.clipboard_content.to_owned())),
+            _ => None,
+        }
     }
 
     fn clipboard_available(&mut self) -> bool {
@@ -224,7 +237,12 @@ impl UiBackend for WebUiBackend {
         true
     }
 
-    fn set_clipboard_content(&mut self, content: String) {
+    fn set_clipboard_data(&mut self, data: Vec<ClipboardData>) {
+        // Only text can be copied with `document.execCommand("copy")`.
+        let Some(ClipboardData::Text(content)) = ClipboardData::find(&data, ClipboardFormat::Text)
+        else {
+            return;
+        };
         self.set_clipboard_content_buffer(content.to_owned());
 
         // We use `document.execCommand("copy")` as `navigator.clipboard.writeText("string")`
@@ -243,7 +261,7 @@ impl UiBackend for WebUiBackend {
                 .expect("create_element(\"textarea\") didn't give us a textarea");
 
             let editing_text = self.js_player.is_virtual_keyboard_focused();
-            textarea.set_value(&content);
+            textarea.set_value(content);
             let _ = element.append_child(&textarea);
             let _ = textarea.focus();
             textarea.select();